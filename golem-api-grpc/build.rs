@@ -26,6 +26,22 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             "golem.worker.StdErrLog",
             "#[derive(bincode::Encode, bincode::Decode, serde::Serialize, serde::Deserialize)]",
         )
+        .type_attribute(
+            "golem.worker.InvocationStarted",
+            "#[derive(bincode::Encode, bincode::Decode, serde::Serialize, serde::Deserialize)]",
+        )
+        .type_attribute(
+            "golem.worker.InvocationFinished",
+            "#[derive(bincode::Encode, bincode::Decode, serde::Serialize, serde::Deserialize)]",
+        )
+        .type_attribute(
+            "golem.worker.InvocationFailed",
+            "#[derive(bincode::Encode, bincode::Decode, serde::Serialize, serde::Deserialize)]",
+        )
+        .type_attribute(
+            "golem.worker.WorkerStatusChanged",
+            "#[derive(bincode::Encode, bincode::Decode, serde::Serialize, serde::Deserialize)]",
+        )
         .type_attribute(
             "golem.worker.Level",
             "#[derive(bincode::Encode, bincode::Decode, serde::Serialize, serde::Deserialize)]",
@@ -34,6 +50,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             "golem.worker.Log",
             "#[derive(bincode::Encode, bincode::Decode, serde::Serialize, serde::Deserialize)]",
         )
+        .type_attribute(
+            "golem.worker.LogAttribute",
+            "#[derive(bincode::Encode, bincode::Decode, serde::Serialize, serde::Deserialize)]",
+        )
+        .type_attribute(
+            "golem.worker.LogAttributeValue",
+            "#[derive(bincode::Encode, bincode::Decode, serde::Serialize, serde::Deserialize)]",
+        )
+        .type_attribute(
+            "golem.worker.LogAttributeValue.value",
+            "#[derive(bincode::Encode, bincode::Decode, serde::Serialize, serde::Deserialize)]",
+        )
         .include_file("mod.rs")
         .compile(
             &[
@@ -67,6 +95,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 "proto/golem/worker/invoke_result.proto",
                 "proto/golem/worker/log_event.proto",
                 "proto/golem/worker/promise_id.proto",
+                "proto/golem/worker/promise_metadata.proto",
                 "proto/golem/worker/worker_execution_error.proto",
                 "proto/golem/worker/worker_error.proto",
                 "proto/golem/worker/worker_id.proto",
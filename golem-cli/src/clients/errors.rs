@@ -13,7 +13,8 @@
 // limitations under the License.
 
 use golem_client::api::{
-    ApiDefinitionError, ApiDeploymentError, ComponentError, HealthCheckError, WorkerError,
+    ApiDefinitionError, ApiDeploymentError, ComponentError, HealthCheckError, KeyValueError,
+    WorkerError,
 };
 use golem_client::model::{
     GolemError, GolemErrorComponentDownloadFailed, GolemErrorComponentParseFailed,
@@ -56,6 +57,19 @@ impl ResponseContentErrorMapper for WorkerError {
     }
 }
 
+impl ResponseContentErrorMapper for KeyValueError {
+    fn map(self) -> String {
+        match self {
+            KeyValueError::Error400(errors) => errors.errors.iter().join(", "),
+            KeyValueError::Error401(error) => error.error,
+            KeyValueError::Error403(error) => error.error,
+            KeyValueError::Error404(error) => error.error,
+            KeyValueError::Error409(error) => error.error,
+            KeyValueError::Error500(error) => display_golem_error(error.golem_error),
+        }
+    }
+}
+
 impl ResponseContentErrorMapper for HealthCheckError {
     fn map(self) -> String {
         "Invalid request".to_string()
@@ -0,0 +1,119 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use golem_client::model::{KeyValueBuckets, KeyValueEntry, KeyValueKeys};
+use tracing::info;
+
+use crate::model::GolemError;
+
+#[async_trait]
+pub trait KeyValueClient {
+    async fn buckets(&self, account_id: &str) -> Result<KeyValueBuckets, GolemError>;
+    async fn keys(
+        &self,
+        account_id: &str,
+        bucket: &str,
+        prefix: Option<String>,
+        cursor: Option<String>,
+        count: Option<u64>,
+    ) -> Result<KeyValueKeys, GolemError>;
+    async fn get(
+        &self,
+        account_id: &str,
+        bucket: &str,
+        key: &str,
+    ) -> Result<KeyValueEntry, GolemError>;
+    async fn set(
+        &self,
+        account_id: &str,
+        bucket: &str,
+        key: &str,
+        value: Vec<u8>,
+    ) -> Result<(), GolemError>;
+    async fn delete(&self, account_id: &str, bucket: &str, key: &str) -> Result<(), GolemError>;
+}
+
+#[derive(Clone)]
+pub struct KeyValueClientLive<C: golem_client::api::KeyValueClient + Sync + Send> {
+    pub client: C,
+}
+
+#[async_trait]
+impl<C: golem_client::api::KeyValueClient + Sync + Send> KeyValueClient for KeyValueClientLive<C> {
+    async fn buckets(&self, account_id: &str) -> Result<KeyValueBuckets, GolemError> {
+        info!("Getting key-value buckets of account {account_id}");
+
+        Ok(self.client.get_key_value_buckets(account_id).await?)
+    }
+
+    async fn keys(
+        &self,
+        account_id: &str,
+        bucket: &str,
+        prefix: Option<String>,
+        cursor: Option<String>,
+        count: Option<u64>,
+    ) -> Result<KeyValueKeys, GolemError> {
+        info!("Getting keys of key-value bucket {account_id}/{bucket}");
+
+        Ok(self
+            .client
+            .get_key_value_keys(
+                account_id,
+                bucket,
+                prefix.as_deref(),
+                cursor.as_deref(),
+                count,
+            )
+            .await?)
+    }
+
+    async fn get(
+        &self,
+        account_id: &str,
+        bucket: &str,
+        key: &str,
+    ) -> Result<KeyValueEntry, GolemError> {
+        info!("Getting key-value entry {account_id}/{bucket}/{key}");
+
+        Ok(self.client.get_key_value(account_id, bucket, key).await?)
+    }
+
+    async fn set(
+        &self,
+        account_id: &str,
+        bucket: &str,
+        key: &str,
+        value: Vec<u8>,
+    ) -> Result<(), GolemError> {
+        info!("Setting key-value entry {account_id}/{bucket}/{key}");
+
+        let _ = self
+            .client
+            .set_key_value(account_id, bucket, key, &KeyValueEntry { value })
+            .await?;
+        Ok(())
+    }
+
+    async fn delete(&self, account_id: &str, bucket: &str, key: &str) -> Result<(), GolemError> {
+        info!("Deleting key-value entry {account_id}/{bucket}/{key}");
+
+        let _ = self
+            .client
+            .delete_key_value(account_id, bucket, key)
+            .await?;
+        Ok(())
+    }
+}
@@ -12,13 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt::{Display, Formatter};
 use std::time::Duration;
 
 use async_trait::async_trait;
 use futures_util::{future, pin_mut, SinkExt, StreamExt};
 use golem_client::model::{
     CallingConvention, InvokeParameters, InvokeResult, UpdateWorkerRequest, WorkerCreationRequest,
-    WorkerFilter, WorkerId, WorkerMetadata, WorkersMetadataRequest, WorkersMetadataResponse,
+    WorkerFilter, WorkerId, WorkerMetadata, WorkerPromises, WorkersMetadataRequest,
+    WorkersMetadataResponse,
 };
 use golem_client::Context;
 use native_tls::TlsConnector;
@@ -29,7 +31,7 @@ use tokio_tungstenite::tungstenite::protocol::Message;
 use tokio_tungstenite::{connect_async_tls_with_config, Connector};
 use tracing::{debug, info};
 
-use crate::model::{ComponentId, GolemError, IdempotencyKey, WorkerName, WorkerUpdateMode};
+use crate::model::{ComponentId, Format, GolemError, IdempotencyKey, WorkerName, WorkerUpdateMode};
 
 #[async_trait]
 pub trait WorkerClient {
@@ -76,6 +78,11 @@ pub trait WorkerClient {
         name: WorkerName,
         component_id: ComponentId,
     ) -> Result<WorkerMetadata, GolemError>;
+    async fn get_promises(
+        &self,
+        name: WorkerName,
+        component_id: ComponentId,
+    ) -> Result<WorkerPromises, GolemError>;
     async fn find_metadata(
         &self,
         component_id: ComponentId,
@@ -92,7 +99,14 @@ pub trait WorkerClient {
         count: Option<u64>,
         precise: Option<bool>,
     ) -> Result<WorkersMetadataResponse, GolemError>;
-    async fn connect(&self, name: WorkerName, component_id: ComponentId) -> Result<(), GolemError>;
+    async fn connect(
+        &self,
+        name: WorkerName,
+        component_id: ComponentId,
+        after: Option<u64>,
+        since: Option<String>,
+        format: Format,
+    ) -> Result<(), GolemError>;
 
     async fn update(
         &self,
@@ -242,6 +256,19 @@ impl<C: golem_client::api::WorkerClient + Sync + Send> WorkerClient for WorkerCl
             .await?)
     }
 
+    async fn get_promises(
+        &self,
+        name: WorkerName,
+        component_id: ComponentId,
+    ) -> Result<WorkerPromises, GolemError> {
+        info!("Getting promises of worker {}/{}", component_id.0, name.0);
+
+        Ok(self
+            .client
+            .get_worker_promises(&component_id.0, &name.0)
+            .await?)
+    }
+
     async fn find_metadata(
         &self,
         component_id: ComponentId,
@@ -295,7 +322,14 @@ impl<C: golem_client::api::WorkerClient + Sync + Send> WorkerClient for WorkerCl
             .await?)
     }
 
-    async fn connect(&self, name: WorkerName, component_id: ComponentId) -> Result<(), GolemError> {
+    async fn connect(
+        &self,
+        name: WorkerName,
+        component_id: ComponentId,
+        after: Option<u64>,
+        since: Option<String>,
+        format: Format,
+    ) -> Result<(), GolemError> {
         let mut url = self.context.base_url.clone();
 
         let ws_schema = if url.scheme() == "http" { "ws" } else { "wss" };
@@ -312,6 +346,14 @@ impl<C: golem_client::api::WorkerClient + Sync + Send> WorkerClient for WorkerCl
             .push(&name.0)
             .push("connect");
 
+        if let Some(after) = after {
+            url.query_pairs_mut()
+                .append_pair("after", &after.to_string());
+        }
+        if let Some(since) = since {
+            url.query_pairs_mut().append_pair("since", &since);
+        }
+
         let mut request = url
             .into_client_request()
             .map_err(|e| GolemError(format!("Can't create request: {e}")))?;
@@ -381,15 +423,22 @@ impl<C: golem_client::api::WorkerClient + Sync + Send> WorkerClient for WorkerCl
                 }
                 Ok(message) => {
                     let instance_connect_msg = match message {
-                        Message::Text(str) => {
-                            let parsed: serde_json::Result<InstanceConnectMessage> =
-                                serde_json::from_str(&str);
-                            Some(parsed.unwrap()) // TODO: error handling
-                        }
+                        Message::Text(str) => match serde_json::from_str::<serde_json::Value>(&str)
+                        {
+                            Ok(json) => Some(json),
+                            Err(error) => {
+                                println!("Error parsing message: {}", error);
+                                None
+                            }
+                        },
                         Message::Binary(data) => {
-                            let parsed: serde_json::Result<InstanceConnectMessage> =
-                                serde_json::from_slice(&data);
-                            Some(parsed.unwrap()) // TODO: error handling
+                            match serde_json::from_slice::<serde_json::Value>(&data) {
+                                Ok(json) => Some(json),
+                                Err(error) => {
+                                    println!("Error parsing message: {}", error);
+                                    None
+                                }
+                            }
                         }
                         Message::Ping(_) => {
                             debug!("Ignore ping");
@@ -418,24 +467,17 @@ impl<C: golem_client::api::WorkerClient + Sync + Send> WorkerClient for WorkerCl
 
                     match instance_connect_msg {
                         None => {}
-                        Some(msg) => match msg.event {
-                            WorkerEvent::Stdout(StdOutLog { message }) => {
-                                print!("{message}")
+                        Some(json) => match format {
+                            Format::Json => println!("{json}"),
+                            Format::Yaml => {
+                                println!("---\n{}", serde_yaml::to_string(&json).unwrap())
                             }
-                            WorkerEvent::Stderr(StdErrLog { message }) => {
-                                print!("{message}")
+                            Format::Text => {
+                                match serde_json::from_value::<InstanceConnectMessage>(json) {
+                                    Ok(message) => print_text_event(message.event),
+                                    Err(error) => println!("Error parsing event: {}", error),
+                                }
                             }
-                            WorkerEvent::Log(Log {
-                                level,
-                                context,
-                                message,
-                            }) => match level {
-                                0 => tracing::trace!(message, context = context),
-                                1 => tracing::debug!(message, context = context),
-                                2 => tracing::info!(message, context = context),
-                                3 => tracing::warn!(message, context = context),
-                                _ => tracing::error!(message, context = context),
-                            },
                         },
                     }
                 }
@@ -487,6 +529,10 @@ enum WorkerEvent {
     Stdout(StdOutLog),
     Stderr(StdErrLog),
     Log(Log),
+    InvocationStarted(InvocationStarted),
+    InvocationFinished(InvocationFinished),
+    InvocationFailed(InvocationFailed),
+    StatusChanged(WorkerStatusChanged),
 }
 
 #[derive(Deserialize, Debug)]
@@ -504,4 +550,234 @@ struct Log {
     pub level: i32,
     pub context: String,
     pub message: String,
+    #[serde(default)]
+    pub attributes: Vec<LogAttribute>,
+}
+
+#[derive(Deserialize, Debug)]
+struct InvocationStarted {
+    pub function: String,
+    pub idempotency_key: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct InvocationFinished {
+    pub function: String,
+    pub idempotency_key: String,
+    pub duration_millis: u64,
+    pub consumed_fuel: i64,
+}
+
+#[derive(Deserialize, Debug)]
+struct InvocationFailed {
+    pub function: String,
+    pub idempotency_key: String,
+    pub error: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct WorkerStatusChanged {
+    pub status: i32,
+}
+
+#[derive(Deserialize, Debug)]
+struct LogAttribute {
+    pub key: String,
+    pub value: Option<LogAttributeValue>,
+}
+
+#[derive(Deserialize, Debug)]
+struct LogAttributeValue {
+    pub value: Option<LogAttributeValueKind>,
+}
+
+#[derive(Deserialize, Debug)]
+enum LogAttributeValueKind {
+    StringValue(String),
+    IntValue(i64),
+    FloatValue(f64),
+    BoolValue(bool),
+}
+
+impl Display for LogAttributeValueKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LogAttributeValueKind::StringValue(value) => write!(f, "{value:?}"),
+            LogAttributeValueKind::IntValue(value) => write!(f, "{value}"),
+            LogAttributeValueKind::FloatValue(value) => write!(f, "{value}"),
+            LogAttributeValueKind::BoolValue(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+fn print_text_event(event: WorkerEvent) {
+    match event {
+        WorkerEvent::Stdout(StdOutLog { message }) => {
+            print!("{message}")
+        }
+        WorkerEvent::Stderr(StdErrLog { message }) => {
+            print!("{message}")
+        }
+        WorkerEvent::Log(Log {
+            level,
+            context,
+            message,
+            attributes,
+        }) => {
+            let message = render_log_message(message, &attributes);
+            match level {
+                0 => tracing::trace!(message, context = context),
+                1 => tracing::debug!(message, context = context),
+                2 => tracing::info!(message, context = context),
+                3 => tracing::warn!(message, context = context),
+                _ => tracing::error!(message, context = context),
+            }
+        }
+        WorkerEvent::InvocationStarted(InvocationStarted {
+            function,
+            idempotency_key,
+        }) => {
+            tracing::info!("Invocation {idempotency_key} of {function} started")
+        }
+        WorkerEvent::InvocationFinished(InvocationFinished {
+            function,
+            idempotency_key,
+            duration_millis,
+            consumed_fuel,
+        }) => {
+            tracing::info!(
+                "Invocation {idempotency_key} of {function} finished in {duration_millis}ms, consuming {consumed_fuel} fuel"
+            )
+        }
+        WorkerEvent::InvocationFailed(InvocationFailed {
+            function,
+            idempotency_key,
+            error,
+        }) => {
+            tracing::warn!("Invocation {idempotency_key} of {function} failed: {error}")
+        }
+        WorkerEvent::StatusChanged(WorkerStatusChanged { status }) => {
+            tracing::info!("Worker status changed to {}", worker_status_name(status))
+        }
+    }
+}
+
+fn worker_status_name(status: i32) -> &'static str {
+    golem_api_grpc::proto::golem::worker::WorkerStatus::try_from(status)
+        .map(|status| status.as_str_name())
+        .unwrap_or("UNKNOWN")
+}
+
+/// Appends the structured attributes of a log event to its message in `key=value` form
+fn render_log_message(message: String, attributes: &[LogAttribute]) -> String {
+    let rendered = attributes
+        .iter()
+        .filter_map(|attribute| {
+            attribute
+                .value
+                .as_ref()
+                .and_then(|value| value.value.as_ref())
+                .map(|value| format!("{}={value}", attribute.key))
+        })
+        .collect::<Vec<_>>();
+
+    if rendered.is_empty() {
+        message
+    } else {
+        format!("{message} {}", rendered.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use golem_api_grpc::proto::golem::worker::{
+        log_event, InvocationFailed, InvocationFinished, InvocationStarted, LogEvent, WorkerStatus,
+        WorkerStatusChanged,
+    };
+
+    use crate::clients::worker::{worker_status_name, InstanceConnectMessage, WorkerEvent};
+
+    fn roundtrip(event: log_event::Event) -> WorkerEvent {
+        let json = serde_json::to_string(&LogEvent {
+            event: Some(event),
+            ..Default::default()
+        })
+        .unwrap();
+        serde_json::from_str::<InstanceConnectMessage>(&json)
+            .unwrap()
+            .event
+    }
+
+    #[test]
+    fn invocation_started_event() {
+        let event = roundtrip(log_event::Event::InvocationStarted(InvocationStarted {
+            function: "golem:it/api.{run}".to_string(),
+            idempotency_key: "key1".to_string(),
+        }));
+
+        match event {
+            WorkerEvent::InvocationStarted(started) => {
+                assert_eq!(started.function, "golem:it/api.{run}");
+                assert_eq!(started.idempotency_key, "key1");
+            }
+            other => panic!("Unexpected event: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn invocation_finished_event() {
+        let event = roundtrip(log_event::Event::InvocationFinished(InvocationFinished {
+            function: "golem:it/api.{run}".to_string(),
+            idempotency_key: "key2".to_string(),
+            duration_millis: 123,
+            consumed_fuel: 4567,
+        }));
+
+        match event {
+            WorkerEvent::InvocationFinished(finished) => {
+                assert_eq!(finished.function, "golem:it/api.{run}");
+                assert_eq!(finished.idempotency_key, "key2");
+                assert_eq!(finished.duration_millis, 123);
+                assert_eq!(finished.consumed_fuel, 4567);
+            }
+            other => panic!("Unexpected event: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn invocation_failed_event() {
+        let event = roundtrip(log_event::Event::InvocationFailed(InvocationFailed {
+            function: "golem:it/api.{run}".to_string(),
+            idempotency_key: "key3".to_string(),
+            error: "trap".to_string(),
+        }));
+
+        match event {
+            WorkerEvent::InvocationFailed(failed) => {
+                assert_eq!(failed.function, "golem:it/api.{run}");
+                assert_eq!(failed.idempotency_key, "key3");
+                assert_eq!(failed.error, "trap");
+            }
+            other => panic!("Unexpected event: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn status_changed_event() {
+        let event = roundtrip(log_event::Event::StatusChanged(WorkerStatusChanged {
+            status: WorkerStatus::Suspended.into(),
+        }));
+
+        match event {
+            WorkerEvent::StatusChanged(changed) => {
+                assert_eq!(worker_status_name(changed.status), "SUSPENDED")
+            }
+            other => panic!("Unexpected event: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn unknown_worker_status() {
+        assert_eq!(worker_status_name(100), "UNKNOWN");
+    }
 }
@@ -0,0 +1,172 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::clients::key_value::KeyValueClient;
+use crate::model::{GolemError, GolemResult};
+use async_trait::async_trait;
+use clap::Subcommand;
+
+#[derive(Subcommand, Debug)]
+#[command()]
+pub enum KeyValueSubcommand {
+    /// Lists the key-value buckets of an account
+    #[command()]
+    Buckets {
+        /// The account owning the buckets
+        #[arg(short, long, default_value = "-1")]
+        account_id: String,
+    },
+
+    /// Lists the keys of a key-value bucket
+    #[command()]
+    Keys {
+        /// The account owning the bucket
+        #[arg(short, long, default_value = "-1")]
+        account_id: String,
+
+        /// Name of the bucket
+        #[arg(short, long)]
+        bucket: String,
+
+        /// Only list the keys starting with this prefix
+        #[arg(short, long)]
+        prefix: Option<String>,
+
+        /// Position where the listing continues, as returned by the previous call
+        #[arg(short = 'P', long)]
+        cursor: Option<String>,
+
+        /// Maximum number of keys to list
+        #[arg(short = 'n', long)]
+        count: Option<u64>,
+    },
+
+    /// Gets the value stored for a key
+    #[command()]
+    Get {
+        /// The account owning the bucket
+        #[arg(short, long, default_value = "-1")]
+        account_id: String,
+
+        /// Name of the bucket
+        #[arg(short, long)]
+        bucket: String,
+
+        /// The key to get the value of
+        #[arg(short, long)]
+        key: String,
+    },
+
+    /// Sets the value stored for a key
+    #[command()]
+    Set {
+        /// The account owning the bucket
+        #[arg(short, long, default_value = "-1")]
+        account_id: String,
+
+        /// Name of the bucket
+        #[arg(short, long)]
+        bucket: String,
+
+        /// The key to set the value of
+        #[arg(short, long)]
+        key: String,
+
+        /// The new value, stored as UTF-8 bytes
+        #[arg(value_name = "value")]
+        value: String,
+    },
+
+    /// Deletes a key and its value
+    #[command()]
+    Delete {
+        /// The account owning the bucket
+        #[arg(short, long, default_value = "-1")]
+        account_id: String,
+
+        /// Name of the bucket
+        #[arg(short, long)]
+        bucket: String,
+
+        /// The key to delete
+        #[arg(short, long)]
+        key: String,
+    },
+}
+
+#[async_trait]
+pub trait KeyValueHandler {
+    async fn handle(&self, subcommand: KeyValueSubcommand) -> Result<GolemResult, GolemError>;
+}
+
+pub struct KeyValueHandlerLive<C: KeyValueClient + Send + Sync> {
+    pub client: C,
+}
+
+#[async_trait]
+impl<C: KeyValueClient + Send + Sync> KeyValueHandler for KeyValueHandlerLive<C> {
+    async fn handle(&self, subcommand: KeyValueSubcommand) -> Result<GolemResult, GolemError> {
+        match subcommand {
+            KeyValueSubcommand::Buckets { account_id } => {
+                let buckets = self.client.buckets(&account_id).await?;
+
+                Ok(GolemResult::Ok(Box::new(buckets)))
+            }
+            KeyValueSubcommand::Keys {
+                account_id,
+                bucket,
+                prefix,
+                cursor,
+                count,
+            } => {
+                let keys = self
+                    .client
+                    .keys(&account_id, &bucket, prefix, cursor, count)
+                    .await?;
+
+                Ok(GolemResult::Ok(Box::new(keys)))
+            }
+            KeyValueSubcommand::Get {
+                account_id,
+                bucket,
+                key,
+            } => {
+                let entry = self.client.get(&account_id, &bucket, &key).await?;
+
+                Ok(GolemResult::Ok(Box::new(entry)))
+            }
+            KeyValueSubcommand::Set {
+                account_id,
+                bucket,
+                key,
+                value,
+            } => {
+                self.client
+                    .set(&account_id, &bucket, &key, value.into_bytes())
+                    .await?;
+
+                Ok(GolemResult::Str("Set".to_string()))
+            }
+            KeyValueSubcommand::Delete {
+                account_id,
+                bucket,
+                key,
+            } => {
+                self.client.delete(&account_id, &bucket, &key).await?;
+
+                Ok(GolemResult::Str("Deleted".to_string()))
+            }
+        }
+    }
+}
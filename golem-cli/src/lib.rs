@@ -17,6 +17,7 @@ pub mod api_deployment;
 pub mod clients;
 pub mod component;
 pub mod examples;
+pub mod key_value;
 pub mod model;
 pub mod version;
 pub mod worker;
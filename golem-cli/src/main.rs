@@ -34,9 +34,11 @@ use golem_cli::clients::api_definition::ApiDefinitionClientLive;
 use golem_cli::clients::api_deployment::ApiDeploymentClientLive;
 use golem_cli::clients::component::ComponentClientLive;
 use golem_cli::clients::health_check::HealthCheckClientLive;
+use golem_cli::clients::key_value::KeyValueClientLive;
 use golem_cli::clients::worker::WorkerClientLive;
 use golem_cli::component::{ComponentHandler, ComponentHandlerLive, ComponentSubCommand};
 use golem_cli::examples;
+use golem_cli::key_value::{KeyValueHandler, KeyValueHandlerLive, KeyValueSubcommand};
 use golem_cli::version::{VersionHandler, VersionHandlerLive};
 use golem_cli::worker::{WorkerHandler, WorkerHandlerLive, WorkerSubcommand};
 
@@ -105,6 +107,13 @@ enum Command {
         #[command(subcommand)]
         subcommand: ApiDeploymentSubcommand,
     },
+
+    /// Inspect and edit the key-value buckets of workers
+    #[command()]
+    Kv {
+        #[command(subcommand)]
+        subcommand: KeyValueSubcommand,
+    },
 }
 
 #[derive(Parser, Debug)]
@@ -234,6 +243,16 @@ async fn async_main(cmd: GolemCommand) -> Result<(), Box<dyn std::error::Error>>
         client: api_deployment_client,
     };
 
+    let key_value_client = KeyValueClientLive {
+        client: golem_client::api::KeyValueClientLive {
+            context: worker_context.clone(),
+        },
+    };
+
+    let key_value_srv = KeyValueHandlerLive {
+        client: key_value_client,
+    };
+
     let health_check_client_for_component = HealthCheckClientLive {
         client: golem_client::api::HealthCheckClientLive {
             context: component_context.clone(),
@@ -298,6 +317,7 @@ async fn async_main(cmd: GolemCommand) -> Result<(), Box<dyn std::error::Error>>
         },
         Command::ApiDefinition { subcommand } => api_definition_srv.handle(subcommand).await,
         Command::ApiDeployment { subcommand } => api_deployment_srv.handle(subcommand).await,
+        Command::Kv { subcommand } => key_value_srv.handle(subcommand).await,
     };
 
     match res {
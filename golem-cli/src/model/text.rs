@@ -3,7 +3,8 @@ use crate::model::invoke_result_view::InvokeResultView;
 use crate::model::{ExampleDescription, IdempotencyKey};
 use cli_table::{format::Justify, print_stdout, Table, WithTitle};
 use golem_client::model::{
-    ApiDeployment, HttpApiDefinition, Route, WorkerId, WorkerMetadata, WorkersMetadataResponse,
+    ApiDeployment, HttpApiDefinition, KeyValueBuckets, KeyValueEntry, KeyValueKeys,
+    PromiseMetadata, Route, WorkerId, WorkerMetadata, WorkerPromises, WorkersMetadataResponse,
 };
 use golem_examples::model::{ExampleName, GuestLanguage, GuestLanguageTier};
 use indoc::{eprintdoc, printdoc};
@@ -325,7 +326,53 @@ impl TextFormat for WorkerMetadata {
             self.args.join(", "),
             self.env.iter().map(|(k, v)| format!("{k}={v}")).join(", "),
             self.retry_count,
+        );
+
+        if let Some(oplog_idx) = self.awaited_promise_oplog_idx {
+            println!("Awaiting promise {oplog_idx}.");
+        }
+    }
+}
+
+#[derive(Table)]
+struct PromiseMetadataView {
+    #[table(title = "Oplog index", justify = "Justify::Right")]
+    pub oplog_idx: u64,
+    #[table(title = "Status")]
+    pub status: String,
+    #[table(title = "Completed at")]
+    pub completed_at: String,
+    #[table(title = "Payload size", justify = "Justify::Right")]
+    pub payload_size: String,
+}
+
+impl From<&PromiseMetadata> for PromiseMetadataView {
+    fn from(value: &PromiseMetadata) -> Self {
+        Self {
+            oplog_idx: value.oplog_idx,
+            status: value.status.to_string(),
+            completed_at: value
+                .completed_at
+                .map(|completed_at| completed_at.to_string())
+                .unwrap_or_default(),
+            payload_size: value
+                .payload_size
+                .map(|size| size.to_string())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl TextFormat for WorkerPromises {
+    fn print(&self) {
+        print_stdout(
+            self.promises
+                .iter()
+                .map(PromiseMetadataView::from)
+                .collect::<Vec<_>>()
+                .with_title(),
         )
+        .unwrap();
     }
 }
 
@@ -420,3 +467,42 @@ impl TextFormat for Vec<ApiDeployment> {
         .unwrap()
     }
 }
+
+impl TextFormat for KeyValueBuckets {
+    fn print(&self) {
+        if self.buckets.is_empty() {
+            println!("No key-value buckets found.")
+        } else {
+            for bucket in &self.buckets {
+                println!("{bucket}")
+            }
+        }
+    }
+}
+
+impl TextFormat for KeyValueKeys {
+    fn print(&self) {
+        for key in &self.keys {
+            println!("{key}")
+        }
+
+        if let Some(cursor) = &self.cursor {
+            printdoc!(
+                "
+                There are more keys to display.
+                To fetch next page use cursor {cursor} this way:
+                kv keys --cursor {cursor} ...
+                "
+            )
+        }
+    }
+}
+
+impl TextFormat for KeyValueEntry {
+    fn print(&self) {
+        match std::str::from_utf8(&self.value) {
+            Ok(value) => println!("{value}"),
+            Err(_) => println!("Binary value of {} bytes.", self.value.len()),
+        }
+    }
+}
@@ -149,6 +149,14 @@ pub enum WorkerSubcommand {
         /// Name of the worker
         #[arg(short, long)]
         worker_name: WorkerName,
+
+        /// Resume streaming after the event with the given sequence number
+        #[arg(long, conflicts_with = "since")]
+        after: Option<u64>,
+
+        /// Replay the retained events emitted at or after the given RFC 3339 timestamp
+        #[arg(long)]
+        since: Option<String>,
     },
 
     /// Interrupts a running worker
@@ -200,6 +208,17 @@ pub enum WorkerSubcommand {
         #[arg(short, long)]
         worker_name: WorkerName,
     },
+    /// Lists the promises created by a worker
+    #[command()]
+    Promises {
+        /// The Golem component the worker belongs to
+        #[command(flatten)]
+        component_id_or_name: ComponentIdOrName,
+
+        /// Name of the worker
+        #[arg(short, long)]
+        worker_name: WorkerName,
+    },
     /// Retrieves metadata about an existing workers in a component
     #[command()]
     List {
@@ -579,10 +598,14 @@ impl<'r, C: WorkerClient + Send + Sync, R: ComponentHandler + Send + Sync> Worke
             WorkerSubcommand::Connect {
                 component_id_or_name,
                 worker_name,
+                after,
+                since,
             } => {
                 let component_id = self.components.resolve_id(component_id_or_name).await?;
 
-                self.client.connect(worker_name, component_id).await?;
+                self.client
+                    .connect(worker_name, component_id, after, since, format)
+                    .await?;
 
                 Err(GolemError("Unexpected connection closure".to_string()))
             }
@@ -628,6 +651,16 @@ impl<'r, C: WorkerClient + Send + Sync, R: ComponentHandler + Send + Sync> Worke
 
                 Ok(GolemResult::Ok(Box::new(response)))
             }
+            WorkerSubcommand::Promises {
+                component_id_or_name,
+                worker_name,
+            } => {
+                let component_id = self.components.resolve_id(component_id_or_name).await?;
+
+                let response = self.client.get_promises(worker_name, component_id).await?;
+
+                Ok(GolemResult::Ok(Box::new(response)))
+            }
             WorkerSubcommand::List {
                 component_id_or_name,
                 filter,
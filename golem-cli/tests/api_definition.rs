@@ -1,7 +1,9 @@
 use crate::cli::{Cli, CliLive};
 use crate::worker::make_component_from_file;
 use golem_cli::model::component::ComponentView;
-use golem_client::model::{GolemWorkerBinding, HttpApiDefinition, MethodPattern, Route};
+use golem_client::model::{
+    BindingType, GolemWorkerBinding, HttpApiDefinition, MethodPattern, Route,
+};
 use golem_test_framework::config::TestDependencies;
 use libtest_mimic::{Failed, Trial};
 use serde_json::json;
@@ -112,12 +114,14 @@ fn golem_def_with_response(
             method: MethodPattern::Get,
             path: "/{user-id}/get-cart-contents".to_string(),
             binding: GolemWorkerBinding {
+                binding_type: Some(BindingType::WitWorker),
                 component: Uuid::parse_str(component_id).unwrap(),
                 worker_id: "worker-${request.path.user-id}".to_string(),
                 function_name: "golem:it/api/get-cart-contents".to_string(),
                 function_params: vec![],
                 idempotency_key: None,
                 response,
+                promise: None,
             },
         }],
     }
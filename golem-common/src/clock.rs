@@ -0,0 +1,146 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::{Duration, SystemTime};
+
+use tokio::sync::watch;
+
+/// Source of the current time for services that schedule or measure work.
+///
+/// Production code uses `SystemClock`; tests can inject a `VirtualClock` to move time
+/// forward deterministically.
+pub trait Clock {
+    fn now(&self) -> SystemTime;
+
+    /// Gets notified whenever the clock is moved explicitly, so services waiting for a point in
+    /// time can react right away. `None` for clocks which only move by themselves.
+    fn changes(&self) -> Option<watch::Receiver<SystemTime>> {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SystemClock;
+
+impl SystemClock {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// A clock that is frozen at a given point in time and only moves when explicitly advanced.
+#[derive(Debug)]
+pub struct VirtualClock {
+    now: watch::Sender<SystemTime>,
+}
+
+impl VirtualClock {
+    /// Creates a virtual clock frozen at the current system time
+    pub fn new() -> Self {
+        Self::starting_at(SystemTime::now())
+    }
+
+    pub fn starting_at(time: SystemTime) -> Self {
+        let (now, _) = watch::channel(time);
+        Self { now }
+    }
+
+    /// Moves the clock forward by the given duration and returns the new current time
+    pub fn advance(&self, duration: Duration) -> SystemTime {
+        let mut result = SystemTime::UNIX_EPOCH;
+        self.now.send_modify(|now| {
+            *now += duration;
+            result = *now;
+        });
+        result
+    }
+
+    /// Sets the clock to the given time. Moving the clock backwards is not allowed, as the
+    /// monotonic clocks exposed to workers are derived from it.
+    pub fn set(&self, time: SystemTime) {
+        self.now.send_if_modified(|now| {
+            if time > *now {
+                *now = time;
+                true
+            } else {
+                false
+            }
+        });
+    }
+}
+
+impl Default for VirtualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for VirtualClock {
+    fn now(&self) -> SystemTime {
+        *self.now.borrow()
+    }
+
+    fn changes(&self) -> Option<watch::Receiver<SystemTime>> {
+        Some(self.now.subscribe())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use crate::clock::{Clock, VirtualClock};
+
+    #[test]
+    fn virtual_clock_only_moves_when_advanced() {
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let clock = VirtualClock::starting_at(start);
+        assert_eq!(clock.now(), start);
+        assert_eq!(clock.now(), start);
+
+        let after = clock.advance(Duration::from_secs(3600));
+        assert_eq!(after, start + Duration::from_secs(3600));
+        assert_eq!(clock.now(), after);
+    }
+
+    #[test]
+    fn virtual_clock_does_not_move_backwards() {
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let clock = VirtualClock::starting_at(start);
+        clock.set(start - Duration::from_secs(10));
+        assert_eq!(clock.now(), start);
+        clock.set(start + Duration::from_secs(10));
+        assert_eq!(clock.now(), start + Duration::from_secs(10));
+    }
+
+    #[tokio::test]
+    async fn virtual_clock_notifies_about_changes() {
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let clock = VirtualClock::starting_at(start);
+        let mut changes = clock.changes().unwrap();
+
+        clock.set(start - Duration::from_secs(10));
+        assert!(!changes.has_changed().unwrap());
+
+        clock.advance(Duration::from_secs(60));
+        changes.changed().await.unwrap();
+        assert_eq!(*changes.borrow(), start + Duration::from_secs(60));
+    }
+}
@@ -57,6 +57,23 @@ impl Default for RedisConfig {
     }
 }
 
+/// Exporting the services' tracing spans to an OpenTelemetry collector. Trace context is only
+/// propagated between the services if they have the exporter enabled.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OtlpConfig {
+    pub enabled: bool,
+    pub endpoint: String,
+}
+
+impl Default for OtlpConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            endpoint: "http://localhost:4317".to_string(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
 pub struct RetryConfig {
     pub max_attempts: u32,
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 pub mod cache;
+pub mod clock;
 pub mod config;
 pub mod metrics;
 pub mod model;
@@ -20,3 +21,4 @@ pub mod newtype;
 pub mod redis;
 pub mod retries;
 pub mod serialization;
+pub mod telemetry;
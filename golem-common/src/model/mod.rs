@@ -57,6 +57,12 @@ impl Timestamp {
     pub fn now_utc() -> Timestamp {
         Timestamp(iso8601_timestamp::Timestamp::now_utc())
     }
+
+    pub fn to_millis(&self) -> u64 {
+        self.0
+            .duration_since(iso8601_timestamp::Timestamp::UNIX_EPOCH)
+            .whole_milliseconds() as u64
+    }
 }
 
 impl Display for Timestamp {
@@ -341,11 +347,34 @@ impl Display for PromiseId {
 pub struct ScheduleId {
     pub timestamp: i64,
     pub promise_id: PromiseId,
+    pub action: ScheduledAction,
 }
 
 impl Display for ScheduleId {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}@{}", self.promise_id, self.timestamp)
+        write!(
+            f,
+            "{}@{} ({})",
+            self.promise_id, self.timestamp, self.action
+        )
+    }
+}
+
+/// What happens to a promise when its schedule is due
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Encode, Decode)]
+pub enum ScheduledAction {
+    /// Completes the promise with an empty result, waking up a sleeping worker
+    CompletePromise,
+    /// Fails the promise with a timeout if it is still pending
+    TimeoutPromise,
+}
+
+impl Display for ScheduledAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScheduledAction::CompletePromise => write!(f, "complete"),
+            ScheduledAction::TimeoutPromise => write!(f, "timeout"),
+        }
     }
 }
 
@@ -493,6 +522,14 @@ impl IdempotencyKey {
     pub fn fresh() -> Self {
         Self::from_uuid(Uuid::new_v4())
     }
+
+    /// Derives the key of the invocation of one worker targeted by an operation with this key,
+    /// so retrying the whole operation with the same key does not invoke any of the workers twice
+    pub fn derived(&self, worker_id: &WorkerId) -> Self {
+        Self {
+            value: format!("{}/{}", self.value, worker_id),
+        }
+    }
 }
 
 impl From<golem_api_grpc::proto::golem::worker::IdempotencyKey> for IdempotencyKey {
@@ -777,6 +814,64 @@ impl From<WorkerStatus> for i32 {
     }
 }
 
+/// Represents the observable state of a promise created by a worker
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Encode, Decode, Enum)]
+pub enum PromiseStatus {
+    /// The promise has not been completed yet
+    Pending,
+    /// The promise was completed with a result
+    Completed,
+    /// The promise was completed with a failure
+    Failed,
+    /// The deadline of the promise passed before it was completed
+    TimedOut,
+    /// The promise was deleted and its result is no longer available
+    Deleted,
+}
+
+impl From<PromiseStatus> for golem_api_grpc::proto::golem::worker::PromiseStatus {
+    fn from(value: PromiseStatus) -> Self {
+        match value {
+            PromiseStatus::Pending => golem_api_grpc::proto::golem::worker::PromiseStatus::Pending,
+            PromiseStatus::Completed => {
+                golem_api_grpc::proto::golem::worker::PromiseStatus::Completed
+            }
+            PromiseStatus::Failed => golem_api_grpc::proto::golem::worker::PromiseStatus::Failed,
+            PromiseStatus::TimedOut => {
+                golem_api_grpc::proto::golem::worker::PromiseStatus::TimedOut
+            }
+            PromiseStatus::Deleted => golem_api_grpc::proto::golem::worker::PromiseStatus::Deleted,
+        }
+    }
+}
+
+impl TryFrom<i32> for PromiseStatus {
+    type Error = String;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(PromiseStatus::Pending),
+            1 => Ok(PromiseStatus::Completed),
+            2 => Ok(PromiseStatus::Failed),
+            3 => Ok(PromiseStatus::TimedOut),
+            4 => Ok(PromiseStatus::Deleted),
+            _ => Err(format!("Unknown promise status: {}", value)),
+        }
+    }
+}
+
+impl From<PromiseStatus> for i32 {
+    fn from(value: PromiseStatus) -> Self {
+        match value {
+            PromiseStatus::Pending => 0,
+            PromiseStatus::Completed => 1,
+            PromiseStatus::Failed => 2,
+            PromiseStatus::TimedOut => 3,
+            PromiseStatus::Deleted => 4,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Encode, Decode)]
 pub enum WorkerInvocation {
     ExportedFunction {
@@ -807,6 +902,54 @@ pub struct TimestampedWorkerInvocation {
     pub invocation: WorkerInvocation,
 }
 
+/// Position in a worker's event stream to start tailing from
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WorkerEventCursor {
+    /// Events following the one with the given sequence number
+    AfterSequence(u64),
+    /// Events emitted at or after the given point in time
+    Since(Timestamp),
+}
+
+impl WorkerEventCursor {
+    pub fn includes(&self, sequence: u64, timestamp: Timestamp) -> bool {
+        match self {
+            WorkerEventCursor::AfterSequence(after) => sequence > *after,
+            WorkerEventCursor::Since(since) => timestamp >= *since,
+        }
+    }
+}
+
+impl From<WorkerEventCursor> for golem_api_grpc::proto::golem::worker::LogEventCursor {
+    fn from(value: WorkerEventCursor) -> Self {
+        use golem_api_grpc::proto::golem::worker::log_event_cursor::Cursor;
+
+        let cursor = match value {
+            WorkerEventCursor::AfterSequence(sequence) => Cursor::AfterSequence(sequence),
+            WorkerEventCursor::Since(timestamp) => Cursor::Since(timestamp.into()),
+        };
+        Self {
+            cursor: Some(cursor),
+        }
+    }
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::worker::LogEventCursor> for WorkerEventCursor {
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::worker::LogEventCursor,
+    ) -> Result<Self, Self::Error> {
+        use golem_api_grpc::proto::golem::worker::log_event_cursor::Cursor;
+
+        match value.cursor {
+            Some(Cursor::AfterSequence(sequence)) => Ok(WorkerEventCursor::AfterSequence(sequence)),
+            Some(Cursor::Since(timestamp)) => Ok(WorkerEventCursor::Since(timestamp.into())),
+            None => Err("Missing cursor".to_string()),
+        }
+    }
+}
+
 #[derive(
     Clone,
     Debug,
@@ -1692,8 +1835,9 @@ mod tests {
     use serde::{Deserialize, Serialize};
 
     use crate::model::{
-        parse_function_name, AccountId, ComponentId, FilterComparator, StringFilterComparator,
-        Timestamp, WorkerFilter, WorkerId, WorkerMetadata, WorkerStatus, WorkerStatusRecord,
+        parse_function_name, AccountId, ComponentId, FilterComparator, IdempotencyKey,
+        StringFilterComparator, Timestamp, WorkerFilter, WorkerId, WorkerMetadata, WorkerStatus,
+        WorkerStatusRecord,
     };
 
     #[test]
@@ -1707,6 +1851,27 @@ mod tests {
         assert_eq!(ts2, ts);
     }
 
+    #[test]
+    fn derived_idempotency_keys() {
+        let parent = IdempotencyKey::new("parent".to_string());
+        let component_id = ComponentId::new_v4();
+        let worker_id1 = WorkerId {
+            component_id: component_id.clone(),
+            worker_name: "worker1".to_string(),
+        };
+        let worker_id2 = WorkerId {
+            component_id,
+            worker_name: "worker2".to_string(),
+        };
+
+        assert_eq!(parent.derived(&worker_id1), parent.derived(&worker_id1));
+        assert_ne!(parent.derived(&worker_id1), parent.derived(&worker_id2));
+        assert_ne!(
+            parent.derived(&worker_id1),
+            IdempotencyKey::new("other".to_string()).derived(&worker_id1)
+        );
+    }
+
     #[test]
     fn parse_function_name_global() {
         let parsed = parse_function_name("run-example");
@@ -112,6 +112,35 @@ pub enum OplogEntry {
         target_version: ComponentVersion,
         details: Option<String>,
     },
+    /// Registers a compensation for a completed step of the atomic region starting at
+    /// `begin_index`. The compensation is invoked if the region is abandoned during recovery, or if
+    /// the worker fails finally before the corresponding `EndAtomicRegion` entry.
+    RegisterCompensation {
+        timestamp: Timestamp,
+        begin_index: OplogIndex,
+        compensation: Compensation,
+    },
+    /// Records the outcome of invoking a registered compensation. Only used to avoid running a
+    /// compensation twice, has no effect on execution.
+    CompensationCompleted {
+        timestamp: Timestamp,
+        idempotency_key: IdempotencyKey,
+        error: Option<String>,
+    },
+    /// The workers waiting through RPC for the invocation with `idempotency_key`, outermost caller
+    /// first. Only used to restore the call chain of the invocation on recovery.
+    InvocationCallChain {
+        timestamp: Timestamp,
+        idempotency_key: IdempotencyKey,
+        call_chain: Vec<WorkerId>,
+    },
+    /// The compensations of the atomic regions left open by an invocation which failed finally,
+    /// in registration order, recorded before running them. The ones without a later
+    /// `CompensationCompleted` entry are run when the worker is recovered.
+    PendingCompensations {
+        timestamp: Timestamp,
+        compensations: Vec<Compensation>,
+    },
 }
 
 impl OplogEntry {
@@ -275,6 +304,46 @@ impl OplogEntry {
         }
     }
 
+    pub fn register_compensation(
+        begin_index: OplogIndex,
+        compensation: Compensation,
+    ) -> OplogEntry {
+        OplogEntry::RegisterCompensation {
+            timestamp: Timestamp::now_utc(),
+            begin_index,
+            compensation,
+        }
+    }
+
+    pub fn compensation_completed(
+        idempotency_key: IdempotencyKey,
+        error: Option<String>,
+    ) -> OplogEntry {
+        OplogEntry::CompensationCompleted {
+            timestamp: Timestamp::now_utc(),
+            idempotency_key,
+            error,
+        }
+    }
+
+    pub fn invocation_call_chain(
+        idempotency_key: IdempotencyKey,
+        call_chain: Vec<WorkerId>,
+    ) -> OplogEntry {
+        OplogEntry::InvocationCallChain {
+            timestamp: Timestamp::now_utc(),
+            idempotency_key,
+            call_chain,
+        }
+    }
+
+    pub fn pending_compensations(compensations: Vec<Compensation>) -> OplogEntry {
+        OplogEntry::PendingCompensations {
+            timestamp: Timestamp::now_utc(),
+            compensations,
+        }
+    }
+
     pub fn is_end_atomic_region(&self, idx: OplogIndex) -> bool {
         matches!(self, OplogEntry::EndAtomicRegion { begin_index, .. } if *begin_index == idx)
     }
@@ -295,6 +364,9 @@ impl OplogEntry {
                 | OplogEntry::PendingUpdate { .. }
                 | OplogEntry::SuccessfulUpdate { .. }
                 | OplogEntry::FailedUpdate { .. }
+                | OplogEntry::CompensationCompleted { .. }
+                | OplogEntry::InvocationCallChain { .. }
+                | OplogEntry::PendingCompensations { .. }
         )
     }
 
@@ -336,7 +408,11 @@ impl OplogEntry {
             | OplogEntry::PendingWorkerInvocation { timestamp, .. }
             | OplogEntry::PendingUpdate { timestamp, .. }
             | OplogEntry::SuccessfulUpdate { timestamp, .. }
-            | OplogEntry::FailedUpdate { timestamp, .. } => *timestamp,
+            | OplogEntry::FailedUpdate { timestamp, .. }
+            | OplogEntry::RegisterCompensation { timestamp, .. }
+            | OplogEntry::CompensationCompleted { timestamp, .. }
+            | OplogEntry::InvocationCallChain { timestamp, .. }
+            | OplogEntry::PendingCompensations { timestamp, .. } => *timestamp,
         }
     }
 }
@@ -383,6 +459,16 @@ pub enum SnapshotSource {
     },
 }
 
+/// A worker function invoked to undo a step of an atomic region which did not complete
+#[derive(Clone, Debug, PartialEq, Encode, Decode)]
+pub struct Compensation {
+    /// Identifies the compensation, and is used as the idempotency key of its invocation
+    pub idempotency_key: IdempotencyKey,
+    pub worker_id: WorkerId,
+    pub function_name: String,
+    pub function_input: Vec<golem_wasm_rpc::Value>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
 pub enum WrappedFunctionType {
     ReadLocal,
@@ -410,10 +496,11 @@ impl Display for WorkerError {
 #[cfg(test)]
 mod tests {
     use golem_wasm_rpc::protobuf::{val, Val, ValResult};
+    use golem_wasm_rpc::Value;
 
-    use crate::model::{CallingConvention, IdempotencyKey};
+    use crate::model::{CallingConvention, ComponentId, IdempotencyKey, WorkerId};
 
-    use super::{OplogEntry, WrappedFunctionType};
+    use super::{Compensation, OplogEntry, WrappedFunctionType};
 
     #[test]
     fn oplog_entry_imported_function_invoked_payload_roundtrip() {
@@ -466,6 +553,59 @@ mod tests {
         assert_eq!(request, vec![val1]);
     }
 
+    #[test]
+    fn only_compensation_outcomes_are_skipped_in_replay() {
+        let key = IdempotencyKey {
+            value: "compensation".to_string(),
+        };
+        let compensation = Compensation {
+            idempotency_key: key.clone(),
+            worker_id: WorkerId {
+                component_id: ComponentId::new_v4(),
+                worker_name: "payments".to_string(),
+            },
+            function_name: "refund".to_string(),
+            function_input: vec![Value::U64(10)],
+        };
+
+        assert!(!OplogEntry::register_compensation(1, compensation).is_hint());
+        assert!(OplogEntry::compensation_completed(key.clone(), None).is_hint());
+        assert!(OplogEntry::compensation_completed(key, Some("failed".to_string())).is_hint());
+    }
+
+    #[test]
+    fn invocation_call_chains_are_skipped_in_replay() {
+        let caller = WorkerId {
+            component_id: ComponentId::new_v4(),
+            worker_name: "caller".to_string(),
+        };
+        let entry = OplogEntry::invocation_call_chain(
+            IdempotencyKey {
+                value: "invocation".to_string(),
+            },
+            vec![caller],
+        );
+
+        assert!(entry.is_hint());
+    }
+
+    #[test]
+    fn pending_compensations_are_skipped_in_replay() {
+        let entry = OplogEntry::pending_compensations(vec![Compensation {
+            idempotency_key: IdempotencyKey {
+                value: "compensation".to_string(),
+            },
+            worker_id: WorkerId {
+                component_id: ComponentId::new_v4(),
+                worker_name: "inventory".to_string(),
+            },
+            function_name: "release".to_string(),
+            function_input: vec![],
+        }]);
+
+        assert!(entry.is_hint());
+    }
+
     #[test]
     fn oplog_entry_exported_function_completed_roundtrip() {
         let val1 = Val {
@@ -153,6 +153,20 @@ impl<'a> RedisLabelledApi<'a> {
         self.record(start, "DEL", self.pool.del(self.prefixed_key(key)).await)
     }
 
+    pub async fn expire<R, K>(&self, key: K, seconds: i64) -> RedisResult<R>
+    where
+        R: FromRedis,
+        K: AsRef<str>,
+    {
+        self.ensure_connected().await?;
+        let start = Instant::now();
+        self.record(
+            start,
+            "EXPIRE",
+            self.pool.expire(self.prefixed_key(key), seconds).await,
+        )
+    }
+
     pub async fn get<R, K>(&self, key: K) -> RedisResult<R>
     where
         R: FromRedis,
@@ -232,6 +246,20 @@ impl<'a> RedisLabelledApi<'a> {
         )
     }
 
+    pub async fn hgetall<R, K>(&self, key: K) -> RedisResult<R>
+    where
+        R: FromRedis,
+        K: AsRef<str>,
+    {
+        self.ensure_connected().await?;
+        let start = Instant::now();
+        self.record(
+            start,
+            "HGETALL",
+            self.pool.hgetall(self.prefixed_key(key)).await,
+        )
+    }
+
     pub async fn hkeys<R, K>(&self, key: K) -> RedisResult<R>
     where
         R: FromRedis,
@@ -614,6 +642,23 @@ impl<'a> RedisLabelledApi<'a> {
         self.record(start, "MULTI", trx.trx.exec(true).await)
     }
 
+    /// Runs a Lua script atomically. The `keys` are prefixed like the keys of all other commands.
+    pub async fn eval<R, V>(&self, script: &str, keys: Vec<String>, args: V) -> RedisResult<R>
+    where
+        R: FromRedis,
+        V: TryInto<MultipleValues> + Send,
+        V::Error: Into<RedisError> + Send,
+    {
+        self.ensure_connected().await?;
+        let start = Instant::now();
+        let keys: Vec<String> = keys.into_iter().map(|key| self.prefixed_key(key)).collect();
+        self.record(
+            start,
+            "EVAL",
+            self.pool.eval(script.to_string(), keys, args).await,
+        )
+    }
+
     pub async fn wait(&self, replicas: i64, timeout: i64) -> RedisResult<i64> {
         self.ensure_connected().await?;
         let start = Instant::now();
@@ -672,6 +717,82 @@ impl<'a> RedisLabelledApi<'a> {
         )
     }
 
+    /// Iterates the field names of a hash matching the given pattern
+    pub async fn hscan<K, P>(
+        &self,
+        key: K,
+        pattern: P,
+        cursor: u64,
+        count: u64,
+    ) -> RedisResult<(u64, Vec<String>)>
+    where
+        K: AsRef<str>,
+        P: AsRef<str>,
+    {
+        self.ensure_connected().await?;
+        let start = Instant::now();
+
+        //https://redis.io/commands/hscan/
+        let args: Vec<String> = vec![
+            self.prefixed_key(key),
+            cursor.to_string(),
+            "MATCH".to_string(),
+            pattern.as_ref().to_string(),
+            "COUNT".to_string(),
+            count.to_string(),
+        ];
+
+        self.record(
+            start,
+            "HSCAN",
+            self.pool
+                .next()
+                .custom_raw(cmd!("HSCAN"), args)
+                .await
+                .and_then(|f| self.parse_hash_scan_frame(f)),
+        )
+    }
+
+    fn parse_hash_scan_frame(&self, frame: Resp3Frame) -> RedisResult<(u64, Vec<String>)> {
+        use fred::prelude::*;
+        if let Resp3Frame::Array { mut data, .. } = frame {
+            if data.len() == 2 {
+                let cursor: u64 = data[0]
+                    .clone()
+                    .try_into()
+                    .and_then(|value: RedisValue| value.convert())?;
+
+                if let Some(Resp3Frame::Array { data, .. }) = data.pop() {
+                    // The elements are alternating field names and values
+                    let mut fields = Vec::with_capacity(data.len() / 2);
+                    for frame in data.into_iter().step_by(2) {
+                        let field: String = frame
+                            .try_into()
+                            .and_then(|value: RedisValue| value.convert())?;
+                        fields.push(field);
+                    }
+
+                    Ok((cursor, fields))
+                } else {
+                    Err(RedisError::new(
+                        RedisErrorKind::Protocol,
+                        "Expected second HSCAN result element to be an array.",
+                    ))
+                }
+            } else {
+                Err(RedisError::new(
+                    RedisErrorKind::Protocol,
+                    "Expected two-element bulk string array from HSCAN.",
+                ))
+            }
+        } else {
+            Err(RedisError::new(
+                RedisErrorKind::Protocol,
+                "Expected bulk string array from HSCAN.",
+            ))
+        }
+    }
+
     fn parse_key_scan_frame(&self, frame: Resp3Frame) -> RedisResult<(u64, Vec<String>)> {
         use fred::prelude::*;
         if let Resp3Frame::Array { mut data, .. } = frame {
@@ -786,4 +907,56 @@ impl RedisTransaction {
     {
         self.trx.scard(self.prefixed_key(key)).await
     }
+
+    pub async fn hdel<K, F>(&self, key: K, fields: F) -> RedisResult<()>
+    where
+        K: AsRef<str>,
+        F: Into<MultipleKeys> + Send,
+    {
+        self.trx.hdel(self.prefixed_key(key), fields).await
+    }
+
+    pub async fn hset<K, V>(&self, key: K, values: V) -> RedisResult<()>
+    where
+        K: AsRef<str>,
+        V: TryInto<RedisMap> + Send,
+        V::Error: Into<RedisError> + Send,
+    {
+        self.trx.hset(self.prefixed_key(key), values).await
+    }
+
+    pub async fn zadd<K, V>(
+        &self,
+        key: K,
+        options: Option<SetOptions>,
+        ordering: Option<Ordering>,
+        changed: bool,
+        incr: bool,
+        values: V,
+    ) -> RedisResult<()>
+    where
+        K: AsRef<str>,
+        V: TryInto<MultipleZaddValues> + Send,
+        V::Error: Into<RedisError> + Send,
+    {
+        self.trx
+            .zadd(
+                self.prefixed_key(key),
+                options,
+                ordering,
+                changed,
+                incr,
+                values,
+            )
+            .await
+    }
+
+    pub async fn zrem<K, V>(&self, key: K, members: V) -> RedisResult<()>
+    where
+        K: AsRef<str>,
+        V: TryInto<MultipleValues> + Send,
+        V::Error: Into<RedisError> + Send,
+    {
+        self.trx.zrem(self.prefixed_key(key), members).await
+    }
 }
@@ -0,0 +1,234 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use opentelemetry::propagation::{Extractor, Injector};
+use opentelemetry::trace::TraceError;
+use opentelemetry::{global, Context, KeyValue};
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::Tracer;
+use opentelemetry_sdk::{runtime, trace, Resource};
+use poem::{Endpoint, Middleware};
+use tonic::metadata::{KeyRef, MetadataKey, MetadataMap, MetadataValue};
+use tracing::{Instrument, Span, Subscriber};
+use tracing_opentelemetry::{OpenTelemetryLayer, OpenTelemetrySpanExt};
+use tracing_subscriber::registry::LookupSpan;
+
+use crate::config::OtlpConfig;
+
+/// Creates a tracing layer exporting the spans of the given service to an OTLP collector, if it
+/// is enabled in the configuration. Also installs the W3C trace context propagator used by the
+/// other functions of this module.
+///
+/// The exporter runs on the Tokio runtime, so this has to be called in the context of one. As
+/// the layer is created before the tracing subscriber is installed, failing to set up the
+/// exporter is returned to the caller instead of being logged.
+pub fn otlp_layer<S>(
+    config: &OtlpConfig,
+    service_name: &str,
+) -> Result<Option<OpenTelemetryLayer<S, Tracer>>, TraceError>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+{
+    global::set_text_map_propagator(TraceContextPropagator::new());
+
+    if config.enabled {
+        let tracer = otlp_tracer(config, service_name)?;
+        Ok(Some(tracing_opentelemetry::layer().with_tracer(tracer)))
+    } else {
+        Ok(None)
+    }
+}
+
+fn otlp_tracer(config: &OtlpConfig, service_name: &str) -> Result<Tracer, TraceError> {
+    opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(
+            opentelemetry_otlp::new_exporter()
+                .tonic()
+                .with_endpoint(config.endpoint.clone()),
+        )
+        .with_trace_config(
+            trace::config().with_resource(Resource::new(vec![KeyValue::new(
+                "service.name",
+                service_name.to_string(),
+            )])),
+        )
+        .install_batch(runtime::Tokio)
+}
+
+/// Wraps an outgoing gRPC message in a request carrying the trace context of the current span
+pub fn with_trace_context<T>(message: T) -> tonic::Request<T> {
+    let mut request = tonic::Request::new(message);
+    inject_trace_context(request.metadata_mut());
+    request
+}
+
+/// Adds the trace context of the current span to the metadata of an outgoing gRPC request
+pub fn inject_trace_context(metadata: &mut MetadataMap) {
+    inject_context(&Span::current().context(), metadata);
+}
+
+fn inject_context(context: &Context, metadata: &mut MetadataMap) {
+    global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(context, &mut MetadataInjector(metadata))
+    });
+}
+
+/// Creates the span of an incoming gRPC request, continuing the trace of the caller.
+///
+/// To be used with tonic's `Server::trace_fn`.
+pub fn grpc_request_span(request: &tonic::codegen::http::Request<()>) -> Span {
+    let span = tracing::info_span!("grpc request", path = request.uri().path());
+    set_parent_from_headers(
+        &span,
+        request
+            .headers()
+            .iter()
+            .filter_map(|(key, value)| value.to_str().ok().map(|value| (key.as_str(), value))),
+    );
+    span
+}
+
+/// The trace context headers of the current span, to be added to an outgoing HTTP request
+pub fn trace_context_headers() -> HashMap<String, String> {
+    let mut headers = HashMap::new();
+    let context = Span::current().context();
+    global::get_text_map_propagator(|propagator| propagator.inject_context(&context, &mut headers));
+    headers
+}
+
+/// Makes the given span continue the trace described by the headers of an incoming HTTP request
+pub fn set_parent_from_headers<'a>(
+    span: &Span,
+    headers: impl IntoIterator<Item = (&'a str, &'a str)>,
+) {
+    let headers: HashMap<String, String> = headers
+        .into_iter()
+        .map(|(key, value)| (key.to_lowercase(), value.to_string()))
+        .collect();
+    let context = global::get_text_map_propagator(|propagator| propagator.extract(&headers));
+    span.set_parent(context);
+}
+
+/// Poem middleware making the spans of incoming HTTP requests continue the trace of the caller
+pub struct TraceContext;
+
+impl<E: Endpoint> Middleware<E> for TraceContext {
+    type Output = TraceContextEndpoint<E>;
+
+    fn transform(&self, ep: E) -> Self::Output {
+        TraceContextEndpoint { inner: ep }
+    }
+}
+
+pub struct TraceContextEndpoint<E> {
+    inner: E,
+}
+
+#[async_trait]
+impl<E: Endpoint> Endpoint for TraceContextEndpoint<E> {
+    type Output = E::Output;
+
+    async fn call(&self, req: poem::Request) -> poem::Result<Self::Output> {
+        let span = tracing::info_span!(
+            "http request",
+            method = %req.method(),
+            path = req.uri().path()
+        );
+        set_parent_from_headers(
+            &span,
+            req.headers()
+                .iter()
+                .filter_map(|(key, value)| value.to_str().ok().map(|value| (key.as_str(), value))),
+        );
+        self.inner.call(req).instrument(span).await
+    }
+}
+
+#[cfg(test)]
+fn extract_context(metadata: &MetadataMap) -> Context {
+    global::get_text_map_propagator(|propagator| propagator.extract(&MetadataExtractor(metadata)))
+}
+
+struct MetadataInjector<'a>(&'a mut MetadataMap);
+
+impl<'a> Injector for MetadataInjector<'a> {
+    fn set(&mut self, key: &str, value: String) {
+        if let (Ok(key), Ok(value)) = (
+            MetadataKey::from_bytes(key.as_bytes()),
+            MetadataValue::try_from(&value),
+        ) {
+            self.0.insert(key, value);
+        }
+    }
+}
+
+struct MetadataExtractor<'a>(&'a MetadataMap);
+
+impl<'a> Extractor for MetadataExtractor<'a> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0
+            .keys()
+            .filter_map(|key| match key {
+                KeyRef::Ascii(key) => Some(key.as_str()),
+                KeyRef::Binary(_) => None,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use opentelemetry::global;
+    use opentelemetry::trace::{
+        SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState,
+    };
+    use opentelemetry::Context;
+    use opentelemetry_sdk::propagation::TraceContextPropagator;
+    use tonic::metadata::MetadataMap;
+
+    use crate::telemetry::{extract_context, inject_context};
+
+    #[test]
+    fn trace_context_round_trips_through_grpc_metadata() {
+        global::set_text_map_propagator(TraceContextPropagator::new());
+
+        let span_context = SpanContext::new(
+            TraceId::from_hex("4bf92f3577b34da6a3ce929d0e0e4736").unwrap(),
+            SpanId::from_hex("00f067aa0ba902b7").unwrap(),
+            TraceFlags::SAMPLED,
+            true,
+            TraceState::default(),
+        );
+        let context = Context::new().with_remote_span_context(span_context.clone());
+
+        let mut metadata = MetadataMap::new();
+        inject_context(&context, &mut metadata);
+        let extracted = extract_context(&metadata);
+
+        assert_eq!(
+            metadata.get("traceparent").unwrap(),
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"
+        );
+        assert_eq!(extracted.span().span_context(), &span_context);
+    }
+}
@@ -2,7 +2,7 @@ use std::net::{Ipv4Addr, SocketAddrV4};
 
 use figment::providers::{Env, Format, Toml};
 use figment::Figment;
-use golem_common::config::RetryConfig;
+use golem_common::config::{OtlpConfig, RetryConfig};
 use golem_worker_executor_base::services::golem_config::CompiledComponentServiceConfig;
 use http::Uri;
 use serde::Deserialize;
@@ -21,6 +21,7 @@ pub struct ServerConfig {
     // General.
     pub enable_tracing_console: bool,
     pub enable_json_log: bool,
+    pub otlp: OtlpConfig,
     pub grpc_port: u16,
     pub grpc_host: String,
 
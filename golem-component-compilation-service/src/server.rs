@@ -9,7 +9,10 @@ use std::{
 use crate::service::compile_service::ComponentCompilationServiceImpl;
 use config::ServerConfig;
 use golem_api_grpc::proto::golem::componentcompilation::component_compilation_service_server::ComponentCompilationServiceServer;
+use golem_common::telemetry::{grpc_request_span, otlp_layer};
 use golem_worker_executor_base::{http_server::HttpServerImpl, services::compiled_component};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
 use tracing_subscriber::EnvFilter;
 
 mod config;
@@ -22,6 +25,15 @@ fn main() {
     let prometheus = metrics::register_all();
     let config = crate::config::ServerConfig::new();
 
+    let runtime = Arc::new(
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap(),
+    );
+    // The OTLP exporter has to be created in the context of the runtime
+    let _runtime_guard = runtime.enter();
+
     if config.enable_tracing_console {
         // NOTE: also requires RUSTFLAGS="--cfg tokio_unstable" cargo build
         console_subscriber::init();
@@ -31,20 +43,24 @@ fn main() {
             .flatten_event(true)
             // .with_span_events(FmtSpan::FULL) // NOTE: enable to see span events
             .with_env_filter(EnvFilter::from_default_env())
+            .finish()
+            .with(
+                otlp_layer(&config.otlp, "golem-component-compilation-service")
+                    .expect("Failed to initialize the OTLP exporter"),
+            )
             .init();
     } else {
         tracing_subscriber::fmt()
             .with_env_filter(EnvFilter::from_default_env())
             .with_ansi(true)
+            .finish()
+            .with(
+                otlp_layer(&config.otlp, "golem-component-compilation-service")
+                    .expect("Failed to initialize the OTLP exporter"),
+            )
             .init();
     }
 
-    let runtime = Arc::new(
-        tokio::runtime::Builder::new_multi_thread()
-            .enable_all()
-            .build()
-            .unwrap(),
-    );
     runtime.block_on(run(config, prometheus))
 }
 
@@ -92,6 +108,7 @@ async fn start_grpc_server(
         .await;
 
     tonic::transport::Server::builder()
+        .trace_fn(grpc_request_span)
         .add_service(health_service)
         .add_service(ComponentCompilationServiceServer::new(
             CompileGrpcService::new(service),
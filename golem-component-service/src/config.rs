@@ -14,6 +14,7 @@
 
 use figment::providers::{Env, Format, Toml};
 use figment::Figment;
+use golem_common::config::OtlpConfig;
 use golem_component_service_base::config::ComponentCompilationConfig;
 use golem_service_base::config::ComponentStoreConfig;
 use serde::Deserialize;
@@ -45,6 +46,7 @@ impl Default for WorkerExecutorClientCacheConfig {
 pub struct ComponentServiceConfig {
     pub enable_tracing_console: bool,
     pub enable_json_log: bool,
+    pub otlp: OtlpConfig,
     pub http_port: u16,
     pub grpc_port: u16,
     pub db: DbConfig,
@@ -94,6 +96,7 @@ impl Default for ComponentServiceConfig {
         Self {
             enable_tracing_console: false,
             enable_json_log: false,
+            otlp: OtlpConfig::default(),
             http_port: 8081,
             grpc_port: 9091,
             db: DbConfig::default(),
@@ -14,6 +14,7 @@
 
 use golem_api_grpc::proto;
 use golem_api_grpc::proto::golem::component::component_service_server::ComponentServiceServer;
+use golem_common::telemetry::grpc_request_span;
 use std::net::SocketAddr;
 use tonic::transport::{Error, Server};
 
@@ -34,6 +35,7 @@ pub async fn start_grpc_server(addr: SocketAddr, services: &Services) -> Result<
         .unwrap();
 
     Server::builder()
+        .trace_fn(grpc_request_span)
         .add_service(reflection_service)
         .add_service(health_service)
         .add_service(ComponentServiceServer::new(ComponentGrpcApi {
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use golem_common::telemetry::{otlp_layer, TraceContext};
 use golem_component_service::api::make_open_api_service;
 use golem_component_service::config::{ComponentServiceConfig, DbConfig};
 use golem_component_service::service::Services;
@@ -27,6 +28,8 @@ use std::sync::Arc;
 use tokio::select;
 use tracing::{error, info};
 use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
 use tracing_subscriber::EnvFilter;
 
 fn main() -> Result<(), std::io::Error> {
@@ -38,6 +41,13 @@ fn main() -> Result<(), std::io::Error> {
         let prometheus = metrics::register_all();
         let config = ComponentServiceConfig::new();
 
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        // The OTLP exporter has to be created in the context of the runtime
+        let _runtime_guard = runtime.enter();
+
         if config.enable_tracing_console {
             // NOTE: also requires RUSTFLAGS="--cfg tokio_unstable" cargo build
             console_subscriber::init();
@@ -47,11 +57,21 @@ fn main() -> Result<(), std::io::Error> {
                 .flatten_event(true)
                 .with_span_events(FmtSpan::FULL) // NOTE: enable to see span events
                 .with_env_filter(EnvFilter::from_default_env())
+                .finish()
+                .with(
+                    otlp_layer(&config.otlp, "golem-component-service")
+                        .expect("Failed to initialize the OTLP exporter"),
+                )
                 .init();
         } else {
             tracing_subscriber::fmt()
                 .with_env_filter(EnvFilter::from_default_env())
                 .with_ansi(true)
+                .finish()
+                .with(
+                    otlp_layer(&config.otlp, "golem-component-service")
+                        .expect("Failed to initialize the OTLP exporter"),
+                )
                 .init();
         }
 
@@ -62,11 +82,7 @@ fn main() -> Result<(), std::io::Error> {
 
         global::set_meter_provider(MeterProvider::builder().with_reader(exporter).build());
 
-        tokio::runtime::Builder::new_multi_thread()
-            .enable_all()
-            .build()
-            .unwrap()
-            .block_on(async_main(&config, prometheus))
+        runtime.block_on(async_main(&config, prometheus))
     }
 }
 
@@ -109,7 +125,8 @@ async fn async_main(
         let prometheus_registry = Arc::new(prometheus_registry);
         let app = api::combined_routes(prometheus_registry, &http_services)
             .with(OpenTelemetryMetrics::new())
-            .with(Tracing);
+            .with(Tracing)
+            .with(TraceContext);
 
         poem::Server::new(TcpListener::bind(format!("0.0.0.0:{}", http_port)))
             .run(app)
@@ -6,5 +6,6 @@ pub enum ApiTags {
     ApiDefinition,
     Component,
     Worker,
+    KeyValue,
     HealthCheck,
 }
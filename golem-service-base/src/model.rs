@@ -16,8 +16,8 @@ use golem_api_grpc::proto::golem::shardmanager::{
     Pod as GrpcPod, RoutingTable as GrpcRoutingTable, RoutingTableEntry as GrpcRoutingTableEntry,
 };
 use golem_common::model::{
-    parse_function_name, ComponentId, ComponentVersion, ShardId, Timestamp, WorkerFilter,
-    WorkerStatus,
+    parse_function_name, ComponentId, ComponentVersion, PromiseStatus, ShardId, Timestamp,
+    WorkerFilter, WorkerStatus,
 };
 use golem_wasm_ast::analysis::{AnalysedResourceId, AnalysedResourceMode};
 use http::Uri;
@@ -1919,6 +1919,10 @@ impl std::fmt::Display for WorkerId {
 pub struct CompleteParameters {
     pub oplog_idx: u64,
     pub data: Vec<u8>,
+    /// Rejects the promise, with `data` as the error payload
+    #[serde(default)]
+    #[oai(default)]
+    pub failed: bool,
 }
 
 impl From<CompleteParameters> for golem_api_grpc::proto::golem::worker::CompleteParameters {
@@ -1926,10 +1930,18 @@ impl From<CompleteParameters> for golem_api_grpc::proto::golem::worker::Complete
         Self {
             oplog_idx: value.oplog_idx,
             data: value.data,
+            failed: value.failed,
         }
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct SendMailboxMessageParameters {
+    pub payload: Vec<u8>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
 #[serde(rename_all = "camelCase")]
 #[oai(rename_all = "camelCase")]
@@ -2625,9 +2637,34 @@ pub struct InterruptResponse {}
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
 pub struct ResumeResponse {}
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
+pub struct SendMailboxMessageResponse {}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
 pub struct UpdateWorkerResponse {}
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct KeyValueBuckets {
+    pub buckets: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct KeyValueKeys {
+    pub keys: Vec<String>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct KeyValueEntry {
+    pub value: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
+pub struct SetKeyValueResponse {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
+pub struct DeleteKeyValueResponse {}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Enum)]
 pub enum WorkerUpdateMode {
     Automatic,
@@ -2692,6 +2729,8 @@ pub struct WorkerMetadata {
     pub updates: Vec<UpdateRecord>,
     pub created_at: Timestamp,
     pub last_error: Option<String>,
+    /// Oplog index of the promise the worker is suspended on
+    pub awaited_promise_oplog_idx: Option<u64>,
 }
 
 impl TryFrom<golem_api_grpc::proto::golem::worker::WorkerMetadata> for WorkerMetadata {
@@ -2715,6 +2754,7 @@ impl TryFrom<golem_api_grpc::proto::golem::worker::WorkerMetadata> for WorkerMet
                 .collect::<Result<Vec<UpdateRecord>, String>>()?,
             created_at: value.created_at.ok_or("Missing created_at")?.into(),
             last_error: value.last_error,
+            awaited_promise_oplog_idx: value.awaited_promise_oplog_idx,
         })
     }
 }
@@ -2735,10 +2775,41 @@ impl From<WorkerMetadata> for golem_api_grpc::proto::golem::worker::WorkerMetada
             updates: value.updates.iter().cloned().map(|u| u.into()).collect(),
             created_at: Some(value.created_at.into()),
             last_error: value.last_error,
+            awaited_promise_oplog_idx: value.awaited_promise_oplog_idx,
         }
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct PromiseMetadata {
+    pub oplog_idx: u64,
+    pub status: PromiseStatus,
+    pub completed_at: Option<Timestamp>,
+    pub payload_size: Option<u64>,
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::worker::PromiseMetadata> for PromiseMetadata {
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::worker::PromiseMetadata,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            oplog_idx: value.oplog_idx,
+            status: value.status.try_into()?,
+            completed_at: value.completed_at.map(|timestamp| timestamp.into()),
+            payload_size: value.payload_size,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct WorkerPromises {
+    pub promises: Vec<PromiseMetadata>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Union)]
 #[serde(rename_all = "camelCase")]
 #[oai(discriminator_name = "type", one_of = true, rename_all = "camelCase")]
@@ -2852,6 +2923,29 @@ pub struct InvokeResult {
     pub result: serde_json::value::Value,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct BroadcastParameters {
+    pub filter: Option<WorkerFilter>,
+    pub params: serde_json::value::Value,
+    pub max_concurrency: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct BroadcastWorkerResult {
+    pub worker_id: WorkerId,
+    pub result: Option<serde_json::value::Value>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct BroadcastResult {
+    pub results: Vec<BroadcastWorkerResult>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Union, thiserror::Error)]
 #[oai(discriminator_name = "type", one_of = true)]
 pub enum GolemError {
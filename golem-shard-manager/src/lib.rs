@@ -33,6 +33,7 @@ use golem_api_grpc::proto::golem;
 use golem_api_grpc::proto::golem::shardmanager::shard_manager_service_server::{
     ShardManagerService, ShardManagerServiceServer,
 };
+use golem_common::telemetry::{grpc_request_span, otlp_layer};
 use model::{Pod, RoutingTable};
 use persistence::{PersistenceService, PersistenceServiceDefault};
 use prometheus::{default_registry, Registry};
@@ -41,6 +42,8 @@ use shard_manager_config::ShardManagerConfig;
 use tonic::transport::Server;
 use tonic::Response;
 use tracing::{debug, info, warn};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
 use tracing_subscriber::EnvFilter;
 use worker_executor::{WorkerExecutorService, WorkerExecutorServiceDefault};
 
@@ -176,17 +179,28 @@ pub fn server_main() -> Result<(), Box<dyn std::error::Error>> {
     let config = ShardManagerConfig::new();
     let registry = default_registry().clone();
 
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    // The OTLP exporter has to be created in the context of the runtime
+    let _runtime_guard = runtime.enter();
+
     if config.enable_json_log {
         tracing_subscriber::fmt()
             .json()
             .flatten_event(true)
             // .with_span_events(FmtSpan::FULL) // NOTE: enable to see span events
             .with_env_filter(EnvFilter::from_default_env())
+            .finish()
+            .with(otlp_layer(&config.otlp, "golem-shard-manager")?)
             .init();
     } else {
         tracing_subscriber::fmt()
             .with_env_filter(EnvFilter::from_default_env())
             .with_ansi(true)
+            .finish()
+            .with(otlp_layer(&config.otlp, "golem-shard-manager")?)
             .init();
     }
 
@@ -195,11 +209,7 @@ pub fn server_main() -> Result<(), Box<dyn std::error::Error>> {
     // TODO: make tracing subscription configurable
     // console_subscriber::init();
 
-    tokio::runtime::Builder::new_multi_thread()
-        .enable_all()
-        .build()
-        .unwrap()
-        .block_on(async_main(&config, registry))
+    runtime.block_on(async_main(&config, registry))
 }
 
 async fn async_main(
@@ -274,6 +284,7 @@ async fn async_main(
 
     // TODO: configurable limits
     Server::builder()
+        .trace_fn(grpc_request_span)
         .concurrency_limit_per_connection(1024)
         .max_concurrent_streams(Some(1024))
         .add_service(reflection_service)
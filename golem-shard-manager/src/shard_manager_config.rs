@@ -16,7 +16,7 @@ use std::time::Duration;
 
 use figment::providers::{Env, Format, Toml};
 use figment::Figment;
-use golem_common::config::{RedisConfig, RetryConfig};
+use golem_common::config::{OtlpConfig, RedisConfig, RetryConfig};
 use serde::Deserialize;
 
 #[derive(Clone, Debug, Deserialize)]
@@ -25,6 +25,7 @@ pub struct ShardManagerConfig {
     pub worker_executors: WorkerExecutorServiceConfig,
     pub health_check: HealthCheckConfig,
     pub enable_json_log: bool,
+    pub otlp: OtlpConfig,
     pub http_port: u16,
     pub number_of_shards: usize,
     pub rebalance_threshold: f64,
@@ -251,6 +251,8 @@ impl WorkerService for ForwardingWorkerService {
                     available_fuel: i64::MAX,
                     max_memory_per_worker: i64::MAX,
                 }),
+                call_chain: request.call_chain,
+                ephemeral: false,
             })
             .await
             .expect("Failed to call golem-worker-executor")
@@ -295,6 +297,7 @@ impl WorkerService for ForwardingWorkerService {
                     available_fuel: i64::MAX,
                     max_memory_per_worker: i64::MAX,
                 }),
+                since: request.since,
             })
             .await
             .expect("Failed to call golem-worker-executor")
@@ -18,6 +18,7 @@ use std::sync::Arc;
 use crate::components::component_compilation_service::ComponentCompilationService;
 pub use cli::{CliParams, CliTestDependencies};
 pub use env::EnvBasedTestDependencies;
+use golem_common::clock::VirtualClock;
 
 use crate::components::component_service::ComponentService;
 use crate::components::rdb::Rdb;
@@ -43,6 +44,12 @@ pub trait TestDependencies {
     fn worker_service(&self) -> Arc<dyn WorkerService + Send + Sync + 'static>;
     fn worker_executor_cluster(&self) -> Arc<dyn WorkerExecutorCluster + Send + Sync + 'static>;
 
+    /// The virtual clock driving the worker executors, if they were started with one.
+    /// Only available when the executors run in the same process as the test.
+    fn virtual_clock(&self) -> Option<Arc<VirtualClock>> {
+        None
+    }
+
     fn kill_all(&self) {
         self.worker_executor_cluster().kill_all();
         self.worker_service().kill();
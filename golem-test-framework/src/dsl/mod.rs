@@ -41,6 +41,7 @@ use golem_wasm_ast::IgnoreAllButMetadata;
 use golem_wasm_rpc::Value;
 use std::collections::HashMap;
 use std::path::Path;
+use std::time::Duration;
 use tokio::select;
 use tokio::sync::mpsc::UnboundedReceiver;
 use tracing::{debug, info};
@@ -149,6 +150,7 @@ pub trait TestDsl {
     async fn simulated_crash(&self, worker_id: &WorkerId);
     async fn auto_update_worker(&self, worker_id: &WorkerId, target_version: ComponentVersion);
     async fn manual_update_worker(&self, worker_id: &WorkerId, target_version: ComponentVersion);
+    async fn advance_time(&self, duration: Duration);
 }
 
 #[async_trait]
@@ -477,6 +479,7 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
                     params: params.into_iter().map(|v| v.into()).collect(),
                 }),
                 calling_convention: cc.into(),
+                call_chain: vec![],
             })
             .await;
 
@@ -505,12 +508,16 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
             let mut response = cloned_service
                 .connect_worker(ConnectWorkerRequest {
                     worker_id: Some(worker_id.clone().into()),
+                    since: None,
                 })
                 .await;
 
             while let Some(event) = response.message().await.expect("Failed to get message") {
                 debug!("Received event: {:?}", event);
-                tx.send(event).expect("Failed to send event");
+                if is_output(&event) {
+                    tx.send(without_position(event))
+                        .expect("Failed to send event");
+                }
             }
 
             debug!("Finished receiving events");
@@ -536,6 +543,7 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
                 let mut response = cloned_service
                     .connect_worker(ConnectWorkerRequest {
                         worker_id: Some(worker_id.clone().into()),
+                        since: None,
                     })
                     .await;
 
@@ -545,7 +553,9 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
                             match msg {
                                 Ok(Some(event)) =>  {
                                     debug!("Received event: {:?}", event);
-                                    tx.send(Some(event)).expect("Failed to send event");
+                                    if is_output(&event) {
+                                        tx.send(Some(without_position(event))).expect("Failed to send event");
+                                    }
                                 }
                                 Ok(None) => {
                                     break;
@@ -581,12 +591,16 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
             let mut response = cloned_service
                 .connect_worker(ConnectWorkerRequest {
                     worker_id: Some(worker_id.clone().into()),
+                    since: None,
                 })
                 .await;
 
             while let Some(event) = response.message().await.expect("Failed to get message") {
                 debug!("Received event: {:?}", event);
-                tx.send(Some(event)).expect("Failed to send event");
+                if is_output(&event) {
+                    tx.send(Some(without_position(event)))
+                        .expect("Failed to send event");
+                }
             }
 
             debug!("Finished receiving events");
@@ -603,6 +617,7 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
             let mut response = cloned_service
                 .connect_worker(ConnectWorkerRequest {
                     worker_id: Some(worker_id.clone().into()),
+                    since: None,
                 })
                 .await;
 
@@ -710,6 +725,14 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
             _ => panic!("Failed to update worker: unknown error"),
         }
     }
+
+    async fn advance_time(&self, duration: Duration) {
+        let clock = self
+            .virtual_clock()
+            .expect("Advancing time requires worker executors running with a virtual clock");
+        let now = clock.advance(duration);
+        debug!("Virtual clock advanced by {duration:?} to {now:?}");
+    }
 }
 
 pub fn stdout_event(s: &str) -> LogEvent {
@@ -717,12 +740,14 @@ pub fn stdout_event(s: &str) -> LogEvent {
         event: Some(log_event::Event::Stdout(StdOutLog {
             message: s.to_string(),
         })),
+        ..Default::default()
     }
 }
 
 pub fn stdout_event_starting_with(event: &LogEvent, s: &str) -> bool {
     if let LogEvent {
         event: Some(log_event::Event::Stdout(StdOutLog { message })),
+        ..
     } = event
     {
         message.starts_with(s)
@@ -736,9 +761,31 @@ pub fn stderr_event(s: &str) -> LogEvent {
         event: Some(log_event::Event::Stderr(StdErrLog {
             message: s.to_string(),
         })),
+        ..Default::default()
+    }
+}
+
+/// Drops the position of a captured event in the worker's event stream, so it can be compared
+/// to the expected events by content only
+fn without_position(event: LogEvent) -> LogEvent {
+    LogEvent {
+        sequence: 0,
+        timestamp: 0,
+        ..event
     }
 }
 
+/// True for stdout, stderr and log events, as opposed to invocation lifecycle and status change
+/// events which are not part of the worker's captured output
+fn is_output(event: &LogEvent) -> bool {
+    matches!(
+        event.event,
+        Some(log_event::Event::Stdout(_))
+            | Some(log_event::Event::Stderr(_))
+            | Some(log_event::Event::Log(_))
+    )
+}
+
 pub fn log_event_to_string(event: &LogEvent) -> String {
     match &event.event {
         Some(log_event::Event::Stdout(stdout)) => stdout.message.clone(),
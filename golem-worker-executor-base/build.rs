@@ -5,12 +5,41 @@ use std::path::Path;
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let golem_wit_root = find_package_root("golem-wit");
     let out_dir = var_os("OUT_DIR").unwrap();
+    let wit_root = Path::new(&out_dir).join("wit");
     let target_file = Path::new(&out_dir).join("preview2_mod.rs");
 
-    std::fs::write(target_file, preview2_mod_gen(&golem_wit_root)).unwrap();
+    // The executor's own interfaces in ./wit form the root package, and golem-wit's package is
+    // added to its dependencies
+    let golem_wit_path = Path::new(&golem_wit_root).join("wit");
+    copy_dir(Path::new("wit"), &wit_root, true)?;
+    copy_dir(&golem_wit_path.join("deps"), &wit_root.join("deps"), true)?;
+    copy_dir(
+        &golem_wit_path,
+        &wit_root.join("deps").join("golem-api"),
+        false,
+    )?;
+
+    std::fs::write(target_file, preview2_mod_gen(&wit_root.to_string_lossy())).unwrap();
 
     println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=wit");
+
+    Ok(())
+}
 
+fn copy_dir(source: &Path, target: &Path, recursive: bool) -> std::io::Result<()> {
+    std::fs::create_dir_all(target)?;
+    for entry in std::fs::read_dir(source)? {
+        let entry = entry?;
+        let target = target.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            if recursive {
+                copy_dir(&entry.path(), &target, recursive)?;
+            }
+        } else {
+            std::fs::copy(entry.path(), target)?;
+        }
+    }
     Ok(())
 }
 
@@ -23,12 +52,20 @@ fn find_package_root(name: &str) -> String {
     package.manifest_path.parent().unwrap().to_string()
 }
 
-fn preview2_mod_gen(golem_wit_path: &str) -> String {
+fn preview2_mod_gen(wit_path: &str) -> String {
     format!(
         r#"wasmtime::component::bindgen!({{
-        path: "{golem_wit_path}/wit",
+        path: "{wit_path}",
         interfaces: "
           import golem:api/host@0.2.0;
+          import golem:ext/async-rpc@0.1.0;
+          import golem:ext/broadcast@0.1.0;
+          import golem:ext/topics@0.1.0;
+          import golem:ext/mailbox@0.1.0;
+          import golem:ext/saga@0.1.0;
+          import golem:ext/keyvalue-keys@0.1.0;
+          import golem:ext/blobstore-attributes@0.1.0;
+          import golem:ext/promises@0.1.0;
 
           import wasi:blobstore/blobstore;
           import wasi:blobstore/container;
@@ -47,6 +84,7 @@ fn preview2_mod_gen(golem_wit_path: &str) -> String {
             "wasi:io/streams/input-stream": InputStream,
             "wasi:io/streams/output-stream": OutputStream,
             "wasi:io/poll/pollable": Pollable,
+            "golem:rpc/types": golem_wasm_rpc::golem::rpc::types,
             "wasi:blobstore/container/container": super::durable_host::blobstore::types::ContainerEntry,
             "wasi:blobstore/container/stream-object-names": super::durable_host::blobstore::types::StreamObjectNamesEntry,
             "wasi:blobstore/types/incoming-value": super::durable_host::blobstore::types::IncomingValueEntry,
@@ -56,6 +94,7 @@ fn preview2_mod_gen(golem_wit_path: &str) -> String {
             "wasi:keyvalue/types/incoming-value": super::durable_host::keyvalue::types::IncomingValueEntry,
             "wasi:keyvalue/types/outgoing-value": super::durable_host::keyvalue::types::OutgoingValueEntry,
             "golem:api/host/get-workers": super::durable_host::golem::GetWorkersEntry,
+            "golem:ext/async-rpc/future-invoke-result": super::durable_host::wasm_rpc::FutureInvokeResultEntry,
         }}
     }});
         "#
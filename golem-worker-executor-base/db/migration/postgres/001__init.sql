@@ -0,0 +1,8 @@
+CREATE TABLE key_values
+(
+    account_id text  NOT NULL,
+    bucket     text  NOT NULL,
+    key        text  NOT NULL,
+    value      bytea NOT NULL,
+    PRIMARY KEY (account_id, bucket, key)
+);
@@ -0,0 +1 @@
+ALTER TABLE key_values ADD COLUMN expires_at bigint;
@@ -0,0 +1,95 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use golem_common::model::oplog::WrappedFunctionType;
+use wasmtime::component::Resource;
+use wasmtime_wasi::preview2::WasiView;
+
+use crate::durable_host::blobstore::types::{ContainerEntry, OutgoingValueEntry};
+use crate::durable_host::serialized::{SerializableError, SerializableObjectAttributes};
+use crate::durable_host::{Durability, DurableWorkerCtx};
+use crate::metrics::wasm::record_host_function_call;
+use crate::preview2::golem::ext::blobstore_attributes::{
+    Container, Error, Host, ObjectAttributes, ObjectName, OutgoingValue,
+};
+use crate::services::blob_store;
+use crate::workerctx::WorkerCtx;
+
+#[async_trait]
+impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
+    async fn set_outgoing_value_attributes(
+        &mut self,
+        value: Resource<OutgoingValue>,
+        attributes: ObjectAttributes,
+    ) -> anyhow::Result<()> {
+        record_host_function_call(
+            "golem::ext::blobstore-attributes",
+            "set_outgoing_value_attributes",
+        );
+        let attributes = blob_store::ObjectAttributes {
+            content_type: attributes.content_type,
+            metadata: attributes.metadata.into_iter().collect(),
+        };
+        self.as_wasi_view()
+            .table_mut()
+            .get_mut::<OutgoingValueEntry>(&value)
+            .map(|outgoing_value_entry| outgoing_value_entry.attributes = attributes)?;
+        Ok(())
+    }
+
+    async fn get_object_attributes(
+        &mut self,
+        container: Resource<Container>,
+        name: ObjectName,
+    ) -> anyhow::Result<Result<ObjectAttributes, Error>> {
+        record_host_function_call("golem::ext::blobstore-attributes", "get_object_attributes");
+        let account_id = self.state.account_id.clone();
+        let container_name = self
+            .as_wasi_view()
+            .table()
+            .get::<ContainerEntry>(&container)
+            .map(|container_entry| container_entry.name.clone())?;
+        let result: anyhow::Result<blob_store::ObjectAttributes> =
+            Durability::<Ctx, SerializableObjectAttributes, SerializableError>::wrap(
+                self,
+                WrappedFunctionType::ReadRemote,
+                "golem blobstore::container::get_object_attributes",
+                |ctx| {
+                    Box::pin(async move {
+                        let object_info = ctx
+                            .state
+                            .blob_store_service
+                            .object_info(account_id, container_name, name)
+                            .await?;
+                        Ok(blob_store::ObjectAttributes {
+                            content_type: object_info.content_type,
+                            metadata: object_info.metadata,
+                        })
+                    })
+                },
+            )
+            .await;
+        match result {
+            Ok(attributes) => {
+                let attributes = SerializableObjectAttributes::from(attributes);
+                Ok(Ok(ObjectAttributes {
+                    content_type: attributes.content_type,
+                    metadata: attributes.metadata,
+                }))
+            }
+            Err(e) => Ok(Err(format!("{:?}", e))),
+        }
+    }
+}
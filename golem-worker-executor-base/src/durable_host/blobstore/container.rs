@@ -12,21 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::{Arc, RwLock};
+
 use async_trait::async_trait;
 use golem_common::model::oplog::WrappedFunctionType;
+use golem_common::model::AccountId;
 use wasmtime::component::Resource;
 use wasmtime_wasi::preview2::WasiView;
 
 use crate::durable_host::blobstore::types::{
-    ContainerEntry, IncomingValueEntry, OutgoingValueEntry, StreamObjectNamesEntry,
+    ContainerEntry, IncomingValueEntry, OutgoingValueBody, OutgoingValueEntry,
+    StreamObjectNamesEntry,
 };
-use crate::durable_host::serialized::SerializableError;
+use crate::durable_host::serialized::{SerializableError, SerializableObjectMetadata};
 use crate::durable_host::{Durability, DurableWorkerCtx};
 use crate::metrics::wasm::record_host_function_call;
 use crate::preview2::wasi::blobstore::container::{
     Container, ContainerMetadata, Error, Host, HostContainer, HostStreamObjectNames, IncomingValue,
     ObjectMetadata, ObjectName, OutgoingValue, StreamObjectNames,
 };
+use crate::services::blob_store::ObjectAttributes;
 use crate::workerctx::WorkerCtx;
 
 #[async_trait]
@@ -114,25 +119,46 @@ impl<Ctx: WorkerCtx> HostContainer for DurableWorkerCtx<Ctx> {
             .table()
             .get::<ContainerEntry>(&container)
             .map(|container_entry| container_entry.name.clone())?;
-        let data = self
+        let (body, attributes) = self
             .as_wasi_view()
             .table()
             .get::<OutgoingValueEntry>(&data)
-            .map(|outgoing_value_entry| outgoing_value_entry.body.read().unwrap().clone())?;
-        let result = Durability::<Ctx, (), SerializableError>::wrap(
-            self,
-            WrappedFunctionType::WriteRemote,
-            "golem blobstore::container::write_data",
-            |ctx| {
-                ctx.state.blob_store_service.write_data(
-                    account_id.clone(),
-                    container_name.clone(),
-                    name.clone(),
-                    data.clone(),
+            .map(|outgoing_value_entry| {
+                (
+                    outgoing_value_entry.body.clone(),
+                    outgoing_value_entry.attributes.clone(),
                 )
-            },
-        )
-        .await;
+            })?;
+        let size = body.read().unwrap().size();
+        let part_size = self.state.config.blob_store.part_size;
+        let result = if size > part_size as u64 {
+            self.write_data_in_parts(
+                account_id,
+                container_name,
+                name,
+                body,
+                attributes,
+                part_size,
+            )
+            .await
+        } else {
+            let data = body.write().unwrap().read(0, size as usize)?;
+            Durability::<Ctx, (), SerializableError>::wrap(
+                self,
+                WrappedFunctionType::WriteRemote,
+                "golem blobstore::container::write_data",
+                |ctx| {
+                    ctx.state.blob_store_service.write_data(
+                        account_id.clone(),
+                        container_name.clone(),
+                        name.clone(),
+                        data.clone(),
+                        attributes.clone(),
+                    )
+                },
+            )
+            .await
+        };
         match result {
             Ok(_) => Ok(Ok(())),
             Err(e) => Ok(Err(format!("{:?}", e))),
@@ -278,11 +304,7 @@ impl<Ctx: WorkerCtx> HostContainer for DurableWorkerCtx<Ctx> {
             .table()
             .get::<ContainerEntry>(&container)
             .map(|container_entry| container_entry.name.clone())?;
-        let result = Durability::<
-            Ctx,
-            crate::services::blob_store::ObjectMetadata,
-            SerializableError,
-        >::wrap(
+        let result = Durability::<Ctx, SerializableObjectMetadata, SerializableError>::wrap(
             self,
             WrappedFunctionType::ReadRemote,
             "golem blobstore::container::object_info",
@@ -403,3 +425,94 @@ impl<Ctx: WorkerCtx> HostStreamObjectNames for DurableWorkerCtx<Ctx> {
 
 #[async_trait]
 impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {}
+
+impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
+    /// Uploads an object too large to be written at once as a multipart upload, reading one
+    /// part at a time from the outgoing value. Every step is recorded in its own oplog entry, so
+    /// an interrupted upload continues with the first part that was not written yet.
+    async fn write_data_in_parts(
+        &mut self,
+        account_id: AccountId,
+        container_name: String,
+        object_name: ObjectName,
+        body: Arc<RwLock<OutgoingValueBody>>,
+        attributes: ObjectAttributes,
+        part_size: usize,
+    ) -> anyhow::Result<()> {
+        let size = body.read().unwrap().size();
+        let upload_id = Durability::<Ctx, String, SerializableError>::wrap(
+            self,
+            WrappedFunctionType::WriteRemote,
+            "golem blobstore::container::start_multipart_write",
+            |ctx| {
+                ctx.state.blob_store_service.start_multipart_write(
+                    account_id.clone(),
+                    container_name.clone(),
+                    object_name.clone(),
+                    attributes.clone(),
+                )
+            },
+        )
+        .await?;
+
+        let mut part_tags = Vec::new();
+        let mut offset = 0;
+        while offset < size {
+            let part = body.write().unwrap().read(offset, part_size)?;
+            offset += part.len() as u64;
+            let part_number = part_tags.len() as u64 + 1;
+            let result = Durability::<Ctx, String, SerializableError>::wrap(
+                self,
+                WrappedFunctionType::WriteRemote,
+                "golem blobstore::container::write_part",
+                |ctx| {
+                    ctx.state.blob_store_service.write_part(
+                        account_id.clone(),
+                        container_name.clone(),
+                        object_name.clone(),
+                        upload_id.clone(),
+                        part_number,
+                        part,
+                    )
+                },
+            )
+            .await;
+            match result {
+                Ok(part_tag) => part_tags.push(part_tag),
+                Err(err) => {
+                    let _ = Durability::<Ctx, (), SerializableError>::wrap(
+                        self,
+                        WrappedFunctionType::WriteRemote,
+                        "golem blobstore::container::abort_multipart_write",
+                        |ctx| {
+                            ctx.state.blob_store_service.abort_multipart_write(
+                                account_id.clone(),
+                                container_name.clone(),
+                                object_name.clone(),
+                                upload_id.clone(),
+                            )
+                        },
+                    )
+                    .await;
+                    return Err(err);
+                }
+            }
+        }
+
+        Durability::<Ctx, (), SerializableError>::wrap(
+            self,
+            WrappedFunctionType::WriteRemote,
+            "golem blobstore::container::complete_multipart_write",
+            |ctx| {
+                ctx.state.blob_store_service.complete_multipart_write(
+                    account_id.clone(),
+                    container_name.clone(),
+                    object_name.clone(),
+                    upload_id.clone(),
+                    part_tags,
+                )
+            },
+        )
+        .await
+    }
+}
@@ -13,13 +13,16 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom, Write};
 use std::sync::{Arc, RwLock};
 
+use anyhow::anyhow;
 use async_trait::async_trait;
 use bytes::Bytes;
 use wasmtime::component::Resource;
 use wasmtime_wasi::preview2::{
-    HostInputStream, HostOutputStream, InputStream, StreamResult, Subscribe, WasiView,
+    HostInputStream, HostOutputStream, InputStream, StreamError, StreamResult, Subscribe, WasiView,
 };
 
 use crate::durable_host::DurableWorkerCtx;
@@ -29,16 +32,18 @@ use crate::preview2::wasi::blobstore::types::{
     Error, Host, HostIncomingValue, HostOutgoingValue, IncomingValue, IncomingValueAsyncBody,
     IncomingValueSyncBody, OutputStream as OutgoingValueBodyAsync,
 };
+use crate::services::blob_store::ObjectAttributes;
 use crate::workerctx::WorkerCtx;
 
 #[async_trait]
 impl<Ctx: WorkerCtx> HostOutgoingValue for DurableWorkerCtx<Ctx> {
     async fn new_outgoing_value(&mut self) -> anyhow::Result<Resource<OutgoingValueEntry>> {
         record_host_function_call("blobstore::types::outgoing_value", "new_outgoing_value");
+        let part_size = self.state.config.blob_store.part_size;
         let outgoing_value = self
             .as_wasi_view()
             .table_mut()
-            .push(OutgoingValueEntry::new())?;
+            .push(OutgoingValueEntry::new(part_size))?;
         Ok(outgoing_value)
     }
 
@@ -145,29 +150,87 @@ impl ContainerEntry {
 }
 
 pub struct OutgoingValueEntry {
-    pub body: Arc<RwLock<Vec<u8>>>,
+    pub body: Arc<RwLock<OutgoingValueBody>>,
+    /// The attributes to store with the object the value gets written to
+    pub attributes: ObjectAttributes,
 }
 
-impl Default for OutgoingValueEntry {
-    fn default() -> Self {
-        Self::new()
+impl OutgoingValueEntry {
+    pub fn new(memory_limit: usize) -> Self {
+        Self {
+            body: Arc::new(RwLock::new(OutgoingValueBody::new(memory_limit))),
+            attributes: ObjectAttributes::default(),
+        }
     }
 }
 
-impl OutgoingValueEntry {
-    pub fn new() -> Self {
+/// The data written to an outgoing value. It is kept in memory up to `memory_limit` bytes, and
+/// moved to an anonymous temporary file once it grows larger, so writing big objects does not
+/// need a matching amount of executor memory.
+pub struct OutgoingValueBody {
+    memory: Vec<u8>,
+    file: Option<File>,
+    size: u64,
+    memory_limit: usize,
+}
+
+impl OutgoingValueBody {
+    pub fn new(memory_limit: usize) -> Self {
         Self {
-            body: Arc::new(RwLock::new(Vec::new())),
+            memory: Vec::new(),
+            file: None,
+            size: 0,
+            memory_limit,
+        }
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn write(&mut self, bytes: &[u8]) -> std::io::Result<()> {
+        match &mut self.file {
+            Some(file) => {
+                file.seek(SeekFrom::End(0))?;
+                file.write_all(bytes)?;
+            }
+            None if self.memory.len() + bytes.len() > self.memory_limit => {
+                let mut file = tempfile::tempfile()?;
+                file.write_all(&self.memory)?;
+                file.write_all(bytes)?;
+                self.memory = Vec::new();
+                self.file = Some(file);
+            }
+            None => self.memory.extend_from_slice(bytes),
+        }
+        self.size += bytes.len() as u64;
+        Ok(())
+    }
+
+    /// Reads at most `length` bytes of the data, starting at `offset`
+    pub fn read(&mut self, offset: u64, length: usize) -> std::io::Result<Vec<u8>> {
+        match &mut self.file {
+            Some(file) => {
+                let mut data = Vec::with_capacity(length);
+                file.seek(SeekFrom::Start(offset))?;
+                file.take(length as u64).read_to_end(&mut data)?;
+                Ok(data)
+            }
+            None => {
+                let start = std::cmp::min(offset as usize, self.memory.len());
+                let end = std::cmp::min(start + length, self.memory.len());
+                Ok(self.memory[start..end].to_vec())
+            }
         }
     }
 }
 
 pub struct OutgoingValueEntryStream {
-    pub body: Arc<RwLock<Vec<u8>>>,
+    pub body: Arc<RwLock<OutgoingValueBody>>,
 }
 
 impl OutgoingValueEntryStream {
-    pub fn new(body: Arc<RwLock<Vec<u8>>>) -> Self {
+    pub fn new(body: Arc<RwLock<OutgoingValueBody>>) -> Self {
         Self { body }
     }
 }
@@ -184,8 +247,11 @@ impl HostOutputStream for OutgoingValueEntryStream {
     }
 
     fn write(&mut self, bytes: Bytes) -> StreamResult<()> {
-        self.body.write().unwrap().extend_from_slice(&bytes);
-        Ok(())
+        self.body
+            .write()
+            .unwrap()
+            .write(&bytes)
+            .map_err(|err| StreamError::LastOperationFailed(anyhow!(err)))
     }
 
     fn flush(&mut self) -> StreamResult<()> {
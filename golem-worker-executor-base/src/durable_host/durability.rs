@@ -1,4 +1,4 @@
-use crate::durable_host::DurableWorkerCtx;
+use crate::durable_host::{is_skipped_in_replay, DurableWorkerCtx};
 use crate::error::GolemError;
 use crate::model::PersistenceLevel;
 use crate::workerctx::WorkerCtx;
@@ -126,6 +126,7 @@ impl<Ctx: WorkerCtx, SerializedSuccess: Sync, SerializedErr: Sync>
         SerializedErr:
             Encode + Decode + for<'b> From<&'b Err> + From<GolemError> + Into<Err> + Debug + Send,
     {
+        self.flush_tcp_writes().await?;
         self.state.consume_hint_entries().await;
         let begin_index = self
             .state
@@ -190,6 +191,7 @@ impl<Ctx: WorkerCtx, SerializedSuccess: Sync, SerializedErr: Sync>
         SerializedErr:
             Encode + Decode + for<'b> From<&'b Err> + From<GolemError> + Into<Err> + Debug + Send,
     {
+        self.flush_tcp_writes().await?;
         self.state.consume_hint_entries().await;
         let begin_index = self
             .state
@@ -233,7 +235,118 @@ impl<Ctx: WorkerCtx, SerializedSuccess: Sync, SerializedErr: Sync>
     }
 }
 
+/// The name under which violations of the egress policy are recorded in the oplog
+pub(crate) const EGRESS_VIOLATION_FUNCTION: &str = "golem egress::violation";
+
+/// Names of the durable host functions which are not async, and so record their results with
+/// `wrap_sync` and `custom_wrap_sync`
+const NON_ASYNC_FUNCTIONS: &[&str] = &[
+    EGRESS_VIOLATION_FUNCTION,
+    "sockets::tcp::start_connect",
+    "sockets::tcp::finish_connect",
+    "sockets::tcp::output_stream::check_write",
+];
+
+/// Checks whether an oplog entry has to be read ahead during replay, because a non-async host
+/// function or an HTTP response body stream may need it before the replay reaches it
+pub(crate) fn is_deferred_oplog_entry(entry: &OplogEntry) -> bool {
+    match entry {
+        OplogEntry::ImportedFunctionInvoked { function_name, .. } => {
+            NON_ASYNC_FUNCTIONS.contains(&function_name.as_str())
+        }
+        entry => is_skipped_in_replay(entry),
+    }
+}
+
 impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
+    /// A version of `custom_wrap_sync` for results which can be directly converted to and from
+    /// their serialized form
+    pub fn wrap_sync<SerializedSuccess, SerializedErr, Success, Err, SyncFn>(
+        &mut self,
+        wrapped_function_type: WrappedFunctionType,
+        function_name: &str,
+        function: SyncFn,
+    ) -> Result<Success, Err>
+    where
+        Success: Clone,
+        Err: From<GolemError>,
+        SyncFn: FnOnce(&mut DurableWorkerCtx<Ctx>) -> Result<Success, Err>,
+        SerializedSuccess: Encode + Decode + From<Success> + Into<Success> + Debug,
+        SerializedErr:
+            Encode + Decode + for<'b> From<&'b Err> + From<GolemError> + Into<Err> + Debug,
+    {
+        self.custom_wrap_sync::<SerializedSuccess, SerializedErr, _, _, _, _, _>(
+            wrapped_function_type,
+            function_name,
+            function,
+            |_, success| Ok(success.clone().into()),
+            |_, serialized_success| Ok(serialized_success.into()),
+        )
+    }
+
+    /// Durability for host functions which are not async, and so cannot access the oplog.
+    ///
+    /// In live mode the result is recorded in an oplog entry which gets written before the next
+    /// entry of an async host function. In replay mode the recorded result is taken from the
+    /// oplog entries read ahead while replaying the previous async host function. The function
+    /// has to be listed in `NON_ASYNC_FUNCTIONS`.
+    pub fn custom_wrap_sync<SerializedSuccess, SerializedErr, Success, Err, SyncFn, GetFn, PutFn>(
+        &mut self,
+        wrapped_function_type: WrappedFunctionType,
+        function_name: &str,
+        function: SyncFn,
+        get_serializable: GetFn,
+        put_serializable: PutFn,
+    ) -> Result<Success, Err>
+    where
+        Err: From<GolemError>,
+        SyncFn: FnOnce(&mut DurableWorkerCtx<Ctx>) -> Result<Success, Err>,
+        GetFn: FnOnce(&mut DurableWorkerCtx<Ctx>, &Success) -> Result<SerializedSuccess, Err>,
+        PutFn: FnOnce(&mut DurableWorkerCtx<Ctx>, SerializedSuccess) -> Result<Success, Err>,
+        SerializedSuccess: Encode + Decode + Debug,
+        SerializedErr:
+            Encode + Decode + for<'b> From<&'b Err> + From<GolemError> + Into<Err> + Debug,
+    {
+        debug_assert!(NON_ASYNC_FUNCTIONS.contains(&function_name));
+
+        if self.state.is_live() || self.state.persistence_level == PersistenceLevel::PersistNothing
+        {
+            let result = function(self);
+            if self.state.persistence_level != PersistenceLevel::PersistNothing {
+                let serializable_result: Result<SerializedSuccess, SerializedErr> = result
+                    .as_ref()
+                    .map_err(|err| err.into())
+                    .and_then(|result| get_serializable(self, result).map_err(|err| (&err).into()));
+                let oplog_entry = OplogEntry::imported_function_invoked(
+                    function_name.to_string(),
+                    &serializable_result,
+                    wrapped_function_type,
+                )
+                .unwrap_or_else(|err| {
+                    panic!(
+                        "failed to serialize function response: {:?}: {err}",
+                        serializable_result
+                    )
+                });
+                self.state.defer_oplog_entry(oplog_entry);
+            }
+            result
+        } else {
+            let oplog_entry = self.state.get_deferred_oplog_entry(function_name)?;
+            let response = oplog_entry
+                .payload::<Result<SerializedSuccess, SerializedErr>>()
+                .map_err(|err| {
+                    GolemError::unexpected_oplog_entry("ImportedFunctionInvoked payload", err)
+                })?
+                .unwrap();
+
+            match response {
+                Ok(serialized_success) => put_serializable(self, serialized_success),
+                Err(serialized_err) => Err(serialized_err.into()),
+            }
+        }
+    }
+
     async fn write_to_oplog<SerializedSuccess, Err, SerializedErr>(
         &mut self,
         wrapped_function_type: &WrappedFunctionType,
@@ -270,7 +383,7 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
         Ok(())
     }
 
-    fn validate_oplog_entry(
+    pub(crate) fn validate_oplog_entry(
         oplog_entry: &OplogEntry,
         expected_function_name: &str,
     ) -> Result<(), GolemError> {
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod promises;
+mod saga;
+
 use anyhow::anyhow;
 use async_trait::async_trait;
 use golem_common::config::RetryConfig;
@@ -32,6 +35,7 @@ use crate::preview2::golem;
 use crate::preview2::golem::api::host::{
     ComponentVersion, HostGetWorkers, OplogIndex, PersistenceLevel, RetryPolicy, UpdateMode,
 };
+use crate::services::promise::PromiseResult;
 use crate::workerctx::WorkerCtx;
 use golem_common::model::oplog::{OplogEntry, WrappedFunctionType};
 use golem_common::model::regions::OplogRegion;
@@ -129,12 +133,14 @@ impl GetWorkersEntry {
 impl<Ctx: WorkerCtx> golem::api::host::Host for DurableWorkerCtx<Ctx> {
     async fn golem_create_promise(&mut self) -> Result<golem::api::host::PromiseId, anyhow::Error> {
         record_host_function_call("golem::api", "golem_create_promise");
-        Ok(self
-            .public_state
-            .promise_service
-            .create(&self.worker_id, self.state.current_oplog_index().await)
-            .await
-            .into())
+        let now: chrono::DateTime<chrono::Utc> = self.state.clock.now().into();
+        let deadline = self
+            .state
+            .config
+            .promise_deadlines
+            .default_timeout
+            .map(|timeout| now + chrono::Duration::from_std(timeout).unwrap());
+        Ok(self.state.create_promise(deadline).await.into())
     }
 
     async fn golem_await_promise(
@@ -143,16 +149,18 @@ impl<Ctx: WorkerCtx> golem::api::host::Host for DurableWorkerCtx<Ctx> {
     ) -> Result<Vec<u8>, anyhow::Error> {
         record_host_function_call("golem::api", "golem_await_promise");
         let promise_id: PromiseId = promise_id.into();
-        match self
-            .public_state
-            .promise_service
-            .poll(promise_id.clone())
-            .await?
-        {
-            Some(result) => Ok(result),
-            None => {
-                debug!("Suspending worker until {} gets completed", promise_id);
-                Err(InterruptKind::Suspend.into())
+        match self.await_promise(promise_id.clone()).await? {
+            PromiseResult::Completed(data) => Ok(data),
+            // The golem:api interface has no way to return an error, so failing the promise
+            // fails the awaiting invocation. The `await-promise-result` function of the
+            // golem:ext promises interface returns it to the worker instead.
+            PromiseResult::Failed(error) => Err(GolemError::runtime(format!(
+                "Promise {promise_id} was rejected: {}",
+                String::from_utf8_lossy(&error)
+            ))
+            .into()),
+            PromiseResult::TimedOut => {
+                Err(GolemError::runtime(format!("Promise {promise_id} timed out")).into())
             }
         }
     }
@@ -205,17 +213,17 @@ impl<Ctx: WorkerCtx> golem::api::host::Host for DurableWorkerCtx<Ctx> {
     async fn get_self_uri(
         &mut self,
         function_name: String,
-    ) -> Result<golem::rpc::types::Uri, anyhow::Error> {
+    ) -> Result<golem_wasm_rpc::Uri, anyhow::Error> {
         record_host_function_call("golem::api", "get_self_uri");
-        let uri = golem_wasm_rpc::golem::rpc::types::Uri::golem_uri(
+        Ok(golem_wasm_rpc::Uri::golem_uri(
             &self.state.worker_id,
             Some(&function_name),
-        );
-        Ok(golem::rpc::types::Uri { value: uri.value })
+        ))
     }
 
     async fn get_oplog_index(&mut self) -> anyhow::Result<OplogIndex> {
         record_host_function_call("golem::api", "get_oplog_index");
+        self.state.flush_deferred_oplog_entries().await;
         let result = self.state.current_oplog_index().await;
         if self.state.is_live() {
             self.state.oplog.add(OplogEntry::nop()).await;
@@ -320,6 +328,11 @@ impl<Ctx: WorkerCtx> golem::api::host::Host for DurableWorkerCtx<Ctx> {
                 None => {
                     debug!("Worker {}'s atomic operation starting at {} is not committed, ignoring persisted entries", self.worker_id, begin_index);
 
+                    let (registered_compensations, completed_compensations) = self
+                        .state
+                        .read_compensations(self.state.replay_idx, self.state.replay_target)
+                        .await;
+
                     // We need to jump to the end of the oplog
                     self.state.replay_idx = self.state.replay_target;
 
@@ -335,6 +348,15 @@ impl<Ctx: WorkerCtx> golem::api::host::Host for DurableWorkerCtx<Ctx> {
                         .oplog
                         .add_and_commit(OplogEntry::jump(deleted_region))
                         .await;
+
+                    // Undoing the steps of the abandoned attempt before retrying the region
+                    let compensations = registered_compensations
+                        .into_iter()
+                        .filter(|(region_begin_index, _)| *region_begin_index == begin_index)
+                        .map(|(_, compensation)| compensation)
+                        .collect();
+                    self.run_compensations(compensations, completed_compensations)
+                        .await;
                 }
             }
         }
@@ -352,6 +374,9 @@ impl<Ctx: WorkerCtx> golem::api::host::Host for DurableWorkerCtx<Ctx> {
         } else {
             let _ = get_oplog_entry!(self.state, OplogEntry::EndAtomicRegion)?;
         }
+        self.state
+            .open_compensations
+            .retain(|(region_begin_index, _)| *region_begin_index != begin);
 
         Ok(())
     }
@@ -393,6 +418,7 @@ impl<Ctx: WorkerCtx> golem::api::host::Host for DurableWorkerCtx<Ctx> {
         record_host_function_call("golem::api", "set_oplog_persistence_level");
         // commit all pending entries and change persistence level
         if self.state.is_live() {
+            self.state.flush_deferred_oplog_entries().await;
             self.state.oplog.commit().await;
         }
         self.state.persistence_level = new_persistence_level.into();
@@ -0,0 +1,103 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::{Duration, SystemTime};
+
+use async_trait::async_trait;
+use golem_common::model::oplog::WrappedFunctionType;
+use golem_common::model::PromiseId;
+use tracing::debug;
+
+use crate::durable_host::serialized::SerializableError;
+use crate::durable_host::{Durability, DurableWorkerCtx};
+use crate::metrics::wasm::record_host_function_call;
+use crate::model::InterruptKind;
+use crate::preview2::golem;
+use crate::preview2::golem::ext::promises::{Datetime, Host, PromiseResult as GuestPromiseResult};
+use crate::services::promise::PromiseResult;
+use crate::workerctx::WorkerCtx;
+
+#[async_trait]
+impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
+    async fn create_promise_with_deadline(
+        &mut self,
+        deadline: Datetime,
+    ) -> anyhow::Result<golem::api::host::PromiseId> {
+        record_host_function_call("golem::ext::promises", "create_promise_with_deadline");
+        let deadline: chrono::DateTime<chrono::Utc> =
+            (SystemTime::UNIX_EPOCH + Duration::new(deadline.seconds, deadline.nanoseconds)).into();
+        Ok(self.state.create_promise(Some(deadline)).await.into())
+    }
+
+    async fn reject_promise(
+        &mut self,
+        promise_id: golem::api::host::PromiseId,
+        error: Vec<u8>,
+    ) -> anyhow::Result<bool> {
+        record_host_function_call("golem::ext::promises", "reject_promise");
+        Durability::<Ctx, bool, SerializableError>::wrap(
+            self,
+            WrappedFunctionType::WriteLocal,
+            "golem::ext::promises::reject_promise",
+            |ctx| {
+                Box::pin(async move {
+                    Ok(ctx
+                        .public_state
+                        .promise_service
+                        .fail(promise_id.into(), error)
+                        .await?)
+                })
+            },
+        )
+        .await
+    }
+
+    async fn await_promise_result(
+        &mut self,
+        promise_id: golem::api::host::PromiseId,
+    ) -> anyhow::Result<GuestPromiseResult> {
+        record_host_function_call("golem::ext::promises", "await_promise_result");
+        Ok(match self.await_promise(promise_id.into()).await? {
+            PromiseResult::Completed(data) => GuestPromiseResult::Completed(data),
+            PromiseResult::Failed(error) => GuestPromiseResult::Failed(error),
+            PromiseResult::TimedOut => GuestPromiseResult::TimedOut,
+        })
+    }
+}
+
+impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
+    /// Returns the outcome of a promise, or suspends the worker until it gets one. The promise
+    /// is polled again when the worker is resumed and the awaiting call is replayed.
+    pub(crate) async fn await_promise(
+        &mut self,
+        promise_id: PromiseId,
+    ) -> anyhow::Result<PromiseResult> {
+        match self
+            .public_state
+            .promise_service
+            .poll(promise_id.clone())
+            .await?
+        {
+            Some(result) => Ok(result),
+            None => {
+                debug!("Suspending worker until {} gets completed", promise_id);
+                self.public_state
+                    .promise_service
+                    .set_awaited(promise_id)
+                    .await;
+                Err(InterruptKind::Suspend.into())
+            }
+        }
+    }
+}
@@ -0,0 +1,242 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use golem_common::model::oplog::{Compensation, OplogEntry};
+use golem_common::model::{AccountId, IdempotencyKey, WorkerId, WorkerStatus};
+use golem_wasm_rpc::WitValue;
+use tracing::{debug, warn};
+
+use crate::durable_host::DurableWorkerCtx;
+use crate::error::GolemError;
+use crate::get_oplog_entry;
+use crate::metrics::wasm::record_host_function_call;
+use crate::preview2::golem;
+use crate::preview2::golem::api::host::OplogIndex;
+use crate::preview2::golem::ext::saga::Host;
+use crate::services::rpc::Rpc;
+use crate::services::{HasAll, HasOplogService, HasRpc};
+use crate::workerctx::{ExternalOperations, WorkerCtx};
+
+#[async_trait]
+impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
+    async fn register_compensation(
+        &mut self,
+        begin: OplogIndex,
+        component_id: golem::api::host::ComponentId,
+        worker_name: String,
+        function_name: String,
+        params: Vec<WitValue>,
+    ) -> anyhow::Result<()> {
+        record_host_function_call("golem::ext::saga", "register_compensation");
+
+        let worker_id = WorkerId {
+            component_id: component_id.into(),
+            worker_name,
+        };
+        // The compensations run when the worker failed, or while it is being recovered, so the
+        // worker itself could never handle them
+        if worker_id == self.worker_id {
+            return Err(GolemError::runtime(format!(
+                "Worker {} cannot register a compensation invoking itself",
+                self.worker_id
+            ))
+            .into());
+        }
+
+        self.state.consume_hint_entries().await;
+
+        let compensation = if self.state.is_live() {
+            let compensation = Compensation {
+                idempotency_key: IdempotencyKey::fresh(),
+                worker_id,
+                function_name,
+                function_input: params.into_iter().map(|param| param.into()).collect(),
+            };
+            self.state
+                .oplog
+                .add(OplogEntry::register_compensation(
+                    begin,
+                    compensation.clone(),
+                ))
+                .await;
+            compensation
+        } else {
+            match get_oplog_entry!(self.state, OplogEntry::RegisterCompensation)? {
+                OplogEntry::RegisterCompensation { compensation, .. } => compensation,
+                _ => unreachable!(),
+            }
+        };
+
+        self.state.open_compensations.push((begin, compensation));
+        Ok(())
+    }
+}
+
+impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
+    /// Runs the compensations of the atomic regions left open by an invocation which failed
+    /// finally. They are recorded in a `PendingCompensations` entry first, so the ones not
+    /// completed are resumed by `resume_pending_compensations` if the executor stops meanwhile.
+    pub(crate) async fn run_open_compensations(&mut self) {
+        let open_compensations = std::mem::take(&mut self.state.open_compensations);
+        if let Some(start) = open_compensations
+            .iter()
+            .map(|(begin_index, _)| *begin_index)
+            .min()
+        {
+            let end = self.state.oplog.current_oplog_index().await + 1;
+            let (_, completed) = self.state.read_compensations(start, end).await;
+            let compensations: Vec<Compensation> = open_compensations
+                .into_iter()
+                .map(|(_, compensation)| compensation)
+                .filter(|compensation| !completed.contains(&compensation.idempotency_key))
+                .collect();
+            if !compensations.is_empty() {
+                self.state
+                    .oplog
+                    .add_and_commit(OplogEntry::pending_compensations(compensations.clone()))
+                    .await;
+                self.run_compensations(compensations, completed).await;
+            }
+        }
+    }
+
+    /// Invokes the compensations in reverse registration order, skipping the ones which have
+    /// already been run, and records the outcome of each in the oplog. A failed compensation is
+    /// not retried.
+    pub(crate) async fn run_compensations(
+        &mut self,
+        compensations: Vec<Compensation>,
+        completed: HashSet<IdempotencyKey>,
+    ) {
+        let call_chain = self.outgoing_call_chain();
+        for compensation in compensations.into_iter().rev() {
+            if completed.contains(&compensation.idempotency_key) {
+                debug!(
+                    "Compensation {} of worker {} has already been run",
+                    compensation.idempotency_key, self.worker_id
+                );
+                continue;
+            }
+
+            let idempotency_key = compensation.idempotency_key.clone();
+            let error = invoke_compensation(
+                self.state.rpc.as_ref(),
+                &self.worker_id,
+                compensation,
+                &self.state.account_id,
+                call_chain.clone(),
+            )
+            .await;
+            self.state
+                .oplog
+                .add_and_commit(OplogEntry::compensation_completed(idempotency_key, error))
+                .await;
+        }
+    }
+
+    /// Finishes running the compensations of a worker whose oplog ends with a
+    /// `PendingCompensations` entry, as the executor stopped before they all completed, and
+    /// marks the worker as failed. Returns whether the worker had pending compensations, in
+    /// which case it must not be recovered, as that would retry its failed invocation.
+    pub(crate) async fn resume_pending_compensations<T: HasAll<Ctx> + Send + Sync>(
+        this: &T,
+        worker_id: &WorkerId,
+        account_id: &AccountId,
+    ) -> Result<bool, GolemError> {
+        let mut completed = HashSet::new();
+        let mut idx = this.oplog_service().get_size(worker_id).await;
+        let compensations = loop {
+            if idx == 0 {
+                return Ok(false);
+            }
+            idx -= 1;
+            match this.oplog_service().read(worker_id, idx, 1).await.pop() {
+                Some(OplogEntry::CompensationCompleted {
+                    idempotency_key, ..
+                }) => {
+                    completed.insert(idempotency_key);
+                }
+                Some(OplogEntry::PendingCompensations { compensations, .. }) => {
+                    break compensations;
+                }
+                _ => return Ok(false),
+            }
+        };
+
+        debug!("Resuming the pending compensations of worker {worker_id}");
+        let oplog = this.oplog_service().open(worker_id).await;
+        for compensation in compensations.into_iter().rev() {
+            if completed.contains(&compensation.idempotency_key) {
+                continue;
+            }
+
+            let idempotency_key = compensation.idempotency_key.clone();
+            let error = invoke_compensation(
+                this.rpc().as_ref(),
+                worker_id,
+                compensation,
+                account_id,
+                vec![worker_id.clone()],
+            )
+            .await;
+            oplog
+                .add_and_commit(OplogEntry::compensation_completed(idempotency_key, error))
+                .await;
+        }
+
+        Ctx::set_worker_status(this, worker_id, WorkerStatus::Failed).await?;
+        Ok(true)
+    }
+}
+
+/// Invokes a compensation registered by `worker_id`, returning the error if it failed
+async fn invoke_compensation(
+    rpc: &(dyn Rpc + Send + Sync),
+    worker_id: &WorkerId,
+    compensation: Compensation,
+    account_id: &AccountId,
+    call_chain: Vec<WorkerId>,
+) -> Option<String> {
+    debug!(
+        "Running compensation {} of worker {} by invoking {} on {}",
+        compensation.idempotency_key, worker_id, compensation.function_name, compensation.worker_id
+    );
+    let result = rpc
+        .invoke_and_await(
+            &compensation.worker_id,
+            Some(compensation.idempotency_key.clone()),
+            compensation.function_name,
+            compensation
+                .function_input
+                .into_iter()
+                .map(|value| value.into())
+                .collect(),
+            account_id,
+            call_chain,
+        )
+        .await;
+    match result {
+        Ok(_) => None,
+        Err(err) => {
+            warn!(
+                "Compensation {} of worker {} failed: {err}",
+                compensation.idempotency_key, worker_id
+            );
+            Some(err.to_string())
+        }
+    }
+}
@@ -0,0 +1,408 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Durability of incoming HTTP response bodies.
+//
+// In live mode the reads of a response body stream are collected in memory, and written to the
+// oplog as a single segment entry right before the next oplog entry of the worker. Reading a body
+// in a loop therefore results in a single oplog entry, no matter how many chunks it consists of.
+// Large segments are stored in the blob store, and only referenced from the oplog.
+//
+// During replay the segments are found by reading ahead in the oplog, and the recorded chunks
+// are returned to the worker one by one, in the same way as they were read originally.
+
+use std::collections::{HashMap, VecDeque};
+
+use tracing::warn;
+use wasmtime::component::Resource;
+use wasmtime_wasi::preview2::bindings::wasi::io::streams::{HostInputStream, InputStream};
+use wasmtime_wasi::preview2::StreamError;
+
+use crate::durable_host::http::serialized::{
+    SerializableHttpBodyChunk, SerializableHttpBodyData, SerializableHttpBodySegment,
+};
+use crate::durable_host::serialized::SerializableStreamError;
+use crate::durable_host::{DurableWorkerCtx, PrivateDurableWorkerState};
+use crate::error::GolemError;
+use crate::model::PersistenceLevel;
+use crate::services::blob_store::{executor_account_id, BlobStoreService, ObjectAttributes};
+use crate::workerctx::WorkerCtx;
+use golem_common::model::oplog::{OplogEntry, WrappedFunctionType};
+use golem_common::model::{AccountId, WorkerId};
+
+const HTTP_BODY_SEGMENT_FUNCTION_NAME: &str = "http::types::incoming_body_stream::segment";
+
+/// The blob store container holding the offloaded response body segments of a worker. It is
+/// stored under the executor's own account, so the worker cannot reach it through the blob
+/// store interface.
+fn http_body_container_name(worker_id: &WorkerId) -> String {
+    format!("http-response-bodies:{}", worker_id.to_redis_key())
+}
+
+/// Deletes the offloaded response body segments of a deleted worker
+pub(crate) async fn delete_http_body_blobs(
+    blob_store_service: &(dyn BlobStoreService + Send + Sync),
+    account_id: &AccountId,
+    worker_id: &WorkerId,
+) -> anyhow::Result<()> {
+    let account_id = executor_account_id(account_id);
+    let container_name = http_body_container_name(worker_id);
+    if blob_store_service
+        .container_exists(account_id.clone(), container_name.clone())
+        .await?
+    {
+        blob_store_service
+            .clear(account_id.clone(), container_name.clone())
+            .await?;
+        blob_store_service
+            .delete_container(account_id, container_name)
+            .await?;
+    }
+    Ok(())
+}
+
+/// Checks whether the oplog entry is a segment of an incoming HTTP response body
+pub(crate) fn is_http_body_segment(entry: &OplogEntry) -> bool {
+    matches!(
+        entry,
+        OplogEntry::ImportedFunctionInvoked { function_name, .. }
+            if function_name == HTTP_BODY_SEGMENT_FUNCTION_NAME
+    )
+}
+
+#[derive(Default)]
+pub(crate) struct HttpBodyStream {
+    next_segment: u64,
+    pending_chunks: Vec<SerializableHttpBodyChunk>,
+    pending_data: Vec<u8>,
+    replayed_chunks: VecDeque<ReplayedChunk>,
+    dropped: bool,
+}
+
+enum ReplayedChunk {
+    Read(Vec<u8>),
+    Skipped(u64),
+    Error(SerializableStreamError),
+}
+
+impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
+    pub(crate) async fn read_http_body(
+        &mut self,
+        body_id: u64,
+        stream: Resource<InputStream>,
+        len: u64,
+        blocking: bool,
+    ) -> Result<Vec<u8>, StreamError> {
+        if self.state.is_live() || self.state.persistence_level == PersistenceLevel::PersistNothing
+        {
+            let result = if blocking {
+                HostInputStream::blocking_read(&mut self.as_wasi_view(), stream, len).await
+            } else {
+                HostInputStream::read(&mut self.as_wasi_view(), stream, len).await
+            };
+            if self.state.persistence_level != PersistenceLevel::PersistNothing {
+                match &result {
+                    Ok(bytes) => self.state.record_http_body_chunk(
+                        body_id,
+                        SerializableHttpBodyChunk::Read(bytes.len() as u64),
+                        bytes,
+                    ),
+                    Err(err) => self.state.record_http_body_chunk(
+                        body_id,
+                        SerializableHttpBodyChunk::Error(err.into()),
+                        &[],
+                    ),
+                }
+            }
+            result
+        } else {
+            match self.state.next_replayed_http_body_chunk(body_id).await? {
+                ReplayedChunk::Read(bytes) => Ok(bytes),
+                ReplayedChunk::Skipped(_) => Err(GolemError::unexpected_oplog_entry(
+                    "read of the HTTP response body",
+                    "skip of the HTTP response body",
+                )
+                .into()),
+                ReplayedChunk::Error(err) => Err(err.into()),
+            }
+        }
+    }
+
+    pub(crate) async fn skip_http_body(
+        &mut self,
+        body_id: u64,
+        stream: Resource<InputStream>,
+        len: u64,
+        blocking: bool,
+    ) -> Result<u64, StreamError> {
+        if self.state.is_live() || self.state.persistence_level == PersistenceLevel::PersistNothing
+        {
+            let result = if blocking {
+                HostInputStream::blocking_skip(&mut self.as_wasi_view(), stream, len).await
+            } else {
+                HostInputStream::skip(&mut self.as_wasi_view(), stream, len).await
+            };
+            if self.state.persistence_level != PersistenceLevel::PersistNothing {
+                match &result {
+                    Ok(skipped) => self.state.record_http_body_chunk(
+                        body_id,
+                        SerializableHttpBodyChunk::Skipped(*skipped),
+                        &[],
+                    ),
+                    Err(err) => self.state.record_http_body_chunk(
+                        body_id,
+                        SerializableHttpBodyChunk::Error(err.into()),
+                        &[],
+                    ),
+                }
+            }
+            result
+        } else {
+            match self.state.next_replayed_http_body_chunk(body_id).await? {
+                ReplayedChunk::Read(bytes) => Ok(bytes.len() as u64),
+                ReplayedChunk::Skipped(skipped) => Ok(skipped),
+                ReplayedChunk::Error(err) => Err(err.into()),
+            }
+        }
+    }
+}
+
+impl<Ctx: WorkerCtx> PrivateDurableWorkerState<Ctx> {
+    /// Associates a resource belonging to an incoming HTTP response (the response itself, its
+    /// body or the body's stream) with the response body's id
+    pub(crate) fn register_http_body(&mut self, rep: u32, body_id: u64) {
+        self.http_body_ids.insert(rep, body_id);
+    }
+
+    pub(crate) fn forget_http_body(&mut self, rep: u32) {
+        self.http_body_ids.remove(&rep);
+    }
+
+    pub(crate) fn http_body_id(&self, rep: u32) -> Option<u64> {
+        self.http_body_ids.get(&rep).copied()
+    }
+
+    /// Forgets a dropped response body stream once all of its reads have been written to the oplog
+    pub(crate) fn drop_http_body_stream(&mut self, rep: u32) {
+        if let Some(body_id) = self.http_body_ids.remove(&rep) {
+            if let Some(stream) = self.http_body_streams.get_mut(&body_id) {
+                if stream.pending_chunks.is_empty() {
+                    self.http_body_streams.remove(&body_id);
+                } else {
+                    stream.dropped = true;
+                }
+            }
+        }
+    }
+
+    fn record_http_body_chunk(
+        &mut self,
+        body_id: u64,
+        chunk: SerializableHttpBodyChunk,
+        data: &[u8],
+    ) {
+        let stream = self.http_body_streams.entry(body_id).or_default();
+        stream.pending_chunks.push(chunk);
+        stream.pending_data.extend_from_slice(data);
+    }
+
+    /// Writes the reads of the response bodies performed since the previous oplog entry
+    pub(crate) async fn flush_http_body_segments(&mut self) {
+        let body_ids = self
+            .http_body_streams
+            .iter()
+            .filter(|(_, stream)| !stream.pending_chunks.is_empty())
+            .map(|(body_id, _)| *body_id)
+            .collect::<Vec<_>>();
+
+        for body_id in body_ids {
+            let stream = self.http_body_streams.get_mut(&body_id).unwrap();
+            let segment = stream.next_segment;
+            stream.next_segment += 1;
+            let chunks = std::mem::take(&mut stream.pending_chunks);
+            let data = std::mem::take(&mut stream.pending_data);
+            if stream.dropped {
+                self.http_body_streams.remove(&body_id);
+            }
+
+            let data = self.store_http_body_data(body_id, segment, data).await;
+            let serializable_segment = SerializableHttpBodySegment {
+                body_id,
+                segment,
+                chunks,
+                data,
+            };
+            let oplog_entry = OplogEntry::imported_function_invoked(
+                HTTP_BODY_SEGMENT_FUNCTION_NAME.to_string(),
+                &serializable_segment,
+                WrappedFunctionType::ReadRemote,
+            )
+            .unwrap_or_else(|err| panic!("failed to serialize http response body: {err}"));
+            self.oplog.add(oplog_entry).await;
+        }
+    }
+
+    async fn store_http_body_data(
+        &self,
+        body_id: u64,
+        segment: u64,
+        data: Vec<u8>,
+    ) -> SerializableHttpBodyData {
+        match self.config.oplog.http_body_blob_threshold {
+            Some(threshold) if data.len() > threshold => {
+                let object_name = format!("{body_id}-{segment}");
+                let size = data.len() as u64;
+                let attributes = ObjectAttributes {
+                    content_type: Some("application/octet-stream".to_string()),
+                    metadata: HashMap::from([
+                        ("worker-id".to_string(), self.worker_id.to_string()),
+                        ("body-id".to_string(), body_id.to_string()),
+                        ("segment".to_string(), segment.to_string()),
+                    ]),
+                };
+                match self
+                    .write_http_body_blob(object_name.clone(), data.clone(), attributes)
+                    .await
+                {
+                    Ok(()) => SerializableHttpBodyData::Blob {
+                        container_name: http_body_container_name(&self.worker_id),
+                        object_name,
+                        size,
+                    },
+                    Err(err) => {
+                        warn!(
+                            "Failed to store HTTP response body of {} in the blob store, storing it in the oplog: {err}",
+                            self.worker_id
+                        );
+                        SerializableHttpBodyData::Inline(data)
+                    }
+                }
+            }
+            _ => SerializableHttpBodyData::Inline(data),
+        }
+    }
+
+    async fn write_http_body_blob(
+        &self,
+        object_name: String,
+        data: Vec<u8>,
+        attributes: ObjectAttributes,
+    ) -> anyhow::Result<()> {
+        let account_id = executor_account_id(&self.account_id);
+        let container_name = http_body_container_name(&self.worker_id);
+        if !self
+            .blob_store_service
+            .container_exists(account_id.clone(), container_name.clone())
+            .await?
+        {
+            self.blob_store_service
+                .create_container(account_id.clone(), container_name.clone())
+                .await?;
+        }
+        self.blob_store_service
+            .write_data(account_id, container_name, object_name, data, attributes)
+            .await
+    }
+
+    async fn next_replayed_http_body_chunk(
+        &mut self,
+        body_id: u64,
+    ) -> Result<ReplayedChunk, GolemError> {
+        loop {
+            let stream = self.http_body_streams.entry(body_id).or_default();
+            if let Some(chunk) = stream.replayed_chunks.pop_front() {
+                break Ok(chunk);
+            }
+            let segment = stream.next_segment;
+            let serialized_segment = self.find_http_body_segment(body_id, segment).await?;
+            let chunks = self.load_http_body_segment(serialized_segment).await?;
+
+            let stream = self.http_body_streams.entry(body_id).or_default();
+            stream.next_segment += 1;
+            stream.replayed_chunks = chunks;
+        }
+    }
+
+    /// Finds a segment of a response body in the oplog entries following the current replay
+    /// position. The segment entries themselves are skipped when the replay reaches them.
+    async fn find_http_body_segment(
+        &mut self,
+        body_id: u64,
+        segment: u64,
+    ) -> Result<SerializableHttpBodySegment, GolemError> {
+        self.prefetch_deferred_oplog_entries().await;
+        for (_, oplog_entry) in &self.prefetched_oplog_entries {
+            if is_http_body_segment(oplog_entry) {
+                let serialized_segment = oplog_entry
+                    .payload::<SerializableHttpBodySegment>()
+                    .map_err(|err| {
+                        GolemError::unexpected_oplog_entry("HTTP response body segment", err)
+                    })?
+                    .unwrap();
+                if serialized_segment.body_id == body_id && serialized_segment.segment == segment {
+                    return Ok(serialized_segment);
+                }
+            }
+        }
+        Err(GolemError::runtime(format!(
+            "Segment {segment} of HTTP response body {body_id} was not found in the oplog"
+        )))
+    }
+
+    async fn load_http_body_segment(
+        &self,
+        segment: SerializableHttpBodySegment,
+    ) -> Result<VecDeque<ReplayedChunk>, GolemError> {
+        let data = match segment.data {
+            SerializableHttpBodyData::Inline(data) => data,
+            SerializableHttpBodyData::Blob {
+                container_name,
+                object_name,
+                size,
+            } => self
+                .blob_store_service
+                .get_data(
+                    executor_account_id(&self.account_id),
+                    container_name,
+                    object_name,
+                    0,
+                    size,
+                )
+                .await
+                .map_err(|err| {
+                    GolemError::runtime(format!(
+                        "Failed to read HTTP response body from the blob store: {err}"
+                    ))
+                })?,
+        };
+
+        let mut offset = 0;
+        let mut chunks = VecDeque::new();
+        for chunk in segment.chunks {
+            chunks.push_back(match chunk {
+                SerializableHttpBodyChunk::Read(len) => {
+                    let end = offset + len as usize;
+                    let bytes = data.get(offset..end).ok_or_else(|| {
+                        GolemError::runtime("HTTP response body segment is shorter than recorded")
+                    })?;
+                    offset = end;
+                    ReplayedChunk::Read(bytes.to_vec())
+                }
+                SerializableHttpBodyChunk::Skipped(skipped) => ReplayedChunk::Skipped(skipped),
+                SerializableHttpBodyChunk::Error(err) => ReplayedChunk::Error(err),
+            });
+        }
+        Ok(chunks)
+    }
+}
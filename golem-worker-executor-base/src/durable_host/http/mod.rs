@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod body;
 pub mod outgoing_http;
 
 /// Serializable response data structures to be stored in the oplog
@@ -12,11 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::net::IpAddr;
+
 use async_trait::async_trait;
+use http::uri::Authority;
+use tracing::debug;
 use wasmtime::component::Resource;
-use wasmtime_wasi_http::bindings::http::types::ErrorCode;
+use wasmtime_wasi_http::bindings::http::types::{ErrorCode, Scheme};
 
 use crate::durable_host::DurableWorkerCtx;
+use crate::egress::EgressViolation;
 use crate::metrics::wasm::record_host_function_call;
 use crate::workerctx::WorkerCtx;
 use golem_common::model::oplog::WrappedFunctionType;
@@ -32,6 +37,12 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
         options: Option<Resource<RequestOptions>>,
     ) -> anyhow::Result<Result<Resource<FutureIncomingResponse>, ErrorCode>> {
         record_host_function_call("http::outgoing_handler", "handle");
+
+        if self.check_request_egress(&request).await?.is_err() {
+            self.table.delete(request)?;
+            return Ok(Err(ErrorCode::HttpRequestDenied));
+        }
+
         // Durability is handled by the WasiHttpView send_request method and the follow-up calls to await/poll the response future
         let begin_index = self
             .state
@@ -56,3 +67,52 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
         Ok(result)
     }
 }
+
+impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
+    async fn check_request_egress(
+        &mut self,
+        request: &Resource<OutgoingRequest>,
+    ) -> anyhow::Result<Result<(), EgressViolation>> {
+        let request = self.table.get(request)?;
+        // Requests without a valid authority are rejected by the outgoing handler itself
+        let authority = match request
+            .authority
+            .as_ref()
+            .and_then(|authority| authority.parse::<Authority>().ok())
+        {
+            Some(authority) => authority,
+            None => return Ok(Ok(())),
+        };
+        let port = authority.port_u16().unwrap_or(match request.scheme {
+            Some(Scheme::Http) => 80,
+            _ => 443,
+        });
+        let addresses = if self.state.is_live() {
+            self.resolve_for_egress(authority.host(), port).await
+        } else {
+            vec![]
+        };
+        Ok(self
+            .check_egress(|policy| policy.check_resolved_host(authority.host(), &addresses, port)))
+    }
+
+    /// Resolves the host name of an outgoing request if the egress policy has rules for IP
+    /// addresses. Resolution failures are not reported here, as the request itself fails.
+    async fn resolve_for_egress(&self, host: &str, port: u16) -> Vec<IpAddr> {
+        let policy = self
+            .state
+            .config
+            .egress
+            .policy(&self.worker_id.component_id, &self.state.account_id);
+        if !policy.has_address_rules() || host.parse::<IpAddr>().is_ok() {
+            return vec![];
+        }
+        match tokio::net::lookup_host((host, port)).await {
+            Ok(addresses) => addresses.map(|address| address.ip()).collect(),
+            Err(err) => {
+                debug!("Failed to resolve {host} for checking the egress policy: {err}");
+                vec![]
+            }
+        }
+    }
+}
@@ -19,7 +19,7 @@ use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::Arc;
 
-use crate::durable_host::serialized::SerializableError;
+use crate::durable_host::serialized::{SerializableError, SerializableStreamError};
 use wasmtime_wasi_http::bindings::http::types::{
     DnsErrorPayload, ErrorCode, FieldSizePayload, TlsAlertReceivedPayload,
 };
@@ -123,6 +123,38 @@ impl TryFrom<SerializableResponseHeaders> for HostIncomingResponse {
     }
 }
 
+/// The reads performed on an incoming response body between two other oplog entries.
+///
+/// A body read in a loop is recorded as a single segment, segments are only split when the
+/// worker writes some other oplog entry in the middle of reading the body.
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct SerializableHttpBodySegment {
+    /// The oplog index of the `future_incoming_response::get` entry which returned the response
+    pub body_id: u64,
+    pub segment: u64,
+    /// The result of each read, in the order they were performed
+    pub chunks: Vec<SerializableHttpBodyChunk>,
+    /// The concatenated bytes of all the `Read` chunks
+    pub data: SerializableHttpBodyData,
+}
+
+#[derive(Debug, Clone, Encode, Decode)]
+pub enum SerializableHttpBodyChunk {
+    Read(u64),
+    Skipped(u64),
+    Error(SerializableStreamError),
+}
+
+#[derive(Debug, Clone, Encode, Decode)]
+pub enum SerializableHttpBodyData {
+    Inline(Vec<u8>),
+    Blob {
+        container_name: String,
+        object_name: String,
+        size: u64,
+    },
+}
+
 #[derive(Debug, Clone, Encode, Decode)]
 pub struct SerializableTlsAlertReceivedPayload {
     pub alert_id: Option<u8>,
@@ -348,11 +348,17 @@ impl<Ctx: WorkerCtx> HostIncomingResponse for DurableWorkerCtx<Ctx> {
         self_: Resource<IncomingResponse>,
     ) -> anyhow::Result<Result<Resource<IncomingBody>, ()>> {
         record_host_function_call("http::types::incoming_response", "consume");
-        HostIncomingResponse::consume(&mut self.as_wasi_http_view(), self_)
+        let body_id = self.state.http_body_id(self_.rep());
+        let result = HostIncomingResponse::consume(&mut self.as_wasi_http_view(), self_)?;
+        if let (Some(body_id), Ok(body)) = (body_id, &result) {
+            self.state.register_http_body(body.rep(), body_id);
+        }
+        Ok(result)
     }
 
     fn drop(&mut self, rep: Resource<IncomingResponse>) -> anyhow::Result<()> {
         record_host_function_call("http::types::incoming_response", "drop");
+        self.state.forget_http_body(rep.rep());
         HostIncomingResponse::drop(&mut self.as_wasi_http_view(), rep)
     }
 }
@@ -364,16 +370,23 @@ impl<Ctx: WorkerCtx> HostIncomingBody for DurableWorkerCtx<Ctx> {
         self_: Resource<IncomingBody>,
     ) -> anyhow::Result<Result<Resource<InputStream>, ()>> {
         record_host_function_call("http::types::incoming_body", "stream");
-        HostIncomingBody::stream(&mut self.as_wasi_http_view(), self_)
+        let body_id = self.state.http_body_id(self_.rep());
+        let result = HostIncomingBody::stream(&mut self.as_wasi_http_view(), self_)?;
+        if let (Some(body_id), Ok(stream)) = (body_id, &result) {
+            self.state.register_http_body(stream.rep(), body_id);
+        }
+        Ok(result)
     }
 
     fn finish(&mut self, this: Resource<IncomingBody>) -> anyhow::Result<Resource<FutureTrailers>> {
         record_host_function_call("http::types::incoming_body", "finish");
+        self.state.forget_http_body(this.rep());
         HostIncomingBody::finish(&mut self.as_wasi_http_view(), this)
     }
 
     fn drop(&mut self, rep: Resource<IncomingBody>) -> anyhow::Result<()> {
         record_host_function_call("http::types::incoming_body", "drop");
+        self.state.forget_http_body(rep.rep());
         HostIncomingBody::drop(&mut self.as_wasi_http_view(), rep)
     }
 }
@@ -547,9 +560,10 @@ impl<Ctx: WorkerCtx> HostFutureIncomingResponse for DurableWorkerCtx<Ctx> {
         // In live mode the underlying implementation is either polling the response future, or, if it was Deferred
         // (when the request was initiated in replay mode), it starts executing the deferred request and returns None.
         //
-        // Note that the response body is streaming, so at this point we don't have it in memory. Each chunk read from
-        // the body is stored in the oplog, so we can replay it later. In replay mode we initialize the body with a
-        // fake stream which can only be read in the oplog, and fails if we try to read it in live mode.
+        // Note that the response body is streaming, so at this point we don't have it in memory. The chunks read from
+        // the body are stored in the oplog in aggregated segments (see the `body` module), identified by the oplog
+        // index of this entry. In replay mode we initialize the body with a fake stream which is served from the
+        // recorded segments, and fails if we try to read it in live mode.
         self.state.consume_hint_entries().await;
         let handle = self_.rep();
         if self.state.is_live() || self.state.persistence_level == PersistenceLevel::PersistNothing
@@ -581,6 +595,11 @@ impl<Ctx: WorkerCtx> HostFutureIncomingResponse for DurableWorkerCtx<Ctx> {
                 .unwrap_or_else(|err| panic!("failed to serialize http response: {err}"));
                 self.state.oplog.add(oplog_entry).await;
 
+                if let Ok(Some(Ok(Ok(resource)))) = &response {
+                    let body_id = self.state.current_oplog_index().await;
+                    self.state.register_http_body(resource.rep(), body_id);
+                }
+
                 if matches!(serializable_response, SerializableResponse::Pending) {
                     match self.state.open_function_table.get(&handle) {
                         Some(begin_index) => {
@@ -610,6 +629,7 @@ impl<Ctx: WorkerCtx> HostFutureIncomingResponse for DurableWorkerCtx<Ctx> {
                     )
                 })
                 .unwrap();
+            let body_id = self.state.current_oplog_index().await;
 
             if matches!(serialized_response, SerializableResponse::Pending) {
                 match self.state.open_function_table.get(&handle) {
@@ -632,6 +652,7 @@ impl<Ctx: WorkerCtx> HostFutureIncomingResponse for DurableWorkerCtx<Ctx> {
                         serializable_response_headers.try_into()?;
 
                     let rep = self.table.push(incoming_response)?;
+                    self.state.register_http_body(rep.rep(), body_id);
                     Ok(Some(Ok(Ok(rep))))
                 }
                 SerializableResponse::InternalError(None) => Ok(Some(Err(()))),
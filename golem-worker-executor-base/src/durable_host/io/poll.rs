@@ -14,7 +14,7 @@
 
 use crate::model::InterruptKind;
 use async_trait::async_trait;
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
 use wasmtime::component::Resource;
 use wasmtime_wasi::preview2::bindings::wasi::io::poll::{Host, HostPollable, Pollable};
 
@@ -26,6 +26,7 @@ use crate::workerctx::WorkerCtx;
 impl<Ctx: WorkerCtx> HostPollable for DurableWorkerCtx<Ctx> {
     async fn ready(&mut self, self_: Resource<Pollable>) -> anyhow::Result<bool> {
         record_host_function_call("io::poll:pollable", "ready");
+        self.flush_tcp_writes().await?;
         HostPollable::ready(&mut self.as_wasi_view(), self_).await
     }
 
@@ -46,11 +47,14 @@ impl<Ctx: WorkerCtx> HostPollable for DurableWorkerCtx<Ctx> {
 impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
     async fn poll(&mut self, in_: Vec<Resource<Pollable>>) -> anyhow::Result<Vec<u32>> {
         record_host_function_call("io::poll", "poll");
+        // Written TCP data has to be sent before waiting for a response to it
+        self.flush_tcp_writes().await?;
         let result = Host::poll(&mut self.as_wasi_view(), in_).await;
 
         match is_suspend_for_sleep(&result) {
             Some(duration) => {
-                self.state.sleep_until(Utc::now() + duration).await?;
+                let now: DateTime<Utc> = self.state.clock.now().into();
+                self.state.sleep_until(now + duration).await?;
                 Err(InterruptKind::Suspend.into())
             }
             None => result,
@@ -38,7 +38,9 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
         len: u64,
     ) -> Result<Vec<u8>, StreamError> {
         record_host_function_call("io::streams::input_stream", "read");
-        if is_incoming_http_body_stream(&self.table, &self_) {
+        if let Some(body_id) = self.state.http_body_id(self_.rep()) {
+            self.read_http_body(body_id, self_, len, false).await
+        } else if is_incoming_http_body_stream(&self.table, &self_) {
             Durability::<Ctx, Vec<u8>, SerializableStreamError>::wrap(
                 self,
                 WrappedFunctionType::ReadRemote,
@@ -50,6 +52,18 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
                 },
             )
             .await
+        } else if self.state.tcp_streams.contains(&self_.rep()) {
+            Durability::<Ctx, Vec<u8>, SerializableStreamError>::wrap(
+                self,
+                WrappedFunctionType::ReadRemote,
+                "sockets::tcp::input_stream::read",
+                |ctx| {
+                    Box::pin(async move {
+                        HostInputStream::read(&mut ctx.as_wasi_view(), self_, len).await
+                    })
+                },
+            )
+            .await
         } else {
             HostInputStream::read(&mut self.as_wasi_view(), self_, len).await
         }
@@ -61,7 +75,9 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
         len: u64,
     ) -> Result<Vec<u8>, StreamError> {
         record_host_function_call("io::streams::input_stream", "blocking_read");
-        if is_incoming_http_body_stream(&self.table, &self_) {
+        if let Some(body_id) = self.state.http_body_id(self_.rep()) {
+            self.read_http_body(body_id, self_, len, true).await
+        } else if is_incoming_http_body_stream(&self.table, &self_) {
             Durability::<Ctx, Vec<u8>, SerializableStreamError>::wrap(
                 self,
                 WrappedFunctionType::ReadRemote,
@@ -73,6 +89,18 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
                 },
             )
             .await
+        } else if self.state.tcp_streams.contains(&self_.rep()) {
+            Durability::<Ctx, Vec<u8>, SerializableStreamError>::wrap(
+                self,
+                WrappedFunctionType::ReadRemote,
+                "sockets::tcp::input_stream::blocking_read",
+                |ctx| {
+                    Box::pin(async move {
+                        HostInputStream::blocking_read(&mut ctx.as_wasi_view(), self_, len).await
+                    })
+                },
+            )
+            .await
         } else {
             HostInputStream::blocking_read(&mut self.as_wasi_view(), self_, len).await
         }
@@ -80,7 +108,9 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
 
     async fn skip(&mut self, self_: Resource<InputStream>, len: u64) -> Result<u64, StreamError> {
         record_host_function_call("io::streams::input_stream", "skip");
-        if is_incoming_http_body_stream(&self.table, &self_) {
+        if let Some(body_id) = self.state.http_body_id(self_.rep()) {
+            self.skip_http_body(body_id, self_, len, false).await
+        } else if is_incoming_http_body_stream(&self.table, &self_) {
             Durability::<Ctx, u64, SerializableStreamError>::wrap(
                 self,
                 WrappedFunctionType::ReadRemote,
@@ -92,6 +122,18 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
                 },
             )
             .await
+        } else if self.state.tcp_streams.contains(&self_.rep()) {
+            Durability::<Ctx, u64, SerializableStreamError>::wrap(
+                self,
+                WrappedFunctionType::ReadRemote,
+                "sockets::tcp::input_stream::skip",
+                |ctx| {
+                    Box::pin(async move {
+                        HostInputStream::skip(&mut ctx.as_wasi_view(), self_, len).await
+                    })
+                },
+            )
+            .await
         } else {
             HostInputStream::skip(&mut self.as_wasi_view(), self_, len).await
         }
@@ -103,7 +145,9 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
         len: u64,
     ) -> Result<u64, StreamError> {
         record_host_function_call("io::streams::input_stream", "blocking_skip");
-        if is_incoming_http_body_stream(&self.table, &self_) {
+        if let Some(body_id) = self.state.http_body_id(self_.rep()) {
+            self.skip_http_body(body_id, self_, len, true).await
+        } else if is_incoming_http_body_stream(&self.table, &self_) {
             Durability::<Ctx, u64, SerializableStreamError>::wrap(
                 self,
                 WrappedFunctionType::ReadRemote,
@@ -115,6 +159,18 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
                 },
             )
             .await
+        } else if self.state.tcp_streams.contains(&self_.rep()) {
+            Durability::<Ctx, u64, SerializableStreamError>::wrap(
+                self,
+                WrappedFunctionType::ReadRemote,
+                "sockets::tcp::input_stream::blocking_skip",
+                |ctx| {
+                    Box::pin(async move {
+                        HostInputStream::blocking_skip(&mut ctx.as_wasi_view(), self_, len).await
+                    })
+                },
+            )
+            .await
         } else {
             HostInputStream::blocking_skip(&mut self.as_wasi_view(), self_, len).await
         }
@@ -127,6 +183,8 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
 
     fn drop(&mut self, rep: Resource<InputStream>) -> anyhow::Result<()> {
         record_host_function_call("io::streams::input_stream", "drop");
+        self.state.tcp_streams.remove(&rep.rep());
+        self.state.drop_http_body_stream(rep.rep());
         HostInputStream::drop(&mut self.as_wasi_view(), rep)
     }
 }
@@ -135,7 +193,18 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
 impl<Ctx: WorkerCtx> HostOutputStream for DurableWorkerCtx<Ctx> {
     fn check_write(&mut self, self_: Resource<OutputStream>) -> Result<u64, StreamError> {
         record_host_function_call("io::streams::output_stream", "check_write");
-        HostOutputStream::check_write(&mut self.as_wasi_view(), self_)
+        if self.state.tcp_streams.contains(&self_.rep()) {
+            self.wrap_sync::<u64, SerializableStreamError, _, _, _>(
+                WrappedFunctionType::ReadLocal,
+                "sockets::tcp::output_stream::check_write",
+                |ctx| {
+                    ctx.tcp_write_error(self_.rep())?;
+                    HostOutputStream::check_write(&mut ctx.as_wasi_view(), self_)
+                },
+            )
+        } else {
+            HostOutputStream::check_write(&mut self.as_wasi_view(), self_)
+        }
     }
 
     fn write(
@@ -145,6 +214,10 @@ impl<Ctx: WorkerCtx> HostOutputStream for DurableWorkerCtx<Ctx> {
     ) -> Result<(), StreamError> {
         record_host_function_call("io::streams::output_stream", "write");
 
+        if self.state.tcp_streams.contains(&self_.rep()) {
+            return self.stage_tcp_write(self_.rep(), &contents);
+        }
+
         let event_service = &self.public_state.event_service;
 
         let mut is_std = false;
@@ -177,6 +250,12 @@ impl<Ctx: WorkerCtx> HostOutputStream for DurableWorkerCtx<Ctx> {
     ) -> Result<(), StreamError> {
         record_host_function_call("io::streams::output_stream", "blocking_write_and_flush");
 
+        if self.state.tcp_streams.contains(&self_.rep()) {
+            self.stage_tcp_write(self_.rep(), &contents)?;
+            self.flush_tcp_writes().await?;
+            return self.tcp_write_error(self_.rep());
+        }
+
         let event_service = &self.public_state.event_service;
 
         let mut is_std = false;
@@ -205,12 +284,22 @@ impl<Ctx: WorkerCtx> HostOutputStream for DurableWorkerCtx<Ctx> {
 
     fn flush(&mut self, self_: Resource<OutputStream>) -> Result<(), StreamError> {
         record_host_function_call("io::streams::output_stream", "flush");
-        HostOutputStream::flush(&mut self.as_wasi_view(), self_)
+        if self.state.tcp_streams.contains(&self_.rep()) {
+            // The pending writes get sent and flushed by the next async host function
+            self.tcp_write_error(self_.rep())
+        } else {
+            HostOutputStream::flush(&mut self.as_wasi_view(), self_)
+        }
     }
 
     async fn blocking_flush(&mut self, self_: Resource<OutputStream>) -> Result<(), StreamError> {
         record_host_function_call("io::streams::output_stream", "blocking_flush");
-        HostOutputStream::blocking_flush(&mut self.as_wasi_view(), self_).await
+        if self.state.tcp_streams.contains(&self_.rep()) {
+            self.flush_tcp_writes().await?;
+            self.tcp_write_error(self_.rep())
+        } else {
+            HostOutputStream::blocking_flush(&mut self.as_wasi_view(), self_).await
+        }
     }
 
     fn subscribe(&mut self, self_: Resource<OutputStream>) -> anyhow::Result<Resource<Pollable>> {
@@ -220,7 +309,11 @@ impl<Ctx: WorkerCtx> HostOutputStream for DurableWorkerCtx<Ctx> {
 
     fn write_zeroes(&mut self, self_: Resource<OutputStream>, len: u64) -> Result<(), StreamError> {
         record_host_function_call("io::streams::output_stream", "write_zeroeas");
-        HostOutputStream::write_zeroes(&mut self.as_wasi_view(), self_, len)
+        if self.state.tcp_streams.contains(&self_.rep()) {
+            self.stage_tcp_write(self_.rep(), &vec![0; len as usize])
+        } else {
+            HostOutputStream::write_zeroes(&mut self.as_wasi_view(), self_, len)
+        }
     }
 
     async fn blocking_write_zeroes_and_flush(
@@ -232,8 +325,14 @@ impl<Ctx: WorkerCtx> HostOutputStream for DurableWorkerCtx<Ctx> {
             "io::streams::output_stream",
             "blocking_write_zeroes_and_flush",
         );
-        HostOutputStream::blocking_write_zeroes_and_flush(&mut self.as_wasi_view(), self_, len)
-            .await
+        if self.state.tcp_streams.contains(&self_.rep()) {
+            self.stage_tcp_write(self_.rep(), &vec![0; len as usize])?;
+            self.flush_tcp_writes().await?;
+            self.tcp_write_error(self_.rep())
+        } else {
+            HostOutputStream::blocking_write_zeroes_and_flush(&mut self.as_wasi_view(), self_, len)
+                .await
+        }
     }
 
     async fn splice(
@@ -258,6 +357,9 @@ impl<Ctx: WorkerCtx> HostOutputStream for DurableWorkerCtx<Ctx> {
 
     fn drop(&mut self, rep: Resource<OutputStream>) -> anyhow::Result<()> {
         record_host_function_call("io::streams::output_stream", "drop");
+        if self.state.tcp_streams.remove(&rep.rep()) && self.keep_dropped_tcp_stream(&rep)? {
+            return Ok(());
+        }
         HostOutputStream::drop(&mut self.as_wasi_view(), rep)
     }
 }
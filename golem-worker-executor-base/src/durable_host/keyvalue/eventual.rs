@@ -94,6 +94,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
             .read()
             .unwrap()
             .clone();
+        let ttl = self.state.config.key_value.ttl(&bucket);
         let result = Durability::<Ctx, (), SerializableError>::wrap(
             self,
             WrappedFunctionType::WriteRemote,
@@ -104,6 +105,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
                     bucket.clone(),
                     key.clone(),
                     outgoing_value.clone(),
+                    ttl,
                 )
             },
         )
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+
+use anyhow::anyhow;
 use async_trait::async_trait;
 use golem_common::model::oplog::WrappedFunctionType;
 use wasmtime::component::Resource;
@@ -90,25 +93,44 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
         bucket: Resource<Bucket>,
     ) -> anyhow::Result<Result<Vec<Key>, Resource<Error>>> {
         record_host_function_call("keyvalue::eventual_batch", "get_keys");
-        let account_id = self.state.account_id.clone();
         let bucket = self
             .as_wasi_view()
             .table()
             .get::<BucketEntry>(&bucket)?
             .name
             .clone();
-        let keys = Durability::<Ctx, Vec<String>, SerializableError>::wrap(
-            self,
-            WrappedFunctionType::ReadRemote,
-            "golem keyvalue::eventual_batch::get_keys",
-            |ctx| {
-                ctx.state
-                    .key_value_service
-                    .get_keys(account_id.clone(), bucket.clone())
-            },
-        )
-        .await?;
-        Ok(Ok(keys))
+        let result = if self.state.is_replay()
+            && self.state.peek_imported_function_name().await.as_deref()
+                == Some(LEGACY_GET_KEYS_FUNCTION)
+        {
+            // Oplogs written before keys were listed page by page record the whole listing in
+            // a single entry
+            Durability::<Ctx, Vec<String>, SerializableError>::wrap(
+                self,
+                WrappedFunctionType::ReadRemote,
+                LEGACY_GET_KEYS_FUNCTION,
+                |_ctx| {
+                    Box::pin(async {
+                        Err(anyhow!(
+                            "Keys are only listed in a single oplog entry in old oplogs"
+                        ))
+                    })
+                },
+            )
+            .await
+        } else {
+            self.get_all_keys(bucket).await
+        };
+        match result {
+            Ok(keys) => Ok(Ok(keys)),
+            Err(e) => {
+                let error = self
+                    .as_wasi_view()
+                    .table_mut()
+                    .push(ErrorEntry::new(format!("{:?}", e)))?;
+                Ok(Err(error))
+            }
+        }
     }
 
     async fn set_many(
@@ -138,6 +160,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
                 Ok((key, outgoing_value))
             })
             .collect::<Result<Vec<(String, Vec<u8>)>, ResourceTableError>>()?;
+        let ttl = self.state.config.key_value.ttl(&bucket);
         let result = Durability::<Ctx, (), SerializableError>::wrap(
             self,
             WrappedFunctionType::WriteRemote,
@@ -147,6 +170,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
                     account_id.clone(),
                     bucket.clone(),
                     key_values.clone(),
+                    ttl,
                 )
             },
         )
@@ -201,3 +225,29 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
         }
     }
 }
+
+/// The name under which all keys of a bucket were recorded in a single oplog entry, before they
+/// were listed page by page
+const LEGACY_GET_KEYS_FUNCTION: &str = "golem keyvalue::eventual_batch::get_keys";
+
+impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
+    /// Lists all keys of a bucket page by page, recording each page in a separate oplog entry so
+    /// large buckets do not result in a single huge entry
+    async fn get_all_keys(&mut self, bucket: String) -> anyhow::Result<Vec<String>> {
+        let page_size = self.state.config.key_value.keys_page_size;
+        let mut keys = Vec::new();
+        let mut seen = HashSet::new();
+        let mut cursor = None;
+        loop {
+            let page = self
+                .get_keys_page(bucket.clone(), None, cursor, page_size)
+                .await?;
+            keys.extend(page.keys.into_iter().filter(|key| seen.insert(key.clone())));
+            match page.cursor {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+        Ok(keys)
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use golem_common::model::oplog::WrappedFunctionType;
+use wasmtime::component::Resource;
+use wasmtime_wasi::preview2::WasiView;
+
+use crate::durable_host::keyvalue::error::ErrorEntry;
+use crate::durable_host::keyvalue::types::BucketEntry;
+use crate::durable_host::serialized::SerializableError;
+use crate::durable_host::{Durability, DurableWorkerCtx};
+use crate::metrics::wasm::record_host_function_call;
+use crate::preview2::golem::ext::keyvalue_keys::{Bucket, Error, Host, KeyPage};
+use crate::services::key_value;
+use crate::workerctx::WorkerCtx;
+
+/// The name under which each page of a key listing is recorded in the oplog
+pub(crate) const GET_KEYS_PAGE_FUNCTION: &str = "golem keyvalue::get_keys_page";
+
+#[async_trait]
+impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
+    async fn list_keys(
+        &mut self,
+        bucket: Resource<Bucket>,
+        prefix: Option<String>,
+        cursor: Option<String>,
+        count: u64,
+    ) -> anyhow::Result<Result<KeyPage, Resource<Error>>> {
+        record_host_function_call("golem::ext::keyvalue-keys", "list_keys");
+        let bucket = self
+            .as_wasi_view()
+            .table()
+            .get::<BucketEntry>(&bucket)?
+            .name
+            .clone();
+        match self.get_keys_page(bucket, prefix, cursor, count).await {
+            Ok(page) => Ok(Ok(KeyPage {
+                keys: page.keys,
+                cursor: page.cursor,
+            })),
+            Err(e) => {
+                let error = self
+                    .as_wasi_view()
+                    .table_mut()
+                    .push(ErrorEntry::new(format!("{:?}", e)))?;
+                Ok(Err(error))
+            }
+        }
+    }
+}
+
+impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
+    /// Lists one page of the keys of a bucket, recording it in the oplog. At most the configured
+    /// `keys_page_size` keys are listed at once, whatever `count` is.
+    pub(crate) async fn get_keys_page(
+        &mut self,
+        bucket: String,
+        prefix: Option<String>,
+        cursor: Option<String>,
+        count: u64,
+    ) -> anyhow::Result<key_value::KeyPage> {
+        let account_id = self.state.account_id.clone();
+        let count = count.clamp(1, self.state.config.key_value.keys_page_size.max(1));
+        Durability::<Ctx, key_value::KeyPage, SerializableError>::wrap(
+            self,
+            WrappedFunctionType::ReadRemote,
+            GET_KEYS_PAGE_FUNCTION,
+            |ctx| {
+                ctx.state.key_value_service.get_keys(
+                    account_id.clone(),
+                    bucket.clone(),
+                    prefix.clone(),
+                    cursor.clone(),
+                    count,
+                )
+            },
+        )
+        .await
+    }
+}
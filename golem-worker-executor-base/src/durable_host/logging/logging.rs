@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::services::worker_event::LogLevel;
+use crate::services::worker_event::{LogAttribute, LogAttributeValue, LogLevel};
 use async_trait::async_trait;
 
 use crate::durable_host::DurableWorkerCtx;
@@ -36,7 +36,8 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
                 Level::Debug => LogLevel::Debug,
                 Level::Trace => LogLevel::Trace,
             };
-            event_service.emit_log(log_level, &context, &message);
+            let (text, attributes) = split_structured_message(&message);
+            event_service.emit_log(log_level, &context, &text, attributes);
 
             Host::log(&mut self.as_wasi_view(), level, context, message).await
         } else {
@@ -44,3 +45,101 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
         }
     }
 }
+
+/// Guests can attach structured fields to a log event by logging a JSON object instead of
+/// a plain message. The `message` (or `msg`) field of the object becomes the text of the
+/// event and every other field is turned into a typed attribute. Nested arrays and objects
+/// are kept as their JSON representation.
+///
+/// Any other message, including JSON objects without a string `message` or `msg` field, is
+/// emitted as-is, without attributes.
+fn split_structured_message(message: &str) -> (String, Vec<LogAttribute>) {
+    match serde_json::from_str::<serde_json::Value>(message) {
+        Ok(serde_json::Value::Object(fields)) if has_message_field(&fields) => {
+            let mut text = None;
+            let mut attributes = Vec::new();
+            for (key, value) in fields {
+                match value {
+                    serde_json::Value::String(s)
+                        if text.is_none() && (key == "message" || key == "msg") =>
+                    {
+                        text = Some(s)
+                    }
+                    serde_json::Value::Null => {}
+                    serde_json::Value::Bool(b) => {
+                        attributes.push(LogAttribute::new(key, LogAttributeValue::Bool(b)))
+                    }
+                    serde_json::Value::Number(n) => {
+                        let value = match n.as_i64() {
+                            Some(i) => LogAttributeValue::Int(i),
+                            None => LogAttributeValue::Float(n.as_f64().unwrap_or(f64::NAN)),
+                        };
+                        attributes.push(LogAttribute::new(key, value))
+                    }
+                    serde_json::Value::String(s) => {
+                        attributes.push(LogAttribute::new(key, LogAttributeValue::String(s)))
+                    }
+                    other => attributes.push(LogAttribute::new(
+                        key,
+                        LogAttributeValue::String(other.to_string()),
+                    )),
+                }
+            }
+            (text.unwrap_or_default(), attributes)
+        }
+        _ => (message.to_string(), Vec::new()),
+    }
+}
+
+fn has_message_field(fields: &serde_json::Map<String, serde_json::Value>) -> bool {
+    ["message", "msg"]
+        .iter()
+        .any(|key| matches!(fields.get(*key), Some(serde_json::Value::String(_))))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::durable_host::logging::logging::split_structured_message;
+    use crate::services::worker_event::{LogAttribute, LogAttributeValue};
+
+    #[test]
+    fn plain_message_has_no_attributes() {
+        let (text, attributes) = split_structured_message("hello world");
+        assert_eq!(text, "hello world");
+        assert!(attributes.is_empty());
+    }
+
+    #[test]
+    fn json_message_is_split_into_attributes() {
+        let (text, mut attributes) = split_structured_message(
+            r#"{"message":"request finished","request_id":"r-1","duration_ms":42,"ratio":0.5,"cached":true,"tags":["a"]}"#,
+        );
+        attributes.sort_by(|a, b| a.key.cmp(&b.key));
+        assert_eq!(text, "request finished");
+        assert_eq!(
+            attributes,
+            vec![
+                LogAttribute::new("cached", LogAttributeValue::Bool(true)),
+                LogAttribute::new("duration_ms", LogAttributeValue::Int(42)),
+                LogAttribute::new("ratio", LogAttributeValue::Float(0.5)),
+                LogAttribute::new("request_id", LogAttributeValue::String("r-1".to_string())),
+                LogAttribute::new("tags", LogAttributeValue::String("[\"a\"]".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn json_object_without_message_is_kept_as_message() {
+        let message = r#"{"request_id":"r-1","duration_ms":42}"#;
+        let (text, attributes) = split_structured_message(message);
+        assert_eq!(text, message);
+        assert!(attributes.is_empty());
+    }
+
+    #[test]
+    fn json_non_object_is_kept_as_message() {
+        let (text, attributes) = split_structured_message("[1, 2, 3]");
+        assert_eq!(text, "[1, 2, 3]");
+        assert!(attributes.is_empty());
+    }
+}
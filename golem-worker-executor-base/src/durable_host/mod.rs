@@ -15,10 +15,11 @@
 // WASI Host implementation for Golem, delegating to the core WASI implementation (wasmtime_wasi)
 // implementing the Golem specific instrumentation on top of it.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
 use std::string::FromUtf8Error;
+use std::sync::atomic::AtomicBool;
 use std::sync::{Arc, RwLock};
 use std::time::{Duration, Instant};
 
@@ -34,7 +35,7 @@ use crate::services::golem_config::GolemConfig;
 use crate::services::key_value::KeyValueService;
 use crate::services::promise::PromiseService;
 use crate::services::worker::WorkerService;
-use crate::services::worker_event::WorkerEventService;
+use crate::services::worker_event::{LogLevel, WorkerEvent, WorkerEventService};
 use crate::services::{worker_enumeration, HasAll, HasInvocationQueue, HasOplog};
 use crate::wasi_host::managed_stdio::ManagedStandardIo;
 use crate::workerctx::{
@@ -44,18 +45,21 @@ use crate::workerctx::{
 use anyhow::anyhow;
 use async_trait::async_trait;
 use cap_std::ambient_authority;
+use golem_common::clock::Clock;
 use golem_common::config::RetryConfig;
 use golem_common::model::oplog::{
-    OplogEntry, OplogIndex, SnapshotSource, UpdateDescription, WrappedFunctionType,
+    Compensation, OplogEntry, OplogIndex, SnapshotSource, UpdateDescription, WrappedFunctionType,
 };
 use golem_common::model::regions::{DeletedRegions, OplogRegion};
 use golem_common::model::{
     AccountId, CallingConvention, ComponentId, ComponentVersion, FailedUpdateRecord,
-    IdempotencyKey, SuccessfulUpdateRecord, WorkerFilter, WorkerId, WorkerMetadata, WorkerStatus,
-    WorkerStatusRecord,
+    IdempotencyKey, PromiseId, SuccessfulUpdateRecord, Timestamp, WorkerFilter, WorkerId,
+    WorkerMetadata, WorkerStatus, WorkerStatusRecord,
 };
+use golem_common::telemetry::trace_context_headers;
 use golem_wasm_rpc::wasmtime::ResourceStore;
 use golem_wasm_rpc::{Uri, Value};
+use http::{HeaderName, HeaderValue};
 use tempfile::TempDir;
 use tracing::{debug, info, warn};
 use wasmtime::component::{Instance, Resource, ResourceAny};
@@ -66,13 +70,23 @@ use wasmtime_wasi_http::types::{
 };
 use wasmtime_wasi_http::{WasiHttpCtx, WasiHttpView};
 
+use crate::durable_host::durability::{is_deferred_oplog_entry, EGRESS_VIOLATION_FUNCTION};
+use crate::durable_host::http::body::{
+    delete_http_body_blobs, is_http_body_segment, HttpBodyStream,
+};
 use crate::durable_host::io::{ManagedStdErr, ManagedStdIn, ManagedStdOut};
+use crate::durable_host::serialized::SerializableStreamError;
+use crate::durable_host::sockets::tcp::PendingTcpWrite;
 use crate::durable_host::wasm_rpc::UriExtensions;
+use crate::egress::{EgressPolicy, EgressViolation};
+use crate::metrics::per_component::{record_component_replay, record_component_suspended_time};
 use crate::metrics::wasm::{record_number_of_replayed_functions, record_resume_worker};
+use crate::services::mailbox::MailboxService;
 use crate::services::oplog::{Oplog, OplogService};
 use crate::services::recovery::RecoveryManagement;
 use crate::services::rpc::Rpc;
 use crate::services::scheduler::SchedulerService;
+use crate::services::topic::TopicService;
 use crate::services::HasOplogService;
 use crate::wasi_host;
 use crate::worker::{calculate_last_known_status, calculate_worker_status};
@@ -128,9 +142,12 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
         oplog: Arc<dyn Oplog + Send + Sync>,
         invocation_queue: Arc<InvocationQueue<Ctx>>,
         scheduler_service: Arc<dyn SchedulerService + Send + Sync>,
+        topic_service: Arc<dyn TopicService + Send + Sync>,
+        mailbox_service: Arc<dyn MailboxService + Send + Sync>,
         recovery_management: Arc<dyn RecoveryManagement + Send + Sync>,
         rpc: Arc<dyn Rpc + Send + Sync>,
         worker_proxy: Arc<dyn WorkerProxy + Send + Sync>,
+        clock: Arc<dyn Clock + Send + Sync>,
         config: Arc<GolemConfig>,
         worker_config: WorkerConfig,
         execution_status: Arc<RwLock<ExecutionStatus>>,
@@ -167,6 +184,7 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
             stderr,
             |duration| anyhow!(SuspendForSleep(duration)),
             config.suspend.suspend_after,
+            clock.clone(),
             |wasi, table| {
                 let wasi_http = WasiHttpCtx;
                 DurableWorkerCtx {
@@ -186,6 +204,8 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
                         oplog,
                         promise_service,
                         scheduler_service,
+                        topic_service,
+                        mailbox_service,
                         worker_service,
                         worker_enumeration_service,
                         key_value_service,
@@ -198,6 +218,7 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
                         recovery_management,
                         rpc,
                         worker_proxy,
+                        clock,
                         resources: HashMap::new(),
                         last_resource_id: 0,
                         deleted_regions: worker_config.deleted_regions.clone(),
@@ -211,6 +232,18 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
                         replay_idx: 1,
                         replay_target: oplog_size,
                         snapshotting_mode: None,
+                        deferred_oplog_entries: Vec::new(),
+                        prefetched_oplog_entries: VecDeque::new(),
+                        tcp_streams: HashSet::new(),
+                        pending_tcp_writes: Vec::new(),
+                        tcp_write_errors: HashMap::new(),
+                        http_body_ids: HashMap::new(),
+                        http_body_streams: HashMap::new(),
+                        last_received_message_id: None,
+                        suspended_on_empty_mailbox: false,
+                        suspended_on_async_rpc: None,
+                        open_compensations: Vec::new(),
+                        replayed_call_chains: HashMap::new(),
                     },
                     temp_dir,
                     execution_status,
@@ -327,7 +360,15 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
             .current_idempotency_key
             .clone_from(&self.state.current_idempotency_key);
         status.oplog_idx = self.state.oplog.current_oplog_index().await;
+        let previous_status = status.status.clone();
         f(&mut status);
+        if status.status != previous_status {
+            self.public_state
+                .event_service
+                .emit_event(WorkerEvent::StatusChanged {
+                    status: status.status.clone(),
+                });
+        }
         self.state
             .worker_service
             .update_status(&self.worker_id, &status)
@@ -341,6 +382,81 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
         self.update_worker_status(|s| s.status = status).await;
     }
 
+    /// Checks an outgoing connection against the egress policy of the worker, reporting
+    /// the violations to the operators.
+    ///
+    /// The policy is only evaluated in live mode, and violations are recorded in the oplog. During
+    /// replay the recorded decision is used instead, so changing the policy does not change the
+    /// outcome of the connections the worker has already made.
+    fn check_egress(
+        &mut self,
+        check: impl FnOnce(&EgressPolicy) -> Result<(), EgressViolation>,
+    ) -> Result<(), EgressViolation> {
+        if self.state.is_replay()
+            && self.state.persistence_level != PersistenceLevel::PersistNothing
+        {
+            return match self.state.take_recorded_egress_violation() {
+                Some(violation) => Err(violation),
+                None => Ok(()),
+            };
+        }
+
+        let policy = self
+            .state
+            .config
+            .egress
+            .policy(&self.worker_id.component_id, &self.state.account_id);
+        check(policy).map_err(|violation| {
+            warn!(
+                "Egress policy violation in worker {}: {violation}",
+                self.worker_id
+            );
+            self.public_state
+                .event_service
+                .emit_event(WorkerEvent::Log {
+                    level: LogLevel::Warn,
+                    context: "egress".to_string(),
+                    message: violation.to_string(),
+                    attributes: vec![],
+                });
+            if self.state.persistence_level != PersistenceLevel::PersistNothing {
+                let entry = OplogEntry::imported_function_invoked(
+                    EGRESS_VIOLATION_FUNCTION.to_string(),
+                    &violation,
+                    WrappedFunctionType::ReadLocal,
+                )
+                .unwrap_or_else(|err| {
+                    panic!("failed to serialize egress violation: {violation:?}: {err}")
+                });
+                self.state.defer_oplog_entry(entry);
+            }
+            violation
+        })
+    }
+
+    /// Records how long the worker has been suspended, if it is being resumed from suspension
+    async fn record_suspended_time(&self) {
+        let status = self
+            .execution_status
+            .read()
+            .unwrap()
+            .last_known_status()
+            .status
+            .clone();
+        if status == WorkerStatus::Suspended {
+            let last_entry = self.state.read_oplog(self.state.replay_target, 1).await;
+            if let Some(OplogEntry::Suspend { timestamp }) = last_entry.first() {
+                let suspended_millis = Timestamp::now_utc()
+                    .to_millis()
+                    .saturating_sub(timestamp.to_millis());
+                record_component_suspended_time(
+                    &self.worker_id,
+                    Duration::from_millis(suspended_millis),
+                );
+            }
+        }
+    }
+
     pub async fn update_pending_invocations(&self) {
         self.update_worker_status(|_| {}).await;
     }
@@ -373,6 +489,32 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
         self.state.rpc.clone()
     }
 
+    /// The call chain to pass with the RPC calls made by the current invocation: the workers
+    /// waiting for this invocation followed by this worker
+    pub fn outgoing_call_chain(&self) -> Vec<WorkerId> {
+        let mut call_chain = match self.state.get_current_idempotency_key() {
+            Some(key) => self.invocation_call_chain(&key),
+            None => Vec::new(),
+        };
+        call_chain.push(self.worker_id.clone());
+        call_chain
+    }
+
+    /// The workers waiting for the given invocation through RPC, either enqueued since the worker
+    /// was loaded or restored from the oplog
+    fn invocation_call_chain(&self, key: &IdempotencyKey) -> Vec<WorkerId> {
+        let call_chain = self.public_state.invocation_queue.call_chain(key);
+        if call_chain.is_empty() {
+            self.state
+                .replayed_call_chains
+                .get(key)
+                .cloned()
+                .unwrap_or_default()
+        } else {
+            call_chain
+        }
+    }
+
     pub fn worker_proxy(&self) -> Arc<dyn WorkerProxy + Send + Sync> {
         self.state.worker_proxy.clone()
     }
@@ -592,12 +734,14 @@ impl<Ctx: WorkerCtx> InvocationHooks for DurableWorkerCtx<Ctx> {
                 .iter()
                 .map(|value| value.clone().into())
                 .collect();
+            let idempotency_key = self.get_current_idempotency_key().await.ok_or(anyhow!(
+                "No active invocation key is associated with the worker"
+            ))?;
+            let call_chain = self.invocation_call_chain(&idempotency_key);
             let oplog_entry = OplogEntry::exported_function_invoked(
                 full_function_name.to_string(),
                 &proto_function_input,
-                self.get_current_idempotency_key().await.ok_or(anyhow!(
-                    "No active invocation key is associated with the worker"
-                ))?,
+                idempotency_key.clone(),
                 calling_convention,
             )
             .unwrap_or_else(|err| {
@@ -607,7 +751,19 @@ impl<Ctx: WorkerCtx> InvocationHooks for DurableWorkerCtx<Ctx> {
                 )
             });
 
-            self.state.oplog.add_and_commit(oplog_entry).await;
+            self.state.flush_deferred_oplog_entries().await;
+            if call_chain.is_empty() {
+                self.state.oplog.add_and_commit(oplog_entry).await;
+            } else {
+                self.state.oplog.add(oplog_entry).await;
+                self.state
+                    .oplog
+                    .add_and_commit(OplogEntry::invocation_call_chain(
+                        idempotency_key,
+                        call_chain,
+                    ))
+                    .await;
+            }
         }
         Ok(())
     }
@@ -616,6 +772,7 @@ impl<Ctx: WorkerCtx> InvocationHooks for DurableWorkerCtx<Ctx> {
         &mut self,
         trap_type: &TrapType,
     ) -> Result<Option<OplogIndex>, anyhow::Error> {
+        self.flush_tcp_writes().await?;
         self.state.consume_hint_entries().await;
 
         if self.state.is_live() {
@@ -630,6 +787,7 @@ impl<Ctx: WorkerCtx> InvocationHooks for DurableWorkerCtx<Ctx> {
             };
 
             if let Some((entry, store)) = needs_commit {
+                self.state.flush_deferred_oplog_entries().await;
                 let oplog_idx = self.state.oplog.add_and_commit(entry).await;
 
                 if store {
@@ -650,6 +808,19 @@ impl<Ctx: WorkerCtx> InvocationHooks for DurableWorkerCtx<Ctx> {
         _payload: &Option<OplogIndex>,
         error: &TrapType,
     ) -> Result<WorkerStatus, anyhow::Error> {
+        // A message sent after `receive` found the mailbox empty, or an async RPC result
+        // arriving after `get` decided to suspend, but before the worker got deactivated, did
+        // not activate the worker as it was still running. The worker is restarted to receive
+        // it instead of staying suspended.
+        let restart = TrapType::Interrupt(InterruptKind::Restart);
+        let error = if self.has_missed_mailbox_message(error).await?
+            || self.has_missed_async_rpc_result(error)
+        {
+            &restart
+        } else {
+            error
+        };
+
         let previous_tries = self.state.trailing_error_count().await;
         let default_retry_config = &self.state.config.retry;
         let retry_config = self
@@ -683,12 +854,18 @@ impl<Ctx: WorkerCtx> InvocationHooks for DurableWorkerCtx<Ctx> {
     ) -> Result<(), anyhow::Error> {
         if let Some(oplog_idx) = payload {
             if let Some(idempotency_key) = self.state.get_current_idempotency_key() {
+                self.state.replayed_call_chains.remove(&idempotency_key);
                 self.public_state
                     .invocation_queue
                     .store_invocation_failure(&idempotency_key, trap_type, *oplog_idx)
                     .await;
             }
         }
+        if let TrapType::Error(_) = trap_type {
+            if self.state.is_live() {
+                self.run_open_compensations().await;
+            }
+        }
         Ok(())
     }
 
@@ -699,7 +876,11 @@ impl<Ctx: WorkerCtx> InvocationHooks for DurableWorkerCtx<Ctx> {
         consumed_fuel: i64,
         output: Vec<Value>,
     ) -> Result<Option<Vec<Value>>, anyhow::Error> {
+        self.flush_tcp_writes().await?;
         self.state.consume_hint_entries().await;
+        if let Some(idempotency_key) = self.state.get_current_idempotency_key() {
+            self.state.replayed_call_chains.remove(&idempotency_key);
+        }
         let is_live_after = self.state.is_live();
 
         if is_live_after {
@@ -714,6 +895,7 @@ impl<Ctx: WorkerCtx> InvocationHooks for DurableWorkerCtx<Ctx> {
                             )
                         });
 
+                self.state.flush_deferred_oplog_entries().await;
                 let oplog_idx = self.state.oplog.add_and_commit(oplog_entry).await;
 
                 if let Some(idempotency_key) = self.state.get_current_idempotency_key() {
@@ -880,6 +1062,13 @@ impl<Ctx: WorkerCtx + DurableWorkerCtxView<Ctx>> ExternalOperations<Ctx> for Dur
         let start = Instant::now();
         let mut count = 0;
 
+        store
+            .as_context()
+            .data()
+            .durable_ctx()
+            .record_suspended_time()
+            .await;
+
         // Handle the case when recovery immediately starts in a deleted region
         // (for example due to a manual update)
         store
@@ -949,6 +1138,7 @@ impl<Ctx: WorkerCtx + DurableWorkerCtxView<Ctx>> ExternalOperations<Ctx> for Dur
         };
         record_resume_worker(start.elapsed());
         record_number_of_replayed_functions(count);
+        record_component_replay(worker_id, start.elapsed());
 
         let retry = Self::finalize_pending_update(&result, instance, store).await;
 
@@ -972,9 +1162,20 @@ impl<Ctx: WorkerCtx + DurableWorkerCtxView<Ctx>> ExternalOperations<Ctx> for Dur
     }
 
     async fn on_worker_deleted<T: HasAll<Ctx> + Send + Sync>(
-        _this: &T,
-        _worker_id: &WorkerId,
+        this: &T,
+        worker_id: &WorkerId,
     ) -> Result<(), GolemError> {
+        if let Some(metadata) = this.worker_service().get(worker_id).await {
+            if let Err(err) = delete_http_body_blobs(
+                this.blob_store_service().as_ref(),
+                &metadata.account_id,
+                worker_id,
+            )
+            .await
+            {
+                warn!("Failed to delete the HTTP response bodies of worker {worker_id}: {err}");
+            }
+        }
         Ok(())
     }
 
@@ -990,6 +1191,11 @@ impl<Ctx: WorkerCtx + DurableWorkerCtxView<Ctx>> ExternalOperations<Ctx> for Dur
         let default_retry_config = &this.config().retry;
         for worker in workers {
             let worker_id = worker.worker_id.clone();
+            // A worker which failed while running its compensations is not recovered, but its
+            // remaining compensations are run
+            if Self::resume_pending_compensations(this, &worker_id, &worker.account_id).await? {
+                continue;
+            }
             let actualized_metadata =
                 calculate_last_known_status(this, &worker_id, &Some(worker)).await?;
             let last_error = Self::get_last_error_and_retry_count(this, &worker_id).await;
@@ -1061,6 +1267,8 @@ pub struct PrivateDurableWorkerState<Ctx: WorkerCtx> {
     oplog: Arc<dyn Oplog + Send + Sync>,
     promise_service: Arc<dyn PromiseService + Send + Sync>,
     scheduler_service: Arc<dyn SchedulerService + Send + Sync>,
+    topic_service: Arc<dyn TopicService + Send + Sync>,
+    mailbox_service: Arc<dyn MailboxService + Send + Sync>,
     worker_service: Arc<dyn WorkerService + Send + Sync>,
     worker_enumeration_service: Arc<dyn worker_enumeration::WorkerEnumerationService + Send + Sync>,
     key_value_service: Arc<dyn KeyValueService + Send + Sync>,
@@ -1073,6 +1281,7 @@ pub struct PrivateDurableWorkerState<Ctx: WorkerCtx> {
     recovery_management: Arc<dyn RecoveryManagement + Send + Sync>,
     rpc: Arc<dyn Rpc + Send + Sync>,
     worker_proxy: Arc<dyn WorkerProxy + Send + Sync>,
+    clock: Arc<dyn Clock + Send + Sync>,
     resources: HashMap<u64, ResourceAny>,
     last_resource_id: u64,
     deleted_regions: DeletedRegions,
@@ -1084,6 +1293,33 @@ pub struct PrivateDurableWorkerState<Ctx: WorkerCtx> {
     replay_target: u64,
     replay_idx: u64,
     snapshotting_mode: Option<PersistenceLevel>,
+    /// Oplog entries recorded by non-async host functions, which cannot write the oplog
+    /// directly. They are written before the next oplog entry.
+    deferred_oplog_entries: Vec<OplogEntry>,
+    /// Oplog entries read ahead during replay, together with their oplog index
+    prefetched_oplog_entries: VecDeque<(u64, OplogEntry)>,
+    /// Input and output streams of TCP connections
+    tcp_streams: HashSet<u32>,
+    /// Writes to TCP output streams which have not been sent yet, as they can only be recorded
+    /// in the oplog by an async host function
+    pending_tcp_writes: Vec<PendingTcpWrite>,
+    /// Failures of sending the pending writes, reported by the next operation on the stream
+    tcp_write_errors: HashMap<u32, SerializableStreamError>,
+    /// Ids of the incoming HTTP response bodies, by the resources belonging to them
+    http_body_ids: HashMap<u32, u64>,
+    http_body_streams: HashMap<u64, HttpBodyStream>,
+    /// Id of the last message received from the worker's mailbox, restored during replay
+    last_received_message_id: Option<String>,
+    /// Whether the worker is being suspended by a `receive` on its empty mailbox
+    suspended_on_empty_mailbox: bool,
+    /// Set when the worker is being suspended until an async RPC invocation completes, becoming
+    /// true once it did
+    suspended_on_async_rpc: Option<Arc<AtomicBool>>,
+    /// Compensations registered in atomic regions which have not ended yet, in registration
+    /// order, together with the begin index of their region
+    open_compensations: Vec<(OplogIndex, Compensation)>,
+    /// Call chains of invocations enqueued before the worker was loaded, restored during replay
+    replayed_call_chains: HashMap<IdempotencyKey, Vec<WorkerId>>,
 }
 
 impl<Ctx: WorkerCtx> PrivateDurableWorkerState<Ctx> {
@@ -1091,6 +1327,7 @@ impl<Ctx: WorkerCtx> PrivateDurableWorkerState<Ctx> {
         &mut self,
         wrapped_function_type: &WrappedFunctionType,
     ) -> Result<u64, GolemError> {
+        self.flush_deferred_oplog_entries().await;
         let begin_index = self.oplog.current_oplog_index().await;
         if !self.assume_idempotence
             && *wrapped_function_type == WrappedFunctionType::WriteRemote
@@ -1198,17 +1435,181 @@ impl<Ctx: WorkerCtx> PrivateDurableWorkerState<Ctx> {
     async fn get_oplog_entry(&mut self) -> OplogEntry {
         assert!(self.is_replay());
 
-        let oplog_entries = self.read_oplog(self.replay_idx, 1).await;
-        let oplog_entry = oplog_entries[0].clone();
+        let oplog_entry = match self.take_prefetched_oplog_entry() {
+            Some(oplog_entry) => oplog_entry,
+            None => {
+                let oplog_entries = self.read_oplog(self.replay_idx, 1).await;
+                oplog_entries[0].clone()
+            }
+        };
 
         if !self.get_out_of_deleted_region() {
             // No jump happened, increment replay index
             self.replay_idx += 1;
         }
 
+        if let OplogEntry::InvocationCallChain {
+            idempotency_key,
+            call_chain,
+            ..
+        } = &oplog_entry
+        {
+            self.replayed_call_chains
+                .insert(idempotency_key.clone(), call_chain.clone());
+        }
+
+        self.prefetch_deferred_oplog_entries().await;
+
         oplog_entry
     }
 
+    /// Records the oplog entry of a non-async host function. It gets written to the oplog
+    /// before the next entry written by an async one.
+    fn defer_oplog_entry(&mut self, entry: OplogEntry) {
+        self.deferred_oplog_entries.push(entry);
+    }
+
+    async fn flush_deferred_oplog_entries(&mut self) {
+        for entry in std::mem::take(&mut self.deferred_oplog_entries) {
+            self.oplog.add(entry).await;
+        }
+        self.flush_http_body_segments().await;
+    }
+
+    /// Reads ahead the oplog entries recorded by non-async host functions and HTTP response body
+    /// streams following the current replay position, as they are needed before the replay
+    /// reaches them.
+    async fn prefetch_deferred_oplog_entries(&mut self) {
+        const CHUNK_SIZE: u64 = 64;
+
+        if self
+            .prefetched_oplog_entries
+            .front()
+            .is_some_and(|(idx, _)| *idx != self.replay_idx)
+        {
+            self.prefetched_oplog_entries.clear();
+        }
+
+        let mut idx = match self.prefetched_oplog_entries.back() {
+            Some((_, entry)) if !is_deferred_oplog_entry(entry) => return,
+            Some((idx, _)) => idx + 1,
+            None => self.replay_idx,
+        };
+        while idx < self.replay_target {
+            let entries = self.read_oplog(idx, CHUNK_SIZE).await;
+            if entries.is_empty() {
+                break;
+            }
+            for entry in entries {
+                let deferred = is_deferred_oplog_entry(&entry);
+                self.prefetched_oplog_entries.push_back((idx, entry));
+                idx += 1;
+                if !deferred {
+                    return;
+                }
+            }
+        }
+    }
+
+    fn take_prefetched_oplog_entry(&mut self) -> Option<OplogEntry> {
+        match self.prefetched_oplog_entries.front() {
+            Some((idx, _)) if *idx == self.replay_idx => self
+                .prefetched_oplog_entries
+                .pop_front()
+                .map(|(_, entry)| entry),
+            _ => {
+                self.prefetched_oplog_entries.clear();
+                None
+            }
+        }
+    }
+
+    /// Returns the name of the host function which recorded the next oplog entry to be replayed,
+    /// without consuming it. Returns `None` if the next replayed entry is not the result of an
+    /// imported function call.
+    async fn peek_imported_function_name(&mut self) -> Option<String> {
+        match self.peek_imported_function_invoked().await? {
+            OplogEntry::ImportedFunctionInvoked { function_name, .. } => Some(function_name),
+            _ => None,
+        }
+    }
+
+    /// Returns the next `ImportedFunctionInvoked` entry to be replayed without consuming it, or
+    /// `None` if the next replayed entry is something else.
+    async fn peek_imported_function_invoked(&mut self) -> Option<OplogEntry> {
+        let mut idx = self.replay_idx;
+        while idx < self.replay_target {
+            let entry = match self
+                .prefetched_oplog_entries
+                .iter()
+                .find(|(prefetched_idx, _)| *prefetched_idx == idx)
+            {
+                Some((_, entry)) => entry.clone(),
+                None => self.read_oplog(idx, 1).await.into_iter().next()?,
+            };
+            match entry {
+                entry @ OplogEntry::ImportedFunctionInvoked { .. } => return Some(entry),
+                entry if is_skipped_in_replay(&entry) => {
+                    idx = match &self.next_deleted_region {
+                        Some(region) if region.start == idx => region.end + 1,
+                        _ => idx + 1,
+                    };
+                }
+                _ => return None,
+            }
+        }
+        None
+    }
+
+    /// Takes the egress policy violation recorded at the current replay position, if there is one
+    fn take_recorded_egress_violation(&mut self) -> Option<EgressViolation> {
+        let is_recorded = matches!(
+            self.prefetched_oplog_entries.front(),
+            Some((idx, OplogEntry::ImportedFunctionInvoked { function_name, .. }))
+                if *idx == self.replay_idx && function_name == EGRESS_VIOLATION_FUNCTION
+        );
+        if is_recorded {
+            self.get_deferred_oplog_entry(EGRESS_VIOLATION_FUNCTION)
+                .ok()
+                .and_then(|entry| entry.payload::<EgressViolation>().ok().flatten())
+        } else {
+            None
+        }
+    }
+
+    /// Gets the next oplog entry recorded by the given non-async host function during replay
+    fn get_deferred_oplog_entry(&mut self, function_name: &str) -> Result<OplogEntry, GolemError> {
+        loop {
+            if self.is_live() {
+                break Err(GolemError::unexpected_oplog_entry(
+                    function_name,
+                    "end of the oplog",
+                ));
+            }
+            let oplog_entry = self.take_prefetched_oplog_entry().ok_or_else(|| {
+                GolemError::runtime(format!(
+                    "The oplog entry of {function_name} was not read ahead during replay"
+                ))
+            })?;
+            if !self.get_out_of_deleted_region() {
+                self.replay_idx += 1;
+            }
+            match &oplog_entry {
+                OplogEntry::ImportedFunctionInvoked {
+                    function_name: name,
+                    ..
+                } if name == function_name => break Ok(oplog_entry),
+                entry if is_skipped_in_replay(entry) => {}
+                _ => {
+                    break Err(GolemError::unexpected_oplog_entry(
+                        function_name,
+                        format!("{:?}", oplog_entry),
+                    ))
+                }
+            }
+        }
+    }
+
     async fn lookup_oplog_entry(
         &mut self,
         begin_idx: u64,
@@ -1232,6 +1633,46 @@ impl<Ctx: WorkerCtx> PrivateDurableWorkerState<Ctx> {
         None
     }
 
+    /// Reads the compensations registered between `start` and `end`, together with the begin
+    /// index of their atomic region, and the idempotency keys of the compensations which have
+    /// already been run
+    async fn read_compensations(
+        &self,
+        start: OplogIndex,
+        end: OplogIndex,
+    ) -> (Vec<(OplogIndex, Compensation)>, HashSet<IdempotencyKey>) {
+        let mut registered = Vec::new();
+        let mut completed = HashSet::new();
+        let mut idx = start;
+        const CHUNK_SIZE: u64 = 1024;
+        while idx < end {
+            let entries = self
+                .oplog_service
+                .read(&self.worker_id, idx, CHUNK_SIZE.min(end - idx))
+                .await;
+            if entries.is_empty() {
+                break;
+            }
+            for entry in &entries {
+                match entry {
+                    OplogEntry::RegisterCompensation {
+                        begin_index,
+                        compensation,
+                        ..
+                    } => registered.push((*begin_index, compensation.clone())),
+                    OplogEntry::CompensationCompleted {
+                        idempotency_key, ..
+                    } => {
+                        completed.insert(idempotency_key.clone());
+                    }
+                    _ => {}
+                }
+            }
+            idx += entries.len() as u64;
+        }
+        (registered, completed)
+    }
+
     async fn get_oplog_entry_exported_function_invoked(
         &mut self,
     ) -> Result<
@@ -1271,7 +1712,7 @@ impl<Ctx: WorkerCtx> PrivateDurableWorkerState<Ctx> {
                             *calling_convention,
                         )));
                     }
-                    entry if entry.is_hint() => {}
+                    entry if is_skipped_in_replay(entry) => {}
                     _ => {
                         break Err(GolemError::unexpected_oplog_entry(
                             "ExportedFunctionInvoked",
@@ -1306,7 +1747,7 @@ impl<Ctx: WorkerCtx> PrivateDurableWorkerState<Ctx> {
                             .collect();
                         break Ok(Some(response));
                     }
-                    entry if entry.is_hint() => {}
+                    entry if is_skipped_in_replay(entry) => {}
                     _ => {
                         break Err(GolemError::unexpected_oplog_entry(
                             "ExportedFunctionCompleted",
@@ -1324,11 +1765,12 @@ impl<Ctx: WorkerCtx> PrivateDurableWorkerState<Ctx> {
     /// keep workers in memory or allow them to rerun etc., but contain no actionable information
     /// during replay
     async fn consume_hint_entries(&mut self) {
+        self.flush_deferred_oplog_entries().await;
         loop {
             if self.is_replay() {
                 let oplog_entry = self.get_oplog_entry().await;
                 match oplog_entry {
-                    entry if entry.is_hint() => {}
+                    entry if is_skipped_in_replay(&entry) => {}
                     _ => {
                         self.replay_idx -= 1;
                         break;
@@ -1340,12 +1782,36 @@ impl<Ctx: WorkerCtx> PrivateDurableWorkerState<Ctx> {
         }
     }
 
-    pub async fn sleep_until(&self, when: chrono::DateTime<chrono::Utc>) -> Result<(), GolemError> {
+    /// Creates a promise owned by the worker. If a deadline is given, the promise fails with a
+    /// timeout unless it gets completed before that.
+    pub async fn create_promise(
+        &self,
+        deadline: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> PromiseId {
         let promise_id = self
             .promise_service
             .create(&self.worker_id, self.current_oplog_index().await)
             .await;
 
+        // During replay the timeout has already been scheduled by the original run
+        if let (Some(deadline), true) = (deadline, self.is_live()) {
+            let schedule_id = self
+                .scheduler_service
+                .schedule_timeout(deadline, promise_id.clone())
+                .await;
+            debug!(
+                "Schedule added to time out promise at {} with id {}",
+                deadline.to_rfc3339(),
+                schedule_id
+            );
+        }
+
+        promise_id
+    }
+
+    pub async fn sleep_until(&self, when: chrono::DateTime<chrono::Utc>) -> Result<(), GolemError> {
+        let promise_id = self.create_promise(None).await;
+
         let schedule_id = self.scheduler_service.schedule(when, promise_id).await;
         debug!(
             "Schedule added to awake suspended worker at {} with id {}",
@@ -1505,11 +1971,13 @@ impl<'a, Ctx: WorkerCtx> WasiHttpView for DurableWorkerCtxWasiHttpView<'a, Ctx>
 
     fn send_request(
         &mut self,
-        request: OutgoingRequest,
+        mut request: OutgoingRequest,
     ) -> anyhow::Result<Resource<HostFutureIncomingResponse>>
     where
         Self: Sized,
     {
+        add_trace_context_headers(&mut request);
+
         if self.0.state.is_replay() {
             // If this is a replay, we must not actually send the request, but we have to store it in the
             // FutureIncomingResponse because it is possible that there wasn't any response recorded in the oplog.
@@ -1525,6 +1993,22 @@ impl<'a, Ctx: WorkerCtx> WasiHttpView for DurableWorkerCtxWasiHttpView<'a, Ctx>
     }
 }
 
+/// Propagates the trace context of the current invocation in the guest's outgoing HTTP requests,
+/// unless the guest has already set it
+fn add_trace_context_headers(request: &mut OutgoingRequest) {
+    let headers = request.request.headers_mut();
+    for (name, value) in trace_context_headers() {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::from_bytes(name.as_bytes()),
+            HeaderValue::from_str(&value),
+        ) {
+            if !headers.contains_key(&name) {
+                headers.insert(name, value);
+            }
+        }
+    }
+}
+
 struct Ready {}
 
 #[async_trait]
@@ -1532,8 +2016,13 @@ impl Subscribe for Ready {
     async fn ready(&mut self) {}
 }
 
+/// Checks whether an oplog entry carries no information for the replayed function calls
+pub(crate) fn is_skipped_in_replay(entry: &OplogEntry) -> bool {
+    entry.is_hint() || is_http_body_segment(entry)
+}
+
 /// Helper macro for expecting a given type of OplogEntry as the next entry in the oplog during
-/// replay, while skipping hint entries.
+/// replay, while skipping hint entries and HTTP response body segments.
 /// The macro expression's type is `Result<OplogEntry, GolemError>` and it fails if the next non-hint
 /// entry was not the expected one.
 #[macro_export]
@@ -1545,7 +2034,7 @@ macro_rules! get_oplog_entry {
                 $case { .. } => {
                     break Ok(oplog_entry);
                 }
-                entry if entry.is_hint() => {}
+                entry if $crate::durable_host::is_skipped_in_replay(&entry) => {}
                 _ => {
                     break Err($crate::error::GolemError::unexpected_oplog_entry(
                         stringify!($case),
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::error::GolemError;
+use crate::services::blob_store::{ObjectAttributes, ObjectMetadata};
 use crate::services::rpc::RpcError;
 use crate::services::worker_proxy::WorkerProxyError;
 use anyhow::anyhow;
@@ -536,6 +537,68 @@ pub struct SerializableFileTimes {
     pub data_modification_timestamp: Option<SerializableDateTime>,
 }
 
+/// The parts of the blob store's object metadata visible through the `wasi:blobstore` interface,
+/// keeping the encoding of already recorded oplog entries unchanged
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct SerializableObjectMetadata {
+    pub name: String,
+    pub container: String,
+    pub created_at: u64,
+    pub size: u64,
+}
+
+impl From<ObjectMetadata> for SerializableObjectMetadata {
+    fn from(value: ObjectMetadata) -> Self {
+        SerializableObjectMetadata {
+            name: value.name,
+            container: value.container,
+            created_at: value.created_at,
+            size: value.size,
+        }
+    }
+}
+
+impl From<SerializableObjectMetadata> for ObjectMetadata {
+    fn from(value: SerializableObjectMetadata) -> Self {
+        ObjectMetadata {
+            name: value.name,
+            container: value.container,
+            created_at: value.created_at,
+            size: value.size,
+            content_type: None,
+            metadata: Default::default(),
+        }
+    }
+}
+
+/// The content type and user-defined metadata of a blob store object, with the metadata ordered
+/// by key
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct SerializableObjectAttributes {
+    pub content_type: Option<String>,
+    pub metadata: Vec<(String, String)>,
+}
+
+impl From<ObjectAttributes> for SerializableObjectAttributes {
+    fn from(value: ObjectAttributes) -> Self {
+        let mut metadata: Vec<(String, String)> = value.metadata.into_iter().collect();
+        metadata.sort();
+        SerializableObjectAttributes {
+            content_type: value.content_type,
+            metadata,
+        }
+    }
+}
+
+impl From<SerializableObjectAttributes> for ObjectAttributes {
+    fn from(value: SerializableObjectAttributes) -> Self {
+        ObjectAttributes {
+            content_type: value.content_type,
+            metadata: value.metadata.into_iter().collect(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::durable_host::serialized::{
@@ -19,3 +19,23 @@ pub mod tcp;
 pub mod tcp_create_socket;
 pub mod udp;
 pub mod udp_create_socket;
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+use wasmtime_wasi::preview2::bindings::sockets::network::IpSocketAddress;
+
+fn to_socket_addr(address: &IpSocketAddress) -> SocketAddr {
+    match address {
+        IpSocketAddress::Ipv4(address) => {
+            let (a, b, c, d) = address.address;
+            SocketAddr::new(IpAddr::V4(Ipv4Addr::new(a, b, c, d)), address.port)
+        }
+        IpSocketAddress::Ipv6(address) => {
+            let (a, b, c, d, e, f, g, h) = address.address;
+            SocketAddr::new(
+                IpAddr::V6(Ipv6Addr::new(a, b, c, d, e, f, g, h)),
+                address.port,
+            )
+        }
+    }
+}
@@ -12,17 +12,29 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::any::Any;
+
+use anyhow::anyhow;
 use async_trait::async_trait;
+use bytes::Bytes;
 use wasmtime::component::Resource;
 
+use crate::durable_host::serialized::{SerializableError, SerializableStreamError};
+use crate::durable_host::sockets::to_socket_addr;
 use crate::durable_host::DurableWorkerCtx;
+use crate::error::GolemError;
 use crate::metrics::wasm::record_host_function_call;
+use crate::model::PersistenceLevel;
 use crate::workerctx::WorkerCtx;
+use golem_common::model::oplog::{OplogEntry, WrappedFunctionType};
+use wasmtime_wasi::preview2::bindings::sockets::network::ErrorCode;
 use wasmtime_wasi::preview2::bindings::wasi::sockets::tcp::{
     Duration, Host, HostTcpSocket, InputStream, IpAddressFamily, IpSocketAddress, Network,
     OutputStream, Pollable, ShutdownType, TcpSocket,
 };
-use wasmtime_wasi::preview2::SocketError;
+use wasmtime_wasi::preview2::{
+    HostInputStream, HostOutputStream, SocketError, StreamError, StreamResult, Subscribe,
+};
 
 #[async_trait]
 impl<Ctx: WorkerCtx> HostTcpSocket for DurableWorkerCtx<Ctx> {
@@ -48,7 +60,20 @@ impl<Ctx: WorkerCtx> HostTcpSocket for DurableWorkerCtx<Ctx> {
         remote_address: IpSocketAddress,
     ) -> Result<(), SocketError> {
         record_host_function_call("sockets::tcp", "start_connect");
-        HostTcpSocket::start_connect(&mut self.as_wasi_view(), self_, network, remote_address)
+        self.check_egress(|policy| policy.check_address(&to_socket_addr(&remote_address)))
+            .map_err(|_| ErrorCode::AccessDenied)?;
+        self.wrap_sync::<(), SerializableError, _, _, _>(
+            WrappedFunctionType::WriteRemote,
+            "sockets::tcp::start_connect",
+            |ctx| {
+                HostTcpSocket::start_connect(
+                    &mut ctx.as_wasi_view(),
+                    self_,
+                    network,
+                    remote_address,
+                )
+            },
+        )
     }
 
     fn finish_connect(
@@ -56,7 +81,29 @@ impl<Ctx: WorkerCtx> HostTcpSocket for DurableWorkerCtx<Ctx> {
         self_: Resource<TcpSocket>,
     ) -> Result<(Resource<InputStream>, Resource<OutputStream>), SocketError> {
         record_host_function_call("sockets::tcp", "finish_connect");
-        HostTcpSocket::finish_connect(&mut self.as_wasi_view(), self_)
+        self.custom_wrap_sync::<(), SerializableError, _, _, _, _, _>(
+            WrappedFunctionType::WriteRemote,
+            "sockets::tcp::finish_connect",
+            |ctx| HostTcpSocket::finish_connect(&mut ctx.as_wasi_view(), self_),
+            |ctx, (input, output)| {
+                ctx.state.tcp_streams.insert(input.rep());
+                ctx.state.tcp_streams.insert(output.rep());
+                Ok(())
+            },
+            |ctx, _| {
+                // The connection cannot be restored during replay, so the worker gets streams
+                // which fail once it tries to use them in live mode
+                let input = ctx
+                    .table
+                    .push(InputStream::Host(Box::new(InterruptedTcpStream)))?;
+                let output = ctx
+                    .table
+                    .push(Box::new(InterruptedTcpStream) as OutputStream)?;
+                ctx.state.tcp_streams.insert(input.rep());
+                ctx.state.tcp_streams.insert(output.rep());
+                Ok((input, output))
+            },
+        )
     }
 
     fn start_listen(&mut self, self_: Resource<TcpSocket>) -> Result<(), SocketError> {
@@ -238,3 +285,209 @@ impl<Ctx: WorkerCtx> HostTcpSocket for DurableWorkerCtx<Ctx> {
 
 #[async_trait]
 impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {}
+
+/// The name under which the sending of the pending writes of a TCP output stream is recorded
+const TCP_WRITE_FUNCTION: &str = "sockets::tcp::output_stream::write";
+
+/// Bytes written to a TCP output stream by non-async host functions.
+///
+/// They cannot be sent right away, because a remote write has to be started in the oplog first,
+/// which needs an async host function. The output stream is only owned here if the worker
+/// dropped it before the writes were sent.
+pub(crate) struct PendingTcpWrite {
+    handle: u32,
+    bytes: Vec<u8>,
+    dropped_stream: Option<OutputStream>,
+}
+
+impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
+    /// Queues bytes written to a TCP output stream until the next async host function call
+    pub(crate) fn stage_tcp_write(&mut self, handle: u32, bytes: &[u8]) -> Result<(), StreamError> {
+        self.tcp_write_error(handle)?;
+        let pending = self
+            .state
+            .pending_tcp_writes
+            .iter_mut()
+            .find(|pending| pending.handle == handle && pending.dropped_stream.is_none());
+        match pending {
+            Some(pending) => pending.bytes.extend_from_slice(bytes),
+            None => self.state.pending_tcp_writes.push(PendingTcpWrite {
+                handle,
+                bytes: bytes.to_vec(),
+                dropped_stream: None,
+            }),
+        }
+        Ok(())
+    }
+
+    /// Fails with the error of sending the earlier writes of a TCP output stream, if there was one
+    pub(crate) fn tcp_write_error(&self, handle: u32) -> Result<(), StreamError> {
+        match self.state.tcp_write_errors.get(&handle) {
+            Some(err) => Err(err.clone().into()),
+            None => Ok(()),
+        }
+    }
+
+    /// Takes over a dropped TCP output stream which still has pending writes, so they can be
+    /// sent later. Returns `false` if the stream has nothing to send.
+    pub(crate) fn keep_dropped_tcp_stream(
+        &mut self,
+        rep: &Resource<OutputStream>,
+    ) -> anyhow::Result<bool> {
+        self.state.tcp_write_errors.remove(&rep.rep());
+        let has_pending_writes = self
+            .state
+            .pending_tcp_writes
+            .iter()
+            .any(|pending| pending.handle == rep.rep() && pending.dropped_stream.is_none());
+        if has_pending_writes {
+            let stream = self
+                .table
+                .delete(Resource::<OutputStream>::new_own(rep.rep()))?;
+            if let Some(pending) = self
+                .state
+                .pending_tcp_writes
+                .iter_mut()
+                .find(|pending| pending.handle == rep.rep() && pending.dropped_stream.is_none())
+            {
+                pending.dropped_stream = Some(stream);
+            }
+        }
+        Ok(has_pending_writes)
+    }
+
+    /// Sends the pending writes of the TCP output streams.
+    ///
+    /// Each of them is a remote write surrounded by `begin_function` and `end_function`, so a
+    /// write which was interrupted by a crash is not silently repeated during recovery. In
+    /// replay mode the results of the writes are taken from the oplog.
+    pub(crate) async fn flush_tcp_writes(&mut self) -> Result<(), GolemError> {
+        for pending in std::mem::take(&mut self.state.pending_tcp_writes) {
+            let handle = pending.handle;
+            let is_dropped = pending.dropped_stream.is_some();
+            self.state.consume_hint_entries().await;
+            let begin_index = self
+                .state
+                .begin_function(&WrappedFunctionType::WriteRemote)
+                .await?;
+            let result = if self.state.is_live()
+                || self.state.persistence_level == PersistenceLevel::PersistNothing
+            {
+                let result = match pending.dropped_stream {
+                    Some(mut stream) => write_all(stream.as_mut(), pending.bytes).await,
+                    None => {
+                        match self
+                            .table
+                            .get_mut(&Resource::<OutputStream>::new_borrow(handle))
+                        {
+                            Ok(stream) => write_all(stream.as_mut(), pending.bytes).await,
+                            Err(err) => Err(StreamError::Trap(err.into())),
+                        }
+                    }
+                };
+                let serializable_result: Result<(), SerializableStreamError> =
+                    result.as_ref().map(|_| ()).map_err(|err| err.into());
+                if self.state.persistence_level != PersistenceLevel::PersistNothing {
+                    let oplog_entry = OplogEntry::imported_function_invoked(
+                        TCP_WRITE_FUNCTION.to_string(),
+                        &serializable_result,
+                        WrappedFunctionType::WriteRemote,
+                    )
+                    .unwrap_or_else(|err| {
+                        panic!(
+                            "failed to serialize function response: {:?}: {err}",
+                            serializable_result
+                        )
+                    });
+                    self.state.oplog.add(oplog_entry).await;
+                    self.state
+                        .end_function(&WrappedFunctionType::WriteRemote, begin_index)
+                        .await?;
+                    self.state.oplog.commit().await;
+                }
+                serializable_result
+            } else {
+                let oplog_entry =
+                    crate::get_oplog_entry!(self.state, OplogEntry::ImportedFunctionInvoked)?;
+                Self::validate_oplog_entry(&oplog_entry, TCP_WRITE_FUNCTION)?;
+                let response = oplog_entry
+                    .payload::<Result<(), SerializableStreamError>>()
+                    .map_err(|err| {
+                        GolemError::unexpected_oplog_entry("ImportedFunctionInvoked payload", err)
+                    })?
+                    .unwrap();
+                self.state
+                    .end_function(&WrappedFunctionType::WriteRemote, begin_index)
+                    .await?;
+                response
+            };
+            if let Err(err) = result {
+                if !is_dropped {
+                    self.state.tcp_write_errors.insert(handle, err);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes all the bytes to the stream, waiting for it to accept them, and flushes it
+async fn write_all(stream: &mut dyn HostOutputStream, bytes: Vec<u8>) -> StreamResult<()> {
+    let mut bytes = Bytes::from(bytes);
+    while !bytes.is_empty() {
+        stream.ready().await;
+        let permit = stream.check_write()?;
+        let len = permit.min(bytes.len());
+        stream.write(bytes.split_to(len))?;
+    }
+    stream.flush()?;
+    stream.ready().await;
+    stream.check_write().map(|_| ())
+}
+
+/// Stands in for the streams of a TCP connection established before the worker got recovered
+struct InterruptedTcpStream;
+
+impl InterruptedTcpStream {
+    fn error() -> StreamError {
+        StreamError::LastOperationFailed(anyhow!(std::io::Error::new(
+            std::io::ErrorKind::ConnectionReset,
+            "TCP connection was interrupted by the recovery of the worker"
+        )))
+    }
+}
+
+#[async_trait]
+impl Subscribe for InterruptedTcpStream {
+    async fn ready(&mut self) {}
+}
+
+#[async_trait]
+impl HostInputStream for InterruptedTcpStream {
+    fn read(&mut self, _size: usize) -> StreamResult<Bytes> {
+        Err(Self::error())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[async_trait]
+impl HostOutputStream for InterruptedTcpStream {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn write(&mut self, _bytes: Bytes) -> StreamResult<()> {
+        Err(Self::error())
+    }
+
+    fn flush(&mut self) -> StreamResult<()> {
+        Err(Self::error())
+    }
+
+    fn check_write(&mut self) -> StreamResult<usize> {
+        Err(Self::error())
+    }
+}
@@ -15,9 +15,11 @@
 use async_trait::async_trait;
 use wasmtime::component::Resource;
 
+use crate::durable_host::sockets::to_socket_addr;
 use crate::durable_host::DurableWorkerCtx;
 use crate::metrics::wasm::record_host_function_call;
 use crate::workerctx::WorkerCtx;
+use wasmtime_wasi::preview2::bindings::sockets::network::ErrorCode;
 use wasmtime_wasi::preview2::bindings::wasi::sockets::udp::{
     Host, HostIncomingDatagramStream, HostOutgoingDatagramStream, HostUdpSocket, IncomingDatagram,
     IncomingDatagramStream, IpAddressFamily, IpSocketAddress, Network, OutgoingDatagram,
@@ -53,6 +55,10 @@ impl<Ctx: WorkerCtx> HostUdpSocket for DurableWorkerCtx<Ctx> {
         SocketError,
     > {
         record_host_function_call("sockets::udp", "stream");
+        if let Some(remote_address) = &remote_address {
+            self.check_egress(|policy| policy.check_address(&to_socket_addr(remote_address)))
+                .map_err(|_| ErrorCode::AccessDenied)?;
+        }
         HostUdpSocket::stream(&mut self.as_wasi_view(), self_, remote_address)
     }
 
@@ -166,6 +172,12 @@ impl<Ctx: WorkerCtx> HostOutgoingDatagramStream for DurableWorkerCtx<Ctx> {
         datagrams: Vec<OutgoingDatagram>,
     ) -> Result<u64, SocketError> {
         record_host_function_call("sockets::udp", "send");
+        for datagram in &datagrams {
+            if let Some(remote_address) = &datagram.remote_address {
+                self.check_egress(|policy| policy.check_address(&to_socket_addr(remote_address)))
+                    .map_err(|_| ErrorCode::AccessDenied)?;
+            }
+        }
         HostOutgoingDatagramStream::send(&mut self.as_wasi_view(), self_, datagrams)
     }
 
@@ -18,6 +18,7 @@ use wasmtime::component::Resource;
 use crate::durable_host::DurableWorkerCtx;
 use crate::metrics::wasm::record_host_function_call;
 use crate::workerctx::WorkerCtx;
+use wasmtime_wasi::preview2::bindings::sockets::network::ErrorCode;
 use wasmtime_wasi::preview2::bindings::wasi::sockets::udp_create_socket::{
     Host, IpAddressFamily, UdpSocket,
 };
@@ -30,6 +31,8 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
         address_family: IpAddressFamily,
     ) -> Result<Resource<UdpSocket>, SocketError> {
         record_host_function_call("sockets::udp_create_socket", "create_udp_socket");
+        self.check_egress(|policy| policy.check_raw_socket())
+            .map_err(|_| ErrorCode::AccessDenied)?;
         Host::create_udp_socket(&mut self.as_wasi_view(), address_family)
     }
 }
@@ -0,0 +1,169 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::durable_host::serialized::SerializableError;
+use crate::durable_host::{Durability, DurableWorkerCtx};
+use crate::error::GolemError;
+use crate::metrics::wasm::record_host_function_call;
+use crate::preview2::golem;
+use crate::preview2::golem::ext::broadcast::{BroadcastResult, Host};
+use crate::services::rpc::RpcError;
+use crate::workerctx::WorkerCtx;
+use async_trait::async_trait;
+use futures_util::stream::{self, StreamExt};
+use golem_common::model::oplog::WrappedFunctionType;
+use golem_common::model::{ComponentId, IdempotencyKey, WorkerId};
+use golem_wasm_rpc::WitValue;
+use tracing::debug;
+use uuid::Uuid;
+
+/// Number of workers fetched at once when enumerating the targets of a broadcast
+const BROADCAST_PAGE_SIZE: u64 = 50;
+
+#[async_trait]
+impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
+    async fn broadcast_invoke_and_await(
+        &mut self,
+        component_id: golem::api::host::ComponentId,
+        filter: Option<golem::api::host::WorkerAnyFilter>,
+        function_name: String,
+        function_params: Vec<WitValue>,
+        max_concurrency: u32,
+    ) -> anyhow::Result<Vec<BroadcastResult>> {
+        record_host_function_call("golem::ext::broadcast", "broadcast-invoke-and-await");
+
+        let uuid = Durability::<Ctx, (u64, u64), SerializableError>::custom_wrap(
+            self,
+            WrappedFunctionType::ReadLocal,
+            "golem::ext::broadcast::broadcast-invoke-and-await idempotency key",
+            |_ctx| {
+                Box::pin(async move {
+                    let uuid = Uuid::new_v4();
+                    Ok::<Uuid, GolemError>(uuid)
+                })
+            },
+            |_ctx, uuid: &Uuid| Ok(uuid.as_u64_pair()),
+            |_ctx, (high_bits, low_bits)| {
+                Box::pin(async move { Ok(Uuid::from_u64_pair(high_bits, low_bits)) })
+            },
+        )
+        .await?;
+        let idempotency_key = IdempotencyKey::from_uuid(uuid);
+        let component_id: ComponentId = component_id.into();
+        let filter = filter.map(|filter| filter.into());
+        let call_chain = self.outgoing_call_chain();
+        let max_concurrency =
+            max_concurrency.clamp(1, self.state.config.limits.max_broadcast_concurrency.max(1));
+
+        // The results of all targets are recorded in a single entry. If the worker fails before
+        // writing it, the broadcast is repeated with the same derived idempotency keys, so the
+        // workers which were already invoked are not invoked again.
+        let results = Durability::<
+            Ctx,
+            Vec<(WorkerId, Result<WitValue, SerializableError>)>,
+            SerializableError,
+        >::custom_wrap(
+            self,
+            WrappedFunctionType::WriteRemote,
+            "golem::ext::broadcast::broadcast-invoke-and-await",
+            |ctx| {
+                Box::pin(async move {
+                    let mut worker_ids = Vec::new();
+                    let mut cursor = Some(0);
+                    while let Some(current) = cursor {
+                        let (next_cursor, workers) = ctx
+                            .state
+                            .get_workers(
+                                &component_id,
+                                filter.clone(),
+                                current,
+                                BROADCAST_PAGE_SIZE,
+                                false,
+                            )
+                            .await?;
+                        worker_ids.extend(
+                            workers
+                                .into_iter()
+                                .map(|worker| worker.worker_id)
+                                .filter(|worker_id| *worker_id != ctx.worker_id),
+                        );
+                        cursor = next_cursor;
+                    }
+                    debug!(
+                        "Broadcasting {function_name} from {} to {} workers",
+                        ctx.worker_id,
+                        worker_ids.len()
+                    );
+
+                    let rpc = ctx.rpc();
+                    let account_id = ctx.state.account_id.clone();
+                    let results = stream::iter(worker_ids)
+                        .map(|worker_id| {
+                            let rpc = rpc.clone();
+                            let idempotency_key = idempotency_key.derived(&worker_id);
+                            let function_name = function_name.clone();
+                            let function_params = function_params.clone();
+                            let account_id = account_id.clone();
+                            let call_chain = call_chain.clone();
+                            async move {
+                                let result = rpc
+                                    .invoke_and_await(
+                                        &worker_id,
+                                        Some(idempotency_key),
+                                        function_name,
+                                        function_params,
+                                        &account_id,
+                                        call_chain,
+                                    )
+                                    .await;
+                                (worker_id, result)
+                            }
+                        })
+                        .buffered(max_concurrency as usize)
+                        .collect::<Vec<_>>()
+                        .await;
+                    Ok::<_, GolemError>(results)
+                })
+            },
+            |_ctx, results: &Vec<(WorkerId, Result<WitValue, RpcError>)>| {
+                Ok(results
+                    .iter()
+                    .map(|(worker_id, result)| {
+                        (
+                            worker_id.clone(),
+                            result.clone().map_err(|err| (&err).into()),
+                        )
+                    })
+                    .collect())
+            },
+            |_ctx, serialized| {
+                Box::pin(async move {
+                    Ok(serialized
+                        .into_iter()
+                        .map(|(worker_id, result)| (worker_id, result.map_err(|err| err.into())))
+                        .collect())
+                })
+            },
+        )
+        .await?;
+
+        Ok(results
+            .into_iter()
+            .map(|(worker_id, result)| BroadcastResult {
+                worker_id: worker_id.into(),
+                result: result.map_err(|err| err.into()),
+            })
+            .collect())
+    }
+}
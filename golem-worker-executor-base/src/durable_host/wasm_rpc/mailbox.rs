@@ -0,0 +1,174 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::durable_host::serialized::SerializableError;
+use crate::durable_host::wasm_rpc::topics::durable_uuid;
+use crate::durable_host::{Durability, DurableWorkerCtx};
+use crate::error::GolemError;
+use crate::metrics::wasm::record_host_function_call;
+use crate::model::{InterruptKind, PersistenceLevel, TrapType};
+use crate::preview2::golem;
+use crate::preview2::golem::ext::mailbox::Host;
+use crate::services::mailbox::MailboxMessage;
+use crate::workerctx::WorkerCtx;
+use async_trait::async_trait;
+use golem_common::model::oplog::WrappedFunctionType;
+use golem_common::model::{IdempotencyKey, WorkerId};
+use tracing::{debug, warn};
+
+#[async_trait]
+impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
+    async fn send(
+        &mut self,
+        worker_id: golem::api::host::WorkerId,
+        payload: Vec<u8>,
+    ) -> anyhow::Result<()> {
+        record_host_function_call("golem::ext::mailbox", "send");
+
+        let worker_id: WorkerId = worker_id.into();
+        let account_id = self.state.account_id.clone();
+        // The id is the same when the send is retried after a crash, so the receiving mailbox
+        // does not get the message twice
+        let message_id = IdempotencyKey::from_uuid(
+            durable_uuid(self, "golem::ext::mailbox::send message id").await?,
+        );
+        Durability::<Ctx, (), SerializableError>::wrap(
+            self,
+            WrappedFunctionType::WriteRemote,
+            "golem::ext::mailbox::send",
+            move |ctx| {
+                Box::pin(async move {
+                    ctx.state
+                        .worker_proxy
+                        .send_mailbox_message(&worker_id, message_id, payload, &account_id)
+                        .await
+                })
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn receive(&mut self) -> anyhow::Result<Vec<u8>> {
+        record_host_function_call("golem::ext::mailbox", "receive");
+
+        // Nothing is written to the oplog while the mailbox is empty, so when the worker is
+        // resumed by an incoming message it gets to this point again in live mode
+        if self.state.is_live() && self.peek_mailbox().await?.is_none() {
+            debug!(
+                "Suspending worker {} until a message arrives to its mailbox",
+                self.worker_id
+            );
+            self.state.suspended_on_empty_mailbox = true;
+            return Err(InterruptKind::Suspend.into());
+        }
+
+        let worker_id = self.worker_id.clone();
+        let after = self.state.last_received_message_id.clone();
+        let message = Durability::<Ctx, MailboxMessage, SerializableError>::wrap(
+            self,
+            WrappedFunctionType::ReadRemote,
+            "golem::ext::mailbox::receive",
+            move |ctx| {
+                Box::pin(async move {
+                    ctx.state
+                        .mailbox_service
+                        .receive_after(&worker_id, after)
+                        .await?
+                        .ok_or_else(|| {
+                            GolemError::runtime(format!("The mailbox of {worker_id} is empty"))
+                        })
+                })
+            },
+        )
+        .await?;
+
+        self.trim_mailbox(&message.id).await;
+        self.state.last_received_message_id = Some(message.id);
+        Ok(message.payload)
+    }
+
+    async fn try_receive(&mut self) -> anyhow::Result<Option<Vec<u8>>> {
+        record_host_function_call("golem::ext::mailbox", "try_receive");
+
+        let worker_id = self.worker_id.clone();
+        let after = self.state.last_received_message_id.clone();
+        let message = Durability::<Ctx, Option<MailboxMessage>, SerializableError>::wrap(
+            self,
+            WrappedFunctionType::ReadRemote,
+            "golem::ext::mailbox::try_receive",
+            move |ctx| {
+                Box::pin(async move {
+                    ctx.state
+                        .mailbox_service
+                        .receive_after(&worker_id, after)
+                        .await
+                })
+            },
+        )
+        .await?;
+
+        match message {
+            Some(message) => {
+                self.trim_mailbox(&message.id).await;
+                self.state.last_received_message_id = Some(message.id);
+                Ok(Some(message.payload))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
+    /// Returns the next message of the worker's mailbox without receiving it
+    async fn peek_mailbox(&self) -> Result<Option<MailboxMessage>, GolemError> {
+        self.state
+            .mailbox_service
+            .receive_after(&self.worker_id, self.state.last_received_message_id.clone())
+            .await
+    }
+
+    /// Returns whether a message arrived to the mailbox while the worker was being suspended
+    /// because of finding it empty
+    pub(crate) async fn has_missed_mailbox_message(
+        &self,
+        trap_type: &TrapType,
+    ) -> Result<bool, GolemError> {
+        if self.state.suspended_on_empty_mailbox
+            && matches!(trap_type, TrapType::Interrupt(InterruptKind::Suspend))
+        {
+            Ok(self.peek_mailbox().await?.is_some())
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Removes the messages up to a received one from the mailbox, after committing the oplog
+    /// entry recording its receiving, as replaying the worker takes it from the oplog. Without
+    /// persistence the message is received from the mailbox again when replaying, so it is kept.
+    async fn trim_mailbox(&mut self, message_id: &str) {
+        if self.state.is_live() && self.state.persistence_level != PersistenceLevel::PersistNothing
+        {
+            self.state.oplog.commit().await;
+            if let Err(err) = self
+                .state
+                .mailbox_service
+                .trim(&self.worker_id, message_id)
+                .await
+            {
+                warn!("Failed to trim the mailbox of {}: {err}", self.worker_id);
+            }
+        }
+    }
+}
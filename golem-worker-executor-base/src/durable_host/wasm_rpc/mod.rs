@@ -12,22 +12,33 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod broadcast;
+mod mailbox;
+mod topics;
+
 use crate::durable_host::serialized::SerializableError;
-use crate::durable_host::{Durability, DurableWorkerCtx};
+use crate::durable_host::{Durability, DurableWorkerCtx, PrivateDurableWorkerState};
 use crate::error::GolemError;
 use crate::metrics::wasm::record_host_function_call;
-use crate::services::rpc::{RpcDemand, RpcError};
+use crate::model::{InterruptKind, PersistenceLevel, TrapType};
+use crate::preview2::golem::ext::async_rpc::{Host as AsyncRpcHost, HostFutureInvokeResult};
+use crate::services::rpc::{Rpc, RpcDemand, RpcError};
 use crate::workerctx::WorkerCtx;
 use anyhow::anyhow;
 use async_trait::async_trait;
-use golem_common::model::oplog::WrappedFunctionType;
-use golem_common::model::{ComponentId, IdempotencyKey, WorkerId};
+use golem_common::model::oplog::{OplogEntry, WrappedFunctionType};
+use golem_common::model::{AccountId, ComponentId, IdempotencyKey, WorkerId};
 use golem_wasm_rpc::golem::rpc::types::Uri;
 use golem_wasm_rpc::{HostWasmRpc, WasmRpcEntry, WitValue};
 use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
 use tracing::{debug, error};
 use uuid::Uuid;
 use wasmtime::component::Resource;
+use wasmtime_wasi::preview2::{subscribe, Pollable, Subscribe};
 
 #[async_trait]
 impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
@@ -39,16 +50,27 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
                 let demand = self.rpc().create_demand(&remote_worker_id).await;
                 let entry = self.table.push(WasmRpcEntry {
                     payload: Box::new(WasmRpcEntryPayload {
-                        demand,
-                        remote_worker_id,
+                        demand: Some(demand),
+                        target: RpcTarget::Worker(remote_worker_id),
                     }),
                 })?;
                 Ok(entry)
             }
-            _ => Err(anyhow!(
-                "Invalid URI: {}. Must be worker://component-id/worker-name",
-                location.value
-            )),
+            _ => match location.parse_as_ephemeral_golem_uri() {
+                Some(component_id) => {
+                    let entry = self.table.push(WasmRpcEntry {
+                        payload: Box::new(WasmRpcEntryPayload {
+                            demand: None,
+                            target: RpcTarget::Ephemeral(component_id),
+                        }),
+                    })?;
+                    Ok(entry)
+                }
+                None => Err(anyhow!(
+                    "Invalid URI: {}. Must be worker://component-id/worker-name, or worker://component-id for ephemeral workers",
+                    location.value
+                )),
+            },
         }
     }
 
@@ -62,7 +84,7 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
 
         let entry = self.table.get(&self_)?;
         let payload = entry.payload.downcast_ref::<WasmRpcEntryPayload>().unwrap();
-        let remote_worker_id = payload.remote_worker_id.clone();
+        let target = payload.target.clone();
 
         let uuid = Durability::<Ctx, (u64, u64), SerializableError>::custom_wrap(
             self,
@@ -81,6 +103,7 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
         )
         .await?;
         let idempotency_key = IdempotencyKey::from_uuid(uuid);
+        let call_chain = self.outgoing_call_chain();
 
         let result = Durability::<Ctx, WitValue, SerializableError>::wrap(
             self,
@@ -88,13 +111,14 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
             "golem::rpc::wasm-rpc::invoke-and-await",
             |ctx| {
                 Box::pin(async move {
-                    ctx.rpc()
+                    target
                         .invoke_and_await(
-                            &remote_worker_id,
-                            Some(idempotency_key),
+                            ctx.rpc(),
+                            idempotency_key,
                             function_name,
                             function_params,
                             &ctx.state.account_id,
+                            call_chain,
                         )
                         .await
                 })
@@ -124,7 +148,15 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
 
         let entry = self.table.get(&self_)?;
         let payload = entry.payload.downcast_ref::<WasmRpcEntryPayload>().unwrap();
-        let remote_worker_id = payload.remote_worker_id.clone();
+        let remote_worker_id = match &payload.target {
+            RpcTarget::Worker(worker_id) => worker_id.clone(),
+            // Nothing would ever observe the outcome of an ephemeral worker which is not awaited
+            RpcTarget::Ephemeral(component_id) => {
+                return Ok(Err(golem_wasm_rpc::RpcError::Denied(format!(
+                    "Ephemeral workers of {component_id} can only be invoked by awaiting the result"
+                ))));
+            }
+        };
 
         let uuid = Durability::<Ctx, (u64, u64), SerializableError>::custom_wrap(
             self,
@@ -190,6 +222,8 @@ impl From<RpcError> for golem_wasm_rpc::RpcError {
             RpcError::RemoteInternalError { details } => {
                 golem_wasm_rpc::RpcError::RemoteInternalError(details)
             }
+            // golem:rpc has no dedicated case for this, so it is reported as a denied call
+            RpcError::Deadlock { .. } => golem_wasm_rpc::RpcError::Denied(value.to_string()),
         }
     }
 }
@@ -197,15 +231,436 @@ impl From<RpcError> for golem_wasm_rpc::RpcError {
 #[async_trait]
 impl<Ctx: WorkerCtx> golem_wasm_rpc::Host for DurableWorkerCtx<Ctx> {}
 
+#[async_trait]
+impl<Ctx: WorkerCtx> AsyncRpcHost for DurableWorkerCtx<Ctx> {
+    async fn async_invoke_and_await(
+        &mut self,
+        rpc: Resource<WasmRpcEntry>,
+        function_name: String,
+        function_params: Vec<WitValue>,
+    ) -> anyhow::Result<Resource<FutureInvokeResultEntry>> {
+        record_host_function_call("golem::ext::async-rpc", "async-invoke-and-await");
+
+        let entry = self.table.get(&rpc)?;
+        let payload = entry.payload.downcast_ref::<WasmRpcEntryPayload>().unwrap();
+        let target = payload.target.clone();
+
+        let uuid = Durability::<Ctx, (u64, u64), SerializableError>::custom_wrap(
+            self,
+            WrappedFunctionType::ReadLocal,
+            "golem::ext::async-rpc::async-invoke-and-await idempotency key",
+            |_ctx| {
+                Box::pin(async move {
+                    let uuid = Uuid::new_v4();
+                    Ok::<Uuid, GolemError>(uuid)
+                })
+            },
+            |_ctx, uuid: &Uuid| Ok(uuid.as_u64_pair()),
+            |_ctx, (high_bits, low_bits)| {
+                Box::pin(async move { Ok(Uuid::from_u64_pair(high_bits, low_bits)) })
+            },
+        )
+        .await?;
+
+        let idempotency_key = IdempotencyKey::from_uuid(uuid);
+        // Invocations of ephemeral workers are not deduplicated, so they cannot be started
+        // again after resuming the worker, and are always waited for
+        let suspend_after = match &target {
+            RpcTarget::Worker(_) => Some(self.state.config.suspend.suspend_after),
+            RpcTarget::Ephemeral(_) => None,
+        };
+        let request = FutureInvokeRequest {
+            target,
+            idempotency_key: idempotency_key.clone(),
+            function_name,
+            function_params,
+            account_id: self.state.account_id.clone(),
+            call_chain: self.outgoing_call_chain(),
+        };
+        // During replay the result is taken from the oplog by `get`, so the call is only
+        // started if the worker reaches live mode before having received it
+        let state = if self.state.is_live() {
+            FutureInvokeResultState::start(self.rpc(), request)
+        } else {
+            FutureInvokeResultState::Deferred { request }
+        };
+        let entry = self.table.push(FutureInvokeResultEntry {
+            idempotency_key,
+            state,
+            suspend_after,
+        })?;
+        Ok(entry)
+    }
+}
+
+#[async_trait]
+impl<Ctx: WorkerCtx> HostFutureInvokeResult for DurableWorkerCtx<Ctx> {
+    async fn subscribe(
+        &mut self,
+        self_: Resource<FutureInvokeResultEntry>,
+    ) -> anyhow::Result<Resource<Pollable>> {
+        record_host_function_call("golem::ext::async-rpc::future-invoke-result", "subscribe");
+
+        if self.state.is_live() {
+            let rpc = self.rpc();
+            let entry = self.table.get_mut(&self_)?;
+            entry.start_if_deferred(rpc);
+        }
+        subscribe(&mut self.table, self_)
+    }
+
+    async fn get(
+        &mut self,
+        self_: Resource<FutureInvokeResultEntry>,
+    ) -> anyhow::Result<Option<Result<WitValue, golem_wasm_rpc::RpcError>>> {
+        record_host_function_call("golem::ext::async-rpc::future-invoke-result", "get");
+
+        // Only the final result is recorded. During replay the call is reported to be in
+        // progress until the recorded result of this call is the next entry to be replayed.
+        let idempotency_key = self.table.get(&self_)?.idempotency_key.clone();
+        let live_result = if self.state.is_live()
+            || self.state.persistence_level == PersistenceLevel::PersistNothing
+        {
+            if let Some(handle) = self.table.get_mut(&self_)?.take_overdue() {
+                return Err(self.suspend_until_completed(handle).await);
+            }
+            let rpc = self.rpc();
+            let Some(result) = self.table.get_mut(&self_)?.take_result(rpc).await? else {
+                return Ok(None);
+            };
+            Some(result)
+        } else if self.state.is_future_result_recorded(&idempotency_key).await {
+            None
+        } else {
+            return Ok(None);
+        };
+
+        let handle = self_.rep();
+        let result: Result<WitValue, RpcError> =
+            Durability::<Ctx, SerializableFutureInvokeResult, SerializableError>::custom_wrap(
+                self,
+                WrappedFunctionType::ReadRemote,
+                FUTURE_INVOKE_RESULT_GET_FUNCTION,
+                |_ctx| {
+                    Box::pin(async move {
+                        live_result.ok_or_else(|| {
+                            GolemError::runtime("The result of the async RPC invocation is missing")
+                        })
+                    })
+                },
+                |_ctx, result: &Result<WitValue, RpcError>| {
+                    Ok((idempotency_key, result.clone().map_err(|err| (&err).into())))
+                },
+                |ctx, (_, serialized)| {
+                    Box::pin(async move {
+                        let entry = ctx
+                            .table
+                            .get_mut(&Resource::<FutureInvokeResultEntry>::new_borrow(handle))
+                            .map_err(|err| GolemError::runtime(err.to_string()))?;
+                        entry.state = FutureInvokeResultState::Consumed;
+                        Ok(serialized.map_err(|err| err.into()))
+                    })
+                },
+            )
+            .await?;
+
+        match result {
+            Ok(result) => {
+                debug!("Async RPC result for {}: {result:?}", self.worker_id);
+                Ok(Some(Ok(result)))
+            }
+            Err(err) => {
+                error!("Async RPC error for {}: {err}", self.worker_id);
+                Ok(Some(Err(err.into())))
+            }
+        }
+    }
+
+    fn drop(&mut self, rep: Resource<FutureInvokeResultEntry>) -> anyhow::Result<()> {
+        record_host_function_call("golem::ext::async-rpc::future-invoke-result", "drop");
+
+        // Dropping the handle does not cancel the remote invocation, it only detaches from it
+        let _ = self.table.delete(rep)?;
+        Ok(())
+    }
+}
+
+/// The name under which the result of an async RPC invocation is recorded in the oplog
+const FUTURE_INVOKE_RESULT_GET_FUNCTION: &str = "golem::ext::async-rpc::future-invoke-result::get";
+
+/// The recorded result of an async RPC invocation, together with the invocation's idempotency key
+type SerializableFutureInvokeResult = (IdempotencyKey, Result<WitValue, SerializableError>);
+
+impl<Ctx: WorkerCtx> PrivateDurableWorkerState<Ctx> {
+    /// Checks whether the next entry to be replayed is the result of the async RPC invocation
+    async fn is_future_result_recorded(&mut self, idempotency_key: &IdempotencyKey) -> bool {
+        match self.peek_imported_function_invoked().await {
+            Some(entry) => {
+                let is_get_result = matches!(
+                    &entry,
+                    OplogEntry::ImportedFunctionInvoked { function_name, .. }
+                        if function_name == FUTURE_INVOKE_RESULT_GET_FUNCTION
+                );
+                is_get_result
+                    && matches!(
+                        entry.payload::<Result<SerializableFutureInvokeResult, SerializableError>>(),
+                        Ok(Some(Ok((recorded_key, _)))) if recorded_key == *idempotency_key
+                    )
+            }
+            None => false,
+        }
+    }
+}
+
+impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
+    /// Suspends the worker until an async RPC invocation it waited for too long completes.
+    /// Its result is not kept: the resumed worker starts the invocation again with the same
+    /// idempotency key, and gets the result the remote worker stored for it.
+    async fn suspend_until_completed(
+        &mut self,
+        handle: JoinHandle<Result<WitValue, RpcError>>,
+    ) -> anyhow::Error {
+        debug!(
+            "Suspending worker {} until the result of an async RPC invocation arrives",
+            self.worker_id
+        );
+        // The worker is woken up by completing a promise, the same way as after a sleep
+        let promise_id = self.state.create_promise(None).await;
+        let completed = Arc::new(AtomicBool::new(false));
+        self.state.suspended_on_async_rpc = Some(completed.clone());
+
+        let scheduler_service = self.state.scheduler_service.clone();
+        let clock = self.state.clock.clone();
+        tokio::spawn(async move {
+            let _ = handle.await;
+            completed.store(true, Ordering::SeqCst);
+            scheduler_service
+                .schedule(clock.now().into(), promise_id)
+                .await;
+        });
+        InterruptKind::Suspend.into()
+    }
+
+    /// Returns whether the async RPC invocation the worker is being suspended for completed
+    /// before the worker got deactivated
+    pub(crate) fn has_missed_async_rpc_result(&self, trap_type: &TrapType) -> bool {
+        matches!(trap_type, TrapType::Interrupt(InterruptKind::Suspend))
+            && self
+                .state
+                .suspended_on_async_rpc
+                .as_ref()
+                .is_some_and(|completed| completed.load(Ordering::SeqCst))
+    }
+}
+
 pub struct WasmRpcEntryPayload {
     #[allow(dead_code)]
-    demand: Box<dyn RpcDemand>,
-    remote_worker_id: WorkerId,
+    demand: Option<Box<dyn RpcDemand>>,
+    target: RpcTarget,
+}
+
+/// The worker a wasm-rpc resource invokes
+#[derive(Clone, Debug)]
+enum RpcTarget {
+    Worker(WorkerId),
+    /// A fresh ephemeral worker of the component for each invocation
+    Ephemeral(ComponentId),
+}
+
+impl RpcTarget {
+    async fn invoke_and_await(
+        &self,
+        rpc: Arc<dyn Rpc + Send + Sync>,
+        idempotency_key: IdempotencyKey,
+        function_name: String,
+        function_params: Vec<WitValue>,
+        account_id: &AccountId,
+        call_chain: Vec<WorkerId>,
+    ) -> Result<WitValue, RpcError> {
+        match self {
+            RpcTarget::Worker(worker_id) => {
+                rpc.invoke_and_await(
+                    worker_id,
+                    Some(idempotency_key),
+                    function_name,
+                    function_params,
+                    account_id,
+                    call_chain,
+                )
+                .await
+            }
+            RpcTarget::Ephemeral(component_id) => {
+                rpc.invoke_and_await_ephemeral(
+                    component_id,
+                    function_name,
+                    function_params,
+                    account_id,
+                    call_chain,
+                )
+                .await
+            }
+        }
+    }
+}
+
+pub struct FutureInvokeResultEntry {
+    idempotency_key: IdempotencyKey,
+    state: FutureInvokeResultState,
+    /// How long the worker waits for the result before getting suspended, or none if it never
+    /// gets suspended
+    suspend_after: Option<Duration>,
+}
+
+impl FutureInvokeResultEntry {
+    /// Starts the remote invocation if it was created during replay
+    fn start_if_deferred(&mut self, rpc: Arc<dyn Rpc + Send + Sync>) {
+        self.state = match std::mem::replace(&mut self.state, FutureInvokeResultState::Consumed) {
+            FutureInvokeResultState::Deferred { request } => {
+                FutureInvokeResultState::start(rpc, request)
+            }
+            state => state,
+        };
+    }
+
+    /// Takes the handle of the invocation if waiting for it took too long and it has not
+    /// completed since
+    fn take_overdue(&mut self) -> Option<JoinHandle<Result<WitValue, RpcError>>> {
+        match std::mem::replace(&mut self.state, FutureInvokeResultState::Consumed) {
+            FutureInvokeResultState::Overdue { handle } if !handle.is_finished() => Some(handle),
+            FutureInvokeResultState::Overdue { handle } => {
+                self.state = FutureInvokeResultState::Pending { handle };
+                None
+            }
+            state => {
+                self.state = state;
+                None
+            }
+        }
+    }
+
+    /// Takes the result of the invocation if it is available, without waiting for it
+    async fn take_result(
+        &mut self,
+        rpc: Arc<dyn Rpc + Send + Sync>,
+    ) -> Result<Option<Result<WitValue, RpcError>>, GolemError> {
+        match std::mem::replace(&mut self.state, FutureInvokeResultState::Consumed) {
+            FutureInvokeResultState::Pending { mut handle } if handle.is_finished() => {
+                Ok(Some(join_invoke_result(&mut handle).await))
+            }
+            FutureInvokeResultState::Pending { handle } => {
+                self.state = FutureInvokeResultState::Pending { handle };
+                Ok(None)
+            }
+            FutureInvokeResultState::Overdue { mut handle } if handle.is_finished() => {
+                Ok(Some(join_invoke_result(&mut handle).await))
+            }
+            FutureInvokeResultState::Overdue { handle } => {
+                self.state = FutureInvokeResultState::Overdue { handle };
+                Ok(None)
+            }
+            FutureInvokeResultState::Completed { result } => Ok(Some(result)),
+            FutureInvokeResultState::Deferred { request } => {
+                self.state = FutureInvokeResultState::start(rpc, request);
+                Ok(None)
+            }
+            FutureInvokeResultState::Consumed => Err(GolemError::runtime(
+                "The result of the async RPC invocation has already been consumed",
+            )),
+        }
+    }
+}
+
+/// The pollable also becomes ready when the result did not arrive within `suspend_after`, so
+/// the following `get` can suspend the worker
+#[async_trait]
+impl Subscribe for FutureInvokeResultEntry {
+    async fn ready(&mut self) {
+        if let FutureInvokeResultState::Pending { handle } = &mut self.state {
+            let result = match self.suspend_after {
+                Some(suspend_after) => {
+                    tokio::time::timeout(suspend_after, join_invoke_result(handle))
+                        .await
+                        .ok()
+                }
+                None => Some(join_invoke_result(handle).await),
+            };
+            self.state = match (
+                result,
+                std::mem::replace(&mut self.state, FutureInvokeResultState::Consumed),
+            ) {
+                (Some(result), _) => FutureInvokeResultState::Completed { result },
+                (None, FutureInvokeResultState::Pending { handle }) => {
+                    FutureInvokeResultState::Overdue { handle }
+                }
+                (None, state) => state,
+            };
+        }
+    }
+}
+
+enum FutureInvokeResultState {
+    /// Created during replay, the invocation has not been started yet
+    Deferred {
+        request: FutureInvokeRequest,
+    },
+    Pending {
+        handle: JoinHandle<Result<WitValue, RpcError>>,
+    },
+    /// Still pending after the worker waited `suspend_after` for it
+    Overdue {
+        handle: JoinHandle<Result<WitValue, RpcError>>,
+    },
+    Completed {
+        result: Result<WitValue, RpcError>,
+    },
+    Consumed,
+}
+
+impl FutureInvokeResultState {
+    fn start(rpc: Arc<dyn Rpc + Send + Sync>, request: FutureInvokeRequest) -> Self {
+        let handle = tokio::spawn(async move {
+            request
+                .target
+                .invoke_and_await(
+                    rpc,
+                    request.idempotency_key,
+                    request.function_name,
+                    request.function_params,
+                    &request.account_id,
+                    request.call_chain,
+                )
+                .await
+        });
+        Self::Pending { handle }
+    }
+}
+
+struct FutureInvokeRequest {
+    target: RpcTarget,
+    idempotency_key: IdempotencyKey,
+    function_name: String,
+    function_params: Vec<WitValue>,
+    account_id: AccountId,
+    call_chain: Vec<WorkerId>,
+}
+
+async fn join_invoke_result(
+    handle: &mut JoinHandle<Result<WitValue, RpcError>>,
+) -> Result<WitValue, RpcError> {
+    match handle.await {
+        Ok(result) => result,
+        Err(err) => Err(RpcError::ProtocolError {
+            details: format!("Async RPC invocation failed: {err}"),
+        }),
+    }
 }
 
 pub trait UriExtensions {
     fn parse_as_golem_uri(&self) -> Option<(WorkerId, Option<String>)>;
 
+    /// Parses `worker://component-id`, which addresses ephemeral workers of a component
+    fn parse_as_ephemeral_golem_uri(&self) -> Option<ComponentId>;
+
     fn golem_uri(worker_id: &WorkerId, function_name: Option<&str>) -> Self;
 }
 
@@ -244,6 +699,15 @@ impl UriExtensions for Uri {
         }
     }
 
+    fn parse_as_ephemeral_golem_uri(&self) -> Option<ComponentId> {
+        let component_id = self.value.strip_prefix("worker://")?;
+        if component_id.contains('/') {
+            None
+        } else {
+            ComponentId::from_str(component_id).ok()
+        }
+    }
+
     fn golem_uri(worker_id: &WorkerId, function_name: Option<&str>) -> Self {
         Self {
             value: match function_name {
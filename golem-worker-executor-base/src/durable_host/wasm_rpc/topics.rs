@@ -0,0 +1,178 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::durable_host::serialized::SerializableError;
+use crate::durable_host::{Durability, DurableWorkerCtx};
+use crate::error::GolemError;
+use crate::metrics::wasm::record_host_function_call;
+use crate::preview2::golem;
+use crate::preview2::golem::ext::topics::Host;
+use crate::services::topic::{SubscriptionTarget, TopicMessage, TopicSubscription};
+use crate::workerctx::WorkerCtx;
+use async_trait::async_trait;
+use golem_common::model::oplog::WrappedFunctionType;
+use golem_common::model::WorkerId;
+use golem_wasm_rpc::WitValue;
+use uuid::Uuid;
+
+#[async_trait]
+impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
+    async fn publish(&mut self, topic: String, params: Vec<WitValue>) -> anyhow::Result<String> {
+        record_host_function_call("golem::ext::topics", "publish");
+
+        // The message id is recorded before publishing, so if the worker fails before the
+        // publish is recorded, the retried publish uses the same id and the subscribers see a
+        // duplicate message with the same idempotency key.
+        let message_id = durable_uuid(self, "golem::ext::topics::publish message id")
+            .await?
+            .to_string();
+        let message = TopicMessage {
+            message_id: message_id.clone(),
+            params,
+        };
+        Durability::<Ctx, (), SerializableError>::wrap(
+            self,
+            WrappedFunctionType::WriteRemote,
+            "golem::ext::topics::publish",
+            move |ctx| {
+                Box::pin(async move {
+                    ctx.state
+                        .topic_service
+                        .publish(&ctx.state.account_id, &topic, message)
+                        .await
+                })
+            },
+        )
+        .await?;
+        Ok(message_id)
+    }
+
+    async fn subscribe(
+        &mut self,
+        topic: String,
+        component_id: golem::api::host::ComponentId,
+        worker_name: String,
+        function_name: String,
+    ) -> anyhow::Result<String> {
+        record_host_function_call("golem::ext::topics", "subscribe");
+
+        let target = SubscriptionTarget::Worker(WorkerId {
+            component_id: component_id.into(),
+            worker_name,
+        });
+        durable_subscribe(
+            self,
+            "golem::ext::topics::subscribe",
+            topic,
+            target,
+            function_name,
+        )
+        .await
+    }
+
+    async fn subscribe_component(
+        &mut self,
+        topic: String,
+        component_id: golem::api::host::ComponentId,
+        function_name: String,
+    ) -> anyhow::Result<String> {
+        record_host_function_call("golem::ext::topics", "subscribe_component");
+
+        let target = SubscriptionTarget::Component(component_id.into());
+        durable_subscribe(
+            self,
+            "golem::ext::topics::subscribe_component",
+            topic,
+            target,
+            function_name,
+        )
+        .await
+    }
+
+    async fn unsubscribe(&mut self, topic: String, subscription_id: String) -> anyhow::Result<()> {
+        record_host_function_call("golem::ext::topics", "unsubscribe");
+
+        Durability::<Ctx, (), SerializableError>::wrap(
+            self,
+            WrappedFunctionType::WriteRemote,
+            "golem::ext::topics::unsubscribe",
+            move |ctx| {
+                Box::pin(async move {
+                    ctx.state
+                        .topic_service
+                        .unsubscribe(&ctx.state.account_id, &topic, &subscription_id)
+                        .await
+                })
+            },
+        )
+        .await
+    }
+}
+
+/// Registers a subscription with a subscription id recorded in the oplog
+async fn durable_subscribe<Ctx: WorkerCtx>(
+    ctx: &mut DurableWorkerCtx<Ctx>,
+    function_name: &str,
+    topic: String,
+    target: SubscriptionTarget,
+    subscribed_function_name: String,
+) -> anyhow::Result<String> {
+    let subscription_id = durable_uuid(ctx, &format!("{function_name} subscription id"))
+        .await?
+        .to_string();
+    let subscription = TopicSubscription {
+        subscription_id: subscription_id.clone(),
+        target,
+        account_id: ctx.state.account_id.clone(),
+        function_name: subscribed_function_name,
+    };
+    Durability::<Ctx, (), SerializableError>::wrap(
+        ctx,
+        WrappedFunctionType::WriteRemote,
+        function_name,
+        move |ctx| {
+            Box::pin(async move {
+                ctx.state
+                    .topic_service
+                    .subscribe(&topic, subscription)
+                    .await
+            })
+        },
+    )
+    .await?;
+    Ok(subscription_id)
+}
+
+/// Generates a random UUID which is recorded in the oplog, so it is the same when replayed
+pub(super) async fn durable_uuid<Ctx: WorkerCtx>(
+    ctx: &mut DurableWorkerCtx<Ctx>,
+    function_name: &str,
+) -> Result<Uuid, GolemError> {
+    Durability::<Ctx, (u64, u64), SerializableError>::custom_wrap(
+        ctx,
+        WrappedFunctionType::ReadLocal,
+        function_name,
+        |_ctx| {
+            Box::pin(async move {
+                let uuid = Uuid::new_v4();
+                Ok::<Uuid, GolemError>(uuid)
+            })
+        },
+        |_ctx, uuid: &Uuid| Ok(uuid.as_u64_pair()),
+        |_ctx, (high_bits, low_bits)| {
+            Box::pin(async move { Ok(Uuid::from_u64_pair(high_bits, low_bits)) })
+        },
+    )
+    .await
+}
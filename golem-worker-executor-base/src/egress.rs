@@ -0,0 +1,291 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{Display, Formatter};
+use std::net::{IpAddr, SocketAddr};
+
+use bincode::{Decode, Encode};
+use ipnet::IpNet;
+use serde::Deserialize;
+
+/// Restricts the outgoing network connections a worker can make.
+///
+/// The entries of `allowed_hosts` and `denied_hosts` can be host names (`example.com`),
+/// wildcards matching all subdomains (`*.example.com`), IP addresses or CIDR ranges
+/// (`10.0.0.0/8`). Host names can only be matched against outgoing HTTP requests, as sockets
+/// only see the resolved IP addresses. The host names of HTTP requests are resolved when the
+/// policy has address rules, so these rules cannot be bypassed by using a name.
+///
+/// Deny rules take precedence. An empty allow list allows every host (or port).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+pub struct EgressPolicy {
+    pub allowed_hosts: Vec<String>,
+    pub denied_hosts: Vec<String>,
+    pub allowed_ports: Vec<u16>,
+    pub denied_ports: Vec<u16>,
+    /// Blocks the creation of TCP and UDP sockets through `wasi:sockets`
+    pub block_raw_sockets: bool,
+}
+
+impl EgressPolicy {
+    /// Checks an outgoing HTTP request to the given host and port
+    pub fn check_host(&self, host: &str, port: u16) -> Result<(), EgressViolation> {
+        self.check_resolved_host(host, &[], port)
+    }
+
+    /// Checks an outgoing HTTP request to the given host and port, where `addresses` are the
+    /// IP addresses the host name resolved to.
+    ///
+    /// The request is denied if either the name or any of the addresses is denied, and allowed
+    /// if either the name or all of the addresses are allowed.
+    pub fn check_resolved_host(
+        &self,
+        host: &str,
+        addresses: &[IpAddr],
+        port: u16,
+    ) -> Result<(), EgressViolation> {
+        let host = host.trim_start_matches('[').trim_end_matches(']');
+        let targets = match host.parse::<IpAddr>() {
+            Ok(ip) => vec![Target::Address(ip)],
+            Err(_) => std::iter::once(Target::Host(host.to_lowercase()))
+                .chain(addresses.iter().map(|ip| Target::Address(*ip)))
+                .collect(),
+        };
+        self.check(&targets, host, port)
+    }
+
+    /// Checks an outgoing socket connection or datagram to the given address
+    pub fn check_address(&self, address: &SocketAddr) -> Result<(), EgressViolation> {
+        let ip = address.ip();
+        self.check(&[Target::Address(ip)], &ip.to_string(), address.port())
+    }
+
+    /// Checks the creation of a raw TCP or UDP socket
+    pub fn check_raw_socket(&self) -> Result<(), EgressViolation> {
+        if self.block_raw_sockets {
+            Err(EgressViolation::RawSocketsBlocked)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Whether any of the host rules is an IP address or a CIDR range, in which case host names
+    /// have to be resolved to be checked
+    pub fn has_address_rules(&self) -> bool {
+        self.allowed_hosts
+            .iter()
+            .chain(self.denied_hosts.iter())
+            .any(|rule| {
+                let rule = rule.trim();
+                rule.parse::<IpNet>().is_ok() || rule.parse::<IpAddr>().is_ok()
+            })
+    }
+
+    fn check(&self, targets: &[Target], display: &str, port: u16) -> Result<(), EgressViolation> {
+        let is_denied = targets
+            .iter()
+            .any(|target| self.denied_hosts.iter().any(|rule| target.matches(rule)));
+        let is_allowed = || {
+            let matches_allowed =
+                |target: &Target| self.allowed_hosts.iter().any(|rule| target.matches(rule));
+            let mut addresses = targets
+                .iter()
+                .filter(|target| matches!(target, Target::Address(_)))
+                .peekable();
+            let name_allowed = targets
+                .iter()
+                .any(|target| matches!(target, Target::Host(_)) && matches_allowed(target));
+            name_allowed || (addresses.peek().is_some() && addresses.all(matches_allowed))
+        };
+
+        if is_denied {
+            Err(EgressViolation::HostDenied(display.to_string()))
+        } else if self.denied_ports.contains(&port) {
+            Err(EgressViolation::PortDenied(port))
+        } else if !self.allowed_hosts.is_empty() && !is_allowed() {
+            Err(EgressViolation::HostNotAllowed(display.to_string()))
+        } else if !self.allowed_ports.is_empty() && !self.allowed_ports.contains(&port) {
+            Err(EgressViolation::PortNotAllowed(port))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+enum Target {
+    Host(String),
+    Address(IpAddr),
+}
+
+impl Target {
+    fn matches(&self, rule: &str) -> bool {
+        let rule = rule.trim().to_lowercase();
+        match self {
+            Target::Host(host) => match rule.strip_prefix("*.") {
+                Some(suffix) => host
+                    .strip_suffix(suffix)
+                    .map(|prefix| prefix.ends_with('.'))
+                    .unwrap_or(false),
+                None => *host == rule,
+            },
+            Target::Address(ip) => {
+                if let Ok(net) = rule.parse::<IpNet>() {
+                    net.contains(ip)
+                } else if let Ok(rule_ip) = rule.parse::<IpAddr>() {
+                    *ip == rule_ip
+                } else {
+                    false
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub enum EgressViolation {
+    HostDenied(String),
+    HostNotAllowed(String),
+    PortDenied(u16),
+    PortNotAllowed(u16),
+    RawSocketsBlocked,
+}
+
+impl Display for EgressViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EgressViolation::HostDenied(host) => write!(f, "Host {host} is denied"),
+            EgressViolation::HostNotAllowed(host) => write!(f, "Host {host} is not allowed"),
+            EgressViolation::PortDenied(port) => write!(f, "Port {port} is denied"),
+            EgressViolation::PortNotAllowed(port) => write!(f, "Port {port} is not allowed"),
+            EgressViolation::RawSocketsBlocked => write!(f, "Raw sockets are blocked"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+
+    use crate::egress::{EgressPolicy, EgressViolation};
+
+    fn policy(allowed_hosts: &[&str], denied_hosts: &[&str]) -> EgressPolicy {
+        EgressPolicy {
+            allowed_hosts: allowed_hosts.iter().map(|s| s.to_string()).collect(),
+            denied_hosts: denied_hosts.iter().map(|s| s.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn default_policy_allows_everything() {
+        let policy = EgressPolicy::default();
+        assert_eq!(policy.check_host("example.com", 443), Ok(()));
+        assert_eq!(
+            policy.check_address(&"10.0.0.1:5432".parse::<SocketAddr>().unwrap()),
+            Ok(())
+        );
+        assert_eq!(policy.check_raw_socket(), Ok(()));
+    }
+
+    #[test]
+    fn host_names_and_wildcards() {
+        let policy = policy(&["*.example.com", "golem.cloud"], &["internal.example.com"]);
+        assert_eq!(policy.check_host("api.example.com", 443), Ok(()));
+        assert_eq!(policy.check_host("Golem.Cloud", 80), Ok(()));
+        assert_eq!(
+            policy.check_host("example.com", 443),
+            Err(EgressViolation::HostNotAllowed("example.com".to_string()))
+        );
+        assert_eq!(
+            policy.check_host("notexample.com", 443),
+            Err(EgressViolation::HostNotAllowed(
+                "notexample.com".to_string()
+            ))
+        );
+        assert_eq!(
+            policy.check_host("internal.example.com", 443),
+            Err(EgressViolation::HostDenied(
+                "internal.example.com".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn cidr_ranges() {
+        let policy = policy(&[], &["10.0.0.0/8", "::1"]);
+        assert_eq!(
+            policy.check_address(&"10.1.2.3:80".parse::<SocketAddr>().unwrap()),
+            Err(EgressViolation::HostDenied("10.1.2.3".to_string()))
+        );
+        assert_eq!(
+            policy.check_address(&"[::1]:80".parse::<SocketAddr>().unwrap()),
+            Err(EgressViolation::HostDenied("::1".to_string()))
+        );
+        assert_eq!(
+            policy.check_host("[::1]", 80),
+            Err(EgressViolation::HostDenied("::1".to_string()))
+        );
+        assert_eq!(policy.check_host("11.0.0.1", 80), Ok(()));
+    }
+
+    #[test]
+    fn ports() {
+        let policy = EgressPolicy {
+            allowed_ports: vec![80, 443],
+            denied_ports: vec![80],
+            ..Default::default()
+        };
+        assert_eq!(policy.check_host("example.com", 443), Ok(()));
+        assert_eq!(
+            policy.check_host("example.com", 80),
+            Err(EgressViolation::PortDenied(80))
+        );
+        assert_eq!(
+            policy.check_host("example.com", 8080),
+            Err(EgressViolation::PortNotAllowed(8080))
+        );
+    }
+
+    #[test]
+    fn resolved_host_names() {
+        let policy = policy(&["*.example.com", "192.168.0.0/16"], &["10.0.0.0/8"]);
+        let internal = ["10.0.0.5".parse().unwrap()];
+        let private = ["192.168.1.1".parse().unwrap()];
+
+        assert!(policy.has_address_rules());
+        assert_eq!(
+            policy.check_resolved_host("api.example.com", &internal, 443),
+            Err(EgressViolation::HostDenied("api.example.com".to_string()))
+        );
+        assert_eq!(
+            policy.check_resolved_host("intranet.local", &private, 443),
+            Ok(())
+        );
+        assert_eq!(
+            policy.check_resolved_host(
+                "mixed.local",
+                &[private[0], "8.8.8.8".parse().unwrap()],
+                443
+            ),
+            Err(EgressViolation::HostNotAllowed("mixed.local".to_string()))
+        );
+        assert_eq!(
+            policy.check_resolved_host("unresolved.local", &[], 443),
+            Err(EgressViolation::HostNotAllowed(
+                "unresolved.local".to_string()
+            ))
+        );
+        assert!(!EgressPolicy::default().has_address_rules());
+    }
+}
@@ -24,16 +24,22 @@ use golem_api_grpc::proto::golem::common::ResourceLimits as GrpcResourceLimits;
 use golem_api_grpc::proto::golem::worker::UpdateMode;
 use golem_api_grpc::proto::golem::workerexecutor::worker_executor_server::WorkerExecutor;
 use golem_api_grpc::proto::golem::workerexecutor::{
+    DeleteKeyValueRequest, DeleteKeyValueResponse, GetKeyValueBucketsRequest,
+    GetKeyValueBucketsResponse, GetKeyValueKeysRequest, GetKeyValueKeysResponse,
+    GetKeyValueRequest, GetKeyValueResponse, GetPromisesRequest, GetPromisesResponse,
     GetRunningWorkersMetadataRequest, GetRunningWorkersMetadataResponse, GetWorkersMetadataRequest,
-    GetWorkersMetadataResponse, UpdateWorkerRequest, UpdateWorkerResponse,
+    GetWorkersMetadataResponse, SendMailboxMessageRequest, SendMailboxMessageResponse,
+    SetKeyValueRequest, SetKeyValueResponse, UpdateWorkerRequest, UpdateWorkerResponse,
 };
 use golem_common::cache::PendingOrFinal;
 use golem_common::model as common_model;
 use golem_common::model::oplog::UpdateDescription;
 use golem_common::model::{
-    AccountId, CallingConvention, IdempotencyKey, ShardId, TimestampedWorkerInvocation,
-    WorkerFilter, WorkerId, WorkerInvocation, WorkerMetadata, WorkerStatus, WorkerStatusRecord,
+    AccountId, CallingConvention, IdempotencyKey, PromiseId, ShardId, TimestampedWorkerInvocation,
+    WorkerEventCursor, WorkerFilter, WorkerId, WorkerInvocation, WorkerMetadata, WorkerStatus,
+    WorkerStatusRecord,
 };
+use golem_common::telemetry::with_trace_context;
 use golem_wasm_rpc::protobuf::Val;
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::ReceiverStream;
@@ -47,14 +53,16 @@ use crate::metrics::grpc::{
     record_closed_grpc_active_stream, record_new_grpc_active_stream, RecordedGrpcRequest,
 };
 use crate::model::{InterruptKind, LastError};
+use crate::services::key_value::KeyPage;
 use crate::services::worker_activator::{DefaultWorkerActivator, LazyWorkerActivator};
 use crate::services::worker_event::LogLevel;
 use crate::services::{
-    worker_event, All, HasActiveWorkers, HasAll, HasInvocationQueue, HasPromiseService,
-    HasRunningWorkerEnumerationService, HasShardManagerService, HasShardService,
-    HasWorkerEnumerationService, HasWorkerService, UsesAllDeps,
+    worker_event, All, HasActiveWorkers, HasAll, HasConfig, HasInvocationQueue, HasKeyValueService,
+    HasMailboxService, HasPromiseService, HasRunningWorkerEnumerationService,
+    HasShardManagerService, HasShardService, HasWorkerEnumerationService, HasWorkerEventLogService,
+    HasWorkerService, UsesAllDeps,
 };
-use crate::worker::{invoke_and_await, PendingWorker, Worker};
+use crate::worker::{invoke_and_await, invoke_and_await_ephemeral, PendingWorker, Worker};
 use crate::workerctx::{PublicWorkerIo, WorkerCtx};
 
 pub enum GrpcError<E> {
@@ -271,6 +279,7 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             .promise_id
             .ok_or(GolemError::invalid_request("promise_id not found"))?;
         let data = request.data;
+        let failed = request.failed;
 
         let worker_id: WorkerId = promise_id
             .worker_id
@@ -283,7 +292,11 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
 
         let promise_id: common_model::PromiseId =
             promise_id.try_into().map_err(GolemError::invalid_request)?;
-        let completed = self.promise_service().complete(promise_id, data).await?;
+        let completed = if failed {
+            self.promise_service().fail(promise_id, data).await?
+        } else {
+            self.promise_service().complete(promise_id, data).await?
+        };
 
         let metadata = self
             .worker_service()
@@ -362,6 +375,15 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         Ctx::on_worker_deleted(self, &worker_id).await?;
         self.worker_service().remove(&worker_id).await;
         self.active_workers().remove(&worker_id);
+        if let Err(err) = self.worker_event_log_service().delete(&worker_id).await {
+            warn!("Failed to delete the persisted events of worker {worker_id}: {err}");
+        }
+        if let Err(err) = self.mailbox_service().delete(&worker_id).await {
+            warn!("Failed to delete the mailbox of worker {worker_id}: {err}");
+        }
+        if let Err(err) = self.promise_service().delete_all(&worker_id).await {
+            warn!("Failed to delete the promises of worker {worker_id}: {err}");
+        }
 
         Ok(())
     }
@@ -493,6 +515,29 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             .map_err(|msg| GolemError::ValueMismatch { details: msg })?;
 
         let calling_convention = request.calling_convention();
+
+        if request.ephemeral {
+            let worker_id = request.worker_id()?;
+            self.validate_worker_id(&worker_id)?;
+
+            if let Some(limits) = request.account_limits() {
+                Ctx::record_last_known_limits(self, &request.account_id()?, &limits.into()).await?;
+            }
+
+            let values = invoke_and_await_ephemeral(
+                self,
+                &worker_id,
+                request.account_id()?,
+                calling_convention.into(),
+                full_function_name,
+                function_input,
+                request.call_chain()?,
+            )
+            .await?;
+            let output = values.into_iter().map(|val| val.into()).collect();
+            return Ok(golem::workerexecutor::InvokeAndAwaitWorkerSuccess { output });
+        }
+
         let worker = self.get_or_create(request).await?;
         let idempotency_key = request
             .idempotency_key()?
@@ -504,6 +549,7 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             calling_convention.into(),
             full_function_name,
             function_input,
+            request.call_chain()?,
         )
         .await?;
         let output = values.into_iter().map(|val| val.into()).collect();
@@ -630,6 +676,7 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
                 full_function_name,
                 function_input,
                 calling_convention,
+                request.call_chain()?,
             )
             .await;
 
@@ -693,11 +740,17 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             Ctx::compute_latest_worker_status(self, &worker_id, &Some(metadata.clone())).await?;
         let last_error_and_retry_count =
             Ctx::get_last_error_and_retry_count(self, &worker_id).await;
+        let awaited_promise = if latest_status.status == WorkerStatus::Suspended {
+            self.promise_service().get_awaited(&worker_id).await
+        } else {
+            None
+        };
 
         Ok(Self::create_proto_metadata(
             metadata,
             latest_status,
             last_error_and_retry_count,
+            awaited_promise,
         ))
     }
 
@@ -724,7 +777,7 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             .into_iter()
             .map(|worker| {
                 let status = worker.last_known_status.clone();
-                Self::create_proto_metadata(worker, status, None)
+                Self::create_proto_metadata(worker, status, None, None)
             })
             .collect();
 
@@ -760,7 +813,7 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             .into_iter()
             .map(|worker| {
                 let status = worker.last_known_status.clone();
-                Self::create_proto_metadata(worker, status, None)
+                Self::create_proto_metadata(worker, status, None, None)
             })
             .collect();
 
@@ -929,10 +982,148 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         Ok(())
     }
 
+    async fn get_key_value_buckets_internal(
+        &self,
+        request: GetKeyValueBucketsRequest,
+    ) -> Result<Vec<String>, GolemError> {
+        let account_id = key_value_account_id(request.account_id)?;
+        self.key_value_service()
+            .get_buckets(account_id)
+            .await
+            .map_err(|err| GolemError::runtime(err.to_string()))
+    }
+
+    async fn get_key_value_keys_internal(
+        &self,
+        request: GetKeyValueKeysRequest,
+    ) -> Result<KeyPage, GolemError> {
+        let account_id = key_value_account_id(request.account_id)?;
+        self.key_value_service()
+            .get_keys(
+                account_id,
+                request.bucket,
+                request.prefix,
+                request.cursor,
+                request.count,
+            )
+            .await
+            .map_err(|err| GolemError::runtime(err.to_string()))
+    }
+
+    async fn get_key_value_internal(
+        &self,
+        request: GetKeyValueRequest,
+    ) -> Result<Option<Vec<u8>>, GolemError> {
+        let account_id = key_value_account_id(request.account_id)?;
+        self.key_value_service()
+            .get(account_id, request.bucket, request.key)
+            .await
+            .map_err(|err| GolemError::runtime(err.to_string()))
+    }
+
+    async fn set_key_value_internal(&self, request: SetKeyValueRequest) -> Result<(), GolemError> {
+        let account_id = key_value_account_id(request.account_id)?;
+        let ttl = self.config().key_value.ttl(&request.bucket);
+        self.key_value_service()
+            .set(account_id, request.bucket, request.key, request.value, ttl)
+            .await
+            .map_err(|err| GolemError::runtime(err.to_string()))
+    }
+
+    async fn delete_key_value_internal(
+        &self,
+        request: DeleteKeyValueRequest,
+    ) -> Result<(), GolemError> {
+        let account_id = key_value_account_id(request.account_id)?;
+        self.key_value_service()
+            .delete(account_id, request.bucket, request.key)
+            .await
+            .map_err(|err| GolemError::runtime(err.to_string()))
+    }
+
+    async fn get_promises_internal(
+        &self,
+        request: GetPromisesRequest,
+    ) -> Result<Vec<golem::worker::PromiseMetadata>, GolemError> {
+        let worker_id: WorkerId = request
+            .worker_id
+            .ok_or(GolemError::invalid_request("worker_id not found"))?
+            .try_into()
+            .map_err(GolemError::invalid_request)?;
+
+        self.validate_worker_id(&worker_id)?;
+
+        let promises = self.promise_service().list(&worker_id).await?;
+        Ok(promises
+            .into_iter()
+            .map(|promise| golem::worker::PromiseMetadata {
+                oplog_idx: promise.promise_id.oplog_idx,
+                status: Into::<golem::worker::PromiseStatus>::into(promise.status).into(),
+                completed_at: promise.completed_at.map(|timestamp| timestamp.into()),
+                payload_size: promise.payload_size,
+            })
+            .collect())
+    }
+
+    async fn send_mailbox_message_internal(
+        &self,
+        request: SendMailboxMessageRequest,
+    ) -> Result<(), GolemError> {
+        let worker_id: WorkerId = request
+            .worker_id
+            .ok_or(GolemError::invalid_request("worker_id not found"))?
+            .try_into()
+            .map_err(GolemError::invalid_request)?;
+
+        self.validate_worker_id(&worker_id)?;
+
+        let metadata = self
+            .worker_service()
+            .get(&worker_id)
+            .await
+            .ok_or(GolemError::worker_not_found(worker_id.clone()))?;
+
+        // Without a key the message is never considered a duplicate
+        let message_id = request
+            .idempotency_key
+            .map(IdempotencyKey::from)
+            .unwrap_or(IdempotencyKey::fresh());
+        self.mailbox_service()
+            .send(&worker_id, &message_id, request.payload)
+            .await?;
+
+        let worker_status =
+            Ctx::compute_latest_worker_status(self, &worker_id, &Some(metadata.clone())).await?;
+        let should_activate = match &worker_status.status {
+            WorkerStatus::Interrupted
+            | WorkerStatus::Running
+            | WorkerStatus::Suspended
+            | WorkerStatus::Retrying => true,
+            WorkerStatus::Exited | WorkerStatus::Failed | WorkerStatus::Idle => false,
+        };
+
+        if should_activate {
+            // A worker suspended in a receive on its empty mailbox gets the new message when
+            // the receive is replayed
+            Worker::activate(
+                &self.services,
+                &metadata.worker_id,
+                metadata.args,
+                metadata.env,
+                Some(worker_status.component_version),
+                metadata.account_id,
+            )
+            .await;
+        }
+
+        Ok(())
+    }
+
     fn create_proto_metadata(
         metadata: WorkerMetadata,
         latest_status: WorkerStatusRecord,
         last_error_and_retry_count: Option<LastError>,
+        awaited_promise: Option<PromiseId>,
     ) -> golem::worker::WorkerMetadata {
         let mut updates = Vec::new();
 
@@ -1001,6 +1192,7 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             updates,
             created_at: Some(metadata.created_at.into()),
             last_error: last_error_and_retry_count.map(|last_error| last_error.error.to_string()),
+            awaited_promise_oplog_idx: awaited_promise.map(|promise_id| promise_id.oplog_idx),
         }
     }
 }
@@ -1141,6 +1333,11 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             .account_id
             .ok_or(GolemError::invalid_request("missing account_id"))?
             .into();
+        let since: Option<WorkerEventCursor> = inner
+            .since
+            .map(|cursor| cursor.try_into())
+            .transpose()
+            .map_err(GolemError::invalid_request)?;
 
         self.validate_worker_id(&worker_id)?;
 
@@ -1172,7 +1369,10 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
                 }
             };
 
-            let mut receiver = event_service.receiver();
+            let mut receiver = match &since {
+                Some(cursor) => event_service.receiver_since(cursor).await,
+                None => event_service.receiver(),
+            };
 
             info!("Client connected to {worker_id}");
             record_new_grpc_active_stream();
@@ -1182,8 +1382,10 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             let (tx, rx) = mpsc::channel(128);
 
             tokio::spawn(async move {
-                while let Ok(item) = receiver.recv().await {
-                    match item {
+                while let Ok(item) = receiver.recv_sequenced().await {
+                    let sequence = item.sequence;
+                    let timestamp = item.timestamp.to_millis();
+                    match item.event {
                         worker_event::WorkerEvent::Close => {
                             break;
                         }
@@ -1195,6 +1397,8 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
                                             message: String::from_utf8(line).unwrap(),
                                         },
                                     )),
+                                    sequence,
+                                    timestamp,
                                 }))
                                 .await
                             {
@@ -1215,6 +1419,8 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
                                             message: String::from_utf8(line).unwrap(),
                                         },
                                     )),
+                                    sequence,
+                                    timestamp,
                                 }))
                                 .await
                             {
@@ -1231,6 +1437,7 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
                             level,
                             context,
                             message,
+                            attributes,
                         } => match tx
                             .send(Result::<_, Status>::Ok(golem::worker::LogEvent {
                                 event: Some(golem::worker::log_event::Event::Log(
@@ -1247,8 +1454,33 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
                                         },
                                         context,
                                         message,
+                                        attributes: attributes
+                                            .into_iter()
+                                            .map(|attribute| attribute.into())
+                                            .collect(),
                                     },
                                 )),
+                                sequence,
+                                timestamp,
+                            }))
+                            .await
+                        {
+                            Ok(_) => {
+                                // item (server response) was queued to be send to client
+                            }
+                            Err(_item) => {
+                                // output_stream was build from rx and both are dropped
+                                break;
+                            }
+                        },
+                        event @ (worker_event::WorkerEvent::InvocationStarted { .. }
+                        | worker_event::WorkerEvent::InvocationFinished { .. }
+                        | worker_event::WorkerEvent::InvocationFailed { .. }
+                        | worker_event::WorkerEvent::StatusChanged { .. }) => match tx
+                            .send(Result::<_, Status>::Ok(golem::worker::LogEvent {
+                                event: worker_event::lifecycle_log_event(event),
+                                sequence,
+                                timestamp,
                             }))
                             .await
                         {
@@ -1617,6 +1849,236 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             ),
         }
     }
+
+    async fn get_key_value_buckets(
+        &self,
+        request: Request<GetKeyValueBucketsRequest>,
+    ) -> Result<Response<GetKeyValueBucketsResponse>, Status> {
+        let request = request.into_inner();
+        let record = RecordedGrpcRequest::new(
+            "get_key_value_buckets",
+            format!("account_id={:?}", request.account_id),
+        );
+        match self.get_key_value_buckets_internal(request).await {
+            Ok(buckets) => record.succeed(Ok(Response::new(GetKeyValueBucketsResponse {
+                result: Some(
+                    golem::workerexecutor::get_key_value_buckets_response::Result::Success(
+                        golem::workerexecutor::GetKeyValueBucketsSuccessResponse { buckets },
+                    ),
+                ),
+            }))),
+            Err(err) => record.fail(
+                Ok(Response::new(GetKeyValueBucketsResponse {
+                    result: Some(
+                        golem::workerexecutor::get_key_value_buckets_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
+        }
+    }
+
+    async fn get_key_value_keys(
+        &self,
+        request: Request<GetKeyValueKeysRequest>,
+    ) -> Result<Response<GetKeyValueKeysResponse>, Status> {
+        let request = request.into_inner();
+        let record = RecordedGrpcRequest::new(
+            "get_key_value_keys",
+            format!(
+                "account_id={:?}, bucket={}, prefix={:?}, cursor={:?}",
+                request.account_id, request.bucket, request.prefix, request.cursor
+            ),
+        );
+        match self.get_key_value_keys_internal(request).await {
+            Ok(KeyPage { keys, cursor }) => {
+                record.succeed(Ok(Response::new(GetKeyValueKeysResponse {
+                    result: Some(
+                        golem::workerexecutor::get_key_value_keys_response::Result::Success(
+                            golem::workerexecutor::GetKeyValueKeysSuccessResponse { keys, cursor },
+                        ),
+                    ),
+                })))
+            }
+            Err(err) => record.fail(
+                Ok(Response::new(GetKeyValueKeysResponse {
+                    result: Some(
+                        golem::workerexecutor::get_key_value_keys_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
+        }
+    }
+
+    async fn get_key_value(
+        &self,
+        request: Request<GetKeyValueRequest>,
+    ) -> Result<Response<GetKeyValueResponse>, Status> {
+        let request = request.into_inner();
+        let record = RecordedGrpcRequest::new(
+            "get_key_value",
+            format!(
+                "account_id={:?}, bucket={}, key={}",
+                request.account_id, request.bucket, request.key
+            ),
+        );
+        match self.get_key_value_internal(request).await {
+            Ok(value) => record.succeed(Ok(Response::new(GetKeyValueResponse {
+                result: Some(
+                    golem::workerexecutor::get_key_value_response::Result::Success(
+                        golem::workerexecutor::GetKeyValueSuccessResponse { value },
+                    ),
+                ),
+            }))),
+            Err(err) => record.fail(
+                Ok(Response::new(GetKeyValueResponse {
+                    result: Some(
+                        golem::workerexecutor::get_key_value_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
+        }
+    }
+
+    async fn set_key_value(
+        &self,
+        request: Request<SetKeyValueRequest>,
+    ) -> Result<Response<SetKeyValueResponse>, Status> {
+        let request = request.into_inner();
+        let record = RecordedGrpcRequest::new(
+            "set_key_value",
+            format!(
+                "account_id={:?}, bucket={}, key={}",
+                request.account_id, request.bucket, request.key
+            ),
+        );
+        match self.set_key_value_internal(request).await {
+            Ok(_) => record.succeed(Ok(Response::new(SetKeyValueResponse {
+                result: Some(
+                    golem::workerexecutor::set_key_value_response::Result::Success(
+                        golem::common::Empty {},
+                    ),
+                ),
+            }))),
+            Err(err) => record.fail(
+                Ok(Response::new(SetKeyValueResponse {
+                    result: Some(
+                        golem::workerexecutor::set_key_value_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
+        }
+    }
+
+    async fn delete_key_value(
+        &self,
+        request: Request<DeleteKeyValueRequest>,
+    ) -> Result<Response<DeleteKeyValueResponse>, Status> {
+        let request = request.into_inner();
+        let record = RecordedGrpcRequest::new(
+            "delete_key_value",
+            format!(
+                "account_id={:?}, bucket={}, key={}",
+                request.account_id, request.bucket, request.key
+            ),
+        );
+        match self.delete_key_value_internal(request).await {
+            Ok(_) => record.succeed(Ok(Response::new(DeleteKeyValueResponse {
+                result: Some(
+                    golem::workerexecutor::delete_key_value_response::Result::Success(
+                        golem::common::Empty {},
+                    ),
+                ),
+            }))),
+            Err(err) => record.fail(
+                Ok(Response::new(DeleteKeyValueResponse {
+                    result: Some(
+                        golem::workerexecutor::delete_key_value_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
+        }
+    }
+
+    async fn get_promises(
+        &self,
+        request: Request<GetPromisesRequest>,
+    ) -> Result<Response<GetPromisesResponse>, Status> {
+        let request = request.into_inner();
+        let record =
+            RecordedGrpcRequest::new("get_promises", format!("worker_id={:?}", request.worker_id));
+        match self.get_promises_internal(request).await {
+            Ok(promises) => record.succeed(Ok(Response::new(GetPromisesResponse {
+                result: Some(
+                    golem::workerexecutor::get_promises_response::Result::Success(
+                        golem::workerexecutor::GetPromisesSuccessResponse { promises },
+                    ),
+                ),
+            }))),
+            Err(err) => record.fail(
+                Ok(Response::new(GetPromisesResponse {
+                    result: Some(
+                        golem::workerexecutor::get_promises_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
+        }
+    }
+
+    async fn send_mailbox_message(
+        &self,
+        request: Request<SendMailboxMessageRequest>,
+    ) -> Result<Response<SendMailboxMessageResponse>, Status> {
+        let request = request.into_inner();
+        let record = RecordedGrpcRequest::new(
+            "send_mailbox_message",
+            format!("worker_id={:?}", request.worker_id),
+        );
+        match self.send_mailbox_message_internal(request).await {
+            Ok(_) => record.succeed(Ok(Response::new(SendMailboxMessageResponse {
+                result: Some(
+                    golem::workerexecutor::send_mailbox_message_response::Result::Success(
+                        golem::common::Empty {},
+                    ),
+                ),
+            }))),
+            Err(err) => record.fail(
+                Ok(Response::new(SendMailboxMessageResponse {
+                    result: Some(
+                        golem::workerexecutor::send_mailbox_message_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
+        }
+    }
+}
+
+fn key_value_account_id(
+    account_id: Option<golem::common::AccountId>,
+) -> Result<AccountId, GolemError> {
+    Ok(account_id
+        .ok_or(GolemError::invalid_request("account_id not found"))?
+        .into())
 }
 
 trait GrpcInvokeRequest {
@@ -1627,6 +2089,7 @@ trait GrpcInvokeRequest {
     fn worker_id(&self) -> Result<common_model::WorkerId, GolemError>;
     fn idempotency_key(&self) -> Result<Option<IdempotencyKey>, GolemError>;
     fn name(&self) -> String;
+    fn call_chain(&self) -> Result<Vec<common_model::WorkerId>, GolemError>;
 }
 
 impl GrpcInvokeRequest for golem::workerexecutor::InvokeWorkerRequest {
@@ -1665,6 +2128,10 @@ impl GrpcInvokeRequest for golem::workerexecutor::InvokeWorkerRequest {
     fn name(&self) -> String {
         self.name.clone()
     }
+
+    fn call_chain(&self) -> Result<Vec<common_model::WorkerId>, GolemError> {
+        Ok(vec![])
+    }
 }
 
 impl GrpcInvokeRequest for golem::workerexecutor::InvokeAndAwaitWorkerRequest {
@@ -1706,6 +2173,14 @@ impl GrpcInvokeRequest for golem::workerexecutor::InvokeAndAwaitWorkerRequest {
     fn name(&self) -> String {
         self.name.clone()
     }
+
+    fn call_chain(&self) -> Result<Vec<common_model::WorkerId>, GolemError> {
+        self.call_chain
+            .iter()
+            .map(|worker_id| worker_id.clone().try_into())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(GolemError::invalid_request)
+    }
 }
 
 pub trait UriBackConversion {
@@ -1719,7 +2194,7 @@ impl UriBackConversion for http::Uri {
 }
 
 pub fn authorised_grpc_request<T>(request: T, access_token: &Uuid) -> Request<T> {
-    let mut req = Request::new(request);
+    let mut req = with_trace_context(request);
     req.metadata_mut().insert(
         "authorization",
         format!("Bearer {}", access_token).parse().unwrap(),
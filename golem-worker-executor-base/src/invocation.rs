@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::Instant;
+
 use anyhow::anyhow;
 use golem_common::model::{parse_function_name, CallingConvention, WorkerId, WorkerStatus};
 use golem_wasm_rpc::wasmtime::{decode_param, encode_output};
@@ -21,9 +23,13 @@ use wasmtime::component::{Func, Val};
 use wasmtime::AsContextMut;
 
 use crate::error::GolemError;
+use crate::metrics::per_component::{
+    record_component_fuel_consumption, record_component_invocation,
+};
 use crate::metrics::wasm::{record_invocation, record_invocation_consumption};
 use crate::model::{InterruptKind, TrapType};
-use crate::workerctx::{FuelManagement, WorkerCtx};
+use crate::services::worker_event::{WorkerEvent, WorkerEventService};
+use crate::workerctx::{FuelManagement, PublicWorkerIo, WorkerCtx};
 
 /// Invokes a function on a worker.
 /// Returns true if the function invocation was finished, false if it was interrupted or scheduled for retry.
@@ -50,6 +56,7 @@ pub async fn invoke_worker<Ctx: WorkerCtx>(
     let worker_id = store.data().worker_id().clone();
     debug!("invoke_worker: {worker_id}/{full_function_name}");
 
+    let started_at = Instant::now();
     let result = invoke_or_fail(
         &worker_id,
         full_function_name.clone(),
@@ -71,20 +78,20 @@ pub async fn invoke_worker<Ctx: WorkerCtx>(
             let trap_type = TrapType::from_error::<Ctx>(&err);
             match trap_type {
                 TrapType::Interrupt(InterruptKind::Interrupt) => {
-                    record_invocation(was_live_before, "interrupted");
+                    record_outcome(&worker_id, was_live_before, "interrupted", started_at);
                     None
                 }
                 TrapType::Interrupt(InterruptKind::Suspend) => {
                     // this invocation was suspended and expected to be resumed by an external call or schedule
-                    record_invocation(was_live_before, "suspended");
+                    record_outcome(&worker_id, was_live_before, "suspended", started_at);
                     None
                 }
                 TrapType::Exit => {
-                    record_invocation(was_live_before, "exited");
+                    record_outcome(&worker_id, was_live_before, "exited", started_at);
                     Some(Err(err))
                 }
                 _ => {
-                    record_invocation(was_live_before, "failed");
+                    record_outcome(&worker_id, was_live_before, "failed", started_at);
                     Some(Err(err))
                 }
             }
@@ -95,12 +102,26 @@ pub async fn invoke_worker<Ctx: WorkerCtx>(
         }
         Ok(Some(result)) => {
             // this invocation finished and produced a result
-            record_invocation(was_live_before, "success");
+            record_outcome(&worker_id, was_live_before, "success", started_at);
             Some(Ok(result))
         }
     }
 }
 
+fn record_outcome(
+    worker_id: &WorkerId,
+    was_live_before: bool,
+    outcome: &'static str,
+    started_at: Instant,
+) {
+    record_invocation(was_live_before, outcome);
+    // Replayed invocations are not measured per component, as their duration only reflects
+    // the time it took to recover the worker
+    if was_live_before {
+        record_component_invocation(worker_id, outcome, started_at.elapsed());
+    }
+}
+
 async fn invoke_or_fail<Ctx: WorkerCtx>(
     worker_id: &WorkerId,
     full_function_name: String,
@@ -153,7 +174,9 @@ async fn invoke_or_fail<Ctx: WorkerCtx>(
             .map(Some),
     }?;
 
-    if was_live_before {
+    // Lifecycle events are only emitted for live invocations, replaying them during recovery
+    // would report them a second time
+    let idempotency_key = if was_live_before {
         store
             .data_mut()
             .on_exported_function_invoked(
@@ -162,7 +185,21 @@ async fn invoke_or_fail<Ctx: WorkerCtx>(
                 Some(calling_convention),
             )
             .await?;
-    }
+
+        let idempotency_key = store.data().get_current_idempotency_key().await;
+        if let Some(idempotency_key) = &idempotency_key {
+            emit_event(
+                store.data(),
+                WorkerEvent::InvocationStarted {
+                    function: full_function_name.clone(),
+                    idempotency_key: idempotency_key.clone(),
+                },
+            );
+        }
+        idempotency_key
+    } else {
+        None
+    };
 
     store.data_mut().set_running();
     store
@@ -170,6 +207,8 @@ async fn invoke_or_fail<Ctx: WorkerCtx>(
         .store_worker_status(WorkerStatus::Running)
         .await;
 
+    let started_at = Instant::now();
+
     let call_result = match function {
         Some(function) => {
             invoke(
@@ -203,18 +242,40 @@ async fn invoke_or_fail<Ctx: WorkerCtx>(
                 .data_mut()
                 .on_invocation_failure_deactivated(&failure_payload, &trap_type)
                 .await?;
-            store
-                .data_mut()
-                .store_worker_status(result_status.clone())
-                .await;
 
+            if let Some(idempotency_key) = idempotency_key {
+                let error = match &trap_type {
+                    TrapType::Error(error) => error.to_string(),
+                    TrapType::Exit => "Process exited".to_string(),
+                    TrapType::Interrupt(kind) => kind.to_string(),
+                };
+                emit_event(
+                    store.data(),
+                    WorkerEvent::InvocationFailed {
+                        function: full_function_name.clone(),
+                        idempotency_key,
+                        error,
+                    },
+                );
+            }
             if result_status == WorkerStatus::Retrying || result_status == WorkerStatus::Running {
+                store
+                    .data_mut()
+                    .store_worker_status(result_status.clone())
+                    .await;
                 Ok(None)
             } else {
+                // The final status is stored only after the failure has been handled, so the
+                // worker is still among the running ones if the executor stops while running
+                // its compensations, and they get resumed on startup
                 store
                     .data_mut()
                     .on_invocation_failure_final(&failure_payload, &trap_type)
                     .await?;
+                store
+                    .data_mut()
+                    .store_worker_status(result_status.clone())
+                    .await;
                 Err(err)
             }
         }
@@ -223,11 +284,28 @@ async fn invoke_or_fail<Ctx: WorkerCtx>(
             consumed_fuel,
             output,
         }) => {
+            // The fuel of replayed invocations was already recorded when they were live
+            if was_live_before {
+                record_component_fuel_consumption(worker_id, consumed_fuel);
+            }
+
             let result = store
                 .data_mut()
                 .on_invocation_success(&full_function_name, &function_input, consumed_fuel, output)
                 .await?;
 
+            if let Some(idempotency_key) = idempotency_key {
+                emit_event(
+                    store.data(),
+                    WorkerEvent::InvocationFinished {
+                        function: full_function_name.clone(),
+                        idempotency_key,
+                        duration: started_at.elapsed(),
+                        consumed_fuel,
+                    },
+                );
+            }
+
             if exited {
                 store.data_mut().deactivate().await;
                 store
@@ -245,6 +323,10 @@ async fn invoke_or_fail<Ctx: WorkerCtx>(
     }
 }
 
+fn emit_event<Ctx: WorkerCtx>(ctx: &Ctx, event: WorkerEvent) {
+    ctx.get_public_state().event_service().emit_event(event);
+}
+
 async fn invoke<Ctx: WorkerCtx>(
     store: &mut impl AsContextMut<Data = Ctx>,
     function: Func,
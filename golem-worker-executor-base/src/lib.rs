@@ -13,6 +13,7 @@
 // limitations under the License.
 
 pub mod durable_host;
+pub mod egress;
 pub mod error;
 pub mod grpc;
 pub mod http_server;
@@ -28,6 +29,8 @@ pub mod workerctx;
 use async_trait::async_trait;
 use golem_api_grpc::proto;
 use golem_api_grpc::proto::golem::workerexecutor::worker_executor_server::WorkerExecutorServer;
+use golem_common::clock::{Clock, SystemClock};
+use golem_common::telemetry::grpc_request_span;
 use prometheus::Registry;
 use std::sync::Arc;
 use tokio::runtime::Handle;
@@ -45,19 +48,24 @@ use crate::services::component::ComponentService;
 use crate::services::events::Events;
 use crate::services::golem_config::{GolemConfig, WorkersServiceConfig};
 use crate::services::key_value::KeyValueService;
+use crate::services::mailbox::MailboxService;
 use crate::services::oplog::{OplogService, RedisOplogService};
 use crate::services::promise::PromiseService;
 use crate::services::scheduler::{SchedulerService, SchedulerServiceDefault};
 use crate::services::shard::{ShardService, ShardServiceDefault};
 use crate::services::shard_manager::ShardManagerService;
+use crate::services::topic::{TopicService, TopicServiceRedis};
 use crate::services::worker::{WorkerService, WorkerServiceInMemory, WorkerServiceRedis};
 use crate::services::worker_activator::{LazyWorkerActivator, WorkerActivator};
 use crate::services::worker_enumeration::{
     RunningWorkerEnumerationService, RunningWorkerEnumerationServiceDefault,
     WorkerEnumerationService, WorkerEnumerationServiceInMemory, WorkerEnumerationServiceRedis,
 };
+use crate::services::worker_event_log::WorkerEventLogService;
 use crate::services::worker_proxy::{RemoteWorkerProxy, WorkerProxy};
-use crate::services::{blob_store, component, key_value, promise, shard_manager, All};
+use crate::services::{
+    blob_store, component, key_value, mailbox, promise, shard_manager, worker_event_log, All,
+};
 use crate::workerctx::WorkerCtx;
 
 /// The Bootstrap trait should be implemented by all Worker Executors to customize the initialization
@@ -90,10 +98,20 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
         worker_activator: Arc<dyn WorkerActivator + Send + Sync>,
         oplog_service: Arc<dyn OplogService + Send + Sync>,
         scheduler_service: Arc<dyn SchedulerService + Send + Sync>,
+        topic_service: Arc<dyn TopicService + Send + Sync>,
+        mailbox_service: Arc<dyn MailboxService + Send + Sync>,
         worker_proxy: Arc<dyn WorkerProxy + Send + Sync>,
         events: Arc<Events>,
+        worker_event_log_service: Arc<dyn WorkerEventLogService + Send + Sync>,
+        clock: Arc<dyn Clock + Send + Sync>,
     ) -> anyhow::Result<All<Ctx>>;
 
+    /// Can be overridden to replace the source of time used by the scheduler and the workers,
+    /// for example with a `VirtualClock` in tests.
+    fn create_clock(&self) -> Arc<dyn Clock + Send + Sync> {
+        Arc::new(SystemClock::new())
+    }
+
     /// Can be overridden to customize the wasmtime configuration
     fn create_wasmtime_config(&self) -> Config {
         let mut config = Config::default();
@@ -120,6 +138,8 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
     ) -> anyhow::Result<()> {
         info!("Golem Worker Executor starting up...");
 
+        metrics::per_component::configure(&golem_config.metrics);
+
         let (mut health_reporter, health_service) = tonic_health::server::health_reporter();
         health_reporter
             .set_serving::<WorkerExecutorServer<WorkerExecutorImpl<Ctx, All<Ctx>>>>()
@@ -148,6 +168,7 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
 
         let golem_config = Arc::new(golem_config.clone());
         let promise_service = promise::configured(&golem_config.promises, pool.clone());
+        let mailbox_service = mailbox::configured(&golem_config.mailboxes, pool.clone());
         let shard_service = Arc::new(ShardServiceDefault::new());
         let lazy_worker_activator = Arc::new(LazyWorkerActivator::new());
 
@@ -215,8 +236,11 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
 
         let linker = Arc::new(linker);
 
+        let clock = self.create_clock();
+
         let key_value_service =
-            key_value::configured(&golem_config.key_value_service, pool.clone());
+            key_value::configured(&golem_config.key_value_service, pool.clone(), clock.clone())
+                .await;
 
         let blob_store_service = blob_store::configured(&golem_config.blob_store_service).await;
 
@@ -225,6 +249,7 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
             shard_service.clone(),
             promise_service.clone(),
             lazy_worker_activator.clone(),
+            clock.clone(),
             golem_config.scheduler.refresh_interval,
         );
 
@@ -237,8 +262,19 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
                 .expect("Access token must be an UUID"),
         ));
 
+        let topic_service = TopicServiceRedis::new(
+            pool.clone(),
+            shard_service.clone(),
+            worker_proxy.clone(),
+            golem_config.topics.delivery_interval,
+            golem_config.topics.max_messages_per_delivery,
+        );
+
         let events = Arc::new(Events::new());
 
+        let worker_event_log_service =
+            worker_event_log::configured(&golem_config.worker_event_log, pool.clone());
+
         let services = self
             .create_services(
                 active_workers,
@@ -258,8 +294,12 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
                 lazy_worker_activator.clone(),
                 oplog_service,
                 scheduler_service,
+                topic_service,
+                mailbox_service,
                 worker_proxy,
                 events,
+                worker_event_log_service,
+                clock,
             )
             .await?;
 
@@ -272,6 +312,7 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
 
         info!("Starting gRPC server on port {}", addr.port());
         Server::builder()
+            .trace_fn(grpc_request_span)
             .concurrency_limit_per_connection(golem_config.limits.concurrency_limit_per_connection)
             .max_concurrent_streams(Some(golem_config.limits.max_concurrent_streams))
             .add_service(reflection_service)
@@ -45,6 +45,10 @@ const FUEL_BUCKETS: &[f64; 11] = &[
     5000000.0, 10000000.0,
 ];
 
+const SUSPENDED_TIME_BUCKETS: &[f64; 11] = &[
+    1.0, 5.0, 30.0, 60.0, 300.0, 900.0, 3600.0, 14400.0, 43200.0, 86400.0, 604800.0,
+];
+
 const MEMORY_SIZE_BUCKETS: &[f64; 11] = &[
     1024.0,
     4096.0,
@@ -278,6 +282,11 @@ pub mod promises {
             "Number of scheduled promise completions"
         )
         .unwrap();
+        static ref PROMISES_SCHEDULED_TIMEOUT_TOTAL: Counter = register_counter!(
+            "promises_scheduled_timeout_total",
+            "Number of promises failed by reaching their deadline"
+        )
+        .unwrap();
     }
 
     pub fn record_promise_created() {
@@ -287,6 +296,10 @@ pub mod promises {
     pub fn record_scheduled_promise_completed() {
         PROMISES_SCHEDULED_COMPLETE_TOTAL.inc();
     }
+
+    pub fn record_scheduled_promise_timed_out() {
+        PROMISES_SCHEDULED_TIMEOUT_TOTAL.inc();
+    }
 }
 
 pub mod sharding {
@@ -405,6 +418,129 @@ pub mod wasm {
     }
 }
 
+pub mod per_component {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::time::Duration;
+
+    use golem_common::model::WorkerId;
+    use lazy_static::lazy_static;
+    use prometheus::*;
+
+    use crate::services::golem_config::MetricsConfig;
+
+    // The worker label is left empty unless per-worker labels are enabled, to keep the
+    // cardinality of these metrics bounded by the number of components by default.
+    static PER_WORKER_LABELS: AtomicBool = AtomicBool::new(false);
+
+    lazy_static! {
+        static ref COMPONENT_INVOCATION_TOTAL: CounterVec = register_counter_vec!(
+            "component_invocation_total",
+            "Number of invocations per component",
+            &["component_id", "worker_name", "outcome"]
+        )
+        .unwrap();
+        static ref COMPONENT_INVOCATION_SECONDS: HistogramVec = register_histogram_vec!(
+            "component_invocation_seconds",
+            "Time taken by invocations per component",
+            &["component_id", "worker_name"],
+            golem_common::metrics::DEFAULT_TIME_BUCKETS.to_vec()
+        )
+        .unwrap();
+        static ref COMPONENT_FUEL_CONSUMED_TOTAL: CounterVec = register_counter_vec!(
+            "component_fuel_consumed_total",
+            "Amount of fuel consumed per component",
+            &["component_id", "worker_name"]
+        )
+        .unwrap();
+        static ref COMPONENT_OPLOG_ENTRIES_TOTAL: CounterVec = register_counter_vec!(
+            "component_oplog_entries_total",
+            "Number of oplog entries written per component",
+            &["component_id", "worker_name"]
+        )
+        .unwrap();
+        static ref COMPONENT_OPLOG_BYTES_TOTAL: CounterVec = register_counter_vec!(
+            "component_oplog_bytes_total",
+            "Size of the oplog entries written per component",
+            &["component_id", "worker_name"]
+        )
+        .unwrap();
+        static ref COMPONENT_REPLAY_SECONDS: HistogramVec = register_histogram_vec!(
+            "component_replay_seconds",
+            "Time taken to replay the oplog when recovering a worker, per component",
+            &["component_id", "worker_name"],
+            golem_common::metrics::DEFAULT_TIME_BUCKETS.to_vec()
+        )
+        .unwrap();
+        static ref COMPONENT_SUSPENDED_SECONDS: HistogramVec = register_histogram_vec!(
+            "component_suspended_seconds",
+            "Time workers spent suspended before being resumed, per component",
+            &["component_id", "worker_name"],
+            crate::metrics::SUSPENDED_TIME_BUCKETS.to_vec()
+        )
+        .unwrap();
+    }
+
+    pub fn configure(config: &MetricsConfig) {
+        PER_WORKER_LABELS.store(config.per_worker_labels, Ordering::Relaxed);
+    }
+
+    fn labels(worker_id: &WorkerId) -> [String; 2] {
+        let worker_name = if PER_WORKER_LABELS.load(Ordering::Relaxed) {
+            worker_id.worker_name.clone()
+        } else {
+            String::new()
+        };
+        [worker_id.component_id.to_string(), worker_name]
+    }
+
+    pub fn record_component_invocation(
+        worker_id: &WorkerId,
+        outcome: &'static str,
+        duration: Duration,
+    ) {
+        let [component_id, worker_name] = labels(worker_id);
+        COMPONENT_INVOCATION_TOTAL
+            .with_label_values(&[&component_id, &worker_name, outcome])
+            .inc();
+        COMPONENT_INVOCATION_SECONDS
+            .with_label_values(&[&component_id, &worker_name])
+            .observe(duration.as_secs_f64());
+    }
+
+    pub fn record_component_fuel_consumption(worker_id: &WorkerId, fuel: i64) {
+        if fuel > 0 {
+            let [component_id, worker_name] = labels(worker_id);
+            COMPONENT_FUEL_CONSUMED_TOTAL
+                .with_label_values(&[&component_id, &worker_name])
+                .inc_by(fuel as f64);
+        }
+    }
+
+    pub fn record_component_oplog_entry(worker_id: &WorkerId, size: usize) {
+        let [component_id, worker_name] = labels(worker_id);
+        COMPONENT_OPLOG_ENTRIES_TOTAL
+            .with_label_values(&[&component_id, &worker_name])
+            .inc();
+        COMPONENT_OPLOG_BYTES_TOTAL
+            .with_label_values(&[&component_id, &worker_name])
+            .inc_by(size as f64);
+    }
+
+    pub fn record_component_replay(worker_id: &WorkerId, duration: Duration) {
+        let [component_id, worker_name] = labels(worker_id);
+        COMPONENT_REPLAY_SECONDS
+            .with_label_values(&[&component_id, &worker_name])
+            .observe(duration.as_secs_f64());
+    }
+
+    pub fn record_component_suspended_time(worker_id: &WorkerId, duration: Duration) {
+        let [component_id, worker_name] = labels(worker_id);
+        COMPONENT_SUSPENDED_SECONDS
+            .with_label_values(&[&component_id, &worker_name])
+            .observe(duration.as_secs_f64());
+    }
+}
+
 pub mod oplog {
     use lazy_static::lazy_static;
     use prometheus::*;
@@ -12,19 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock};
 
 use async_trait::async_trait;
 use aws_config::BehaviorVersion;
 use aws_sdk_s3 as s3;
-use bincode::{Decode, Encode};
+use futures::AsyncWriteExt;
 use golem_common::model::AccountId;
 use s3::config::Region;
 use serde::{Deserialize, Serialize};
 use tokio::io::AsyncReadExt;
 use tokio_stream::StreamExt;
+use uuid::Uuid;
 
 use crate::services::golem_config::{BlobStoreServiceConfig, BlobStoreServiceS3Config};
 
@@ -124,7 +125,57 @@ pub trait BlobStoreService {
         container_name: String,
         object_name: String,
         data: Vec<u8>,
+        attributes: ObjectAttributes,
     ) -> anyhow::Result<()>;
+
+    /// Starts writing an object in multiple parts, returning the id of the upload to pass to
+    /// `write_part` and `complete_multipart_write`
+    async fn start_multipart_write(
+        &self,
+        account_id: AccountId,
+        container_name: String,
+        object_name: String,
+        attributes: ObjectAttributes,
+    ) -> anyhow::Result<String>;
+
+    /// Writes one part of a multipart upload. Parts are numbered from 1, and all of them except
+    /// the last one must be at least 5 MiB long. Returns the tag identifying the written part.
+    async fn write_part(
+        &self,
+        account_id: AccountId,
+        container_name: String,
+        object_name: String,
+        upload_id: String,
+        part_number: u64,
+        data: Vec<u8>,
+    ) -> anyhow::Result<String>;
+
+    /// Assembles the object from the parts written so far, given the tags of all of them in
+    /// the order of their part numbers
+    async fn complete_multipart_write(
+        &self,
+        account_id: AccountId,
+        container_name: String,
+        object_name: String,
+        upload_id: String,
+        part_tags: Vec<String>,
+    ) -> anyhow::Result<()>;
+
+    async fn abort_multipart_write(
+        &self,
+        account_id: AccountId,
+        container_name: String,
+        object_name: String,
+        upload_id: String,
+    ) -> anyhow::Result<()>;
+}
+
+/// The account under which the executor keeps its own objects belonging to the workers of the
+/// given account. Workers only reach the containers of their own account.
+pub fn executor_account_id(account_id: &AccountId) -> AccountId {
+    AccountId {
+        value: format!("{}:golem-executor", account_id.value),
+    }
 }
 
 pub async fn configured(
@@ -454,11 +505,21 @@ impl BlobStoreService for BlobStoreServiceS3 {
         if let Some(contents) = list_objects_v2_output.contents {
             for object in contents {
                 if object.key.clone().unwrap() == object_name {
+                    // Content type and user-defined metadata are only returned for single objects
+                    let head_object_output = self
+                        .client
+                        .head_object()
+                        .bucket(&bucket_name)
+                        .key(object_name.clone())
+                        .send()
+                        .await?;
                     return Ok(ObjectMetadata {
                         name: object.key.unwrap(),
                         container: container_name,
                         created_at: object.last_modified.unwrap().to_millis()? as u64,
                         size: object.size.unwrap_or(0) as u64,
+                        content_type: head_object_output.content_type,
+                        metadata: head_object_output.metadata.unwrap_or_default(),
                     });
                 }
             }
@@ -472,24 +533,127 @@ impl BlobStoreService for BlobStoreServiceS3 {
         container_name: String,
         object_name: String,
         data: Vec<u8>,
+        attributes: ObjectAttributes,
     ) -> anyhow::Result<()> {
         let bucket_name = self.bucket_name(&account_id, &container_name);
         self.client
             .put_object()
             .bucket(&bucket_name)
             .key(object_name)
+            .set_content_type(attributes.content_type)
+            .set_metadata(Some(attributes.metadata))
             .body(data.into())
             .send()
             .await?;
         Ok(())
     }
+
+    async fn start_multipart_write(
+        &self,
+        account_id: AccountId,
+        container_name: String,
+        object_name: String,
+        attributes: ObjectAttributes,
+    ) -> anyhow::Result<String> {
+        let bucket_name = self.bucket_name(&account_id, &container_name);
+        let create_multipart_upload_output = self
+            .client
+            .create_multipart_upload()
+            .bucket(&bucket_name)
+            .key(object_name)
+            .set_content_type(attributes.content_type)
+            .set_metadata(Some(attributes.metadata))
+            .send()
+            .await?;
+        create_multipart_upload_output
+            .upload_id
+            .ok_or(anyhow::anyhow!("Multipart upload has no id"))
+    }
+
+    async fn write_part(
+        &self,
+        account_id: AccountId,
+        container_name: String,
+        object_name: String,
+        upload_id: String,
+        part_number: u64,
+        data: Vec<u8>,
+    ) -> anyhow::Result<String> {
+        let bucket_name = self.bucket_name(&account_id, &container_name);
+        let upload_part_output = self
+            .client
+            .upload_part()
+            .bucket(&bucket_name)
+            .key(object_name)
+            .upload_id(upload_id)
+            .part_number(part_number.try_into()?)
+            .body(data.into())
+            .send()
+            .await?;
+        upload_part_output
+            .e_tag
+            .ok_or(anyhow::anyhow!("Uploaded part {part_number} has no ETag"))
+    }
+
+    async fn complete_multipart_write(
+        &self,
+        account_id: AccountId,
+        container_name: String,
+        object_name: String,
+        upload_id: String,
+        part_tags: Vec<String>,
+    ) -> anyhow::Result<()> {
+        let bucket_name = self.bucket_name(&account_id, &container_name);
+        let mut parts = Vec::new();
+        for (idx, e_tag) in part_tags.into_iter().enumerate() {
+            parts.push(
+                s3::types::CompletedPart::builder()
+                    .e_tag(e_tag)
+                    .part_number((idx + 1).try_into()?)
+                    .build(),
+            );
+        }
+        let completed_multipart_upload = s3::types::CompletedMultipartUpload::builder()
+            .set_parts(Some(parts))
+            .build();
+        self.client
+            .complete_multipart_upload()
+            .bucket(&bucket_name)
+            .key(object_name)
+            .upload_id(upload_id)
+            .multipart_upload(completed_multipart_upload)
+            .send()
+            .await?;
+        Ok(())
+    }
+
+    async fn abort_multipart_write(
+        &self,
+        account_id: AccountId,
+        container_name: String,
+        object_name: String,
+        upload_id: String,
+    ) -> anyhow::Result<()> {
+        let bucket_name = self.bucket_name(&account_id, &container_name);
+        self.client
+            .abort_multipart_upload()
+            .bucket(&bucket_name)
+            .key(object_name)
+            .upload_id(upload_id)
+            .send()
+            .await?;
+        Ok(())
+    }
 }
 
-type Containers = HashMap<String, (u64, Objects)>;
-type Objects = HashMap<String, (u64, Vec<u8>)>;
+/// Containers by the account they belong to and their name
+type Containers = HashMap<(AccountId, String), (u64, Objects)>;
+type Objects = HashMap<String, (u64, Vec<u8>, ObjectAttributes)>;
+type Uploads = HashMap<String, (ObjectAttributes, BTreeMap<u64, Vec<u8>>)>;
 
 pub struct BlobStoreServiceInMemory {
     pub containers: Arc<RwLock<Containers>>,
+    uploads: Arc<RwLock<Uploads>>,
 }
 
 impl Default for BlobStoreServiceInMemory {
@@ -498,113 +662,126 @@ impl Default for BlobStoreServiceInMemory {
     }
 }
 
+fn container_key(account_id: &AccountId, container_name: &str) -> (AccountId, String) {
+    (account_id.clone(), container_name.to_string())
+}
+
 impl BlobStoreServiceInMemory {
     pub fn new() -> Self {
         Self {
             containers: Arc::new(RwLock::new(HashMap::new())),
+            uploads: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 }
 
 #[async_trait]
 impl BlobStoreService for BlobStoreServiceInMemory {
-    async fn clear(&self, _account_id: AccountId, container_name: String) -> anyhow::Result<()> {
+    async fn clear(&self, account_id: AccountId, container_name: String) -> anyhow::Result<()> {
+        let key = container_key(&account_id, &container_name);
         let mut containers = self.containers.write().unwrap();
-        if !containers.contains_key(&container_name) {
+        if !containers.contains_key(&key) {
             anyhow::bail!("Container does not exist");
         }
-        let (_, objects) = containers.get_mut(&container_name).unwrap();
+        let (_, objects) = containers.get_mut(&key).unwrap();
         objects.clear();
         Ok(())
     }
 
     async fn container_exists(
         &self,
-        _account_id: AccountId,
+        account_id: AccountId,
         container_name: String,
     ) -> anyhow::Result<bool> {
+        let key = container_key(&account_id, &container_name);
         let containers = self.containers.read().unwrap();
-        Ok(containers.contains_key(&container_name))
+        Ok(containers.contains_key(&key))
     }
 
     async fn copy_object(
         &self,
-        _account_id: AccountId,
+        account_id: AccountId,
         source_container_name: String,
         source_object_name: String,
         destination_container_name: String,
         destination_object_name: String,
     ) -> anyhow::Result<()> {
+        let source_key = container_key(&account_id, &source_container_name);
+        let destination_key = container_key(&account_id, &destination_container_name);
         let mut containers = self.containers.write().unwrap();
-        if !containers.contains_key(&source_container_name) {
+        if !containers.contains_key(&source_key) {
             anyhow::bail!("Source container does not exist");
         }
-        if !containers.contains_key(&destination_container_name) {
+        if !containers.contains_key(&destination_key) {
             anyhow::bail!("Destination container does not exist");
         }
-        let source_container = &containers.get_mut(&source_container_name).unwrap().1;
+        let source_container = &containers.get_mut(&source_key).unwrap().1;
         if !source_container.contains_key(&source_object_name) {
             anyhow::bail!("Source object does not exist");
         }
         let source_object = source_container.get(&source_object_name).unwrap().clone();
-        let destination_container = &mut containers.get_mut(&destination_container_name).unwrap().1;
+        let destination_container = &mut containers.get_mut(&destination_key).unwrap().1;
         destination_container.insert(destination_object_name, source_object.clone());
         Ok(())
     }
 
     async fn create_container(
         &self,
-        _account_id: AccountId,
+        account_id: AccountId,
         container_name: String,
     ) -> anyhow::Result<u64> {
+        let key = container_key(&account_id, &container_name);
         let mut containers = self.containers.write().unwrap();
-        if containers.contains_key(&container_name) {
+        if containers.contains_key(&key) {
             anyhow::bail!("Container already exists");
         }
         let created_at = chrono::Utc::now().timestamp_millis() as u64;
-        containers.insert(container_name, (created_at, HashMap::new()));
+        containers.insert(key, (created_at, HashMap::new()));
         Ok(created_at)
     }
 
     async fn delete_container(
         &self,
-        _account_id: AccountId,
+        account_id: AccountId,
         container_name: String,
     ) -> anyhow::Result<()> {
+        let key = container_key(&account_id, &container_name);
         let mut containers = self.containers.write().unwrap();
-        if !containers.contains_key(&container_name) {
+        if !containers.contains_key(&key) {
             anyhow::bail!("Container does not exist");
         }
-        containers.remove(&container_name);
+        containers.remove(&key);
         Ok(())
     }
 
     async fn delete_object(
         &self,
-        _account_id: AccountId,
+        account_id: AccountId,
         container_name: String,
         object_name: String,
     ) -> anyhow::Result<()> {
+        let key = container_key(&account_id, &container_name);
         let mut containers = self.containers.write().unwrap();
-        if !containers.contains_key(&container_name) {
+        if !containers.contains_key(&key) {
             anyhow::bail!("Container does not exist");
         }
-        let (_, objects) = containers.get_mut(&container_name).unwrap();
+        let (_, objects) = containers.get_mut(&key).unwrap();
         objects.remove(&object_name);
         Ok(())
     }
 
     async fn delete_objects(
         &self,
-        _account_id: AccountId,
+        account_id: AccountId,
         container_name: String,
         object_names: Vec<String>,
     ) -> anyhow::Result<()> {
+        let key = container_key(&account_id, &container_name);
         let mut containers = self.containers.write().unwrap();
-        if !containers.contains_key(&container_name) {
+        if !containers.contains_key(&key) {
             anyhow::bail!("Container does not exist");
         }
-        let (_, objects) = containers.get_mut(&container_name).unwrap();
+        let (_, objects) = containers.get_mut(&key).unwrap();
         for object_name in object_names {
             objects.remove(&object_name);
         }
@@ -613,130 +790,219 @@ impl BlobStoreService for BlobStoreServiceInMemory {
 
     async fn get_container(
         &self,
-        _account_id: AccountId,
+        account_id: AccountId,
         container_name: String,
     ) -> anyhow::Result<Option<u64>> {
+        let key = container_key(&account_id, &container_name);
         let containers = self.containers.read().unwrap();
-        if !containers.contains_key(&container_name) {
+        if !containers.contains_key(&key) {
             return Ok(None);
         }
-        let (created_at, _) = containers.get(&container_name).unwrap();
+        let (created_at, _) = containers.get(&key).unwrap();
         Ok(Some(*created_at))
     }
 
     async fn get_data(
         &self,
-        _account_id: AccountId,
+        account_id: AccountId,
         container_name: String,
         object_name: String,
         start: u64,
         end: u64,
     ) -> anyhow::Result<Vec<u8>> {
+        let key = container_key(&account_id, &container_name);
         let containers = self.containers.read().unwrap();
-        if !containers.contains_key(&container_name) {
+        if !containers.contains_key(&key) {
             anyhow::bail!("Container does not exist");
         }
-        let (_, objects) = containers.get(&container_name).unwrap();
+        let (_, objects) = containers.get(&key).unwrap();
         if !objects.contains_key(&object_name) {
             anyhow::bail!("Object does not exist");
         }
-        let (_, data) = objects.get(&object_name).unwrap();
+        let (_, data, _) = objects.get(&object_name).unwrap();
         Ok(data[start as usize..end as usize].to_vec())
     }
 
     async fn has_object(
         &self,
-        _account_id: AccountId,
+        account_id: AccountId,
         container_name: String,
         object_name: String,
     ) -> anyhow::Result<bool> {
+        let key = container_key(&account_id, &container_name);
         let containers = self.containers.read().unwrap();
-        if !containers.contains_key(&container_name) {
+        if !containers.contains_key(&key) {
             anyhow::bail!("Container does not exist");
         }
-        let (_, objects) = containers.get(&container_name).unwrap();
+        let (_, objects) = containers.get(&key).unwrap();
         Ok(objects.contains_key(&object_name))
     }
 
     async fn list_objects(
         &self,
-        _account_id: AccountId,
+        account_id: AccountId,
         container_name: String,
     ) -> anyhow::Result<Vec<String>> {
+        let key = container_key(&account_id, &container_name);
         let containers = self.containers.read().unwrap();
-        if !containers.contains_key(&container_name) {
+        if !containers.contains_key(&key) {
             anyhow::bail!("Container does not exist");
         }
-        let (_, objects) = containers.get(&container_name).unwrap();
+        let (_, objects) = containers.get(&key).unwrap();
         Ok(objects.keys().cloned().collect())
     }
 
     async fn move_object(
         &self,
-        _account_id: AccountId,
+        account_id: AccountId,
         source_container_name: String,
         source_object_name: String,
         destination_container_name: String,
         destination_object_name: String,
     ) -> anyhow::Result<()> {
+        let source_key = container_key(&account_id, &source_container_name);
+        let destination_key = container_key(&account_id, &destination_container_name);
         let mut containers = self.containers.write().unwrap();
-        if !containers.contains_key(&source_container_name) {
+        if !containers.contains_key(&source_key) {
             anyhow::bail!("Source container does not exist");
         }
-        if !containers.contains_key(&destination_container_name) {
+        if !containers.contains_key(&destination_key) {
             anyhow::bail!("Destination container does not exist");
         }
-        let source_container = &mut containers.get_mut(&source_container_name).unwrap().1;
+        let source_container = &mut containers.get_mut(&source_key).unwrap().1;
         if !source_container.contains_key(&source_object_name) {
             anyhow::bail!("Source object does not exist");
         }
         let source_object = source_container.remove(&source_object_name).unwrap();
-        let destination_container = &mut containers.get_mut(&destination_container_name).unwrap().1;
+        let destination_container = &mut containers.get_mut(&destination_key).unwrap().1;
         destination_container.insert(destination_object_name, source_object);
         Ok(())
     }
 
     async fn object_info(
         &self,
-        _account_id: AccountId,
+        account_id: AccountId,
         container_name: String,
         object_name: String,
     ) -> anyhow::Result<ObjectMetadata> {
+        let key = container_key(&account_id, &container_name);
         let containers = self.containers.read().unwrap();
-        if !containers.contains_key(&container_name) {
+        if !containers.contains_key(&key) {
             anyhow::bail!("Container does not exist");
         }
-        let (_, objects) = containers.get(&container_name).unwrap();
+        let (_, objects) = containers.get(&key).unwrap();
         if !objects.contains_key(&object_name) {
             anyhow::bail!("Object does not exist");
         }
-        let (created_at, data) = objects.get(&object_name).unwrap();
+        let (created_at, data, attributes) = objects.get(&object_name).unwrap();
         Ok(ObjectMetadata {
             name: object_name,
             container: container_name,
             created_at: *created_at,
             size: data.len() as u64,
+            content_type: attributes.content_type.clone(),
+            metadata: attributes.metadata.clone(),
         })
     }
 
     async fn write_data(
         &self,
-        _account_id: AccountId,
+        account_id: AccountId,
         container_name: String,
         object_name: String,
         data: Vec<u8>,
+        attributes: ObjectAttributes,
     ) -> anyhow::Result<()> {
+        let key = container_key(&account_id, &container_name);
         let mut containers = self.containers.write().unwrap();
-        if !containers.contains_key(&container_name) {
+        if !containers.contains_key(&key) {
             anyhow::bail!("Container does not exist");
         }
-        let (_, objects) = containers.get_mut(&container_name).unwrap();
+        let (_, objects) = containers.get_mut(&key).unwrap();
         objects.insert(
             object_name,
-            (chrono::Utc::now().timestamp_millis() as u64, data),
+            (
+                chrono::Utc::now().timestamp_millis() as u64,
+                data,
+                attributes,
+            ),
         );
         Ok(())
     }
+
+    async fn start_multipart_write(
+        &self,
+        account_id: AccountId,
+        container_name: String,
+        _object_name: String,
+        attributes: ObjectAttributes,
+    ) -> anyhow::Result<String> {
+        let key = container_key(&account_id, &container_name);
+        let containers = self.containers.read().unwrap();
+        if !containers.contains_key(&key) {
+            anyhow::bail!("Container does not exist");
+        }
+        let upload_id = Uuid::new_v4().to_string();
+        let mut uploads = self.uploads.write().unwrap();
+        uploads.insert(upload_id.clone(), (attributes, BTreeMap::new()));
+        Ok(upload_id)
+    }
+
+    async fn write_part(
+        &self,
+        _account_id: AccountId,
+        _container_name: String,
+        _object_name: String,
+        upload_id: String,
+        part_number: u64,
+        data: Vec<u8>,
+    ) -> anyhow::Result<String> {
+        let mut uploads = self.uploads.write().unwrap();
+        if !uploads.contains_key(&upload_id) {
+            anyhow::bail!("Upload does not exist");
+        }
+        let (_, parts) = uploads.get_mut(&upload_id).unwrap();
+        parts.insert(part_number, data);
+        Ok(part_number.to_string())
+    }
+
+    async fn complete_multipart_write(
+        &self,
+        account_id: AccountId,
+        container_name: String,
+        object_name: String,
+        upload_id: String,
+        part_tags: Vec<String>,
+    ) -> anyhow::Result<()> {
+        let (attributes, parts) = {
+            let mut uploads = self.uploads.write().unwrap();
+            if !uploads.contains_key(&upload_id) {
+                anyhow::bail!("Upload does not exist");
+            }
+            uploads.remove(&upload_id).unwrap()
+        };
+        let mut data = Vec::new();
+        for part_number in 1..=part_tags.len() as u64 {
+            match parts.get(&part_number) {
+                Some(part) => data.extend_from_slice(part),
+                None => anyhow::bail!("Part {part_number} was not written"),
+            }
+        }
+        self.write_data(account_id, container_name, object_name, data, attributes)
+            .await
+    }
+
+    async fn abort_multipart_write(
+        &self,
+        _account_id: AccountId,
+        _container_name: String,
+        _object_name: String,
+        upload_id: String,
+    ) -> anyhow::Result<()> {
+        let mut uploads = self.uploads.write().unwrap();
+        uploads.remove(&upload_id);
+        Ok(())
+    }
 }
 
 pub struct BlobStoreServiceLocal {
@@ -777,6 +1043,87 @@ impl BlobStoreServiceLocal {
             anyhow::bail!("Invalid object path pointing outside of the root directory for {account_id}/{container_name}");
         }
     }
+
+    /// The file storing the content type and user-defined metadata of an object as JSON, in a
+    /// directory tree mirroring the containers, so it never shows up as an object
+    fn attributes_path(
+        &self,
+        account_id: &AccountId,
+        container_name: &String,
+        object_name: &String,
+    ) -> anyhow::Result<PathBuf> {
+        let path = self
+            .metadata_container_path(account_id, container_name)?
+            .join(object_name);
+        if path.starts_with(&self.root) {
+            Ok(path)
+        } else {
+            anyhow::bail!("Invalid object metadata path pointing outside of the root directory for {account_id}/{container_name}");
+        }
+    }
+
+    fn metadata_container_path(
+        &self,
+        account_id: &AccountId,
+        container_name: &String,
+    ) -> anyhow::Result<PathBuf> {
+        let path = self
+            .root
+            .join(".metadata")
+            .join(account_id.to_string())
+            .join(container_name);
+        if path.starts_with(&self.root) {
+            Ok(path)
+        } else {
+            anyhow::bail!("Invalid container metadata path pointing outside of the root directory for {account_id}/{container_name}");
+        }
+    }
+
+    async fn read_attributes(&self, path: &Path) -> anyhow::Result<ObjectAttributes> {
+        match async_fs::read(path).await {
+            Ok(data) => Ok(serde_json::from_slice(&data)?),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                Ok(ObjectAttributes::default())
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn write_attributes(
+        &self,
+        path: &Path,
+        attributes: &ObjectAttributes,
+    ) -> anyhow::Result<()> {
+        if *attributes == ObjectAttributes::default() {
+            self.remove_attributes(path).await
+        } else {
+            if let Some(parent) = path.parent() {
+                async_fs::create_dir_all(parent).await?;
+            }
+            async_fs::write(path, serde_json::to_vec(attributes)?).await?;
+            Ok(())
+        }
+    }
+
+    async fn remove_attributes(&self, path: &Path) -> anyhow::Result<()> {
+        match async_fs::remove_file(path).await {
+            Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err.into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// The directory collecting the parts of a multipart upload, outside of all containers so
+    /// they never show up as objects
+    fn upload_path(&self, upload_id: &str) -> anyhow::Result<PathBuf> {
+        let path = self.root.join(".uploads").join(upload_id);
+        if path.starts_with(&self.root) {
+            Ok(path)
+        } else {
+            anyhow::bail!(
+                "Invalid upload path pointing outside of the root directory for {upload_id}"
+            );
+        }
+    }
 }
 
 #[async_trait]
@@ -787,6 +1134,10 @@ impl BlobStoreService for BlobStoreServiceLocal {
             async_fs::remove_dir_all(&container_path).await?;
             async_fs::create_dir_all(&container_path).await?;
         }
+        let metadata_container_path = self.metadata_container_path(&account_id, &container_name)?;
+        if async_fs::metadata(&metadata_container_path).await.is_ok() {
+            async_fs::remove_dir_all(&metadata_container_path).await?;
+        }
         Ok(())
     }
 
@@ -816,6 +1167,17 @@ impl BlobStoreService for BlobStoreServiceLocal {
         )?;
 
         async_fs::copy(&source_path, &destination_path).await?;
+
+        let source_attributes_path =
+            self.attributes_path(&account_id, &source_container_name, &source_object_name)?;
+        let destination_attributes_path = self.attributes_path(
+            &account_id,
+            &destination_container_name,
+            &destination_object_name,
+        )?;
+        let attributes = self.read_attributes(&source_attributes_path).await?;
+        self.write_attributes(&destination_attributes_path, &attributes)
+            .await?;
         Ok(())
     }
 
@@ -842,6 +1204,10 @@ impl BlobStoreService for BlobStoreServiceLocal {
     ) -> anyhow::Result<()> {
         let container_path = self.container_path(&account_id, &container_name)?;
         async_fs::remove_dir_all(&container_path).await?;
+        let metadata_container_path = self.metadata_container_path(&account_id, &container_name)?;
+        if async_fs::metadata(&metadata_container_path).await.is_ok() {
+            async_fs::remove_dir_all(&metadata_container_path).await?;
+        }
         Ok(())
     }
 
@@ -853,6 +1219,8 @@ impl BlobStoreService for BlobStoreServiceLocal {
     ) -> anyhow::Result<()> {
         let object_path = self.object_path(&account_id, &container_name, &object_name)?;
         async_fs::remove_file(&object_path).await?;
+        let attributes_path = self.attributes_path(&account_id, &container_name, &object_name)?;
+        self.remove_attributes(&attributes_path).await?;
         Ok(())
     }
 
@@ -942,6 +1310,18 @@ impl BlobStoreService for BlobStoreServiceLocal {
         )?;
 
         async_fs::rename(&source_path, &destination_path).await?;
+
+        let source_attributes_path =
+            self.attributes_path(&account_id, &source_container_name, &source_object_name)?;
+        let destination_attributes_path = self.attributes_path(
+            &account_id,
+            &destination_container_name,
+            &destination_object_name,
+        )?;
+        let attributes = self.read_attributes(&source_attributes_path).await?;
+        self.write_attributes(&destination_attributes_path, &attributes)
+            .await?;
+        self.remove_attributes(&source_attributes_path).await?;
         Ok(())
     }
 
@@ -959,11 +1339,15 @@ impl BlobStoreService for BlobStoreServiceLocal {
             .as_millis()
             .try_into()?;
         let size = metadata.len();
+        let attributes_path = self.attributes_path(&account_id, &container_name, &object_name)?;
+        let attributes = self.read_attributes(&attributes_path).await?;
         Ok(ObjectMetadata {
             name: object_name,
             container: container_name,
             created_at,
             size,
+            content_type: attributes.content_type,
+            metadata: attributes.metadata,
         })
     }
 
@@ -973,25 +1357,121 @@ impl BlobStoreService for BlobStoreServiceLocal {
         container_name: String,
         object_name: String,
         data: Vec<u8>,
+        attributes: ObjectAttributes,
     ) -> anyhow::Result<()> {
         let object_path = self.object_path(&account_id, &container_name, &object_name)?;
         async_fs::write(&object_path, data).await?;
+        let attributes_path = self.attributes_path(&account_id, &container_name, &object_name)?;
+        self.write_attributes(&attributes_path, &attributes).await?;
+        Ok(())
+    }
+
+    async fn start_multipart_write(
+        &self,
+        account_id: AccountId,
+        container_name: String,
+        _object_name: String,
+        attributes: ObjectAttributes,
+    ) -> anyhow::Result<String> {
+        let container_path = self.container_path(&account_id, &container_name)?;
+        if async_fs::metadata(&container_path).await.is_err() {
+            anyhow::bail!("Container does not exist");
+        }
+        let upload_id = Uuid::new_v4().to_string();
+        let upload_path = self.upload_path(&upload_id)?;
+        async_fs::create_dir_all(&upload_path).await?;
+        async_fs::write(
+            upload_path.join("attributes"),
+            serde_json::to_vec(&attributes)?,
+        )
+        .await?;
+        Ok(upload_id)
+    }
+
+    async fn write_part(
+        &self,
+        _account_id: AccountId,
+        _container_name: String,
+        _object_name: String,
+        upload_id: String,
+        part_number: u64,
+        data: Vec<u8>,
+    ) -> anyhow::Result<String> {
+        let part_path = self.upload_path(&upload_id)?.join(part_number.to_string());
+        async_fs::write(&part_path, data).await?;
+        Ok(part_number.to_string())
+    }
+
+    async fn complete_multipart_write(
+        &self,
+        account_id: AccountId,
+        container_name: String,
+        object_name: String,
+        upload_id: String,
+        part_tags: Vec<String>,
+    ) -> anyhow::Result<()> {
+        let upload_path = self.upload_path(&upload_id)?;
+        let object_path = self.object_path(&account_id, &container_name, &object_name)?;
+
+        // Assembling the object next to the parts, so it only appears in the container once complete
+        let assembled_path = upload_path.join("object");
+        let mut assembled = async_fs::File::create(&assembled_path).await?;
+        for part_number in 1..=part_tags.len() {
+            let part = async_fs::File::open(upload_path.join(part_number.to_string())).await?;
+            futures::io::copy(part, &mut assembled).await?;
+        }
+        assembled.flush().await?;
+        assembled.sync_all().await?;
+        drop(assembled);
+
+        async_fs::rename(&assembled_path, &object_path).await?;
+
+        let attributes = self
+            .read_attributes(&upload_path.join("attributes"))
+            .await?;
+        let attributes_path = self.attributes_path(&account_id, &container_name, &object_name)?;
+        self.write_attributes(&attributes_path, &attributes).await?;
+        async_fs::remove_dir_all(&upload_path).await?;
+        Ok(())
+    }
+
+    async fn abort_multipart_write(
+        &self,
+        _account_id: AccountId,
+        _container_name: String,
+        _object_name: String,
+        upload_id: String,
+    ) -> anyhow::Result<()> {
+        let upload_path = self.upload_path(&upload_id)?;
+        if async_fs::metadata(&upload_path).await.is_ok() {
+            async_fs::remove_dir_all(&upload_path).await?;
+        }
         Ok(())
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Encode, Decode)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ObjectMetadata {
     pub name: String,
     pub container: String,
     pub created_at: u64,
     pub size: u64,
+    pub content_type: Option<String>,
+    pub metadata: HashMap<String, String>,
+}
+
+/// The content type and user-defined metadata stored together with an object
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ObjectAttributes {
+    pub content_type: Option<String>,
+    pub metadata: HashMap<String, String>,
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::services::blob_store::BlobStoreService;
+    use crate::services::blob_store::{BlobStoreService, ObjectAttributes};
     use golem_common::model::AccountId;
+    use std::collections::HashMap;
     use tempfile::TempDir;
 
     async fn test_container_exists(blob_store: &impl BlobStoreService) {
@@ -1055,6 +1535,7 @@ mod tests {
                 "container1".to_string(),
                 "obj1".to_string(),
                 original_data.clone(),
+                ObjectAttributes::default(),
             )
             .await
             .unwrap();
@@ -1108,6 +1589,7 @@ mod tests {
                 "container1".to_string(),
                 "obj1".to_string(),
                 original_data.clone(),
+                ObjectAttributes::default(),
             )
             .await
             .unwrap();
@@ -1160,6 +1642,190 @@ mod tests {
         );
     }
 
+    async fn test_container_multipart_write(blob_store: &impl BlobStoreService) {
+        let account1 = AccountId {
+            value: "account1".to_string(),
+        };
+
+        blob_store
+            .create_container(account1.clone(), "container1".to_string())
+            .await
+            .unwrap();
+
+        let upload_id = blob_store
+            .start_multipart_write(
+                account1.clone(),
+                "container1".to_string(),
+                "obj1".to_string(),
+                ObjectAttributes {
+                    content_type: Some("application/octet-stream".to_string()),
+                    metadata: HashMap::new(),
+                },
+            )
+            .await
+            .unwrap();
+
+        let mut part_tags = Vec::new();
+        for (part_number, data) in [vec![1, 2, 3], vec![4, 5], vec![6]].into_iter().enumerate() {
+            part_tags.push(
+                blob_store
+                    .write_part(
+                        account1.clone(),
+                        "container1".to_string(),
+                        "obj1".to_string(),
+                        upload_id.clone(),
+                        part_number as u64 + 1,
+                        data,
+                    )
+                    .await
+                    .unwrap(),
+            );
+        }
+
+        assert_eq!(
+            blob_store
+                .list_objects(account1.clone(), "container1".to_string())
+                .await
+                .unwrap(),
+            Vec::<String>::new()
+        );
+
+        blob_store
+            .complete_multipart_write(
+                account1.clone(),
+                "container1".to_string(),
+                "obj1".to_string(),
+                upload_id,
+                part_tags,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            blob_store
+                .get_data(
+                    account1.clone(),
+                    "container1".to_string(),
+                    "obj1".to_string(),
+                    0,
+                    6
+                )
+                .await
+                .unwrap(),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+
+        assert_eq!(
+            blob_store
+                .list_objects(account1.clone(), "container1".to_string())
+                .await
+                .unwrap(),
+            vec!["obj1"]
+        );
+    }
+
+    async fn test_container_write_copy_move_attributes(blob_store: &impl BlobStoreService) {
+        let account1 = AccountId {
+            value: "account1".to_string(),
+        };
+
+        blob_store
+            .create_container(account1.clone(), "container1".to_string())
+            .await
+            .unwrap();
+
+        let attributes = ObjectAttributes {
+            content_type: Some("text/plain".to_string()),
+            metadata: HashMap::from_iter(vec![("author".to_string(), "golem".to_string())]),
+        };
+        blob_store
+            .write_data(
+                account1.clone(),
+                "container1".to_string(),
+                "obj1".to_string(),
+                vec![1, 2, 3, 4],
+                attributes.clone(),
+            )
+            .await
+            .unwrap();
+        blob_store
+            .copy_object(
+                account1.clone(),
+                "container1".to_string(),
+                "obj1".to_string(),
+                "container1".to_string(),
+                "obj2".to_string(),
+            )
+            .await
+            .unwrap();
+        blob_store
+            .move_object(
+                account1.clone(),
+                "container1".to_string(),
+                "obj2".to_string(),
+                "container1".to_string(),
+                "obj3".to_string(),
+            )
+            .await
+            .unwrap();
+
+        let obj1 = blob_store
+            .object_info(
+                account1.clone(),
+                "container1".to_string(),
+                "obj1".to_string(),
+            )
+            .await
+            .unwrap();
+        let obj3 = blob_store
+            .object_info(
+                account1.clone(),
+                "container1".to_string(),
+                "obj3".to_string(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(obj1.content_type, attributes.content_type);
+        assert_eq!(obj1.metadata, attributes.metadata);
+        assert_eq!(obj3.content_type, attributes.content_type);
+        assert_eq!(obj3.metadata, attributes.metadata);
+
+        let mut objects = blob_store
+            .list_objects(account1.clone(), "container1".to_string())
+            .await
+            .unwrap();
+        objects.sort();
+        assert_eq!(objects, vec!["obj1", "obj3"]);
+    }
+
+    async fn test_containers_of_executor_account(blob_store: &impl BlobStoreService) {
+        let account1 = AccountId {
+            value: "account1".to_string(),
+        };
+        let executor_account = super::executor_account_id(&account1);
+        blob_store
+            .create_container(executor_account.clone(), "container1".to_string())
+            .await
+            .unwrap();
+        assert!(!blob_store
+            .container_exists(account1.clone(), "container1".to_string())
+            .await
+            .unwrap());
+        blob_store
+            .create_container(account1.clone(), "container1".to_string())
+            .await
+            .unwrap();
+        blob_store
+            .delete_container(account1.clone(), "container1".to_string())
+            .await
+            .unwrap();
+        assert!(blob_store
+            .container_exists(executor_account.clone(), "container1".to_string())
+            .await
+            .unwrap());
+    }
+
     #[tokio::test]
     async fn test_container_exists_in_memory() {
         let blob_store = super::BlobStoreServiceInMemory::new();
@@ -1219,4 +1885,49 @@ mod tests {
             .unwrap();
         test_container_list_copy_move_list(&blob_store).await;
     }
+
+    #[tokio::test]
+    async fn test_container_multipart_write_in_memory() {
+        let blob_store = super::BlobStoreServiceInMemory::new();
+        test_container_multipart_write(&blob_store).await;
+    }
+
+    #[tokio::test]
+    async fn test_container_multipart_write_local() {
+        let tempdir = TempDir::new().unwrap();
+        let blob_store = super::BlobStoreServiceLocal::new(tempdir.path())
+            .await
+            .unwrap();
+        test_container_multipart_write(&blob_store).await;
+    }
+
+    #[tokio::test]
+    async fn test_container_write_copy_move_attributes_in_memory() {
+        let blob_store = super::BlobStoreServiceInMemory::new();
+        test_container_write_copy_move_attributes(&blob_store).await;
+    }
+
+    #[tokio::test]
+    async fn test_container_write_copy_move_attributes_local() {
+        let tempdir = TempDir::new().unwrap();
+        let blob_store = super::BlobStoreServiceLocal::new(tempdir.path())
+            .await
+            .unwrap();
+        test_container_write_copy_move_attributes(&blob_store).await;
+    }
+
+    #[tokio::test]
+    async fn test_containers_of_executor_account_in_memory() {
+        let blob_store = super::BlobStoreServiceInMemory::new();
+        test_containers_of_executor_account(&blob_store).await;
+    }
+
+    #[tokio::test]
+    async fn test_containers_of_executor_account_local() {
+        let tempdir = TempDir::new().unwrap();
+        let blob_store = super::BlobStoreServiceLocal::new(tempdir.path())
+            .await
+            .unwrap();
+        test_containers_of_executor_account(&blob_store).await;
+    }
 }
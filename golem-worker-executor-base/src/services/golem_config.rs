@@ -13,17 +13,21 @@
 // limitations under the License.
 
 use anyhow::Context;
+use std::collections::HashMap;
 use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
 use std::path::PathBuf;
 use std::time::Duration;
 
 use figment::providers::{Env, Format, Toml};
 use figment::Figment;
-use golem_common::config::{RedisConfig, RetryConfig};
+use golem_common::config::{OtlpConfig, RedisConfig, RetryConfig};
+use golem_common::model::{AccountId, ComponentId};
 use http::Uri;
 use serde::Deserialize;
 use url::Url;
 
+use crate::egress::EgressPolicy;
+
 /// The shared global Golem configuration
 #[derive(Clone, Debug, Deserialize)]
 pub struct GolemConfig {
@@ -33,19 +37,28 @@ pub struct GolemConfig {
     pub component_service: ComponentServiceConfig,
     pub compiled_component_service: CompiledComponentServiceConfig,
     pub blob_store_service: BlobStoreServiceConfig,
+    pub blob_store: BlobStoreConfig,
     pub key_value_service: KeyValueServiceConfig,
+    pub key_value: KeyValueConfig,
     pub promises: PromisesConfig,
+    pub promise_deadlines: PromiseDeadlinesConfig,
+    pub mailboxes: MailboxesConfig,
     pub shard_manager_service: ShardManagerServiceConfig,
     pub workers: WorkersServiceConfig,
+    pub worker_event_log: WorkerEventLogConfig,
     pub redis: RedisConfig,
     pub oplog: OplogConfig,
     pub suspend: SuspendConfig,
     pub active_workers: ActiveWorkersConfig,
     pub scheduler: SchedulerConfig,
+    pub topics: TopicsConfig,
     pub invocation_keys: InvocationKeysConfig, // TODO: review and remove?
     pub public_worker_api: WorkerServiceGrpcConfig,
     pub enable_tracing_console: bool,
     pub enable_json_log: bool,
+    pub otlp: OtlpConfig,
+    pub metrics: MetricsConfig,
+    pub egress: EgressConfig,
     pub grpc_address: String,
     pub port: u16,
     pub http_address: String,
@@ -63,6 +76,9 @@ pub struct Limits {
     #[serde(with = "humantime_serde")]
     pub epoch_interval: Duration,
     pub epoch_ticks: u64,
+    /// Upper limit of the number of workers a broadcast started by a worker invokes at once,
+    /// whatever concurrency the worker asks for
+    pub max_broadcast_concurrency: u32,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -141,6 +157,14 @@ pub struct BlobStoreServiceLocalConfig {
     pub root: PathBuf,
 }
 
+#[derive(Clone, Debug, Deserialize)]
+pub struct BlobStoreConfig {
+    /// Objects written by workers are kept in memory up to this size. Larger ones are buffered
+    /// in a temporary file and uploaded in parts of this size, each recorded as a separate oplog
+    /// entry. Must be at least 5 MiB when using S3.
+    pub part_size: usize,
+}
+
 #[derive(Clone, Debug, Deserialize)]
 #[serde(tag = "type", content = "config")]
 pub enum ShardManagerServiceConfig {
@@ -160,6 +184,52 @@ pub struct ShardManagerServiceGrpcConfig {
 pub enum KeyValueServiceConfig {
     Redis,
     InMemory,
+    Sqlite(KeyValueServiceSqliteConfig),
+    Postgres(KeyValueServicePostgresConfig),
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct KeyValueServiceSqliteConfig {
+    pub database: String,
+    pub max_connections: u32,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct KeyValueServicePostgresConfig {
+    pub host: String,
+    pub port: u16,
+    pub database: String,
+    pub username: String,
+    pub password: String,
+    pub max_connections: u32,
+    pub schema: Option<String>,
+}
+
+/// Expiry of the entries written to the worker key-value store. A bucket's own TTL takes
+/// precedence over the default one; entries never expire if neither is set.
+#[derive(Clone, Debug, Deserialize)]
+pub struct KeyValueConfig {
+    #[serde(default, with = "humantime_serde")]
+    pub default_ttl: Option<Duration>,
+    pub buckets: HashMap<String, KeyValueBucketConfig>,
+    /// Number of keys listed at once when a worker lists the keys of a bucket. Each page is
+    /// recorded as a separate oplog entry.
+    pub keys_page_size: u64,
+}
+
+impl KeyValueConfig {
+    pub fn ttl(&self, bucket: &str) -> Option<Duration> {
+        self.buckets
+            .get(bucket)
+            .map(|bucket| bucket.ttl)
+            .or(self.default_ttl)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct KeyValueBucketConfig {
+    #[serde(with = "humantime_serde")]
+    pub ttl: Duration,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -169,6 +239,21 @@ pub enum PromisesConfig {
     InMemory,
 }
 
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type", content = "config")]
+pub enum MailboxesConfig {
+    Redis,
+    InMemory,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct PromiseDeadlinesConfig {
+    /// Promises created by workers fail with a timeout if they are not completed within this
+    /// time. By default they never time out.
+    #[serde(default, with = "humantime_serde")]
+    pub default_timeout: Option<Duration>,
+}
+
 #[derive(Clone, Debug, Deserialize)]
 #[serde(tag = "type", content = "config")]
 pub enum WorkersServiceConfig {
@@ -176,6 +261,21 @@ pub enum WorkersServiceConfig {
     InMemory,
 }
 
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type", content = "config")]
+pub enum WorkerEventLogConfig {
+    Disabled,
+    Redis(WorkerEventLogRetentionConfig),
+    InMemory(WorkerEventLogRetentionConfig),
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct WorkerEventLogRetentionConfig {
+    pub max_events: usize,
+    #[serde(with = "humantime_serde")]
+    pub max_age: Duration,
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct WorkerServiceGrpcConfig {
     pub host: String,
@@ -260,6 +360,31 @@ pub struct SuspendConfig {
     pub suspend_after: Duration,
 }
 
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct MetricsConfig {
+    /// Labels the per-component metrics with the worker name too. This results in one time
+    /// series per worker, so it should only be enabled when the number of workers is small.
+    pub per_worker_labels: bool,
+}
+
+/// Egress policies of the workers. A component's own policy takes precedence over the policy
+/// of its account, which takes precedence over the default one.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct EgressConfig {
+    pub default: EgressPolicy,
+    pub accounts: HashMap<String, EgressPolicy>,
+    pub components: HashMap<String, EgressPolicy>,
+}
+
+impl EgressConfig {
+    pub fn policy(&self, component_id: &ComponentId, account_id: &AccountId) -> &EgressPolicy {
+        self.components
+            .get(&component_id.to_string())
+            .or_else(|| self.accounts.get(&account_id.value))
+            .unwrap_or(&self.default)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct ActiveWorkersConfig {
     pub drop_when_full: f64,
@@ -273,11 +398,23 @@ pub struct SchedulerConfig {
     pub refresh_interval: Duration,
 }
 
+#[derive(Clone, Debug, Deserialize)]
+pub struct TopicsConfig {
+    /// How often the subscriptions are checked for undelivered messages
+    #[serde(with = "humantime_serde")]
+    pub delivery_interval: Duration,
+    /// Maximum number of messages delivered to one subscription in a single round
+    pub max_messages_per_delivery: u64,
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct OplogConfig {
     pub max_operations_before_commit: u64,
     pub operations_to_load: u64,
     pub debug_enabled: bool,
+    /// Incoming HTTP response bodies larger than this are stored in the blob store instead of
+    /// the oplog. Offloading is disabled if not set.
+    pub http_body_blob_threshold: Option<usize>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -296,19 +433,28 @@ impl Default for GolemConfig {
             component_service: ComponentServiceConfig::default(),
             compiled_component_service: CompiledComponentServiceConfig::default(),
             blob_store_service: BlobStoreServiceConfig::default(),
+            blob_store: BlobStoreConfig::default(),
             key_value_service: KeyValueServiceConfig::default(),
+            key_value: KeyValueConfig::default(),
             promises: PromisesConfig::default(),
+            promise_deadlines: PromiseDeadlinesConfig::default(),
+            mailboxes: MailboxesConfig::default(),
             shard_manager_service: ShardManagerServiceConfig::default(),
             workers: WorkersServiceConfig::default(),
+            worker_event_log: WorkerEventLogConfig::default(),
             redis: RedisConfig::default(),
             oplog: OplogConfig::default(),
             suspend: SuspendConfig::default(),
             scheduler: SchedulerConfig::default(),
+            topics: TopicsConfig::default(),
             invocation_keys: InvocationKeysConfig::default(),
             active_workers: ActiveWorkersConfig::default(),
             public_worker_api: WorkerServiceGrpcConfig::default(),
             enable_tracing_console: false,
             enable_json_log: false,
+            otlp: OtlpConfig::default(),
+            metrics: MetricsConfig::default(),
+            egress: EgressConfig::default(),
             grpc_address: "0.0.0.0".to_string(),
             port: 9000,
             http_address: "0.0.0.0".to_string(),
@@ -328,6 +474,7 @@ impl Default for Limits {
             fuel_to_borrow: 10000,
             epoch_interval: Duration::from_millis(10),
             epoch_ticks: 1,
+            max_broadcast_concurrency: 64,
         }
     }
 }
@@ -393,6 +540,14 @@ impl Default for BlobStoreServiceS3Config {
     }
 }
 
+impl Default for BlobStoreConfig {
+    fn default() -> Self {
+        Self {
+            part_size: 8 * 1024 * 1024,
+        }
+    }
+}
+
 impl Default for ShardManagerServiceConfig {
     fn default() -> Self {
         Self::Grpc(ShardManagerServiceGrpcConfig::default())
@@ -415,24 +570,47 @@ impl Default for KeyValueServiceConfig {
     }
 }
 
+impl Default for KeyValueConfig {
+    fn default() -> Self {
+        Self {
+            default_ttl: None,
+            buckets: HashMap::new(),
+            keys_page_size: 1000,
+        }
+    }
+}
+
 impl Default for PromisesConfig {
     fn default() -> Self {
         Self::Redis
     }
 }
 
+impl Default for MailboxesConfig {
+    fn default() -> Self {
+        Self::Redis
+    }
+}
+
 impl Default for WorkersServiceConfig {
     fn default() -> Self {
         Self::Redis
     }
 }
 
+impl Default for WorkerEventLogConfig {
+    fn default() -> Self {
+        Self::Disabled
+    }
+}
+
 impl Default for OplogConfig {
     fn default() -> Self {
         Self {
             debug_enabled: false,
             operations_to_load: 128,
             max_operations_before_commit: 128,
+            http_body_blob_threshold: Some(1024 * 1024),
         }
     }
 }
@@ -462,6 +640,15 @@ impl Default for SchedulerConfig {
     }
 }
 
+impl Default for TopicsConfig {
+    fn default() -> Self {
+        Self {
+            delivery_interval: Duration::from_secs(1),
+            max_messages_per_delivery: 100,
+        }
+    }
+}
+
 impl Default for InvocationKeysConfig {
     fn default() -> Self {
         Self {
@@ -20,7 +20,7 @@ use std::sync::{Arc, RwLock};
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
 use tokio::sync::Mutex;
 use tokio::task::JoinHandle;
-use tracing::{debug, warn};
+use tracing::{debug, info_span, warn, Instrument, Span};
 use wasmtime::Store;
 
 use crate::error::GolemError;
@@ -61,6 +61,8 @@ pub struct InvocationQueue<Ctx: WorkerCtx> {
     pending_updates: Arc<RwLock<VecDeque<TimestampedUpdateDescription>>>,
     running: Arc<Mutex<Option<RunningInvocationQueue<Ctx>>>>,
     invocation_results: Arc<RwLock<HashMap<IdempotencyKey, InvocationResult>>>,
+    invocation_spans: Arc<RwLock<HashMap<IdempotencyKey, Span>>>,
+    invocation_call_chains: Arc<RwLock<HashMap<IdempotencyKey, Vec<WorkerId>>>>,
     events: Arc<Events>,
 }
 
@@ -100,6 +102,8 @@ impl<Ctx: WorkerCtx> InvocationQueue<Ctx> {
             pending_updates,
             running,
             invocation_results,
+            invocation_spans: Arc::new(RwLock::new(HashMap::new())),
+            invocation_call_chains: Arc::new(RwLock::new(HashMap::new())),
             events,
         }
     }
@@ -110,6 +114,7 @@ impl<Ctx: WorkerCtx> InvocationQueue<Ctx> {
         *running = Some(RunningInvocationQueue::new(
             worker,
             this.queue.clone(),
+            this.invocation_spans.clone(),
             Arc::downgrade(&this),
             this.worker_activator.clone(),
         ));
@@ -129,13 +134,47 @@ impl<Ctx: WorkerCtx> InvocationQueue<Ctx> {
     }
 
     /// Enqueue invocation of an exported function
+    ///
+    /// `call_chain` lists the workers waiting for the result of this invocation through RPC,
+    /// and is empty if it was not invoked by another worker.
     pub async fn enqueue(
         &self,
         idempotency_key: IdempotencyKey,
         full_function_name: String,
         function_input: Vec<Value>,
         calling_convention: CallingConvention,
+        call_chain: Vec<WorkerId>,
     ) {
+        // The invocation is performed by the queue's own task, so the span and the call chain of
+        // the caller are kept for it. A duplicate of an invocation which is already pending or
+        // completed never runs on its own, so nothing is kept for it.
+        let is_known = self
+            .invocation_results
+            .read()
+            .unwrap()
+            .contains_key(&idempotency_key)
+            || self
+                .queue
+                .read()
+                .unwrap()
+                .iter()
+                .any(|entry| entry.invocation.is_idempotency_key(&idempotency_key));
+        let call_chain = if is_known {
+            Vec::new()
+        } else {
+            self.invocation_spans
+                .write()
+                .unwrap()
+                .insert(idempotency_key.clone(), Span::current());
+            call_chain
+        };
+        if !call_chain.is_empty() {
+            self.invocation_call_chains
+                .write()
+                .unwrap()
+                .insert(idempotency_key.clone(), call_chain.clone());
+        }
+
         match self.running.lock().await.as_ref() {
             Some(running) => {
                 running
@@ -144,6 +183,7 @@ impl<Ctx: WorkerCtx> InvocationQueue<Ctx> {
                         full_function_name,
                         function_input,
                         calling_convention,
+                        call_chain,
                     )
                     .await;
             }
@@ -152,6 +192,14 @@ impl<Ctx: WorkerCtx> InvocationQueue<Ctx> {
                     "Worker {} is initializing, persisting pending invocation",
                     self.worker_id
                 );
+                if !call_chain.is_empty() {
+                    self.oplog
+                        .add(OplogEntry::invocation_call_chain(
+                            idempotency_key.clone(),
+                            call_chain,
+                        ))
+                        .await;
+                }
                 let invocation = WorkerInvocation::ExportedFunction {
                     idempotency_key,
                     full_function_name,
@@ -252,12 +300,27 @@ impl<Ctx: WorkerCtx> InvocationQueue<Ctx> {
         )
     }
 
+    /// Gets the workers waiting for the given invocation through RPC
+    ///
+    /// Only covers the invocations enqueued since the worker was loaded. The call chains of
+    /// earlier ones are stored in the oplog and restored during replay.
+    pub fn call_chain(&self, key: &IdempotencyKey) -> Vec<WorkerId> {
+        self.invocation_call_chains
+            .read()
+            .unwrap()
+            .get(key)
+            .cloned()
+            .unwrap_or_default()
+    }
+
     pub async fn store_invocation_success(
         &self,
         key: &IdempotencyKey,
         result: Vec<Value>,
         oplog_index: OplogIndex,
     ) {
+        self.invocation_spans.write().unwrap().remove(key);
+        self.invocation_call_chains.write().unwrap().remove(key);
         let mut map = self.invocation_results.write().unwrap();
         map.insert(
             key.clone(),
@@ -280,6 +343,8 @@ impl<Ctx: WorkerCtx> InvocationQueue<Ctx> {
         trap_type: &TrapType,
         oplog_index: OplogIndex,
     ) {
+        self.invocation_spans.write().unwrap().remove(key);
+        self.invocation_call_chains.write().unwrap().remove(key);
         let mut map = self.invocation_results.write().unwrap();
         map.insert(
             key.clone(),
@@ -377,6 +442,7 @@ impl<Ctx: WorkerCtx> RunningInvocationQueue<Ctx> {
     pub fn new(
         worker: Arc<Worker<Ctx>>,
         queue: Arc<RwLock<VecDeque<TimestampedWorkerInvocation>>>,
+        invocation_spans: Arc<RwLock<HashMap<IdempotencyKey, Span>>>,
         parent: Weak<InvocationQueue<Ctx>>,
         worker_activator: Arc<dyn WorkerActivator + Send + Sync>,
     ) -> Self {
@@ -396,6 +462,7 @@ impl<Ctx: WorkerCtx> RunningInvocationQueue<Ctx> {
             RunningInvocationQueue::invocation_loop(
                 receiver,
                 active_clone,
+                invocation_spans,
                 worker_clone,
                 worker_id,
                 parent,
@@ -418,22 +485,37 @@ impl<Ctx: WorkerCtx> RunningInvocationQueue<Ctx> {
         full_function_name: String,
         function_input: Vec<Value>,
         calling_convention: CallingConvention,
+        call_chain: Vec<WorkerId>,
     ) {
+        let call_chain_entry = if call_chain.is_empty() {
+            None
+        } else {
+            Some(OplogEntry::invocation_call_chain(
+                idempotency_key.clone(),
+                call_chain,
+            ))
+        };
         let invocation = WorkerInvocation::ExportedFunction {
             idempotency_key,
             full_function_name,
             function_input,
             calling_convention,
         };
-        self.enqueue_worker_invocation(invocation).await;
+        self.enqueue_worker_invocation(invocation, call_chain_entry)
+            .await;
     }
 
     pub async fn enqueue_manual_update(&self, target_version: ComponentVersion) {
         let invocation = WorkerInvocation::ManualUpdate { target_version };
-        self.enqueue_worker_invocation(invocation).await;
+        self.enqueue_worker_invocation(invocation, None).await;
     }
 
-    async fn enqueue_worker_invocation(&self, invocation: WorkerInvocation) {
+    /// `call_chain_entry` is written before the pending invocation, if that gets persisted
+    async fn enqueue_worker_invocation(
+        &self,
+        invocation: WorkerInvocation,
+        call_chain_entry: Option<OplogEntry>,
+    ) {
         let entry = OplogEntry::pending_worker_invocation(invocation.clone());
         let timestamped_invocation = TimestampedWorkerInvocation {
             timestamp: entry.timestamp(),
@@ -446,7 +528,11 @@ impl<Ctx: WorkerCtx> RunningInvocationQueue<Ctx> {
                     worker.metadata.worker_id
                 );
                 // The worker is currently busy, so we write the pending worker invocation to the oplog
-                worker.public_state.oplog().add_and_commit(entry).await;
+                let oplog = worker.public_state.oplog();
+                if let Some(call_chain_entry) = call_chain_entry {
+                    oplog.add(call_chain_entry).await;
+                }
+                oplog.add_and_commit(entry).await;
             }
         }
         self.queue
@@ -459,6 +545,7 @@ impl<Ctx: WorkerCtx> RunningInvocationQueue<Ctx> {
     async fn invocation_loop(
         mut receiver: UnboundedReceiver<()>,
         active: Arc<RwLock<VecDeque<TimestampedWorkerInvocation>>>,
+        invocation_spans: Arc<RwLock<HashMap<IdempotencyKey, Span>>>,
         worker: Weak<Worker<Ctx>>,
         worker_id: WorkerId,
         parent: Weak<InvocationQueue<Ctx>>,
@@ -472,6 +559,12 @@ impl<Ctx: WorkerCtx> RunningInvocationQueue<Ctx> {
                 .unwrap()
                 .pop_front()
                 .expect("Message should be present");
+            let caller_span = match &message.invocation {
+                WorkerInvocation::ExportedFunction {
+                    idempotency_key, ..
+                } => invocation_spans.write().unwrap().remove(idempotency_key),
+                WorkerInvocation::ManualUpdate { .. } => None,
+            };
             if let Some(worker) = worker.upgrade() {
                 debug!("Invocation queue processing {message:?} for {worker_id}");
 
@@ -487,6 +580,13 @@ impl<Ctx: WorkerCtx> RunningInvocationQueue<Ctx> {
                         function_input,
                         calling_convention,
                     } => {
+                        let span = info_span!(
+                            parent: caller_span.as_ref().and_then(Span::id),
+                            "invocation",
+                            worker_id = %worker_id,
+                            function = %full_function_name
+                        );
+
                         store
                             .data_mut()
                             .set_current_idempotency_key(invocation_key)
@@ -515,6 +615,7 @@ impl<Ctx: WorkerCtx> RunningInvocationQueue<Ctx> {
                             calling_convention,
                             true, // Invocation queue is always initialized _after_ the worker recovery
                         )
+                        .instrument(span)
                         .await;
                     }
                     WorkerInvocation::ManualUpdate { target_version } => {
@@ -12,18 +12,30 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::hash_map::Entry;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
 use std::sync::{Arc, RwLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use async_trait::async_trait;
+use bincode::{Decode, Encode};
 use bytes::Bytes;
+use golem_common::clock::{Clock, SystemClock};
 use golem_common::model::AccountId;
-use golem_common::redis::RedisPool;
+use golem_common::redis::{RedisError, RedisPool, RedisTransaction};
+use sqlx::postgres::{PgConnectOptions, PgPoolOptions};
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+use sqlx::{Executor, Pool, Postgres, QueryBuilder, Sqlite, Transaction};
+use tracing::info;
 
-use crate::services::golem_config::KeyValueServiceConfig;
+use crate::services::golem_config::{
+    KeyValueServiceConfig, KeyValueServicePostgresConfig, KeyValueServiceSqliteConfig,
+};
 
 /// Service implementing a persistent key-value store
+///
+/// Values written with a TTL are no longer visible once it passed, according to the service's
+/// clock. Expiry is not recorded, so replaying workers see the recorded read results instead.
 #[async_trait]
 pub trait KeyValueService {
     async fn delete(
@@ -54,7 +66,21 @@ pub trait KeyValueService {
         key: String,
     ) -> anyhow::Result<Option<Vec<u8>>>;
 
-    async fn get_keys(&self, account_id: AccountId, bucket: String) -> anyhow::Result<Vec<String>>;
+    /// Lists the names of the buckets of an account, in alphabetical order
+    async fn get_buckets(&self, account_id: AccountId) -> anyhow::Result<Vec<String>>;
+
+    /// Lists one page of the keys of a bucket starting with `prefix`. The listing starts with
+    /// no cursor, and continues with the one returned in the previous page until it is `None`.
+    /// Pages may contain fewer than `count` keys, even if more keys follow, and a key may be
+    /// listed in more than one page.
+    async fn get_keys(
+        &self,
+        account_id: AccountId,
+        bucket: String,
+        prefix: Option<String>,
+        cursor: Option<String>,
+        count: u64,
+    ) -> anyhow::Result<KeyPage>;
 
     async fn get_many(
         &self,
@@ -69,6 +95,7 @@ pub trait KeyValueService {
         bucket: String,
         key: String,
         outgoing_value: Vec<u8>,
+        ttl: Option<Duration>,
     ) -> anyhow::Result<()>;
 
     async fn set_many(
@@ -76,30 +103,199 @@ pub trait KeyValueService {
         account_id: AccountId,
         bucket: String,
         key_values: Vec<(String, Vec<u8>)>,
+        ttl: Option<Duration>,
     ) -> anyhow::Result<()>;
 }
 
-pub fn configured(
+/// A page of the keys of a bucket, with the cursor to list the next page with
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct KeyPage {
+    pub keys: Vec<String>,
+    pub cursor: Option<String>,
+}
+
+pub async fn configured(
     config: &KeyValueServiceConfig,
     redis_pool: RedisPool,
+    clock: Arc<dyn Clock + Send + Sync>,
 ) -> Arc<dyn KeyValueService + Send + Sync> {
     match config {
-        KeyValueServiceConfig::InMemory => Arc::new(KeyValueServiceInMemory::new()),
-        KeyValueServiceConfig::Redis => Arc::new(KeyValueServiceRedis::new(redis_pool.clone())),
+        KeyValueServiceConfig::InMemory => Arc::new(KeyValueServiceInMemory::new(clock)),
+        KeyValueServiceConfig::Redis => {
+            Arc::new(KeyValueServiceRedis::new(redis_pool.clone(), clock))
+        }
+        KeyValueServiceConfig::Sqlite(config) => Arc::new(
+            KeyValueServiceSqlite::configured(config, clock)
+                .await
+                .expect("Failed to initialize the SQLite key-value store"),
+        ),
+        KeyValueServiceConfig::Postgres(config) => Arc::new(
+            KeyValueServicePostgres::configured(config, clock)
+                .await
+                .expect("Failed to initialize the PostgreSQL key-value store"),
+        ),
+    }
+}
+
+/// Milliseconds since the epoch at which a value written now with the given TTL expires
+fn expires_at(clock: &(dyn Clock + Send + Sync), ttl: Option<Duration>) -> Option<i64> {
+    ttl.map(|ttl| millis(clock.now() + ttl))
+}
+
+/// Escapes the characters with a special meaning in Redis `MATCH` patterns
+fn escape_glob(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
     }
+    escaped
 }
 
-#[derive(Clone, Debug)]
+fn millis(time: SystemTime) -> i64 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as i64
+}
+
+/// Key-value store keeping each bucket in a Redis hash. As Redis cannot expire individual hash
+/// fields, the expiry times are kept in a sorted set next to the hash, and the expired fields
+/// are removed before reading the bucket.
+#[derive(Clone)]
 pub struct KeyValueServiceRedis {
     redis: RedisPool,
+    clock: Arc<dyn Clock + Send + Sync>,
 }
 
 impl KeyValueServiceRedis {
-    pub fn new(redis: RedisPool) -> Self {
-        Self { redis }
+    pub fn new(redis: RedisPool, clock: Arc<dyn Clock + Send + Sync>) -> Self {
+        Self { redis, clock }
+    }
+
+    fn bucket_key(account_id: &AccountId, bucket: &str) -> String {
+        format!("instance:keyvalue:{}:{}", account_id, bucket)
+    }
+
+    fn expiry_key(account_id: &AccountId, bucket: &str) -> String {
+        format!("instance:keyvalue-expiry:{}:{}", account_id, bucket)
+    }
+
+    /// Removes the expired keys of a bucket. Runs as a script so that a key which gets set
+    /// again concurrently is never removed together with its new value.
+    async fn remove_expired(&self, account_id: &AccountId, bucket: &str) -> anyhow::Result<()> {
+        let now = millis(self.clock.now());
+        let _: i64 = self
+            .redis
+            .with("key_value", "remove_expired")
+            .eval(
+                REMOVE_EXPIRED_SCRIPT,
+                vec![
+                    Self::bucket_key(account_id, bucket),
+                    Self::expiry_key(account_id, bucket),
+                ],
+                vec![now.to_string()],
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Adds the commands updating the expiry times of the given keys to a transaction
+    async fn update_expiry(
+        &self,
+        trx: &RedisTransaction,
+        account_id: &AccountId,
+        bucket: &str,
+        keys: Vec<String>,
+        ttl: Option<Duration>,
+    ) -> Result<(), RedisError> {
+        if keys.is_empty() {
+            return Ok(());
+        }
+
+        let expiry_key = Self::expiry_key(account_id, bucket);
+        match expires_at(self.clock.as_ref(), ttl) {
+            Some(expires_at) => {
+                let scores: Vec<(f64, String)> = keys
+                    .into_iter()
+                    .map(|key| (expires_at as f64, key))
+                    .collect();
+                trx.zadd(expiry_key, None, None, false, false, scores).await
+            }
+            None => trx.zrem(expiry_key, keys).await,
+        }
+    }
+
+    async fn delete_keys(
+        &self,
+        api_name: &'static str,
+        account_id: &AccountId,
+        bucket: &str,
+        keys: Vec<String>,
+    ) -> anyhow::Result<()> {
+        if keys.is_empty() {
+            return Ok(());
+        }
+
+        let _: () = self
+            .redis
+            .with("key_value", api_name)
+            .transaction(|trx| async move {
+                trx.hdel(Self::bucket_key(account_id, bucket), keys.clone())
+                    .await?;
+                self.update_expiry(&trx, account_id, bucket, keys, None)
+                    .await?;
+                Ok(trx)
+            })
+            .await?;
+        Ok(())
+    }
+
+    async fn set_keys(
+        &self,
+        api_name: &'static str,
+        account_id: &AccountId,
+        bucket: &str,
+        key_values: Vec<(String, Vec<u8>)>,
+        ttl: Option<Duration>,
+    ) -> anyhow::Result<()> {
+        if key_values.is_empty() {
+            return Ok(());
+        }
+
+        let keys: Vec<String> = key_values.iter().map(|(key, _)| key.clone()).collect();
+        let key_values: Vec<(String, Bytes)> = key_values
+            .into_iter()
+            .map(|(key, value)| (key, Bytes::from(value)))
+            .collect();
+        let _: () = self
+            .redis
+            .with("key_value", api_name)
+            .transaction(|trx| async move {
+                trx.hset(Self::bucket_key(account_id, bucket), key_values)
+                    .await?;
+                self.update_expiry(&trx, account_id, bucket, keys, ttl)
+                    .await?;
+                Ok(trx)
+            })
+            .await?;
+        Ok(())
     }
 }
 
+/// Removes the fields of the bucket hash (`KEYS[1]`) whose expiry time in the expiry sorted set
+/// (`KEYS[2]`) is not after `ARGV[1]`, in batches to stay below the Lua stack limit
+const REMOVE_EXPIRED_SCRIPT: &str = r#"
+local expired = redis.call('ZRANGEBYSCORE', KEYS[2], 0, ARGV[1])
+for i = 1, #expired, 1000 do
+    local batch = {unpack(expired, i, math.min(i + 999, #expired))}
+    redis.call('HDEL', KEYS[1], unpack(batch))
+    redis.call('ZREM', KEYS[2], unpack(batch))
+end
+return #expired
+"#;
+
 #[async_trait]
 impl KeyValueService for KeyValueServiceRedis {
     async fn delete(
@@ -108,12 +304,8 @@ impl KeyValueService for KeyValueServiceRedis {
         bucket: String,
         key: String,
     ) -> anyhow::Result<()> {
-        let bucket = format!("instance:keyvalue:{}:{}", account_id, bucket);
-        self.redis
-            .with("key_value", "delete")
-            .hdel(bucket, key)
-            .await?;
-        Ok(())
+        self.delete_keys("delete", &account_id, &bucket, vec![key])
+            .await
     }
 
     async fn delete_many(
@@ -122,12 +314,8 @@ impl KeyValueService for KeyValueServiceRedis {
         bucket: String,
         keys: Vec<String>,
     ) -> anyhow::Result<()> {
-        let bucket = format!("instance:keyvalue:{}:{}", account_id, bucket);
-        self.redis
-            .with("key_value", "delete_many")
-            .hdel(bucket, keys)
-            .await?;
-        Ok(())
+        self.delete_keys("delete_many", &account_id, &bucket, keys)
+            .await
     }
 
     async fn exists(
@@ -136,7 +324,8 @@ impl KeyValueService for KeyValueServiceRedis {
         bucket: String,
         key: String,
     ) -> anyhow::Result<bool> {
-        let bucket = format!("instance:keyvalue:{}:{}", account_id, bucket);
+        self.remove_expired(&account_id, &bucket).await?;
+        let bucket = Self::bucket_key(&account_id, &bucket);
         let exists: bool = self
             .redis
             .with("key_value", "exists")
@@ -151,7 +340,8 @@ impl KeyValueService for KeyValueServiceRedis {
         bucket: String,
         key: String,
     ) -> anyhow::Result<Option<Vec<u8>>> {
-        let bucket = format!("instance:keyvalue:{}:{}", account_id, bucket);
+        self.remove_expired(&account_id, &bucket).await?;
+        let bucket = Self::bucket_key(&account_id, &bucket);
         let incoming_value: Option<Vec<u8>> = self
             .redis
             .with("key_value", "get")
@@ -160,14 +350,59 @@ impl KeyValueService for KeyValueServiceRedis {
         Ok(incoming_value)
     }
 
-    async fn get_keys(&self, account_id: AccountId, bucket: String) -> anyhow::Result<Vec<String>> {
-        let bucket = format!("instance:keyvalue:{}:{}", account_id, bucket);
-        let keys: Vec<String> = self
+    async fn get_buckets(&self, account_id: AccountId) -> anyhow::Result<Vec<String>> {
+        let prefix = Self::bucket_key(&account_id, "");
+        let pattern = format!("{}*", escape_glob(&prefix));
+        let mut buckets = Vec::new();
+        let mut cursor = 0;
+        loop {
+            let (next_cursor, keys) = self
+                .redis
+                .with("key_value", "get_buckets")
+                .scan(&pattern, cursor, 1000)
+                .await?;
+            buckets.extend(
+                keys.into_iter()
+                    .filter_map(|key| key.strip_prefix(&prefix).map(|bucket| bucket.to_string())),
+            );
+            if next_cursor == 0 {
+                break;
+            }
+            cursor = next_cursor;
+        }
+        // SCAN may return the same key multiple times
+        buckets.sort();
+        buckets.dedup();
+        Ok(buckets)
+    }
+
+    async fn get_keys(
+        &self,
+        account_id: AccountId,
+        bucket: String,
+        prefix: Option<String>,
+        cursor: Option<String>,
+        count: u64,
+    ) -> anyhow::Result<KeyPage> {
+        self.remove_expired(&account_id, &bucket).await?;
+        let bucket = Self::bucket_key(&account_id, &bucket);
+        let pattern = format!("{}*", escape_glob(prefix.as_deref().unwrap_or_default()));
+        let cursor = match cursor {
+            Some(cursor) => cursor.parse::<u64>()?,
+            None => 0,
+        };
+        let (next_cursor, mut keys): (u64, Vec<String>) = self
             .redis
             .with("key_value", "get_keys")
-            .hkeys(bucket)
+            .hscan(bucket, pattern, cursor, count)
             .await?;
-        Ok(keys)
+        // HSCAN may return the same field multiple times
+        let mut seen = HashSet::new();
+        keys.retain(|key| seen.insert(key.clone()));
+        Ok(KeyPage {
+            keys,
+            cursor: (next_cursor != 0).then(|| next_cursor.to_string()),
+        })
     }
 
     async fn get_many(
@@ -176,7 +411,8 @@ impl KeyValueService for KeyValueServiceRedis {
         bucket: String,
         keys: Vec<String>,
     ) -> anyhow::Result<Vec<Option<Vec<u8>>>> {
-        let bucket = format!("instance:keyvalue:{}:{}", account_id, bucket);
+        self.remove_expired(&account_id, &bucket).await?;
+        let bucket = Self::bucket_key(&account_id, &bucket);
         let incoming_values: Vec<Option<Vec<u8>>> = self
             .redis
             .with("key_value", "get_many")
@@ -191,12 +427,548 @@ impl KeyValueService for KeyValueServiceRedis {
         bucket: String,
         key: String,
         outgoing_value: Vec<u8>,
+        ttl: Option<Duration>,
+    ) -> anyhow::Result<()> {
+        self.set_keys(
+            "set",
+            &account_id,
+            &bucket,
+            vec![(key, outgoing_value)],
+            ttl,
+        )
+        .await
+    }
+
+    async fn set_many(
+        &self,
+        account_id: AccountId,
+        bucket: String,
+        key_values: Vec<(String, Vec<u8>)>,
+        ttl: Option<Duration>,
+    ) -> anyhow::Result<()> {
+        self.set_keys("set_many", &account_id, &bucket, key_values, ttl)
+            .await
+    }
+}
+
+/// Key-value store keeping all buckets in a single `key_values` table of a SQLite database
+#[derive(Clone)]
+pub struct KeyValueServiceSqlite {
+    pool: Pool<Sqlite>,
+    clock: Arc<dyn Clock + Send + Sync>,
+}
+
+impl KeyValueServiceSqlite {
+    pub fn new(pool: Pool<Sqlite>, clock: Arc<dyn Clock + Send + Sync>) -> Self {
+        Self { pool, clock }
+    }
+
+    fn now(&self) -> i64 {
+        millis(self.clock.now())
+    }
+
+    /// Deletes the expired entries of a bucket, so they do not accumulate in the table
+    async fn remove_expired(
+        transaction: &mut Transaction<'_, Sqlite>,
+        account_id: &AccountId,
+        bucket: &str,
+        now: i64,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            "DELETE FROM key_values WHERE account_id = ? AND bucket = ? AND expires_at <= ?",
+        )
+        .bind(account_id.to_string())
+        .bind(bucket)
+        .bind(now)
+        .execute(&mut **transaction)
+        .await?;
+        Ok(())
+    }
+
+    /// Connects to the configured database and applies the schema migrations
+    pub async fn configured(
+        config: &KeyValueServiceSqliteConfig,
+        clock: Arc<dyn Clock + Send + Sync>,
+    ) -> anyhow::Result<Self> {
+        info!("Key-value store: sqlite://{}", config.database);
+        let options = SqliteConnectOptions::new()
+            .filename(Path::new(&config.database))
+            .create_if_missing(true);
+        let pool = SqlitePoolOptions::new()
+            .max_connections(config.max_connections)
+            .connect_with(options)
+            .await?;
+        sqlx::migrate!("./db/migration/sqlite").run(&pool).await?;
+        Ok(Self::new(pool, clock))
+    }
+}
+
+#[async_trait]
+impl KeyValueService for KeyValueServiceSqlite {
+    async fn delete(
+        &self,
+        account_id: AccountId,
+        bucket: String,
+        key: String,
+    ) -> anyhow::Result<()> {
+        sqlx::query("DELETE FROM key_values WHERE account_id = ? AND bucket = ? AND key = ?")
+            .bind(account_id.to_string())
+            .bind(bucket)
+            .bind(key)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn delete_many(
+        &self,
+        account_id: AccountId,
+        bucket: String,
+        keys: Vec<String>,
+    ) -> anyhow::Result<()> {
+        let mut transaction = self.pool.begin().await?;
+        for key in keys {
+            sqlx::query("DELETE FROM key_values WHERE account_id = ? AND bucket = ? AND key = ?")
+                .bind(account_id.to_string())
+                .bind(&bucket)
+                .bind(key)
+                .execute(&mut *transaction)
+                .await?;
+        }
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    async fn exists(
+        &self,
+        account_id: AccountId,
+        bucket: String,
+        key: String,
+    ) -> anyhow::Result<bool> {
+        let row = sqlx::query(
+            "SELECT key FROM key_values WHERE account_id = ? AND bucket = ? AND key = ? \
+             AND (expires_at IS NULL OR expires_at > ?)",
+        )
+        .bind(account_id.to_string())
+        .bind(bucket)
+        .bind(key)
+        .bind(self.now())
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row.is_some())
+    }
+
+    async fn get(
+        &self,
+        account_id: AccountId,
+        bucket: String,
+        key: String,
+    ) -> anyhow::Result<Option<Vec<u8>>> {
+        let value: Option<Vec<u8>> = sqlx::query_scalar(
+            "SELECT value FROM key_values WHERE account_id = ? AND bucket = ? AND key = ? \
+             AND (expires_at IS NULL OR expires_at > ?)",
+        )
+        .bind(account_id.to_string())
+        .bind(bucket)
+        .bind(key)
+        .bind(self.now())
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(value)
+    }
+
+    async fn get_buckets(&self, account_id: AccountId) -> anyhow::Result<Vec<String>> {
+        let buckets: Vec<String> = sqlx::query_scalar(
+            "SELECT DISTINCT bucket FROM key_values WHERE account_id = ? \
+             AND (expires_at IS NULL OR expires_at > ?) ORDER BY bucket",
+        )
+        .bind(account_id.to_string())
+        .bind(self.now())
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(buckets)
+    }
+
+    async fn get_keys(
+        &self,
+        account_id: AccountId,
+        bucket: String,
+        prefix: Option<String>,
+        cursor: Option<String>,
+        count: u64,
+    ) -> anyhow::Result<KeyPage> {
+        let mut query =
+            QueryBuilder::<Sqlite>::new("SELECT key FROM key_values WHERE account_id = ");
+        query
+            .push_bind(account_id.to_string())
+            .push(" AND bucket = ")
+            .push_bind(bucket)
+            .push(" AND (expires_at IS NULL OR expires_at > ")
+            .push_bind(self.now())
+            .push(")");
+        if let Some(prefix) = prefix {
+            query
+                .push(" AND substr(key, 1, length(")
+                .push_bind(prefix.clone())
+                .push(")) = ")
+                .push_bind(prefix);
+        }
+        if let Some(cursor) = cursor {
+            query.push(" AND key > ").push_bind(cursor);
+        }
+        query.push(" ORDER BY key LIMIT ").push_bind(count as i64);
+
+        let keys: Vec<String> = query.build_query_scalar().fetch_all(&self.pool).await?;
+        let cursor = if keys.len() as u64 == count {
+            keys.last().cloned()
+        } else {
+            None
+        };
+        Ok(KeyPage { keys, cursor })
+    }
+
+    async fn get_many(
+        &self,
+        account_id: AccountId,
+        bucket: String,
+        keys: Vec<String>,
+    ) -> anyhow::Result<Vec<Option<Vec<u8>>>> {
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut query =
+            QueryBuilder::<Sqlite>::new("SELECT key, value FROM key_values WHERE account_id = ");
+        query
+            .push_bind(account_id.to_string())
+            .push(" AND bucket = ")
+            .push_bind(bucket)
+            .push(" AND (expires_at IS NULL OR expires_at > ")
+            .push_bind(self.now())
+            .push(") AND key IN (");
+        let mut separated = query.separated(", ");
+        for key in &keys {
+            separated.push_bind(key.clone());
+        }
+        separated.push_unseparated(")");
+
+        let rows: Vec<(String, Vec<u8>)> = query.build_query_as().fetch_all(&self.pool).await?;
+        let values: HashMap<String, Vec<u8>> = rows.into_iter().collect();
+        Ok(keys.iter().map(|key| values.get(key).cloned()).collect())
+    }
+
+    async fn set(
+        &self,
+        account_id: AccountId,
+        bucket: String,
+        key: String,
+        outgoing_value: Vec<u8>,
+        ttl: Option<Duration>,
+    ) -> anyhow::Result<()> {
+        let mut transaction = self.pool.begin().await?;
+        Self::remove_expired(&mut transaction, &account_id, &bucket, self.now()).await?;
+        sqlx::query(
+            "INSERT INTO key_values (account_id, bucket, key, value, expires_at) \
+             VALUES (?, ?, ?, ?, ?) ON CONFLICT (account_id, bucket, key) \
+             DO UPDATE SET value = excluded.value, expires_at = excluded.expires_at",
+        )
+        .bind(account_id.to_string())
+        .bind(&bucket)
+        .bind(key)
+        .bind(outgoing_value)
+        .bind(expires_at(self.clock.as_ref(), ttl))
+        .execute(&mut *transaction)
+        .await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    async fn set_many(
+        &self,
+        account_id: AccountId,
+        bucket: String,
+        key_values: Vec<(String, Vec<u8>)>,
+        ttl: Option<Duration>,
+    ) -> anyhow::Result<()> {
+        let expires_at = expires_at(self.clock.as_ref(), ttl);
+        let mut transaction = self.pool.begin().await?;
+        Self::remove_expired(&mut transaction, &account_id, &bucket, self.now()).await?;
+        for (key, value) in key_values {
+            sqlx::query(
+                "INSERT INTO key_values (account_id, bucket, key, value, expires_at) \
+                 VALUES (?, ?, ?, ?, ?) ON CONFLICT (account_id, bucket, key) \
+                 DO UPDATE SET value = excluded.value, expires_at = excluded.expires_at",
+            )
+            .bind(account_id.to_string())
+            .bind(&bucket)
+            .bind(key)
+            .bind(value)
+            .bind(expires_at)
+            .execute(&mut *transaction)
+            .await?;
+        }
+        transaction.commit().await?;
+        Ok(())
+    }
+}
+
+/// Quotes an identifier, such as the configured schema name, for using it in a Postgres statement
+fn quote_identifier(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+/// Key-value store keeping all buckets in a single `key_values` table of a PostgreSQL database
+#[derive(Clone)]
+pub struct KeyValueServicePostgres {
+    pool: Pool<Postgres>,
+    clock: Arc<dyn Clock + Send + Sync>,
+}
+
+impl KeyValueServicePostgres {
+    pub fn new(pool: Pool<Postgres>, clock: Arc<dyn Clock + Send + Sync>) -> Self {
+        Self { pool, clock }
+    }
+
+    fn now(&self) -> i64 {
+        millis(self.clock.now())
+    }
+
+    /// Deletes the expired entries of a bucket, so they do not accumulate in the table
+    async fn remove_expired(
+        transaction: &mut Transaction<'_, Postgres>,
+        account_id: &AccountId,
+        bucket: &str,
+        now: i64,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            "DELETE FROM key_values WHERE account_id = $1 AND bucket = $2 AND expires_at <= $3",
+        )
+        .bind(account_id.to_string())
+        .bind(bucket)
+        .bind(now)
+        .execute(&mut **transaction)
+        .await?;
+        Ok(())
+    }
+
+    /// Connects to the configured database and applies the schema migrations
+    pub async fn configured(
+        config: &KeyValueServicePostgresConfig,
+        clock: Arc<dyn Clock + Send + Sync>,
+    ) -> anyhow::Result<Self> {
+        let schema = config.schema.clone().unwrap_or("public".to_string());
+        info!(
+            "Key-value store: postgresql://{}:{}/{}?currentSchema={}",
+            config.host, config.port, config.database, schema
+        );
+        let options = PgConnectOptions::new()
+            .host(&config.host)
+            .port(config.port)
+            .database(&config.database)
+            .username(&config.username)
+            .password(&config.password);
+        let connection_schema = quote_identifier(&schema);
+        let pool = PgPoolOptions::new()
+            .max_connections(config.max_connections)
+            .after_connect(move |conn, _meta| {
+                let schema = connection_schema.clone();
+                Box::pin(async move {
+                    conn.execute(sqlx::query(&format!("SET search_path TO {schema};")))
+                        .await?;
+                    Ok(())
+                })
+            })
+            .connect_with(options)
+            .await?;
+        pool.execute(sqlx::query(&format!(
+            "CREATE SCHEMA IF NOT EXISTS {};",
+            quote_identifier(&schema)
+        )))
+        .await?;
+        sqlx::migrate!("./db/migration/postgres").run(&pool).await?;
+        Ok(Self::new(pool, clock))
+    }
+}
+
+#[async_trait]
+impl KeyValueService for KeyValueServicePostgres {
+    async fn delete(
+        &self,
+        account_id: AccountId,
+        bucket: String,
+        key: String,
+    ) -> anyhow::Result<()> {
+        sqlx::query("DELETE FROM key_values WHERE account_id = $1 AND bucket = $2 AND key = $3")
+            .bind(account_id.to_string())
+            .bind(bucket)
+            .bind(key)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn delete_many(
+        &self,
+        account_id: AccountId,
+        bucket: String,
+        keys: Vec<String>,
     ) -> anyhow::Result<()> {
-        let bucket = format!("instance:keyvalue:{}:{}", account_id, bucket);
-        self.redis
-            .with("key_value", "set")
-            .hset(bucket, (key, Bytes::from(outgoing_value)))
+        let mut transaction = self.pool.begin().await?;
+        for key in keys {
+            sqlx::query(
+                "DELETE FROM key_values WHERE account_id = $1 AND bucket = $2 AND key = $3",
+            )
+            .bind(account_id.to_string())
+            .bind(&bucket)
+            .bind(key)
+            .execute(&mut *transaction)
             .await?;
+        }
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    async fn exists(
+        &self,
+        account_id: AccountId,
+        bucket: String,
+        key: String,
+    ) -> anyhow::Result<bool> {
+        let row = sqlx::query(
+            "SELECT key FROM key_values WHERE account_id = $1 AND bucket = $2 AND key = $3 \
+             AND (expires_at IS NULL OR expires_at > $4)",
+        )
+        .bind(account_id.to_string())
+        .bind(bucket)
+        .bind(key)
+        .bind(self.now())
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row.is_some())
+    }
+
+    async fn get(
+        &self,
+        account_id: AccountId,
+        bucket: String,
+        key: String,
+    ) -> anyhow::Result<Option<Vec<u8>>> {
+        let value: Option<Vec<u8>> = sqlx::query_scalar(
+            "SELECT value FROM key_values WHERE account_id = $1 AND bucket = $2 AND key = $3 \
+             AND (expires_at IS NULL OR expires_at > $4)",
+        )
+        .bind(account_id.to_string())
+        .bind(bucket)
+        .bind(key)
+        .bind(self.now())
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(value)
+    }
+
+    async fn get_buckets(&self, account_id: AccountId) -> anyhow::Result<Vec<String>> {
+        let buckets: Vec<String> = sqlx::query_scalar(
+            "SELECT DISTINCT bucket FROM key_values WHERE account_id = $1 \
+             AND (expires_at IS NULL OR expires_at > $2) ORDER BY bucket",
+        )
+        .bind(account_id.to_string())
+        .bind(self.now())
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(buckets)
+    }
+
+    async fn get_keys(
+        &self,
+        account_id: AccountId,
+        bucket: String,
+        prefix: Option<String>,
+        cursor: Option<String>,
+        count: u64,
+    ) -> anyhow::Result<KeyPage> {
+        let mut query =
+            QueryBuilder::<Postgres>::new("SELECT key FROM key_values WHERE account_id = ");
+        query
+            .push_bind(account_id.to_string())
+            .push(" AND bucket = ")
+            .push_bind(bucket)
+            .push(" AND (expires_at IS NULL OR expires_at > ")
+            .push_bind(self.now())
+            .push(")");
+        if let Some(prefix) = prefix {
+            query
+                .push(" AND substr(key, 1, length(")
+                .push_bind(prefix.clone())
+                .push(")) = ")
+                .push_bind(prefix);
+        }
+        if let Some(cursor) = cursor {
+            query.push(" AND key > ").push_bind(cursor);
+        }
+        query.push(" ORDER BY key LIMIT ").push_bind(count as i64);
+
+        let keys: Vec<String> = query.build_query_scalar().fetch_all(&self.pool).await?;
+        let cursor = if keys.len() as u64 == count {
+            keys.last().cloned()
+        } else {
+            None
+        };
+        Ok(KeyPage { keys, cursor })
+    }
+
+    async fn get_many(
+        &self,
+        account_id: AccountId,
+        bucket: String,
+        keys: Vec<String>,
+    ) -> anyhow::Result<Vec<Option<Vec<u8>>>> {
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut query =
+            QueryBuilder::<Postgres>::new("SELECT key, value FROM key_values WHERE account_id = ");
+        query
+            .push_bind(account_id.to_string())
+            .push(" AND bucket = ")
+            .push_bind(bucket)
+            .push(" AND (expires_at IS NULL OR expires_at > ")
+            .push_bind(self.now())
+            .push(") AND key IN (");
+        let mut separated = query.separated(", ");
+        for key in &keys {
+            separated.push_bind(key.clone());
+        }
+        separated.push_unseparated(")");
+
+        let rows: Vec<(String, Vec<u8>)> = query.build_query_as().fetch_all(&self.pool).await?;
+        let values: HashMap<String, Vec<u8>> = rows.into_iter().collect();
+        Ok(keys.iter().map(|key| values.get(key).cloned()).collect())
+    }
+
+    async fn set(
+        &self,
+        account_id: AccountId,
+        bucket: String,
+        key: String,
+        outgoing_value: Vec<u8>,
+        ttl: Option<Duration>,
+    ) -> anyhow::Result<()> {
+        let mut transaction = self.pool.begin().await?;
+        Self::remove_expired(&mut transaction, &account_id, &bucket, self.now()).await?;
+        sqlx::query(
+            "INSERT INTO key_values (account_id, bucket, key, value, expires_at) \
+             VALUES ($1, $2, $3, $4, $5) ON CONFLICT (account_id, bucket, key) \
+             DO UPDATE SET value = excluded.value, expires_at = excluded.expires_at",
+        )
+        .bind(account_id.to_string())
+        .bind(&bucket)
+        .bind(key)
+        .bind(outgoing_value)
+        .bind(expires_at(self.clock.as_ref(), ttl))
+        .execute(&mut *transaction)
+        .await?;
+        transaction.commit().await?;
         Ok(())
     }
 
@@ -205,37 +977,59 @@ impl KeyValueService for KeyValueServiceRedis {
         account_id: AccountId,
         bucket: String,
         key_values: Vec<(String, Vec<u8>)>,
+        ttl: Option<Duration>,
     ) -> anyhow::Result<()> {
-        let bucket = format!("instance:keyvalue:{}:{}", account_id, bucket);
-        let key_values: Vec<(String, Bytes)> = key_values
-            .into_iter()
-            .map(|(key, value)| (key, Bytes::from(value)))
-            .collect();
-        self.redis
-            .with("key_value", "set_many")
-            .hmset(bucket, key_values)
+        let expires_at = expires_at(self.clock.as_ref(), ttl);
+        let mut transaction = self.pool.begin().await?;
+        Self::remove_expired(&mut transaction, &account_id, &bucket, self.now()).await?;
+        for (key, value) in key_values {
+            sqlx::query(
+                "INSERT INTO key_values (account_id, bucket, key, value, expires_at) \
+                 VALUES ($1, $2, $3, $4, $5) ON CONFLICT (account_id, bucket, key) \
+                 DO UPDATE SET value = excluded.value, expires_at = excluded.expires_at",
+            )
+            .bind(account_id.to_string())
+            .bind(&bucket)
+            .bind(key)
+            .bind(value)
+            .bind(expires_at)
+            .execute(&mut *transaction)
             .await?;
+        }
+        transaction.commit().await?;
         Ok(())
     }
 }
 
-type Bucket = HashMap<String, Vec<u8>>;
+/// A stored value and the time it expires at, in milliseconds since the epoch
+type Entry = (Vec<u8>, Option<i64>);
+type Bucket = HashMap<String, Entry>;
 type Buckets = HashMap<String, Bucket>;
 
 pub struct KeyValueServiceInMemory {
     buckets: Arc<RwLock<Buckets>>,
+    clock: Arc<dyn Clock + Send + Sync>,
 }
 
 impl Default for KeyValueServiceInMemory {
     fn default() -> Self {
-        Self::new()
+        Self::new(Arc::new(SystemClock::new()))
     }
 }
 
 impl KeyValueServiceInMemory {
-    pub fn new() -> Self {
+    pub fn new(clock: Arc<dyn Clock + Send + Sync>) -> Self {
         Self {
             buckets: Arc::new(RwLock::new(Buckets::new())),
+            clock,
+        }
+    }
+
+    fn live_value(&self, entry: &Entry) -> Option<Vec<u8>> {
+        let (value, expires_at) = entry;
+        match expires_at {
+            Some(expires_at) if *expires_at <= millis(self.clock.now()) => None,
+            _ => Some(value.clone()),
         }
     }
 }
@@ -250,13 +1044,10 @@ impl KeyValueService for KeyValueServiceInMemory {
     ) -> anyhow::Result<()> {
         let mut buckets = self.buckets.write().unwrap();
         if let Some(bucket) = buckets.get_mut(&bucket) {
-            match bucket.entry(key) {
-                Entry::Occupied(entry) => entry.remove(),
-                Entry::Vacant(_) => {
-                    anyhow::bail!("Key does not exist");
-                }
-            };
-            Ok(())
+            match bucket.remove(&key) {
+                Some(_) => Ok(()),
+                None => anyhow::bail!("Key does not exist"),
+            }
         } else {
             anyhow::bail!("Container does not exist");
         }
@@ -282,7 +1073,10 @@ impl KeyValueService for KeyValueServiceInMemory {
     ) -> anyhow::Result<bool> {
         let buckets = self.buckets.read().unwrap();
         if let Some(bucket) = buckets.get(&bucket) {
-            Ok(bucket.contains_key(&key))
+            Ok(bucket
+                .get(&key)
+                .and_then(|entry| self.live_value(entry))
+                .is_some())
         } else {
             anyhow::bail!("Container does not exist");
         }
@@ -296,20 +1090,48 @@ impl KeyValueService for KeyValueServiceInMemory {
     ) -> anyhow::Result<Option<Vec<u8>>> {
         let buckets = self.buckets.read().unwrap();
         if let Some(bucket) = buckets.get(&bucket) {
-            Ok(bucket.get(&key).cloned())
+            Ok(bucket.get(&key).and_then(|entry| self.live_value(entry)))
         } else {
             anyhow::bail!("Container does not exist");
         }
     }
 
+    async fn get_buckets(&self, _account_id: AccountId) -> anyhow::Result<Vec<String>> {
+        let buckets = self.buckets.read().unwrap();
+        let mut names: Vec<String> = buckets.keys().cloned().collect();
+        names.sort();
+        Ok(names)
+    }
+
     async fn get_keys(
         &self,
         _account_id: AccountId,
         bucket: String,
-    ) -> anyhow::Result<Vec<String>> {
+        prefix: Option<String>,
+        cursor: Option<String>,
+        count: u64,
+    ) -> anyhow::Result<KeyPage> {
         let buckets = self.buckets.read().unwrap();
         if let Some(bucket) = buckets.get(&bucket) {
-            Ok(bucket.keys().cloned().collect())
+            let mut keys: Vec<String> = bucket
+                .iter()
+                .filter(|(key, entry)| {
+                    self.live_value(entry).is_some()
+                        && prefix
+                            .as_ref()
+                            .map_or(true, |prefix| key.starts_with(prefix))
+                        && cursor.as_ref().map_or(true, |cursor| *key > cursor)
+                })
+                .map(|(key, _)| key.clone())
+                .collect();
+            keys.sort();
+            let cursor = if keys.len() as u64 > count {
+                keys.truncate(count as usize);
+                keys.last().cloned()
+            } else {
+                None
+            };
+            Ok(KeyPage { keys, cursor })
         } else {
             anyhow::bail!("Container does not exist");
         }
@@ -334,10 +1156,12 @@ impl KeyValueService for KeyValueServiceInMemory {
         bucket: String,
         key: String,
         outgoing_value: Vec<u8>,
+        ttl: Option<Duration>,
     ) -> anyhow::Result<()> {
+        let expires_at = expires_at(self.clock.as_ref(), ttl);
         let mut buckets = self.buckets.write().unwrap();
         if let Some(bucket) = buckets.get_mut(&bucket) {
-            bucket.insert(key, outgoing_value);
+            bucket.insert(key, (outgoing_value, expires_at));
             Ok(())
         } else {
             anyhow::bail!("Container does not exist");
@@ -349,9 +1173,10 @@ impl KeyValueService for KeyValueServiceInMemory {
         _account_id: AccountId,
         bucket: String,
         key_values: Vec<(String, Vec<u8>)>,
+        ttl: Option<Duration>,
     ) -> anyhow::Result<()> {
         for (key, value) in key_values {
-            self.set(_account_id.clone(), bucket.clone(), key, value)
+            self.set(_account_id.clone(), bucket.clone(), key, value, ttl)
                 .await?;
         }
         Ok(())
@@ -0,0 +1,412 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bincode::{Decode, Encode};
+use bytes::Bytes;
+use dashmap::DashMap;
+use fred::prelude::RedisValue;
+use fred::types::{XCapKind, XCapTrim};
+use golem_common::model::{IdempotencyKey, WorkerId};
+use golem_common::redis::RedisPool;
+
+use crate::error::GolemError;
+use crate::services::golem_config::MailboxesConfig;
+
+/// Service storing the incoming messages of the workers' mailboxes
+///
+/// Receiving a message does not remove it from the mailbox. The received message is recorded in
+/// the worker's oplog, so replaying the worker receives the same messages again, and the
+/// messages are only trimmed once that record has been committed. The remaining messages are
+/// removed together with the worker.
+///
+/// Every message is sent with an id, and sending a message with an id the mailbox has already
+/// seen does nothing, so senders can safely retry.
+#[async_trait]
+pub trait MailboxService {
+    /// Appends a message to the end of the worker's mailbox, unless a message with the same id
+    /// has already been sent to it
+    async fn send(
+        &self,
+        worker_id: &WorkerId,
+        message_id: &IdempotencyKey,
+        payload: Vec<u8>,
+    ) -> Result<(), GolemError>;
+
+    /// Returns the message following the one with the id `after`, or the first message of the
+    /// mailbox if `after` is not set. Returns `None` if there is no such message yet.
+    async fn receive_after(
+        &self,
+        worker_id: &WorkerId,
+        after: Option<String>,
+    ) -> Result<Option<MailboxMessage>, GolemError>;
+
+    /// Removes the messages up to and including the one with the id `up_to`
+    async fn trim(&self, worker_id: &WorkerId, up_to: &str) -> Result<(), GolemError>;
+
+    /// Removes every message of the worker's mailbox
+    async fn delete(&self, worker_id: &WorkerId) -> Result<(), GolemError>;
+}
+
+/// A message received from a worker's mailbox
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct MailboxMessage {
+    pub id: String,
+    pub payload: Vec<u8>,
+}
+
+pub fn configured(
+    config: &MailboxesConfig,
+    redis_pool: RedisPool,
+) -> Arc<dyn MailboxService + Send + Sync> {
+    match config {
+        MailboxesConfig::InMemory => Arc::new(MailboxServiceInMemory::new()),
+        MailboxesConfig::Redis => Arc::new(MailboxServiceRedis::new(redis_pool)),
+    }
+}
+
+/// Appends the message to the stream only if its id was not yet recorded in the set of sent
+/// message ids
+const SEND_SCRIPT: &str = r#"
+if redis.call('SADD', KEYS[1], ARGV[1]) == 1 then
+  return redis.call('XADD', KEYS[2], '*', 'payload', ARGV[2])
+end
+return false
+"#;
+
+#[derive(Clone, Debug)]
+pub struct MailboxServiceRedis {
+    redis: RedisPool,
+}
+
+impl MailboxServiceRedis {
+    pub fn new(redis: RedisPool) -> Self {
+        Self { redis }
+    }
+}
+
+#[async_trait]
+impl MailboxService for MailboxServiceRedis {
+    async fn send(
+        &self,
+        worker_id: &WorkerId,
+        message_id: &IdempotencyKey,
+        payload: Vec<u8>,
+    ) -> Result<(), GolemError> {
+        let _: Option<String> = self
+            .redis
+            .with("mailbox", "send")
+            .eval(
+                SEND_SCRIPT,
+                vec![
+                    get_sent_messages_redis_key(worker_id),
+                    get_mailbox_redis_key(worker_id),
+                ],
+                vec![
+                    RedisValue::String(message_id.value.as_str().into()),
+                    RedisValue::Bytes(Bytes::from(payload)),
+                ],
+            )
+            .await
+            .map_err(|err| {
+                GolemError::unknown(format!("failed to send message to {worker_id}: {err}"))
+            })?;
+        Ok(())
+    }
+
+    async fn receive_after(
+        &self,
+        worker_id: &WorkerId,
+        after: Option<String>,
+    ) -> Result<Option<MailboxMessage>, GolemError> {
+        let start = match after {
+            Some(after) => format!("({after}"),
+            None => "-".to_string(),
+        };
+        let results: Vec<HashMap<String, HashMap<String, Bytes>>> = self
+            .redis
+            .with("mailbox", "receive")
+            .xrange(get_mailbox_redis_key(worker_id), start, "+", Some(1))
+            .await
+            .map_err(|err| {
+                GolemError::unknown(format!("failed to read the mailbox of {worker_id}: {err}"))
+            })?;
+
+        Ok(results
+            .into_iter()
+            .flat_map(|result| result.into_iter())
+            .next()
+            .map(|(id, fields)| MailboxMessage {
+                id,
+                payload: fields
+                    .into_values()
+                    .next()
+                    .map(|payload| payload.to_vec())
+                    .unwrap_or_default(),
+            }))
+    }
+
+    async fn trim(&self, worker_id: &WorkerId, up_to: &str) -> Result<(), GolemError> {
+        // MINID keeps the entries with an id not less than the threshold
+        let threshold = next_stream_id(up_to)
+            .ok_or_else(|| GolemError::unknown(format!("invalid mailbox message id {up_to}")))?;
+        let _: u64 = self
+            .redis
+            .with("mailbox", "trim")
+            .xtrim(
+                get_mailbox_redis_key(worker_id),
+                (XCapKind::MinID, XCapTrim::Exact, threshold, None),
+            )
+            .await
+            .map_err(|err| {
+                GolemError::unknown(format!("failed to trim the mailbox of {worker_id}: {err}"))
+            })?;
+        Ok(())
+    }
+
+    async fn delete(&self, worker_id: &WorkerId) -> Result<(), GolemError> {
+        for key in [
+            get_mailbox_redis_key(worker_id),
+            get_sent_messages_redis_key(worker_id),
+        ] {
+            let _: u32 = self
+                .redis
+                .with("mailbox", "delete")
+                .del(key)
+                .await
+                .map_err(|err| {
+                    GolemError::unknown(format!(
+                        "failed to delete the mailbox of {worker_id}: {err}"
+                    ))
+                })?;
+        }
+        Ok(())
+    }
+}
+
+fn get_mailbox_redis_key(worker_id: &WorkerId) -> String {
+    format!("instance:mailbox:{}", worker_id.to_redis_key())
+}
+
+fn get_sent_messages_redis_key(worker_id: &WorkerId) -> String {
+    format!("instance:mailbox:sent:{}", worker_id.to_redis_key())
+}
+
+/// Returns the smallest Redis stream id following `id`, which has the form
+/// `<milliseconds>-<sequence>`
+fn next_stream_id(id: &str) -> Option<String> {
+    let (millis, sequence) = id.split_once('-')?;
+    let millis: u64 = millis.parse().ok()?;
+    let sequence: u64 = sequence.parse().ok()?;
+    Some(match sequence.checked_add(1) {
+        Some(sequence) => format!("{millis}-{sequence}"),
+        None => format!("{}-0", millis + 1),
+    })
+}
+
+/// Keeps the mailboxes in memory, identifying the messages by their position in the mailbox.
+/// Trimmed messages leave an empty slot behind, so the positions of the others do not change.
+#[derive(Clone, Debug, Default)]
+pub struct MailboxServiceInMemory {
+    mailboxes: Arc<DashMap<WorkerId, InMemoryMailbox>>,
+}
+
+#[derive(Debug, Default)]
+struct InMemoryMailbox {
+    messages: Vec<Option<Vec<u8>>>,
+    sent: HashSet<IdempotencyKey>,
+}
+
+impl MailboxServiceInMemory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl MailboxService for MailboxServiceInMemory {
+    async fn send(
+        &self,
+        worker_id: &WorkerId,
+        message_id: &IdempotencyKey,
+        payload: Vec<u8>,
+    ) -> Result<(), GolemError> {
+        let mut mailbox = self.mailboxes.entry(worker_id.clone()).or_default();
+        if mailbox.sent.insert(message_id.clone()) {
+            mailbox.messages.push(Some(payload));
+        }
+        Ok(())
+    }
+
+    async fn receive_after(
+        &self,
+        worker_id: &WorkerId,
+        after: Option<String>,
+    ) -> Result<Option<MailboxMessage>, GolemError> {
+        let idx = match after {
+            Some(after) => parse_position(&after)? + 1,
+            None => 0,
+        };
+        Ok(self.mailboxes.get(worker_id).and_then(|mailbox| {
+            mailbox
+                .messages
+                .iter()
+                .enumerate()
+                .skip(idx)
+                .find_map(|(idx, payload)| {
+                    payload.as_ref().map(|payload| MailboxMessage {
+                        id: idx.to_string(),
+                        payload: payload.clone(),
+                    })
+                })
+        }))
+    }
+
+    async fn trim(&self, worker_id: &WorkerId, up_to: &str) -> Result<(), GolemError> {
+        let up_to = parse_position(up_to)?;
+        if let Some(mut mailbox) = self.mailboxes.get_mut(worker_id) {
+            for payload in mailbox.messages.iter_mut().take(up_to + 1) {
+                *payload = None;
+            }
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, worker_id: &WorkerId) -> Result<(), GolemError> {
+        self.mailboxes.remove(worker_id);
+        Ok(())
+    }
+}
+
+fn parse_position(id: &str) -> Result<usize, GolemError> {
+    id.parse::<usize>()
+        .map_err(|err| GolemError::unknown(format!("invalid mailbox message id {id}: {err}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::services::mailbox::{MailboxMessage, MailboxService, MailboxServiceInMemory};
+    use golem_common::model::{ComponentId, IdempotencyKey, WorkerId};
+
+    #[tokio::test]
+    async fn in_memory_mailbox_receives_messages_in_order() {
+        let mailbox = MailboxServiceInMemory::new();
+        let worker_id = WorkerId {
+            component_id: ComponentId::new_v4(),
+            worker_name: "receiver".to_string(),
+        };
+
+        assert_eq!(mailbox.receive_after(&worker_id, None).await.unwrap(), None);
+
+        mailbox
+            .send(&worker_id, &IdempotencyKey::fresh(), vec![1])
+            .await
+            .unwrap();
+        mailbox
+            .send(&worker_id, &IdempotencyKey::fresh(), vec![2])
+            .await
+            .unwrap();
+
+        let first = mailbox.receive_after(&worker_id, None).await.unwrap();
+        assert_eq!(
+            first,
+            Some(MailboxMessage {
+                id: "0".to_string(),
+                payload: vec![1],
+            })
+        );
+        let second = mailbox
+            .receive_after(&worker_id, Some(first.unwrap().id))
+            .await
+            .unwrap();
+        assert_eq!(second.as_ref().map(|m| m.payload.clone()), Some(vec![2]));
+        assert_eq!(
+            mailbox
+                .receive_after(&worker_id, Some(second.unwrap().id))
+                .await
+                .unwrap(),
+            None
+        );
+
+        mailbox.delete(&worker_id).await.unwrap();
+        assert_eq!(mailbox.receive_after(&worker_id, None).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn in_memory_mailbox_keeps_the_ids_of_messages_after_trimming() {
+        let mailbox = MailboxServiceInMemory::new();
+        let worker_id = WorkerId {
+            component_id: ComponentId::new_v4(),
+            worker_name: "receiver".to_string(),
+        };
+
+        mailbox
+            .send(&worker_id, &IdempotencyKey::fresh(), vec![1])
+            .await
+            .unwrap();
+        mailbox
+            .send(&worker_id, &IdempotencyKey::fresh(), vec![2])
+            .await
+            .unwrap();
+        mailbox.trim(&worker_id, "0").await.unwrap();
+
+        let expected = Some(MailboxMessage {
+            id: "1".to_string(),
+            payload: vec![2],
+        });
+        assert_eq!(
+            mailbox.receive_after(&worker_id, None).await.unwrap(),
+            expected
+        );
+        assert_eq!(
+            mailbox
+                .receive_after(&worker_id, Some("0".to_string()))
+                .await
+                .unwrap(),
+            expected
+        );
+    }
+
+    #[tokio::test]
+    async fn in_memory_mailbox_appends_a_message_only_once() {
+        let mailbox = MailboxServiceInMemory::new();
+        let worker_id = WorkerId {
+            component_id: ComponentId::new_v4(),
+            worker_name: "receiver".to_string(),
+        };
+
+        let message_id = IdempotencyKey::fresh();
+        mailbox
+            .send(&worker_id, &message_id, vec![1])
+            .await
+            .unwrap();
+        mailbox
+            .send(&worker_id, &message_id, vec![1])
+            .await
+            .unwrap();
+
+        let first = mailbox.receive_after(&worker_id, None).await.unwrap();
+        assert_eq!(first.as_ref().map(|m| m.payload.clone()), Some(vec![1]));
+        assert_eq!(
+            mailbox
+                .receive_after(&worker_id, Some(first.unwrap().id))
+                .await
+                .unwrap(),
+            None
+        );
+    }
+}
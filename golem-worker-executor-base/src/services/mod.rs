@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use std::sync::Arc;
+
+use golem_common::clock::Clock;
 #[cfg(any(feature = "mocks", test))]
 use std::time::Duration;
 
@@ -31,6 +33,7 @@ pub mod events;
 pub mod golem_config;
 pub mod invocation_queue;
 pub mod key_value;
+pub mod mailbox;
 pub mod oplog;
 pub mod promise;
 pub mod recovery;
@@ -38,10 +41,12 @@ pub mod rpc;
 pub mod scheduler;
 pub mod shard;
 pub mod shard_manager;
+pub mod topic;
 pub mod worker;
 pub mod worker_activator;
 pub mod worker_enumeration;
 pub mod worker_event;
+pub mod worker_event_log;
 pub mod worker_proxy;
 
 // HasXXX traits for fine-grained control of which dependencies a function needs
@@ -116,6 +121,14 @@ pub trait HasSchedulerService {
     fn scheduler_service(&self) -> Arc<dyn scheduler::SchedulerService + Send + Sync>;
 }
 
+pub trait HasTopicService {
+    fn topic_service(&self) -> Arc<dyn topic::TopicService + Send + Sync>;
+}
+
+pub trait HasMailboxService {
+    fn mailbox_service(&self) -> Arc<dyn mailbox::MailboxService + Send + Sync>;
+}
+
 pub trait HasExtraDeps<Ctx: WorkerCtx> {
     fn extra_deps(&self) -> Ctx::ExtraDeps;
 }
@@ -140,6 +153,16 @@ pub trait HasEvents {
     fn events(&self) -> Arc<Events>;
 }
 
+pub trait HasWorkerEventLogService {
+    fn worker_event_log_service(
+        &self,
+    ) -> Arc<dyn worker_event_log::WorkerEventLogService + Send + Sync>;
+}
+
+pub trait HasClock {
+    fn clock(&self) -> Arc<dyn Clock + Send + Sync>;
+}
+
 /// HasAll is a shortcut for requiring all available service dependencies
 pub trait HasAll<Ctx: WorkerCtx>:
     HasActiveWorkers<Ctx>
@@ -156,9 +179,13 @@ pub trait HasAll<Ctx: WorkerCtx>:
     + HasRecoveryManagement
     + HasRpc
     + HasSchedulerService
+    + HasTopicService
+    + HasMailboxService
     + HasWorkerActivator
     + HasWorkerProxy
     + HasEvents
+    + HasWorkerEventLogService
+    + HasClock
     + HasExtraDeps<Ctx>
     + Clone
 {
@@ -180,9 +207,13 @@ impl<
             + HasRecoveryManagement
             + HasRpc
             + HasSchedulerService
+            + HasTopicService
+            + HasMailboxService
             + HasWorkerActivator
             + HasWorkerProxy
             + HasEvents
+            + HasWorkerEventLogService
+            + HasClock
             + HasExtraDeps<Ctx>
             + Clone,
     > HasAll<Ctx> for T
@@ -211,9 +242,13 @@ pub struct All<Ctx: WorkerCtx> {
     recovery_management: Arc<dyn recovery::RecoveryManagement + Send + Sync>,
     rpc: Arc<dyn rpc::Rpc + Send + Sync>,
     scheduler_service: Arc<dyn scheduler::SchedulerService + Send + Sync>,
+    topic_service: Arc<dyn topic::TopicService + Send + Sync>,
+    mailbox_service: Arc<dyn mailbox::MailboxService + Send + Sync>,
     worker_activator: Arc<dyn WorkerActivator + Send + Sync>,
     worker_proxy: Arc<dyn worker_proxy::WorkerProxy + Send + Sync>,
     events: Arc<Events>,
+    worker_event_log_service: Arc<dyn worker_event_log::WorkerEventLogService + Send + Sync>,
+    clock: Arc<dyn Clock + Send + Sync>,
     extra_deps: Ctx::ExtraDeps,
 }
 
@@ -238,9 +273,13 @@ impl<Ctx: WorkerCtx> Clone for All<Ctx> {
             recovery_management: self.recovery_management.clone(),
             rpc: self.rpc.clone(),
             scheduler_service: self.scheduler_service.clone(),
+            topic_service: self.topic_service.clone(),
+            mailbox_service: self.mailbox_service.clone(),
             worker_activator: self.worker_activator.clone(),
             worker_proxy: self.worker_proxy.clone(),
             events: self.events.clone(),
+            worker_event_log_service: self.worker_event_log_service.clone(),
+            clock: self.clock.clone(),
             extra_deps: self.extra_deps.clone(),
         }
     }
@@ -271,9 +310,13 @@ impl<Ctx: WorkerCtx> All<Ctx> {
         recovery_management: Arc<dyn recovery::RecoveryManagement + Send + Sync>,
         rpc: Arc<dyn rpc::Rpc + Send + Sync>,
         scheduler_service: Arc<dyn scheduler::SchedulerService + Send + Sync>,
+        topic_service: Arc<dyn topic::TopicService + Send + Sync>,
+        mailbox_service: Arc<dyn mailbox::MailboxService + Send + Sync>,
         worker_activator: Arc<dyn WorkerActivator + Send + Sync>,
         worker_proxy: Arc<dyn worker_proxy::WorkerProxy + Send + Sync>,
         events: Arc<Events>,
+        worker_event_log_service: Arc<dyn worker_event_log::WorkerEventLogService + Send + Sync>,
+        clock: Arc<dyn Clock + Send + Sync>,
         extra_deps: Ctx::ExtraDeps,
     ) -> Self {
         Self {
@@ -295,13 +338,65 @@ impl<Ctx: WorkerCtx> All<Ctx> {
             recovery_management,
             rpc,
             scheduler_service,
+            topic_service,
+            mailbox_service,
             worker_activator,
             worker_proxy,
             events,
+            worker_event_log_service,
+            clock,
             extra_deps,
         }
     }
 
+    /// Collects the dependencies of a service which does not hold an `All` itself
+    pub fn from_deps<T: HasAll<Ctx> + HasShardService + HasShardManagerService>(deps: &T) -> Self {
+        Self::new(
+            deps.active_workers(),
+            deps.engine(),
+            deps.linker(),
+            deps.runtime(),
+            deps.component_service(),
+            deps.shard_manager_service(),
+            deps.worker_service(),
+            deps.worker_enumeration_service(),
+            deps.running_worker_enumeration_service(),
+            deps.promise_service(),
+            deps.config(),
+            deps.shard_service(),
+            deps.key_value_service(),
+            deps.blob_store_service(),
+            deps.oplog_service(),
+            deps.recovery_management(),
+            deps.rpc(),
+            deps.scheduler_service(),
+            deps.topic_service(),
+            deps.mailbox_service(),
+            deps.worker_activator(),
+            deps.worker_proxy(),
+            deps.events(),
+            deps.worker_event_log_service(),
+            deps.clock(),
+            deps.extra_deps(),
+        )
+    }
+
+    /// Derives a set of dependencies for running ephemeral workers. Worker metadata, the oplog
+    /// and the event log are only kept in memory and failed invocations are never recovered.
+    /// The instances live in the regular active workers cache, so they count towards
+    /// `max_active_workers` like any other worker.
+    pub fn ephemeral(&self) -> Self {
+        Self {
+            worker_service: Arc::new(worker::WorkerServiceInMemory::new()),
+            oplog_service: Arc::new(oplog::OplogServiceInMemory::new()),
+            recovery_management: Arc::new(recovery::RecoveryManagementDisabled),
+            worker_event_log_service: Arc::new(
+                worker_event_log::WorkerEventLogServiceDisabled::new(),
+            ),
+            ..self.clone()
+        }
+    }
+
     #[cfg(any(feature = "mocks", test))]
     pub async fn mocked(mocked_extra_deps: Ctx::ExtraDeps) -> Self {
         let active_workers = Arc::new(active_workers::ActiveWorkers::bounded(
@@ -322,15 +417,20 @@ impl<Ctx: WorkerCtx> All<Ctx> {
         let golem_config = Arc::new(golem_config::GolemConfig::default());
         let shard_service = Arc::new(shard::ShardServiceDefault::new());
         let shard_manager_service = Arc::new(shard_manager::ShardManagerServiceSingleShard::new());
-        let key_value_service = Arc::new(key_value::KeyValueServiceInMemory::new());
+        let key_value_service = Arc::new(key_value::KeyValueServiceInMemory::default());
         let blob_store_service = Arc::new(blob_store::BlobStoreServiceInMemory::new());
         let oplog_service = Arc::new(oplog::OplogServiceMock::new());
         let recovery_management = Arc::new(recovery::RecoveryManagementMock::new());
         let rpc = Arc::new(rpc::RpcMock::new());
         let scheduler_service = Arc::new(scheduler::SchedulerServiceMock::new());
+        let topic_service = Arc::new(topic::TopicServiceMock::new());
+        let mailbox_service = Arc::new(mailbox::MailboxServiceInMemory::new());
         let worker_activator = Arc::new(worker_activator::WorkerActivatorMock::new());
         let worker_proxy = Arc::new(worker_proxy::WorkerProxyMock::new());
         let events = Arc::new(Events::new());
+        let worker_event_log_service =
+            Arc::new(worker_event_log::WorkerEventLogServiceDisabled::new());
+        let clock = Arc::new(golem_common::clock::SystemClock::new());
         Self {
             active_workers,
             engine,
@@ -350,9 +450,13 @@ impl<Ctx: WorkerCtx> All<Ctx> {
             recovery_management,
             rpc,
             scheduler_service,
+            topic_service,
+            mailbox_service,
             worker_activator,
             worker_proxy,
             events,
+            worker_event_log_service,
+            clock,
             extra_deps: mocked_extra_deps,
         }
     }
@@ -480,6 +584,18 @@ impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasSchedulerService for T {
     }
 }
 
+impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasTopicService for T {
+    fn topic_service(&self) -> Arc<dyn topic::TopicService + Send + Sync> {
+        self.all().topic_service.clone()
+    }
+}
+
+impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasMailboxService for T {
+    fn mailbox_service(&self) -> Arc<dyn mailbox::MailboxService + Send + Sync> {
+        self.all().mailbox_service.clone()
+    }
+}
+
 impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasWorkerActivator for T {
     fn worker_activator(&self) -> Arc<dyn WorkerActivator + Send + Sync> {
         self.all().worker_activator.clone()
@@ -498,6 +614,20 @@ impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasEvents for T {
     }
 }
 
+impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasWorkerEventLogService for T {
+    fn worker_event_log_service(
+        &self,
+    ) -> Arc<dyn worker_event_log::WorkerEventLogService + Send + Sync> {
+        self.all().worker_event_log_service.clone()
+    }
+}
+
+impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasClock for T {
+    fn clock(&self) -> Arc<dyn Clock + Send + Sync> {
+        self.all().clock.clone()
+    }
+}
+
 impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasExtraDeps<Ctx> for T {
     fn extra_deps(&self) -> Ctx::ExtraDeps {
         self.all().extra_deps.clone()
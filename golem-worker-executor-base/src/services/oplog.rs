@@ -20,6 +20,7 @@ use std::time::Duration;
 
 use async_trait::async_trait;
 use bytes::Bytes;
+use dashmap::DashMap;
 use fred::prelude::RedisValue;
 use fred::types::RedisKey;
 use golem_common::metrics::redis::record_redis_serialized_size;
@@ -29,6 +30,7 @@ use golem_common::redis::RedisPool;
 use tracing::error;
 
 use crate::metrics::oplog::record_oplog_call;
+use crate::metrics::per_component::record_component_oplog_entry;
 
 #[async_trait]
 pub trait OplogService {
@@ -123,6 +125,7 @@ impl OplogService for RedisOplogService {
         });
 
         record_redis_serialized_size("oplog", "entry", value.len());
+        record_component_oplog_entry(worker_id, value.len());
 
         let field: RedisKey = "key".into();
         let _: String = self
@@ -153,6 +156,7 @@ impl OplogService for RedisOplogService {
             self.redis.clone(),
             self.replicas,
             self.max_operations_before_commit,
+            worker_id.clone(),
             key,
             oplog_size,
         ))
@@ -235,6 +239,7 @@ impl RedisOplog {
         redis: RedisPool,
         replicas: u8,
         max_operations_before_commit: u64,
+        worker_id: WorkerId,
         key: String,
         oplog_size: u64,
     ) -> Self {
@@ -243,6 +248,7 @@ impl RedisOplog {
                 redis,
                 replicas,
                 max_operations_before_commit,
+                worker_id,
                 key: key.clone(),
                 buffer: VecDeque::new(),
                 last_committed_idx: oplog_size,
@@ -257,6 +263,7 @@ struct RedisOplogState {
     redis: RedisPool,
     replicas: u8,
     max_operations_before_commit: u64,
+    worker_id: WorkerId,
     key: String,
     buffer: VecDeque<OplogEntry>,
     last_oplog_idx: u64,
@@ -276,6 +283,7 @@ impl RedisOplogState {
             });
 
             record_redis_serialized_size("oplog", "entry", value.len());
+            record_component_oplog_entry(&self.worker_id, value.len());
 
             let field: RedisKey = "key".into();
             let id = self.last_committed_idx + 1;
@@ -404,6 +412,142 @@ impl Oplog for RedisOplog {
     }
 }
 
+/// Oplog service keeping every oplog in memory only, for workers whose state is not meant to
+/// survive the executor (such as ephemeral workers).
+#[derive(Clone, Debug)]
+pub struct OplogServiceInMemory {
+    oplogs: Arc<DashMap<WorkerId, Vec<OplogEntry>>>,
+}
+
+impl Default for OplogServiceInMemory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OplogServiceInMemory {
+    pub fn new() -> Self {
+        Self {
+            oplogs: Arc::new(DashMap::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl OplogService for OplogServiceInMemory {
+    async fn create(
+        &self,
+        worker_id: &WorkerId,
+        initial_entry: OplogEntry,
+    ) -> Arc<dyn Oplog + Send + Sync> {
+        record_oplog_call("create");
+
+        if self.oplogs.contains_key(worker_id) {
+            panic!("oplog for worker {worker_id} already exists in memory")
+        }
+        record_in_memory_oplog_entry(worker_id, &initial_entry);
+        self.oplogs.insert(worker_id.clone(), vec![initial_entry]);
+
+        self.open(worker_id).await
+    }
+
+    async fn open(&self, worker_id: &WorkerId) -> Arc<dyn Oplog + Send + Sync> {
+        record_oplog_call("open");
+
+        Arc::new(InMemoryOplog {
+            oplogs: self.oplogs.clone(),
+            worker_id: worker_id.clone(),
+        })
+    }
+
+    async fn get_size(&self, worker_id: &WorkerId) -> u64 {
+        record_oplog_call("get_size");
+
+        self.oplogs
+            .get(worker_id)
+            .map(|entries| entries.len() as u64)
+            .unwrap_or_default()
+    }
+
+    async fn delete(&self, worker_id: &WorkerId) {
+        record_oplog_call("drop");
+
+        self.oplogs.remove(worker_id);
+    }
+
+    async fn read(&self, worker_id: &WorkerId, idx: u64, n: u64) -> Vec<OplogEntry> {
+        record_oplog_call("read");
+
+        self.oplogs
+            .get(worker_id)
+            .map(|entries| {
+                entries
+                    .iter()
+                    .skip(idx as usize)
+                    .take(n as usize)
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Entries of the in-memory oplog are not serialized, so they are measured by the size they
+/// would have in the persistent oplog
+fn record_in_memory_oplog_entry(worker_id: &WorkerId, entry: &OplogEntry) {
+    let size = golem_common::serialization::serialize(entry)
+        .map(|bytes| bytes.len())
+        .unwrap_or_default();
+    record_component_oplog_entry(worker_id, size);
+}
+
+struct InMemoryOplog {
+    oplogs: Arc<DashMap<WorkerId, Vec<OplogEntry>>>,
+    worker_id: WorkerId,
+}
+
+impl Debug for InMemoryOplog {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "in-memory oplog of {}", self.worker_id)
+    }
+}
+
+#[async_trait]
+impl Oplog for InMemoryOplog {
+    async fn add(&self, entry: OplogEntry) {
+        record_in_memory_oplog_entry(&self.worker_id, &entry);
+        self.oplogs
+            .entry(self.worker_id.clone())
+            .or_default()
+            .push(entry);
+    }
+
+    async fn commit(&self) {}
+
+    async fn current_oplog_index(&self) -> u64 {
+        self.oplogs
+            .get(&self.worker_id)
+            .map(|entries| entries.len() as u64)
+            .unwrap_or_default()
+    }
+
+    async fn wait_for_replicas(&self, _replicas: u8, _timeout: Duration) -> bool {
+        true
+    }
+
+    async fn read(&self, oplog_index: OplogIndex) -> OplogEntry {
+        self.oplogs
+            .get(&self.worker_id)
+            .and_then(|entries| entries.get(oplog_index as usize).cloned())
+            .unwrap_or_else(|| {
+                panic!(
+                    "Missing oplog entry {oplog_index} for {} in memory",
+                    self.worker_id
+                )
+            })
+    }
+}
+
 #[cfg(any(feature = "mocks", test))]
 pub struct OplogServiceMock {}
 
@@ -448,3 +592,82 @@ impl OplogService for OplogServiceMock {
         unimplemented!()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use golem_common::model::oplog::OplogEntry;
+    use golem_common::model::{AccountId, ComponentId, WorkerId};
+
+    use crate::services::oplog::{OplogService, OplogServiceInMemory};
+
+    fn component_counter(name: &str, worker_id: &WorkerId) -> f64 {
+        let component_id = worker_id.component_id.to_string();
+        prometheus::default_registry()
+            .gather()
+            .iter()
+            .filter(|family| family.get_name() == name)
+            .flat_map(|family| family.get_metric().to_vec())
+            .filter(|metric| {
+                metric.get_label().iter().any(|label| {
+                    label.get_name() == "component_id" && label.get_value() == component_id
+                })
+            })
+            .map(|metric| metric.get_counter().get_value())
+            .sum()
+    }
+
+    #[tokio::test]
+    async fn in_memory_oplog_is_indexed_like_the_persistent_one() {
+        let svc = OplogServiceInMemory::new();
+        let worker_id = WorkerId {
+            component_id: ComponentId::new_v4(),
+            worker_name: "test".to_string(),
+        };
+        let account_id = AccountId {
+            value: "test-account".to_string(),
+        };
+
+        let oplog = svc
+            .create(
+                &worker_id,
+                OplogEntry::create(worker_id.clone(), 0, vec![], vec![], account_id),
+            )
+            .await;
+        let idx = oplog.add_and_commit(OplogEntry::suspend()).await;
+
+        assert_eq!(idx, 1);
+        assert_eq!(oplog.current_oplog_index().await, 2);
+        assert_eq!(svc.get_size(&worker_id).await, 2);
+        assert!(matches!(oplog.read(1).await, OplogEntry::Suspend { .. }));
+        assert_eq!(svc.read(&worker_id, 1, 10).await.len(), 1);
+
+        svc.delete(&worker_id).await;
+        assert_eq!(svc.get_size(&worker_id).await, 0);
+    }
+
+    #[tokio::test]
+    async fn in_memory_oplog_records_component_metrics() {
+        let svc = OplogServiceInMemory::new();
+        let worker_id = WorkerId {
+            component_id: ComponentId::new_v4(),
+            worker_name: "test".to_string(),
+        };
+        let account_id = AccountId {
+            value: "test-account".to_string(),
+        };
+
+        let oplog = svc
+            .create(
+                &worker_id,
+                OplogEntry::create(worker_id.clone(), 0, vec![], vec![], account_id),
+            )
+            .await;
+        oplog.add_and_commit(OplogEntry::suspend()).await;
+
+        assert_eq!(
+            component_counter("component_oplog_entries_total", &worker_id),
+            2.0
+        );
+        assert!(component_counter("component_oplog_bytes_total", &worker_id) > 0.0);
+    }
+}
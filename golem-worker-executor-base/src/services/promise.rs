@@ -25,7 +25,7 @@ use dashmap::mapref::entry::Entry;
 use dashmap::DashMap;
 use fred::prelude::*;
 use golem_common::metrics::redis::record_redis_serialized_size;
-use golem_common::model::{PromiseId, WorkerId};
+use golem_common::model::{PromiseId, PromiseStatus, Timestamp, WorkerId};
 use golem_common::redis::RedisPool;
 use serde::{Deserialize, Serialize};
 use tokio::sync::oneshot;
@@ -40,13 +40,81 @@ use crate::services::golem_config::PromisesConfig;
 pub trait PromiseService {
     async fn create(&self, worker_id: &WorkerId, oplog_idx: u64) -> PromiseId;
 
-    async fn wait_for(&self, promise_id: PromiseId) -> Result<Vec<u8>, GolemError>;
+    async fn wait_for(&self, promise_id: PromiseId) -> Result<PromiseResult, GolemError>;
 
-    async fn poll(&self, promise_id: PromiseId) -> Result<Option<Vec<u8>>, GolemError>;
+    async fn poll(&self, promise_id: PromiseId) -> Result<Option<PromiseResult>, GolemError>;
 
     async fn complete(&self, promise_id: PromiseId, data: Vec<u8>) -> Result<bool, GolemError>;
 
+    /// Rejects the promise with an error payload. Returns false if it was already completed.
+    async fn fail(&self, promise_id: PromiseId, error: Vec<u8>) -> Result<bool, GolemError>;
+
+    /// Fails the promise because its deadline passed. Returns false if it was already completed.
+    async fn time_out(&self, promise_id: PromiseId) -> Result<bool, GolemError>;
+
     async fn delete(&self, promise_id: PromiseId);
+
+    /// Lists every promise created by the worker, including the deleted ones, ordered by their
+    /// oplog index
+    async fn list(&self, worker_id: &WorkerId) -> Result<Vec<PromiseMetadata>, GolemError>;
+
+    /// Deletes every promise of a deleted worker, together with the bookkeeping of them
+    async fn delete_all(&self, worker_id: &WorkerId) -> Result<(), GolemError>;
+
+    /// Remembers the promise the worker is going to suspend on
+    async fn set_awaited(&self, promise_id: PromiseId);
+
+    /// Gets the promise the worker is suspended on, if it is still pending
+    async fn get_awaited(&self, worker_id: &WorkerId) -> Option<PromiseId>;
+}
+
+/// The outcome of a promise that is no longer pending
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PromiseResult {
+    Completed(Vec<u8>),
+    Failed(Vec<u8>),
+    TimedOut,
+}
+
+/// Information about a single promise of a worker
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PromiseMetadata {
+    pub promise_id: PromiseId,
+    pub status: PromiseStatus,
+    pub completed_at: Option<Timestamp>,
+    pub payload_size: Option<u64>,
+}
+
+impl PromiseMetadata {
+    fn new(
+        promise_id: PromiseId,
+        result: Option<&PromiseResult>,
+        completed_at: Option<Timestamp>,
+    ) -> Self {
+        let (status, payload_size) = match result {
+            None => (PromiseStatus::Pending, None),
+            Some(PromiseResult::Completed(data)) => {
+                (PromiseStatus::Completed, Some(data.len() as u64))
+            }
+            Some(PromiseResult::Failed(error)) => (PromiseStatus::Failed, Some(error.len() as u64)),
+            Some(PromiseResult::TimedOut) => (PromiseStatus::TimedOut, None),
+        };
+        Self {
+            promise_id,
+            status,
+            completed_at,
+            payload_size,
+        }
+    }
+
+    fn deleted(promise_id: PromiseId, completed_at: Option<Timestamp>) -> Self {
+        Self {
+            promise_id,
+            status: PromiseStatus::Deleted,
+            completed_at,
+            payload_size: None,
+        }
+    }
 }
 
 pub fn configured(
@@ -106,18 +174,24 @@ impl PromiseService for PromiseServiceRedis {
 
         record_redis_serialized_size("promise", "promise", value.len());
 
-        let _: () = self
+        let created: Option<String> = self
             .redis
             .with("promise", "create")
             .set(key.clone(), value, None, Some(SetOptions::NX), false)
             .await
             .unwrap_or_else(|err| panic!("failed to set promise {promise_id} in Redis: {err}"));
 
+        if created.is_some() {
+            self.write_record(&promise_id, RedisPromiseRecord { completed_at: None })
+                .await
+                .unwrap_or_else(|err| panic!("{err}"));
+        }
+
         record_promise_created();
         promise_id
     }
 
-    async fn wait_for(&self, promise_id: PromiseId) -> Result<Vec<u8>, GolemError> {
+    async fn wait_for(&self, promise_id: PromiseId) -> Result<PromiseResult, GolemError> {
         let key = get_promise_redis_key(&promise_id);
 
         let response: Option<Bytes> = self
@@ -133,10 +207,10 @@ impl PromiseService for PromiseServiceRedis {
                 .expect("failed to deserialize RedisPromiseState")
         });
 
-        match response {
-            Some(RedisPromiseState::Complete(data)) => Ok(data),
-            Some(RedisPromiseState::Pending) => {
-                let (sender, receiver) = oneshot::channel::<Vec<u8>>();
+        match response.map(|state| state.into_result()) {
+            Some(Some(result)) => Ok(result),
+            Some(None) => {
+                let (sender, receiver) = oneshot::channel::<PromiseResult>();
 
                 let pending =
                     PromiseState::Pending(Arc::new(Mutex::new(Some(sender))), Mutex::new(receiver));
@@ -156,19 +230,19 @@ impl PromiseService for PromiseServiceRedis {
                     PromiseState::Pending(_, receiver) => {
                         let mut mutex_guard = receiver.lock().await;
                         let receiver = mutex_guard.deref_mut();
-                        let data = receiver
+                        let result = receiver
                             .await
                             .map_err(|_| GolemError::PromiseDropped { promise_id })?;
-                        Ok(data)
+                        Ok(result)
                     }
-                    PromiseState::Complete(data) => Ok(data.clone()),
+                    PromiseState::Complete(result) => Ok(result.clone()),
                 }
             }
             None => Err(GolemError::PromiseNotFound { promise_id }),
         }
     }
 
-    async fn poll(&self, promise_id: PromiseId) -> Result<Option<Vec<u8>>, GolemError> {
+    async fn poll(&self, promise_id: PromiseId) -> Result<Option<PromiseResult>, GolemError> {
         let key = get_promise_redis_key(&promise_id);
 
         let response: Option<Bytes> = self
@@ -185,36 +259,271 @@ impl PromiseService for PromiseServiceRedis {
         });
 
         match response {
-            Some(RedisPromiseState::Complete(data)) => Ok(Some(data)),
-            Some(RedisPromiseState::Pending) => Ok(None),
+            Some(state) => Ok(state.into_result()),
             None => Err(GolemError::PromiseNotFound { promise_id }),
         }
     }
 
     async fn complete(&self, promise_id: PromiseId, data: Vec<u8>) -> Result<bool, GolemError> {
+        self.settle(promise_id, PromiseResult::Completed(data))
+            .await
+    }
+
+    async fn fail(&self, promise_id: PromiseId, error: Vec<u8>) -> Result<bool, GolemError> {
+        self.settle(promise_id, PromiseResult::Failed(error)).await
+    }
+
+    async fn time_out(&self, promise_id: PromiseId) -> Result<bool, GolemError> {
+        self.settle(promise_id, PromiseResult::TimedOut).await
+    }
+
+    async fn delete(&self, promise_id: PromiseId) {
         let key = get_promise_redis_key(&promise_id);
+        let _: u32 = self
+            .redis
+            .with("promise", "delete")
+            .del(key)
+            .await
+            .unwrap_or_else(|err| {
+                panic!("failed to delete promise {promise_id} from Redis: {err}")
+            });
+    }
+
+    async fn list(&self, worker_id: &WorkerId) -> Result<Vec<PromiseMetadata>, GolemError> {
+        let promise_ids = self.promise_ids_of(worker_id).await?;
+        let mut result = Vec::with_capacity(promise_ids.len());
+        for promise_ids in promise_ids.chunks(PROMISE_BATCH_SIZE) {
+            let records: Vec<Option<Bytes>> = self
+                .redis
+                .with("promise", "list")
+                .hmget(
+                    get_promise_records_redis_key(worker_id),
+                    promise_ids
+                        .iter()
+                        .map(|promise_id| promise_id.oplog_idx.to_string())
+                        .collect::<Vec<_>>(),
+                )
+                .await
+                .map_err(|err| GolemError::unknown(format!("failed to list promises: {err}")))?;
+            let states: Vec<Option<Bytes>> = self
+                .redis
+                .with("promise", "list")
+                .eval(
+                    GET_ALL_SCRIPT,
+                    promise_ids.iter().map(get_promise_redis_key).collect(),
+                    Vec::<String>::new(),
+                )
+                .await
+                .map_err(|err| GolemError::unknown(format!("failed to list promises: {err}")))?;
+
+            for ((promise_id, record), state) in promise_ids.iter().zip(records).zip(states) {
+                let completed_at = record
+                    .map(|bs| {
+                        self.redis
+                            .deserialize::<RedisPromiseRecord>(&bs)
+                            .map_err(GolemError::unknown)
+                    })
+                    .transpose()?
+                    .and_then(|record| record.completed_at);
+                let state = state
+                    .map(|bs| {
+                        self.redis
+                            .deserialize::<RedisPromiseState>(&bs)
+                            .map_err(GolemError::unknown)
+                    })
+                    .transpose()?;
+
+                result.push(match state {
+                    Some(state) => PromiseMetadata::new(
+                        promise_id.clone(),
+                        state.into_result().as_ref(),
+                        completed_at,
+                    ),
+                    None => PromiseMetadata::deleted(promise_id.clone(), completed_at),
+                });
+            }
+        }
+        Ok(result)
+    }
+
+    async fn delete_all(&self, worker_id: &WorkerId) -> Result<(), GolemError> {
+        let promise_ids = self.promise_ids_of(worker_id).await?;
+        let mut keys: Vec<String> = promise_ids.iter().map(get_promise_redis_key).collect();
+        keys.push(get_promise_records_redis_key(worker_id));
+        keys.push(get_awaited_promise_redis_key(worker_id));
+
+        let _: () = self
+            .redis
+            .with("promise", "delete_all")
+            .transaction(|trx| async move {
+                for key in keys {
+                    trx.del(key).await?;
+                }
+                Ok(trx)
+            })
+            .await
+            .map_err(|err| {
+                GolemError::unknown(format!(
+                    "failed to delete the promises of {worker_id}: {err}"
+                ))
+            })?;
+        for promise_id in promise_ids {
+            self.promises.remove(&promise_id);
+        }
+        Ok(())
+    }
+
+    async fn set_awaited(&self, promise_id: PromiseId) {
         let value = self
             .redis
-            .serialize(&RedisPromiseState::Complete(data.clone()))
-            .expect("failed to serialize RedisPromiseState");
+            .serialize(&promise_id.oplog_idx)
+            .expect("failed to serialize awaited promise");
 
+        let _: () = self
+            .redis
+            .with("promise", "set_awaited")
+            .set(
+                get_awaited_promise_redis_key(&promise_id.worker_id),
+                value,
+                None,
+                None,
+                false,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                panic!("failed to set awaited promise {promise_id} in Redis: {err}")
+            });
+    }
+
+    async fn get_awaited(&self, worker_id: &WorkerId) -> Option<PromiseId> {
         let response: Option<Bytes> = self
             .redis
-            .with("promise", "complete")
-            .set(key, value, None, Some(SetOptions::XX), true)
+            .with("promise", "get_awaited")
+            .get(get_awaited_promise_redis_key(worker_id))
             .await
-            .unwrap_or_else(|err| panic!("failed to set promise {promise_id} in Redis: {err}"));
+            .unwrap_or_else(|err| {
+                panic!("failed to get awaited promise of {worker_id} from Redis: {err}")
+            });
 
-        let response: Option<RedisPromiseState> = response.map(|bs| {
-            self.redis
-                .deserialize(&bs)
-                .expect("failed to deserialize RedisPromiseState")
-        });
+        let oplog_idx: u64 = self
+            .redis
+            .deserialize(&response?)
+            .expect("failed to deserialize awaited promise");
+        let promise_id = PromiseId {
+            worker_id: worker_id.clone(),
+            oplog_idx,
+        };
 
-        match response {
-            Some(RedisPromiseState::Complete(_)) => Ok(false),
-            Some(RedisPromiseState::Pending) => {
-                let complete = PromiseState::Complete(data.clone());
+        match self.read_state(&promise_id, "get_awaited").await {
+            Ok(Some(RedisPromiseState::Pending)) => Some(promise_id),
+            _ => None,
+        }
+    }
+}
+
+impl PromiseServiceRedis {
+    async fn read_state(
+        &self,
+        promise_id: &PromiseId,
+        op: &'static str,
+    ) -> Result<Option<RedisPromiseState>, GolemError> {
+        let response: Option<Bytes> = self
+            .redis
+            .with("promise", op)
+            .get(get_promise_redis_key(promise_id))
+            .await
+            .map_err(|err| {
+                GolemError::unknown(format!("failed to get promise {promise_id}: {err}"))
+            })?;
+
+        response
+            .map(|bs| self.redis.deserialize(&bs).map_err(GolemError::unknown))
+            .transpose()
+    }
+
+    /// Gets the ids of every promise created by the worker, ordered by their oplog index
+    async fn promise_ids_of(&self, worker_id: &WorkerId) -> Result<Vec<PromiseId>, GolemError> {
+        let fields: Vec<String> = self
+            .redis
+            .with("promise", "list")
+            .hkeys(get_promise_records_redis_key(worker_id))
+            .await
+            .map_err(|err| GolemError::unknown(format!("failed to list promises: {err}")))?;
+
+        let mut promise_ids = fields
+            .into_iter()
+            .map(|field| {
+                let oplog_idx = field.parse::<u64>().map_err(|err| {
+                    GolemError::unknown(format!("invalid promise record {field}: {err}"))
+                })?;
+                Ok(PromiseId {
+                    worker_id: worker_id.clone(),
+                    oplog_idx,
+                })
+            })
+            .collect::<Result<Vec<_>, GolemError>>()?;
+        promise_ids.sort_by_key(|promise_id| promise_id.oplog_idx);
+        Ok(promise_ids)
+    }
+
+    async fn write_record(
+        &self,
+        promise_id: &PromiseId,
+        record: RedisPromiseRecord,
+    ) -> Result<(), GolemError> {
+        let value = self.redis.serialize(&record).map_err(GolemError::unknown)?;
+
+        record_redis_serialized_size("promise", "record", value.len());
+
+        self.redis
+            .with("promise", "write_record")
+            .hset(
+                get_promise_records_redis_key(&promise_id.worker_id),
+                (promise_id.oplog_idx.to_string(), value),
+            )
+            .await
+            .map_err(|err| {
+                GolemError::unknown(format!(
+                    "failed to write promise record {promise_id}: {err}"
+                ))
+            })
+    }
+
+    async fn settle(
+        &self,
+        promise_id: PromiseId,
+        result: PromiseResult,
+    ) -> Result<bool, GolemError> {
+        let key = get_promise_redis_key(&promise_id);
+        let pending = self
+            .redis
+            .serialize(&RedisPromiseState::Pending)
+            .map_err(GolemError::unknown)?;
+        let value = self
+            .redis
+            .serialize(&RedisPromiseState::from(result.clone()))
+            .map_err(GolemError::unknown)?;
+
+        let settled: i64 = self
+            .redis
+            .with("promise", "complete")
+            .eval(SETTLE_SCRIPT, vec![key], vec![pending, value])
+            .await
+            .map_err(|err| {
+                GolemError::unknown(format!("failed to complete promise {promise_id}: {err}"))
+            })?;
+
+        match settled {
+            1 => {
+                self.write_record(
+                    &promise_id,
+                    RedisPromiseRecord {
+                        completed_at: Some(Timestamp::now_utc()),
+                    },
+                )
+                .await?;
+
+                let complete = PromiseState::Complete(result.clone());
                 self.insert_if_empty(promise_id.clone(), complete);
                 let entry = self.promises.get(&promise_id).unwrap_or_else(|| {
                     panic!(
@@ -233,51 +542,101 @@ impl PromiseService for PromiseServiceRedis {
                                     promise_id: promise_id.clone(),
                                 })?;
                         owned_sender
-                            .send(data)
+                            .send(result)
                             .map_err(|_| GolemError::PromiseDropped { promise_id })?;
                         Ok(true)
                     }
                     _ => Ok(true),
                 }
             }
-            None => Err(GolemError::PromiseNotFound { promise_id }),
+            // The first result wins, so a late completion or timeout does not replace it
+            0 => Ok(false),
+            _ => Err(GolemError::PromiseNotFound { promise_id }),
         }
     }
-
-    async fn delete(&self, promise_id: PromiseId) {
-        let key = get_promise_redis_key(&promise_id);
-        let _: u32 = self
-            .redis
-            .with("promise", "delete")
-            .del(key)
-            .await
-            .unwrap_or_else(|err| {
-                panic!("failed to delete promise {promise_id} from Redis: {err}")
-            });
-    }
 }
 
+/// Number of promises read with a single command when listing the promises of a worker
+const PROMISE_BATCH_SIZE: usize = 1000;
+
+/// Gets the values of all `KEYS`, like `MGET`
+const GET_ALL_SCRIPT: &str = "return redis.call('MGET', unpack(KEYS))";
+
+/// Replaces the state of the promise (`KEYS[1]`) with `ARGV[2]` if it is still pending, that is
+/// equal to `ARGV[1]`. Returns 1 if it was replaced, 0 if the promise was already completed and
+/// -1 if it does not exist.
+const SETTLE_SCRIPT: &str = r#"
+local current = redis.call('GET', KEYS[1])
+if not current then
+    return -1
+elseif current == ARGV[1] then
+    redis.call('SET', KEYS[1], ARGV[2])
+    return 1
+else
+    return 0
+end
+"#;
+
 fn get_promise_redis_key(promise_id: &PromiseId) -> String {
     format!("instance:promise:{}", promise_id.to_redis_key())
 }
 
+fn get_promise_records_redis_key(worker_id: &WorkerId) -> String {
+    format!("instance:promises:{}", worker_id.to_redis_key())
+}
+
+fn get_awaited_promise_redis_key(worker_id: &WorkerId) -> String {
+    format!("instance:promises:{}:awaited", worker_id.to_redis_key())
+}
+
 #[derive(Debug)]
 enum PromiseState {
     Pending(
-        Arc<Mutex<Option<oneshot::Sender<Vec<u8>>>>>,
-        Mutex<oneshot::Receiver<Vec<u8>>>,
+        Arc<Mutex<Option<oneshot::Sender<PromiseResult>>>>,
+        Mutex<oneshot::Receiver<PromiseResult>>,
     ),
-    Complete(Vec<u8>),
+    Complete(PromiseResult),
 }
 
 #[derive(Debug, Serialize, Deserialize, Encode, Decode)]
 enum RedisPromiseState {
     Pending,
     Complete(Vec<u8>),
+    Failed(Vec<u8>),
+    TimedOut,
+}
+
+impl RedisPromiseState {
+    fn into_result(self) -> Option<PromiseResult> {
+        match self {
+            RedisPromiseState::Pending => None,
+            RedisPromiseState::Complete(data) => Some(PromiseResult::Completed(data)),
+            RedisPromiseState::Failed(error) => Some(PromiseResult::Failed(error)),
+            RedisPromiseState::TimedOut => Some(PromiseResult::TimedOut),
+        }
+    }
+}
+
+/// Bookkeeping kept for every created promise, surviving the deletion of the promise itself
+#[derive(Debug, Serialize, Deserialize, Encode, Decode)]
+struct RedisPromiseRecord {
+    completed_at: Option<Timestamp>,
+}
+
+impl From<PromiseResult> for RedisPromiseState {
+    fn from(value: PromiseResult) -> Self {
+        match value {
+            PromiseResult::Completed(data) => RedisPromiseState::Complete(data),
+            PromiseResult::Failed(error) => RedisPromiseState::Failed(error),
+            PromiseResult::TimedOut => RedisPromiseState::TimedOut,
+        }
+    }
 }
 
 pub struct PromiseServiceInMemory {
     promises: Arc<DashMap<PromiseId, PromiseState>>,
+    completed_at: Arc<DashMap<PromiseId, Option<Timestamp>>>,
+    awaited: Arc<DashMap<WorkerId, PromiseId>>,
 }
 
 impl Default for PromiseServiceInMemory {
@@ -290,6 +649,8 @@ impl PromiseServiceInMemory {
     pub fn new() -> Self {
         Self {
             promises: Arc::new(DashMap::new()),
+            completed_at: Arc::new(DashMap::new()),
+            awaited: Arc::new(DashMap::new()),
         }
     }
 }
@@ -302,35 +663,36 @@ impl PromiseService for PromiseServiceInMemory {
             oplog_idx,
         };
 
-        let (sender, receiver) = oneshot::channel::<Vec<u8>>();
+        let (sender, receiver) = oneshot::channel::<PromiseResult>();
         let pending =
             PromiseState::Pending(Arc::new(Mutex::new(Some(sender))), Mutex::new(receiver));
         self.promises.insert(promise_id.clone(), pending);
+        self.completed_at.entry(promise_id.clone()).or_insert(None);
 
         promise_id
     }
 
-    async fn wait_for(&self, promise_id: PromiseId) -> Result<Vec<u8>, GolemError> {
+    async fn wait_for(&self, promise_id: PromiseId) -> Result<PromiseResult, GolemError> {
         match self.promises.get(&promise_id) {
             Some(item) => match item.value() {
-                PromiseState::Complete(data) => Ok(data.clone()),
+                PromiseState::Complete(result) => Ok(result.clone()),
                 PromiseState::Pending(_, receiver) => {
                     let mut mutex_guard = receiver.lock().await;
                     let receiver = mutex_guard.deref_mut();
-                    let data = receiver
+                    let result = receiver
                         .await
                         .map_err(|_| GolemError::PromiseDropped { promise_id })?;
-                    Ok(data)
+                    Ok(result)
                 }
             },
             None => Err(GolemError::PromiseNotFound { promise_id }),
         }
     }
 
-    async fn poll(&self, promise_id: PromiseId) -> Result<Option<Vec<u8>>, GolemError> {
+    async fn poll(&self, promise_id: PromiseId) -> Result<Option<PromiseResult>, GolemError> {
         match self.promises.get(&promise_id) {
             Some(item) => match item.value() {
-                PromiseState::Complete(data) => Ok(Some(data.clone())),
+                PromiseState::Complete(result) => Ok(Some(result.clone())),
                 PromiseState::Pending(_, _) => Ok(None),
             },
             None => Err(GolemError::PromiseNotFound { promise_id }),
@@ -338,27 +700,91 @@ impl PromiseService for PromiseServiceInMemory {
     }
 
     async fn complete(&self, promise_id: PromiseId, data: Vec<u8>) -> Result<bool, GolemError> {
+        self.settle(promise_id, PromiseResult::Completed(data))
+    }
+
+    async fn fail(&self, promise_id: PromiseId, error: Vec<u8>) -> Result<bool, GolemError> {
+        self.settle(promise_id, PromiseResult::Failed(error))
+    }
+
+    async fn time_out(&self, promise_id: PromiseId) -> Result<bool, GolemError> {
+        self.settle(promise_id, PromiseResult::TimedOut)
+    }
+
+    async fn delete(&self, promise_id: PromiseId) {
+        self.promises.remove(&promise_id);
+    }
+
+    async fn list(&self, worker_id: &WorkerId) -> Result<Vec<PromiseMetadata>, GolemError> {
+        let mut result: Vec<PromiseMetadata> = self
+            .completed_at
+            .iter()
+            .filter(|entry| &entry.key().worker_id == worker_id)
+            .map(|entry| {
+                let promise_id = entry.key().clone();
+                let completed_at = *entry.value();
+                match self.promises.get(&promise_id) {
+                    Some(state) => match state.value() {
+                        PromiseState::Pending(_, _) => {
+                            PromiseMetadata::new(promise_id, None, completed_at)
+                        }
+                        PromiseState::Complete(result) => {
+                            PromiseMetadata::new(promise_id, Some(result), completed_at)
+                        }
+                    },
+                    None => PromiseMetadata::deleted(promise_id, completed_at),
+                }
+            })
+            .collect();
+        result.sort_by_key(|metadata| metadata.promise_id.oplog_idx);
+        Ok(result)
+    }
+
+    async fn delete_all(&self, worker_id: &WorkerId) -> Result<(), GolemError> {
+        self.promises
+            .retain(|promise_id, _| &promise_id.worker_id != worker_id);
+        self.completed_at
+            .retain(|promise_id, _| &promise_id.worker_id != worker_id);
+        self.awaited.remove(worker_id);
+        Ok(())
+    }
+
+    async fn set_awaited(&self, promise_id: PromiseId) {
+        self.awaited
+            .insert(promise_id.worker_id.clone(), promise_id);
+    }
+
+    async fn get_awaited(&self, worker_id: &WorkerId) -> Option<PromiseId> {
+        let promise_id = self.awaited.get(worker_id)?.value().clone();
+        match self.promises.get(&promise_id)?.value() {
+            PromiseState::Pending(_, _) => Some(promise_id),
+            PromiseState::Complete(_) => None,
+        }
+    }
+}
+
+impl PromiseServiceInMemory {
+    fn settle(&self, promise_id: PromiseId, result: PromiseResult) -> Result<bool, GolemError> {
         match self.promises.entry(promise_id.clone()) {
             Entry::Occupied(mut entry) => match entry.get() {
                 PromiseState::Complete(_) => Ok(false),
                 PromiseState::Pending(_, _) => {
-                    let complete = PromiseState::Complete(data.clone());
+                    let complete = PromiseState::Complete(result);
                     *(entry.get_mut()) = complete;
+                    self.completed_at
+                        .insert(promise_id, Some(Timestamp::now_utc()));
                     Ok(true)
                 }
             },
             Entry::Vacant(_) => Err(GolemError::PromiseNotFound { promise_id }),
         }
     }
-
-    async fn delete(&self, promise_id: PromiseId) {
-        self.promises.remove(&promise_id);
-    }
 }
 
 #[cfg(any(feature = "mocks", test))]
 pub struct PromiseServiceMock {
     completed: Arc<Mutex<HashSet<PromiseId>>>,
+    timed_out: Arc<Mutex<HashSet<PromiseId>>>,
 }
 
 #[cfg(any(feature = "mocks", test))]
@@ -373,12 +799,17 @@ impl PromiseServiceMock {
     pub fn new() -> Self {
         Self {
             completed: Arc::new(Mutex::new(HashSet::new())),
+            timed_out: Arc::new(Mutex::new(HashSet::new())),
         }
     }
 
     pub async fn all_completed(&self) -> HashSet<PromiseId> {
         self.completed.lock().await.clone()
     }
+
+    pub async fn all_timed_out(&self) -> HashSet<PromiseId> {
+        self.timed_out.lock().await.clone()
+    }
 }
 
 #[cfg(any(feature = "mocks", test))]
@@ -388,11 +819,11 @@ impl PromiseService for PromiseServiceMock {
         unimplemented!()
     }
 
-    async fn wait_for(&self, _promise_id: PromiseId) -> Result<Vec<u8>, GolemError> {
+    async fn wait_for(&self, _promise_id: PromiseId) -> Result<PromiseResult, GolemError> {
         unimplemented!()
     }
 
-    async fn poll(&self, _promise_id: PromiseId) -> Result<Option<Vec<u8>>, GolemError> {
+    async fn poll(&self, _promise_id: PromiseId) -> Result<Option<PromiseResult>, GolemError> {
         unimplemented!()
     }
 
@@ -401,7 +832,32 @@ impl PromiseService for PromiseServiceMock {
         Ok(true)
     }
 
+    async fn fail(&self, _promise_id: PromiseId, _error: Vec<u8>) -> Result<bool, GolemError> {
+        unimplemented!()
+    }
+
+    async fn time_out(&self, promise_id: PromiseId) -> Result<bool, GolemError> {
+        self.timed_out.lock().await.insert(promise_id);
+        Ok(true)
+    }
+
     async fn delete(&self, _promise_id: PromiseId) {
         unimplemented!()
     }
+
+    async fn list(&self, _worker_id: &WorkerId) -> Result<Vec<PromiseMetadata>, GolemError> {
+        unimplemented!()
+    }
+
+    async fn delete_all(&self, _worker_id: &WorkerId) -> Result<(), GolemError> {
+        unimplemented!()
+    }
+
+    async fn set_awaited(&self, _promise_id: PromiseId) {
+        unimplemented!()
+    }
+
+    async fn get_awaited(&self, _worker_id: &WorkerId) -> Option<PromiseId> {
+        unimplemented!()
+    }
 }
@@ -22,6 +22,7 @@ use tokio::runtime::Handle;
 use tokio::task::JoinHandle;
 use tracing::{info, warn};
 
+use golem_common::clock::Clock;
 use golem_common::config::RetryConfig;
 use golem_common::model::oplog::WorkerError;
 use golem_common::model::{WorkerId, WorkerStatus};
@@ -31,12 +32,13 @@ use crate::model::{InterruptKind, LastError, TrapType};
 use crate::services::events::Events;
 use crate::services::rpc::Rpc;
 use crate::services::{
-    active_workers, blob_store, component, golem_config, key_value, oplog, promise, scheduler,
-    worker, worker_activator, worker_enumeration, worker_proxy, HasActiveWorkers, HasAll,
-    HasBlobStoreService, HasComponentService, HasConfig, HasEvents, HasExtraDeps,
-    HasKeyValueService, HasOplogService, HasPromiseService, HasRecoveryManagement, HasRpc,
-    HasRunningWorkerEnumerationService, HasSchedulerService, HasWasmtimeEngine, HasWorkerActivator,
-    HasWorkerEnumerationService, HasWorkerProxy, HasWorkerService,
+    active_workers, blob_store, component, golem_config, key_value, mailbox, oplog, promise,
+    scheduler, topic, worker, worker_activator, worker_enumeration, worker_event_log, worker_proxy,
+    HasActiveWorkers, HasAll, HasBlobStoreService, HasClock, HasComponentService, HasConfig,
+    HasEvents, HasExtraDeps, HasKeyValueService, HasMailboxService, HasOplogService,
+    HasPromiseService, HasRecoveryManagement, HasRpc, HasRunningWorkerEnumerationService,
+    HasSchedulerService, HasTopicService, HasWasmtimeEngine, HasWorkerActivator,
+    HasWorkerEnumerationService, HasWorkerEventLogService, HasWorkerProxy, HasWorkerService,
 };
 use crate::worker::Worker;
 use crate::workerctx::WorkerCtx;
@@ -86,6 +88,8 @@ pub struct RecoveryManagementDefault<Ctx: WorkerCtx> {
     oplog_service: Arc<dyn oplog::OplogService + Send + Sync>,
     promise_service: Arc<dyn promise::PromiseService + Send + Sync>,
     scheduler_service: Arc<dyn scheduler::SchedulerService + Send + Sync>,
+    topic_service: Arc<dyn topic::TopicService + Send + Sync>,
+    mailbox_service: Arc<dyn mailbox::MailboxService + Send + Sync>,
     golem_config: Arc<golem_config::GolemConfig>,
     recovery_override: Option<Arc<dyn Fn(WorkerId) + Send + Sync>>,
     key_value_service: Arc<dyn key_value::KeyValueService + Send + Sync>,
@@ -94,6 +98,8 @@ pub struct RecoveryManagementDefault<Ctx: WorkerCtx> {
     worker_activator: Arc<dyn worker_activator::WorkerActivator + Send + Sync>,
     worker_proxy: Arc<dyn worker_proxy::WorkerProxy + Send + Sync>,
     events: Arc<Events>,
+    worker_event_log_service: Arc<dyn worker_event_log::WorkerEventLogService + Send + Sync>,
+    clock: Arc<dyn Clock + Send + Sync>,
     extra_deps: Ctx::ExtraDeps,
 }
 
@@ -112,6 +118,8 @@ impl<Ctx: WorkerCtx> Clone for RecoveryManagementDefault<Ctx> {
             oplog_service: self.oplog_service.clone(),
             promise_service: self.promise_service.clone(),
             scheduler_service: self.scheduler_service.clone(),
+            topic_service: self.topic_service.clone(),
+            mailbox_service: self.mailbox_service.clone(),
             golem_config: self.golem_config.clone(),
             recovery_override: self.recovery_override.clone(),
             key_value_service: self.key_value_service.clone(),
@@ -120,6 +128,8 @@ impl<Ctx: WorkerCtx> Clone for RecoveryManagementDefault<Ctx> {
             worker_activator: self.worker_activator.clone(),
             worker_proxy: self.worker_proxy.clone(),
             events: self.events.clone(),
+            worker_event_log_service: self.worker_event_log_service.clone(),
+            clock: self.clock.clone(),
             extra_deps: self.extra_deps.clone(),
         }
     }
@@ -203,6 +213,18 @@ impl<Ctx: WorkerCtx> HasSchedulerService for RecoveryManagementDefault<Ctx> {
     }
 }
 
+impl<Ctx: WorkerCtx> HasTopicService for RecoveryManagementDefault<Ctx> {
+    fn topic_service(&self) -> Arc<dyn topic::TopicService + Send + Sync> {
+        self.topic_service.clone()
+    }
+}
+
+impl<Ctx: WorkerCtx> HasMailboxService for RecoveryManagementDefault<Ctx> {
+    fn mailbox_service(&self) -> Arc<dyn mailbox::MailboxService + Send + Sync> {
+        self.mailbox_service.clone()
+    }
+}
+
 impl<Ctx: WorkerCtx> HasWorkerActivator for RecoveryManagementDefault<Ctx> {
     fn worker_activator(&self) -> Arc<dyn worker_activator::WorkerActivator + Send + Sync> {
         self.worker_activator.clone()
@@ -239,6 +261,20 @@ impl<Ctx: WorkerCtx> HasEvents for RecoveryManagementDefault<Ctx> {
     }
 }
 
+impl<Ctx: WorkerCtx> HasWorkerEventLogService for RecoveryManagementDefault<Ctx> {
+    fn worker_event_log_service(
+        &self,
+    ) -> Arc<dyn worker_event_log::WorkerEventLogService + Send + Sync> {
+        self.worker_event_log_service.clone()
+    }
+}
+
+impl<Ctx: WorkerCtx> HasClock for RecoveryManagementDefault<Ctx> {
+    fn clock(&self) -> Arc<dyn Clock + Send + Sync> {
+        self.clock.clone()
+    }
+}
+
 impl<Ctx: WorkerCtx> HasExtraDeps<Ctx> for RecoveryManagementDefault<Ctx> {
     fn extra_deps(&self) -> Ctx::ExtraDeps {
         self.extra_deps.clone()
@@ -262,12 +298,16 @@ impl<Ctx: WorkerCtx> RecoveryManagementDefault<Ctx> {
         oplog_service: Arc<dyn oplog::OplogService + Send + Sync>,
         promise_service: Arc<dyn promise::PromiseService + Send + Sync>,
         scheduler_service: Arc<dyn scheduler::SchedulerService + Send + Sync>,
+        topic_service: Arc<dyn topic::TopicService + Send + Sync>,
+        mailbox_service: Arc<dyn mailbox::MailboxService + Send + Sync>,
         key_value_service: Arc<dyn key_value::KeyValueService + Send + Sync>,
         blob_store_service: Arc<dyn blob_store::BlobStoreService + Send + Sync>,
         rpc: Arc<dyn Rpc + Send + Sync>,
         worker_activator: Arc<dyn worker_activator::WorkerActivator + Send + Sync>,
         worker_proxy: Arc<dyn worker_proxy::WorkerProxy + Send + Sync>,
         events: Arc<Events>,
+        worker_event_log_service: Arc<dyn worker_event_log::WorkerEventLogService + Send + Sync>,
+        clock: Arc<dyn Clock + Send + Sync>,
         golem_config: Arc<golem_config::GolemConfig>,
         extra_deps: Ctx::ExtraDeps,
     ) -> Self {
@@ -284,6 +324,8 @@ impl<Ctx: WorkerCtx> RecoveryManagementDefault<Ctx> {
             oplog_service,
             promise_service,
             scheduler_service,
+            topic_service,
+            mailbox_service,
             key_value_service,
             blob_store_service,
             golem_config,
@@ -292,6 +334,8 @@ impl<Ctx: WorkerCtx> RecoveryManagementDefault<Ctx> {
             worker_activator,
             worker_proxy,
             events,
+            worker_event_log_service,
+            clock,
             extra_deps,
         }
     }
@@ -313,6 +357,8 @@ impl<Ctx: WorkerCtx> RecoveryManagementDefault<Ctx> {
         oplog_service: Arc<dyn oplog::OplogService + Send + Sync>,
         promise_service: Arc<dyn promise::PromiseService + Send + Sync>,
         scheduler_service: Arc<dyn scheduler::SchedulerService + Send + Sync>,
+        topic_service: Arc<dyn topic::TopicService + Send + Sync>,
+        mailbox_service: Arc<dyn mailbox::MailboxService + Send + Sync>,
         key_value_service: Arc<dyn key_value::KeyValueService + Send + Sync>,
         blob_store_service: Arc<dyn blob_store::BlobStoreService + Send + Sync>,
         golem_config: Arc<golem_config::GolemConfig>,
@@ -320,6 +366,8 @@ impl<Ctx: WorkerCtx> RecoveryManagementDefault<Ctx> {
         worker_activator: Arc<dyn worker_activator::WorkerActivator + Send + Sync>,
         worker_proxy: Arc<dyn worker_proxy::WorkerProxy + Send + Sync>,
         events: Arc<Events>,
+        worker_event_log_service: Arc<dyn worker_event_log::WorkerEventLogService + Send + Sync>,
+        clock: Arc<dyn Clock + Send + Sync>,
         extra_deps: Ctx::ExtraDeps,
         recovery_override: F,
     ) -> Self
@@ -339,6 +387,8 @@ impl<Ctx: WorkerCtx> RecoveryManagementDefault<Ctx> {
             oplog_service,
             promise_service,
             scheduler_service,
+            topic_service,
+            mailbox_service,
             key_value_service,
             blob_store_service,
             golem_config,
@@ -347,6 +397,8 @@ impl<Ctx: WorkerCtx> RecoveryManagementDefault<Ctx> {
             worker_activator,
             worker_proxy,
             events,
+            worker_event_log_service,
+            clock,
             extra_deps,
         }
     }
@@ -553,6 +605,33 @@ pub fn is_worker_error_retriable(
     }
 }
 
+/// Recovery management that never recovers a worker, for workers which are not kept alive
+/// beyond a single invocation (such as ephemeral workers).
+#[derive(Clone, Debug, Default)]
+pub struct RecoveryManagementDisabled;
+
+#[async_trait]
+impl RecoveryManagement for RecoveryManagementDisabled {
+    async fn schedule_recovery_on_trap(
+        &self,
+        _worker_id: &WorkerId,
+        _retry_config: &RetryConfig,
+        _previous_tries: u64,
+        _trap_type: &TrapType,
+    ) -> RecoveryDecision {
+        RecoveryDecision::None
+    }
+
+    async fn schedule_recovery_on_startup(
+        &self,
+        _worker_id: &WorkerId,
+        _retry_config: &RetryConfig,
+        _last_error: &Option<LastError>,
+    ) -> RecoveryDecision {
+        RecoveryDecision::None
+    }
+}
+
 #[cfg(any(feature = "mocks", test))]
 pub struct RecoveryManagementMock;
 
@@ -627,15 +706,17 @@ mod tests {
     use crate::services::golem_config::GolemConfig;
     use crate::services::invocation_queue::InvocationQueue;
     use crate::services::key_value::KeyValueService;
+    use crate::services::mailbox::MailboxService;
     use crate::services::oplog::{Oplog, OplogService, OplogServiceMock};
     use crate::services::promise::PromiseService;
     use crate::services::recovery::{RecoveryManagement, RecoveryManagementDefault, TrapType};
     use crate::services::rpc::Rpc;
     use crate::services::scheduler::SchedulerService;
+    use crate::services::topic::TopicService;
     use crate::services::worker::WorkerService;
     use crate::services::worker_event::WorkerEventService;
     use crate::services::worker_proxy::WorkerProxy;
-    use crate::services::{scheduler, HasEvents};
+    use crate::services::{scheduler, HasEvents, HasWorkerEventLogService};
     use crate::services::{
         worker_enumeration, All, HasAll, HasBlobStoreService, HasComponentService, HasConfig,
         HasExtraDeps, HasInvocationQueue, HasKeyValueService, HasOplog, HasPromiseService, HasRpc,
@@ -904,6 +985,8 @@ mod tests {
             _oplog: Arc<dyn Oplog + Send + Sync>,
             _invocation_queue: Arc<InvocationQueue<EmptyContext>>,
             _scheduler_service: Arc<dyn SchedulerService + Send + Sync>,
+            _topic_service: Arc<dyn TopicService + Send + Sync>,
+            _mailbox_service: Arc<dyn MailboxService + Send + Sync>,
             _recovery_management: Arc<dyn RecoveryManagement + Send + Sync>,
             rpc: Arc<dyn Rpc + Send + Sync>,
             worker_proxy: Arc<dyn WorkerProxy + Send + Sync>,
@@ -1010,6 +1093,8 @@ mod tests {
             oplog,
             deps.promise_service(),
             scheduler,
+            deps.topic_service(),
+            deps.mailbox_service(),
             deps.key_value_service(),
             deps.blob_store_service(),
             deps.config(),
@@ -1017,6 +1102,8 @@ mod tests {
             deps.worker_activator(),
             deps.worker_proxy(),
             deps.events(),
+            deps.worker_event_log_service(),
+            deps.clock(),
             (),
             recovery_fn,
         )
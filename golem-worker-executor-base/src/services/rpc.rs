@@ -21,27 +21,36 @@ use golem_wasm_rpc::{Value, WitValue};
 use serde::{Deserialize, Serialize};
 use tokio::runtime::Handle;
 use tracing::debug;
+use uuid::Uuid;
 
-use golem_common::model::{AccountId, IdempotencyKey, WorkerId};
+use golem_common::clock::Clock;
+use golem_common::model::{AccountId, ComponentId, IdempotencyKey, WorkerId};
 
 use crate::error::GolemError;
 use crate::services::events::Events;
 use crate::services::worker_proxy::{WorkerProxy, WorkerProxyError};
 use crate::services::{
-    active_workers, blob_store, component, golem_config, key_value, oplog, promise, recovery,
-    scheduler, shard, shard_manager, worker, worker_activator, worker_enumeration,
-    HasActiveWorkers, HasBlobStoreService, HasComponentService, HasConfig, HasEvents, HasExtraDeps,
-    HasKeyValueService, HasOplogService, HasPromiseService, HasRecoveryManagement, HasRpc,
-    HasRunningWorkerEnumerationService, HasSchedulerService, HasShardService, HasWasmtimeEngine,
-    HasWorkerActivator, HasWorkerEnumerationService, HasWorkerProxy, HasWorkerService,
+    active_workers, blob_store, component, golem_config, key_value, mailbox, oplog, promise,
+    recovery, scheduler, shard, shard_manager, topic, worker, worker_activator, worker_enumeration,
+    worker_event_log, HasActiveWorkers, HasBlobStoreService, HasClock, HasComponentService,
+    HasConfig, HasEvents, HasExtraDeps, HasKeyValueService, HasMailboxService, HasOplogService,
+    HasPromiseService, HasRecoveryManagement, HasRpc, HasRunningWorkerEnumerationService,
+    HasSchedulerService, HasShardManagerService, HasShardService, HasTopicService,
+    HasWasmtimeEngine, HasWorkerActivator, HasWorkerEnumerationService, HasWorkerEventLogService,
+    HasWorkerProxy, HasWorkerService,
 };
-use crate::worker::{invoke, invoke_and_await, Worker};
+use crate::worker::{invoke, invoke_and_await, invoke_and_await_ephemeral, Worker};
 use crate::workerctx::WorkerCtx;
 
 #[async_trait]
 pub trait Rpc {
     async fn create_demand(&self, worker_id: &WorkerId) -> Box<dyn RpcDemand>;
 
+    /// Invokes a function on a worker and awaits its result.
+    ///
+    /// `call_chain` contains the workers already waiting for this invocation, outermost caller
+    /// first. If the target worker is among them the invocation would never complete, so it
+    /// fails with `RpcError::Deadlock` without being enqueued.
     async fn invoke_and_await(
         &self,
         worker_id: &WorkerId,
@@ -49,6 +58,19 @@ pub trait Rpc {
         function_name: String,
         function_params: Vec<WitValue>,
         account_id: &AccountId,
+        call_chain: Vec<WorkerId>,
+    ) -> Result<WitValue, RpcError>;
+
+    /// Invokes a function of the latest version of a component on a fresh ephemeral worker and
+    /// awaits its result. Every call gets its own worker, so there is no idempotency key and no
+    /// call chain check: an ephemeral worker can never be among the callers.
+    async fn invoke_and_await_ephemeral(
+        &self,
+        component_id: &ComponentId,
+        function_name: String,
+        function_params: Vec<WitValue>,
+        account_id: &AccountId,
+        call_chain: Vec<WorkerId>,
     ) -> Result<WitValue, RpcError>;
 
     async fn invoke(
@@ -67,6 +89,20 @@ pub enum RpcError {
     Denied { details: String },
     NotFound { details: String },
     RemoteInternalError { details: String },
+    Deadlock { call_chain: Vec<WorkerId> },
+}
+
+impl RpcError {
+    /// Fails with `RpcError::Deadlock` if `worker_id` is already waiting in `call_chain`
+    pub fn check_call_chain(worker_id: &WorkerId, call_chain: &[WorkerId]) -> Result<(), Self> {
+        if call_chain.contains(worker_id) {
+            let mut call_chain = call_chain.to_vec();
+            call_chain.push(worker_id.clone());
+            Err(RpcError::Deadlock { call_chain })
+        } else {
+            Ok(())
+        }
+    }
 }
 
 impl Display for RpcError {
@@ -78,6 +114,15 @@ impl Display for RpcError {
             RpcError::RemoteInternalError { details } => {
                 write!(f, "Remote internal error: {}", details)
             }
+            RpcError::Deadlock { call_chain } => write!(
+                f,
+                "Deadlock: {}",
+                call_chain
+                    .iter()
+                    .map(|worker_id| worker_id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(" -> ")
+            ),
         }
     }
 }
@@ -180,7 +225,10 @@ impl Rpc for RemoteInvocationRpc {
         function_name: String,
         function_params: Vec<WitValue>,
         account_id: &AccountId,
+        call_chain: Vec<WorkerId>,
     ) -> Result<WitValue, RpcError> {
+        RpcError::check_call_chain(worker_id, &call_chain)?;
+
         Ok(self
             .worker_proxy
             .invoke_and_await(
@@ -189,6 +237,27 @@ impl Rpc for RemoteInvocationRpc {
                 function_name,
                 function_params,
                 account_id,
+                call_chain,
+            )
+            .await?)
+    }
+
+    async fn invoke_and_await_ephemeral(
+        &self,
+        component_id: &ComponentId,
+        function_name: String,
+        function_params: Vec<WitValue>,
+        account_id: &AccountId,
+        call_chain: Vec<WorkerId>,
+    ) -> Result<WitValue, RpcError> {
+        Ok(self
+            .worker_proxy
+            .invoke_and_await_ephemeral(
+                component_id,
+                function_name,
+                function_params,
+                account_id,
+                call_chain,
             )
             .await?)
     }
@@ -234,8 +303,12 @@ pub struct DirectWorkerInvocationRpc<Ctx: WorkerCtx> {
     oplog_service: Arc<dyn oplog::OplogService + Send + Sync>,
     recovery_management: Arc<Mutex<Option<Arc<dyn recovery::RecoveryManagement + Send + Sync>>>>,
     scheduler_service: Arc<dyn scheduler::SchedulerService + Send + Sync>,
+    topic_service: Arc<dyn topic::TopicService + Send + Sync>,
+    mailbox_service: Arc<dyn mailbox::MailboxService + Send + Sync>,
     worker_activator: Arc<dyn worker_activator::WorkerActivator + Send + Sync>,
     events: Arc<Events>,
+    worker_event_log_service: Arc<dyn worker_event_log::WorkerEventLogService + Send + Sync>,
+    clock: Arc<dyn Clock + Send + Sync>,
     extra_deps: Ctx::ExtraDeps,
 }
 
@@ -260,8 +333,12 @@ impl<Ctx: WorkerCtx> Clone for DirectWorkerInvocationRpc<Ctx> {
             oplog_service: self.oplog_service.clone(),
             recovery_management: self.recovery_management.clone(),
             scheduler_service: self.scheduler_service.clone(),
+            topic_service: self.topic_service.clone(),
+            mailbox_service: self.mailbox_service.clone(),
             worker_activator: self.worker_activator.clone(),
             events: self.events.clone(),
+            worker_event_log_service: self.worker_event_log_service.clone(),
+            clock: self.clock.clone(),
             extra_deps: self.extra_deps.clone(),
         }
     }
@@ -273,6 +350,20 @@ impl<Ctx: WorkerCtx> HasEvents for DirectWorkerInvocationRpc<Ctx> {
     }
 }
 
+impl<Ctx: WorkerCtx> HasWorkerEventLogService for DirectWorkerInvocationRpc<Ctx> {
+    fn worker_event_log_service(
+        &self,
+    ) -> Arc<dyn worker_event_log::WorkerEventLogService + Send + Sync> {
+        self.worker_event_log_service.clone()
+    }
+}
+
+impl<Ctx: WorkerCtx> HasClock for DirectWorkerInvocationRpc<Ctx> {
+    fn clock(&self) -> Arc<dyn Clock + Send + Sync> {
+        self.clock.clone()
+    }
+}
+
 impl<Ctx: WorkerCtx> HasActiveWorkers<Ctx> for DirectWorkerInvocationRpc<Ctx> {
     fn active_workers(&self) -> Arc<active_workers::ActiveWorkers<Ctx>> {
         self.active_workers.clone()
@@ -351,6 +442,18 @@ impl<Ctx: WorkerCtx> HasSchedulerService for DirectWorkerInvocationRpc<Ctx> {
     }
 }
 
+impl<Ctx: WorkerCtx> HasTopicService for DirectWorkerInvocationRpc<Ctx> {
+    fn topic_service(&self) -> Arc<dyn topic::TopicService + Send + Sync> {
+        self.topic_service.clone()
+    }
+}
+
+impl<Ctx: WorkerCtx> HasMailboxService for DirectWorkerInvocationRpc<Ctx> {
+    fn mailbox_service(&self) -> Arc<dyn mailbox::MailboxService + Send + Sync> {
+        self.mailbox_service.clone()
+    }
+}
+
 impl<Ctx: WorkerCtx> HasOplogService for DirectWorkerInvocationRpc<Ctx> {
     fn oplog_service(&self) -> Arc<dyn oplog::OplogService + Send + Sync> {
         self.oplog_service.clone()
@@ -386,6 +489,12 @@ impl<Ctx: WorkerCtx> HasShardService for DirectWorkerInvocationRpc<Ctx> {
     }
 }
 
+impl<Ctx: WorkerCtx> HasShardManagerService for DirectWorkerInvocationRpc<Ctx> {
+    fn shard_manager_service(&self) -> Arc<dyn shard_manager::ShardManagerService + Send + Sync> {
+        self.shard_manager_service.clone()
+    }
+}
+
 impl<Ctx: WorkerCtx> HasWorkerActivator for DirectWorkerInvocationRpc<Ctx> {
     fn worker_activator(&self) -> Arc<dyn worker_activator::WorkerActivator + Send + Sync> {
         self.worker_activator.clone()
@@ -421,8 +530,12 @@ impl<Ctx: WorkerCtx> DirectWorkerInvocationRpc<Ctx> {
         blob_store_service: Arc<dyn blob_store::BlobStoreService + Send + Sync>,
         oplog_service: Arc<dyn oplog::OplogService + Send + Sync>,
         scheduler_service: Arc<dyn scheduler::SchedulerService + Send + Sync>,
+        topic_service: Arc<dyn topic::TopicService + Send + Sync>,
+        mailbox_service: Arc<dyn mailbox::MailboxService + Send + Sync>,
         worker_activator: Arc<dyn worker_activator::WorkerActivator + Send + Sync>,
         events: Arc<Events>,
+        worker_event_log_service: Arc<dyn worker_event_log::WorkerEventLogService + Send + Sync>,
+        clock: Arc<dyn Clock + Send + Sync>,
         extra_deps: Ctx::ExtraDeps,
     ) -> Self {
         Self {
@@ -444,8 +557,12 @@ impl<Ctx: WorkerCtx> DirectWorkerInvocationRpc<Ctx> {
             oplog_service,
             recovery_management: Arc::new(Mutex::new(None)),
             scheduler_service,
+            topic_service,
+            mailbox_service,
             worker_activator,
             events,
+            worker_event_log_service,
+            clock,
             extra_deps,
         }
     }
@@ -472,7 +589,10 @@ impl<Ctx: WorkerCtx> Rpc for DirectWorkerInvocationRpc<Ctx> {
         function_name: String,
         function_params: Vec<WitValue>,
         account_id: &AccountId,
+        call_chain: Vec<WorkerId>,
     ) -> Result<WitValue, RpcError> {
+        RpcError::check_call_chain(worker_id, &call_chain)?;
+
         let idempotency_key = idempotency_key.unwrap_or(IdempotencyKey::fresh());
 
         if self.shard_service().check_worker(worker_id).is_ok() {
@@ -493,6 +613,7 @@ impl<Ctx: WorkerCtx> Rpc for DirectWorkerInvocationRpc<Ctx> {
                 golem_common::model::CallingConvention::Component,
                 function_name,
                 input_values,
+                call_chain,
             )
             .await?;
             Ok(Value::Tuple(result_values).into())
@@ -504,6 +625,54 @@ impl<Ctx: WorkerCtx> Rpc for DirectWorkerInvocationRpc<Ctx> {
                     function_name,
                     function_params,
                     account_id,
+                    call_chain,
+                )
+                .await
+        }
+    }
+
+    async fn invoke_and_await_ephemeral(
+        &self,
+        component_id: &ComponentId,
+        function_name: String,
+        function_params: Vec<WitValue>,
+        account_id: &AccountId,
+        call_chain: Vec<WorkerId>,
+    ) -> Result<WitValue, RpcError> {
+        // Named the same way as the ephemeral workers created by the worker service
+        let worker_id = WorkerId {
+            component_id: component_id.clone(),
+            worker_name: format!("ephemeral-{}", Uuid::new_v4()),
+        };
+
+        if self.shard_service().check_worker(&worker_id).is_ok() {
+            debug!("Invoking local ephemeral worker {worker_id} function {function_name} with parameters {function_params:?}");
+
+            let input_values = function_params
+                .into_iter()
+                .map(|wit_value| wit_value.into())
+                .collect();
+
+            let result_values = invoke_and_await_ephemeral(
+                self,
+                &worker_id,
+                account_id.clone(),
+                golem_common::model::CallingConvention::Component,
+                function_name,
+                input_values,
+                call_chain,
+            )
+            .await?;
+            Ok(Value::Tuple(result_values).into())
+        } else {
+            // The worker service generates its own id and routes it to the right shard
+            self.remote_rpc
+                .invoke_and_await_ephemeral(
+                    component_id,
+                    function_name,
+                    function_params,
+                    account_id,
+                    call_chain,
                 )
                 .await
         }
@@ -537,6 +706,7 @@ impl<Ctx: WorkerCtx> Rpc for DirectWorkerInvocationRpc<Ctx> {
                 golem_common::model::CallingConvention::Component,
                 function_name,
                 input_values,
+                vec![],
             )
             .await?;
             Ok(())
@@ -587,6 +757,18 @@ impl Rpc for RpcMock {
         _function_name: String,
         _function_params: Vec<WitValue>,
         _account_id: &AccountId,
+        _call_chain: Vec<WorkerId>,
+    ) -> Result<WitValue, RpcError> {
+        unimplemented!()
+    }
+
+    async fn invoke_and_await_ephemeral(
+        &self,
+        _component_id: &ComponentId,
+        _function_name: String,
+        _function_params: Vec<WitValue>,
+        _account_id: &AccountId,
+        _call_chain: Vec<WorkerId>,
     ) -> Result<WitValue, RpcError> {
         unimplemented!()
     }
@@ -602,3 +784,44 @@ impl Rpc for RpcMock {
         unimplemented!()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use golem_common::model::{ComponentId, WorkerId};
+    use uuid::Uuid;
+
+    use crate::services::rpc::RpcError;
+
+    fn worker_id(component_id: &ComponentId, worker_name: &str) -> WorkerId {
+        WorkerId {
+            component_id: component_id.clone(),
+            worker_name: worker_name.to_string(),
+        }
+    }
+
+    #[test]
+    pub fn call_chain_without_target_is_accepted() {
+        let component_id = ComponentId(Uuid::new_v4());
+        let w1 = worker_id(&component_id, "w1");
+        let w2 = worker_id(&component_id, "w2");
+        let w3 = worker_id(&component_id, "w3");
+
+        assert!(RpcError::check_call_chain(&w3, &[]).is_ok());
+        assert!(RpcError::check_call_chain(&w3, &[w1, w2]).is_ok());
+    }
+
+    #[test]
+    pub fn call_chain_with_target_is_a_deadlock() {
+        let component_id = ComponentId(Uuid::new_v4());
+        let w1 = worker_id(&component_id, "w1");
+        let w2 = worker_id(&component_id, "w2");
+
+        let result = RpcError::check_call_chain(&w1, &[w1.clone(), w2.clone()]);
+        match result {
+            Err(RpcError::Deadlock { call_chain }) => {
+                assert_eq!(call_chain, vec![w1.clone(), w2, w1])
+            }
+            _ => panic!("Expected a deadlock, got {result:?}"),
+        }
+    }
+}
@@ -12,18 +12,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::min;
 use std::collections::HashSet;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
-use crate::metrics::promises::record_scheduled_promise_completed;
+use crate::error::GolemError;
+use crate::metrics::promises::{
+    record_scheduled_promise_completed, record_scheduled_promise_timed_out,
+};
 use crate::services::promise::PromiseService;
 use crate::services::shard::ShardService;
 use crate::services::worker_activator::WorkerActivator;
 use async_trait::async_trait;
 use bytes::Bytes;
 use chrono::{DateTime, TimeZone, Utc};
-use golem_common::model::{PromiseId, ScheduleId};
+use golem_common::clock::Clock;
+use golem_common::model::{PromiseId, ScheduleId, ScheduledAction, WorkerId};
 use golem_common::redis::RedisPool;
 use tokio::task::JoinHandle;
 use tracing::error;
@@ -32,6 +37,10 @@ use tracing::error;
 pub trait SchedulerService {
     async fn schedule(&self, time: DateTime<Utc>, promise_id: PromiseId) -> ScheduleId;
 
+    /// Schedules failing the given promise with a timeout at `time`, unless it gets completed
+    /// before that
+    async fn schedule_timeout(&self, time: DateTime<Utc>, promise_id: PromiseId) -> ScheduleId;
+
     async fn cancel(&self, id: ScheduleId);
 }
 
@@ -42,6 +51,8 @@ pub struct SchedulerServiceDefault {
     shard_service: Arc<dyn ShardService + Send + Sync>,
     promise_service: Arc<dyn PromiseService + Send + Sync>,
     worker_activator: Arc<dyn WorkerActivator + Send + Sync>,
+    clock: Arc<dyn Clock + Send + Sync>,
+    last_processed_hour: Arc<Mutex<Option<i64>>>,
 }
 
 const HOUR_IN_MILLIS: i64 = 1000 * 60 * 60;
@@ -59,6 +70,7 @@ impl SchedulerServiceDefault {
         shard_service: Arc<dyn ShardService + Send + Sync>,
         promise_service: Arc<dyn PromiseService + Send + Sync>,
         worker_activator: Arc<dyn WorkerActivator + Send + Sync>,
+        clock: Arc<dyn Clock + Send + Sync>,
         process_interval: Duration,
     ) -> Arc<Self> {
         let svc = Self {
@@ -67,13 +79,29 @@ impl SchedulerServiceDefault {
             shard_service,
             promise_service,
             worker_activator,
+            clock,
+            last_processed_hour: Arc::new(Mutex::new(None)),
         };
         let svc = Arc::new(svc);
         let svc_clone = svc.clone();
         let background_handle = tokio::spawn(async move {
+            let mut clock_changes = svc_clone.clock.changes();
             loop {
-                tokio::time::sleep(process_interval).await;
-                let r = svc_clone.process(Utc::now()).await;
+                match &mut clock_changes {
+                    Some(changes) => {
+                        // A clock moved explicitly makes the due schedules run right away
+                        let closed = tokio::select! {
+                            _ = tokio::time::sleep(process_interval) => false,
+                            result = changes.changed() => result.is_err(),
+                        };
+                        if closed {
+                            clock_changes = None;
+                        }
+                    }
+                    None => tokio::time::sleep(process_interval).await,
+                }
+                let now = svc_clone.clock.now().into();
+                let r = svc_clone.process(now).await;
                 if let Err(err) = r {
                     error!("Error in scheduler background task: {}", err);
                 }
@@ -86,29 +114,66 @@ impl SchedulerServiceDefault {
 
     async fn process(&self, now: DateTime<Utc>) -> Result<(), String> {
         let (hours_since_epoch, remainder) = split_time(now);
-        let previous_hours_since_epoch = hours_since_epoch - 1;
 
-        let previous_hour_key = get_schedule_redis_key_from_timestamp(previous_hours_since_epoch);
-        let current_hour_key = get_schedule_redis_key_from_timestamp(hours_since_epoch);
+        // Normally only the previous hour needs to be checked, but when the clock jumps forward
+        // (for example a virtual clock used in tests) every hour since the last run is processed.
+        let first_past_hour = match *self.last_processed_hour.lock().unwrap() {
+            Some(last_processed_hour) => min(last_processed_hour, hours_since_epoch - 1),
+            None => hours_since_epoch - 1,
+        };
 
-        let all_from_prev_hour_raw: Vec<Bytes> = self
-            .redis
-            .with("scheduler", "process")
-            .zrange(&previous_hour_key, 0, -1, None, false, None, false)
-            .await
-            .map_err(|redis_err| format!("{redis_err}"))?;
+        let mut worker_ids = HashSet::new();
+        for action in [
+            ScheduledAction::CompletePromise,
+            ScheduledAction::TimeoutPromise,
+        ] {
+            worker_ids.extend(
+                self.process_action(action, first_past_hour, hours_since_epoch, remainder)
+                    .await?,
+            );
+        }
 
-        let all_from_prev_hour: Vec<(&str, PromiseId)> = all_from_prev_hour_raw
-            .iter()
-            .map(|serialized| {
+        for worker_id in worker_ids {
+            self.worker_activator.activate_worker(&worker_id).await;
+        }
+
+        *self.last_processed_hour.lock().unwrap() = Some(hours_since_epoch);
+
+        Ok(())
+    }
+
+    /// Performs `action` on all the due promises belonging to this executor, returning the
+    /// workers to activate
+    async fn process_action(
+        &self,
+        action: ScheduledAction,
+        first_past_hour: i64,
+        hours_since_epoch: i64,
+        remainder: f64,
+    ) -> Result<HashSet<WorkerId>, String> {
+        let past_hour_keys: Vec<String> = (first_past_hour..hours_since_epoch)
+            .map(|timestamp| get_schedule_redis_key_from_timestamp(action, timestamp))
+            .collect();
+        let current_hour_key = get_schedule_redis_key_from_timestamp(action, hours_since_epoch);
+
+        let mut all_from_past_hours: Vec<(&str, PromiseId)> = Vec::new();
+        for past_hour_key in &past_hour_keys {
+            let all_from_hour_raw: Vec<Bytes> = self
+                .redis
+                .with("scheduler", "process")
+                .zrange(past_hour_key, 0, -1, None, false, None, false)
+                .await
+                .map_err(|redis_err| format!("{redis_err}"))?;
+
+            all_from_past_hours.extend(all_from_hour_raw.iter().map(|serialized| {
                 (
-                    previous_hour_key.as_str(),
+                    past_hour_key.as_str(),
                     self.redis
                         .deserialize(serialized)
                         .expect("failed to deserialize worker id"),
                 )
-            })
-            .collect();
+            }));
+        }
 
         let all_from_this_hour_raw: Vec<Bytes> = self
             .redis
@@ -129,7 +194,7 @@ impl SchedulerServiceDefault {
             })
             .collect();
 
-        let mut all = all_from_prev_hour;
+        let mut all = all_from_past_hours;
         all.append(&mut all_from_this_hour);
         let matching: Vec<(&str, PromiseId)> = all
             .into_iter()
@@ -153,37 +218,41 @@ impl SchedulerServiceDefault {
                 )
                 .await
                 .map_err(|redis_err| format!("{redis_err}"))?;
-            self.promise_service
-                .complete(promise_id, vec![])
-                .await
-                .map_err(|golem_err| format!("{golem_err}"))?;
 
-            record_scheduled_promise_completed();
-        }
+            match action {
+                ScheduledAction::CompletePromise => {
+                    self.promise_service
+                        .complete(promise_id, vec![])
+                        .await
+                        .map_err(|golem_err| format!("{golem_err}"))?;
 
-        for worker_id in worker_ids {
-            self.worker_activator.activate_worker(&worker_id).await;
+                    record_scheduled_promise_completed();
+                }
+                ScheduledAction::TimeoutPromise => {
+                    // Timeouts are not cancelled when the promise gets completed or deleted
+                    match self.promise_service.time_out(promise_id).await {
+                        Ok(true) => record_scheduled_promise_timed_out(),
+                        Ok(false) | Err(GolemError::PromiseNotFound { .. }) => {}
+                        Err(golem_err) => return Err(format!("{golem_err}")),
+                    }
+                }
+            }
         }
 
-        Ok(())
+        Ok(worker_ids)
     }
-}
 
-impl Drop for SchedulerServiceDefault {
-    fn drop(&mut self) {
-        if let Some(handle) = self.background_handle.lock().unwrap().take() {
-            handle.abort();
-        }
-    }
-}
-
-#[async_trait]
-impl SchedulerService for SchedulerServiceDefault {
-    async fn schedule(&self, time: DateTime<Utc>, promise_id: PromiseId) -> ScheduleId {
+    async fn add(
+        &self,
+        time: DateTime<Utc>,
+        promise_id: PromiseId,
+        action: ScheduledAction,
+    ) -> ScheduleId {
         let (hours_since_epoch, remainder) = split_time(time);
         let id = ScheduleId {
             timestamp: hours_since_epoch,
             promise_id: promise_id.clone(),
+            action,
         };
         let key = get_schedule_redis_key(&id);
         let value = self
@@ -202,6 +271,27 @@ impl SchedulerService for SchedulerServiceDefault {
 
         id
     }
+}
+
+impl Drop for SchedulerServiceDefault {
+    fn drop(&mut self) {
+        if let Some(handle) = self.background_handle.lock().unwrap().take() {
+            handle.abort();
+        }
+    }
+}
+
+#[async_trait]
+impl SchedulerService for SchedulerServiceDefault {
+    async fn schedule(&self, time: DateTime<Utc>, promise_id: PromiseId) -> ScheduleId {
+        self.add(time, promise_id, ScheduledAction::CompletePromise)
+            .await
+    }
+
+    async fn schedule_timeout(&self, time: DateTime<Utc>, promise_id: PromiseId) -> ScheduleId {
+        self.add(time, promise_id, ScheduledAction::TimeoutPromise)
+            .await
+    }
 
     async fn cancel(&self, id: ScheduleId) {
         let key = get_schedule_redis_key(&id);
@@ -224,11 +314,15 @@ impl SchedulerService for SchedulerServiceDefault {
 }
 
 fn get_schedule_redis_key(id: &ScheduleId) -> String {
-    get_schedule_redis_key_from_timestamp(id.timestamp)
+    get_schedule_redis_key_from_timestamp(id.action, id.timestamp)
 }
 
-fn get_schedule_redis_key_from_timestamp(timestamp: i64) -> String {
-    format!("instance:schedule:{}", timestamp)
+fn get_schedule_redis_key_from_timestamp(action: ScheduledAction, timestamp: i64) -> String {
+    // Timeouts are kept in separate sets so the entries of sleeping workers keep their format
+    match action {
+        ScheduledAction::CompletePromise => format!("instance:schedule:{}", timestamp),
+        ScheduledAction::TimeoutPromise => format!("instance:schedule:timeout:{}", timestamp),
+    }
 }
 
 #[cfg(any(feature = "mocks", test))]
@@ -255,6 +349,10 @@ impl SchedulerService for SchedulerServiceMock {
         unimplemented!()
     }
 
+    async fn schedule_timeout(&self, _time: DateTime<Utc>, _promise_id: PromiseId) -> ScheduleId {
+        unimplemented!()
+    }
+
     async fn cancel(&self, _id: ScheduleId) {
         unimplemented!()
     }
@@ -277,6 +375,7 @@ mod tests {
     use fred::error::RedisError;
     use fred::mocks::{MockCommand, Mocks};
     use fred::prelude::RedisValue;
+    use golem_common::clock::{SystemClock, VirtualClock};
     use golem_common::model::{ComponentId, PromiseId, WorkerId};
     use golem_common::redis::RedisPool;
     use uuid::Uuid;
@@ -449,6 +548,7 @@ mod tests {
             shard_service,
             promise_service,
             worker_activator,
+            Arc::new(SystemClock::new()),
             Duration::from_secs(1000), // not testing process() here
         );
 
@@ -531,6 +631,7 @@ mod tests {
             shard_service,
             promise_service,
             worker_activator,
+            Arc::new(SystemClock::new()),
             Duration::from_secs(1000), // not testing process() here
         );
 
@@ -656,6 +757,7 @@ mod tests {
             shard_service,
             promise_service.clone(),
             worker_activator,
+            Arc::new(SystemClock::new()),
             Duration::from_secs(1000), // explicitly calling process for testing
         );
 
@@ -694,6 +796,24 @@ mod tests {
                 MockCommand { cmd: "ZRANGEBYSCORE".into(), subcommand: None, args: vec!["instance:schedule:469330".as_bytes().into(), 0.0.into(), 900000.0.into()] },
                 MockCommand { cmd: "ZREM".into(), subcommand: None, args: vec!["instance:schedule:469330".as_bytes().into(), serialized_data(&PromiseId::from_json_string(&format!("{{\"instance_id\":{{\"component_id\":\"{uuid}\",\"worker_name\":\"inst1\"}},\"oplog_idx\":101}}")))] },
                 MockCommand { cmd: "ZREM".into(), subcommand: None, args: vec!["instance:schedule:469330".as_bytes().into(), serialized_data(&PromiseId::from_json_string(&format!("{{\"instance_id\":{{\"component_id\":\"{uuid}\",\"worker_name\":\"inst2\"}},\"oplog_idx\":1000}}")))] },
+                MockCommand {
+                    cmd: "ZRANGE".into(),
+                    subcommand: None,
+                    args: vec![
+                        "instance:schedule:timeout:469329".as_bytes().into(),
+                        0.into(),
+                        (-1).into(),
+                    ],
+                },
+                MockCommand {
+                    cmd: "ZRANGEBYSCORE".into(),
+                    subcommand: None,
+                    args: vec![
+                        "instance:schedule:timeout:469330".as_bytes().into(),
+                        0.0.into(),
+                        900000.0.into(),
+                    ],
+                },
             ]
         );
 
@@ -752,6 +872,7 @@ mod tests {
             shard_service,
             promise_service.clone(),
             worker_activator,
+            Arc::new(SystemClock::new()),
             Duration::from_secs(1000), // explicitly calling process for testing
         );
 
@@ -852,6 +973,24 @@ mod tests {
                         serialized_data(&p3),
                     ],
                 },
+                MockCommand {
+                    cmd: "ZRANGE".into(),
+                    subcommand: None,
+                    args: vec![
+                        "instance:schedule:timeout:469329".as_bytes().into(),
+                        0.into(),
+                        (-1).into(),
+                    ],
+                },
+                MockCommand {
+                    cmd: "ZRANGEBYSCORE".into(),
+                    subcommand: None,
+                    args: vec![
+                        "instance:schedule:timeout:469330".as_bytes().into(),
+                        0.0.into(),
+                        900000.0.into(),
+                    ],
+                },
             ]
         );
 
@@ -872,6 +1011,78 @@ mod tests {
         assert!(completed_promises.contains(&p2));
     }
 
+    #[tokio::test]
+    pub async fn process_catches_up_on_skipped_hours() {
+        let c1: ComponentId = ComponentId(Uuid::new_v4());
+        let i1: WorkerId = WorkerId {
+            component_id: c1.clone(),
+            worker_name: "inst1".to_string(),
+        };
+
+        let p1: PromiseId = PromiseId {
+            worker_id: i1.clone(),
+            oplog_idx: 101,
+        };
+        let p2: PromiseId = PromiseId {
+            worker_id: i1.clone(),
+            oplog_idx: 123,
+        };
+        let p3: PromiseId = PromiseId {
+            worker_id: i1.clone(),
+            oplog_idx: 1000,
+        };
+
+        let buffer = Arc::new(RedisMock::new());
+        let pool = mocked(buffer.clone()).await;
+
+        let shard_service = Arc::new(ShardServiceMock::new());
+        let promise_service = Arc::new(PromiseServiceMock::new());
+        let worker_activator = Arc::new(WorkerActivatorMock::new());
+
+        let svc = SchedulerServiceDefault::new(
+            pool,
+            shard_service,
+            promise_service.clone(),
+            worker_activator,
+            Arc::new(SystemClock::new()),
+            Duration::from_secs(1000), // explicitly calling process for testing
+        );
+
+        let _s1 = svc
+            .schedule(
+                DateTime::from_str("2023-07-17T12:10:00Z").unwrap(),
+                p1.clone(),
+            )
+            .await;
+        let _s2 = svc
+            .schedule(
+                DateTime::from_str("2023-07-17T15:20:00Z").unwrap(),
+                p2.clone(),
+            )
+            .await;
+        let _s3 = svc
+            .schedule(
+                DateTime::from_str("2023-07-17T15:40:00Z").unwrap(),
+                p3.clone(),
+            )
+            .await;
+
+        svc.process(DateTime::from_str("2023-07-17T10:15:00Z").unwrap())
+            .await
+            .unwrap();
+
+        // The clock jumps forward by more than an hour between two runs
+        svc.process(DateTime::from_str("2023-07-17T15:30:00Z").unwrap())
+            .await
+            .unwrap();
+
+        let completed_promises = promise_service.all_completed().await;
+
+        assert!(completed_promises.contains(&p1));
+        assert!(completed_promises.contains(&p2));
+        assert!(!completed_promises.contains(&p3));
+    }
+
     #[tokio::test]
     pub async fn process_past_and_current_hours_past_schedules_2() {
         let c1: ComponentId = ComponentId(Uuid::new_v4());
@@ -913,6 +1124,7 @@ mod tests {
             shard_service,
             promise_service.clone(),
             worker_activator,
+            Arc::new(SystemClock::new()),
             Duration::from_secs(1000), // explicitly calling process for testing
         );
 
@@ -1036,6 +1248,24 @@ mod tests {
                         serialized_data(&p3),
                     ],
                 },
+                MockCommand {
+                    cmd: "ZRANGE".into(),
+                    subcommand: None,
+                    args: vec![
+                        "instance:schedule:timeout:469329".as_bytes().into(),
+                        0.into(),
+                        (-1).into(),
+                    ],
+                },
+                MockCommand {
+                    cmd: "ZRANGEBYSCORE".into(),
+                    subcommand: None,
+                    args: vec![
+                        "instance:schedule:timeout:469330".as_bytes().into(),
+                        0.0.into(),
+                        900000.0.into(),
+                    ],
+                },
             ]
         );
 
@@ -1093,6 +1323,7 @@ mod tests {
             shard_service,
             promise_service.clone(),
             worker_activator,
+            Arc::new(SystemClock::new()),
             Duration::from_secs(1000), // explicitly calling process for testing
         );
 
@@ -1193,6 +1424,24 @@ mod tests {
                         serialized_data(&p1),
                     ],
                 },
+                MockCommand {
+                    cmd: "ZRANGE".into(),
+                    subcommand: None,
+                    args: vec![
+                        "instance:schedule:timeout:469329".as_bytes().into(),
+                        0.into(),
+                        (-1).into(),
+                    ],
+                },
+                MockCommand {
+                    cmd: "ZRANGEBYSCORE".into(),
+                    subcommand: None,
+                    args: vec![
+                        "instance:schedule:timeout:469330".as_bytes().into(),
+                        0.0.into(),
+                        900000.0.into(),
+                    ],
+                },
             ]
         );
 
@@ -1212,4 +1461,140 @@ mod tests {
         assert!(completed_promises.contains(&p3));
         assert!(completed_promises.contains(&p2));
     }
+
+    #[tokio::test]
+    pub async fn process_times_out_promises() {
+        let c1: ComponentId = ComponentId(Uuid::new_v4());
+        let i1: WorkerId = WorkerId {
+            component_id: c1.clone(),
+            worker_name: "inst1".to_string(),
+        };
+
+        let p1: PromiseId = PromiseId {
+            worker_id: i1.clone(),
+            oplog_idx: 101,
+        };
+        let p2: PromiseId = PromiseId {
+            worker_id: i1.clone(),
+            oplog_idx: 123,
+        };
+        let p3: PromiseId = PromiseId {
+            worker_id: i1.clone(),
+            oplog_idx: 1000,
+        };
+
+        let buffer = Arc::new(RedisMock::new());
+        let pool = mocked(buffer.clone()).await;
+
+        let shard_service = Arc::new(ShardServiceMock::new());
+        let promise_service = Arc::new(PromiseServiceMock::new());
+        let worker_activator = Arc::new(WorkerActivatorMock::new());
+
+        let svc = SchedulerServiceDefault::new(
+            pool,
+            shard_service,
+            promise_service.clone(),
+            worker_activator,
+            Arc::new(SystemClock::new()),
+            Duration::from_secs(1000), // explicitly calling process for testing
+        );
+
+        let _s1 = svc
+            .schedule(
+                DateTime::from_str("2023-07-17T10:05:00Z").unwrap(),
+                p1.clone(),
+            )
+            .await;
+        let _s2 = svc
+            .schedule_timeout(
+                DateTime::from_str("2023-07-17T10:10:00Z").unwrap(),
+                p2.clone(),
+            )
+            .await;
+        let _s3 = svc
+            .schedule_timeout(
+                DateTime::from_str("2023-07-17T10:30:00Z").unwrap(),
+                p3.clone(),
+            )
+            .await;
+
+        svc.process(DateTime::from_str("2023-07-17T10:15:00Z").unwrap())
+            .await
+            .unwrap();
+
+        let result = buffer.result();
+        // The only item remaining is the timeout in the future
+        assert_eq!(
+            result,
+            HashMap::from([
+                ("instance:schedule:469330".to_string(), vec![]),
+                (
+                    "instance:schedule:timeout:469330".to_string(),
+                    vec![(1800000.0, serialized_bytes(&p3))]
+                )
+            ])
+        );
+
+        let completed_promises = promise_service.all_completed().await;
+        let timed_out_promises = promise_service.all_timed_out().await;
+
+        assert!(completed_promises.contains(&p1));
+        assert!(!completed_promises.contains(&p2));
+        assert!(timed_out_promises.contains(&p2));
+        assert!(!timed_out_promises.contains(&p1));
+        assert!(!timed_out_promises.contains(&p3));
+    }
+
+    #[tokio::test]
+    pub async fn advancing_the_clock_processes_due_schedules() {
+        let c1: ComponentId = ComponentId(Uuid::new_v4());
+        let i1: WorkerId = WorkerId {
+            component_id: c1.clone(),
+            worker_name: "inst1".to_string(),
+        };
+
+        let p1: PromiseId = PromiseId {
+            worker_id: i1.clone(),
+            oplog_idx: 101,
+        };
+
+        let buffer = Arc::new(RedisMock::new());
+        let pool = mocked(buffer.clone()).await;
+
+        let shard_service = Arc::new(ShardServiceMock::new());
+        let promise_service = Arc::new(PromiseServiceMock::new());
+        let worker_activator = Arc::new(WorkerActivatorMock::new());
+        let clock = Arc::new(VirtualClock::starting_at(
+            DateTime::<chrono::Utc>::from_str("2023-07-17T10:00:00Z")
+                .unwrap()
+                .into(),
+        ));
+
+        let svc = SchedulerServiceDefault::new(
+            pool,
+            shard_service,
+            promise_service.clone(),
+            worker_activator,
+            clock.clone(),
+            Duration::from_secs(1000), // only the clock change can trigger processing
+        );
+
+        let _s1 = svc
+            .schedule(
+                DateTime::from_str("2023-07-17T10:05:00Z").unwrap(),
+                p1.clone(),
+            )
+            .await;
+
+        clock.advance(Duration::from_secs(600));
+
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(5);
+        while !promise_service.all_completed().await.contains(&p1)
+            && tokio::time::Instant::now() < deadline
+        {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        assert!(promise_service.all_completed().await.contains(&p1));
+    }
 }
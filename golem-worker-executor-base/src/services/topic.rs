@@ -0,0 +1,648 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::services::shard::ShardService;
+use crate::services::worker_proxy::WorkerProxy;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use bincode::{Decode, Encode};
+use bytes::Bytes;
+use fred::prelude::RedisValue;
+use fred::types::{XCapKind, XCapTrim};
+use golem_common::model::{AccountId, ComponentId, IdempotencyKey, ShardId, WorkerId};
+use golem_common::redis::RedisPool;
+use golem_wasm_rpc::WitValue;
+use tokio::task::JoinHandle;
+use tracing::{debug, error, warn};
+
+/// Service implementing durable publish/subscribe topics
+///
+/// Messages are appended to a Redis stream per topic, and delivered to every subscription by
+/// invoking the subscribed function with the message parameters. Each executor only delivers
+/// to the subscriptions belonging to its shards. Delivery is at-least-once; invocations of a
+/// worker get an idempotency key derived from the message and the subscription, so repeated
+/// deliveries of the same message are not executed twice. Deliveries to a component run on a
+/// fresh ephemeral worker each time and are not deduplicated.
+///
+/// Topics are scoped by account: the same topic name used from two accounts names two
+/// independent topics.
+#[async_trait]
+pub trait TopicService {
+    /// Publishes a message. Messages published to a topic without subscriptions are dropped.
+    async fn publish(
+        &self,
+        account_id: &AccountId,
+        topic: &str,
+        message: TopicMessage,
+    ) -> anyhow::Result<()>;
+
+    /// Registers a subscription to the topic of the subscription's account. Only the messages
+    /// published after this call are delivered to it.
+    async fn subscribe(&self, topic: &str, subscription: TopicSubscription) -> anyhow::Result<()>;
+
+    /// Removes a subscription of the given account, deleting the topic's stream together with
+    /// its last subscription
+    async fn unsubscribe(
+        &self,
+        account_id: &AccountId,
+        topic: &str,
+        subscription_id: &str,
+    ) -> anyhow::Result<()>;
+}
+
+/// A message published to a topic
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct TopicMessage {
+    pub message_id: String,
+    pub params: Vec<WitValue>,
+}
+
+/// What gets invoked with the messages of a subscription
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub enum SubscriptionTarget {
+    /// A function of a specific worker
+    Worker(WorkerId),
+    /// A function of the latest version of a component, invoked on a fresh ephemeral worker
+    /// for every message
+    Component(ComponentId),
+}
+
+impl Display for SubscriptionTarget {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubscriptionTarget::Worker(worker_id) => write!(f, "worker {worker_id}"),
+            SubscriptionTarget::Component(component_id) => write!(f, "component {component_id}"),
+        }
+    }
+}
+
+/// A function invoked with the parameters of every message published to a topic
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct TopicSubscription {
+    pub subscription_id: String,
+    pub target: SubscriptionTarget,
+    pub account_id: AccountId,
+    pub function_name: String,
+}
+
+impl TopicSubscription {
+    /// The idempotency key of the delivery of a message to this subscription
+    pub fn delivery_idempotency_key(&self, message_id: &str) -> IdempotencyKey {
+        IdempotencyKey::new(format!("{message_id}/{}", self.subscription_id))
+    }
+
+    /// The shard whose executor delivers the messages of this subscription. Subscriptions of a
+    /// worker belong to the worker's shard, component subscriptions are spread over the shards
+    /// by their id.
+    pub fn shard_id(&self, number_of_shards: usize) -> ShardId {
+        match &self.target {
+            SubscriptionTarget::Worker(worker_id) => {
+                ShardId::from_worker_id(worker_id, number_of_shards)
+            }
+            SubscriptionTarget::Component(component_id) => ShardId::from_worker_id(
+                &WorkerId {
+                    component_id: component_id.clone(),
+                    worker_name: self.subscription_id.clone(),
+                },
+                number_of_shards,
+            ),
+        }
+    }
+}
+
+/// Entry of the per-shard subscription index
+#[derive(Debug, Clone, Encode, Decode)]
+struct ShardSubscription {
+    topic: String,
+    subscription: TopicSubscription,
+}
+
+#[derive(Clone)]
+pub struct TopicServiceRedis {
+    redis: RedisPool,
+    background_handle: Arc<Mutex<Option<JoinHandle<()>>>>,
+    shard_service: Arc<dyn ShardService + Send + Sync>,
+    worker_proxy: Arc<dyn WorkerProxy + Send + Sync>,
+    max_messages_per_delivery: u64,
+}
+
+/// Appends a message to the stream only if the topic has subscriptions, so a publish racing
+/// with the removal of the last subscription cannot leave an orphaned stream behind
+const PUBLISH_SCRIPT: &str = r#"
+if redis.call('EXISTS', KEYS[1]) == 1 then
+  return redis.call('XADD', KEYS[2], '*', 'message', ARGV[1])
+end
+return false
+"#;
+
+/// Deletes the stream and the cursors of a topic if it has no subscriptions left
+const CLEANUP_SCRIPT: &str = r#"
+if redis.call('EXISTS', KEYS[1]) == 0 then
+  redis.call('DEL', KEYS[2], KEYS[3])
+end
+return 0
+"#;
+
+impl TopicServiceRedis {
+    pub fn new(
+        redis: RedisPool,
+        shard_service: Arc<dyn ShardService + Send + Sync>,
+        worker_proxy: Arc<dyn WorkerProxy + Send + Sync>,
+        delivery_interval: Duration,
+        max_messages_per_delivery: u64,
+    ) -> Arc<Self> {
+        let svc = Self {
+            redis,
+            background_handle: Arc::new(Mutex::new(None)),
+            shard_service,
+            worker_proxy,
+            max_messages_per_delivery,
+        };
+        let svc = Arc::new(svc);
+        let svc_clone = svc.clone();
+        let background_handle = tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(delivery_interval).await;
+                let r = svc_clone.deliver().await;
+                if let Err(err) = r {
+                    error!("Error in topic delivery background task: {}", err);
+                }
+            }
+        });
+        *svc.background_handle.lock().unwrap() = Some(background_handle);
+
+        svc
+    }
+
+    /// Delivers the pending messages of all the subscriptions belonging to the shards assigned
+    /// to this executor
+    async fn deliver(&self) -> anyhow::Result<()> {
+        let shard_assignment = self.shard_service.current_assignment();
+
+        for shard_id in shard_assignment.shard_ids {
+            let subscriptions: HashMap<String, Bytes> = self
+                .redis
+                .with("topic", "deliver")
+                .hgetall(get_shard_subscriptions_redis_key(&shard_id))
+                .await?;
+
+            for serialized in subscriptions.into_values() {
+                let entry: ShardSubscription = self
+                    .redis
+                    .deserialize(&serialized)
+                    .map_err(|err| anyhow!(err))?;
+                self.deliver_to(&entry.topic, &entry.subscription).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Invokes the subscribed function with the messages following the subscription's cursor.
+    /// The cursor is moved after each successful invocation, so a failed delivery is retried
+    /// in the next round, and the messages after it wait until it succeeds.
+    async fn deliver_to(
+        &self,
+        topic: &str,
+        subscription: &TopicSubscription,
+    ) -> anyhow::Result<()> {
+        let cursor: Option<String> = self
+            .redis
+            .with("topic", "deliver")
+            .hget(
+                get_cursors_redis_key(&subscription.account_id, topic),
+                &subscription.subscription_id,
+            )
+            .await?;
+        let start = match cursor {
+            Some(cursor) => format!("({cursor}"),
+            None => "-".to_string(),
+        };
+
+        let results: Vec<HashMap<String, HashMap<String, Bytes>>> = self
+            .redis
+            .with("topic", "deliver")
+            .xrange(
+                get_stream_redis_key(&subscription.account_id, topic),
+                start,
+                "+",
+                Some(self.max_messages_per_delivery),
+            )
+            .await?;
+
+        let mut delivered = false;
+        for result in results {
+            for (stream_id, fields) in result {
+                for (_, value) in fields {
+                    let message: TopicMessage =
+                        self.redis.deserialize(&value).map_err(|err| anyhow!(err))?;
+
+                    debug!(
+                        "Delivering message {} of topic {topic} to {}",
+                        message.message_id, subscription.target
+                    );
+                    let delivery = match &subscription.target {
+                        SubscriptionTarget::Worker(worker_id) => {
+                            self.worker_proxy
+                                .invoke(
+                                    worker_id,
+                                    Some(
+                                        subscription.delivery_idempotency_key(&message.message_id),
+                                    ),
+                                    subscription.function_name.clone(),
+                                    message.params,
+                                    &subscription.account_id,
+                                )
+                                .await
+                        }
+                        SubscriptionTarget::Component(component_id) => self
+                            .worker_proxy
+                            .invoke_and_await_ephemeral(
+                                component_id,
+                                subscription.function_name.clone(),
+                                message.params,
+                                &subscription.account_id,
+                                vec![],
+                            )
+                            .await
+                            .map(|_| ()),
+                    };
+                    if let Err(err) = delivery {
+                        warn!(
+                            "Failed to deliver message {} of topic {topic} to {}, retrying later: {err}",
+                            message.message_id, subscription.target
+                        );
+                        if delivered {
+                            self.trim(&subscription.account_id, topic).await?;
+                        }
+                        return Ok(());
+                    }
+                }
+
+                let _: u32 = self
+                    .redis
+                    .with("topic", "deliver")
+                    .hset(
+                        get_cursors_redis_key(&subscription.account_id, topic),
+                        (subscription.subscription_id.clone(), stream_id),
+                    )
+                    .await?;
+                delivered = true;
+            }
+        }
+
+        if delivered {
+            self.trim(&subscription.account_id, topic).await?;
+        }
+        Ok(())
+    }
+
+    /// Removes the messages already delivered to every subscription of a topic
+    async fn trim(&self, account_id: &AccountId, topic: &str) -> anyhow::Result<()> {
+        let subscription_ids: Vec<String> = self
+            .redis
+            .with("topic", "trim")
+            .hkeys(get_subscriptions_redis_key(account_id, topic))
+            .await?;
+        let cursors: HashMap<String, String> = self
+            .redis
+            .with("topic", "trim")
+            .hgetall(get_cursors_redis_key(account_id, topic))
+            .await?;
+
+        // A subscription without a cursor has not received anything yet
+        let cursor_ids: HashSet<&String> = cursors.keys().collect();
+        if subscription_ids.iter().any(|id| !cursor_ids.contains(id)) {
+            return Ok(());
+        }
+
+        let min_cursor = subscription_ids
+            .iter()
+            .filter_map(|id| cursors.get(id))
+            .filter_map(|cursor| parse_stream_id(cursor))
+            .min();
+
+        if let Some((millis, sequence)) = min_cursor {
+            // MINID keeps the entries with an id not less than the threshold, so the threshold
+            // is the id following the cursor
+            let threshold = match sequence.checked_add(1) {
+                Some(sequence) => format!("{millis}-{sequence}"),
+                None => format!("{}-0", millis + 1),
+            };
+            let _: u64 = self
+                .redis
+                .with("topic", "trim")
+                .xtrim(
+                    get_stream_redis_key(account_id, topic),
+                    (XCapKind::MinID, XCapTrim::Exact, threshold, None),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn last_stream_id(
+        &self,
+        account_id: &AccountId,
+        topic: &str,
+    ) -> anyhow::Result<Option<String>> {
+        let results: Vec<HashMap<String, HashMap<String, Bytes>>> = self
+            .redis
+            .with("topic", "subscribe")
+            .xrevrange(get_stream_redis_key(account_id, topic), "+", "-", Some(1))
+            .await?;
+        Ok(results
+            .into_iter()
+            .flat_map(|result| result.into_keys())
+            .next())
+    }
+}
+
+impl Drop for TopicServiceRedis {
+    fn drop(&mut self) {
+        if let Some(handle) = self.background_handle.lock().unwrap().take() {
+            handle.abort();
+        }
+    }
+}
+
+#[async_trait]
+impl TopicService for TopicServiceRedis {
+    async fn publish(
+        &self,
+        account_id: &AccountId,
+        topic: &str,
+        message: TopicMessage,
+    ) -> anyhow::Result<()> {
+        let value = self.redis.serialize(&message).map_err(|err| anyhow!(err))?;
+        let stream_id: Option<String> = self
+            .redis
+            .with("topic", "publish")
+            .eval(
+                PUBLISH_SCRIPT,
+                vec![
+                    get_subscriptions_redis_key(account_id, topic),
+                    get_stream_redis_key(account_id, topic),
+                ],
+                vec![RedisValue::Bytes(value)],
+            )
+            .await?;
+        if stream_id.is_none() {
+            debug!(
+                "Dropped message {} of topic {topic} without subscriptions",
+                message.message_id
+            );
+        }
+        Ok(())
+    }
+
+    async fn subscribe(&self, topic: &str, subscription: TopicSubscription) -> anyhow::Result<()> {
+        // The cursor is written first, so the stream is never trimmed past a message the new
+        // subscription has not received
+        let cursor = self
+            .last_stream_id(&subscription.account_id, topic)
+            .await?
+            .unwrap_or_else(|| "0-0".to_string());
+        let _: u32 = self
+            .redis
+            .with("topic", "subscribe")
+            .hset(
+                get_cursors_redis_key(&subscription.account_id, topic),
+                (subscription.subscription_id.clone(), cursor),
+            )
+            .await?;
+
+        let shard_id =
+            subscription.shard_id(self.shard_service.current_assignment().number_of_shards);
+        let entry = ShardSubscription {
+            topic: topic.to_string(),
+            subscription: subscription.clone(),
+        };
+        let value = self.redis.serialize(&entry).map_err(|err| anyhow!(err))?;
+        let _: u32 = self
+            .redis
+            .with("topic", "subscribe")
+            .hset(
+                get_shard_subscriptions_redis_key(&shard_id),
+                (subscription.subscription_id.clone(), value),
+            )
+            .await?;
+
+        let value = self
+            .redis
+            .serialize(&subscription)
+            .map_err(|err| anyhow!(err))?;
+        let _: u32 = self
+            .redis
+            .with("topic", "subscribe")
+            .hset(
+                get_subscriptions_redis_key(&subscription.account_id, topic),
+                (subscription.subscription_id, value),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn unsubscribe(
+        &self,
+        account_id: &AccountId,
+        topic: &str,
+        subscription_id: &str,
+    ) -> anyhow::Result<()> {
+        let serialized: Option<Bytes> = self
+            .redis
+            .with("topic", "unsubscribe")
+            .hget(
+                get_subscriptions_redis_key(account_id, topic),
+                subscription_id,
+            )
+            .await?;
+
+        if let Some(serialized) = serialized {
+            let subscription: TopicSubscription = self
+                .redis
+                .deserialize(&serialized)
+                .map_err(|err| anyhow!(err))?;
+            if subscription.account_id != *account_id {
+                return Err(anyhow!(
+                    "Subscription {subscription_id} of topic {topic} does not belong to account {account_id}"
+                ));
+            }
+            let shard_id =
+                subscription.shard_id(self.shard_service.current_assignment().number_of_shards);
+            let _: u32 = self
+                .redis
+                .with("topic", "unsubscribe")
+                .hdel(
+                    get_shard_subscriptions_redis_key(&shard_id),
+                    subscription_id,
+                )
+                .await?;
+        }
+
+        let _: u32 = self
+            .redis
+            .with("topic", "unsubscribe")
+            .hdel(
+                get_subscriptions_redis_key(account_id, topic),
+                subscription_id,
+            )
+            .await?;
+        let _: u32 = self
+            .redis
+            .with("topic", "unsubscribe")
+            .hdel(get_cursors_redis_key(account_id, topic), subscription_id)
+            .await?;
+        let _: u32 = self
+            .redis
+            .with("topic", "unsubscribe")
+            .eval(
+                CLEANUP_SCRIPT,
+                vec![
+                    get_subscriptions_redis_key(account_id, topic),
+                    get_stream_redis_key(account_id, topic),
+                    get_cursors_redis_key(account_id, topic),
+                ],
+                Vec::<String>::new(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+/// Parses a Redis stream id of the form `<milliseconds>-<sequence>`
+fn parse_stream_id(stream_id: &str) -> Option<(u64, u64)> {
+    let (millis, sequence) = stream_id.split_once('-')?;
+    Some((millis.parse().ok()?, sequence.parse().ok()?))
+}
+
+fn get_stream_redis_key(account_id: &AccountId, topic: &str) -> String {
+    format!("topic:stream:{account_id}:{topic}")
+}
+
+fn get_subscriptions_redis_key(account_id: &AccountId, topic: &str) -> String {
+    format!("topic:subscriptions:{account_id}:{topic}")
+}
+
+fn get_cursors_redis_key(account_id: &AccountId, topic: &str) -> String {
+    format!("topic:cursors:{account_id}:{topic}")
+}
+
+fn get_shard_subscriptions_redis_key(shard_id: &ShardId) -> String {
+    format!("topic:shard:{shard_id}")
+}
+
+#[cfg(any(feature = "mocks", test))]
+pub struct TopicServiceMock {}
+
+#[cfg(any(feature = "mocks", test))]
+impl Default for TopicServiceMock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(any(feature = "mocks", test))]
+impl TopicServiceMock {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+#[cfg(any(feature = "mocks", test))]
+#[async_trait]
+impl TopicService for TopicServiceMock {
+    async fn publish(
+        &self,
+        _account_id: &AccountId,
+        _topic: &str,
+        _message: TopicMessage,
+    ) -> anyhow::Result<()> {
+        unimplemented!()
+    }
+
+    async fn subscribe(
+        &self,
+        _topic: &str,
+        _subscription: TopicSubscription,
+    ) -> anyhow::Result<()> {
+        unimplemented!()
+    }
+
+    async fn unsubscribe(
+        &self,
+        _account_id: &AccountId,
+        _topic: &str,
+        _subscription_id: &str,
+    ) -> anyhow::Result<()> {
+        unimplemented!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::services::topic::{SubscriptionTarget, TopicSubscription};
+    use golem_common::model::{AccountId, ComponentId, ShardId, WorkerId};
+
+    fn subscription(subscription_id: &str) -> TopicSubscription {
+        TopicSubscription {
+            subscription_id: subscription_id.to_string(),
+            target: SubscriptionTarget::Worker(WorkerId {
+                component_id: ComponentId::new_v4(),
+                worker_name: "subscriber".to_string(),
+            }),
+            account_id: AccountId {
+                value: "account".to_string(),
+            },
+            function_name: "on-message".to_string(),
+        }
+    }
+
+    #[test]
+    fn delivery_idempotency_keys_are_unique_per_message_and_subscription() {
+        let first = subscription("first");
+        let second = subscription("second");
+
+        assert_eq!(
+            first.delivery_idempotency_key("message1"),
+            first.delivery_idempotency_key("message1")
+        );
+        assert_ne!(
+            first.delivery_idempotency_key("message1"),
+            first.delivery_idempotency_key("message2")
+        );
+        assert_ne!(
+            first.delivery_idempotency_key("message1"),
+            second.delivery_idempotency_key("message1")
+        );
+    }
+
+    #[test]
+    fn worker_subscriptions_belong_to_the_shard_of_the_worker() {
+        let subscription = subscription("first");
+        let SubscriptionTarget::Worker(worker_id) = &subscription.target else {
+            unreachable!()
+        };
+
+        assert_eq!(
+            subscription.shard_id(16),
+            ShardId::from_worker_id(worker_id, 16)
+        );
+    }
+}
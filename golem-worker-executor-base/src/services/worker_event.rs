@@ -12,13 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use bincode::{Decode, Encode};
+use golem_api_grpc::proto::golem::worker::{log_attribute_value, log_event};
+use golem_common::model::{IdempotencyKey, Timestamp, WorkerEventCursor, WorkerId, WorkerStatus};
 use ringbuf::*;
 use tokio::sync::broadcast::error::RecvError;
 use tokio::sync::broadcast::*;
+use tokio::sync::mpsc;
+use tracing::{error, warn};
 
 use crate::metrics::events::{record_broadcast_event, record_event};
+use crate::services::worker_event_log::WorkerEventLogService;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Encode, Decode)]
 pub enum LogLevel {
     Trace,
     Debug,
@@ -28,7 +39,49 @@ pub enum LogLevel {
     Critical,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Typed value of a structured log attribute
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub enum LogAttributeValue {
+    String(String),
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+}
+
+/// A key-value field attached to a structured log event
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub struct LogAttribute {
+    pub key: String,
+    pub value: LogAttributeValue,
+}
+
+impl LogAttribute {
+    pub fn new(key: impl Into<String>, value: LogAttributeValue) -> Self {
+        Self {
+            key: key.into(),
+            value,
+        }
+    }
+}
+
+impl From<LogAttribute> for golem_api_grpc::proto::golem::worker::LogAttribute {
+    fn from(value: LogAttribute) -> Self {
+        let attribute_value = match value.value {
+            LogAttributeValue::String(s) => log_attribute_value::Value::StringValue(s),
+            LogAttributeValue::Int(i) => log_attribute_value::Value::IntValue(i),
+            LogAttributeValue::Float(f) => log_attribute_value::Value::FloatValue(f),
+            LogAttributeValue::Bool(b) => log_attribute_value::Value::BoolValue(b),
+        };
+        Self {
+            key: value.key,
+            value: Some(golem_api_grpc::proto::golem::worker::LogAttributeValue {
+                value: Some(attribute_value),
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
 pub enum WorkerEvent {
     StdOut(Vec<u8>),
     StdErr(Vec<u8>),
@@ -36,11 +89,92 @@ pub enum WorkerEvent {
         level: LogLevel,
         context: String,
         message: String,
+        attributes: Vec<LogAttribute>,
+    },
+    /// A live invocation of an exported function has started
+    InvocationStarted {
+        function: String,
+        idempotency_key: IdempotencyKey,
+    },
+    /// A live invocation of an exported function has successfully completed
+    InvocationFinished {
+        function: String,
+        idempotency_key: IdempotencyKey,
+        duration: Duration,
+        consumed_fuel: i64,
+    },
+    /// A live invocation of an exported function has stopped without a result, because it
+    /// failed, the worker exited or it got interrupted. Depending on the retry policy and the
+    /// kind of interruption it may be retried or resumed, which is reported by a subsequent
+    /// status change.
+    InvocationFailed {
+        function: String,
+        idempotency_key: IdempotencyKey,
+        error: String,
+    },
+    /// The worker's status has changed
+    StatusChanged {
+        status: WorkerStatus,
     },
     Close,
 }
 
+/// Converts an invocation lifecycle or status change event to its gRPC representation
+pub fn lifecycle_log_event(event: WorkerEvent) -> Option<log_event::Event> {
+    use golem_api_grpc::proto::golem::worker as grpc;
+
+    match event {
+        WorkerEvent::InvocationStarted {
+            function,
+            idempotency_key,
+        } => Some(log_event::Event::InvocationStarted(
+            grpc::InvocationStarted {
+                function,
+                idempotency_key: idempotency_key.value,
+            },
+        )),
+        WorkerEvent::InvocationFinished {
+            function,
+            idempotency_key,
+            duration,
+            consumed_fuel,
+        } => Some(log_event::Event::InvocationFinished(
+            grpc::InvocationFinished {
+                function,
+                idempotency_key: idempotency_key.value,
+                duration_millis: duration.as_millis() as u64,
+                consumed_fuel,
+            },
+        )),
+        WorkerEvent::InvocationFailed {
+            function,
+            idempotency_key,
+            error,
+        } => Some(log_event::Event::InvocationFailed(grpc::InvocationFailed {
+            function,
+            idempotency_key: idempotency_key.value,
+            error,
+        })),
+        WorkerEvent::StatusChanged { status } => {
+            Some(log_event::Event::StatusChanged(grpc::WorkerStatusChanged {
+                status: status.into(),
+            }))
+        }
+        _ => None,
+    }
+}
+
+/// A worker event with its position in the worker's event stream. Events which could not be
+/// persisted are still delivered to live subscribers, with [`UNSEQUENCED`] as their sequence.
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub struct SequencedWorkerEvent {
+    pub sequence: u64,
+    pub timestamp: Timestamp,
+    pub event: WorkerEvent,
+}
+
 /// Per-worker event stream
+#[async_trait]
 pub trait WorkerEventService {
     fn emit_event(&self, event: WorkerEvent);
 
@@ -52,67 +186,360 @@ pub trait WorkerEventService {
         self.emit_event(WorkerEvent::StdErr(data))
     }
 
-    fn emit_log(&self, log_level: LogLevel, context: &str, message: &str) {
+    fn emit_log(
+        &self,
+        log_level: LogLevel,
+        context: &str,
+        message: &str,
+        attributes: Vec<LogAttribute>,
+    ) {
         self.emit_event(WorkerEvent::Log {
             level: log_level,
             context: context.to_string(),
             message: message.to_string(),
+            attributes,
         })
     }
 
     fn receiver(&self) -> WorkerEventReceiver;
+
+    /// Subscribes to the event stream, first replaying the retained events matching the cursor
+    /// from the in-memory history and, if enabled, the persistent event log.
+    async fn receiver_since(&self, cursor: &WorkerEventCursor) -> WorkerEventReceiver;
 }
 
 pub struct WorkerEventReceiver {
-    history: Vec<WorkerEvent>,
-    receiver: Receiver<WorkerEvent>,
+    history: Vec<SequencedWorkerEvent>,
+    receiver: Receiver<SequencedWorkerEvent>,
+    last_sequence: Option<u64>,
 }
 
 impl WorkerEventReceiver {
     pub async fn recv(&mut self) -> Result<WorkerEvent, RecvError> {
-        match self.history.pop() {
-            Some(event) => Ok(event),
-            None => self.receiver.recv().await,
+        self.recv_sequenced().await.map(|event| event.event)
+    }
+
+    /// Receives the next event, skipping the ones already delivered from the history
+    pub async fn recv_sequenced(&mut self) -> Result<SequencedWorkerEvent, RecvError> {
+        loop {
+            let event = match self.history.pop() {
+                Some(event) => event,
+                None => self.receiver.recv().await?,
+            };
+            match (&event.event, self.last_sequence) {
+                (WorkerEvent::Close, _) => break Ok(event),
+                _ if event.sequence == UNSEQUENCED => break Ok(event),
+                (_, Some(last_sequence)) if event.sequence <= last_sequence => continue,
+                _ => {
+                    self.last_sequence = Some(event.sequence);
+                    break Ok(event);
+                }
+            }
+        }
+    }
+}
+
+struct WorkerEventPersistence {
+    worker_id: WorkerId,
+    event_log: Arc<dyn WorkerEventLogService + Send + Sync>,
+    writer: mpsc::UnboundedSender<(Timestamp, WorkerEvent)>,
+}
+
+/// The in-memory part of the event stream shared with the persisting writer task
+struct WorkerEventBroadcast {
+    sender: Sender<SequencedWorkerEvent>,
+    ring: Mutex<HeapRb<SequencedWorkerEvent>>,
+    last_sequence: AtomicU64,
+}
+
+impl WorkerEventBroadcast {
+    /// Publishes an event which has already been assigned its sequence number
+    fn publish(&self, event: SequencedWorkerEvent) {
+        let mut ring = self.ring.lock().unwrap();
+        self.publish_locked(&mut ring, event);
+    }
+
+    /// Assigns the next sequence number to the event and publishes it
+    fn publish_next(&self, timestamp: Timestamp, event: WorkerEvent) {
+        let mut ring = self.ring.lock().unwrap();
+        let last_sequence = self.last_sequence.load(Ordering::Acquire);
+        let sequence = match event {
+            WorkerEvent::Close => last_sequence,
+            _ => last_sequence + 1,
+        };
+        self.publish_locked(
+            &mut ring,
+            SequencedWorkerEvent {
+                sequence,
+                timestamp,
+                event,
+            },
+        );
+    }
+
+    /// Sends an event which could not be persisted to the current subscribers only, leaving
+    /// the ring and the last sequence number untouched
+    fn publish_unsequenced(&self, timestamp: Timestamp, event: WorkerEvent) {
+        if self.sender.receiver_count() > 0 {
+            record_broadcast_event(label(&event));
+
+            let _ = self.sender.send(SequencedWorkerEvent {
+                sequence: UNSEQUENCED,
+                timestamp,
+                event,
+            });
         }
     }
+
+    fn publish_locked(&self, ring: &mut HeapRb<SequencedWorkerEvent>, event: SequencedWorkerEvent) {
+        if event.event != WorkerEvent::Close {
+            self.last_sequence.store(event.sequence, Ordering::Release);
+        }
+        if self.sender.receiver_count() > 0 {
+            record_broadcast_event(label(&event.event));
+
+            let _ = self.sender.send(event.clone());
+        }
+        ring.push_overwrite(event);
+    }
 }
 
 pub struct WorkerEventServiceDefault {
-    sender: Sender<WorkerEvent>,
-    ring: HeapRb<WorkerEvent>,
+    broadcast: Arc<WorkerEventBroadcast>,
+    persistence: Option<WorkerEventPersistence>,
 }
 
 impl WorkerEventServiceDefault {
     pub fn new(channel_capacity: usize, ring_capacity: usize) -> WorkerEventServiceDefault {
+        Self::with_last_sequence(channel_capacity, ring_capacity, 0)
+    }
+
+    fn with_last_sequence(
+        channel_capacity: usize,
+        ring_capacity: usize,
+        last_sequence: u64,
+    ) -> WorkerEventServiceDefault {
         let (tx, _) = channel(channel_capacity);
         let ring = HeapRb::new(ring_capacity);
-        // ring.sub
-        WorkerEventServiceDefault { sender: tx, ring }
+        WorkerEventServiceDefault {
+            broadcast: Arc::new(WorkerEventBroadcast {
+                sender: tx,
+                ring: Mutex::new(ring),
+                last_sequence: AtomicU64::new(last_sequence),
+            }),
+            persistence: None,
+        }
+    }
+
+    /// Creates an event service which appends every event to the given persistent event log,
+    /// continuing the sequence numbers after `last_sequence`.
+    ///
+    /// Events are sequenced by a background writer and only get published to the subscribers
+    /// once they have been persisted, so a sequence number seen by a client is never reused after
+    /// a restart.
+    pub fn persistent(
+        channel_capacity: usize,
+        ring_capacity: usize,
+        worker_id: WorkerId,
+        event_log: Arc<dyn WorkerEventLogService + Send + Sync>,
+        last_sequence: u64,
+    ) -> WorkerEventServiceDefault {
+        let (writer, pending) = mpsc::unbounded_channel::<(Timestamp, WorkerEvent)>();
+
+        let mut result = Self::with_last_sequence(channel_capacity, ring_capacity, last_sequence);
+        tokio::spawn(Self::persist_events(
+            worker_id.clone(),
+            event_log.clone(),
+            result.broadcast.clone(),
+            pending,
+            last_sequence,
+        ));
+        result.persistence = Some(WorkerEventPersistence {
+            worker_id,
+            event_log,
+            writer,
+        });
+        result
+    }
+
+    async fn persist_events(
+        worker_id: WorkerId,
+        event_log: Arc<dyn WorkerEventLogService + Send + Sync>,
+        broadcast: Arc<WorkerEventBroadcast>,
+        mut pending: mpsc::UnboundedReceiver<(Timestamp, WorkerEvent)>,
+        last_sequence: u64,
+    ) {
+        let mut next_sequence = last_sequence + 1;
+        let mut batch = Vec::new();
+        while pending.recv_many(&mut batch, 128).await > 0 {
+            let mut remaining = Vec::new();
+            let mut close = None;
+            for (timestamp, event) in batch.drain(..) {
+                match event {
+                    WorkerEvent::Close => close = Some(timestamp),
+                    event => remaining.push((timestamp, event)),
+                }
+            }
+
+            let mut attempt = 0;
+            while !remaining.is_empty() {
+                let events: Vec<SequencedWorkerEvent> = remaining
+                    .iter()
+                    .zip(next_sequence..)
+                    .map(|((timestamp, event), sequence)| SequencedWorkerEvent {
+                        sequence,
+                        timestamp: *timestamp,
+                        event: event.clone(),
+                    })
+                    .collect();
+
+                match event_log.append(&worker_id, events.clone()).await {
+                    Ok(()) => {
+                        next_sequence += events.len() as u64;
+                        remaining.clear();
+                        for event in events {
+                            broadcast.publish(event);
+                        }
+                    }
+                    Err(err) if attempt < PERSIST_MAX_ATTEMPTS => {
+                        warn!("Failed to persist events of worker {worker_id}: {err}");
+                        tokio::time::sleep(PERSIST_RETRY_DELAY * 2u32.pow(attempt)).await;
+                        attempt += 1;
+
+                        // Part of the batch may have been stored, and another writer of the
+                        // same worker may have appended in the meantime. The stored prefix gets
+                        // published and the rest is renumbered after the persisted tail.
+                        let cursor = WorkerEventCursor::AfterSequence(next_sequence - 1);
+                        if let Ok(persisted) = event_log.read(&worker_id, &cursor).await {
+                            let stored = events
+                                .iter()
+                                .zip(persisted.iter())
+                                .take_while(|(event, persisted)| event == persisted)
+                                .count();
+                            remaining.drain(..stored);
+                            for event in events.into_iter().take(stored) {
+                                broadcast.publish(event);
+                            }
+                            next_sequence += stored as u64;
+                        }
+                        if let Ok(last_sequence) = event_log.last_sequence(&worker_id).await {
+                            next_sequence = next_sequence.max(last_sequence + 1);
+                        }
+                    }
+                    Err(err) => {
+                        error!(
+                            "Dropping {} events of worker {worker_id} which could not be persisted: {err}",
+                            remaining.len()
+                        );
+                        for (timestamp, event) in remaining.drain(..) {
+                            broadcast.publish_unsequenced(timestamp, event);
+                        }
+                    }
+                }
+            }
+
+            if let Some(timestamp) = close {
+                broadcast.publish(SequencedWorkerEvent {
+                    sequence: next_sequence - 1,
+                    timestamp,
+                    event: WorkerEvent::Close,
+                });
+            }
+        }
+    }
+
+    fn subscribe(&self) -> (Vec<SequencedWorkerEvent>, Receiver<SequencedWorkerEvent>) {
+        let ring = self.broadcast.ring.lock().unwrap();
+        let receiver = self.broadcast.sender.subscribe();
+        let history = ring.iter().cloned().collect();
+        (history, receiver)
     }
 }
 
+/// The sequence number of events which are only broadcast because they could not be persisted
+pub const UNSEQUENCED: u64 = 0;
+
+const PERSIST_MAX_ATTEMPTS: u32 = 8;
+const PERSIST_RETRY_DELAY: Duration = Duration::from_millis(50);
+
 impl Drop for WorkerEventServiceDefault {
     fn drop(&mut self) {
         self.emit_event(WorkerEvent::Close);
     }
 }
 
+#[async_trait]
 impl WorkerEventService for WorkerEventServiceDefault {
     fn emit_event(&self, event: WorkerEvent) {
         record_event(label(&event));
 
-        if self.sender.receiver_count() > 0 {
-            record_broadcast_event(label(&event));
+        let timestamp = Timestamp::now_utc();
+        let event = match &self.persistence {
+            Some(persistence) => match persistence.writer.send((timestamp, event)) {
+                Ok(()) => return,
+                // The writer only stops when the runtime is shutting down
+                Err(mpsc::error::SendError((_, event))) => event,
+            },
+            None => event,
+        };
+
+        self.broadcast.publish_next(timestamp, event);
+    }
 
-            let _ = self.sender.send(event.clone());
+    fn receiver(&self) -> WorkerEventReceiver {
+        let (history, receiver) = self.subscribe();
+        WorkerEventReceiver {
+            history: history.into_iter().rev().collect(),
+            receiver,
+            last_sequence: None,
         }
-        let _ = unsafe { Producer::new(&self.ring) }.push(event);
     }
 
-    fn receiver(&self) -> WorkerEventReceiver {
-        let receiver = self.sender.subscribe();
-        let history = self.ring.iter().cloned().rev().collect();
-        WorkerEventReceiver { history, receiver }
+    async fn receiver_since(&self, cursor: &WorkerEventCursor) -> WorkerEventReceiver {
+        let (history, receiver) = self.subscribe();
+
+        // A cursor pointing past the last published event was issued before the sequence got
+        // reset, so the client only gets the events emitted from now on
+        let last_sequence = self.broadcast.last_sequence.load(Ordering::Acquire);
+        let cursor = match cursor {
+            WorkerEventCursor::AfterSequence(after) if *after > last_sequence => {
+                WorkerEventCursor::AfterSequence(last_sequence)
+            }
+            other => other.clone(),
+        };
+
+        let covered_by_history = history
+            .first()
+            .map(|oldest| !cursor.includes(oldest.sequence, oldest.timestamp))
+            .unwrap_or(false);
+
+        let mut events = Vec::new();
+        if !covered_by_history {
+            if let Some(persistence) = &self.persistence {
+                match persistence
+                    .event_log
+                    .read(&persistence.worker_id, &cursor)
+                    .await
+                {
+                    Ok(persisted) => events.extend(persisted),
+                    Err(err) => warn!(
+                        "Failed to read persisted events of worker {}: {err}",
+                        persistence.worker_id
+                    ),
+                }
+            }
+        }
+        events.extend(history.into_iter().filter(|event| {
+            event.event != WorkerEvent::Close && cursor.includes(event.sequence, event.timestamp)
+        }));
+        events.sort_by_key(|event| event.sequence);
+        events.dedup_by_key(|event| event.sequence);
+
+        WorkerEventReceiver {
+            history: events.into_iter().rev().collect(),
+            receiver,
+            last_sequence: None,
+        }
     }
 }
 
@@ -121,6 +548,10 @@ fn label(event: &WorkerEvent) -> &'static str {
         WorkerEvent::StdOut(_) => "stdout",
         WorkerEvent::StdErr(_) => "stderr",
         WorkerEvent::Log { .. } => "log",
+        WorkerEvent::InvocationStarted { .. } => "invocation_started",
+        WorkerEvent::InvocationFinished { .. } => "invocation_finished",
+        WorkerEvent::InvocationFailed { .. } => "invocation_failed",
+        WorkerEvent::StatusChanged { .. } => "status_changed",
         WorkerEvent::Close => "close",
     }
 }
@@ -128,12 +559,18 @@ fn label(event: &WorkerEvent) -> &'static str {
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
+    use std::time::Duration;
 
+    use async_trait::async_trait;
+    use golem_common::model::{ComponentId, WorkerEventCursor, WorkerId};
     use tokio::sync::Mutex;
 
+    use crate::services::golem_config::WorkerEventLogRetentionConfig;
     use crate::services::worker_event::{
-        WorkerEvent, WorkerEventService, WorkerEventServiceDefault,
+        SequencedWorkerEvent, WorkerEvent, WorkerEventReceiver, WorkerEventService,
+        WorkerEventServiceDefault, UNSEQUENCED,
     };
+    use crate::services::worker_event_log::{WorkerEventLogService, WorkerEventLogServiceInMemory};
 
     #[tokio::test]
     pub async fn both_subscriber_gets_events_small() {
@@ -280,4 +717,194 @@ mod tests {
                 ]
         )
     }
+
+    async fn collect_sequences(mut receiver: WorkerEventReceiver) -> Vec<u64> {
+        let mut result = Vec::new();
+        loop {
+            let event = receiver.recv_sequenced().await.unwrap();
+            match event.event {
+                WorkerEvent::Close => break,
+                _ => result.push(event.sequence),
+            }
+        }
+        result
+    }
+
+    async fn wait_for_persisted(
+        event_log: &Arc<WorkerEventLogServiceInMemory>,
+        worker_id: &WorkerId,
+        sequence: u64,
+    ) {
+        while event_log.last_sequence(worker_id).await.unwrap() < sequence {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    }
+
+    #[tokio::test]
+    pub async fn resuming_from_persisted_events() {
+        let event_log = Arc::new(WorkerEventLogServiceInMemory::new(
+            WorkerEventLogRetentionConfig {
+                max_events: 100,
+                max_age: Duration::from_secs(60 * 60),
+            },
+        ));
+        let worker_id = WorkerId {
+            component_id: ComponentId::new_v4(),
+            worker_name: "test".to_string(),
+        };
+
+        let svc =
+            WorkerEventServiceDefault::persistent(4, 2, worker_id.clone(), event_log.clone(), 0);
+        for b in 1..6u8 {
+            svc.emit_event(WorkerEvent::StdOut(vec![b]));
+        }
+        wait_for_persisted(&event_log, &worker_id, 5).await;
+
+        let receiver = svc
+            .receiver_since(&WorkerEventCursor::AfterSequence(1))
+            .await;
+        drop(svc);
+        let first_run = collect_sequences(receiver).await;
+
+        // Simulating a restart of the worker, continuing the sequence from the persisted log
+        let last_sequence = event_log.last_sequence(&worker_id).await.unwrap();
+        let svc = WorkerEventServiceDefault::persistent(
+            4,
+            2,
+            worker_id.clone(),
+            event_log.clone(),
+            last_sequence,
+        );
+        svc.emit_event(WorkerEvent::StdOut(vec![6]));
+
+        let receiver = svc
+            .receiver_since(&WorkerEventCursor::AfterSequence(3))
+            .await;
+        drop(svc);
+        let second_run = collect_sequences(receiver).await;
+
+        assert_eq!(first_run, vec![2, 3, 4, 5]);
+        assert_eq!(second_run, vec![4, 5, 6]);
+    }
+
+    #[tokio::test]
+    pub async fn stale_last_sequence_is_not_reused() {
+        let event_log = Arc::new(WorkerEventLogServiceInMemory::new(
+            WorkerEventLogRetentionConfig {
+                max_events: 100,
+                max_age: Duration::from_secs(60 * 60),
+            },
+        ));
+        let worker_id = WorkerId {
+            component_id: ComponentId::new_v4(),
+            worker_name: "test".to_string(),
+        };
+
+        let svc =
+            WorkerEventServiceDefault::persistent(4, 8, worker_id.clone(), event_log.clone(), 0);
+        for b in 1..4u8 {
+            svc.emit_event(WorkerEvent::StdOut(vec![b]));
+        }
+        wait_for_persisted(&event_log, &worker_id, 3).await;
+        drop(svc);
+
+        // A new instance started before the previous one has flushed its events
+        let svc =
+            WorkerEventServiceDefault::persistent(4, 8, worker_id.clone(), event_log.clone(), 1);
+        let receiver = svc.receiver();
+        svc.emit_event(WorkerEvent::StdOut(vec![4]));
+        svc.emit_event(WorkerEvent::StdOut(vec![5]));
+        wait_for_persisted(&event_log, &worker_id, 5).await;
+        drop(svc);
+
+        let persisted = event_log
+            .read(&worker_id, &WorkerEventCursor::AfterSequence(0))
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|event| (event.sequence, event.event))
+            .collect::<Vec<_>>();
+
+        assert_eq!(collect_sequences(receiver).await, vec![4, 5]);
+        assert_eq!(
+            persisted,
+            (1..6u8)
+                .map(|b| (b as u64, WorkerEvent::StdOut(vec![b])))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    pub async fn cursor_after_the_tail_only_gets_new_events() {
+        let svc = WorkerEventServiceDefault::new(4, 16);
+        for b in 1..4u8 {
+            svc.emit_event(WorkerEvent::StdOut(vec![b]));
+        }
+
+        let receiver = svc
+            .receiver_since(&WorkerEventCursor::AfterSequence(10))
+            .await;
+        svc.emit_event(WorkerEvent::StdOut(vec![4]));
+        drop(svc);
+
+        assert_eq!(collect_sequences(receiver).await, vec![4]);
+    }
+
+    struct FailingWorkerEventLog;
+
+    #[async_trait]
+    impl WorkerEventLogService for FailingWorkerEventLog {
+        fn is_enabled(&self) -> bool {
+            true
+        }
+
+        async fn append(
+            &self,
+            _worker_id: &WorkerId,
+            _events: Vec<SequencedWorkerEvent>,
+        ) -> anyhow::Result<()> {
+            Err(anyhow::anyhow!("unavailable"))
+        }
+
+        async fn read(
+            &self,
+            _worker_id: &WorkerId,
+            _cursor: &WorkerEventCursor,
+        ) -> anyhow::Result<Vec<SequencedWorkerEvent>> {
+            Err(anyhow::anyhow!("unavailable"))
+        }
+
+        async fn last_sequence(&self, _worker_id: &WorkerId) -> anyhow::Result<u64> {
+            Err(anyhow::anyhow!("unavailable"))
+        }
+
+        async fn delete(&self, _worker_id: &WorkerId) -> anyhow::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    pub async fn events_which_cannot_be_persisted_are_still_broadcast() {
+        let worker_id = WorkerId {
+            component_id: ComponentId::new_v4(),
+            worker_name: "test".to_string(),
+        };
+
+        let svc = WorkerEventServiceDefault::persistent(
+            4,
+            8,
+            worker_id,
+            Arc::new(FailingWorkerEventLog),
+            0,
+        );
+        let receiver = svc.receiver();
+        svc.emit_event(WorkerEvent::StdOut(vec![1]));
+        svc.emit_event(WorkerEvent::StdOut(vec![2]));
+        drop(svc);
+
+        assert_eq!(
+            collect_sequences(receiver).await,
+            vec![UNSEQUENCED, UNSEQUENCED]
+        );
+    }
 }
@@ -0,0 +1,385 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use fred::prelude::RedisValue;
+use fred::types::{RedisKey, XCapKind, XCapTrim};
+use golem_common::metrics::redis::record_redis_serialized_size;
+use golem_common::model::{Timestamp, WorkerEventCursor, WorkerId};
+use golem_common::redis::RedisPool;
+
+use crate::services::golem_config::{WorkerEventLogConfig, WorkerEventLogRetentionConfig};
+use crate::services::worker_event::SequencedWorkerEvent;
+
+/// Optional persistent storage of worker events, allowing clients to resume tailing a worker's
+/// event stream after an executor restart or a shard reassignment.
+#[async_trait]
+pub trait WorkerEventLogService {
+    /// True if events are actually persisted by this implementation
+    fn is_enabled(&self) -> bool;
+
+    async fn append(
+        &self,
+        worker_id: &WorkerId,
+        events: Vec<SequencedWorkerEvent>,
+    ) -> anyhow::Result<()>;
+
+    /// Reads the retained events matching the given cursor, ordered by their sequence number
+    async fn read(
+        &self,
+        worker_id: &WorkerId,
+        cursor: &WorkerEventCursor,
+    ) -> anyhow::Result<Vec<SequencedWorkerEvent>>;
+
+    /// Gets the sequence number of the last persisted event, or 0 if there is none
+    async fn last_sequence(&self, worker_id: &WorkerId) -> anyhow::Result<u64>;
+
+    async fn delete(&self, worker_id: &WorkerId) -> anyhow::Result<()>;
+}
+
+pub fn configured(
+    config: &WorkerEventLogConfig,
+    redis_pool: RedisPool,
+) -> Arc<dyn WorkerEventLogService + Send + Sync> {
+    match config {
+        WorkerEventLogConfig::Disabled => Arc::new(WorkerEventLogServiceDisabled::new()),
+        WorkerEventLogConfig::InMemory(retention) => {
+            Arc::new(WorkerEventLogServiceInMemory::new(retention.clone()))
+        }
+        WorkerEventLogConfig::Redis(retention) => Arc::new(WorkerEventLogServiceRedis::new(
+            redis_pool.clone(),
+            retention.clone(),
+        )),
+    }
+}
+
+fn is_retained(event: &SequencedWorkerEvent, max_age: Duration) -> bool {
+    let now = Timestamp::now_utc().to_millis();
+    event.timestamp.to_millis() + max_age.as_millis() as u64 >= now
+}
+
+#[derive(Clone, Debug)]
+pub struct WorkerEventLogServiceRedis {
+    redis: RedisPool,
+    retention: WorkerEventLogRetentionConfig,
+}
+
+impl WorkerEventLogServiceRedis {
+    pub fn new(redis: RedisPool, retention: WorkerEventLogRetentionConfig) -> Self {
+        Self { redis, retention }
+    }
+
+    fn decode(
+        &self,
+        results: Vec<HashMap<String, HashMap<String, Bytes>>>,
+    ) -> anyhow::Result<Vec<SequencedWorkerEvent>> {
+        let mut events = Vec::new();
+        for result in results.iter() {
+            for (_, value) in result.iter() {
+                for (_, value) in value.iter() {
+                    let event = self
+                        .redis
+                        .deserialize::<SequencedWorkerEvent>(value)
+                        .map_err(|err| {
+                            anyhow::anyhow!("failed to deserialize worker event: {err}")
+                        })?;
+                    events.push(event);
+                }
+            }
+        }
+        Ok(events)
+    }
+}
+
+#[async_trait]
+impl WorkerEventLogService for WorkerEventLogServiceRedis {
+    fn is_enabled(&self) -> bool {
+        true
+    }
+
+    async fn append(
+        &self,
+        worker_id: &WorkerId,
+        events: Vec<SequencedWorkerEvent>,
+    ) -> anyhow::Result<()> {
+        let key = get_worker_event_log_redis_key(worker_id);
+        for event in events {
+            let value = self
+                .redis
+                .serialize(&event)
+                .map_err(|err| anyhow::anyhow!("failed to serialize worker event: {err}"))?;
+
+            record_redis_serialized_size("worker_event_log", "event", value.len());
+
+            let field: RedisKey = "event".into();
+            let _: String = self
+                .redis
+                .with("worker_event_log", "append")
+                .xadd(
+                    &key,
+                    false,
+                    (
+                        XCapKind::MaxLen,
+                        XCapTrim::AlmostExact,
+                        self.retention.max_events as i64,
+                        None,
+                    ),
+                    event.sequence.to_string(),
+                    (field, RedisValue::Bytes(value)),
+                )
+                .await?;
+        }
+
+        let _: () = self
+            .redis
+            .with("worker_event_log", "append")
+            .expire(&key, self.retention.max_age.as_secs() as i64)
+            .await?;
+        Ok(())
+    }
+
+    async fn read(
+        &self,
+        worker_id: &WorkerId,
+        cursor: &WorkerEventCursor,
+    ) -> anyhow::Result<Vec<SequencedWorkerEvent>> {
+        let key = get_worker_event_log_redis_key(worker_id);
+        let start = match cursor {
+            WorkerEventCursor::AfterSequence(sequence) => (sequence + 1).to_string(),
+            WorkerEventCursor::Since(_) => "-".to_string(),
+        };
+        let results: Vec<HashMap<String, HashMap<String, Bytes>>> = self
+            .redis
+            .with("worker_event_log", "read")
+            .xrange(&key, start, "+", None)
+            .await?;
+
+        Ok(self
+            .decode(results)?
+            .into_iter()
+            .filter(|event| {
+                cursor.includes(event.sequence, event.timestamp)
+                    && is_retained(event, self.retention.max_age)
+            })
+            .collect())
+    }
+
+    async fn last_sequence(&self, worker_id: &WorkerId) -> anyhow::Result<u64> {
+        let key = get_worker_event_log_redis_key(worker_id);
+        let results: Vec<HashMap<String, HashMap<String, Bytes>>> = self
+            .redis
+            .with("worker_event_log", "last_sequence")
+            .xrevrange(&key, "+", "-", Some(1))
+            .await?;
+
+        Ok(self
+            .decode(results)?
+            .last()
+            .map(|event| event.sequence)
+            .unwrap_or(0))
+    }
+
+    async fn delete(&self, worker_id: &WorkerId) -> anyhow::Result<()> {
+        let _: () = self
+            .redis
+            .with("worker_event_log", "delete")
+            .del(get_worker_event_log_redis_key(worker_id))
+            .await?;
+        Ok(())
+    }
+}
+
+fn get_worker_event_log_redis_key(worker_id: &WorkerId) -> String {
+    format!("instance:events:{}", worker_id.to_redis_key())
+}
+
+#[derive(Debug)]
+pub struct WorkerEventLogServiceInMemory {
+    retention: WorkerEventLogRetentionConfig,
+    events: RwLock<HashMap<WorkerId, VecDeque<SequencedWorkerEvent>>>,
+}
+
+impl WorkerEventLogServiceInMemory {
+    pub fn new(retention: WorkerEventLogRetentionConfig) -> Self {
+        Self {
+            retention,
+            events: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl WorkerEventLogService for WorkerEventLogServiceInMemory {
+    fn is_enabled(&self) -> bool {
+        true
+    }
+
+    async fn append(
+        &self,
+        worker_id: &WorkerId,
+        events: Vec<SequencedWorkerEvent>,
+    ) -> anyhow::Result<()> {
+        let mut all = self.events.write().unwrap();
+        let entry = all.entry(worker_id.clone()).or_default();
+        let mut last_sequence = entry.back().map(|event| event.sequence).unwrap_or(0);
+        for event in &events {
+            // Same as the stream IDs of the Redis implementation
+            if event.sequence <= last_sequence {
+                return Err(anyhow::anyhow!(
+                    "event sequence {} is not greater than the last persisted one ({last_sequence})",
+                    event.sequence
+                ));
+            }
+            last_sequence = event.sequence;
+        }
+        entry.extend(events);
+        while entry.len() > self.retention.max_events {
+            entry.pop_front();
+        }
+        Ok(())
+    }
+
+    async fn read(
+        &self,
+        worker_id: &WorkerId,
+        cursor: &WorkerEventCursor,
+    ) -> anyhow::Result<Vec<SequencedWorkerEvent>> {
+        let all = self.events.read().unwrap();
+        Ok(all
+            .get(worker_id)
+            .map(|events| {
+                events
+                    .iter()
+                    .filter(|event| {
+                        cursor.includes(event.sequence, event.timestamp)
+                            && is_retained(event, self.retention.max_age)
+                    })
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    async fn last_sequence(&self, worker_id: &WorkerId) -> anyhow::Result<u64> {
+        let all = self.events.read().unwrap();
+        Ok(all
+            .get(worker_id)
+            .and_then(|events| events.back())
+            .map(|event| event.sequence)
+            .unwrap_or(0))
+    }
+
+    async fn delete(&self, worker_id: &WorkerId) -> anyhow::Result<()> {
+        self.events.write().unwrap().remove(worker_id);
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct WorkerEventLogServiceDisabled {}
+
+impl WorkerEventLogServiceDisabled {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+#[async_trait]
+impl WorkerEventLogService for WorkerEventLogServiceDisabled {
+    fn is_enabled(&self) -> bool {
+        false
+    }
+
+    async fn append(
+        &self,
+        _worker_id: &WorkerId,
+        _events: Vec<SequencedWorkerEvent>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn read(
+        &self,
+        _worker_id: &WorkerId,
+        _cursor: &WorkerEventCursor,
+    ) -> anyhow::Result<Vec<SequencedWorkerEvent>> {
+        Ok(Vec::new())
+    }
+
+    async fn last_sequence(&self, _worker_id: &WorkerId) -> anyhow::Result<u64> {
+        Ok(0)
+    }
+
+    async fn delete(&self, _worker_id: &WorkerId) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use golem_common::model::{ComponentId, Timestamp, WorkerEventCursor, WorkerId};
+
+    use crate::services::golem_config::WorkerEventLogRetentionConfig;
+    use crate::services::worker_event::{SequencedWorkerEvent, WorkerEvent};
+    use crate::services::worker_event_log::{WorkerEventLogService, WorkerEventLogServiceInMemory};
+
+    fn stdout(sequence: u64, timestamp: u64) -> SequencedWorkerEvent {
+        SequencedWorkerEvent {
+            sequence,
+            timestamp: Timestamp::from(timestamp),
+            event: WorkerEvent::StdOut(format!("{sequence}").into_bytes()),
+        }
+    }
+
+    #[tokio::test]
+    async fn in_memory_log_is_capped_and_resumable() {
+        let svc = WorkerEventLogServiceInMemory::new(WorkerEventLogRetentionConfig {
+            max_events: 3,
+            max_age: Duration::from_secs(60 * 60 * 24 * 365 * 1000),
+        });
+        let worker_id = WorkerId {
+            component_id: ComponentId::new_v4(),
+            worker_name: "test".to_string(),
+        };
+
+        svc.append(&worker_id, (1..=5).map(|i| stdout(i, i * 1000)).collect())
+            .await
+            .unwrap();
+
+        let after_3 = svc
+            .read(&worker_id, &WorkerEventCursor::AfterSequence(3))
+            .await
+            .unwrap();
+        let since_4s = svc
+            .read(&worker_id, &WorkerEventCursor::Since(Timestamp::from(4000)))
+            .await
+            .unwrap();
+        let all = svc
+            .read(&worker_id, &WorkerEventCursor::AfterSequence(0))
+            .await
+            .unwrap();
+
+        assert_eq!(svc.last_sequence(&worker_id).await.unwrap(), 5);
+        assert_eq!(after_3, vec![stdout(4, 4000), stdout(5, 5000)]);
+        assert_eq!(since_4s, vec![stdout(4, 4000), stdout(5, 5000)]);
+        assert_eq!(all, vec![stdout(3, 3000), stdout(4, 4000), stdout(5, 5000)]);
+    }
+}
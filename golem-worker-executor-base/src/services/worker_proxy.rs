@@ -18,12 +18,13 @@ use async_trait::async_trait;
 use bincode::{Decode, Encode};
 use golem_api_grpc::proto::golem::worker::worker_service_client::WorkerServiceClient;
 use golem_api_grpc::proto::golem::worker::{
-    invoke_and_await_response, invoke_response, update_worker_response, worker_error,
-    CallingConvention, InvokeAndAwaitRequest, InvokeAndAwaitResponse, InvokeParameters,
-    InvokeRequest, InvokeResponse, UpdateMode, UpdateWorkerRequest, UpdateWorkerResponse,
-    WorkerError,
+    invoke_and_await_response, invoke_response, send_mailbox_message_response,
+    update_worker_response, worker_error, CallingConvention, InvokeAndAwaitEphemeralRequest,
+    InvokeAndAwaitRequest, InvokeAndAwaitResponse, InvokeParameters, InvokeRequest, InvokeResponse,
+    SendMailboxMessageRequest, SendMailboxMessageResponse, UpdateMode, UpdateWorkerRequest,
+    UpdateWorkerResponse, WorkerError,
 };
-use golem_common::model::{AccountId, ComponentVersion, IdempotencyKey, WorkerId};
+use golem_common::model::{AccountId, ComponentId, ComponentVersion, IdempotencyKey, WorkerId};
 use golem_wasm_rpc::{Value, WitValue};
 use http::Uri;
 use std::error::Error;
@@ -40,6 +41,17 @@ pub trait WorkerProxy {
         function_name: String,
         function_params: Vec<WitValue>,
         account_id: &AccountId,
+        call_chain: Vec<WorkerId>,
+    ) -> Result<WitValue, WorkerProxyError>;
+
+    /// Invokes a function of the latest version of a component on a fresh ephemeral worker
+    async fn invoke_and_await_ephemeral(
+        &self,
+        component_id: &ComponentId,
+        function_name: String,
+        function_params: Vec<WitValue>,
+        account_id: &AccountId,
+        call_chain: Vec<WorkerId>,
     ) -> Result<WitValue, WorkerProxyError>;
 
     async fn invoke(
@@ -58,6 +70,16 @@ pub trait WorkerProxy {
         mode: UpdateMode,
         account_id: &AccountId,
     ) -> Result<(), WorkerProxyError>;
+
+    /// Appends a message to the mailbox of a worker, unless a message with the same id has
+    /// already been sent to it
+    async fn send_mailbox_message(
+        &self,
+        worker_id: &WorkerId,
+        message_id: IdempotencyKey,
+        payload: Vec<u8>,
+        account_id: &AccountId,
+    ) -> Result<(), WorkerProxyError>;
 }
 
 #[derive(Debug, Clone, Encode, Decode)]
@@ -157,6 +179,7 @@ impl WorkerProxy for RemoteWorkerProxy {
         function_name: String,
         function_params: Vec<WitValue>,
         _account_id: &AccountId,
+        call_chain: Vec<WorkerId>,
     ) -> Result<WitValue, WorkerProxyError> {
         debug!("Invoking remote worker {worker_id} function {function_name} with parameters {function_params:?}");
 
@@ -181,31 +204,54 @@ impl WorkerProxy for RemoteWorkerProxy {
                     function: function_name,
                     invoke_parameters,
                     calling_convention: CallingConvention::Component as i32,
+                    call_chain: call_chain.into_iter().map(|id| id.into()).collect(),
                 },
                 &self.access_token,
             ))
             .await?
             .into_inner();
 
-        match response.result {
-            Some(invoke_and_await_response::Result::Success(result)) => {
-                let mut result_values = Vec::new();
-                for proto_value in result.result {
-                    let value: Value = proto_value.try_into().map_err(|err| {
-                        WorkerProxyError::InternalError(GolemError::unknown(format!(
-                            "Could not decode result: {err}"
-                        )))
-                    })?;
-                    result_values.push(value);
-                }
-                let result: WitValue = Value::Tuple(result_values).into();
-                Ok(result)
-            }
-            Some(invoke_and_await_response::Result::Error(error)) => Err(error.into()),
-            None => Err(WorkerProxyError::InternalError(GolemError::unknown(
-                "Empty response through the worker API".to_string(),
-            ))),
-        }
+        decode_invoke_and_await_response(response)
+    }
+
+    async fn invoke_and_await_ephemeral(
+        &self,
+        component_id: &ComponentId,
+        function_name: String,
+        function_params: Vec<WitValue>,
+        _account_id: &AccountId,
+        call_chain: Vec<WorkerId>,
+    ) -> Result<WitValue, WorkerProxyError> {
+        debug!("Invoking ephemeral worker of {component_id} function {function_name} with parameters {function_params:?}");
+
+        let proto_params = function_params
+            .into_iter()
+            .map(|param| {
+                let value: Value = param.into();
+                value.into()
+            })
+            .collect();
+        let invoke_parameters = Some(InvokeParameters {
+            params: proto_params,
+        });
+
+        let mut client = WorkerServiceClient::connect(self.endpoint.as_http_02()).await?;
+
+        let response: InvokeAndAwaitResponse = client
+            .invoke_and_await_ephemeral(authorised_grpc_request(
+                InvokeAndAwaitEphemeralRequest {
+                    component_id: Some(component_id.clone().into()),
+                    function: function_name,
+                    invoke_parameters,
+                    calling_convention: CallingConvention::Component as i32,
+                    call_chain: call_chain.into_iter().map(|id| id.into()).collect(),
+                },
+                &self.access_token,
+            ))
+            .await?
+            .into_inner();
+
+        decode_invoke_and_await_response(response)
     }
 
     async fn invoke(
@@ -284,6 +330,62 @@ impl WorkerProxy for RemoteWorkerProxy {
             ))),
         }
     }
+
+    async fn send_mailbox_message(
+        &self,
+        worker_id: &WorkerId,
+        message_id: IdempotencyKey,
+        payload: Vec<u8>,
+        _account_id: &AccountId,
+    ) -> Result<(), WorkerProxyError> {
+        debug!("Sending a message to the mailbox of remote worker {worker_id}");
+
+        let mut client = WorkerServiceClient::connect(self.endpoint.as_http_02()).await?;
+
+        let response: SendMailboxMessageResponse = client
+            .send_mailbox_message(authorised_grpc_request(
+                SendMailboxMessageRequest {
+                    worker_id: Some(worker_id.clone().into()),
+                    payload,
+                    idempotency_key: Some(message_id.into()),
+                },
+                &self.access_token,
+            ))
+            .await?
+            .into_inner();
+
+        match response.result {
+            Some(send_mailbox_message_response::Result::Success(_)) => Ok(()),
+            Some(send_mailbox_message_response::Result::Error(error)) => Err(error.into()),
+            None => Err(WorkerProxyError::InternalError(GolemError::unknown(
+                "Empty response through the worker API".to_string(),
+            ))),
+        }
+    }
+}
+
+fn decode_invoke_and_await_response(
+    response: InvokeAndAwaitResponse,
+) -> Result<WitValue, WorkerProxyError> {
+    match response.result {
+        Some(invoke_and_await_response::Result::Success(result)) => {
+            let mut result_values = Vec::new();
+            for proto_value in result.result {
+                let value: Value = proto_value.try_into().map_err(|err| {
+                    WorkerProxyError::InternalError(GolemError::unknown(format!(
+                        "Could not decode result: {err}"
+                    )))
+                })?;
+                result_values.push(value);
+            }
+            let result: WitValue = Value::Tuple(result_values).into();
+            Ok(result)
+        }
+        Some(invoke_and_await_response::Result::Error(error)) => Err(error.into()),
+        None => Err(WorkerProxyError::InternalError(GolemError::unknown(
+            "Empty response through the worker API".to_string(),
+        ))),
+    }
 }
 
 #[cfg(any(feature = "mocks", test))]
@@ -313,6 +415,18 @@ impl WorkerProxy for WorkerProxyMock {
         _function_name: String,
         _function_params: Vec<WitValue>,
         _account_id: &AccountId,
+        _call_chain: Vec<WorkerId>,
+    ) -> Result<WitValue, WorkerProxyError> {
+        unimplemented!()
+    }
+
+    async fn invoke_and_await_ephemeral(
+        &self,
+        _component_id: &ComponentId,
+        _function_name: String,
+        _function_params: Vec<WitValue>,
+        _account_id: &AccountId,
+        _call_chain: Vec<WorkerId>,
     ) -> Result<WitValue, WorkerProxyError> {
         unimplemented!()
     }
@@ -337,4 +451,14 @@ impl WorkerProxy for WorkerProxyMock {
     ) -> Result<(), WorkerProxyError> {
         unimplemented!()
     }
+
+    async fn send_mailbox_message(
+        &self,
+        _worker_id: &WorkerId,
+        _message_id: IdempotencyKey,
+        _payload: Vec<u8>,
+        _account_id: &AccountId,
+    ) -> Result<(), WorkerProxyError> {
+        unimplemented!()
+    }
 }
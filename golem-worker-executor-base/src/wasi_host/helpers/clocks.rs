@@ -12,42 +12,77 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use cap_std::time::SystemClock;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
 use cap_std::{ambient_authority, AmbientAuthority};
 use cap_time_ext::SystemClockExt;
-use wasmtime_wasi::preview2::HostMonotonicClock;
+use golem_common::clock::Clock;
+use wasmtime_wasi::preview2::{HostMonotonicClock, HostWallClock};
 
-/// Using a SystemClock as a monotonic clock so instants are reusable between persisted executions
+/// Using the wall clock as a monotonic clock so instants are reusable between persisted executions
 pub struct MonotonicClock {
-    clock: cap_std::time::SystemClock,
+    clock: Arc<dyn Clock + Send + Sync>,
+    resolution: Duration,
 }
 
 impl MonotonicClock {
-    pub fn new(ambient_authority: AmbientAuthority) -> Self {
+    pub fn new(clock: Arc<dyn Clock + Send + Sync>, ambient_authority: AmbientAuthority) -> Self {
         Self {
-            clock: cap_std::time::SystemClock::new(ambient_authority),
+            clock,
+            resolution: cap_std::time::SystemClock::new(ambient_authority).resolution(),
         }
     }
 }
 
 impl HostMonotonicClock for MonotonicClock {
     fn resolution(&self) -> u64 {
-        self.clock.resolution().as_nanos().try_into().unwrap()
+        self.resolution.as_nanos().try_into().unwrap()
     }
 
     fn now(&self) -> u64 {
         // Unwrap here and in `resolution` above; a `u64` is wide enough to
         // hold over 584 years of nanoseconds.
-        self.clock
-            .now()
-            .duration_since(SystemClock::UNIX_EPOCH)
-            .unwrap()
+        since_epoch(self.clock.as_ref())
             .as_nanos()
             .try_into()
             .unwrap()
     }
 }
 
-pub fn monotonic_clock() -> impl HostMonotonicClock {
-    MonotonicClock::new(ambient_authority())
+/// Wall clock reading the current time from the executor's `Clock`
+pub struct WallClock {
+    clock: Arc<dyn Clock + Send + Sync>,
+    resolution: Duration,
+}
+
+impl WallClock {
+    pub fn new(clock: Arc<dyn Clock + Send + Sync>, ambient_authority: AmbientAuthority) -> Self {
+        Self {
+            clock,
+            resolution: cap_std::time::SystemClock::new(ambient_authority).resolution(),
+        }
+    }
+}
+
+impl HostWallClock for WallClock {
+    fn resolution(&self) -> Duration {
+        self.resolution
+    }
+
+    fn now(&self) -> Duration {
+        since_epoch(self.clock.as_ref())
+    }
+}
+
+fn since_epoch(clock: &(dyn Clock + Send + Sync)) -> Duration {
+    clock.now().duration_since(SystemTime::UNIX_EPOCH).unwrap()
+}
+
+pub fn monotonic_clock(clock: Arc<dyn Clock + Send + Sync>) -> impl HostMonotonicClock {
+    MonotonicClock::new(clock, ambient_authority())
+}
+
+pub fn wall_clock(clock: Arc<dyn Clock + Send + Sync>) -> impl HostWallClock {
+    WallClock::new(clock, ambient_authority())
 }
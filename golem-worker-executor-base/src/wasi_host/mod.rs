@@ -13,9 +13,11 @@
 // limitations under the License.
 
 use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::Duration;
 
 use cap_std::fs::Dir;
+use golem_common::clock::Clock;
 
 use wasmtime::component::Linker;
 use wasmtime::Engine;
@@ -132,6 +134,7 @@ pub fn create_context<T, F>(
     stderr: impl StdoutStream + Sized + 'static,
     suspend_signal: impl Fn(Duration) -> anyhow::Error + Send + Sync + 'static,
     suspend_threshold: Duration,
+    clock: Arc<dyn Clock + Send + Sync>,
     f: F,
 ) -> Result<T, anyhow::Error>
 where
@@ -144,7 +147,8 @@ where
         .stdin(stdin)
         .stdout(stdout)
         .stderr(stderr)
-        .monotonic_clock(helpers::clocks::monotonic_clock())
+        .monotonic_clock(helpers::clocks::monotonic_clock(clock.clone()))
+        .wall_clock(helpers::clocks::wall_clock(clock))
         .preopened_dir(
             root_dir
                 .try_clone()
@@ -30,8 +30,9 @@ use golem_common::model::{
     WorkerStatus, WorkerStatusRecord,
 };
 use golem_wasm_rpc::Value;
+use tokio::sync::broadcast::error::RecvError;
 use tokio::sync::broadcast::Receiver;
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 use wasmtime::{Store, UpdateDeadline};
 
 use crate::error::GolemError;
@@ -43,11 +44,17 @@ use crate::services::invocation_queue::InvocationQueue;
 use crate::services::oplog::Oplog;
 use crate::services::recovery::is_worker_error_retriable;
 use crate::services::worker_activator::WorkerActivator;
-use crate::services::worker_event::{WorkerEventService, WorkerEventServiceDefault};
+use crate::services::worker_event::{
+    SequencedWorkerEvent, WorkerEvent, WorkerEventReceiver, WorkerEventService,
+    WorkerEventServiceDefault,
+};
+use crate::services::worker_event_log::WorkerEventLogService;
 use crate::services::{
-    HasAll, HasComponentService, HasConfig, HasInvocationQueue, HasOplogService, HasWorkerService,
+    All, HasActiveWorkers, HasAll, HasClock, HasComponentService, HasConfig, HasInvocationQueue,
+    HasOplogService, HasShardManagerService, HasShardService, HasWorkerEventLogService,
+    HasWorkerService,
 };
-use crate::workerctx::WorkerCtx;
+use crate::workerctx::{PublicWorkerIo, WorkerCtx};
 
 /// Worker is one active wasmtime instance representing a Golem worker with its corresponding
 /// worker context. The worker struct itself is responsible for creating/reactivating/interrupting
@@ -142,9 +149,12 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
                     pending_worker.oplog.clone(),
                     pending_worker.invocation_queue.clone(),
                     this.scheduler_service(),
+                    this.topic_service(),
+                    this.mailbox_service(),
                     this.recovery_management(),
                     this.rpc(),
                     this.worker_proxy(),
+                    this.clock(),
                     this.extra_deps(),
                     this.config(),
                     WorkerConfig::new(
@@ -345,6 +355,8 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         .await?;
 
         let oplog = this.oplog_service().open(worker_id).await;
+        let event_log = this.worker_event_log_service();
+        let last_event_sequence = last_persisted_event_sequence(&event_log, worker_id).await;
         let initial_pending_invocations = worker_metadata
             .last_known_status
             .pending_invocations
@@ -367,6 +379,8 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
                         worker_id_clone_1,
                         config_clone,
                         oplog,
+                        event_log,
+                        last_event_sequence,
                         this.worker_activator().clone(),
                         this.events().clone(),
                         &initial_pending_invocations,
@@ -428,6 +442,8 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         .await?;
 
         let oplog = this.oplog_service().open(worker_id).await;
+        let event_log = this.worker_event_log_service();
+        let last_event_sequence = last_persisted_event_sequence(&event_log, worker_id).await;
         let initial_pending_invocations = worker_metadata
             .last_known_status
             .pending_invocations
@@ -449,6 +465,8 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
                         worker_id_clone_1,
                         config_clone,
                         oplog,
+                        event_log,
+                        last_event_sequence,
                         this.worker_activator().clone(),
                         this.events().clone(),
                         &initial_pending_invocations,
@@ -511,6 +529,8 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         .await?;
 
         let oplog = this.oplog_service().open(worker_id).await;
+        let event_log = this.worker_event_log_service();
+        let last_event_sequence = last_persisted_event_sequence(&event_log, worker_id).await;
         let initial_pending_invocations = worker_metadata
             .last_known_status
             .pending_invocations
@@ -535,6 +555,8 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
                         worker_id_clone_1,
                         config_clone,
                         oplog,
+                        event_log,
+                        last_event_sequence,
                         this.worker_activator().clone(),
                         this.events().clone(),
                         &initial_pending_invocations,
@@ -717,6 +739,8 @@ impl<Ctx: WorkerCtx> PendingWorker<Ctx> {
         worker_id: WorkerId,
         config: Arc<GolemConfig>,
         oplog: Arc<dyn Oplog + Send + Sync>,
+        event_log: Arc<dyn WorkerEventLogService + Send + Sync>,
+        last_event_sequence: u64,
         worker_activator: Arc<dyn WorkerActivator + Send + Sync>,
         events: Arc<Events>,
         initial_pending_invocations: &[TimestampedWorkerInvocation],
@@ -733,11 +757,23 @@ impl<Ctx: WorkerCtx> PendingWorker<Ctx> {
             initial_invocation_results,
         ));
 
-        Ok(PendingWorker {
-            event_service: Arc::new(WorkerEventServiceDefault::new(
+        let event_service = if event_log.is_enabled() {
+            WorkerEventServiceDefault::persistent(
                 config.limits.event_broadcast_capacity,
                 config.limits.event_history_size,
-            )),
+                worker_id.clone(),
+                event_log,
+                last_event_sequence,
+            )
+        } else {
+            WorkerEventServiceDefault::new(
+                config.limits.event_broadcast_capacity,
+                config.limits.event_history_size,
+            )
+        };
+
+        Ok(PendingWorker {
+            event_service: Arc::new(event_service),
             oplog,
             invocation_queue,
             worker_id,
@@ -745,6 +781,24 @@ impl<Ctx: WorkerCtx> PendingWorker<Ctx> {
     }
 }
 
+/// Gets the sequence number the worker's event stream continues from
+async fn last_persisted_event_sequence(
+    event_log: &Arc<dyn WorkerEventLogService + Send + Sync>,
+    worker_id: &WorkerId,
+) -> u64 {
+    if event_log.is_enabled() {
+        event_log
+            .last_sequence(worker_id)
+            .await
+            .unwrap_or_else(|err| {
+                warn!("Failed to get the last persisted event of worker {worker_id}: {err}");
+                0
+            })
+    } else {
+        0
+    }
+}
+
 fn validate_worker(
     worker_metadata: WorkerMetadata,
     worker_args: Vec<String>,
@@ -781,6 +835,7 @@ pub async fn invoke<Ctx: WorkerCtx>(
     calling_convention: CallingConvention,
     full_function_name: String,
     function_input: Vec<Value>,
+    call_chain: Vec<WorkerId>,
 ) -> Result<Option<Result<Vec<Value>, GolemError>>, GolemError> {
     let output = worker
         .public_state
@@ -802,6 +857,7 @@ pub async fn invoke<Ctx: WorkerCtx>(
                     full_function_name,
                     function_input,
                     calling_convention,
+                    call_chain,
                 )
                 .await;
             Ok(None)
@@ -815,6 +871,7 @@ pub async fn invoke_and_await<Ctx: WorkerCtx>(
     calling_convention: CallingConvention,
     full_function_name: String,
     function_input: Vec<Value>,
+    call_chain: Vec<WorkerId>,
 ) -> Result<Vec<Value>, GolemError> {
     let worker_id = worker.metadata.worker_id.clone();
     match invoke(
@@ -823,6 +880,7 @@ pub async fn invoke_and_await<Ctx: WorkerCtx>(
         calling_convention,
         full_function_name,
         function_input,
+        call_chain,
     )
     .await?
     {
@@ -857,6 +915,98 @@ pub async fn invoke_and_await<Ctx: WorkerCtx>(
     }
 }
 
+/// Invokes a function on a fresh ephemeral worker and awaits its result.
+///
+/// The worker is created with dependencies derived by [crate::services::All::ephemeral], so its
+/// metadata and oplog only live in memory. It occupies a slot of the regular active workers cache
+/// while running, and is dropped as soon as the invocation finished, successfully or not.
+pub async fn invoke_and_await_ephemeral<Ctx, T>(
+    this: &T,
+    worker_id: &WorkerId,
+    account_id: AccountId,
+    calling_convention: CallingConvention,
+    full_function_name: String,
+    function_input: Vec<Value>,
+    call_chain: Vec<WorkerId>,
+) -> Result<Vec<Value>, GolemError>
+where
+    Ctx: WorkerCtx,
+    T: HasAll<Ctx> + HasShardService + HasShardManagerService,
+{
+    let ephemeral = All::from_deps(this).ephemeral();
+
+    let component_version = ephemeral
+        .component_service()
+        .get_latest_version(&worker_id.component_id)
+        .await?;
+
+    // The in-memory worker service does not create the oplog when the worker gets added
+    ephemeral
+        .oplog_service()
+        .create(
+            worker_id,
+            OplogEntry::create(
+                worker_id.clone(),
+                component_version,
+                vec![],
+                vec![],
+                account_id.clone(),
+            ),
+        )
+        .await;
+
+    let worker = Worker::get_or_create_with_config(
+        &ephemeral,
+        worker_id,
+        vec![],
+        vec![],
+        Some(component_version),
+        account_id,
+    )
+    .await?;
+
+    // Nothing would ever resume a suspended ephemeral worker, so instead of waiting for its
+    // result forever the invocation fails as soon as the worker gets suspended
+    let events = worker.public_state.event_service().receiver();
+    let result = tokio::select! {
+        result = invoke_and_await(
+            worker,
+            IdempotencyKey::fresh(),
+            calling_convention,
+            full_function_name,
+            function_input,
+            call_chain,
+        ) => result,
+        _ = wait_for_suspension(events) => Err(GolemError::runtime(format!(
+            "Ephemeral worker {worker_id} got suspended, but ephemeral workers cannot be resumed"
+        ))),
+    };
+
+    // Nothing can invoke the worker again, so its slot in the active workers cache is freed
+    // right away instead of waiting for it to be evicted
+    ephemeral.active_workers().remove(worker_id);
+
+    result
+}
+
+/// Waits until the worker emits a status change to [WorkerStatus::Suspended]. Never returns if
+/// the event stream gets closed before that.
+async fn wait_for_suspension(mut events: WorkerEventReceiver) {
+    loop {
+        match events.recv_sequenced().await {
+            Ok(SequencedWorkerEvent {
+                event:
+                    WorkerEvent::StatusChanged {
+                        status: WorkerStatus::Suspended,
+                    },
+                ..
+            }) => break,
+            Ok(_) | Err(RecvError::Lagged(_)) => {}
+            Err(RecvError::Closed) => std::future::pending().await,
+        }
+    }
+}
+
 /// Gets the last cached worker status record and the new oplog entries and calculates the new worker status.
 pub async fn calculate_last_known_status<T>(
     this: &T,
@@ -1028,6 +1178,12 @@ fn calculate_latest_worker_status(
             }
             OplogEntry::FailedUpdate { .. } => {}
             OplogEntry::SuccessfulUpdate { .. } => {}
+            OplogEntry::RegisterCompensation { .. } => {
+                result = WorkerStatus::Running;
+            }
+            OplogEntry::CompensationCompleted { .. } => {}
+            OplogEntry::InvocationCallChain { .. } => {}
+            OplogEntry::PendingCompensations { .. } => {}
         }
     }
     result
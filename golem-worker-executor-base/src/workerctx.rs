@@ -20,6 +20,7 @@ use golem_wasm_rpc::wasmtime::ResourceStore;
 use golem_wasm_rpc::Value;
 use wasmtime::{AsContextMut, ResourceLimiterAsync};
 
+use golem_common::clock::Clock;
 use golem_common::model::{
     AccountId, CallingConvention, ComponentVersion, IdempotencyKey, WorkerId, WorkerMetadata,
     WorkerStatus, WorkerStatusRecord,
@@ -36,11 +37,13 @@ use crate::services::events::Events;
 use crate::services::golem_config::GolemConfig;
 use crate::services::invocation_queue::InvocationQueue;
 use crate::services::key_value::KeyValueService;
+use crate::services::mailbox::MailboxService;
 use crate::services::oplog::{Oplog, OplogService};
 use crate::services::promise::PromiseService;
 use crate::services::recovery::RecoveryManagement;
 use crate::services::rpc::Rpc;
 use crate::services::scheduler::SchedulerService;
+use crate::services::topic::TopicService;
 use crate::services::worker::WorkerService;
 use crate::services::worker_event::WorkerEventService;
 use crate::services::worker_proxy::WorkerProxy;
@@ -82,9 +85,12 @@ pub trait WorkerCtx:
     /// - `active_workers`: The service for managing active workers
     /// - `oplog_service`: The service for reading and writing the oplog
     /// - `scheduler_service`: The scheduler implementation responsible for waking up suspended workers
+    /// - `topic_service`: The service for publishing to and subscribing to topics
+    /// - `mailbox_service`: The service storing the messages sent to the worker's mailbox
     /// - `recovery_management`: The service for deciding if a worker should be recovered
     /// - `rpc`: The RPC implementation used for worker to worker communication
     /// - `worker_proyx`: Access to the worker proxy above the worker executor cluster
+    /// - `clock`: The source of time for the worker's clocks and scheduled wake-ups
     /// - `extra_deps`: Extra dependencies that are required by this specific worker context
     /// - `config`: The shared worker configuration
     /// - `worker_config`: Configuration for this specific worker
@@ -107,9 +113,12 @@ pub trait WorkerCtx:
         oplog: Arc<dyn Oplog + Send + Sync>,
         invocation_queue: Arc<InvocationQueue<Self>>,
         scheduler_service: Arc<dyn SchedulerService + Send + Sync>,
+        topic_service: Arc<dyn TopicService + Send + Sync>,
+        mailbox_service: Arc<dyn MailboxService + Send + Sync>,
         recovery_management: Arc<dyn RecoveryManagement + Send + Sync>,
         rpc: Arc<dyn Rpc + Send + Sync>,
         worker_proxy: Arc<dyn WorkerProxy + Send + Sync>,
+        clock: Arc<dyn Clock + Send + Sync>,
         extra_deps: Self::ExtraDeps,
         config: Arc<GolemConfig>,
         worker_config: WorkerConfig,
@@ -11,22 +11,27 @@ use assert2::check;
 use http_02::{Response, StatusCode};
 use redis::Commands;
 
+use golem_api_grpc::proto::golem::common::ResourceLimits;
 use golem_api_grpc::proto::golem::worker::{
-    worker_execution_error, ComponentParseFailed, LogEvent,
+    worker_execution_error, CallingConvention, ComponentParseFailed, LogEvent, PromiseStatus,
+};
+use golem_api_grpc::proto::golem::workerexecutor::{
+    get_promises_response, get_worker_metadata_response, invoke_and_await_worker_response,
+    CompletePromiseRequest, GetPromisesRequest, GetPromisesResponse, InvokeAndAwaitWorkerRequest,
+    SendMailboxMessageRequest,
 };
-use golem_api_grpc::proto::golem::workerexecutor::CompletePromiseRequest;
 use golem_common::model::{
     AccountId, ComponentId, FilterComparator, IdempotencyKey, PromiseId, StringFilterComparator,
     WorkerFilter, WorkerId, WorkerMetadata, WorkerStatus,
 };
 use golem_wasm_rpc::Value;
 
-use crate::common::{start, TestContext, TestWorkerExecutor};
+use crate::common::{start, start_with_virtual_clock, TestContext, TestWorkerExecutor};
 use golem_test_framework::config::TestDependencies;
 use golem_test_framework::dsl::{
     drain_connection, is_worker_execution_error, stdout_event, worker_error_message, TestDsl,
 };
-use tokio::time::sleep;
+use tokio::time::{sleep, timeout};
 use tonic::transport::Body;
 use tracing::debug;
 use warp::Filter;
@@ -299,6 +304,7 @@ async fn promise() {
                 .into(),
             ),
             data: vec![42],
+            failed: false,
         })
         .await
         .unwrap();
@@ -310,6 +316,335 @@ async fn promise() {
     check!(result == Ok(vec![Value::List(vec![Value::U8(42)])]));
 }
 
+#[tokio::test]
+#[tracing::instrument]
+async fn promise_failed() {
+    let context = TestContext::new();
+    let executor = start(&context).await.unwrap();
+
+    let component_id = executor.store_component("promise").await;
+    let worker_id = executor.start_worker(&component_id, "promise-2").await;
+
+    let executor_clone = executor.clone();
+    let worker_id_clone = worker_id.clone();
+    let fiber = tokio::spawn(async move {
+        executor_clone
+            .invoke_and_await(&worker_id_clone, "run", vec![])
+            .await
+    });
+
+    sleep(Duration::from_secs(10)).await;
+
+    executor
+        .client()
+        .await
+        .complete_promise(CompletePromiseRequest {
+            promise_id: Some(
+                PromiseId {
+                    worker_id: worker_id.clone(),
+                    oplog_idx: 2,
+                }
+                .into(),
+            ),
+            data: "rejected".as_bytes().to_vec(),
+            failed: true,
+        })
+        .await
+        .unwrap();
+
+    let result = fiber.await.unwrap();
+
+    drop(executor);
+
+    check!(result.is_err());
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn list_promises() {
+    let context = TestContext::new();
+    let executor = start(&context).await.unwrap();
+
+    let component_id = executor.store_component("promise").await;
+    let worker_id = executor.start_worker(&component_id, "promise-3").await;
+
+    let executor_clone = executor.clone();
+    let worker_id_clone = worker_id.clone();
+    let fiber = tokio::spawn(async move {
+        executor_clone
+            .invoke_and_await(&worker_id_clone, "run", vec![])
+            .await
+    });
+
+    sleep(Duration::from_secs(10)).await;
+
+    let mut client = executor.client().await;
+    let get_promises = GetPromisesRequest {
+        worker_id: Some(worker_id.clone().into()),
+    };
+
+    let pending = client.get_promises(get_promises.clone()).await.unwrap();
+    let metadata = client
+        .get_worker_metadata(golem_api_grpc::proto::golem::worker::WorkerId::from(
+            worker_id.clone(),
+        ))
+        .await
+        .unwrap();
+
+    client
+        .complete_promise(CompletePromiseRequest {
+            promise_id: Some(
+                PromiseId {
+                    worker_id: worker_id.clone(),
+                    oplog_idx: 2,
+                }
+                .into(),
+            ),
+            data: vec![42],
+            failed: false,
+        })
+        .await
+        .unwrap();
+
+    let _ = fiber.await.unwrap();
+
+    let completed = client.get_promises(get_promises).await.unwrap();
+
+    drop(executor);
+
+    let promises = |response: GetPromisesResponse| match response.result {
+        Some(get_promises_response::Result::Success(success)) => success.promises,
+        _ => panic!("unexpected response"),
+    };
+    let pending = promises(pending.into_inner());
+    let completed = promises(completed.into_inner());
+    let awaited = match metadata.into_inner().result {
+        Some(get_worker_metadata_response::Result::Success(metadata)) => {
+            metadata.awaited_promise_oplog_idx
+        }
+        _ => None,
+    };
+
+    check!(pending.len() == 1);
+    check!(pending[0].oplog_idx == 2);
+    check!(pending[0].status == PromiseStatus::Pending as i32);
+    check!(awaited == Some(2));
+    check!(completed.len() == 1);
+    check!(completed[0].status == PromiseStatus::Completed as i32);
+    check!(completed[0].completed_at.is_some());
+    check!(completed[0].payload_size == Some(1));
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn deleting_a_worker_deletes_its_promises() {
+    let context = TestContext::new();
+    let executor = start(&context).await.unwrap();
+
+    let component_id = executor.store_component("promise").await;
+    let worker_id = executor.start_worker(&component_id, "promise-5").await;
+
+    let executor_clone = executor.clone();
+    let worker_id_clone = worker_id.clone();
+    let _fiber = tokio::spawn(async move {
+        executor_clone
+            .invoke_and_await(&worker_id_clone, "run", vec![])
+            .await
+    });
+
+    sleep(Duration::from_secs(10)).await;
+
+    let mut client = executor.client().await;
+    let get_promises = GetPromisesRequest {
+        worker_id: Some(worker_id.clone().into()),
+    };
+
+    let before = client.get_promises(get_promises.clone()).await.unwrap();
+    executor.delete_worker(&worker_id).await;
+    let after = client.get_promises(get_promises).await.unwrap();
+
+    drop(executor);
+
+    let promises = |response: GetPromisesResponse| match response.result {
+        Some(get_promises_response::Result::Success(success)) => success.promises,
+        _ => panic!("unexpected response"),
+    };
+
+    check!(promises(before.into_inner()).len() == 1);
+    check!(promises(after.into_inner()).is_empty());
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn promise_rejection_is_returned_by_await_promise_result() {
+    let context = TestContext::new();
+    let executor = start(&context).await.unwrap();
+
+    let component_id = executor.store_component("promise").await;
+    let worker_id = executor.start_worker(&component_id, "promise-4").await;
+
+    let executor_clone = executor.clone();
+    let worker_id_clone = worker_id.clone();
+    let fiber = tokio::spawn(async move {
+        executor_clone
+            .invoke_and_await(
+                &worker_id_clone,
+                "await-with-timeout",
+                vec![Value::U64(3600)],
+            )
+            .await
+    });
+
+    sleep(Duration::from_secs(10)).await;
+
+    let mut client = executor.client().await;
+    let promises = client
+        .get_promises(GetPromisesRequest {
+            worker_id: Some(worker_id.clone().into()),
+        })
+        .await
+        .unwrap();
+    let oplog_idx = match promises.into_inner().result {
+        Some(get_promises_response::Result::Success(success)) => success.promises[0].oplog_idx,
+        _ => panic!("unexpected response"),
+    };
+
+    client
+        .complete_promise(CompletePromiseRequest {
+            promise_id: Some(
+                PromiseId {
+                    worker_id: worker_id.clone(),
+                    oplog_idx,
+                }
+                .into(),
+            ),
+            data: "rejected".as_bytes().to_vec(),
+            failed: true,
+        })
+        .await
+        .unwrap();
+
+    let result = fiber.await.unwrap();
+
+    drop(executor);
+
+    check!(
+        result
+            == Ok(vec![Value::Tuple(vec![
+                Value::String("failed".to_string()),
+                Value::List("rejected".bytes().map(Value::U8).collect()),
+            ])])
+    );
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn promise_times_out_at_its_deadline() {
+    let context = TestContext::new();
+    let executor = start_with_virtual_clock(&context).await.unwrap();
+
+    let component_id = executor.store_component("promise").await;
+    let worker_id = executor.start_worker(&component_id, "promise-5").await;
+
+    let executor_clone = executor.clone();
+    let worker_id_clone = worker_id.clone();
+    let fiber = tokio::spawn(async move {
+        executor_clone
+            .invoke_and_await(&worker_id_clone, "await-with-timeout", vec![Value::U64(60)])
+            .await
+    });
+
+    sleep(Duration::from_secs(10)).await;
+
+    executor.advance_time(Duration::from_secs(61)).await;
+
+    let result = fiber.await.unwrap();
+
+    drop(executor);
+
+    check!(
+        result
+            == Ok(vec![Value::Tuple(vec![
+                Value::String("timed-out".to_string()),
+                Value::List(vec![]),
+            ])])
+    );
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn receive_suspends_the_worker_until_a_message_arrives() {
+    let context = TestContext::new();
+    let executor = start(&context).await.unwrap();
+
+    let component_id = executor.store_component("promise").await;
+    let worker_id = executor.start_worker(&component_id, "mailbox-1").await;
+
+    let send = |payload: Vec<u8>, idempotency_key: IdempotencyKey| SendMailboxMessageRequest {
+        worker_id: Some(worker_id.clone().into()),
+        payload,
+        idempotency_key: Some(idempotency_key.into()),
+    };
+    // A retried send with the same key only appends the message once
+    let first_key = IdempotencyKey::fresh();
+    for _ in 0..2 {
+        executor
+            .client()
+            .await
+            .send_mailbox_message(send(vec![1], first_key.clone()))
+            .await
+            .unwrap();
+    }
+
+    let executor_clone = executor.clone();
+    let worker_id_clone = worker_id.clone();
+    let fiber = tokio::spawn(async move {
+        executor_clone
+            .invoke_and_await(&worker_id_clone, "receive-messages", vec![Value::U32(2)])
+            .await
+    });
+
+    sleep(Duration::from_secs(10)).await;
+
+    let status = executor
+        .get_worker_metadata(&worker_id)
+        .await
+        .unwrap()
+        .last_known_status
+        .status;
+
+    executor
+        .client()
+        .await
+        .send_mailbox_message(send(vec![2], IdempotencyKey::fresh()))
+        .await
+        .unwrap();
+
+    let result = fiber.await.unwrap();
+
+    // The received messages are removed from the mailbox
+    let mut redis = executor.redis().get_connection(0);
+    let remaining: usize = redis
+        .xlen(format!(
+            "{}instance:mailbox:{}",
+            context.redis_prefix(),
+            worker_id.to_redis_key()
+        ))
+        .unwrap();
+
+    drop(executor);
+
+    check!(status == WorkerStatus::Suspended);
+    check!(
+        result
+            == Ok(vec![Value::List(vec![
+                Value::List(vec![Value::U8(1)]),
+                Value::List(vec![Value::U8(2)]),
+            ])])
+    );
+    check!(remaining == 0);
+}
+
 #[tokio::test]
 #[tracing::instrument]
 async fn get_self_uri() {
@@ -539,6 +874,77 @@ async fn invoking_with_same_idempotency_key_is_idempotent_after_restart() {
     );
 }
 
+fn component_counter(name: &str, component_id: &ComponentId) -> f64 {
+    let component_id = component_id.to_string();
+    prometheus::default_registry()
+        .gather()
+        .iter()
+        .filter(|family| family.get_name() == name)
+        .flat_map(|family| family.get_metric().to_vec())
+        .filter(|metric| {
+            metric.get_label().iter().any(|label| {
+                label.get_name() == "component_id" && label.get_value() == component_id
+            })
+        })
+        .map(|metric| metric.get_counter().get_value())
+        .sum()
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn replayed_invocations_are_not_counted_in_component_metrics() {
+    let context = TestContext::new();
+    let executor = start(&context).await.unwrap();
+
+    let component_id = executor.store_component("shopping-cart").await;
+    let worker_id = executor
+        .start_worker(&component_id, "shopping-cart-metrics")
+        .await;
+
+    let idempotency_key = IdempotencyKey::fresh();
+    let add_item = vec![Value::Record(vec![
+        Value::String("G1000".to_string()),
+        Value::String("Golem T-Shirt M".to_string()),
+        Value::F32(100.0),
+        Value::U32(5),
+    ])];
+    executor
+        .invoke_and_await_with_key(
+            &worker_id,
+            &idempotency_key,
+            "golem:it/api/add-item",
+            add_item.clone(),
+        )
+        .await
+        .unwrap();
+
+    let fuel_before_restart = component_counter("component_fuel_consumed_total", &component_id);
+    let invocations_before_restart = component_counter("component_invocation_total", &component_id);
+
+    drop(executor);
+    let executor = start(&context).await.unwrap();
+
+    // Recovers the worker by replaying the first invocation, without running a live one
+    executor
+        .invoke_and_await_with_key(
+            &worker_id,
+            &idempotency_key,
+            "golem:it/api/add-item",
+            add_item,
+        )
+        .await
+        .unwrap();
+
+    let fuel_after_restart = component_counter("component_fuel_consumed_total", &component_id);
+    let invocations_after_restart = component_counter("component_invocation_total", &component_id);
+
+    drop(executor);
+
+    check!(fuel_before_restart > 0.0);
+    check!(fuel_after_restart == fuel_before_restart);
+    check!(invocations_after_restart == invocations_before_restart);
+}
+
 #[tokio::test]
 #[tracing::instrument]
 async fn optional_parameters() {
@@ -2034,3 +2440,125 @@ async fn invocation_queue_is_persistent() {
 
     check!(result == vec![Value::U64(4)]);
 }
+
+#[tokio::test]
+#[tracing::instrument]
+async fn ephemeral_invocations_do_not_keep_any_state() {
+    let context = TestContext::new();
+    let executor = start(&context).await.unwrap();
+
+    let component_id = executor.store_component("counters").await;
+    let worker_id = WorkerId {
+        component_id,
+        worker_name: "ephemeral-1".to_string(),
+    };
+
+    let ephemeral_request = |function: &str, params: Vec<Value>| InvokeAndAwaitWorkerRequest {
+        worker_id: Some(worker_id.clone().into()),
+        idempotency_key: Some(IdempotencyKey::fresh().into()),
+        name: function.to_string(),
+        input: params.into_iter().map(|value| value.into()).collect(),
+        calling_convention: CallingConvention::Component as i32,
+        account_id: Some(
+            AccountId {
+                value: "test-account".to_string(),
+            }
+            .into(),
+        ),
+        account_limits: Some(ResourceLimits {
+            available_fuel: i64::MAX,
+            max_memory_per_worker: i64::MAX,
+        }),
+        call_chain: vec![],
+        ephemeral: true,
+    };
+
+    let mut client = executor.client().await;
+    let increment = client
+        .invoke_and_await_worker(ephemeral_request(
+            "rpc:counters/api/inc-global-by",
+            vec![Value::U64(1)],
+        ))
+        .await
+        .unwrap()
+        .into_inner();
+    let value = client
+        .invoke_and_await_worker(ephemeral_request(
+            "rpc:counters/api/get-global-value",
+            vec![],
+        ))
+        .await
+        .unwrap()
+        .into_inner();
+
+    let metadata = executor.get_worker_metadata(&worker_id).await;
+
+    drop(executor);
+
+    check!(matches!(
+        increment.result,
+        Some(invoke_and_await_worker_response::Result::Success(_))
+    ));
+    let output = match value.result {
+        Some(invoke_and_await_worker_response::Result::Success(success)) => success
+            .output
+            .into_iter()
+            .map(|value| value.try_into().unwrap())
+            .collect::<Vec<Value>>(),
+        _ => panic!("unexpected response"),
+    };
+    // Both invocations got a fresh worker, and none of them was stored
+    check!(output == vec![Value::U64(0)]);
+    check!(metadata.is_none());
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn ephemeral_invocations_fail_when_the_worker_gets_suspended() {
+    let context = TestContext::new();
+    let executor = start(&context).await.unwrap();
+
+    let component_id = executor.store_component("promise").await;
+    let worker_id = WorkerId {
+        component_id,
+        worker_name: "ephemeral-promise-1".to_string(),
+    };
+
+    // `run` awaits a promise nobody completes, which suspends the worker
+    let result = timeout(
+        Duration::from_secs(30),
+        executor
+            .client()
+            .await
+            .invoke_and_await_worker(InvokeAndAwaitWorkerRequest {
+                worker_id: Some(worker_id.clone().into()),
+                idempotency_key: Some(IdempotencyKey::fresh().into()),
+                name: "run".to_string(),
+                input: vec![],
+                calling_convention: CallingConvention::Component as i32,
+                account_id: Some(
+                    AccountId {
+                        value: "test-account".to_string(),
+                    }
+                    .into(),
+                ),
+                account_limits: Some(ResourceLimits {
+                    available_fuel: i64::MAX,
+                    max_memory_per_worker: i64::MAX,
+                }),
+                call_chain: vec![],
+                ephemeral: true,
+            }),
+    )
+    .await
+    .expect("the ephemeral invocation should not wait for the suspended worker")
+    .unwrap()
+    .into_inner();
+
+    drop(executor);
+
+    check!(matches!(
+        result.result,
+        Some(invoke_and_await_worker_response::Result::Failure(_))
+    ));
+}
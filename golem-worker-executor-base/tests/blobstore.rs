@@ -1,4 +1,4 @@
-use crate::common::{start, TestContext};
+use crate::common::{start, start_customized, TestContext};
 use assert2::check;
 use golem_test_framework::dsl::TestDsl;
 use golem_wasm_rpc::Value;
@@ -65,3 +65,143 @@ async fn blobstore_exists_return_false_if_the_container_was_not_created() {
 
     check!(result == vec![Value::Bool(false)]);
 }
+
+#[tokio::test]
+#[tracing::instrument]
+async fn blobstore_object_attributes_are_stored_with_the_object() {
+    let context = TestContext::new();
+    let executor = start(&context).await.unwrap();
+
+    let component_id = executor.store_component("blob-store-service").await;
+    let worker_name = "blob-store-service-attributes";
+    let worker_id = executor.start_worker(&component_id, worker_name).await;
+    let container_name = format!("{component_id}-{worker_name}-container");
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api/create-container",
+            vec![Value::String(container_name.clone())],
+        )
+        .await
+        .unwrap();
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api/write-object",
+            vec![
+                Value::String(container_name.clone()),
+                Value::String("object".to_string()),
+                Value::List(vec![Value::U8(1), Value::U8(2), Value::U8(3)]),
+                Value::Option(Some(Box::new(Value::String("text/plain".to_string())))),
+                Value::List(vec![
+                    Value::Tuple(vec![
+                        Value::String("owner".to_string()),
+                        Value::String("golem".to_string()),
+                    ]),
+                    Value::Tuple(vec![
+                        Value::String("kind".to_string()),
+                        Value::String("test".to_string()),
+                    ]),
+                ]),
+            ],
+        )
+        .await
+        .unwrap();
+
+    let result = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api/get-object-attributes",
+            vec![
+                Value::String(container_name),
+                Value::String("object".to_string()),
+            ],
+        )
+        .await
+        .unwrap();
+
+    drop(executor);
+
+    check!(
+        result
+            == vec![Value::Tuple(vec![
+                Value::Option(Some(Box::new(Value::String("text/plain".to_string())))),
+                Value::List(vec![
+                    Value::Tuple(vec![
+                        Value::String("kind".to_string()),
+                        Value::String("test".to_string()),
+                    ]),
+                    Value::Tuple(vec![
+                        Value::String("owner".to_string()),
+                        Value::String("golem".to_string()),
+                    ]),
+                ]),
+            ])]
+    );
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn blobstore_objects_larger_than_a_part_are_written_in_parts() {
+    let context = TestContext::new();
+    let executor = start_customized(&context, None, |config| {
+        config.blob_store.part_size = 1024;
+    })
+    .await
+    .unwrap();
+
+    let component_id = executor.store_component("blob-store-service").await;
+    let worker_name = "blob-store-service-multipart";
+    let worker_id = executor.start_worker(&component_id, worker_name).await;
+    let container_name = format!("{component_id}-{worker_name}-container");
+    let data = (0..3000u32).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api/create-container",
+            vec![Value::String(container_name.clone())],
+        )
+        .await
+        .unwrap();
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api/write-object",
+            vec![
+                Value::String(container_name.clone()),
+                Value::String("object".to_string()),
+                Value::List(data.iter().map(|b| Value::U8(*b)).collect()),
+                Value::Option(None),
+                Value::List(vec![]),
+            ],
+        )
+        .await
+        .unwrap();
+
+    let read_object = || {
+        executor.invoke_and_await(
+            &worker_id,
+            "golem:it/api/read-object",
+            vec![
+                Value::String(container_name.clone()),
+                Value::String("object".to_string()),
+            ],
+        )
+    };
+
+    let before_crash = read_object().await.unwrap();
+
+    // The worker is recovered by replaying the multipart write from its oplog
+    executor.simulated_crash(&worker_id).await;
+    let after_crash = read_object().await.unwrap();
+
+    drop(executor);
+
+    let expected = vec![Value::List(data.into_iter().map(Value::U8).collect())];
+    check!(before_crash == expected);
+    check!(after_crash == expected);
+}
@@ -38,6 +38,7 @@ use golem_worker_executor_base::services::active_workers::ActiveWorkers;
 use golem_worker_executor_base::services::blob_store::BlobStoreService;
 use golem_worker_executor_base::services::component::ComponentService;
 use golem_worker_executor_base::services::key_value::KeyValueService;
+use golem_worker_executor_base::services::mailbox::MailboxService;
 use golem_worker_executor_base::services::oplog::{Oplog, OplogService};
 use golem_worker_executor_base::services::promise::PromiseService;
 use golem_worker_executor_base::services::recovery::{
@@ -46,6 +47,7 @@ use golem_worker_executor_base::services::recovery::{
 use golem_worker_executor_base::services::scheduler::SchedulerService;
 use golem_worker_executor_base::services::shard::ShardService;
 use golem_worker_executor_base::services::shard_manager::ShardManagerService;
+use golem_worker_executor_base::services::topic::TopicService;
 use golem_worker_executor_base::services::worker::WorkerService;
 use golem_worker_executor_base::services::worker_activator::WorkerActivator;
 use golem_worker_executor_base::services::worker_event::WorkerEventService;
@@ -62,6 +64,7 @@ use tokio::runtime::Handle;
 use tokio::task::JoinHandle;
 
 use golem::api;
+use golem_common::clock::{Clock, SystemClock, VirtualClock};
 use golem_common::config::RedisConfig;
 
 use golem_api_grpc::proto::golem::workerexecutor::{
@@ -86,6 +89,7 @@ use golem_worker_executor_base::services::rpc::{
 use golem_worker_executor_base::services::worker_enumeration::{
     RunningWorkerEnumerationService, WorkerEnumerationService,
 };
+use golem_worker_executor_base::services::worker_event_log::WorkerEventLogService;
 use golem_worker_executor_base::services::worker_proxy::WorkerProxy;
 use tonic::transport::Channel;
 use tracing::{error, info};
@@ -95,6 +99,7 @@ use wasmtime::{AsContextMut, Engine, ResourceLimiterAsync};
 pub struct TestWorkerExecutor {
     handle: Option<JoinHandle<Result<(), String>>>,
     deps: WorkerExecutorPerTestDependencies,
+    virtual_clock: Option<Arc<VirtualClock>>,
 }
 
 impl TestWorkerExecutor {
@@ -173,6 +178,7 @@ impl Clone for TestWorkerExecutor {
         Self {
             handle: None,
             deps: self.deps.clone(),
+            virtual_clock: self.virtual_clock.clone(),
         }
     }
 }
@@ -226,6 +232,10 @@ impl TestDependencies for TestWorkerExecutor {
     fn worker_executor_cluster(&self) -> Arc<dyn WorkerExecutorCluster + Send + Sync + 'static> {
         self.deps.worker_executor_cluster()
     }
+
+    fn virtual_clock(&self) -> Option<Arc<VirtualClock>> {
+        self.virtual_clock.clone()
+    }
 }
 
 impl Drop for TestWorkerExecutor {
@@ -267,6 +277,22 @@ impl TestContext {
 }
 
 pub async fn start(context: &TestContext) -> anyhow::Result<TestWorkerExecutor> {
+    start_customized(context, None, |_| {}).await
+}
+
+/// Starts a worker executor whose scheduler and worker clocks are driven by a virtual clock,
+/// which can be moved forward with `TestDsl::advance_time`
+pub async fn start_with_virtual_clock(context: &TestContext) -> anyhow::Result<TestWorkerExecutor> {
+    start_customized(context, Some(Arc::new(VirtualClock::new())), |_| {}).await
+}
+
+/// Starts a worker executor with an optional virtual clock, letting the test adjust the
+/// configuration before the executor is started
+pub async fn start_customized(
+    context: &TestContext,
+    virtual_clock: Option<Arc<VirtualClock>>,
+    customize: impl FnOnce(&mut GolemConfig),
+) -> anyhow::Result<TestWorkerExecutor> {
     let redis = BASE_DEPS.redis();
     let redis_monitor = BASE_DEPS.redis_monitor();
     redis.assert_valid();
@@ -274,7 +300,7 @@ pub async fn start(context: &TestContext) -> anyhow::Result<TestWorkerExecutor>
     println!("Using Redis on port {}", redis.public_port());
 
     let prometheus = golem_worker_executor_base::metrics::register_all();
-    let config = GolemConfig {
+    let mut config = GolemConfig {
         port: context.grpc_port(),
         http_port: context.http_port(),
         component_service: ComponentServiceConfig::Local(ComponentServiceLocalConfig {
@@ -302,13 +328,17 @@ pub async fn start(context: &TestContext) -> anyhow::Result<TestWorkerExecutor>
         },
         ..Default::default()
     };
+    customize(&mut config);
 
     let handle = Handle::current();
 
     let grpc_port = config.port;
 
+    let bootstrap = ServerBootstrap {
+        virtual_clock: virtual_clock.clone(),
+    };
     let server_handle = tokio::spawn(async move {
-        let r = run(config, prometheus, handle)
+        let r = run(bootstrap, config, prometheus, handle)
             .await
             .map_err(|e| format!("{e}"));
         match &r {
@@ -330,6 +360,7 @@ pub async fn start(context: &TestContext) -> anyhow::Result<TestWorkerExecutor>
             break Ok(TestWorkerExecutor {
                 handle: Some(server_handle),
                 deps,
+                virtual_clock,
             });
         } else if start.elapsed().as_secs() > 10 {
             break Err(anyhow::anyhow!("Timeout waiting for server to start"));
@@ -338,12 +369,13 @@ pub async fn start(context: &TestContext) -> anyhow::Result<TestWorkerExecutor>
 }
 
 async fn run(
+    bootstrap: ServerBootstrap,
     golem_config: GolemConfig,
     prometheus_registry: Registry,
     runtime: Handle,
 ) -> Result<(), Box<dyn std::error::Error>> {
     info!("Golem Worker Executor starting up...");
-    Ok(ServerBootstrap {}
+    Ok(bootstrap
         .run(golem_config, prometheus_registry, runtime)
         .await?)
 }
@@ -606,7 +638,9 @@ impl UpdateManagement for TestWorkerCtx {
     }
 }
 
-struct ServerBootstrap {}
+struct ServerBootstrap {
+    virtual_clock: Option<Arc<VirtualClock>>,
+}
 
 #[async_trait]
 impl WorkerCtx for TestWorkerCtx {
@@ -627,9 +661,12 @@ impl WorkerCtx for TestWorkerCtx {
         oplog: Arc<dyn Oplog + Send + Sync>,
         invocation_queue: Arc<InvocationQueue<TestWorkerCtx>>,
         scheduler_service: Arc<dyn SchedulerService + Send + Sync>,
+        topic_service: Arc<dyn TopicService + Send + Sync>,
+        mailbox_service: Arc<dyn MailboxService + Send + Sync>,
         recovery_management: Arc<dyn RecoveryManagement + Send + Sync>,
         rpc: Arc<dyn Rpc + Send + Sync>,
         worker_proxy: Arc<dyn WorkerProxy + Send + Sync>,
+        clock: Arc<dyn Clock + Send + Sync>,
         _extra_deps: Self::ExtraDeps,
         config: Arc<GolemConfig>,
         worker_config: WorkerConfig,
@@ -650,9 +687,12 @@ impl WorkerCtx for TestWorkerCtx {
             oplog,
             invocation_queue,
             scheduler_service,
+            topic_service,
+            mailbox_service,
             recovery_management,
             rpc,
             worker_proxy,
+            clock,
             config,
             worker_config,
             execution_status,
@@ -739,8 +779,12 @@ impl Bootstrap<TestWorkerCtx> for ServerBootstrap {
         worker_activator: Arc<dyn WorkerActivator + Send + Sync>,
         oplog_service: Arc<dyn OplogService + Send + Sync>,
         scheduler_service: Arc<dyn SchedulerService + Send + Sync>,
+        topic_service: Arc<dyn TopicService + Send + Sync>,
+        mailbox_service: Arc<dyn MailboxService + Send + Sync>,
         worker_proxy: Arc<dyn WorkerProxy + Send + Sync>,
         events: Arc<Events>,
+        worker_event_log_service: Arc<dyn WorkerEventLogService + Send + Sync>,
+        clock: Arc<dyn Clock + Send + Sync>,
     ) -> anyhow::Result<All<TestWorkerCtx>> {
         let rpc = Arc::new(DirectWorkerInvocationRpc::new(
             Arc::new(RemoteInvocationRpc::new(worker_proxy.clone())),
@@ -760,8 +804,12 @@ impl Bootstrap<TestWorkerCtx> for ServerBootstrap {
             blob_store_service.clone(),
             oplog_service.clone(),
             scheduler_service.clone(),
+            topic_service.clone(),
+            mailbox_service.clone(),
             worker_activator.clone(),
             events.clone(),
+            worker_event_log_service.clone(),
+            clock.clone(),
             (),
         ));
         let recovery_management = Arc::new(RecoveryManagementDefault::new(
@@ -776,12 +824,16 @@ impl Bootstrap<TestWorkerCtx> for ServerBootstrap {
             oplog_service.clone(),
             promise_service.clone(),
             scheduler_service.clone(),
+            topic_service.clone(),
+            mailbox_service.clone(),
             key_value_service.clone(),
             blob_store_service.clone(),
             rpc.clone(),
             worker_activator.clone(),
             worker_proxy.clone(),
             events.clone(),
+            worker_event_log_service.clone(),
+            clock.clone(),
             golem_config.clone(),
             (),
         ));
@@ -806,13 +858,24 @@ impl Bootstrap<TestWorkerCtx> for ServerBootstrap {
             recovery_management,
             rpc,
             scheduler_service,
+            topic_service,
+            mailbox_service,
             worker_activator,
             worker_proxy,
             events.clone(),
+            worker_event_log_service,
+            clock,
             (),
         ))
     }
 
+    fn create_clock(&self) -> Arc<dyn Clock + Send + Sync> {
+        match &self.virtual_clock {
+            Some(virtual_clock) => virtual_clock.clone(),
+            None => Arc::new(SystemClock::new()),
+        }
+    }
+
     fn create_wasmtime_linker(&self, engine: &Engine) -> anyhow::Result<Linker<TestWorkerCtx>> {
         let mut linker =
             create_linker::<TestWorkerCtx, DurableWorkerCtx<TestWorkerCtx>>(engine, |x| {
@@ -826,6 +889,38 @@ impl Bootstrap<TestWorkerCtx> for ServerBootstrap {
             TestWorkerCtx,
             DurableWorkerCtx<TestWorkerCtx>,
         >(&mut linker, |x| &mut x.durable_ctx)?;
+        golem::ext::async_rpc::add_to_linker::<TestWorkerCtx, DurableWorkerCtx<TestWorkerCtx>>(
+            &mut linker,
+            |x| &mut x.durable_ctx,
+        )?;
+        golem::ext::broadcast::add_to_linker::<TestWorkerCtx, DurableWorkerCtx<TestWorkerCtx>>(
+            &mut linker,
+            |x| &mut x.durable_ctx,
+        )?;
+        golem::ext::topics::add_to_linker::<TestWorkerCtx, DurableWorkerCtx<TestWorkerCtx>>(
+            &mut linker,
+            |x| &mut x.durable_ctx,
+        )?;
+        golem::ext::mailbox::add_to_linker::<TestWorkerCtx, DurableWorkerCtx<TestWorkerCtx>>(
+            &mut linker,
+            |x| &mut x.durable_ctx,
+        )?;
+        golem::ext::saga::add_to_linker::<TestWorkerCtx, DurableWorkerCtx<TestWorkerCtx>>(
+            &mut linker,
+            |x| &mut x.durable_ctx,
+        )?;
+        golem::ext::keyvalue_keys::add_to_linker::<TestWorkerCtx, DurableWorkerCtx<TestWorkerCtx>>(
+            &mut linker,
+            |x| &mut x.durable_ctx,
+        )?;
+        golem::ext::blobstore_attributes::add_to_linker::<
+            TestWorkerCtx,
+            DurableWorkerCtx<TestWorkerCtx>,
+        >(&mut linker, |x| &mut x.durable_ctx)?;
+        golem::ext::promises::add_to_linker::<TestWorkerCtx, DurableWorkerCtx<TestWorkerCtx>>(
+            &mut linker,
+            |x| &mut x.durable_ctx,
+        )?;
         Ok(linker)
     }
 }
@@ -1,13 +1,115 @@
-use crate::common::{start, TestContext};
+use crate::common::{start_customized, TestContext, TestWorkerExecutor};
 use assert2::check;
+use ctor::dtor;
+use golem_api_grpc::proto::golem::workerexecutor::{
+    get_key_value_buckets_response, get_key_value_response, DeleteKeyValueRequest,
+    GetKeyValueBucketsRequest, GetKeyValueRequest, GetKeyValueSuccessResponse, SetKeyValueRequest,
+};
+use golem_common::clock::VirtualClock;
+use golem_test_framework::components::rdb::docker_postgres::DockerPostgresRdb;
+use golem_test_framework::components::rdb::{DbInfo, Rdb};
 use golem_test_framework::dsl::TestDsl;
 use golem_wasm_rpc::Value;
+use golem_worker_executor_base::services::golem_config::{
+    GolemConfig, KeyValueServiceConfig, KeyValueServicePostgresConfig, KeyValueServiceSqliteConfig,
+};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::OnceCell;
+
+/// The key-value backends the same test suite is run against
+#[derive(Debug, Clone, Copy)]
+enum KeyValueBackend {
+    Redis,
+    Sqlite,
+    Postgres,
+}
+
+/// A Postgres container shared by the tests of the Postgres key-value backend, started by the
+/// first of them
+static POSTGRES: OnceCell<DockerPostgresRdb> = OnceCell::const_new();
+
+#[dtor]
+fn stop_postgres() {
+    if let Some(postgres) = POSTGRES.get() {
+        postgres.kill();
+    }
+}
+
+async fn start_with_backend(context: &TestContext, backend: KeyValueBackend) -> TestWorkerExecutor {
+    start_with_backend_customized(context, backend, None, |_| {}).await
+}
+
+async fn start_with_backend_customized(
+    context: &TestContext,
+    backend: KeyValueBackend,
+    virtual_clock: Option<Arc<VirtualClock>>,
+    customize: impl FnOnce(&mut GolemConfig),
+) -> TestWorkerExecutor {
+    let key_value_service = match backend {
+        KeyValueBackend::Redis => KeyValueServiceConfig::Redis,
+        KeyValueBackend::Sqlite => {
+            let database = std::env::temp_dir().join(format!(
+                "golem-key-value-{}-{}.db",
+                std::process::id(),
+                context.grpc_port()
+            ));
+            let _ = std::fs::remove_file(&database);
+            KeyValueServiceConfig::Sqlite(KeyValueServiceSqliteConfig {
+                database: database.to_string_lossy().to_string(),
+                max_connections: 4,
+            })
+        }
+        KeyValueBackend::Postgres => {
+            let postgres = POSTGRES
+                .get_or_init(|| async { DockerPostgresRdb::new(true).await })
+                .await;
+            match postgres.info() {
+                DbInfo::Postgres(info) => {
+                    KeyValueServiceConfig::Postgres(KeyValueServicePostgresConfig {
+                        host: info.host,
+                        port: info.port,
+                        database: info.database_name,
+                        username: info.username,
+                        password: info.password,
+                        max_connections: 4,
+                        // Each test gets its own schema, with a name which has to be quoted
+                        schema: Some(format!("golem-key-value-{}", context.grpc_port())),
+                    })
+                }
+                DbInfo::Sqlite(_) => panic!("Expected a Postgres database"),
+            }
+        }
+    };
+    start_customized(context, virtual_clock, |config| {
+        config.key_value_service = key_value_service;
+        customize(config);
+    })
+    .await
+    .unwrap()
+}
 
 #[tokio::test]
 #[tracing::instrument]
 async fn readwrite_get_returns_the_value_that_was_set() {
+    readwrite_get_returns_the_value_that_was_set_test(KeyValueBackend::Redis).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn readwrite_get_returns_the_value_that_was_set_sqlite() {
+    readwrite_get_returns_the_value_that_was_set_test(KeyValueBackend::Sqlite).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn readwrite_get_returns_the_value_that_was_set_postgres() {
+    readwrite_get_returns_the_value_that_was_set_test(KeyValueBackend::Postgres).await
+}
+
+async fn readwrite_get_returns_the_value_that_was_set_test(backend: KeyValueBackend) {
     let context = TestContext::new();
-    let executor = start(&context).await.unwrap();
+    let executor = start_with_backend(&context, backend).await;
 
     let component_id = executor.store_component("key-value-service").await;
     let worker_name = "key-value-service-1";
@@ -53,8 +155,24 @@ async fn readwrite_get_returns_the_value_that_was_set() {
 #[tokio::test]
 #[tracing::instrument]
 async fn readwrite_get_fails_if_the_value_was_not_set() {
+    readwrite_get_fails_if_the_value_was_not_set_test(KeyValueBackend::Redis).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn readwrite_get_fails_if_the_value_was_not_set_sqlite() {
+    readwrite_get_fails_if_the_value_was_not_set_test(KeyValueBackend::Sqlite).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn readwrite_get_fails_if_the_value_was_not_set_postgres() {
+    readwrite_get_fails_if_the_value_was_not_set_test(KeyValueBackend::Postgres).await
+}
+
+async fn readwrite_get_fails_if_the_value_was_not_set_test(backend: KeyValueBackend) {
     let context = TestContext::new();
-    let executor = start(&context).await.unwrap();
+    let executor = start_with_backend(&context, backend).await;
 
     let component_id = executor.store_component("key-value-service").await;
     let worker_name = "key-value-service-2";
@@ -77,11 +195,195 @@ async fn readwrite_get_fails_if_the_value_was_not_set() {
     check!(result == vec![Value::Option(None)]);
 }
 
+#[tokio::test]
+#[tracing::instrument]
+async fn readwrite_get_returns_none_after_the_ttl_passed() {
+    readwrite_get_returns_none_after_the_ttl_passed_test(KeyValueBackend::Redis).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn readwrite_get_returns_none_after_the_ttl_passed_sqlite() {
+    readwrite_get_returns_none_after_the_ttl_passed_test(KeyValueBackend::Sqlite).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn readwrite_get_returns_none_after_the_ttl_passed_postgres() {
+    readwrite_get_returns_none_after_the_ttl_passed_test(KeyValueBackend::Postgres).await
+}
+
+async fn readwrite_get_returns_none_after_the_ttl_passed_test(backend: KeyValueBackend) {
+    let context = TestContext::new();
+    let executor = start_with_backend_customized(
+        &context,
+        backend,
+        Some(Arc::new(VirtualClock::new())),
+        |config| {
+            config.key_value.default_ttl = Some(Duration::from_secs(3600));
+        },
+    )
+    .await;
+
+    let component_id = executor.store_component("key-value-service").await;
+    let worker_name = "key-value-service-ttl";
+    let worker_id = executor.start_worker(&component_id, worker_name).await;
+    let bucket = format!("{component_id}-{worker_name}-bucket");
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api/set",
+            vec![
+                Value::String(bucket.clone()),
+                Value::String("key".to_string()),
+                Value::List(vec![Value::U8(1), Value::U8(2), Value::U8(3)]),
+            ],
+        )
+        .await
+        .unwrap();
+
+    let before_expiry = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api/get",
+            vec![
+                Value::String(bucket.clone()),
+                Value::String("key".to_string()),
+            ],
+        )
+        .await
+        .unwrap();
+
+    executor.advance_time(Duration::from_secs(3601)).await;
+
+    let after_expiry = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api/get",
+            vec![Value::String(bucket), Value::String("key".to_string())],
+        )
+        .await
+        .unwrap();
+
+    drop(executor);
+
+    check!(
+        before_expiry
+            == vec![Value::Option(Some(Box::new(Value::List(vec![
+                Value::U8(1),
+                Value::U8(2),
+                Value::U8(3),
+            ]))))]
+    );
+    check!(after_expiry == vec![Value::Option(None)]);
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn readwrite_set_after_the_ttl_passed_keeps_the_new_value() {
+    readwrite_set_after_the_ttl_passed_keeps_the_new_value_test(KeyValueBackend::Redis).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn readwrite_set_after_the_ttl_passed_keeps_the_new_value_sqlite() {
+    readwrite_set_after_the_ttl_passed_keeps_the_new_value_test(KeyValueBackend::Sqlite).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn readwrite_set_after_the_ttl_passed_keeps_the_new_value_postgres() {
+    readwrite_set_after_the_ttl_passed_keeps_the_new_value_test(KeyValueBackend::Postgres).await
+}
+
+async fn readwrite_set_after_the_ttl_passed_keeps_the_new_value_test(backend: KeyValueBackend) {
+    let context = TestContext::new();
+    let executor = start_with_backend_customized(
+        &context,
+        backend,
+        Some(Arc::new(VirtualClock::new())),
+        |config| {
+            config.key_value.default_ttl = Some(Duration::from_secs(3600));
+        },
+    )
+    .await;
+
+    let component_id = executor.store_component("key-value-service").await;
+    let worker_name = "key-value-service-ttl-reset";
+    let worker_id = executor.start_worker(&component_id, worker_name).await;
+    let bucket = format!("{component_id}-{worker_name}-bucket");
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api/set",
+            vec![
+                Value::String(bucket.clone()),
+                Value::String("key".to_string()),
+                Value::List(vec![Value::U8(1), Value::U8(2), Value::U8(3)]),
+            ],
+        )
+        .await
+        .unwrap();
+
+    executor.advance_time(Duration::from_secs(3601)).await;
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api/set",
+            vec![
+                Value::String(bucket.clone()),
+                Value::String("key".to_string()),
+                Value::List(vec![Value::U8(4), Value::U8(5), Value::U8(6)]),
+            ],
+        )
+        .await
+        .unwrap();
+
+    let result = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api/get",
+            vec![Value::String(bucket), Value::String("key".to_string())],
+        )
+        .await
+        .unwrap();
+
+    drop(executor);
+
+    check!(
+        result
+            == vec![Value::Option(Some(Box::new(Value::List(vec![
+                Value::U8(4),
+                Value::U8(5),
+                Value::U8(6),
+            ]))))]
+    );
+}
+
 #[tokio::test]
 #[tracing::instrument]
 async fn readwrite_set_replaces_the_value_if_it_was_already_set() {
+    readwrite_set_replaces_the_value_if_it_was_already_set_test(KeyValueBackend::Redis).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn readwrite_set_replaces_the_value_if_it_was_already_set_sqlite() {
+    readwrite_set_replaces_the_value_if_it_was_already_set_test(KeyValueBackend::Sqlite).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn readwrite_set_replaces_the_value_if_it_was_already_set_postgres() {
+    readwrite_set_replaces_the_value_if_it_was_already_set_test(KeyValueBackend::Postgres).await
+}
+
+async fn readwrite_set_replaces_the_value_if_it_was_already_set_test(backend: KeyValueBackend) {
     let context = TestContext::new();
-    let executor = start(&context).await.unwrap();
+    let executor = start_with_backend(&context, backend).await;
 
     let component_id = executor.store_component("key-value-service").await;
     let worker_name = "key-value-service-3";
@@ -140,8 +442,24 @@ async fn readwrite_set_replaces_the_value_if_it_was_already_set() {
 #[tokio::test]
 #[tracing::instrument]
 async fn readwrite_delete_removes_the_value_if_it_was_already_set() {
+    readwrite_delete_removes_the_value_if_it_was_already_set_test(KeyValueBackend::Redis).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn readwrite_delete_removes_the_value_if_it_was_already_set_sqlite() {
+    readwrite_delete_removes_the_value_if_it_was_already_set_test(KeyValueBackend::Sqlite).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn readwrite_delete_removes_the_value_if_it_was_already_set_postgres() {
+    readwrite_delete_removes_the_value_if_it_was_already_set_test(KeyValueBackend::Postgres).await
+}
+
+async fn readwrite_delete_removes_the_value_if_it_was_already_set_test(backend: KeyValueBackend) {
     let context = TestContext::new();
-    let executor = start(&context).await.unwrap();
+    let executor = start_with_backend(&context, backend).await;
 
     let component_id = executor.store_component("key-value-service").await;
     let worker_name = "key-value-service-4";
@@ -192,8 +510,24 @@ async fn readwrite_delete_removes_the_value_if_it_was_already_set() {
 #[tokio::test]
 #[tracing::instrument]
 async fn readwrite_exists_returns_true_if_the_value_was_set() {
+    readwrite_exists_returns_true_if_the_value_was_set_test(KeyValueBackend::Redis).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn readwrite_exists_returns_true_if_the_value_was_set_sqlite() {
+    readwrite_exists_returns_true_if_the_value_was_set_test(KeyValueBackend::Sqlite).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn readwrite_exists_returns_true_if_the_value_was_set_postgres() {
+    readwrite_exists_returns_true_if_the_value_was_set_test(KeyValueBackend::Postgres).await
+}
+
+async fn readwrite_exists_returns_true_if_the_value_was_set_test(backend: KeyValueBackend) {
     let context = TestContext::new();
-    let executor = start(&context).await.unwrap();
+    let executor = start_with_backend(&context, backend).await;
 
     let component_id = executor.store_component("key-value-service").await;
     let worker_name = "key-value-service-5";
@@ -232,8 +566,24 @@ async fn readwrite_exists_returns_true_if_the_value_was_set() {
 #[tokio::test]
 #[tracing::instrument]
 async fn readwrite_exists_returns_false_if_the_value_was_not_set() {
+    readwrite_exists_returns_false_if_the_value_was_not_set_test(KeyValueBackend::Redis).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn readwrite_exists_returns_false_if_the_value_was_not_set_sqlite() {
+    readwrite_exists_returns_false_if_the_value_was_not_set_test(KeyValueBackend::Sqlite).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn readwrite_exists_returns_false_if_the_value_was_not_set_postgres() {
+    readwrite_exists_returns_false_if_the_value_was_not_set_test(KeyValueBackend::Postgres).await
+}
+
+async fn readwrite_exists_returns_false_if_the_value_was_not_set_test(backend: KeyValueBackend) {
     let context = TestContext::new();
-    let executor = start(&context).await.unwrap();
+    let executor = start_with_backend(&context, backend).await;
 
     let component_id = executor.store_component("key-value-service").await;
     let worker_name = "key-value-service-6";
@@ -259,8 +609,24 @@ async fn readwrite_exists_returns_false_if_the_value_was_not_set() {
 #[tokio::test]
 #[tracing::instrument]
 async fn readwrite_buckets_can_be_shared_between_workers() {
+    readwrite_buckets_can_be_shared_between_workers_test(KeyValueBackend::Redis).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn readwrite_buckets_can_be_shared_between_workers_sqlite() {
+    readwrite_buckets_can_be_shared_between_workers_test(KeyValueBackend::Sqlite).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn readwrite_buckets_can_be_shared_between_workers_postgres() {
+    readwrite_buckets_can_be_shared_between_workers_test(KeyValueBackend::Postgres).await
+}
+
+async fn readwrite_buckets_can_be_shared_between_workers_test(backend: KeyValueBackend) {
     let context = TestContext::new();
-    let executor = start(&context).await.unwrap();
+    let executor = start_with_backend(&context, backend).await;
 
     let component_id = executor.store_component("key-value-service").await;
     let worker_id_1 = executor
@@ -310,8 +676,24 @@ async fn readwrite_buckets_can_be_shared_between_workers() {
 #[tokio::test]
 #[tracing::instrument]
 async fn batch_get_many_gets_multiple_values() {
+    batch_get_many_gets_multiple_values_test(KeyValueBackend::Redis).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn batch_get_many_gets_multiple_values_sqlite() {
+    batch_get_many_gets_multiple_values_test(KeyValueBackend::Sqlite).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn batch_get_many_gets_multiple_values_postgres() {
+    batch_get_many_gets_multiple_values_test(KeyValueBackend::Postgres).await
+}
+
+async fn batch_get_many_gets_multiple_values_test(backend: KeyValueBackend) {
     let context = TestContext::new();
-    let executor = start(&context).await.unwrap();
+    let executor = start_with_backend(&context, backend).await;
 
     let component_id = executor.store_component("key-value-service").await;
     let worker_name = "key-value-service-9";
@@ -387,8 +769,24 @@ async fn batch_get_many_gets_multiple_values() {
 #[tokio::test]
 #[tracing::instrument]
 async fn batch_get_many_fails_if_any_value_was_not_set() {
+    batch_get_many_fails_if_any_value_was_not_set_test(KeyValueBackend::Redis).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn batch_get_many_fails_if_any_value_was_not_set_sqlite() {
+    batch_get_many_fails_if_any_value_was_not_set_test(KeyValueBackend::Sqlite).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn batch_get_many_fails_if_any_value_was_not_set_postgres() {
+    batch_get_many_fails_if_any_value_was_not_set_test(KeyValueBackend::Postgres).await
+}
+
+async fn batch_get_many_fails_if_any_value_was_not_set_test(backend: KeyValueBackend) {
     let context = TestContext::new();
-    let executor = start(&context).await.unwrap();
+    let executor = start_with_backend(&context, backend).await;
 
     let component_id = executor.store_component("key-value-service").await;
     let worker_name = "key-value-service-10";
@@ -444,8 +842,24 @@ async fn batch_get_many_fails_if_any_value_was_not_set() {
 #[tokio::test]
 #[tracing::instrument]
 async fn batch_set_many_sets_multiple_values() {
+    batch_set_many_sets_multiple_values_test(KeyValueBackend::Redis).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn batch_set_many_sets_multiple_values_sqlite() {
+    batch_set_many_sets_multiple_values_test(KeyValueBackend::Sqlite).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn batch_set_many_sets_multiple_values_postgres() {
+    batch_set_many_sets_multiple_values_test(KeyValueBackend::Postgres).await
+}
+
+async fn batch_set_many_sets_multiple_values_test(backend: KeyValueBackend) {
     let context = TestContext::new();
-    let executor = start(&context).await.unwrap();
+    let executor = start_with_backend(&context, backend).await;
 
     let component_id = executor.store_component("key-value-service").await;
     let worker_name = "key-value-service-11";
@@ -543,8 +957,24 @@ async fn batch_set_many_sets_multiple_values() {
 #[tokio::test]
 #[tracing::instrument]
 async fn batch_delete_many_deletes_multiple_values() {
+    batch_delete_many_deletes_multiple_values_test(KeyValueBackend::Redis).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn batch_delete_many_deletes_multiple_values_sqlite() {
+    batch_delete_many_deletes_multiple_values_test(KeyValueBackend::Sqlite).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn batch_delete_many_deletes_multiple_values_postgres() {
+    batch_delete_many_deletes_multiple_values_test(KeyValueBackend::Postgres).await
+}
+
+async fn batch_delete_many_deletes_multiple_values_test(backend: KeyValueBackend) {
     let context = TestContext::new();
-    let executor = start(&context).await.unwrap();
+    let executor = start_with_backend(&context, backend).await;
 
     let component_id = executor.store_component("key-value-service").await;
     let worker_name = "key-value-service-12";
@@ -651,8 +1081,24 @@ async fn batch_delete_many_deletes_multiple_values() {
 #[tokio::test]
 #[tracing::instrument]
 async fn batch_get_keys_returns_multiple_keys() {
+    batch_get_keys_returns_multiple_keys_test(KeyValueBackend::Redis).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn batch_get_keys_returns_multiple_keys_sqlite() {
+    batch_get_keys_returns_multiple_keys_test(KeyValueBackend::Sqlite).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn batch_get_keys_returns_multiple_keys_postgres() {
+    batch_get_keys_returns_multiple_keys_test(KeyValueBackend::Postgres).await
+}
+
+async fn batch_get_keys_returns_multiple_keys_test(backend: KeyValueBackend) {
     let context = TestContext::new();
-    let executor = start(&context).await.unwrap();
+    let executor = start_with_backend(&context, backend).await;
 
     let component_id = executor.store_component("key-value-service").await;
     let worker_name = "key-value-service-13";
@@ -719,3 +1165,289 @@ async fn batch_get_keys_returns_multiple_keys() {
             ])]
     );
 }
+
+#[tokio::test]
+#[tracing::instrument]
+async fn batch_get_keys_returns_the_keys_of_all_pages() {
+    batch_get_keys_returns_the_keys_of_all_pages_test(KeyValueBackend::Redis).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn batch_get_keys_returns_the_keys_of_all_pages_sqlite() {
+    batch_get_keys_returns_the_keys_of_all_pages_test(KeyValueBackend::Sqlite).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn batch_get_keys_returns_the_keys_of_all_pages_postgres() {
+    batch_get_keys_returns_the_keys_of_all_pages_test(KeyValueBackend::Postgres).await
+}
+
+async fn batch_get_keys_returns_the_keys_of_all_pages_test(backend: KeyValueBackend) {
+    let context = TestContext::new();
+    let executor = start_with_backend_customized(&context, backend, None, |config| {
+        config.key_value.keys_page_size = 1;
+    })
+    .await;
+
+    let component_id = executor.store_component("key-value-service").await;
+    let worker_name = "key-value-service-14";
+    let worker_id = executor.start_worker(&component_id, worker_name).await;
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api/set",
+            vec![
+                Value::String(format!("{component_id}-{worker_name}-bucket")),
+                Value::String("key1".to_string()),
+                Value::List(vec![Value::U8(1), Value::U8(2), Value::U8(3)]),
+            ],
+        )
+        .await
+        .unwrap();
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api/set",
+            vec![
+                Value::String(format!("{component_id}-{worker_name}-bucket")),
+                Value::String("key2".to_string()),
+                Value::List(vec![Value::U8(4), Value::U8(5), Value::U8(6)]),
+            ],
+        )
+        .await
+        .unwrap();
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api/set",
+            vec![
+                Value::String(format!("{component_id}-{worker_name}-bucket")),
+                Value::String("key3".to_string()),
+                Value::List(vec![Value::U8(7), Value::U8(8), Value::U8(9)]),
+            ],
+        )
+        .await
+        .unwrap();
+
+    let result = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api/get-keys",
+            vec![Value::String(format!(
+                "{component_id}-{worker_name}-bucket"
+            ))],
+        )
+        .await
+        .unwrap();
+
+    drop(executor);
+
+    check!(
+        result
+            == vec![Value::List(vec![
+                Value::String("key1".to_string()),
+                Value::String("key2".to_string()),
+                Value::String("key3".to_string()),
+            ])]
+    );
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn operator_api_reads_and_writes_worker_buckets() {
+    operator_api_reads_and_writes_worker_buckets_test(KeyValueBackend::Redis).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn operator_api_reads_and_writes_worker_buckets_sqlite() {
+    operator_api_reads_and_writes_worker_buckets_test(KeyValueBackend::Sqlite).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn operator_api_reads_and_writes_worker_buckets_postgres() {
+    operator_api_reads_and_writes_worker_buckets_test(KeyValueBackend::Postgres).await
+}
+
+async fn operator_api_reads_and_writes_worker_buckets_test(backend: KeyValueBackend) {
+    let context = TestContext::new();
+    let executor = start_with_backend(&context, backend).await;
+
+    let component_id = executor.store_component("key-value-service").await;
+    let worker_name = "key-value-service-15";
+    let worker_id = executor.start_worker(&component_id, worker_name).await;
+    let bucket = format!("{component_id}-{worker_name}-bucket");
+    let account_id = Some(golem_api_grpc::proto::golem::common::AccountId {
+        name: "test-account".to_string(),
+    });
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api/set",
+            vec![
+                Value::String(bucket.clone()),
+                Value::String("key1".to_string()),
+                Value::List(vec![Value::U8(1), Value::U8(2), Value::U8(3)]),
+            ],
+        )
+        .await
+        .unwrap();
+
+    let mut client = executor.client().await;
+
+    let buckets = client
+        .get_key_value_buckets(GetKeyValueBucketsRequest {
+            account_id: account_id.clone(),
+        })
+        .await
+        .unwrap()
+        .into_inner();
+
+    let value = client
+        .get_key_value(GetKeyValueRequest {
+            account_id: account_id.clone(),
+            bucket: bucket.clone(),
+            key: "key1".to_string(),
+        })
+        .await
+        .unwrap()
+        .into_inner();
+
+    let _ = client
+        .set_key_value(SetKeyValueRequest {
+            account_id: account_id.clone(),
+            bucket: bucket.clone(),
+            key: "key2".to_string(),
+            value: vec![4, 5, 6],
+        })
+        .await
+        .unwrap();
+
+    let _ = client
+        .delete_key_value(DeleteKeyValueRequest {
+            account_id: account_id.clone(),
+            bucket: bucket.clone(),
+            key: "key1".to_string(),
+        })
+        .await
+        .unwrap();
+
+    let result = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api/get-keys",
+            vec![Value::String(bucket.clone())],
+        )
+        .await
+        .unwrap();
+
+    drop(executor);
+
+    let buckets = match buckets.result {
+        Some(get_key_value_buckets_response::Result::Success(success)) => success.buckets,
+        _ => panic!("Failed to list the key-value buckets"),
+    };
+
+    check!(buckets.contains(&bucket));
+    check!(
+        value.result
+            == Some(get_key_value_response::Result::Success(
+                GetKeyValueSuccessResponse {
+                    value: Some(vec![1, 2, 3])
+                }
+            ))
+    );
+    check!(result == vec![Value::List(vec![Value::String("key2".to_string())])]);
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn list_keys_returns_the_keys_with_the_prefix_page_by_page() {
+    list_keys_returns_the_keys_with_the_prefix_page_by_page_test(KeyValueBackend::Redis).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn list_keys_returns_the_keys_with_the_prefix_page_by_page_sqlite() {
+    list_keys_returns_the_keys_with_the_prefix_page_by_page_test(KeyValueBackend::Sqlite).await
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn list_keys_returns_the_keys_with_the_prefix_page_by_page_postgres() {
+    list_keys_returns_the_keys_with_the_prefix_page_by_page_test(KeyValueBackend::Postgres).await
+}
+
+async fn list_keys_returns_the_keys_with_the_prefix_page_by_page_test(backend: KeyValueBackend) {
+    let context = TestContext::new();
+    let executor = start_with_backend(&context, backend).await;
+
+    let component_id = executor.store_component("key-value-service").await;
+    let worker_name = "key-value-service-list-keys";
+    let worker_id = executor.start_worker(&component_id, worker_name).await;
+    let bucket = format!("{component_id}-{worker_name}-bucket");
+
+    for key in ["key1", "key2", "key3", "other"] {
+        let _ = executor
+            .invoke_and_await(
+                &worker_id,
+                "golem:it/api/set",
+                vec![
+                    Value::String(bucket.clone()),
+                    Value::String(key.to_string()),
+                    Value::List(vec![Value::U8(1)]),
+                ],
+            )
+            .await
+            .unwrap();
+    }
+
+    let mut keys = Vec::new();
+    let mut cursor = None;
+    loop {
+        let result = executor
+            .invoke_and_await(
+                &worker_id,
+                "golem:it/api/list-keys",
+                vec![
+                    Value::String(bucket.clone()),
+                    Value::Option(Some(Box::new(Value::String("key".to_string())))),
+                    Value::Option(cursor.map(Box::new)),
+                    Value::U64(1),
+                ],
+            )
+            .await
+            .unwrap();
+
+        let [Value::Tuple(page)] = result.as_slice() else {
+            panic!("Unexpected result: {result:?}");
+        };
+        let [Value::List(page_keys), Value::Option(next_cursor)] = page.as_slice() else {
+            panic!("Unexpected page: {page:?}");
+        };
+        for key in page_keys {
+            let Value::String(key) = key else {
+                panic!("Unexpected key: {key:?}");
+            };
+            if !keys.contains(key) {
+                keys.push(key.clone());
+            }
+        }
+        match next_cursor {
+            Some(next_cursor) => cursor = Some(next_cursor.as_ref().clone()),
+            None => break,
+        }
+    }
+
+    drop(executor);
+
+    keys.sort();
+    check!(keys == vec!["key1".to_string(), "key2".to_string(), "key3".to_string()]);
+}
@@ -35,6 +35,7 @@ pub mod hot_update;
 pub mod keyvalue;
 pub mod rpc;
 pub mod scalability;
+pub mod topics;
 pub mod transactions;
 pub mod wasi;
 
@@ -1,9 +1,10 @@
 use crate::common;
 use assert2::check;
+use golem_common::model::WorkerStatus;
 use golem_test_framework::dsl::TestDsl;
 use golem_wasm_rpc::Value;
 use std::collections::HashMap;
-use std::time::SystemTime;
+use std::time::{Duration, Instant, SystemTime};
 
 #[tokio::test]
 #[tracing::instrument]
@@ -300,3 +301,259 @@ async fn counter_resource_test_3_with_restart() {
     check!(result1 == Ok(vec![Value::U64(1)]));
     check!(result2 == Ok(vec![Value::U64(2)]));
 }
+
+#[tokio::test]
+#[tracing::instrument]
+async fn counter_async_rpc_test() {
+    let context = common::TestContext::new();
+    let executor = common::start(&context).await.unwrap();
+
+    let counters_component_id = executor.store_component("counters").await;
+    let caller_component_id = executor.store_component("caller_composed").await;
+
+    let mut env = HashMap::new();
+    env.insert(
+        "COUNTERS_COMPONENT_ID".to_string(),
+        counters_component_id.to_string(),
+    );
+    let caller_worker_id = executor
+        .start_worker_with(&caller_component_id, "rpc-counters-4", vec![], env)
+        .await;
+
+    let result1 = executor
+        .invoke_and_await(&caller_worker_id, "test4", vec![])
+        .await;
+    let result2 = executor
+        .invoke_and_await(&caller_worker_id, "test4", vec![])
+        .await;
+
+    drop(executor);
+
+    check!(result1 == Ok(vec![Value::U64(3)]));
+    check!(result2 == Ok(vec![Value::U64(6)]));
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn counter_async_rpc_test_with_restart() {
+    let context = common::TestContext::new();
+    let executor = common::start(&context).await.unwrap();
+
+    let counters_component_id = executor.store_component("counters").await;
+    let caller_component_id = executor.store_component("caller_composed").await;
+
+    let mut env = HashMap::new();
+    env.insert(
+        "COUNTERS_COMPONENT_ID".to_string(),
+        counters_component_id.to_string(),
+    );
+    let caller_worker_id = executor
+        .start_worker_with(&caller_component_id, "rpc-counters-4r", vec![], env)
+        .await;
+
+    let result1 = executor
+        .invoke_and_await(&caller_worker_id, "test4", vec![])
+        .await;
+
+    drop(executor);
+    let executor = common::start(&context).await.unwrap();
+
+    // Replaying the first invocation must not start the remote calls again, even though the
+    // results are available earlier than when they were first polled
+    let result2 = executor
+        .invoke_and_await(&caller_worker_id, "test4", vec![])
+        .await;
+
+    drop(executor);
+
+    check!(result1 == Ok(vec![Value::U64(3)]));
+    check!(result2 == Ok(vec![Value::U64(6)]));
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn async_rpc_suspends_the_caller_until_the_result_arrives() {
+    let context = common::TestContext::new();
+    let executor = common::start_customized(&context, None, |config| {
+        config.suspend.suspend_after = Duration::from_secs(2);
+    })
+    .await
+    .unwrap();
+
+    let clock_component_id = executor.store_component("clock-service").await;
+    let caller_component_id = executor.store_component("caller_composed").await;
+
+    let mut env = HashMap::new();
+    env.insert(
+        "CLOCK_SERVICE_COMPONENT_ID".to_string(),
+        clock_component_id.to_string(),
+    );
+    let caller_worker_id = executor
+        .start_worker_with(&caller_component_id, "rpc-async-suspend", vec![], env)
+        .await;
+
+    let executor_clone = executor.clone();
+    let caller_worker_id_clone = caller_worker_id.clone();
+    let fiber = tokio::spawn(async move {
+        executor_clone
+            .invoke_and_await(&caller_worker_id_clone, "test7", vec![Value::U64(6)])
+            .await
+    });
+
+    // The remote call takes longer than the suspension threshold, so the caller gets suspended
+    let start = Instant::now();
+    let mut suspended = false;
+    while !suspended && start.elapsed() < Duration::from_secs(6) {
+        let metadata = executor
+            .get_worker_metadata(&caller_worker_id)
+            .await
+            .unwrap();
+        suspended = metadata.last_known_status.status == WorkerStatus::Suspended;
+        tokio::time::sleep(Duration::from_millis(200)).await;
+    }
+
+    let result = tokio::time::timeout(Duration::from_secs(60), fiber).await;
+
+    drop(executor);
+
+    check!(suspended);
+    check!(result.is_ok());
+    check!(result.unwrap().unwrap() == Ok(vec![Value::U64(6)]));
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn broadcast_invokes_every_other_worker_of_the_component_once() {
+    let context = common::TestContext::new();
+    let executor = common::start_customized(&context, None, |config| {
+        config.limits.max_broadcast_concurrency = 2;
+    })
+    .await
+    .unwrap();
+
+    let counters_component_id = executor.store_component("counters").await;
+    let caller_component_id = executor.store_component("caller_composed").await;
+
+    let mut counter_worker_ids = Vec::new();
+    for idx in 1..=5 {
+        counter_worker_ids.push(
+            executor
+                .start_worker(&counters_component_id, &format!("counters-broadcast-{idx}"))
+                .await,
+        );
+    }
+
+    let mut env = HashMap::new();
+    env.insert(
+        "COUNTERS_COMPONENT_ID".to_string(),
+        counters_component_id.to_string(),
+    );
+    let caller_worker_id = executor
+        .start_worker_with(&caller_component_id, "rpc-broadcast-1", vec![], env)
+        .await;
+
+    // The requested concurrency is above the configured limit, which caps it
+    let result = executor
+        .invoke_and_await(&caller_worker_id, "test5", vec![Value::U32(u32::MAX)])
+        .await;
+
+    let mut values = Vec::new();
+    for worker_id in &counter_worker_ids {
+        values.push(
+            executor
+                .invoke_and_await(worker_id, "rpc:counters/api/get-global-value", vec![])
+                .await,
+        );
+    }
+
+    drop(executor);
+
+    check!(result == Ok(vec![Value::U32(5)]));
+    for value in values {
+        check!(value == Ok(vec![Value::U64(1)]));
+    }
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn calling_back_a_waiting_worker_is_denied() {
+    let context = common::TestContext::new();
+    let executor = common::start(&context).await.unwrap();
+
+    let caller_component_id = executor.store_component("caller_composed").await;
+
+    let mut env = HashMap::new();
+    env.insert(
+        "CALLER_COMPONENT_ID".to_string(),
+        caller_component_id.to_string(),
+    );
+    let worker_a = executor
+        .start_worker_with(&caller_component_id, "rpc-bounce-a", vec![], env.clone())
+        .await;
+    let worker_b = executor
+        .start_worker_with(&caller_component_id, "rpc-bounce-b", vec![], env)
+        .await;
+
+    // A calls B which calls back A, which is still waiting for B
+    let result = executor
+        .invoke_and_await(
+            &worker_a,
+            "bounce",
+            vec![Value::List(vec![
+                Value::String("rpc-bounce-b".to_string()),
+                Value::String("rpc-bounce-a".to_string()),
+            ])],
+        )
+        .await;
+
+    // Without a cycle the same call succeeds
+    let result2 = executor
+        .invoke_and_await(
+            &worker_a,
+            "bounce",
+            vec![Value::List(vec![Value::String("rpc-bounce-b".to_string())])],
+        )
+        .await;
+
+    drop(executor);
+
+    check!(
+        result
+            == Ok(vec![Value::String(format!(
+                "denied: Deadlock: {worker_a} -> {worker_b} -> {worker_a}"
+            ))])
+    );
+    check!(result2 == Ok(vec![Value::String("done".to_string())]));
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn rpc_to_a_component_uses_a_fresh_ephemeral_worker_for_every_call() {
+    let context = common::TestContext::new();
+    let executor = common::start(&context).await.unwrap();
+
+    let counters_component_id = executor.store_component("counters").await;
+    let caller_component_id = executor.store_component("caller_composed").await;
+
+    let mut env = HashMap::new();
+    env.insert(
+        "COUNTERS_COMPONENT_ID".to_string(),
+        counters_component_id.to_string(),
+    );
+    let caller_worker_id = executor
+        .start_worker_with(&caller_component_id, "rpc-ephemeral-1", vec![], env)
+        .await;
+
+    let result = executor
+        .invoke_and_await(&caller_worker_id, "test6", vec![])
+        .await;
+    let counters_workers = executor
+        .get_workers_metadata(&counters_component_id, None, 0, 10, true)
+        .await;
+
+    drop(executor);
+
+    // The increment happened on another worker than the read
+    check!(result == Ok(vec![Value::U64(0)]));
+    check!(counters_workers.1.is_empty());
+}
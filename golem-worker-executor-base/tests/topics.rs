@@ -0,0 +1,378 @@
+use crate::common::TestContext;
+use crate::BASE_DEPS;
+use assert2::check;
+use async_trait::async_trait;
+use golem_api_grpc::proto::golem::worker::UpdateMode;
+use golem_common::config::RedisConfig;
+use golem_common::model::{
+    AccountId, ComponentId, ComponentVersion, IdempotencyKey, ShardId, WorkerId,
+};
+use golem_common::redis::RedisPool;
+use golem_test_framework::config::TestDependencies;
+use golem_wasm_rpc::{Value, WitValue};
+use golem_worker_executor_base::error::GolemError;
+use golem_worker_executor_base::services::shard::{ShardService, ShardServiceDefault};
+use golem_worker_executor_base::services::topic::{
+    SubscriptionTarget, TopicMessage, TopicService, TopicServiceRedis, TopicSubscription,
+};
+use golem_worker_executor_base::services::worker_proxy::{WorkerProxy, WorkerProxyError};
+use redis::Commands;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// A delivered message, as seen by the worker proxy
+#[derive(Debug, Clone, PartialEq)]
+struct Delivery {
+    target: SubscriptionTarget,
+    idempotency_key: Option<IdempotencyKey>,
+    function_name: String,
+    params: Vec<Value>,
+}
+
+/// Records the deliveries, failing the first `failures` of them
+struct RecordingWorkerProxy {
+    failures: Mutex<usize>,
+    deliveries: Mutex<Vec<Delivery>>,
+}
+
+impl RecordingWorkerProxy {
+    fn new(failures: usize) -> Self {
+        Self {
+            failures: Mutex::new(failures),
+            deliveries: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn record(&self, delivery: Delivery) -> Result<(), WorkerProxyError> {
+        self.deliveries.lock().unwrap().push(delivery);
+        let mut failures = self.failures.lock().unwrap();
+        if *failures > 0 {
+            *failures -= 1;
+            Err(WorkerProxyError::InternalError(GolemError::unknown(
+                "delivery failed",
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn deliveries(&self) -> Vec<Delivery> {
+        self.deliveries.lock().unwrap().clone()
+    }
+
+    async fn wait_for_deliveries(&self, count: usize) -> Vec<Delivery> {
+        for _ in 0..100 {
+            let deliveries = self.deliveries();
+            if deliveries.len() >= count {
+                return deliveries;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        self.deliveries()
+    }
+}
+
+#[async_trait]
+impl WorkerProxy for RecordingWorkerProxy {
+    async fn invoke_and_await(
+        &self,
+        _worker_id: &WorkerId,
+        _idempotency_key: Option<IdempotencyKey>,
+        _function_name: String,
+        _function_params: Vec<WitValue>,
+        _account_id: &AccountId,
+        _call_chain: Vec<WorkerId>,
+    ) -> Result<WitValue, WorkerProxyError> {
+        unimplemented!()
+    }
+
+    async fn invoke_and_await_ephemeral(
+        &self,
+        component_id: &ComponentId,
+        function_name: String,
+        function_params: Vec<WitValue>,
+        _account_id: &AccountId,
+        _call_chain: Vec<WorkerId>,
+    ) -> Result<WitValue, WorkerProxyError> {
+        self.record(Delivery {
+            target: SubscriptionTarget::Component(component_id.clone()),
+            idempotency_key: None,
+            function_name,
+            params: function_params.into_iter().map(Value::from).collect(),
+        })?;
+        Ok(Value::Tuple(vec![]).into())
+    }
+
+    async fn invoke(
+        &self,
+        worker_id: &WorkerId,
+        idempotency_key: Option<IdempotencyKey>,
+        function_name: String,
+        function_params: Vec<WitValue>,
+        _account_id: &AccountId,
+    ) -> Result<(), WorkerProxyError> {
+        self.record(Delivery {
+            target: SubscriptionTarget::Worker(worker_id.clone()),
+            idempotency_key,
+            function_name,
+            params: function_params.into_iter().map(Value::from).collect(),
+        })
+    }
+
+    async fn update(
+        &self,
+        _worker_id: &WorkerId,
+        _target_version: ComponentVersion,
+        _mode: UpdateMode,
+        _account_id: &AccountId,
+    ) -> Result<(), WorkerProxyError> {
+        unimplemented!()
+    }
+
+    async fn send_mailbox_message(
+        &self,
+        _worker_id: &WorkerId,
+        _message_id: IdempotencyKey,
+        _payload: Vec<u8>,
+        _account_id: &AccountId,
+    ) -> Result<(), WorkerProxyError> {
+        unimplemented!()
+    }
+}
+
+async fn start_topic_service(
+    context: &TestContext,
+    worker_proxy: Arc<RecordingWorkerProxy>,
+) -> Arc<TopicServiceRedis> {
+    let redis = RedisPool::configured(&RedisConfig {
+        port: BASE_DEPS.redis().public_port(),
+        key_prefix: context.redis_prefix(),
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+
+    let shard_service = Arc::new(ShardServiceDefault::new());
+    shard_service.register(1, &HashSet::from([ShardId::new(0)]));
+
+    TopicServiceRedis::new(
+        redis,
+        shard_service,
+        worker_proxy,
+        Duration::from_millis(100),
+        10,
+    )
+}
+
+fn account(value: &str) -> AccountId {
+    AccountId {
+        value: value.to_string(),
+    }
+}
+
+fn subscription(subscription_id: &str, target: SubscriptionTarget) -> TopicSubscription {
+    TopicSubscription {
+        subscription_id: subscription_id.to_string(),
+        target,
+        account_id: account("test-account"),
+        function_name: "on-message".to_string(),
+    }
+}
+
+fn message(message_id: &str, value: u64) -> TopicMessage {
+    TopicMessage {
+        message_id: message_id.to_string(),
+        params: vec![Value::U64(value).into()],
+    }
+}
+
+fn key_exists(context: &TestContext, key: &str) -> bool {
+    let mut redis = BASE_DEPS.redis().get_connection(0);
+    let exists: bool = redis
+        .exists(format!("{}{key}", context.redis_prefix()))
+        .unwrap();
+    exists
+}
+
+fn stream_length(context: &TestContext, topic: &str) -> usize {
+    let mut redis = BASE_DEPS.redis().get_connection(0);
+    let length: usize = redis
+        .xlen(format!(
+            "{}topic:stream:test-account:{topic}",
+            context.redis_prefix()
+        ))
+        .unwrap();
+    length
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn failed_deliveries_are_retried_with_the_same_idempotency_key() {
+    let context = TestContext::new();
+    let worker_proxy = Arc::new(RecordingWorkerProxy::new(1));
+    let topics = start_topic_service(&context, worker_proxy.clone()).await;
+
+    let worker_id = WorkerId {
+        component_id: ComponentId::new_v4(),
+        worker_name: "subscriber".to_string(),
+    };
+    let subscription = subscription("sub1", SubscriptionTarget::Worker(worker_id.clone()));
+    topics
+        .subscribe("orders", subscription.clone())
+        .await
+        .unwrap();
+    topics
+        .publish(&account("test-account"), "orders", message("msg1", 1))
+        .await
+        .unwrap();
+    topics
+        .publish(&account("test-account"), "orders", message("msg2", 2))
+        .await
+        .unwrap();
+
+    let deliveries = worker_proxy.wait_for_deliveries(3).await;
+    let expected = |message_id: &str, value: u64| Delivery {
+        target: SubscriptionTarget::Worker(worker_id.clone()),
+        idempotency_key: Some(subscription.delivery_idempotency_key(message_id)),
+        function_name: "on-message".to_string(),
+        params: vec![Value::U64(value)],
+    };
+
+    // The first delivery fails, so the first message is delivered again before the second one
+    check!(
+        deliveries
+            == vec![
+                expected("msg1", 1),
+                expected("msg1", 1),
+                expected("msg2", 2)
+            ]
+    );
+
+    // Every message has been delivered to the only subscription
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    check!(stream_length(&context, "orders") == 0);
+    check!(worker_proxy.deliveries().len() == 3);
+
+    topics
+        .unsubscribe(&account("test-account"), "orders", "sub1")
+        .await
+        .unwrap();
+    check!(!key_exists(
+        &context,
+        "topic:subscriptions:test-account:orders"
+    ));
+    check!(!key_exists(&context, "topic:cursors:test-account:orders"));
+    check!(!key_exists(&context, "topic:shard:<0>"));
+
+    // Messages published without subscriptions are dropped
+    topics
+        .publish(&account("test-account"), "orders", message("msg3", 3))
+        .await
+        .unwrap();
+    check!(!key_exists(&context, "topic:stream:test-account:orders"));
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn component_subscriptions_are_delivered_to_ephemeral_workers() {
+    let context = TestContext::new();
+    let worker_proxy = Arc::new(RecordingWorkerProxy::new(0));
+    let topics = start_topic_service(&context, worker_proxy.clone()).await;
+
+    let component_id = ComponentId::new_v4();
+    let worker_id = WorkerId {
+        component_id: component_id.clone(),
+        worker_name: "subscriber".to_string(),
+    };
+    topics
+        .subscribe(
+            "events",
+            subscription("sub1", SubscriptionTarget::Component(component_id.clone())),
+        )
+        .await
+        .unwrap();
+    topics
+        .subscribe(
+            "events",
+            subscription("sub2", SubscriptionTarget::Worker(worker_id.clone())),
+        )
+        .await
+        .unwrap();
+    topics
+        .publish(&account("test-account"), "events", message("msg1", 1))
+        .await
+        .unwrap();
+
+    let deliveries = worker_proxy.wait_for_deliveries(2).await;
+    let targets: HashSet<String> = deliveries
+        .iter()
+        .map(|delivery| delivery.target.to_string())
+        .collect();
+    check!(deliveries.len() == 2);
+    check!(
+        targets
+            == HashSet::from([
+                SubscriptionTarget::Component(component_id).to_string(),
+                SubscriptionTarget::Worker(worker_id).to_string()
+            ])
+    );
+
+    // The stream is kept while one of the subscriptions is still subscribed
+    topics
+        .unsubscribe(&account("test-account"), "events", "sub1")
+        .await
+        .unwrap();
+    check!(key_exists(&context, "topic:cursors:test-account:events"));
+    topics
+        .unsubscribe(&account("test-account"), "events", "sub2")
+        .await
+        .unwrap();
+    check!(!key_exists(&context, "topic:cursors:test-account:events"));
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn topics_are_scoped_by_account() {
+    let context = TestContext::new();
+    let worker_proxy = Arc::new(RecordingWorkerProxy::new(0));
+    let topics = start_topic_service(&context, worker_proxy.clone()).await;
+
+    let worker_id = WorkerId {
+        component_id: ComponentId::new_v4(),
+        worker_name: "subscriber".to_string(),
+    };
+    topics
+        .subscribe(
+            "orders",
+            subscription("sub1", SubscriptionTarget::Worker(worker_id)),
+        )
+        .await
+        .unwrap();
+
+    // Messages published from another account go to that account's topic
+    topics
+        .publish(&account("other-account"), "orders", message("msg1", 1))
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    check!(worker_proxy.deliveries().is_empty());
+
+    // Another account cannot remove the subscription
+    let result = topics
+        .unsubscribe(&account("other-account"), "orders", "sub1")
+        .await;
+    check!(result.is_ok());
+    check!(key_exists(
+        &context,
+        "topic:subscriptions:test-account:orders"
+    ));
+
+    topics
+        .publish(&account("test-account"), "orders", message("msg2", 2))
+        .await
+        .unwrap();
+    let deliveries = worker_proxy.wait_for_deliveries(1).await;
+    check!(deliveries.len() == 1);
+    check!(deliveries[0].params == vec![Value::U64(2)]);
+}
@@ -260,6 +260,66 @@ async fn atomic_region() {
     check!(events == vec!["1", "2", "1", "2", "1", "2", "3", "4", "5", "5", "5", "6"]);
 }
 
+#[tokio::test]
+#[tracing::instrument]
+async fn failed_atomic_region_runs_compensations_in_reverse_order() {
+    let context = TestContext::new();
+    let executor = start(&context).await.unwrap();
+
+    let host_http_port = context.host_http_port();
+
+    let http_server = TestHttpServer::start(host_http_port, 0);
+    let component_id = executor.store_component("runtime-service").await;
+
+    let mut env = HashMap::new();
+    env.insert("PORT".to_string(), context.host_http_port().to_string());
+
+    let worker_id = executor
+        .start_worker_with(
+            &component_id,
+            "failed-compensated-region",
+            vec![],
+            env.clone(),
+        )
+        .await;
+    let compensating_worker_id = executor
+        .start_worker_with(&component_id, "compensating-worker", vec![], env)
+        .await;
+
+    let component_id_val = {
+        let (high, low) = component_id.0.as_u64_pair();
+        Value::Record(vec![Value::Record(vec![Value::U64(high), Value::U64(low)])])
+    };
+    let result = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api/failed-compensated-region",
+            vec![
+                component_id_val,
+                Value::String(compensating_worker_id.worker_name.clone()),
+            ],
+        )
+        .await;
+
+    // The failure is reported before the compensations run
+    let mut events = http_server.get_events();
+    for _ in 0..50 {
+        if events.len() >= 4 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        events = http_server.get_events();
+    }
+
+    drop(executor);
+    http_server.abort();
+
+    println!("events:\n - {}", events.join("\n - "));
+
+    check!(result.is_err());
+    check!(events == vec!["1", "2", "undo 2", "undo 1"]);
+}
+
 #[tokio::test]
 #[tracing::instrument]
 async fn idempotence_on() {
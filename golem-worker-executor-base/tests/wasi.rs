@@ -4,7 +4,7 @@ use std::sync::atomic::AtomicU8;
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, SystemTime};
 
-use crate::common::{start, TestContext};
+use crate::common::{start, start_with_virtual_clock, TestContext};
 use assert2::{assert, check};
 use golem_common::model::WorkerStatus;
 use golem_test_framework::dsl::{stderr_event, stdout_event, worker_error_message, TestDsl};
@@ -558,6 +558,129 @@ async fn http_client_response_persisted_between_invocations() {
     );
 }
 
+/// A multi-megabyte response body, large enough to be stored in the blob store instead of the oplog
+fn large_response_body() -> String {
+    "0123456789abcdef".repeat(3 * 1024 * 1024 / 16)
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn http_client_large_response() {
+    let context = TestContext::new();
+    let executor = start(&context).await.unwrap();
+
+    let host_http_port = context.host_http_port();
+    let http_server = tokio::spawn(async move {
+        let route = warp::path::end().and(warp::post()).map(|| {
+            Response::builder()
+                .status(StatusCode::OK)
+                .body(Body::from(large_response_body()))
+                .unwrap()
+        });
+
+        warp::serve(route)
+            .run(
+                format!("0.0.0.0:{}", host_http_port)
+                    .parse::<SocketAddr>()
+                    .unwrap(),
+            )
+            .await;
+    });
+
+    let component_id = executor.store_component("http-client").await;
+    let mut env = HashMap::new();
+    env.insert("PORT".to_string(), host_http_port.to_string());
+
+    let worker_id = executor
+        .start_worker_with(&component_id, "http-client-large-1", vec![], env)
+        .await;
+    let rx = executor.capture_output(&worker_id).await;
+
+    let result = executor
+        .invoke_and_await(&worker_id, "golem:it/api/run", vec![])
+        .await;
+
+    drop(executor);
+    drop(rx);
+    http_server.abort();
+
+    check!(
+        result
+            == Ok(vec![Value::String(format!(
+                "200 {}",
+                large_response_body()
+            ))])
+    );
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn http_client_large_response_persisted_between_invocations() {
+    let context = TestContext::new();
+    let executor = start(&context).await.unwrap();
+    let host_http_port = context.host_http_port();
+
+    let http_server = tokio::spawn(async move {
+        let call_count = Arc::new(AtomicU8::new(0));
+        let route = warp::path::end().and(warp::post()).map(move || {
+            let old_count = call_count.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            match old_count {
+                0 => Response::builder()
+                    .status(StatusCode::OK)
+                    .body(Body::from(large_response_body()))
+                    .unwrap(),
+                _ => Response::builder()
+                    .status(StatusCode::NOT_FOUND)
+                    .body(Body::empty())
+                    .unwrap(),
+            }
+        });
+
+        warp::serve(route)
+            .run(
+                format!("0.0.0.0:{}", host_http_port)
+                    .parse::<SocketAddr>()
+                    .unwrap(),
+            )
+            .await;
+    });
+
+    let component_id = executor.store_component("http-client").await;
+    let mut env = HashMap::new();
+    env.insert("PORT".to_string(), host_http_port.to_string());
+
+    let worker_id = executor
+        .start_worker_with(&component_id, "http-client-large-2", vec![], env)
+        .await;
+    let rx = executor.capture_output(&worker_id).await;
+
+    let _ = executor
+        .invoke_and_await(&worker_id, "golem:it/api/send-request", vec![])
+        .await
+        .expect("first send-request failed");
+
+    drop(executor);
+    drop(rx);
+
+    // The response body is replayed from the oplog, the server would respond with 404 now
+    let executor = start(&context).await.unwrap();
+    let _rx = executor.capture_output(&worker_id).await;
+
+    let result = executor
+        .invoke_and_await(&worker_id, "golem:it/api/process-response", vec![])
+        .await;
+
+    http_server.abort();
+
+    check!(
+        result
+            == Ok(vec![Value::String(format!(
+                "200 {}",
+                large_response_body()
+            ))])
+    );
+}
+
 #[tokio::test]
 #[tracing::instrument]
 async fn sleep() {
@@ -629,6 +752,50 @@ async fn resuming_sleep() {
     check!(duration.as_secs() >= 10);
 }
 
+#[tokio::test]
+#[tracing::instrument]
+async fn sleep_woken_up_by_virtual_clock() {
+    let context = TestContext::new();
+    let executor = start_with_virtual_clock(&context).await.unwrap();
+
+    let component_id = executor.store_component("clock-service").await;
+    let worker_id = executor
+        .start_worker(&component_id, "clock-service-virtual-1")
+        .await;
+
+    let executor_clone = executor.clone();
+    let worker_id_clone = worker_id.clone();
+    let fiber = spawn(async move {
+        executor_clone
+            .invoke_and_await(
+                &worker_id_clone,
+                "golem:it/api/sleep",
+                vec![Value::U64(3600)],
+            )
+            .await
+    });
+
+    // The hour long sleep suspends the worker, and it stays suspended until the clock moves
+    let start = Instant::now();
+    loop {
+        let metadata = executor.get_worker_metadata(&worker_id).await.unwrap();
+        if metadata.last_known_status.status == WorkerStatus::Suspended {
+            break;
+        }
+        assert!(start.elapsed() < Duration::from_secs(30));
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+
+    executor.advance_time(Duration::from_secs(3600)).await;
+
+    let result = tokio::time::timeout(Duration::from_secs(30), fiber).await;
+
+    drop(executor);
+
+    check!(result.is_ok());
+    check!(result.unwrap().unwrap().is_ok());
+}
+
 #[tokio::test]
 #[tracing::instrument]
 async fn failing_worker() {
@@ -1364,3 +1531,71 @@ async fn ip_address_resolve() {
     check!(result1.len() > 0);
     check!(result2.len() > 0);
 }
+
+#[tokio::test]
+#[tracing::instrument]
+async fn tcp_write_read_is_replayed() {
+    let context = TestContext::new();
+    let executor = start(&context).await.unwrap();
+
+    // An echo server counting the bytes it receives, to see if replay sends anything again
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let received = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let received_clone = received.clone();
+    let echo_server = spawn(async move {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let received = received_clone.clone();
+            spawn(async move {
+                let mut buf = [0u8; 1024];
+                loop {
+                    match socket.read(&mut buf).await {
+                        Ok(0) | Err(_) => break,
+                        Ok(n) => {
+                            received.fetch_add(n, std::sync::atomic::Ordering::SeqCst);
+                            if socket.write_all(&buf[..n]).await.is_err() {
+                                break;
+                            }
+                        }
+                    }
+                }
+            });
+        }
+    });
+
+    let component_id = executor.store_component("networking").await;
+    let worker_id = executor
+        .start_worker(&component_id, "tcp-write-read-1")
+        .await;
+
+    let result1 = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api/tcp-echo",
+            vec![Value::U16(port), Value::String("hello".to_string())],
+        )
+        .await
+        .unwrap();
+
+    drop(executor);
+    let executor = start(&context).await.unwrap();
+
+    // Recovering the worker replays the first connection from the oplog without sending anything
+    let result2 = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api/tcp-echo",
+            vec![Value::U16(port), Value::String("world!".to_string())],
+        )
+        .await
+        .unwrap();
+
+    drop(executor);
+    echo_server.abort();
+
+    check!(result1 == vec![Value::String("hello".to_string())]);
+    check!(result2 == vec![Value::String("world!".to_string())]);
+    check!(received.load(std::sync::atomic::Ordering::SeqCst) == "hello".len() + "world!".len());
+}
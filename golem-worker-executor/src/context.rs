@@ -18,6 +18,7 @@ use std::sync::{Arc, RwLock};
 use crate::services::AdditionalDeps;
 use anyhow::Error;
 use async_trait::async_trait;
+use golem_common::clock::Clock;
 use golem_common::model::{
     AccountId, CallingConvention, ComponentVersion, IdempotencyKey, WorkerId, WorkerMetadata,
     WorkerStatus, WorkerStatusRecord,
@@ -38,11 +39,13 @@ use golem_worker_executor_base::services::events::Events;
 use golem_worker_executor_base::services::golem_config::GolemConfig;
 use golem_worker_executor_base::services::invocation_queue::InvocationQueue;
 use golem_worker_executor_base::services::key_value::KeyValueService;
+use golem_worker_executor_base::services::mailbox::MailboxService;
 use golem_worker_executor_base::services::oplog::{Oplog, OplogService};
 use golem_worker_executor_base::services::promise::PromiseService;
 use golem_worker_executor_base::services::recovery::RecoveryManagement;
 use golem_worker_executor_base::services::rpc::Rpc;
 use golem_worker_executor_base::services::scheduler::SchedulerService;
+use golem_worker_executor_base::services::topic::TopicService;
 use golem_worker_executor_base::services::worker::WorkerService;
 use golem_worker_executor_base::services::worker_event::WorkerEventService;
 use golem_worker_executor_base::services::worker_proxy::WorkerProxy;
@@ -311,9 +314,12 @@ impl WorkerCtx for Context {
         oplog: Arc<dyn Oplog + Send + Sync>,
         invocation_queue: Arc<InvocationQueue<Context>>,
         scheduler_service: Arc<dyn SchedulerService + Send + Sync>,
+        topic_service: Arc<dyn TopicService + Send + Sync>,
+        mailbox_service: Arc<dyn MailboxService + Send + Sync>,
         recovery_management: Arc<dyn RecoveryManagement + Send + Sync>,
         rpc: Arc<dyn Rpc + Send + Sync>,
         worker_proxy: Arc<dyn WorkerProxy + Send + Sync>,
+        clock: Arc<dyn Clock + Send + Sync>,
         _extra_deps: Self::ExtraDeps,
         config: Arc<GolemConfig>,
         worker_config: WorkerConfig,
@@ -334,9 +340,12 @@ impl WorkerCtx for Context {
             oplog,
             invocation_queue,
             scheduler_service,
+            topic_service,
+            mailbox_service,
             recovery_management,
             rpc,
             worker_proxy,
+            clock,
             config,
             worker_config,
             execution_status,
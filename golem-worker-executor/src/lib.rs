@@ -18,6 +18,7 @@ pub mod services;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use golem_common::clock::Clock;
 use golem_worker_executor_base::durable_host::DurableWorkerCtx;
 use golem_worker_executor_base::preview2::golem;
 use golem_worker_executor_base::services::active_workers::ActiveWorkers;
@@ -26,6 +27,7 @@ use golem_worker_executor_base::services::component::ComponentService;
 use golem_worker_executor_base::services::events::Events;
 use golem_worker_executor_base::services::golem_config::GolemConfig;
 use golem_worker_executor_base::services::key_value::KeyValueService;
+use golem_worker_executor_base::services::mailbox::MailboxService;
 use golem_worker_executor_base::services::oplog::OplogService;
 use golem_worker_executor_base::services::promise::PromiseService;
 use golem_worker_executor_base::services::recovery::RecoveryManagementDefault;
@@ -33,11 +35,13 @@ use golem_worker_executor_base::services::rpc::{DirectWorkerInvocationRpc, Remot
 use golem_worker_executor_base::services::scheduler::SchedulerService;
 use golem_worker_executor_base::services::shard::ShardService;
 use golem_worker_executor_base::services::shard_manager::ShardManagerService;
+use golem_worker_executor_base::services::topic::TopicService;
 use golem_worker_executor_base::services::worker::WorkerService;
 use golem_worker_executor_base::services::worker_activator::WorkerActivator;
 use golem_worker_executor_base::services::worker_enumeration::{
     RunningWorkerEnumerationService, WorkerEnumerationService,
 };
+use golem_worker_executor_base::services::worker_event_log::WorkerEventLogService;
 use golem_worker_executor_base::services::worker_proxy::WorkerProxy;
 use golem_worker_executor_base::services::All;
 use golem_worker_executor_base::wasi_host::create_linker;
@@ -78,8 +82,12 @@ impl Bootstrap<Context> for ServerBootstrap {
         worker_activator: Arc<dyn WorkerActivator + Send + Sync>,
         oplog_service: Arc<dyn OplogService + Send + Sync>,
         scheduler_service: Arc<dyn SchedulerService + Send + Sync>,
+        topic_service: Arc<dyn TopicService + Send + Sync>,
+        mailbox_service: Arc<dyn MailboxService + Send + Sync>,
         worker_proxy: Arc<dyn WorkerProxy + Send + Sync>,
         events: Arc<Events>,
+        worker_event_log_service: Arc<dyn WorkerEventLogService + Send + Sync>,
+        clock: Arc<dyn Clock + Send + Sync>,
     ) -> anyhow::Result<All<Context>> {
         let additional_deps = AdditionalDeps {};
 
@@ -101,8 +109,12 @@ impl Bootstrap<Context> for ServerBootstrap {
             blob_store_service.clone(),
             oplog_service.clone(),
             scheduler_service.clone(),
+            topic_service.clone(),
+            mailbox_service.clone(),
             worker_activator.clone(),
             events.clone(),
+            worker_event_log_service.clone(),
+            clock.clone(),
             additional_deps.clone(),
         ));
         let recovery_management = Arc::new(RecoveryManagementDefault::new(
@@ -117,12 +129,16 @@ impl Bootstrap<Context> for ServerBootstrap {
             oplog_service.clone(),
             promise_service.clone(),
             scheduler_service.clone(),
+            topic_service.clone(),
+            mailbox_service.clone(),
             key_value_service.clone(),
             blob_store_service.clone(),
             rpc.clone(),
             worker_activator.clone(),
             worker_proxy.clone(),
             events.clone(),
+            worker_event_log_service.clone(),
+            clock.clone(),
             golem_config.clone(),
             additional_deps.clone(),
         ));
@@ -147,9 +163,13 @@ impl Bootstrap<Context> for ServerBootstrap {
             recovery_management,
             rpc,
             scheduler_service,
+            topic_service,
+            mailbox_service,
             worker_activator.clone(),
             worker_proxy.clone(),
             events.clone(),
+            worker_event_log_service,
+            clock,
             additional_deps,
         ))
     }
@@ -164,6 +184,37 @@ impl Bootstrap<Context> for ServerBootstrap {
             &mut linker,
             |x| &mut x.durable_ctx,
         )?;
+        golem::ext::async_rpc::add_to_linker::<Context, DurableWorkerCtx<Context>>(
+            &mut linker,
+            |x| &mut x.durable_ctx,
+        )?;
+        golem::ext::broadcast::add_to_linker::<Context, DurableWorkerCtx<Context>>(
+            &mut linker,
+            |x| &mut x.durable_ctx,
+        )?;
+        golem::ext::topics::add_to_linker::<Context, DurableWorkerCtx<Context>>(
+            &mut linker,
+            |x| &mut x.durable_ctx,
+        )?;
+        golem::ext::mailbox::add_to_linker::<Context, DurableWorkerCtx<Context>>(
+            &mut linker,
+            |x| &mut x.durable_ctx,
+        )?;
+        golem::ext::saga::add_to_linker::<Context, DurableWorkerCtx<Context>>(&mut linker, |x| {
+            &mut x.durable_ctx
+        })?;
+        golem::ext::keyvalue_keys::add_to_linker::<Context, DurableWorkerCtx<Context>>(
+            &mut linker,
+            |x| &mut x.durable_ctx,
+        )?;
+        golem::ext::blobstore_attributes::add_to_linker::<Context, DurableWorkerCtx<Context>>(
+            &mut linker,
+            |x| &mut x.durable_ctx,
+        )?;
+        golem::ext::promises::add_to_linker::<Context, DurableWorkerCtx<Context>>(
+            &mut linker,
+            |x| &mut x.durable_ctx,
+        )?;
         Ok(linker)
     }
 }
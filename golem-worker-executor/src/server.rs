@@ -14,15 +14,27 @@
 
 use std::sync::Arc;
 
+use golem_common::telemetry::otlp_layer;
 use golem_worker_executor::run;
 use golem_worker_executor_base::metrics;
 use golem_worker_executor_base::services::golem_config::GolemConfig;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
 use tracing_subscriber::EnvFilter;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let prometheus = metrics::register_all();
     let config = GolemConfig::new();
 
+    let runtime = Arc::new(
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap(),
+    );
+    // The OTLP exporter has to be created in the context of the runtime
+    let _runtime_guard = runtime.enter();
+
     if config.enable_tracing_console {
         // NOTE: also requires RUSTFLAGS="--cfg tokio_unstable" cargo build
         console_subscriber::init();
@@ -32,19 +44,17 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             .flatten_event(true)
             // .with_span_events(FmtSpan::FULL) // NOTE: enable to see span events
             .with_env_filter(EnvFilter::from_default_env())
+            .finish()
+            .with(otlp_layer(&config.otlp, "golem-worker-executor")?)
             .init();
     } else {
         tracing_subscriber::fmt()
             .with_env_filter(EnvFilter::from_default_env())
             .with_ansi(true)
+            .finish()
+            .with(otlp_layer(&config.otlp, "golem-worker-executor")?)
             .init();
     }
 
-    let runtime = Arc::new(
-        tokio::runtime::Builder::new_multi_thread()
-            .enable_all()
-            .build()
-            .unwrap(),
-    );
     runtime.block_on(run(config, prometheus, runtime.handle().clone()))
 }
@@ -10,9 +10,9 @@ use tracing::{error, info};
 use crate::http::{ApiInputPath, InputHttpRequest};
 use crate::service::api_definition_lookup::ApiDefinitionLookup;
 
-use crate::worker_binding::WorkerBindingResolver;
-use crate::worker_bridge_execution::WorkerRequest;
+use crate::worker_binding::{BindingType, WorkerBindingResolver};
 use crate::worker_bridge_execution::WorkerRequestExecutor;
+use crate::worker_bridge_execution::{PromiseCompletionRequest, WorkerRequest};
 
 // Executes custom request with the help of worker_request_executor and definition_service
 // This is a common API projects can make use of, similar to healthcheck service
@@ -92,28 +92,55 @@ impl CustomHttpRequestApi {
 
         match api_request.resolve(&api_definition) {
             Some(resolved_route) => {
-                let resolved_worker_request =
-                    match WorkerRequest::from_resolved_route(resolved_route.clone()) {
-                        Ok(golem_worker_request) => golem_worker_request,
-                        Err(e) => {
-                            error!(
-                                "API request id: {} - request error: {}",
-                                &api_definition.id, e
-                            );
-                            return Response::builder()
-                                .status(StatusCode::INTERNAL_SERVER_ERROR)
-                                .body(Body::from_string(
-                                    format!("API request error {}", e).to_string(),
-                                ));
-                        }
-                    };
-
-                // Execute the request using a executor
-                match self
-                    .worker_to_http_response_service
-                    .execute(resolved_worker_request.clone())
-                    .await
-                {
+                let result = match resolved_route.resolved_worker_binding_template.binding_type {
+                    BindingType::WitWorker | BindingType::EphemeralWorker => {
+                        let resolved_worker_request =
+                            match WorkerRequest::from_resolved_route(resolved_route.clone()) {
+                                Ok(golem_worker_request) => golem_worker_request,
+                                Err(e) => {
+                                    error!(
+                                        "API request id: {} - request error: {}",
+                                        &api_definition.id, e
+                                    );
+                                    return Response::builder()
+                                        .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                        .body(Body::from_string(
+                                            format!("API request error {}", e).to_string(),
+                                        ));
+                                }
+                            };
+
+                        // Execute the request using a executor
+                        self.worker_to_http_response_service
+                            .execute(resolved_worker_request.clone())
+                            .await
+                    }
+                    BindingType::CompletePromise => {
+                        let promise_completion_request =
+                            match PromiseCompletionRequest::from_resolved_route(
+                                resolved_route.clone(),
+                            ) {
+                                Ok(promise_completion_request) => promise_completion_request,
+                                Err(e) => {
+                                    error!(
+                                        "API request id: {} - request error: {}",
+                                        &api_definition.id, e
+                                    );
+                                    return Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from_string(
+                                            format!("API request error {}", e).to_string(),
+                                        ));
+                                }
+                            };
+
+                        self.worker_to_http_response_service
+                            .complete_promise(promise_completion_request)
+                            .await
+                    }
+                };
+
+                match result {
                     Ok(worker_response) => worker_response.to_http_response(
                         &resolved_route.resolved_worker_binding_template.response,
                         &resolved_route.typed_value_from_input,
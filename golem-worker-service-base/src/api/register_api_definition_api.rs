@@ -11,6 +11,7 @@ use crate::api_definition::{ApiDefinitionId, ApiSite, ApiVersion};
 use crate::expression;
 use crate::expression::Expr;
 use crate::parser::ParseError;
+use crate::worker_binding::BindingType;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
 #[serde(rename_all = "camelCase")]
@@ -46,12 +47,25 @@ pub struct Route {
 #[serde(rename_all = "camelCase")]
 #[oai(rename_all = "camelCase")]
 pub struct GolemWorkerBinding {
+    #[serde(default)]
+    #[oai(default)]
+    pub binding_type: BindingType,
     pub component: ComponentId,
     pub worker_id: String,
     pub function_name: String,
     pub function_params: Vec<String>,
     pub idempotency_key: Option<String>,
     pub response: Option<String>,
+    pub promise: Option<PromiseBinding>,
+}
+
+// Expressions computing the promise completed by a `complete-promise` binding
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct PromiseBinding {
+    pub oplog_idx: String,
+    pub payload: String,
 }
 
 impl<N> From<crate::api_definition::ApiDeployment<N>> for ApiDeployment {
@@ -160,13 +174,23 @@ impl TryFrom<crate::worker_binding::GolemWorkerBinding> for GolemWorkerBinding {
             None
         };
 
+        let promise = match value.promise {
+            Some(promise) => Some(PromiseBinding {
+                oplog_idx: expression::to_string(&promise.oplog_idx).map_err(|e| e.to_string())?,
+                payload: expression::to_string(&promise.payload).map_err(|e| e.to_string())?,
+            }),
+            None => None,
+        };
+
         Ok(Self {
+            binding_type: value.binding_type,
             component: value.component,
             worker_id,
             function_name: value.function_name,
             function_params,
             idempotency_key,
             response,
+            promise,
         })
     }
 }
@@ -197,13 +221,23 @@ impl TryInto<crate::worker_binding::GolemWorkerBinding> for GolemWorkerBinding {
             None
         };
 
+        let promise = match self.promise {
+            Some(promise) => Some(crate::worker_binding::PromiseBinding {
+                oplog_idx: expression::from_string(promise.oplog_idx).map_err(|e| e.to_string())?,
+                payload: expression::from_string(promise.payload).map_err(|e| e.to_string())?,
+            }),
+            None => None,
+        };
+
         Ok(crate::worker_binding::GolemWorkerBinding {
+            binding_type: self.binding_type,
             component: self.component,
             worker_id,
             function_name: self.function_name,
             function_params,
             idempotency_key,
             response,
+            promise,
         })
     }
 }
@@ -296,6 +330,16 @@ impl From<MethodPattern> for grpc_apidefinition::HttpMethod {
     }
 }
 
+impl From<BindingType> for grpc_apidefinition::WorkerBindingType {
+    fn from(value: BindingType) -> Self {
+        match value {
+            BindingType::WitWorker => grpc_apidefinition::WorkerBindingType::WitWorker,
+            BindingType::CompletePromise => grpc_apidefinition::WorkerBindingType::CompletePromise,
+            BindingType::EphemeralWorker => grpc_apidefinition::WorkerBindingType::EphemeralWorker,
+        }
+    }
+}
+
 impl TryFrom<grpc_apidefinition::HttpRoute> for crate::api_definition::http::Route {
     type Error = String;
 
@@ -338,6 +382,16 @@ impl TryFrom<crate::worker_binding::GolemWorkerBinding> for grpc_apidefinition::
             None
         };
 
+        let promise = match value.promise {
+            Some(promise) => Some(grpc_apidefinition::PromiseBinding {
+                oplog_idx: expression::to_string(&promise.oplog_idx).map_err(|e| e.to_string())?,
+                payload: expression::to_string(&promise.payload).map_err(|e| e.to_string())?,
+            }),
+            None => None,
+        };
+
+        let binding_type: grpc_apidefinition::WorkerBindingType = value.binding_type.into();
+
         let result = grpc_apidefinition::WorkerBinding {
             component: Some(value.component.into()),
             worker_id,
@@ -345,6 +399,8 @@ impl TryFrom<crate::worker_binding::GolemWorkerBinding> for grpc_apidefinition::
             function_params,
             idempotency_key,
             response,
+            binding_type: binding_type as i32,
+            promise,
         };
 
         Ok(result)
@@ -382,13 +438,31 @@ impl TryFrom<grpc_apidefinition::WorkerBinding> for crate::worker_binding::Golem
             None
         };
 
+        let promise = match value.promise {
+            Some(promise) => Some(crate::worker_binding::PromiseBinding {
+                oplog_idx: promise
+                    .oplog_idx
+                    .parse()
+                    .map_err(|e: ParseError| e.to_string())?,
+                payload: promise
+                    .payload
+                    .parse()
+                    .map_err(|e: ParseError| e.to_string())?,
+            }),
+            None => None,
+        };
+
+        let binding_type: BindingType = value.binding_type.try_into()?;
+
         let result = crate::worker_binding::GolemWorkerBinding {
+            binding_type,
             component: component_id,
             worker_id,
             function_name: value.function_name,
             function_params,
             idempotency_key,
             response,
+            promise,
         };
 
         Ok(result)
@@ -403,3 +477,12 @@ fn test_method_pattern() {
         assert_eq!(method, method_grpc as i32);
     }
 }
+
+#[test]
+fn test_binding_type() {
+    for binding_type in 0..2 {
+        let binding_type_model: BindingType = binding_type.try_into().unwrap();
+        let binding_type_grpc: grpc_apidefinition::WorkerBindingType = binding_type_model.into();
+        assert_eq!(binding_type, binding_type_grpc as i32);
+    }
+}
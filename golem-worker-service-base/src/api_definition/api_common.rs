@@ -43,6 +43,12 @@ pub trait HasIsDraft {
     fn set_not_draft(&mut self);
 }
 
+// API definitions stored before their encoding got versioned
+pub trait HasLegacyEncoding: Sized {
+    // Decodes a definition stored before the worker bindings got a binding type and a promise
+    fn decode_v1(bytes: &[u8]) -> Result<Self, String>;
+}
+
 pub trait HasGolemWorkerBindings {
     fn get_golem_worker_bindings(&self) -> Vec<GolemWorkerBinding>;
 }
@@ -10,11 +10,12 @@ use serde_json::Value;
 
 use crate::api_definition::api_common::HasIsDraft;
 use crate::api_definition::{
-    ApiDefinitionId, ApiVersion, HasApiDefinitionId, HasGolemWorkerBindings, HasVersion,
+    ApiDefinitionId, ApiVersion, HasApiDefinitionId, HasGolemWorkerBindings, HasLegacyEncoding,
+    HasVersion,
 };
 use crate::parser::path_pattern_parser::PathPatternParser;
 use crate::parser::{GolemParser, ParseError};
-use crate::worker_binding::GolemWorkerBinding;
+use crate::worker_binding::{GolemWorkerBinding, GolemWorkerBindingV1};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Encode, Decode)]
 #[serde(rename_all = "camelCase")]
@@ -57,6 +58,42 @@ impl HasIsDraft for HttpApiDefinition {
     }
 }
 
+impl HasLegacyEncoding for HttpApiDefinition {
+    fn decode_v1(bytes: &[u8]) -> Result<Self, String> {
+        let definition: HttpApiDefinitionV1 = golem_common::serialization::deserialize(bytes)?;
+        Ok(HttpApiDefinition {
+            id: definition.id,
+            version: definition.version,
+            routes: definition
+                .routes
+                .into_iter()
+                .map(|route| Route {
+                    method: route.method,
+                    path: route.path,
+                    binding: route.binding.into(),
+                })
+                .collect(),
+            draft: definition.draft,
+        })
+    }
+}
+
+// The encoding of definitions stored before the worker bindings got a binding type and a promise
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+struct HttpApiDefinitionV1 {
+    id: ApiDefinitionId,
+    version: ApiVersion,
+    routes: Vec<RouteV1>,
+    draft: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+struct RouteV1 {
+    method: MethodPattern,
+    path: AllPathPatterns,
+    binding: GolemWorkerBindingV1,
+}
+
 #[derive(
     Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Display, Encode, Decode, Enum,
 )]
@@ -77,7 +77,7 @@ impl ParseFromJSON for JsonOpenApiDefinition {
 mod internal {
     use crate::api_definition::http::{AllPathPatterns, HttpResponseMapping, MethodPattern, Route};
     use crate::expression::Expr;
-    use crate::worker_binding::{GolemWorkerBinding, ResponseMapping};
+    use crate::worker_binding::{BindingType, GolemWorkerBinding, PromiseBinding, ResponseMapping};
     use golem_common::model::ComponentId;
     use openapiv3::{OpenAPI, PathItem, Paths, ReferenceOr};
     use serde_json::Value;
@@ -153,13 +153,35 @@ mod internal {
                 GOLEM_WORKER_BRIDGE_EXTENSION
             ))?;
 
+        let binding_type = get_binding_type(worker_bridge_info)?;
+
+        // A promise completion doesn't call any function of the worker
+        let (function_name, function_params, promise) = match binding_type {
+            BindingType::WitWorker | BindingType::EphemeralWorker => (
+                get_function_name(worker_bridge_info)?,
+                get_function_params_expr(worker_bridge_info)?,
+                None,
+            ),
+            BindingType::CompletePromise => (
+                String::new(),
+                vec![],
+                Some(get_promise_binding(worker_bridge_info)?),
+            ),
+        };
+
         let binding = GolemWorkerBinding {
-            worker_id: get_worker_id_expr(worker_bridge_info)?,
-            function_name: get_function_name(worker_bridge_info)?,
-            function_params: get_function_params_expr(worker_bridge_info)?,
+            binding_type: binding_type.clone(),
+            worker_id: match binding_type {
+                // Ephemeral workers are created for each request, there is no worker to identify
+                BindingType::EphemeralWorker => Expr::Literal(String::new()),
+                _ => get_worker_id_expr(worker_bridge_info)?,
+            },
+            function_name,
+            function_params,
             component: get_component_id(worker_bridge_info)?,
             idempotency_key: get_idempotency_key(worker_bridge_info)?,
             response: get_response_mapping(worker_bridge_info)?,
+            promise,
         };
 
         Ok(Route {
@@ -169,6 +191,44 @@ mod internal {
         })
     }
 
+    pub(crate) fn get_binding_type(worker_bridge_info: &Value) -> Result<BindingType, String> {
+        match worker_bridge_info.get("type") {
+            None => Ok(BindingType::WitWorker),
+            Some(Value::String(binding_type)) => match binding_type.as_str() {
+                "wit-worker" => Ok(BindingType::WitWorker),
+                "complete-promise" => Ok(BindingType::CompletePromise),
+                "ephemeral-worker" => Ok(BindingType::EphemeralWorker),
+                _ => Err(format!("Unknown binding type {}", binding_type)),
+            },
+            _ => Err("type is not a string".to_string()),
+        }
+    }
+
+    pub(crate) fn get_promise_binding(
+        worker_bridge_info: &Value,
+    ) -> Result<PromiseBinding, String> {
+        let promise = worker_bridge_info
+            .get("promise")
+            .ok_or("No promise found")?;
+
+        let oplog_idx = promise
+            .get("oplog-idx")
+            .ok_or("No promise oplog-idx found")?
+            .as_str()
+            .ok_or("promise oplog-idx is not a string")?;
+
+        let payload = promise
+            .get("payload")
+            .ok_or("No promise payload found")?
+            .as_str()
+            .ok_or("promise payload is not a string")?;
+
+        Ok(PromiseBinding {
+            oplog_idx: expression::from_string(oplog_idx).map_err(|err| err.to_string())?,
+            payload: expression::from_string(payload).map_err(|err| err.to_string())?,
+        })
+    }
+
     pub(crate) fn get_component_id(worker_bridge_info: &Value) -> Result<ComponentId, String> {
         let component_id = worker_bridge_info
             .get("component-id")
@@ -277,7 +337,7 @@ mod tests {
     use super::*;
     use crate::api_definition::http::{AllPathPatterns, MethodPattern, Route};
     use crate::expression::{Expr, InnerNumber};
-    use crate::worker_binding::{GolemWorkerBinding, ResponseMapping};
+    use crate::worker_binding::{BindingType, GolemWorkerBinding, PromiseBinding, ResponseMapping};
     use golem_common::model::ComponentId;
     use openapiv3::PathItem;
     use serde_json::json;
@@ -308,6 +368,7 @@ mod tests {
                 path: path_pattern,
                 method: MethodPattern::Get,
                 binding: GolemWorkerBinding {
+                    binding_type: BindingType::WitWorker,
                     worker_id: Expr::Concat(vec![
                         Expr::Literal("worker-".to_string()),
                         Expr::SelectField(
@@ -351,7 +412,107 @@ mod tests {
                         ]
                         .into_iter()
                         .collect()
-                    )))
+                    ))),
+                    promise: None,
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_get_complete_promise_route_from_path_item() {
+        let path_item = PathItem {
+            extensions: vec![(
+                "x-golem-worker-bridge".to_string(),
+                json!({
+                    "type": "complete-promise",
+                    "worker-id": "${request.path.worker}",
+                    "component-id": "00000000-0000-0000-0000-000000000000",
+                    "promise": {
+                        "oplog-idx": "${request.path.oplog-idx}",
+                        "payload": "${request.body}"
+                    }
+                }),
+            )]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+
+        let path_pattern = AllPathPatterns::parse("/callback/{worker}/{oplog-idx}").unwrap();
+
+        let result = get_route_from_path_item("post", &path_item, &path_pattern);
+
+        let request_path = |field: &str| {
+            Expr::SelectField(
+                Box::new(Expr::SelectField(
+                    Box::new(Expr::Request()),
+                    "path".to_string(),
+                )),
+                field.to_string(),
+            )
+        };
+
+        assert_eq!(
+            result,
+            Ok(Route {
+                path: path_pattern,
+                method: MethodPattern::Post,
+                binding: GolemWorkerBinding {
+                    binding_type: BindingType::CompletePromise,
+                    worker_id: request_path("worker"),
+                    function_name: "".to_string(),
+                    function_params: vec![],
+                    component: ComponentId(Uuid::nil()),
+                    idempotency_key: None,
+                    response: None,
+                    promise: Some(PromiseBinding {
+                        oplog_idx: request_path("oplog-idx"),
+                        payload: Expr::SelectField(Box::new(Expr::Request()), "body".to_string()),
+                    }),
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_get_ephemeral_worker_route_from_path_item() {
+        let path_item = PathItem {
+            extensions: vec![(
+                "x-golem-worker-bridge".to_string(),
+                json!({
+                    "type": "ephemeral-worker",
+                    "function-name": "test",
+                    "function-params": ["${request.body}"],
+                    "component-id": "00000000-0000-0000-0000-000000000000"
+                }),
+            )]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+
+        let path_pattern = AllPathPatterns::parse("/test").unwrap();
+
+        let result = get_route_from_path_item("post", &path_item, &path_pattern);
+
+        assert_eq!(
+            result,
+            Ok(Route {
+                path: path_pattern,
+                method: MethodPattern::Post,
+                binding: GolemWorkerBinding {
+                    binding_type: BindingType::EphemeralWorker,
+                    worker_id: Expr::Literal("".to_string()),
+                    function_name: "test".to_string(),
+                    function_params: vec![Expr::SelectField(
+                        Box::new(Expr::Request()),
+                        "body".to_string()
+                    )],
+                    component: ComponentId(Uuid::nil()),
+                    idempotency_key: None,
+                    response: None,
+                    promise: None,
                 }
             })
         );
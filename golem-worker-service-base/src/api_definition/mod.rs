@@ -1,4 +1,6 @@
 pub use api_common::{ApiDefinitionId, ApiDeployment, ApiSite, ApiSiteString, ApiVersion};
-pub(crate) use api_common::{HasApiDefinitionId, HasGolemWorkerBindings, HasIsDraft, HasVersion};
+pub(crate) use api_common::{
+    HasApiDefinitionId, HasGolemWorkerBindings, HasIsDraft, HasLegacyEncoding, HasVersion,
+};
 mod api_common;
 pub mod http;
@@ -7,7 +7,7 @@ use serde::Deserialize;
 use url::Url;
 use uuid::Uuid;
 
-use golem_common::config::{RedisConfig, RetryConfig};
+use golem_common::config::{OtlpConfig, RedisConfig, RetryConfig};
 use golem_service_base::routing_table::RoutingTableConfig;
 
 // The base configuration for the worker service
@@ -20,11 +20,13 @@ pub struct WorkerServiceBaseConfig {
     pub component_service: ComponentServiceConfig,
     pub enable_tracing_console: bool,
     pub enable_json_log: bool,
+    pub otlp: OtlpConfig,
     pub port: u16,
     pub custom_request_port: u16,
     pub worker_grpc_port: u16,
     pub routing_table: RoutingTableConfig,
     pub worker_executor_client_cache: WorkerExecutorClientCacheConfig,
+    pub limits: WorkerServiceLimitsConfig,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -43,6 +45,21 @@ impl Default for WorkerExecutorClientCacheConfig {
     }
 }
 
+#[derive(Clone, Debug, Deserialize)]
+pub struct WorkerServiceLimitsConfig {
+    /// Upper limit of the number of workers a broadcast invokes at once, whatever concurrency
+    /// the client asks for
+    pub max_broadcast_concurrency: u32,
+}
+
+impl Default for WorkerServiceLimitsConfig {
+    fn default() -> Self {
+        Self {
+            max_broadcast_concurrency: 64,
+        }
+    }
+}
+
 impl WorkerServiceBaseConfig {
     pub fn is_local_env(&self) -> bool {
         self.environment.to_lowercase() == "local"
@@ -65,11 +82,13 @@ impl Default for WorkerServiceBaseConfig {
             component_service: ComponentServiceConfig::default(),
             enable_tracing_console: false,
             enable_json_log: false,
+            otlp: OtlpConfig::default(),
             port: 9000,
             custom_request_port: 9001,
             worker_grpc_port: 9092,
             routing_table: RoutingTableConfig::default(),
             worker_executor_client_cache: WorkerExecutorClientCacheConfig::default(),
+            limits: WorkerServiceLimitsConfig::default(),
         }
     }
 }
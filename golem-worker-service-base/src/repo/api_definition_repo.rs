@@ -3,14 +3,15 @@ use std::fmt::Debug;
 use std::sync::Mutex;
 
 use async_trait::async_trait;
-use bytes::Bytes;
+use bytes::{BufMut, Bytes, BytesMut};
 use serde::de::DeserializeOwned;
 use tracing::{debug, info};
 
 use golem_common::config::RedisConfig;
 use golem_common::redis::{RedisError, RedisPool};
+use golem_common::serialization::SERIALIZATION_VERSION_V2;
 
-use crate::api_definition::{ApiDefinitionId, HasIsDraft};
+use crate::api_definition::{ApiDefinitionId, HasIsDraft, HasLegacyEncoding};
 use crate::repo::api_namespace::ApiNamespace;
 use crate::service::api_definition::ApiDefinitionKey;
 
@@ -191,6 +192,10 @@ pub struct RedisApiRegistry {
     pool: RedisPool,
 }
 
+/// Prefixes the definitions stored since the worker bindings got a binding type and a promise.
+/// The definitions stored before start with the serialization version of their value instead.
+const DEFINITION_ENCODING_V2: u8 = 0x80;
+
 impl RedisApiRegistry {
     pub async fn new(config: &RedisConfig) -> Result<RedisApiRegistry, ApiRegistrationRepoError> {
         let pool_result = RedisPool::configured(config).await?;
@@ -203,7 +208,13 @@ impl RedisApiRegistry {
 impl<Namespace, ApiDefinition> ApiDefinitionRepo<Namespace, ApiDefinition> for RedisApiRegistry
 where
     Namespace: ApiNamespace,
-    ApiDefinition: HasIsDraft + bincode::Decode + bincode::Encode + DeserializeOwned + Send + Sync,
+    ApiDefinition: HasIsDraft
+        + HasLegacyEncoding
+        + bincode::Decode
+        + bincode::Encode
+        + DeserializeOwned
+        + Send
+        + Sync,
 {
     async fn create(
         &self,
@@ -376,10 +387,9 @@ where
 impl RedisApiRegistry {
     fn deserialize<T>(&self, bytes: &[u8]) -> Result<T, ApiRegistrationRepoError>
     where
-        T: DeserializeOwned + bincode::Decode,
+        T: HasLegacyEncoding + bincode::Decode,
     {
-        self.pool
-            .deserialize(bytes)
+        Self::decode_definition(bytes)
             .map_err(|e| ApiRegistrationRepoError::Internal(anyhow::Error::msg(e)))
     }
 
@@ -387,11 +397,31 @@ impl RedisApiRegistry {
     where
         T: bincode::Encode,
     {
-        self.pool
-            .serialize(value)
+        Self::encode_definition(value)
             .map_err(|e| ApiRegistrationRepoError::Internal(anyhow::Error::msg(e)))
     }
 
+    fn encode_definition<T: bincode::Encode>(value: &T) -> Result<Bytes, String> {
+        let mut bytes = BytesMut::new();
+        bytes.put_u8(DEFINITION_ENCODING_V2);
+        bytes.extend_from_slice(&golem_common::serialization::serialize(value)?);
+        Ok(bytes.freeze())
+    }
+
+    fn decode_definition<T>(bytes: &[u8]) -> Result<T, String>
+    where
+        T: HasLegacyEncoding + bincode::Decode,
+    {
+        match bytes.split_first() {
+            Some((&DEFINITION_ENCODING_V2, value)) => {
+                golem_common::serialization::deserialize(value)
+            }
+            Some((&SERIALIZATION_VERSION_V2, _)) => T::decode_v1(bytes),
+            Some((encoding, _)) => Err(format!("Unknown API definition encoding: {encoding}")),
+            None => Err("Empty API definition".to_string()),
+        }
+    }
+
     /// Retrieve all keys for a given namespace.
     async fn get_all_keys<Namespace: ApiNamespace>(
         &self,
@@ -416,7 +446,7 @@ impl RedisApiRegistry {
     /// Retrieve all api definitions for a given set of keys.
     async fn get_all_api_definitions<
         Namespace: ApiNamespace,
-        ApiDefinition: bincode::Decode + DeserializeOwned,
+        ApiDefinition: HasLegacyEncoding + bincode::Decode + DeserializeOwned,
     >(
         &self,
         keys: Vec<ApiDefinitionKey<Namespace>>,
@@ -492,9 +522,12 @@ mod tests {
     use serde::Deserialize;
 
     use golem_common::config::RedisConfig;
+    use golem_common::model::ComponentId;
 
-    use crate::api_definition::http::HttpApiDefinition;
+    use crate::api_definition::http::{AllPathPatterns, HttpApiDefinition, MethodPattern};
     use crate::api_definition::{ApiDefinitionId, ApiVersion};
+    use crate::expression::Expr;
+    use crate::worker_binding::ResponseMapping;
 
     use super::*;
 
@@ -609,6 +642,78 @@ mod tests {
         assert!(api_definition_result4.is_empty());
     }
 
+    // The layout of the definitions stored before the worker bindings got a binding type
+    #[derive(Encode)]
+    struct OldHttpApiDefinition {
+        id: ApiDefinitionId,
+        version: ApiVersion,
+        routes: Vec<OldRoute>,
+        draft: bool,
+    }
+
+    #[derive(Encode)]
+    struct OldRoute {
+        method: MethodPattern,
+        path: AllPathPatterns,
+        binding: OldGolemWorkerBinding,
+    }
+
+    #[derive(Encode)]
+    struct OldGolemWorkerBinding {
+        component: ComponentId,
+        worker_id: Expr,
+        function_name: String,
+        function_params: Vec<Expr>,
+        idempotency_key: Option<Expr>,
+        response: Option<ResponseMapping>,
+    }
+
+    #[test]
+    pub fn test_decode_definitions_stored_with_the_old_layout() {
+        let api_id = ApiDefinitionKey {
+            namespace: CommonNamespace::new("default"),
+            id: ApiDefinitionId("api1".to_string()),
+            version: ApiVersion("0.0.1".to_string()),
+        };
+        let api_definition = get_simple_api_definition_example(
+            &api_id,
+            "getcartcontent/{cart-id}",
+            "cart-${path.cart-id}",
+        );
+
+        let old_api_definition = OldHttpApiDefinition {
+            id: api_definition.id.clone(),
+            version: api_definition.version.clone(),
+            routes: api_definition
+                .routes
+                .iter()
+                .map(|route| OldRoute {
+                    method: route.method.clone(),
+                    path: route.path.clone(),
+                    binding: OldGolemWorkerBinding {
+                        component: route.binding.component.clone(),
+                        worker_id: route.binding.worker_id.clone(),
+                        function_name: route.binding.function_name.clone(),
+                        function_params: route.binding.function_params.clone(),
+                        idempotency_key: route.binding.idempotency_key.clone(),
+                        response: route.binding.response.clone(),
+                    },
+                })
+                .collect(),
+            draft: api_definition.draft,
+        };
+        let old_bytes = golem_common::serialization::serialize(&old_api_definition).unwrap();
+        let new_bytes = RedisApiRegistry::encode_definition(&api_definition).unwrap();
+
+        let decoded_old: HttpApiDefinition =
+            RedisApiRegistry::decode_definition(&old_bytes).unwrap();
+        let decoded_new: HttpApiDefinition =
+            RedisApiRegistry::decode_definition(&new_bytes).unwrap();
+
+        assert_eq!(decoded_old, api_definition);
+        assert_eq!(decoded_new, api_definition);
+    }
+
     // docker run -d --name redis-stack-server -p 6379:6379 redis/redis-stack-server:latest
     #[tokio::test]
     #[ignore]
@@ -11,6 +11,7 @@ use golem_service_base::model::{
 use crate::api_definition::http::{HttpApiDefinition, MethodPattern, Route};
 use crate::http::router::{Router, RouterPattern};
 use crate::service::api_definition_validator::{ApiDefinitionValidatorService, ValidationErrors};
+use crate::worker_binding::BindingType;
 
 // Http Api Definition Validator
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
@@ -113,6 +114,17 @@ fn validate_route(
     route: Route,
     components: &HashMap<&ComponentId, &ComponentMetadata>,
 ) -> Result<(), RouteValidationError> {
+    if route.binding.binding_type == BindingType::CompletePromise {
+        return if route.binding.promise.is_some() {
+            Ok(())
+        } else {
+            Err(RouteValidationError::from_route(
+                route,
+                "Missing promise for complete-promise binding".to_string(),
+            ))
+        };
+    }
+
     let component_id = route.binding.component.clone();
     // We can unwrap here because we've already validated that all components are present.
     let component = components.get(&component_id).unwrap();
@@ -157,12 +169,14 @@ fn test_unique_routes() {
             method,
             path: crate::api_definition::http::AllPathPatterns::parse(path).unwrap(),
             binding: crate::worker_binding::GolemWorkerBinding {
+                binding_type: BindingType::WitWorker,
                 component: ComponentId::new_v4(),
                 worker_id: crate::expression::Expr::Request(),
                 function_name: "test".into(),
                 function_params: vec![],
                 idempotency_key: None,
                 response: None,
+                promise: None,
             },
         }
     }
@@ -13,7 +13,7 @@ where
     K: AsRef<str>,
     V: AsRef<str>,
 {
-    let mut req = tonic::Request::new(request);
+    let mut req = golem_common::telemetry::with_trace_context(request);
     let req_metadata = req.metadata_mut();
 
     for (key, value) in metadata {
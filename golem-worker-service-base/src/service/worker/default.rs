@@ -3,6 +3,7 @@ use std::pin::Pin;
 use std::{collections::HashMap, sync::Arc, time::Duration};
 
 use async_trait::async_trait;
+use futures::stream::{self, StreamExt};
 use golem_wasm_ast::analysis::AnalysedFunctionResult;
 use golem_wasm_rpc::json::get_json_from_typed_value;
 use golem_wasm_rpc::protobuf::Val as ProtoVal;
@@ -11,21 +12,27 @@ use serde_json::Value;
 use tokio::time::sleep;
 use tonic::transport::Channel;
 use tracing::{debug, info};
+use uuid::Uuid;
 
 use golem_api_grpc::proto::golem::worker::IdempotencyKey as ProtoIdempotencyKey;
+use golem_api_grpc::proto::golem::worker::WorkerId as ProtoWorkerId;
 use golem_api_grpc::proto::golem::worker::{InvokeResult as ProtoInvokeResult, UpdateMode};
 use golem_api_grpc::proto::golem::workerexecutor::worker_executor_client::WorkerExecutorClient;
 use golem_api_grpc::proto::golem::workerexecutor::{
-    self, CompletePromiseRequest, ConnectWorkerRequest, CreateWorkerRequest,
-    InterruptWorkerRequest, InvokeAndAwaitWorkerRequest, ResumeWorkerRequest, UpdateWorkerRequest,
+    self, CompletePromiseRequest, ConnectWorkerRequest, CreateWorkerRequest, DeleteKeyValueRequest,
+    GetKeyValueBucketsRequest, GetKeyValueKeysRequest, GetKeyValueRequest, GetPromisesRequest,
+    InterruptWorkerRequest, InvokeAndAwaitWorkerRequest, ResumeWorkerRequest,
+    SendMailboxMessageRequest, SetKeyValueRequest, UpdateWorkerRequest,
 };
 
 use golem_common::model::{
     AccountId, CallingConvention, ComponentId, ComponentVersion, FilterComparator, IdempotencyKey,
-    Timestamp, WorkerFilter, WorkerStatus,
+    Timestamp, WorkerEventCursor, WorkerFilter, WorkerStatus,
 };
+use golem_common::telemetry::with_trace_context;
 use golem_service_base::model::{
-    GolemErrorUnknown, PromiseId, ResourceLimits, WorkerId, WorkerMetadata,
+    BroadcastWorkerResult, GolemErrorUnknown, KeyValueKeys, PromiseId, PromiseMetadata,
+    ResourceLimits, WorkerId, WorkerMetadata, WorkerPromises,
 };
 use golem_service_base::typechecker::{TypeCheckIn, TypeCheckOut};
 use golem_service_base::{
@@ -55,6 +62,7 @@ pub trait WorkerService<AuthCtx> {
     async fn connect(
         &self,
         worker_id: &WorkerId,
+        since: Option<WorkerEventCursor>,
         metadata: WorkerRequestMetadata,
         auth_ctx: &AuthCtx,
     ) -> WorkerResult<ConnectWorkerStream>;
@@ -83,6 +91,8 @@ pub trait WorkerService<AuthCtx> {
         auth_ctx: &AuthCtx,
     ) -> WorkerResult<TypeAnnotatedValue>;
 
+    /// `call_chain` lists the workers waiting for this invocation through wasm-rpc, and is
+    /// forwarded to the worker executor for detecting deadlocks between workers.
     async fn invoke_and_await_function_proto(
         &self,
         worker_id: &WorkerId,
@@ -90,10 +100,61 @@ pub trait WorkerService<AuthCtx> {
         function_name: String,
         params: Vec<ProtoVal>,
         calling_convention: &CallingConvention,
+        call_chain: Vec<ProtoWorkerId>,
         metadata: WorkerRequestMetadata,
         auth_ctx: &AuthCtx,
     ) -> WorkerResult<ProtoInvokeResult>;
 
+    /// Invokes a function of the latest version of a component on a fresh ephemeral worker.
+    /// Ephemeral workers have no durable state: they are created for a single invocation,
+    /// dropped right after it and are never recovered or retried.
+    async fn invoke_and_await_ephemeral_function(
+        &self,
+        component_id: &ComponentId,
+        function_name: String,
+        params: Value,
+        calling_convention: &CallingConvention,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<Value>;
+
+    async fn invoke_and_await_ephemeral_function_typed_value(
+        &self,
+        component_id: &ComponentId,
+        function_name: String,
+        params: Value,
+        calling_convention: &CallingConvention,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<TypeAnnotatedValue>;
+
+    async fn invoke_and_await_ephemeral_function_proto(
+        &self,
+        component_id: &ComponentId,
+        function_name: String,
+        params: Vec<ProtoVal>,
+        calling_convention: &CallingConvention,
+        call_chain: Vec<ProtoWorkerId>,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<ProtoInvokeResult>;
+
+    /// Invokes a function on every worker of a component matching the filter, and waits for
+    /// all of them. The invocation of each worker gets an idempotency key derived from the given
+    /// one, so the broadcast can be retried with it without invoking any worker twice.
+    async fn broadcast_invoke_and_await_function(
+        &self,
+        component_id: &ComponentId,
+        filter: Option<WorkerFilter>,
+        idempotency_key: Option<IdempotencyKey>,
+        function_name: String,
+        params: Value,
+        calling_convention: &CallingConvention,
+        max_concurrency: usize,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<Vec<BroadcastWorkerResult>>;
+
     async fn invoke_function(
         &self,
         worker_id: &WorkerId,
@@ -119,9 +180,24 @@ pub trait WorkerService<AuthCtx> {
         worker_id: &WorkerId,
         oplog_id: u64,
         data: Vec<u8>,
+        failed: bool,
         auth_ctx: &AuthCtx,
     ) -> WorkerResult<bool>;
 
+    async fn get_promises(
+        &self,
+        worker_id: &WorkerId,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<WorkerPromises>;
+
+    async fn send_mailbox_message(
+        &self,
+        worker_id: &WorkerId,
+        idempotency_key: Option<IdempotencyKey>,
+        payload: Vec<u8>,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()>;
+
     async fn interrupt(
         &self,
         worker_id: &WorkerId,
@@ -154,6 +230,47 @@ pub trait WorkerService<AuthCtx> {
         target_version: ComponentVersion,
         auth_ctx: &AuthCtx,
     ) -> WorkerResult<()>;
+
+    async fn get_key_value_buckets(
+        &self,
+        account_id: &AccountId,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<Vec<String>>;
+
+    async fn get_key_value_keys(
+        &self,
+        account_id: &AccountId,
+        bucket: &str,
+        prefix: Option<String>,
+        cursor: Option<String>,
+        count: u64,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<KeyValueKeys>;
+
+    async fn get_key_value(
+        &self,
+        account_id: &AccountId,
+        bucket: &str,
+        key: &str,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<Option<Vec<u8>>>;
+
+    async fn set_key_value(
+        &self,
+        account_id: &AccountId,
+        bucket: &str,
+        key: &str,
+        value: Vec<u8>,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()>;
+
+    async fn delete_key_value(
+        &self,
+        account_id: &AccountId,
+        bucket: &str,
+        key: &str,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()>;
 }
 
 #[derive(Clone, Debug)]
@@ -167,6 +284,7 @@ pub struct WorkerServiceDefault<AuthCtx> {
     worker_executor_clients: Arc<dyn WorkerExecutorClients + Send + Sync>,
     component_service: Arc<dyn ComponentService<AuthCtx> + Send + Sync>,
     routing_table_service: Arc<dyn RoutingTableService + Send + Sync>,
+    max_broadcast_concurrency: usize,
 }
 
 impl<AuthCtx> WorkerServiceDefault<AuthCtx> {
@@ -174,11 +292,13 @@ impl<AuthCtx> WorkerServiceDefault<AuthCtx> {
         worker_executor_clients: Arc<dyn WorkerExecutorClients + Send + Sync>,
         component_service: Arc<dyn ComponentService<AuthCtx> + Send + Sync>,
         routing_table_service: Arc<dyn RoutingTableService + Send + Sync>,
+        max_broadcast_concurrency: usize,
     ) -> Self {
         Self {
             worker_executor_clients,
             component_service,
             routing_table_service,
+            max_broadcast_concurrency,
         }
     }
 }
@@ -203,7 +323,7 @@ where
             |worker_executor_client, (worker_id, component_version, args, env, metadata)| {
                 Box::pin(async move {
                     let response: tonic::Response<workerexecutor::CreateWorkerResponse> = worker_executor_client
-                        .create_worker(
+                        .create_worker(with_trace_context(
                             CreateWorkerRequest {
                                 worker_id: Some(worker_id.clone().into()),
                                 component_version: *component_version,
@@ -212,7 +332,7 @@ where
                                 account_id: metadata.account_id.clone().map(|id| id.into()),
                                 account_limits: metadata.limits.clone().map(|id| id.into()),
                             }
-                        )
+                        ))
                         .await
                         .map_err(|err| {
                             GolemError::RuntimeError(GolemErrorRuntimeError {
@@ -242,21 +362,23 @@ where
     async fn connect(
         &self,
         worker_id: &WorkerId,
+        since: Option<WorkerEventCursor>,
         metadata: WorkerRequestMetadata,
         _auth_ctx: &AuthCtx,
     ) -> WorkerResult<ConnectWorkerStream> {
         let stream = self
             .retry_on_invalid_shard_id(
                 worker_id,
-                &(worker_id.clone(), metadata),
-                |worker_executor_client, (worker_id, metadata)| {
+                &(worker_id.clone(), since, metadata),
+                |worker_executor_client, (worker_id, since, metadata)| {
                     Box::pin(async move {
                         let response = match worker_executor_client
-                            .connect_worker(ConnectWorkerRequest {
+                            .connect_worker(with_trace_context(ConnectWorkerRequest {
                                 worker_id: Some(worker_id.clone().into()),
                                 account_id: metadata.account_id.clone().map(|id| id.into()),
                                 account_limits: metadata.limits.clone().map(|id| id.into()),
-                            })
+                                since: since.clone().map(|cursor| cursor.into()),
+                            }))
                             .await
                         {
                             Ok(response) => Ok(response),
@@ -291,9 +413,9 @@ where
             |worker_executor_client, worker_id| {
                 Box::pin(async move {
                     let response = worker_executor_client
-                        .delete_worker(golem_api_grpc::proto::golem::worker::WorkerId::from(
+                        .delete_worker(with_trace_context(golem_api_grpc::proto::golem::worker::WorkerId::from(
                             worker_id.clone(),
-                        ))
+                        )))
                         .await
                         .map_err(|err| {
                             GolemError::RuntimeError(GolemErrorRuntimeError {
@@ -346,6 +468,73 @@ where
         Ok(get_json_from_typed_value(&typed_value))
     }
 
+    async fn broadcast_invoke_and_await_function(
+        &self,
+        component_id: &ComponentId,
+        filter: Option<WorkerFilter>,
+        idempotency_key: Option<IdempotencyKey>,
+        function_name: String,
+        params: Value,
+        calling_convention: &CallingConvention,
+        max_concurrency: usize,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<Vec<BroadcastWorkerResult>> {
+        let idempotency_key = idempotency_key.unwrap_or_else(IdempotencyKey::fresh);
+
+        let mut worker_ids = Vec::new();
+        let mut cursor = Some(0);
+        while let Some(current) = cursor {
+            let (next_cursor, workers) = self
+                .find_metadata(component_id, filter.clone(), current, 50, false, auth_ctx)
+                .await?;
+            worker_ids.extend(workers.into_iter().map(|worker| worker.worker_id));
+            cursor = next_cursor;
+        }
+        debug!(
+            "Broadcasting {function_name} to {} workers of {component_id}",
+            worker_ids.len()
+        );
+
+        let results = stream::iter(worker_ids)
+            .map(|worker_id| {
+                let idempotency_key = idempotency_key.derived(&worker_id.clone().into());
+                let function_name = function_name.clone();
+                let params = params.clone();
+                let metadata = metadata.clone();
+                async move {
+                    let result = self
+                        .invoke_and_await_function(
+                            &worker_id,
+                            Some(idempotency_key),
+                            function_name,
+                            params,
+                            calling_convention,
+                            metadata,
+                            auth_ctx,
+                        )
+                        .await;
+                    match result {
+                        Ok(result) => BroadcastWorkerResult {
+                            worker_id,
+                            result: Some(result),
+                            error: None,
+                        },
+                        Err(error) => BroadcastWorkerResult {
+                            worker_id,
+                            result: None,
+                            error: Some(error.to_string()),
+                        },
+                    }
+                }
+            })
+            .buffered(max_concurrency.clamp(1, self.max_broadcast_concurrency.max(1)))
+            .collect()
+            .await;
+
+        Ok(results)
+    }
+
     async fn invoke_and_await_function_typed_value(
         &self,
         worker_id: &WorkerId,
@@ -384,6 +573,7 @@ where
                 function_name,
                 params_val,
                 calling_convention,
+                vec![],
                 metadata,
                 auth_ctx,
             )
@@ -408,6 +598,7 @@ where
         function_name: String,
         params: Vec<ProtoVal>,
         calling_convention: &CallingConvention,
+        call_chain: Vec<ProtoWorkerId>,
         metadata: WorkerRequestMetadata,
         auth_ctx: &AuthCtx,
     ) -> WorkerResult<ProtoInvokeResult> {
@@ -431,50 +622,140 @@ where
             )
             .map_err(|err| WorkerServiceError::TypeChecker(err.join(", ")))?;
 
-        let invoke_response = self.retry_on_invalid_shard_id(
+        self.invoke_and_await_on_executor(
             worker_id,
-            &(worker_id.clone(), function_name, params_val, idempotency_key.clone(), *calling_convention, metadata),
-            |worker_executor_client, (worker_id, function_name, params_val, idempotency_key, calling_convention, metadata)| {
-                Box::pin(async move {
-                    let response = worker_executor_client.invoke_and_await_worker(
-                        InvokeAndAwaitWorkerRequest {
-                            worker_id: Some(worker_id.clone().into()),
-                            name: function_name.clone(),
-                            input: params_val.clone(),
-                            idempotency_key: idempotency_key.clone(),
-                            calling_convention: (*calling_convention).into(),
-                            account_id: metadata.account_id.clone().map(|id| id.into()),
-                            account_limits: metadata.limits.clone().map(|id| id.into()),
-                        }
-                    ).await.map_err(|err| {
-                        GolemError::RuntimeError(GolemErrorRuntimeError {
-                            details: err.to_string(),
-                        })
-                    })?;
-                    match response.into_inner() {
-                        workerexecutor::InvokeAndAwaitWorkerResponse {
-                            result:
-                            Some(workerexecutor::invoke_and_await_worker_response::Result::Success(
-                                     workerexecutor::InvokeAndAwaitWorkerSuccess {
-                                         output,
-                                     },
-                                 )),
-                        } => Ok(ProtoInvokeResult { result: output }),
-                        workerexecutor::InvokeAndAwaitWorkerResponse {
-                            result:
-                            Some(workerexecutor::invoke_and_await_worker_response::Result::Failure(err)),
-                        } => Err(err.try_into().unwrap()),
-                        workerexecutor::InvokeAndAwaitWorkerResponse { .. } => {
-                            Err(GolemError::Unknown(GolemErrorUnknown {
-                                details: "Empty response".to_string(),
-                            }))
-                        }
-                    }
-                })
-            },
-        ).await?;
+            function_name,
+            params_val,
+            idempotency_key,
+            calling_convention,
+            call_chain,
+            false,
+            metadata,
+        )
+        .await
+    }
 
-        Ok(invoke_response)
+    async fn invoke_and_await_ephemeral_function(
+        &self,
+        component_id: &ComponentId,
+        function_name: String,
+        params: Value,
+        calling_convention: &CallingConvention,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<Value> {
+        let typed_value = self
+            .invoke_and_await_ephemeral_function_typed_value(
+                component_id,
+                function_name,
+                params,
+                calling_convention,
+                metadata,
+                auth_ctx,
+            )
+            .await?;
+
+        Ok(get_json_from_typed_value(&typed_value))
+    }
+
+    async fn invoke_and_await_ephemeral_function_typed_value(
+        &self,
+        component_id: &ComponentId,
+        function_name: String,
+        params: Value,
+        calling_convention: &CallingConvention,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<TypeAnnotatedValue> {
+        let component_details = self
+            .component_service
+            .get_latest(component_id, auth_ctx)
+            .await?;
+
+        let function_type = component_details
+            .metadata
+            .function_by_name(&function_name)
+            .ok_or_else(|| {
+                WorkerServiceError::TypeChecker("Failed to find the function".to_string())
+            })?;
+
+        let params_val = params
+            .validate_function_parameters(
+                function_type
+                    .parameters
+                    .into_iter()
+                    .map(|parameter| parameter.into())
+                    .collect(),
+                *calling_convention,
+            )
+            .map_err(|err| WorkerServiceError::TypeChecker(err.join(", ")))?;
+        let results_val = self
+            .invoke_and_await_on_executor(
+                &Self::ephemeral_worker_id(component_id)?,
+                function_name,
+                params_val,
+                None,
+                calling_convention,
+                vec![],
+                true,
+                metadata,
+            )
+            .await?;
+
+        let function_results: Vec<AnalysedFunctionResult> = function_type
+            .results
+            .iter()
+            .map(|x| x.clone().into())
+            .collect();
+
+        results_val
+            .result
+            .validate_function_result(function_results, *calling_convention)
+            .map_err(|err| WorkerServiceError::TypeChecker(err.join(", ")))
+    }
+
+    async fn invoke_and_await_ephemeral_function_proto(
+        &self,
+        component_id: &ComponentId,
+        function_name: String,
+        params: Vec<ProtoVal>,
+        calling_convention: &CallingConvention,
+        call_chain: Vec<ProtoWorkerId>,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<ProtoInvokeResult> {
+        let component_details = self
+            .component_service
+            .get_latest(component_id, auth_ctx)
+            .await?;
+        let function_type = component_details
+            .metadata
+            .function_by_name(&function_name)
+            .ok_or_else(|| {
+                WorkerServiceError::TypeChecker("Failed to find the function".to_string())
+            })?;
+        let params_val = params
+            .validate_function_parameters(
+                function_type
+                    .parameters
+                    .into_iter()
+                    .map(|parameter| parameter.into())
+                    .collect(),
+                *calling_convention,
+            )
+            .map_err(|err| WorkerServiceError::TypeChecker(err.join(", ")))?;
+
+        self.invoke_and_await_on_executor(
+            &Self::ephemeral_worker_id(component_id)?,
+            function_name,
+            params_val,
+            None,
+            calling_convention,
+            call_chain,
+            true,
+            metadata,
+        )
+        .await
     }
 
     async fn invoke_function(
@@ -560,14 +841,14 @@ where
              (worker_id, function_name, params_val, metadata, idempotency_key)| {
                 Box::pin(async move {
                     let response = worker_executor_client
-                        .invoke_worker(workerexecutor::InvokeWorkerRequest {
+                        .invoke_worker(with_trace_context(workerexecutor::InvokeWorkerRequest {
                             worker_id: Some(worker_id.clone().into()),
                             idempotency_key: idempotency_key.clone(),
                             name: function_name.clone(),
                             input: params_val.clone(),
                             account_id: metadata.account_id.clone().map(|id| id.into()),
                             account_limits: metadata.limits.clone().map(|id| id.into()),
-                        })
+                        }))
                         .await
                         .map_err(|err| {
                             GolemError::RuntimeError(GolemErrorRuntimeError {
@@ -599,6 +880,7 @@ where
         worker_id: &WorkerId,
         oplog_id: u64,
         data: Vec<u8>,
+        failed: bool,
         _auth_ctx: &AuthCtx,
     ) -> WorkerResult<bool> {
         let promise_id = PromiseId {
@@ -609,14 +891,15 @@ where
         let result = self
             .retry_on_invalid_shard_id(
                 worker_id,
-                &(promise_id, data),
-                |worker_executor_client, (promise_id, data)| {
+                &(promise_id, data, failed),
+                |worker_executor_client, (promise_id, data, failed)| {
                     Box::pin(async move {
                         let response = worker_executor_client
-                            .complete_promise(CompletePromiseRequest {
+                            .complete_promise(with_trace_context(CompletePromiseRequest {
                                 promise_id: Some(promise_id.clone().into()),
                                 data: data.clone(),
-                            })
+                                failed: *failed,
+                            }))
                             .await
                             .map_err(|err| {
                                 GolemError::RuntimeError(GolemErrorRuntimeError {
@@ -649,22 +932,353 @@ where
         Ok(result)
     }
 
-    async fn interrupt(
+    async fn get_promises(
+        &self,
+        worker_id: &WorkerId,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<WorkerPromises> {
+        let promises = self
+            .retry_on_invalid_shard_id(
+                worker_id,
+                worker_id,
+                |worker_executor_client, worker_id| {
+                    Box::pin(async move {
+                        let response = worker_executor_client
+                            .get_promises(with_trace_context(GetPromisesRequest {
+                                worker_id: Some(worker_id.clone().into()),
+                            }))
+                            .await
+                            .map_err(|err| {
+                                GolemError::RuntimeError(GolemErrorRuntimeError {
+                                    details: err.to_string(),
+                                })
+                            })?;
+                        match response.into_inner() {
+                            workerexecutor::GetPromisesResponse {
+                                result:
+                                    Some(workerexecutor::get_promises_response::Result::Success(
+                                        success,
+                                    )),
+                            } => success
+                                .promises
+                                .into_iter()
+                                .map(|promise| promise.try_into())
+                                .collect::<Result<Vec<PromiseMetadata>, String>>()
+                                .map_err(|err| {
+                                    GolemError::Unknown(GolemErrorUnknown { details: err })
+                                }),
+                            workerexecutor::GetPromisesResponse {
+                                result:
+                                    Some(workerexecutor::get_promises_response::Result::Failure(
+                                        err,
+                                    )),
+                            } => Err(err.try_into().unwrap()),
+                            workerexecutor::GetPromisesResponse { .. } => {
+                                Err(GolemError::Unknown(GolemErrorUnknown {
+                                    details: "Empty response".to_string(),
+                                }))
+                            }
+                        }
+                    })
+                },
+            )
+            .await?;
+        Ok(WorkerPromises { promises })
+    }
+
+    async fn send_mailbox_message(
+        &self,
+        worker_id: &WorkerId,
+        idempotency_key: Option<IdempotencyKey>,
+        payload: Vec<u8>,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()> {
+        self.retry_on_invalid_shard_id(
+            worker_id,
+            &(worker_id.clone(), idempotency_key, payload),
+            |worker_executor_client, (worker_id, idempotency_key, payload)| {
+                Box::pin(async move {
+                    let response = worker_executor_client
+                        .send_mailbox_message(with_trace_context(SendMailboxMessageRequest {
+                            worker_id: Some(worker_id.clone().into()),
+                            payload: payload.clone(),
+                            idempotency_key: idempotency_key.clone().map(|key| key.into()),
+                        }))
+                        .await
+                        .map_err(|err| {
+                            GolemError::RuntimeError(GolemErrorRuntimeError {
+                                details: err.to_string(),
+                            })
+                        })?;
+                    match response.into_inner() {
+                        workerexecutor::SendMailboxMessageResponse {
+                            result:
+                                Some(workerexecutor::send_mailbox_message_response::Result::Success(
+                                    _,
+                                )),
+                        } => Ok(()),
+                        workerexecutor::SendMailboxMessageResponse {
+                            result:
+                                Some(workerexecutor::send_mailbox_message_response::Result::Failure(
+                                    err,
+                                )),
+                        } => Err(err.try_into().unwrap()),
+                        workerexecutor::SendMailboxMessageResponse { .. } => {
+                            Err(GolemError::Unknown(GolemErrorUnknown {
+                                details: "Empty response".to_string(),
+                            }))
+                        }
+                    }
+                })
+            },
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn interrupt(
+        &self,
+        worker_id: &WorkerId,
+        recover_immediately: bool,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()> {
+        self.retry_on_invalid_shard_id(
+            worker_id,
+            worker_id,
+            |worker_executor_client, worker_id| {
+                Box::pin(async move {
+                    let response = worker_executor_client
+                        .interrupt_worker(with_trace_context(InterruptWorkerRequest {
+                            worker_id: Some(worker_id.clone().into()),
+                            recover_immediately,
+                        }))
+                        .await
+                        .map_err(|err| {
+                            GolemError::RuntimeError(GolemErrorRuntimeError {
+                                details: err.to_string(),
+                            })
+                        })?;
+                    match response.into_inner() {
+                        workerexecutor::InterruptWorkerResponse {
+                            result: Some(workerexecutor::interrupt_worker_response::Result::Success(_)),
+                        } => Ok(()),
+                        workerexecutor::InterruptWorkerResponse {
+                            result: Some(workerexecutor::interrupt_worker_response::Result::Failure(err)),
+                        } => Err(err.try_into().unwrap()),
+                        workerexecutor::InterruptWorkerResponse { .. } => {
+                            Err(GolemError::Unknown(GolemErrorUnknown {
+                                details: "Empty response".to_string(),
+                            }))
+                        }
+                    }
+                })
+            },
+        ).await?;
+
+        Ok(())
+    }
+
+    async fn get_metadata(
+        &self,
+        worker_id: &WorkerId,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<WorkerMetadata> {
+        let metadata = self.retry_on_invalid_shard_id(
+            worker_id,
+            worker_id,
+            |worker_executor_client, worker_id| {
+                Box::pin(async move {
+                    let response = worker_executor_client.get_worker_metadata(with_trace_context(
+                        golem_api_grpc::proto::golem::worker::WorkerId::from(worker_id.clone())
+                    )).await.map_err(|err| {
+                        GolemError::RuntimeError(GolemErrorRuntimeError {
+                            details: err.to_string(),
+                        })
+                    })?;
+                    match response.into_inner() {
+                        workerexecutor::GetWorkerMetadataResponse {
+                            result:
+                            Some(workerexecutor::get_worker_metadata_response::Result::Success(metadata)),
+                        } => Ok(metadata.try_into().unwrap()),
+                        workerexecutor::GetWorkerMetadataResponse {
+                            result:
+                            Some(workerexecutor::get_worker_metadata_response::Result::Failure(err)),
+                        } => Err(err.try_into().unwrap()),
+                        workerexecutor::GetWorkerMetadataResponse { .. } => {
+                            Err(GolemError::Unknown(GolemErrorUnknown {
+                                details: "Empty response".to_string(),
+                            }))
+                        }
+                    }
+                })
+            },
+        ).await?;
+
+        Ok(metadata)
+    }
+
+    async fn find_metadata(
+        &self,
+        component_id: &ComponentId,
+        filter: Option<WorkerFilter>,
+        cursor: u64,
+        count: u64,
+        precise: bool,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<(Option<u64>, Vec<WorkerMetadata>)> {
+        if filter.clone().is_some_and(is_filter_with_running_status) {
+            let result = self
+                .find_running_metadata_internal(component_id, filter, auth_ctx)
+                .await?;
+
+            Ok((None, result.into_iter().take(count as usize).collect()))
+        } else {
+            self.find_metadata_internal(component_id, filter, cursor, count, precise, auth_ctx)
+                .await
+        }
+    }
+
+    async fn resume(&self, worker_id: &WorkerId, _auth_ctx: &AuthCtx) -> WorkerResult<()> {
+        self.retry_on_invalid_shard_id(
+            worker_id,
+            worker_id,
+            |worker_executor_client, worker_id| {
+                Box::pin(async move {
+                    let response = worker_executor_client
+                        .resume_worker(with_trace_context(ResumeWorkerRequest {
+                            worker_id: Some(worker_id.clone().into()),
+                        }))
+                        .await
+                        .map_err(|err| {
+                            GolemError::RuntimeError(GolemErrorRuntimeError {
+                                details: err.to_string(),
+                            })
+                        })?;
+                    match response.into_inner() {
+                        workerexecutor::ResumeWorkerResponse {
+                            result: Some(workerexecutor::resume_worker_response::Result::Success(_)),
+                        } => Ok(()),
+                        workerexecutor::ResumeWorkerResponse {
+                            result: Some(workerexecutor::resume_worker_response::Result::Failure(err)),
+                        } => Err(err.try_into().unwrap()),
+                        workerexecutor::ResumeWorkerResponse { .. } => {
+                            Err(GolemError::Unknown(GolemErrorUnknown {
+                                details: "Empty response".to_string(),
+                            }))
+                        }
+                    }
+                })
+            },
+        )
+            .await?;
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        worker_id: &WorkerId,
+        update_mode: UpdateMode,
+        target_version: ComponentVersion,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()> {
+        self.retry_on_invalid_shard_id(
+            worker_id,
+            worker_id,
+            |worker_executor_client, worker_id| {
+                Box::pin(async move {
+                    let response = worker_executor_client
+                        .update_worker(with_trace_context(UpdateWorkerRequest {
+                            worker_id: Some(worker_id.clone().into()),
+                            mode: update_mode.into(),
+                            target_version,
+                        }))
+                        .await
+                        .map_err(|err| {
+                            GolemError::RuntimeError(GolemErrorRuntimeError {
+                                details: err.to_string(),
+                            })
+                        })?;
+                    match response.into_inner() {
+                        workerexecutor::UpdateWorkerResponse {
+                            result: Some(workerexecutor::update_worker_response::Result::Success(_)),
+                        } => Ok(()),
+                        workerexecutor::UpdateWorkerResponse {
+                            result: Some(workerexecutor::update_worker_response::Result::Failure(err)),
+                        } => Err(err.try_into().unwrap()),
+                        workerexecutor::UpdateWorkerResponse { .. } => {
+                            Err(GolemError::Unknown(GolemErrorUnknown {
+                                details: "Empty response".to_string(),
+                            }))
+                        }
+                    }
+                })
+            },
+        )
+            .await?;
+        Ok(())
+    }
+
+    async fn get_key_value_buckets(
+        &self,
+        account_id: &AccountId,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<Vec<String>> {
+        let buckets = self.execute_with_random_client(
+            account_id,
+            |worker_executor_client, account_id| {
+                Box::pin(async move {
+                    let response = worker_executor_client
+                        .get_key_value_buckets(with_trace_context(GetKeyValueBucketsRequest {
+                            account_id: Some(account_id.clone().into()),
+                        }))
+                        .await
+                        .map_err(|err| {
+                            GolemError::RuntimeError(GolemErrorRuntimeError {
+                                details: err.to_string(),
+                            })
+                        })?;
+                    match response.into_inner() {
+                        workerexecutor::GetKeyValueBucketsResponse {
+                            result: Some(workerexecutor::get_key_value_buckets_response::Result::Success(success)),
+                        } => Ok(success.buckets),
+                        workerexecutor::GetKeyValueBucketsResponse {
+                            result: Some(workerexecutor::get_key_value_buckets_response::Result::Failure(err)),
+                        } => Err(err.try_into().unwrap()),
+                        workerexecutor::GetKeyValueBucketsResponse { .. } => {
+                            Err(GolemError::Unknown(GolemErrorUnknown {
+                                details: "Empty response".to_string(),
+                            }))
+                        }
+                    }
+                })
+            },
+        ).await?;
+
+        Ok(buckets)
+    }
+
+    async fn get_key_value_keys(
         &self,
-        worker_id: &WorkerId,
-        recover_immediately: bool,
+        account_id: &AccountId,
+        bucket: &str,
+        prefix: Option<String>,
+        cursor: Option<String>,
+        count: u64,
         _auth_ctx: &AuthCtx,
-    ) -> WorkerResult<()> {
-        self.retry_on_invalid_shard_id(
-            worker_id,
-            worker_id,
-            |worker_executor_client, worker_id| {
+    ) -> WorkerResult<KeyValueKeys> {
+        let keys = self.execute_with_random_client(
+            &(account_id.clone(), bucket.to_string(), prefix, cursor, count),
+            |worker_executor_client, (account_id, bucket, prefix, cursor, count)| {
                 Box::pin(async move {
                     let response = worker_executor_client
-                        .interrupt_worker(InterruptWorkerRequest {
-                            worker_id: Some(worker_id.clone().into()),
-                            recover_immediately,
-                        })
+                        .get_key_value_keys(with_trace_context(GetKeyValueKeysRequest {
+                            account_id: Some(account_id.clone().into()),
+                            bucket: bucket.clone(),
+                            prefix: prefix.clone(),
+                            cursor: cursor.clone(),
+                            count: *count,
+                        }))
                         .await
                         .map_err(|err| {
                             GolemError::RuntimeError(GolemErrorRuntimeError {
@@ -672,13 +1286,16 @@ where
                             })
                         })?;
                     match response.into_inner() {
-                        workerexecutor::InterruptWorkerResponse {
-                            result: Some(workerexecutor::interrupt_worker_response::Result::Success(_)),
-                        } => Ok(()),
-                        workerexecutor::InterruptWorkerResponse {
-                            result: Some(workerexecutor::interrupt_worker_response::Result::Failure(err)),
+                        workerexecutor::GetKeyValueKeysResponse {
+                            result: Some(workerexecutor::get_key_value_keys_response::Result::Success(success)),
+                        } => Ok(KeyValueKeys {
+                            keys: success.keys,
+                            cursor: success.cursor,
+                        }),
+                        workerexecutor::GetKeyValueKeysResponse {
+                            result: Some(workerexecutor::get_key_value_keys_response::Result::Failure(err)),
                         } => Err(err.try_into().unwrap()),
-                        workerexecutor::InterruptWorkerResponse { .. } => {
+                        workerexecutor::GetKeyValueKeysResponse { .. } => {
                             Err(GolemError::Unknown(GolemErrorUnknown {
                                 details: "Empty response".to_string(),
                             }))
@@ -688,36 +1305,40 @@ where
             },
         ).await?;
 
-        Ok(())
+        Ok(keys)
     }
 
-    async fn get_metadata(
+    async fn get_key_value(
         &self,
-        worker_id: &WorkerId,
+        account_id: &AccountId,
+        bucket: &str,
+        key: &str,
         _auth_ctx: &AuthCtx,
-    ) -> WorkerResult<WorkerMetadata> {
-        let metadata = self.retry_on_invalid_shard_id(
-            worker_id,
-            worker_id,
-            |worker_executor_client, worker_id| {
+    ) -> WorkerResult<Option<Vec<u8>>> {
+        let value = self.execute_with_random_client(
+            &(account_id.clone(), bucket.to_string(), key.to_string()),
+            |worker_executor_client, (account_id, bucket, key)| {
                 Box::pin(async move {
-                    let response = worker_executor_client.get_worker_metadata(
-                        golem_api_grpc::proto::golem::worker::WorkerId::from(worker_id.clone())
-                    ).await.map_err(|err| {
-                        GolemError::RuntimeError(GolemErrorRuntimeError {
-                            details: err.to_string(),
-                        })
-                    })?;
+                    let response = worker_executor_client
+                        .get_key_value(with_trace_context(GetKeyValueRequest {
+                            account_id: Some(account_id.clone().into()),
+                            bucket: bucket.clone(),
+                            key: key.clone(),
+                        }))
+                        .await
+                        .map_err(|err| {
+                            GolemError::RuntimeError(GolemErrorRuntimeError {
+                                details: err.to_string(),
+                            })
+                        })?;
                     match response.into_inner() {
-                        workerexecutor::GetWorkerMetadataResponse {
-                            result:
-                            Some(workerexecutor::get_worker_metadata_response::Result::Success(metadata)),
-                        } => Ok(metadata.try_into().unwrap()),
-                        workerexecutor::GetWorkerMetadataResponse {
-                            result:
-                            Some(workerexecutor::get_worker_metadata_response::Result::Failure(err)),
+                        workerexecutor::GetKeyValueResponse {
+                            result: Some(workerexecutor::get_key_value_response::Result::Success(success)),
+                        } => Ok(success.value),
+                        workerexecutor::GetKeyValueResponse {
+                            result: Some(workerexecutor::get_key_value_response::Result::Failure(err)),
                         } => Err(err.try_into().unwrap()),
-                        workerexecutor::GetWorkerMetadataResponse { .. } => {
+                        workerexecutor::GetKeyValueResponse { .. } => {
                             Err(GolemError::Unknown(GolemErrorUnknown {
                                 details: "Empty response".to_string(),
                             }))
@@ -727,40 +1348,29 @@ where
             },
         ).await?;
 
-        Ok(metadata)
+        Ok(value)
     }
 
-    async fn find_metadata(
+    async fn set_key_value(
         &self,
-        component_id: &ComponentId,
-        filter: Option<WorkerFilter>,
-        cursor: u64,
-        count: u64,
-        precise: bool,
-        auth_ctx: &AuthCtx,
-    ) -> WorkerResult<(Option<u64>, Vec<WorkerMetadata>)> {
-        if filter.clone().is_some_and(is_filter_with_running_status) {
-            let result = self
-                .find_running_metadata_internal(component_id, filter, auth_ctx)
-                .await?;
-
-            Ok((None, result.into_iter().take(count as usize).collect()))
-        } else {
-            self.find_metadata_internal(component_id, filter, cursor, count, precise, auth_ctx)
-                .await
-        }
-    }
-
-    async fn resume(&self, worker_id: &WorkerId, _auth_ctx: &AuthCtx) -> WorkerResult<()> {
-        self.retry_on_invalid_shard_id(
-            worker_id,
-            worker_id,
-            |worker_executor_client, worker_id| {
+        account_id: &AccountId,
+        bucket: &str,
+        key: &str,
+        value: Vec<u8>,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()> {
+        let size = value.len();
+        self.execute_with_random_client(
+            &(account_id.clone(), bucket.to_string(), key.to_string(), value),
+            |worker_executor_client, (account_id, bucket, key, value)| {
                 Box::pin(async move {
                     let response = worker_executor_client
-                        .resume_worker(ResumeWorkerRequest {
-                            worker_id: Some(worker_id.clone().into()),
-                        })
+                        .set_key_value(with_trace_context(SetKeyValueRequest {
+                            account_id: Some(account_id.clone().into()),
+                            bucket: bucket.clone(),
+                            key: key.clone(),
+                            value: value.clone(),
+                        }))
                         .await
                         .map_err(|err| {
                             GolemError::RuntimeError(GolemErrorRuntimeError {
@@ -768,13 +1378,13 @@ where
                             })
                         })?;
                     match response.into_inner() {
-                        workerexecutor::ResumeWorkerResponse {
-                            result: Some(workerexecutor::resume_worker_response::Result::Success(_)),
+                        workerexecutor::SetKeyValueResponse {
+                            result: Some(workerexecutor::set_key_value_response::Result::Success(_)),
                         } => Ok(()),
-                        workerexecutor::ResumeWorkerResponse {
-                            result: Some(workerexecutor::resume_worker_response::Result::Failure(err)),
+                        workerexecutor::SetKeyValueResponse {
+                            result: Some(workerexecutor::set_key_value_response::Result::Failure(err)),
                         } => Err(err.try_into().unwrap()),
-                        workerexecutor::ResumeWorkerResponse { .. } => {
+                        workerexecutor::SetKeyValueResponse { .. } => {
                             Err(GolemError::Unknown(GolemErrorUnknown {
                                 details: "Empty response".to_string(),
                             }))
@@ -782,29 +1392,35 @@ where
                     }
                 })
             },
-        )
-            .await?;
+        ).await?;
+
+        info!(
+            account_id = %account_id,
+            bucket,
+            key,
+            size,
+            "Key-value entry set through the operator API"
+        );
         Ok(())
     }
 
-    async fn update(
+    async fn delete_key_value(
         &self,
-        worker_id: &WorkerId,
-        update_mode: UpdateMode,
-        target_version: ComponentVersion,
+        account_id: &AccountId,
+        bucket: &str,
+        key: &str,
         _auth_ctx: &AuthCtx,
     ) -> WorkerResult<()> {
-        self.retry_on_invalid_shard_id(
-            worker_id,
-            worker_id,
-            |worker_executor_client, worker_id| {
+        self.execute_with_random_client(
+            &(account_id.clone(), bucket.to_string(), key.to_string()),
+            |worker_executor_client, (account_id, bucket, key)| {
                 Box::pin(async move {
                     let response = worker_executor_client
-                        .update_worker(UpdateWorkerRequest {
-                            worker_id: Some(worker_id.clone().into()),
-                            mode: update_mode.into(),
-                            target_version,
-                        })
+                        .delete_key_value(with_trace_context(DeleteKeyValueRequest {
+                            account_id: Some(account_id.clone().into()),
+                            bucket: bucket.clone(),
+                            key: key.clone(),
+                        }))
                         .await
                         .map_err(|err| {
                             GolemError::RuntimeError(GolemErrorRuntimeError {
@@ -812,13 +1428,13 @@ where
                             })
                         })?;
                     match response.into_inner() {
-                        workerexecutor::UpdateWorkerResponse {
-                            result: Some(workerexecutor::update_worker_response::Result::Success(_)),
+                        workerexecutor::DeleteKeyValueResponse {
+                            result: Some(workerexecutor::delete_key_value_response::Result::Success(_)),
                         } => Ok(()),
-                        workerexecutor::UpdateWorkerResponse {
-                            result: Some(workerexecutor::update_worker_response::Result::Failure(err)),
+                        workerexecutor::DeleteKeyValueResponse {
+                            result: Some(workerexecutor::delete_key_value_response::Result::Failure(err)),
                         } => Err(err.try_into().unwrap()),
-                        workerexecutor::UpdateWorkerResponse { .. } => {
+                        workerexecutor::DeleteKeyValueResponse { .. } => {
                             Err(GolemError::Unknown(GolemErrorUnknown {
                                 details: "Empty response".to_string(),
                             }))
@@ -826,8 +1442,14 @@ where
                     }
                 })
             },
-        )
-            .await?;
+        ).await?;
+
+        info!(
+            account_id = %account_id,
+            bucket,
+            key,
+            "Key-value entry deleted through the operator API"
+        );
         Ok(())
     }
 }
@@ -863,6 +1485,76 @@ where
         }
     }
 
+    /// Invokes a function on the worker executor owning the worker, retrying when the routing
+    /// table is outdated. Parameters are expected to be validated by the caller.
+    async fn invoke_and_await_on_executor(
+        &self,
+        worker_id: &WorkerId,
+        function_name: String,
+        params_val: Vec<ProtoVal>,
+        idempotency_key: Option<ProtoIdempotencyKey>,
+        calling_convention: &CallingConvention,
+        call_chain: Vec<ProtoWorkerId>,
+        ephemeral: bool,
+        metadata: WorkerRequestMetadata,
+    ) -> WorkerResult<ProtoInvokeResult> {
+        let invoke_response = self.retry_on_invalid_shard_id(
+            worker_id,
+            &(worker_id.clone(), function_name, params_val, idempotency_key, *calling_convention, call_chain, ephemeral, metadata),
+            |worker_executor_client, (worker_id, function_name, params_val, idempotency_key, calling_convention, call_chain, ephemeral, metadata)| {
+                Box::pin(async move {
+                    let response = worker_executor_client.invoke_and_await_worker(with_trace_context(
+                        InvokeAndAwaitWorkerRequest {
+                            worker_id: Some(worker_id.clone().into()),
+                            name: function_name.clone(),
+                            input: params_val.clone(),
+                            idempotency_key: idempotency_key.clone(),
+                            calling_convention: (*calling_convention).into(),
+                            account_id: metadata.account_id.clone().map(|id| id.into()),
+                            account_limits: metadata.limits.clone().map(|id| id.into()),
+                            call_chain: call_chain.clone(),
+                            ephemeral: *ephemeral,
+                        }
+                    )).await.map_err(|err| {
+                        GolemError::RuntimeError(GolemErrorRuntimeError {
+                            details: err.to_string(),
+                        })
+                    })?;
+                    match response.into_inner() {
+                        workerexecutor::InvokeAndAwaitWorkerResponse {
+                            result:
+                            Some(workerexecutor::invoke_and_await_worker_response::Result::Success(
+                                     workerexecutor::InvokeAndAwaitWorkerSuccess {
+                                         output,
+                                     },
+                                 )),
+                        } => Ok(ProtoInvokeResult { result: output }),
+                        workerexecutor::InvokeAndAwaitWorkerResponse {
+                            result:
+                            Some(workerexecutor::invoke_and_await_worker_response::Result::Failure(err)),
+                        } => Err(err.try_into().unwrap()),
+                        workerexecutor::InvokeAndAwaitWorkerResponse { .. } => {
+                            Err(GolemError::Unknown(GolemErrorUnknown {
+                                details: "Empty response".to_string(),
+                            }))
+                        }
+                    }
+                })
+            },
+        ).await?;
+
+        Ok(invoke_response)
+    }
+
+    /// Generates the id of a new ephemeral worker; the worker name is never reused
+    fn ephemeral_worker_id(component_id: &ComponentId) -> WorkerResult<WorkerId> {
+        WorkerId::new(
+            component_id.clone(),
+            format!("ephemeral-{}", Uuid::new_v4()),
+        )
+        .map_err(|err| WorkerServiceError::Internal(anyhow::anyhow!(err)))
+    }
+
     async fn get_worker_executor_client(
         &self,
         worker_id: &WorkerId,
@@ -1159,12 +1851,12 @@ where
                 Box::pin(async move {
                     let component_id: golem_api_grpc::proto::golem::component::ComponentId =
                         component_id.clone().into();
-                    let response = worker_executor_client.get_running_workers_metadata(
+                    let response = worker_executor_client.get_running_workers_metadata(with_trace_context(
                         golem_api_grpc::proto::golem::workerexecutor::GetRunningWorkersMetadataRequest {
                             component_id: Some(component_id),
                             filter: filter.clone().map(|f| f.into())
                         }
-                    ).await.map_err(|err| {
+                    )).await.map_err(|err| {
                         GolemError::RuntimeError(GolemErrorRuntimeError {
                             details: err.to_string(),
                         })
@@ -1213,7 +1905,7 @@ where
                 Box::pin(async move {
                     let component_id: golem_api_grpc::proto::golem::component::ComponentId =
                         component_id.clone().into();
-                    let response = worker_executor_client.get_workers_metadata(
+                    let response = worker_executor_client.get_workers_metadata(with_trace_context(
                         golem_api_grpc::proto::golem::workerexecutor::GetWorkersMetadataRequest {
                             component_id: Some(component_id),
                             filter: filter.clone().map(|f| f.into()),
@@ -1221,7 +1913,7 @@ where
                             count: *count,
                             precise: *precise,
                         }
-                    ).await.map_err(|err| {
+                    )).await.map_err(|err| {
                         GolemError::RuntimeError(GolemErrorRuntimeError {
                             details: err.to_string(),
                         })
@@ -1312,6 +2004,7 @@ where
     async fn connect(
         &self,
         _worker_id: &WorkerId,
+        _since: Option<WorkerEventCursor>,
         _metadata: WorkerRequestMetadata,
         _auth_ctx: &AuthCtx,
     ) -> WorkerResult<ConnectWorkerStream> {
@@ -1337,6 +2030,21 @@ where
         Ok(Value::default())
     }
 
+    async fn broadcast_invoke_and_await_function(
+        &self,
+        _component_id: &ComponentId,
+        _filter: Option<WorkerFilter>,
+        _idempotency_key: Option<IdempotencyKey>,
+        _function_name: String,
+        _params: Value,
+        _calling_convention: &CallingConvention,
+        _max_concurrency: usize,
+        _metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<Vec<BroadcastWorkerResult>> {
+        Ok(vec![])
+    }
+
     async fn invoke_and_await_function_typed_value(
         &self,
         _worker_id: &WorkerId,
@@ -1360,6 +2068,47 @@ where
         _function_name: String,
         _params: Vec<ProtoVal>,
         _calling_convention: &CallingConvention,
+        _call_chain: Vec<ProtoWorkerId>,
+        _metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<ProtoInvokeResult> {
+        Ok(ProtoInvokeResult::default())
+    }
+
+    async fn invoke_and_await_ephemeral_function(
+        &self,
+        _component_id: &ComponentId,
+        _function_name: String,
+        _params: Value,
+        _calling_convention: &CallingConvention,
+        _metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<Value> {
+        Ok(Value::default())
+    }
+
+    async fn invoke_and_await_ephemeral_function_typed_value(
+        &self,
+        _component_id: &ComponentId,
+        _function_name: String,
+        _params: Value,
+        _calling_convention: &CallingConvention,
+        _metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<TypeAnnotatedValue> {
+        Ok(TypeAnnotatedValue::Tuple {
+            value: vec![],
+            typ: vec![],
+        })
+    }
+
+    async fn invoke_and_await_ephemeral_function_proto(
+        &self,
+        _component_id: &ComponentId,
+        _function_name: String,
+        _params: Vec<ProtoVal>,
+        _calling_convention: &CallingConvention,
+        _call_chain: Vec<ProtoWorkerId>,
         _metadata: WorkerRequestMetadata,
         _auth_ctx: &AuthCtx,
     ) -> WorkerResult<ProtoInvokeResult> {
@@ -1395,11 +2144,30 @@ where
         _worker_id: &WorkerId,
         _oplog_id: u64,
         _data: Vec<u8>,
+        _failed: bool,
         _auth_ctx: &AuthCtx,
     ) -> WorkerResult<bool> {
         Ok(true)
     }
 
+    async fn get_promises(
+        &self,
+        _worker_id: &WorkerId,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<WorkerPromises> {
+        Ok(WorkerPromises { promises: vec![] })
+    }
+
+    async fn send_mailbox_message(
+        &self,
+        _worker_id: &WorkerId,
+        _idempotency_key: Option<IdempotencyKey>,
+        _payload: Vec<u8>,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()> {
+        Ok(())
+    }
+
     async fn interrupt(
         &self,
         _worker_id: &WorkerId,
@@ -1425,6 +2193,7 @@ where
             updates: vec![],
             created_at: Timestamp::now_utc(),
             last_error: None,
+            awaited_promise_oplog_idx: None,
         })
     }
 
@@ -1453,4 +2222,58 @@ where
     ) -> WorkerResult<()> {
         Ok(())
     }
+
+    async fn get_key_value_buckets(
+        &self,
+        _account_id: &AccountId,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<Vec<String>> {
+        Ok(vec![])
+    }
+
+    async fn get_key_value_keys(
+        &self,
+        _account_id: &AccountId,
+        _bucket: &str,
+        _prefix: Option<String>,
+        _cursor: Option<String>,
+        _count: u64,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<KeyValueKeys> {
+        Ok(KeyValueKeys {
+            keys: vec![],
+            cursor: None,
+        })
+    }
+
+    async fn get_key_value(
+        &self,
+        _account_id: &AccountId,
+        _bucket: &str,
+        _key: &str,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<Option<Vec<u8>>> {
+        Ok(None)
+    }
+
+    async fn set_key_value(
+        &self,
+        _account_id: &AccountId,
+        _bucket: &str,
+        _key: &str,
+        _value: Vec<u8>,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()> {
+        Ok(())
+    }
+
+    async fn delete_key_value(
+        &self,
+        _account_id: &AccountId,
+        _bucket: &str,
+        _key: &str,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()> {
+        Ok(())
+    }
 }
@@ -1,4 +1,5 @@
 use bincode::{Decode, Encode};
+use poem_openapi::Enum;
 use serde::{Deserialize, Serialize};
 
 use golem_common::model::ComponentId;
@@ -8,12 +9,81 @@ use crate::expression::Expr;
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Encode, Decode)]
 #[serde(rename_all = "camelCase")]
 pub struct GolemWorkerBinding {
+    #[serde(default, rename = "type")]
+    pub binding_type: BindingType,
     pub component: ComponentId,
     pub worker_id: Expr,
+    #[serde(default)]
     pub function_name: String,
+    #[serde(default)]
     pub function_params: Vec<Expr>,
     pub idempotency_key: Option<Expr>,
     pub response: Option<ResponseMapping>,
+    #[serde(default)]
+    pub promise: Option<PromiseBinding>,
+}
+
+// The encoding of worker bindings stored before they got a binding type and a promise
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub struct GolemWorkerBindingV1 {
+    pub component: ComponentId,
+    pub worker_id: Expr,
+    pub function_name: String,
+    pub function_params: Vec<Expr>,
+    pub idempotency_key: Option<Expr>,
+    pub response: Option<ResponseMapping>,
+}
+
+impl From<GolemWorkerBindingV1> for GolemWorkerBinding {
+    fn from(value: GolemWorkerBindingV1) -> Self {
+        GolemWorkerBinding {
+            binding_type: BindingType::WitWorker,
+            component: value.component,
+            worker_id: value.worker_id,
+            function_name: value.function_name,
+            function_params: value.function_params,
+            idempotency_key: value.idempotency_key,
+            response: value.response,
+            promise: None,
+        }
+    }
+}
+
+// What a route does with the worker it is bound to
+#[derive(
+    Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize, Encode, Decode, Enum,
+)]
+#[serde(rename_all = "kebab-case")]
+#[oai(rename_all = "kebab-case")]
+pub enum BindingType {
+    // Invokes a function of the worker
+    #[default]
+    WitWorker,
+    // Completes a promise created by the worker, so that an external callback can resume it
+    CompletePromise,
+    // Invokes a function on a fresh ephemeral worker of the component, without durable state
+    EphemeralWorker,
+}
+
+impl TryFrom<i32> for BindingType {
+    type Error = &'static str;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(BindingType::WitWorker),
+            1 => Ok(BindingType::CompletePromise),
+            2 => Ok(BindingType::EphemeralWorker),
+            _ => Err("Failed to parse BindingType"),
+        }
+    }
+}
+
+// The promise completed by a `complete-promise` binding, both computed from the request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub struct PromiseBinding {
+    pub oplog_idx: Expr,
+    pub payload: Expr,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Encode, Decode)]
@@ -5,7 +5,8 @@ use serde_json::Value;
 use golem_common::model::ComponentId;
 
 use crate::evaluator::{Evaluator, RawString};
-use crate::worker_binding::ResolvedWorkerBinding;
+use crate::primitive::{GetPrimitive, Primitive};
+use crate::worker_binding::{BindingType, ResolvedWorkerBinding};
 
 mod worker_request_executor;
 mod worker_response;
@@ -18,9 +19,20 @@ pub use worker_response::*;
 #[derive(PartialEq, Debug, Clone)]
 pub struct WorkerRequest {
     pub component: ComponentId,
+    // Empty for ephemeral requests, which get a fresh worker each time
     pub worker_id: String,
     pub function: String,
     pub function_params: Value,
+    pub ephemeral: bool,
+}
+
+// A promise completion request is formed from routes with a `complete-promise` binding
+#[derive(PartialEq, Debug, Clone)]
+pub struct PromiseCompletionRequest {
+    pub component: ComponentId,
+    pub worker_id: String,
+    pub oplog_idx: u64,
+    pub payload: Vec<u8>,
 }
 
 impl WorkerRequest {
@@ -28,20 +40,13 @@ impl WorkerRequest {
     pub fn from_resolved_route(
         resolved_route: ResolvedWorkerBinding,
     ) -> Result<WorkerRequest, String> {
-        let worker_id_value: TypeAnnotatedValue = resolved_route
-            .resolved_worker_binding_template
-            .worker_id
-            .evaluate(&resolved_route.typed_value_from_input)
-            .map_err(|err| err.to_string())?;
+        let ephemeral = resolved_route.resolved_worker_binding_template.binding_type
+            == BindingType::EphemeralWorker;
 
-        let worker_id = match worker_id_value {
-            TypeAnnotatedValue::Str(value) => value,
-            _ => {
-                return Err(format!(
-                    "Worker id is not a string. {}",
-                    get_json_from_typed_value(&worker_id_value)
-                ))
-            }
+        let worker_id = if ephemeral {
+            String::new()
+        } else {
+            resolve_worker_id(&resolved_route)?
         };
 
         let function_name_value = RawString::new(
@@ -85,6 +90,74 @@ impl WorkerRequest {
                 .clone(),
             function: function_name,
             function_params: Value::Array(function_params),
+            ephemeral,
         })
     }
 }
+
+impl PromiseCompletionRequest {
+    pub fn from_resolved_route(
+        resolved_route: ResolvedWorkerBinding,
+    ) -> Result<PromiseCompletionRequest, String> {
+        let worker_id = resolve_worker_id(&resolved_route)?;
+
+        let promise = resolved_route
+            .resolved_worker_binding_template
+            .promise
+            .as_ref()
+            .ok_or("Missing promise in complete-promise binding".to_string())?;
+
+        let oplog_idx_value = promise
+            .oplog_idx
+            .evaluate(&resolved_route.typed_value_from_input)
+            .map_err(|err| err.to_string())?;
+
+        let oplog_idx = oplog_idx_value
+            .get_primitive()
+            .and_then(|primitive| match primitive {
+                Primitive::Num(number) => number.to_string().parse::<u64>().ok(),
+                Primitive::String(value) => value.parse::<u64>().ok(),
+                Primitive::Bool(_) => None,
+            })
+            .ok_or(format!(
+                "Promise oplog index is not an unsigned integer. {}",
+                get_json_from_typed_value(&oplog_idx_value)
+            ))?;
+
+        // Strings are passed as they are, any other value is completed with its JSON representation
+        let payload = match promise
+            .payload
+            .evaluate(&resolved_route.typed_value_from_input)
+            .map_err(|err| err.to_string())?
+        {
+            TypeAnnotatedValue::Str(value) => value.into_bytes(),
+            value => get_json_from_typed_value(&value).to_string().into_bytes(),
+        };
+
+        Ok(PromiseCompletionRequest {
+            component: resolved_route
+                .resolved_worker_binding_template
+                .component
+                .clone(),
+            worker_id,
+            oplog_idx,
+            payload,
+        })
+    }
+}
+
+fn resolve_worker_id(resolved_route: &ResolvedWorkerBinding) -> Result<String, String> {
+    let worker_id_value: TypeAnnotatedValue = resolved_route
+        .resolved_worker_binding_template
+        .worker_id
+        .evaluate(&resolved_route.typed_value_from_input)
+        .map_err(|err| err.to_string())?;
+
+    match worker_id_value {
+        TypeAnnotatedValue::Str(value) => Ok(value),
+        _ => Err(format!(
+            "Worker id is not a string. {}",
+            get_json_from_typed_value(&worker_id_value)
+        )),
+    }
+}
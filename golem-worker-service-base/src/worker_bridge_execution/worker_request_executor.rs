@@ -1,5 +1,5 @@
 use crate::worker_bridge_execution::worker_response::WorkerResponse;
-use crate::worker_bridge_execution::WorkerRequest;
+use crate::worker_bridge_execution::{PromiseCompletionRequest, WorkerRequest};
 use async_trait::async_trait;
 use std::fmt::Display;
 
@@ -9,6 +9,12 @@ pub trait WorkerRequestExecutor<Response> {
         &self,
         resolved_worker_request: WorkerRequest,
     ) -> Result<WorkerResponse, WorkerRequestExecutorError>;
+
+    // The response holds whether the promise was completed by this request
+    async fn complete_promise(
+        &self,
+        promise_completion_request: PromiseCompletionRequest,
+    ) -> Result<WorkerResponse, WorkerRequestExecutorError>;
 }
 
 #[derive(Clone, Debug)]
@@ -14,7 +14,7 @@ use crate::worker_binding::ResponseMapping;
 use crate::worker_bridge_execution::worker_request_executor::{
     WorkerRequestExecutor, WorkerRequestExecutorError,
 };
-use crate::worker_bridge_execution::WorkerRequest;
+use crate::worker_bridge_execution::{PromiseCompletionRequest, WorkerRequest};
 
 pub struct WorkerResponse {
     pub result: TypeAnnotatedValue,
@@ -115,6 +115,22 @@ impl WorkerRequestExecutor<poem::Response> for NoOpWorkerRequestExecutor {
 
         Ok(worker_response)
     }
+
+    async fn complete_promise(
+        &self,
+        promise_completion_request: PromiseCompletionRequest,
+    ) -> Result<WorkerResponse, WorkerRequestExecutorError> {
+        info!(
+            "Completing promise {} of component: {}, worker: {}",
+            promise_completion_request.oplog_idx,
+            promise_completion_request.component,
+            promise_completion_request.worker_id
+        );
+
+        Ok(WorkerResponse {
+            result: TypeAnnotatedValue::Bool(true),
+        })
+    }
 }
 
 mod internal {
@@ -0,0 +1,147 @@
+use golem_common::model::AccountId;
+use golem_service_base::api_tags::ApiTags;
+use golem_worker_service_base::auth::EmptyAuthCtx;
+use poem_openapi::param::{Path, Query};
+use poem_openapi::payload::Json;
+use poem_openapi::*;
+
+use golem_service_base::model::*;
+use golem_worker_service_base::api::WorkerApiBaseError;
+
+use crate::service::worker::WorkerService;
+
+pub struct KeyValueApi {
+    pub worker_service: WorkerService,
+}
+
+type Result<T> = std::result::Result<T, WorkerApiBaseError>;
+
+#[OpenApi(prefix_path = "/v2/accounts", tag = ApiTags::KeyValue)]
+impl KeyValueApi {
+    #[oai(
+        path = "/:account_id/key-value/buckets",
+        method = "get",
+        operation_id = "get_key_value_buckets"
+    )]
+    async fn get_key_value_buckets(
+        &self,
+        account_id: Path<String>,
+    ) -> Result<Json<KeyValueBuckets>> {
+        let account_id = AccountId {
+            value: account_id.0,
+        };
+        let buckets = self
+            .worker_service
+            .get_key_value_buckets(&account_id, &EmptyAuthCtx {})
+            .await?;
+
+        Ok(Json(KeyValueBuckets { buckets }))
+    }
+
+    #[oai(
+        path = "/:account_id/key-value/buckets/:bucket/keys",
+        method = "get",
+        operation_id = "get_key_value_keys"
+    )]
+    async fn get_key_value_keys(
+        &self,
+        account_id: Path<String>,
+        bucket: Path<String>,
+        prefix: Query<Option<String>>,
+        cursor: Query<Option<String>>,
+        count: Query<Option<u64>>,
+    ) -> Result<Json<KeyValueKeys>> {
+        let account_id = AccountId {
+            value: account_id.0,
+        };
+        let keys = self
+            .worker_service
+            .get_key_value_keys(
+                &account_id,
+                &bucket.0,
+                prefix.0,
+                cursor.0,
+                count.0.unwrap_or(100),
+                &EmptyAuthCtx {},
+            )
+            .await?;
+
+        Ok(Json(keys))
+    }
+
+    #[oai(
+        path = "/:account_id/key-value/buckets/:bucket/keys/:key",
+        method = "get",
+        operation_id = "get_key_value"
+    )]
+    async fn get_key_value(
+        &self,
+        account_id: Path<String>,
+        bucket: Path<String>,
+        key: Path<String>,
+    ) -> Result<Json<KeyValueEntry>> {
+        let account_id = AccountId {
+            value: account_id.0,
+        };
+        let value = self
+            .worker_service
+            .get_key_value(&account_id, &bucket.0, &key.0, &EmptyAuthCtx {})
+            .await?;
+
+        match value {
+            Some(value) => Ok(Json(KeyValueEntry { value })),
+            None => Err(WorkerApiBaseError::NotFound(Json(ErrorBody {
+                error: format!("Key {} not found in bucket {}", key.0, bucket.0),
+            }))),
+        }
+    }
+
+    #[oai(
+        path = "/:account_id/key-value/buckets/:bucket/keys/:key",
+        method = "put",
+        operation_id = "set_key_value"
+    )]
+    async fn set_key_value(
+        &self,
+        account_id: Path<String>,
+        bucket: Path<String>,
+        key: Path<String>,
+        entry: Json<KeyValueEntry>,
+    ) -> Result<Json<SetKeyValueResponse>> {
+        let account_id = AccountId {
+            value: account_id.0,
+        };
+        self.worker_service
+            .set_key_value(
+                &account_id,
+                &bucket.0,
+                &key.0,
+                entry.0.value,
+                &EmptyAuthCtx {},
+            )
+            .await?;
+
+        Ok(Json(SetKeyValueResponse {}))
+    }
+
+    #[oai(
+        path = "/:account_id/key-value/buckets/:bucket/keys/:key",
+        method = "delete",
+        operation_id = "delete_key_value"
+    )]
+    async fn delete_key_value(
+        &self,
+        account_id: Path<String>,
+        bucket: Path<String>,
+        key: Path<String>,
+    ) -> Result<Json<DeleteKeyValueResponse>> {
+        let account_id = AccountId {
+            value: account_id.0,
+        };
+        self.worker_service
+            .delete_key_value(&account_id, &bucket.0, &key.0, &EmptyAuthCtx {})
+            .await?;
+
+        Ok(Json(DeleteKeyValueResponse {}))
+    }
+}
@@ -1,4 +1,5 @@
 pub mod deploy_api_definition;
+pub mod key_value;
 pub mod register_api_definition;
 pub mod worker;
 pub mod worker_connect;
@@ -16,6 +17,7 @@ use std::sync::Arc;
 
 type ApiServices = (
     WorkerApi,
+    key_value::KeyValueApi,
     register_api_definition::RegisterApiDefinitionApi,
     deploy_api_definition::ApiDeploymentApi,
     HealthcheckApi,
@@ -57,6 +59,9 @@ pub fn make_open_api_service(services: &Services) -> OpenApiService<ApiServices,
                 component_service: services.component_service.clone(),
                 worker_service: services.worker_service.clone(),
             },
+            key_value::KeyValueApi {
+                worker_service: services.worker_service.clone(),
+            },
             register_api_definition::RegisterApiDefinitionApi::new(
                 services.definition_service.clone(),
             ),
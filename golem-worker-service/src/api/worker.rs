@@ -19,6 +19,9 @@ pub struct WorkerApi {
 
 type Result<T> = std::result::Result<T, WorkerApiBaseError>;
 
+/// Number of workers invoked at the same time by a broadcast if the request does not limit it
+const DEFAULT_BROADCAST_CONCURRENCY: u32 = 16;
+
 #[OpenApi(prefix_path = "/v2/components", tag = ApiTags::Worker)]
 impl WorkerApi {
     #[oai(
@@ -161,11 +164,60 @@ impl WorkerApi {
         params: Json<CompleteParameters>,
     ) -> Result<Json<bool>> {
         let worker_id = make_worker_id(component_id.0, worker_name.0)?;
-        let CompleteParameters { oplog_idx, data } = params.0;
+        let CompleteParameters {
+            oplog_idx,
+            data,
+            failed,
+        } = params.0;
 
         let result = self
             .worker_service
-            .complete_promise(&worker_id, oplog_idx, data, &EmptyAuthCtx {})
+            .complete_promise(&worker_id, oplog_idx, data, failed, &EmptyAuthCtx {})
+            .await?;
+
+        Ok(Json(result))
+    }
+
+    #[oai(
+        path = "/:component_id/workers/:worker_name/mailbox",
+        method = "post",
+        operation_id = "send_mailbox_message"
+    )]
+    async fn send_mailbox_message(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        #[oai(name = "Idempotency-Key")] idempotency_key: Header<Option<IdempotencyKey>>,
+        params: Json<SendMailboxMessageParameters>,
+    ) -> Result<Json<SendMailboxMessageResponse>> {
+        let worker_id = make_worker_id(component_id.0, worker_name.0)?;
+
+        self.worker_service
+            .send_mailbox_message(
+                &worker_id,
+                idempotency_key.0,
+                params.0.payload,
+                &EmptyAuthCtx {},
+            )
+            .await?;
+
+        Ok(Json(SendMailboxMessageResponse {}))
+    }
+
+    #[oai(
+        path = "/:component_id/workers/:worker_name/promises",
+        method = "get",
+        operation_id = "get_worker_promises"
+    )]
+    async fn get_worker_promises(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+    ) -> Result<Json<WorkerPromises>> {
+        let worker_id = make_worker_id(component_id.0, worker_name.0)?;
+        let result = self
+            .worker_service
+            .get_promises(&worker_id, &EmptyAuthCtx {})
             .await?;
 
         Ok(Json(result))
@@ -276,6 +328,73 @@ impl WorkerApi {
         Ok(Json(WorkersMetadataResponse { workers, cursor }))
     }
 
+    #[oai(
+        path = "/:component_id/workers/broadcast",
+        method = "post",
+        operation_id = "broadcast_invoke_and_await_function"
+    )]
+    async fn broadcast_invoke_and_await_function(
+        &self,
+        component_id: Path<ComponentId>,
+        #[oai(name = "Idempotency-Key")] idempotency_key: Header<Option<IdempotencyKey>>,
+        function: Query<String>,
+        #[oai(name = "calling-convention")] calling_convention: Query<Option<CallingConvention>>,
+        params: Json<BroadcastParameters>,
+    ) -> Result<Json<BroadcastResult>> {
+        let calling_convention = calling_convention.0.unwrap_or(CallingConvention::Component);
+        let BroadcastParameters {
+            filter,
+            params,
+            max_concurrency,
+        } = params.0;
+
+        let results = self
+            .worker_service
+            .broadcast_invoke_and_await_function(
+                &component_id.0,
+                filter,
+                idempotency_key.0,
+                function.0,
+                params,
+                &calling_convention,
+                max_concurrency.unwrap_or(DEFAULT_BROADCAST_CONCURRENCY) as usize,
+                empty_worker_metadata(),
+                &EmptyAuthCtx {},
+            )
+            .await?;
+
+        Ok(Json(BroadcastResult { results }))
+    }
+
+    #[oai(
+        path = "/:component_id/invoke-and-await",
+        method = "post",
+        operation_id = "invoke_and_await_ephemeral_function"
+    )]
+    async fn invoke_and_await_ephemeral_function(
+        &self,
+        component_id: Path<ComponentId>,
+        function: Query<String>,
+        #[oai(name = "calling-convention")] calling_convention: Query<Option<CallingConvention>>,
+        params: Json<InvokeParameters>,
+    ) -> Result<Json<InvokeResult>> {
+        let calling_convention = calling_convention.0.unwrap_or(CallingConvention::Component);
+
+        let result = self
+            .worker_service
+            .invoke_and_await_ephemeral_function(
+                &component_id.0,
+                function.0,
+                params.0.params,
+                &calling_convention,
+                empty_worker_metadata(),
+                &EmptyAuthCtx {},
+            )
+            .await?;
+
+        Ok(Json(InvokeResult { result }))
+    }
+
     #[oai(
         path = "/:component_id/workers/:worker_name/resume",
         method = "post",
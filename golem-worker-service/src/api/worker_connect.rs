@@ -15,7 +15,7 @@
 use std::time::Duration;
 
 use futures::StreamExt;
-use golem_common::model::ComponentId;
+use golem_common::model::{ComponentId, Timestamp, WorkerEventCursor};
 use golem_service_base::model::WorkerId;
 use golem_worker_service_base::auth::EmptyAuthCtx;
 use golem_worker_service_base::service::worker::{proxy_worker_connection, ConnectWorkerStream};
@@ -80,9 +80,14 @@ async fn get_worker_stream(
         Err(err) => return Err((http::StatusCode::BAD_REQUEST, err).into_response()),
     };
 
+    let since = match get_since(req) {
+        Ok(since) => since,
+        Err(err) => return Err((http::StatusCode::BAD_REQUEST, err).into_response()),
+    };
+
     let worker_stream = service
         .worker_service
-        .connect(&worker_id, empty_worker_metadata(), &EmptyAuthCtx {})
+        .connect(&worker_id, since, empty_worker_metadata(), &EmptyAuthCtx {})
         .await
         .map_err(|e| (http::StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
 
@@ -102,3 +107,27 @@ fn get_worker_id(req: &Request) -> Result<WorkerId, String> {
 
     Ok(worker_id)
 }
+
+#[derive(Debug, Default, serde::Deserialize)]
+struct ConnectParams {
+    after: Option<u64>,
+    since: Option<String>,
+}
+
+/// Resuming from a previous connection is possible by passing either the last received
+/// event's sequence number as `after`, or an RFC 3339 timestamp as `since`
+fn get_since(req: &Request) -> Result<Option<WorkerEventCursor>, String> {
+    let params = req
+        .params::<ConnectParams>()
+        .map_err(|error| format!("Invalid query parameters: {error}"))?;
+
+    match (params.after, params.since) {
+        (Some(_), Some(_)) => Err("Only one of 'after' and 'since' can be specified".to_string()),
+        (Some(after), None) => Ok(Some(WorkerEventCursor::AfterSequence(after))),
+        (None, Some(since)) => since
+            .parse::<Timestamp>()
+            .map(|timestamp| Some(WorkerEventCursor::Since(timestamp)))
+            .map_err(|error| format!("Invalid since timestamp: {error}")),
+        (None, None) => Ok(None),
+    }
+}
@@ -1,6 +1,7 @@
 use golem_api_grpc::proto;
 use golem_api_grpc::proto::golem::apidefinition::api_definition_service_server::ApiDefinitionServiceServer;
 use golem_api_grpc::proto::golem::worker::worker_service_server::WorkerServiceServer;
+use golem_common::telemetry::grpc_request_span;
 use std::net::SocketAddr;
 use tonic::transport::{Error, Server};
 
@@ -28,6 +29,7 @@ pub async fn start_grpc_server(addr: SocketAddr, services: &Services) -> Result<
         .unwrap();
 
     Server::builder()
+        .trace_fn(grpc_request_span)
         .add_service(reflection_service)
         .add_service(health_service)
         .add_service(WorkerServiceServer::new(WorkerGrpcApi::new(
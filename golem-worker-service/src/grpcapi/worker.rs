@@ -17,20 +17,22 @@ use golem_api_grpc::proto::golem::worker::worker_service_server::WorkerService a
 use golem_api_grpc::proto::golem::worker::{
     complete_promise_response, delete_worker_response, get_worker_metadata_response,
     get_workers_metadata_response, interrupt_worker_response, invoke_and_await_response,
-    invoke_response, launch_new_worker_response, resume_worker_response, update_worker_response,
-    CompletePromiseRequest, CompletePromiseResponse, ConnectWorkerRequest, DeleteWorkerRequest,
-    DeleteWorkerResponse, GetWorkerMetadataRequest, GetWorkerMetadataResponse,
-    GetWorkersMetadataRequest, GetWorkersMetadataResponse, GetWorkersMetadataSuccessResponse,
-    InterruptWorkerRequest, InterruptWorkerResponse, InvokeAndAwaitRequest, InvokeAndAwaitResponse,
-    InvokeRequest, InvokeResponse, LaunchNewWorkerRequest, LaunchNewWorkerResponse,
-    LaunchNewWorkerSuccessResponse, ResumeWorkerRequest, ResumeWorkerResponse, UpdateWorkerRequest,
-    UpdateWorkerResponse,
+    invoke_response, launch_new_worker_response, resume_worker_response,
+    send_mailbox_message_response, update_worker_response, CompletePromiseRequest,
+    CompletePromiseResponse, ConnectWorkerRequest, DeleteWorkerRequest, DeleteWorkerResponse,
+    GetWorkerMetadataRequest, GetWorkerMetadataResponse, GetWorkersMetadataRequest,
+    GetWorkersMetadataResponse, GetWorkersMetadataSuccessResponse, InterruptWorkerRequest,
+    InterruptWorkerResponse, InvokeAndAwaitEphemeralRequest, InvokeAndAwaitRequest,
+    InvokeAndAwaitResponse, InvokeRequest, InvokeResponse, LaunchNewWorkerRequest,
+    LaunchNewWorkerResponse, LaunchNewWorkerSuccessResponse, ResumeWorkerRequest,
+    ResumeWorkerResponse, SendMailboxMessageRequest, SendMailboxMessageResponse,
+    UpdateWorkerRequest, UpdateWorkerResponse,
 };
 use golem_api_grpc::proto::golem::worker::{
     worker_error, worker_execution_error, InvokeResult, WorkerError as GrpcWorkerError,
     WorkerExecutionError, WorkerMetadata,
 };
-use golem_common::model::{ComponentVersion, WorkerFilter, WorkerId};
+use golem_common::model::{ComponentVersion, WorkerEventCursor, WorkerFilter, WorkerId};
 use golem_worker_service_base::auth::EmptyAuthCtx;
 use golem_worker_service_base::service::worker::ConnectWorkerStream;
 use tap::TapFallible;
@@ -89,6 +91,20 @@ impl GrpcWorkerService for WorkerGrpcApi {
         }))
     }
 
+    async fn send_mailbox_message(
+        &self,
+        request: Request<SendMailboxMessageRequest>,
+    ) -> Result<Response<SendMailboxMessageResponse>, Status> {
+        let response = match self.send_mailbox_message(request.into_inner()).await {
+            Ok(()) => send_mailbox_message_response::Result::Success(Empty {}),
+            Err(error) => send_mailbox_message_response::Result::Error(error),
+        };
+
+        Ok(Response::new(SendMailboxMessageResponse {
+            result: Some(response),
+        }))
+    }
+
     async fn delete_worker(
         &self,
         request: Request<DeleteWorkerRequest>,
@@ -145,6 +161,20 @@ impl GrpcWorkerService for WorkerGrpcApi {
         }))
     }
 
+    async fn invoke_and_await_ephemeral(
+        &self,
+        request: Request<InvokeAndAwaitEphemeralRequest>,
+    ) -> Result<Response<InvokeAndAwaitResponse>, Status> {
+        let response = match self.invoke_and_await_ephemeral(request.into_inner()).await {
+            Ok(result) => invoke_and_await_response::Result::Success(result),
+            Err(error) => invoke_and_await_response::Result::Error(error),
+        };
+
+        Ok(Response::new(InvokeAndAwaitResponse {
+            result: Some(response),
+        }))
+    }
+
     async fn invoke(
         &self,
         request: Request<InvokeRequest>,
@@ -287,6 +317,7 @@ impl WorkerGrpcApi {
                 &worker_id,
                 parameters.oplog_idx,
                 parameters.data,
+                parameters.failed,
                 &EmptyAuthCtx {},
             )
             .await?;
@@ -294,6 +325,24 @@ impl WorkerGrpcApi {
         Ok(result)
     }
 
+    async fn send_mailbox_message(
+        &self,
+        request: SendMailboxMessageRequest,
+    ) -> Result<(), GrpcWorkerError> {
+        let worker_id = make_crate_worker_id(request.worker_id)?;
+
+        self.worker_service
+            .send_mailbox_message(
+                &worker_id,
+                request.idempotency_key.map(|key| key.into()),
+                request.payload,
+                &EmptyAuthCtx {},
+            )
+            .await?;
+
+        Ok(())
+    }
+
     async fn get_worker_metadata(
         &self,
         request: GetWorkerMetadataRequest,
@@ -400,6 +449,41 @@ impl WorkerGrpcApi {
                 request.function,
                 params.params,
                 &calling_convention,
+                request.call_chain,
+                empty_worker_metadata(),
+                &EmptyAuthCtx {},
+            )
+            .await?;
+
+        Ok(result)
+    }
+
+    async fn invoke_and_await_ephemeral(
+        &self,
+        request: InvokeAndAwaitEphemeralRequest,
+    ) -> Result<InvokeResult, GrpcWorkerError> {
+        let component_id: golem_common::model::ComponentId = request
+            .component_id
+            .and_then(|id| id.try_into().ok())
+            .ok_or_else(|| bad_request_error("Missing component id"))?;
+
+        let params = request
+            .invoke_parameters
+            .ok_or(bad_request_error("Missing invoke parameters"))?;
+
+        let calling_convention: golem_common::model::CallingConvention = request
+            .calling_convention
+            .try_into()
+            .map_err(bad_request_error)?;
+
+        let result = self
+            .worker_service
+            .invoke_and_await_ephemeral_function_proto(
+                &component_id,
+                request.function,
+                params.params,
+                &calling_convention,
+                request.call_chain,
                 empty_worker_metadata(),
                 &EmptyAuthCtx {},
             )
@@ -423,9 +507,14 @@ impl WorkerGrpcApi {
         request: ConnectWorkerRequest,
     ) -> Result<ConnectWorkerStream, GrpcWorkerError> {
         let worker_id = make_crate_worker_id(request.worker_id)?;
+        let since = request
+            .since
+            .map(WorkerEventCursor::try_from)
+            .transpose()
+            .map_err(bad_request_error)?;
         let stream = self
             .worker_service
-            .connect(&worker_id, empty_worker_metadata(), &EmptyAuthCtx {})
+            .connect(&worker_id, since, empty_worker_metadata(), &EmptyAuthCtx {})
             .await?;
 
         Ok(stream)
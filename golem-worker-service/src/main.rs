@@ -1,3 +1,5 @@
+use golem_common::config::OtlpConfig;
+use golem_common::telemetry::{otlp_layer, TraceContext};
 use golem_worker_service::api;
 use golem_worker_service::api::make_open_api_service;
 use golem_worker_service::service::Services;
@@ -13,6 +15,8 @@ use prometheus::Registry;
 use std::net::{Ipv4Addr, SocketAddrV4};
 use std::sync::Arc;
 use tokio::select;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
 
 #[tokio::main]
 async fn main() -> std::io::Result<()> {
@@ -32,7 +36,7 @@ pub async fn app(
     worker_config: &WorkerServiceBaseConfig,
     prometheus_registry: Registry,
 ) -> std::io::Result<()> {
-    init_tracing_metrics();
+    init_tracing_metrics(&worker_config.otlp);
     let config = worker_config.clone();
 
     let services: Services = Services::new(&config)
@@ -46,7 +50,8 @@ pub async fn app(
     let custom_request_server = tokio::spawn(async move {
         let route = api::custom_request_route(http_service1)
             .with(OpenTelemetryMetrics::new())
-            .with(Tracing);
+            .with(Tracing)
+            .with(TraceContext);
 
         poem::Server::new(poem::listener::TcpListener::bind((
             "0.0.0.0",
@@ -62,7 +67,8 @@ pub async fn app(
         let prometheus_registry = Arc::new(prometheus_registry);
         let app = api::combined_routes(prometheus_registry, &http_service2)
             .with(OpenTelemetryMetrics::new())
-            .with(Tracing);
+            .with(Tracing)
+            .with(TraceContext);
 
         poem::Server::new(TcpListener::bind(format!("0.0.0.0:{}", config.port)))
             .run(app)
@@ -87,7 +93,7 @@ pub async fn app(
     Ok(())
 }
 
-fn init_tracing_metrics() {
+fn init_tracing_metrics(otlp: &OtlpConfig) {
     let prometheus = prometheus::default_registry();
     let exporter = opentelemetry_prometheus::exporter()
         .with_registry(prometheus.clone())
@@ -99,5 +105,10 @@ fn init_tracing_metrics() {
     tracing_subscriber::fmt()
         .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
         .with_ansi(true)
+        .finish()
+        .with(
+            otlp_layer(otlp, "golem-worker-service")
+                .expect("Failed to initialize the OTLP exporter"),
+        )
         .init();
 }
@@ -91,6 +91,7 @@ impl Services {
             worker_executor_grpc_clients.clone(),
             component_service.clone(),
             routing_table_service.clone(),
+            config.limits.max_broadcast_concurrency as usize,
         ));
 
         let worker_to_http_service: Arc<dyn WorkerRequestExecutor<Response> + Sync + Send> =
@@ -4,7 +4,8 @@ use async_trait::async_trait;
 use golem_worker_service_base::auth::EmptyAuthCtx;
 use golem_worker_service_base::service::worker::WorkerService;
 use golem_worker_service_base::worker_bridge_execution::{
-    WorkerRequest, WorkerRequestExecutor, WorkerRequestExecutorError, WorkerResponse,
+    PromiseCompletionRequest, WorkerRequest, WorkerRequestExecutor, WorkerRequestExecutorError,
+    WorkerResponse,
 };
 
 pub struct WorkerRequestToHttpResponse {
@@ -25,6 +26,13 @@ impl WorkerRequestExecutor<poem::Response> for WorkerRequestToHttpResponse {
     ) -> Result<WorkerResponse, WorkerRequestExecutorError> {
         internal::execute(self, worker_request_params.clone()).await
     }
+
+    async fn complete_promise(
+        &self,
+        promise_completion_request: PromiseCompletionRequest,
+    ) -> Result<WorkerResponse, WorkerRequestExecutorError> {
+        internal::complete_promise(self, promise_completion_request).await
+    }
 }
 
 mod internal {
@@ -34,8 +42,9 @@ mod internal {
     use golem_service_base::model::WorkerId;
     use golem_worker_service_base::auth::EmptyAuthCtx;
 
+    use golem_wasm_rpc::TypeAnnotatedValue;
     use golem_worker_service_base::worker_bridge_execution::{
-        WorkerRequest, WorkerRequestExecutorError, WorkerResponse,
+        PromiseCompletionRequest, WorkerRequest, WorkerRequestExecutorError, WorkerResponse,
     };
     use tracing::info;
 
@@ -43,6 +52,10 @@ mod internal {
         default_executor: &WorkerRequestToHttpResponse,
         worker_request_params: WorkerRequest,
     ) -> Result<WorkerResponse, WorkerRequestExecutorError> {
+        if worker_request_params.ephemeral {
+            return execute_ephemeral(default_executor, worker_request_params).await;
+        }
+
         let worker_name = worker_request_params.worker_id;
 
         let component_id = worker_request_params.component;
@@ -84,4 +97,65 @@ mod internal {
             result: invoke_result,
         })
     }
+
+    async fn execute_ephemeral(
+        default_executor: &WorkerRequestToHttpResponse,
+        worker_request_params: WorkerRequest,
+    ) -> Result<WorkerResponse, WorkerRequestExecutorError> {
+        let component_id = worker_request_params.component;
+
+        info!(
+            "Executing request for component: {} on an ephemeral worker, function: {}, invocation params: {:?}",
+            component_id, worker_request_params.function, worker_request_params.function_params
+        );
+
+        let invoke_result = default_executor
+            .worker_service
+            .invoke_and_await_ephemeral_function_typed_value(
+                &component_id,
+                worker_request_params.function,
+                worker_request_params.function_params,
+                &CallingConvention::Component,
+                empty_worker_metadata(),
+                &EmptyAuthCtx {},
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(WorkerResponse {
+            result: invoke_result,
+        })
+    }
+
+    pub(crate) async fn complete_promise(
+        default_executor: &WorkerRequestToHttpResponse,
+        promise_completion_request: PromiseCompletionRequest,
+    ) -> Result<WorkerResponse, WorkerRequestExecutorError> {
+        let worker_name = promise_completion_request.worker_id;
+
+        let component_id = promise_completion_request.component;
+
+        let worker_id = WorkerId::new(component_id.clone(), worker_name.clone())?;
+
+        info!(
+            "Completing promise {} of component: {}, worker: {}",
+            promise_completion_request.oplog_idx, component_id, worker_name
+        );
+
+        let completed = default_executor
+            .worker_service
+            .complete_promise(
+                &worker_id,
+                promise_completion_request.oplog_idx,
+                promise_completion_request.payload,
+                false,
+                &EmptyAuthCtx {},
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(WorkerResponse {
+            result: TypeAnnotatedValue::Bool(completed),
+        })
+    }
 }
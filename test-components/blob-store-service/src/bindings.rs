@@ -1,4 +1,189 @@
 // Generated by `wit-bindgen` 0.16.0. DO NOT EDIT!
+pub mod golem {
+  pub mod ext {
+    
+    #[allow(clippy::all)]
+    pub mod blobstore_attributes {
+      #[used]
+      #[doc(hidden)]
+      #[cfg(target_arch = "wasm32")]
+      static __FORCE_SECTION_REF: fn() = super::super::super::__link_section;
+      pub type OutgoingValue = super::super::super::wasi::blobstore::types::OutgoingValue;
+      pub type ObjectName = super::super::super::wasi::blobstore::types::ObjectName;
+      pub type Error = super::super::super::wasi::blobstore::types::Error;
+      pub type Container = super::super::super::wasi::blobstore::container::Container;
+      /// The attributes stored together with an object
+      #[derive(Clone)]
+      pub struct ObjectAttributes {
+        pub content_type: Option<wit_bindgen::rt::string::String>,
+        /// The user-defined metadata, ordered by key
+        pub metadata: wit_bindgen::rt::vec::Vec::<(wit_bindgen::rt::string::String,wit_bindgen::rt::string::String,)>,
+      }
+      impl ::core::fmt::Debug for ObjectAttributes {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          f.debug_struct("ObjectAttributes").field("content-type", &self.content_type).field("metadata", &self.metadata).finish()
+        }
+      }
+      #[allow(unused_unsafe, clippy::all)]
+      /// Sets the attributes to store with the object when the outgoing value is written with
+      /// `container.write-data`
+      pub fn set_outgoing_value_attributes(value: &OutgoingValue,attributes: &ObjectAttributes,){
+        
+        #[allow(unused_imports)]
+        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+        unsafe {
+          let ObjectAttributes{ content_type:content_type0, metadata:metadata0, } = attributes;
+          let (result2_0,result2_1,result2_2,) = match content_type0 {
+            Some(e) => {
+              let vec1 = e;
+              let ptr1 = vec1.as_ptr() as i32;
+              let len1 = vec1.len() as i32;
+              
+              (1i32, ptr1, len1)
+            },
+            None => {
+              (0i32, 0i32, 0i32)
+            },
+          };let vec6 = metadata0;
+          let len6 = vec6.len() as i32;
+          let layout6 = alloc::Layout::from_size_align_unchecked(vec6.len() * 16, 4);
+          let result6 = if layout6.size() != 0
+          {
+            let ptr = alloc::alloc(layout6);
+            if ptr.is_null()
+            {
+              alloc::handle_alloc_error(layout6);
+            }
+            ptr
+          }else {{
+            ::core::ptr::null_mut()
+          }};
+          for (i, e) in vec6.into_iter().enumerate() {
+            let base = result6 as i32 + (i as i32) * 16;
+            {
+              let (t3_0, t3_1, ) = e;
+              let vec4 = t3_0;
+              let ptr4 = vec4.as_ptr() as i32;
+              let len4 = vec4.len() as i32;
+              *((base + 4) as *mut i32) = len4;
+              *((base + 0) as *mut i32) = ptr4;
+              let vec5 = t3_1;
+              let ptr5 = vec5.as_ptr() as i32;
+              let len5 = vec5.len() as i32;
+              *((base + 12) as *mut i32) = len5;
+              *((base + 8) as *mut i32) = ptr5;
+            }
+          }
+          
+          #[cfg(target_arch = "wasm32")]
+          #[link(wasm_import_module = "golem:ext/blobstore-attributes@0.1.0")]
+          extern "C" {
+            #[link_name = "set-outgoing-value-attributes"]
+            fn wit_import(_: i32, _: i32, _: i32, _: i32, _: i32, _: i32, );
+          }
+          
+          #[cfg(not(target_arch = "wasm32"))]
+          fn wit_import(_: i32, _: i32, _: i32, _: i32, _: i32, _: i32, ){ unreachable!() }
+          wit_import((value).handle() as i32, result2_0, result2_1, result2_2, result6 as i32, len6);
+          if layout6.size() != 0 {
+            alloc::dealloc(result6, layout6);
+          }
+        }
+      }
+      #[allow(unused_unsafe, clippy::all)]
+      /// Returns the attributes stored with an object
+      pub fn get_object_attributes(container: &Container,name: &ObjectName,) -> Result<ObjectAttributes,Error>{
+        
+        #[allow(unused_imports)]
+        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+        unsafe {
+          
+          #[repr(align(4))]
+          struct RetArea([u8; 24]);
+          let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+          let vec0 = name;
+          let ptr0 = vec0.as_ptr() as i32;
+          let len0 = vec0.len() as i32;
+          let ptr1 = ret_area.as_mut_ptr() as i32;
+          #[cfg(target_arch = "wasm32")]
+          #[link(wasm_import_module = "golem:ext/blobstore-attributes@0.1.0")]
+          extern "C" {
+            #[link_name = "get-object-attributes"]
+            fn wit_import(_: i32, _: i32, _: i32, _: i32, );
+          }
+          
+          #[cfg(not(target_arch = "wasm32"))]
+          fn wit_import(_: i32, _: i32, _: i32, _: i32, ){ unreachable!() }
+          wit_import((container).handle() as i32, ptr0, len0, ptr1);
+          let l2 = i32::from(*((ptr1 + 0) as *const u8));
+          match l2 {
+            0 => {
+              let e = {
+                let l3 = i32::from(*((ptr1 + 4) as *const u8));
+                let l7 = *((ptr1 + 16) as *const i32);
+                let l8 = *((ptr1 + 20) as *const i32);
+                let base15 = l7;
+                let len15 = l8;
+                let mut result15 = Vec::with_capacity(len15 as usize);
+                for i in 0..len15 {
+                  let base = base15 + i * 16;
+                  let e15 = {
+                    let l9 = *((base + 0) as *const i32);
+                    let l10 = *((base + 4) as *const i32);
+                    let len11 = l10 as usize;
+                    let bytes11 = Vec::from_raw_parts(l9 as *mut _, len11, len11);
+                    let l12 = *((base + 8) as *const i32);
+                    let l13 = *((base + 12) as *const i32);
+                    let len14 = l13 as usize;
+                    let bytes14 = Vec::from_raw_parts(l12 as *mut _, len14, len14);
+                    
+                    (wit_bindgen::rt::string_lift(bytes11), wit_bindgen::rt::string_lift(bytes14))
+                  };
+                  result15.push(e15);
+                }
+                wit_bindgen::rt::dealloc(base15, (len15 as usize) * 16, 4);
+                
+                ObjectAttributes{
+                  content_type: match l3 {
+                    0 => None,
+                    1 => {
+                      let e = {
+                        let l4 = *((ptr1 + 8) as *const i32);
+                        let l5 = *((ptr1 + 12) as *const i32);
+                        let len6 = l5 as usize;
+                        let bytes6 = Vec::from_raw_parts(l4 as *mut _, len6, len6);
+                        
+                        wit_bindgen::rt::string_lift(bytes6)
+                      };
+                      Some(e)
+                    }
+                    _ => wit_bindgen::rt::invalid_enum_discriminant(),
+                  },
+                  metadata: result15,
+                }
+              };
+              Ok(e)
+            }
+            1 => {
+              let e = {
+                let l16 = *((ptr1 + 4) as *const i32);
+                let l17 = *((ptr1 + 8) as *const i32);
+                let len18 = l17 as usize;
+                let bytes18 = Vec::from_raw_parts(l16 as *mut _, len18, len18);
+                
+                wit_bindgen::rt::string_lift(bytes18)
+              };
+              Err(e)
+            }
+            _ => wit_bindgen::rt::invalid_enum_discriminant(),
+          }
+        }
+      }
+      
+    }
+    
+  }
+}
 pub mod wasi {
   pub mod blobstore {
     
@@ -2911,12 +3096,251 @@ pub mod wasi {
                 match result1 { true => 1, false => 0 }
               }
             };
+            const _: () = {
+              
+              #[doc(hidden)]
+              #[export_name = "golem:it/api#write-object"]
+              #[allow(non_snake_case)]
+              unsafe extern "C" fn __export_write_object(arg0: i32,arg1: i32,arg2: i32,arg3: i32,arg4: i32,arg5: i32,arg6: i32,arg7: i32,arg8: i32,arg9: i32,arg10: i32,) {
+                #[allow(unused_imports)]
+                use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                
+                // Before executing any other code, use this function to run all static
+                // constructors, if they have not yet been run. This is a hack required
+                // to work around wasi-libc ctors calling import functions to initialize
+                // the environment.
+                //
+                // This functionality will be removed once rust 1.69.0 is stable, at which
+                // point wasi-libc will no longer have this behavior.
+                //
+                // See
+                // https://github.com/bytecodealliance/preview2-prototyping/issues/99
+                // for more details.
+                #[cfg(target_arch="wasm32")]
+                wit_bindgen::rt::run_ctors_once();
+                
+                let len0 = arg1 as usize;
+                let bytes0 = Vec::from_raw_parts(arg0 as *mut _, len0, len0);
+                let len1 = arg3 as usize;
+                let bytes1 = Vec::from_raw_parts(arg2 as *mut _, len1, len1);
+                let len2 = arg5 as usize;
+                let base10 = arg9;
+                let len10 = arg10;
+                let mut result10 = Vec::with_capacity(len10 as usize);
+                for i in 0..len10 {
+                  let base = base10 + i * 16;
+                  let e10 = {
+                    let l4 = *((base + 0) as *const i32);
+                    let l5 = *((base + 4) as *const i32);
+                    let len6 = l5 as usize;
+                    let bytes6 = Vec::from_raw_parts(l4 as *mut _, len6, len6);
+                    let l7 = *((base + 8) as *const i32);
+                    let l8 = *((base + 12) as *const i32);
+                    let len9 = l8 as usize;
+                    let bytes9 = Vec::from_raw_parts(l7 as *mut _, len9, len9);
+                    
+                    (wit_bindgen::rt::string_lift(bytes6), wit_bindgen::rt::string_lift(bytes9))
+                  };
+                  result10.push(e10);
+                }
+                wit_bindgen::rt::dealloc(base10, (len10 as usize) * 16, 4);
+                <_GuestImpl as Guest>::write_object(wit_bindgen::rt::string_lift(bytes0), wit_bindgen::rt::string_lift(bytes1), Vec::from_raw_parts(arg4 as *mut _, len2, len2), match arg6 {
+                  0 => None,
+                  1 => {
+                    let e = {
+                      let len3 = arg8 as usize;
+                      let bytes3 = Vec::from_raw_parts(arg7 as *mut _, len3, len3);
+                      
+                      wit_bindgen::rt::string_lift(bytes3)
+                    };
+                    Some(e)
+                  }
+                  _ => wit_bindgen::rt::invalid_enum_discriminant(),
+                }, result10);
+              }
+            };
+            const _: () = {
+              
+              #[doc(hidden)]
+              #[export_name = "golem:it/api#get-object-attributes"]
+              #[allow(non_snake_case)]
+              unsafe extern "C" fn __export_get_object_attributes(arg0: i32,arg1: i32,arg2: i32,arg3: i32,) -> i32 {
+                #[allow(unused_imports)]
+                use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                
+                // Before executing any other code, use this function to run all static
+                // constructors, if they have not yet been run. This is a hack required
+                // to work around wasi-libc ctors calling import functions to initialize
+                // the environment.
+                //
+                // This functionality will be removed once rust 1.69.0 is stable, at which
+                // point wasi-libc will no longer have this behavior.
+                //
+                // See
+                // https://github.com/bytecodealliance/preview2-prototyping/issues/99
+                // for more details.
+                #[cfg(target_arch="wasm32")]
+                wit_bindgen::rt::run_ctors_once();
+                
+                let len0 = arg1 as usize;
+                let bytes0 = Vec::from_raw_parts(arg0 as *mut _, len0, len0);
+                let len1 = arg3 as usize;
+                let bytes1 = Vec::from_raw_parts(arg2 as *mut _, len1, len1);
+                let result2 = <_GuestImpl as Guest>::get_object_attributes(wit_bindgen::rt::string_lift(bytes0), wit_bindgen::rt::string_lift(bytes1));
+                let ptr3 = _RET_AREA.0.as_mut_ptr() as i32;
+                let (t4_0, t4_1, ) = result2;
+                match t4_0 {
+                  Some(e) => {
+                    *((ptr3 + 0) as *mut u8) = (1i32) as u8;
+                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                    let ptr5 = vec5.as_ptr() as i32;
+                    let len5 = vec5.len() as i32;
+                    ::core::mem::forget(vec5);
+                    *((ptr3 + 8) as *mut i32) = len5;
+                    *((ptr3 + 4) as *mut i32) = ptr5;
+                  },
+                  None => {
+                    {
+                      *((ptr3 + 0) as *mut u8) = (0i32) as u8;
+                    }
+                  },
+                };let vec9 = t4_1;
+                let len9 = vec9.len() as i32;
+                let layout9 = alloc::Layout::from_size_align_unchecked(vec9.len() * 16, 4);
+                let result9 = if layout9.size() != 0
+                {
+                  let ptr = alloc::alloc(layout9);
+                  if ptr.is_null()
+                  {
+                    alloc::handle_alloc_error(layout9);
+                  }
+                  ptr
+                }else {{
+                  ::core::ptr::null_mut()
+                }};
+                for (i, e) in vec9.into_iter().enumerate() {
+                  let base = result9 as i32 + (i as i32) * 16;
+                  {
+                    let (t6_0, t6_1, ) = e;
+                    let vec7 = (t6_0.into_bytes()).into_boxed_slice();
+                    let ptr7 = vec7.as_ptr() as i32;
+                    let len7 = vec7.len() as i32;
+                    ::core::mem::forget(vec7);
+                    *((base + 4) as *mut i32) = len7;
+                    *((base + 0) as *mut i32) = ptr7;
+                    let vec8 = (t6_1.into_bytes()).into_boxed_slice();
+                    let ptr8 = vec8.as_ptr() as i32;
+                    let len8 = vec8.len() as i32;
+                    ::core::mem::forget(vec8);
+                    *((base + 12) as *mut i32) = len8;
+                    *((base + 8) as *mut i32) = ptr8;
+                  }
+                }
+                *((ptr3 + 16) as *mut i32) = len9;
+                *((ptr3 + 12) as *mut i32) = result9 as i32;
+                ptr3
+              }
+              
+              const _: () = {
+                #[doc(hidden)]
+                #[export_name = "cabi_post_golem:it/api#get-object-attributes"]
+                #[allow(non_snake_case)]
+                unsafe extern "C" fn __post_return_get_object_attributes(arg0: i32,) {
+                  let l0 = i32::from(*((arg0 + 0) as *const u8));
+                  match l0 {
+                    0 => (),
+                    _ => {
+                      let l1 = *((arg0 + 4) as *const i32);
+                      let l2 = *((arg0 + 8) as *const i32);
+                      wit_bindgen::rt::dealloc(l1, (l2) as usize, 1);
+                    },
+                  }
+                  let l7 = *((arg0 + 12) as *const i32);
+                  let l8 = *((arg0 + 16) as *const i32);
+                  let base9 = l7;
+                  let len9 = l8;
+                  for i in 0..len9 {
+                    let base = base9 + i *16;
+                    {
+                      let l3 = *((base + 0) as *const i32);
+                      let l4 = *((base + 4) as *const i32);
+                      wit_bindgen::rt::dealloc(l3, (l4) as usize, 1);
+                      let l5 = *((base + 8) as *const i32);
+                      let l6 = *((base + 12) as *const i32);
+                      wit_bindgen::rt::dealloc(l5, (l6) as usize, 1);
+                    }
+                  }
+                  wit_bindgen::rt::dealloc(base9, (len9 as usize) * 16, 4);
+                }
+              };
+            };
+            const _: () = {
+              
+              #[doc(hidden)]
+              #[export_name = "golem:it/api#read-object"]
+              #[allow(non_snake_case)]
+              unsafe extern "C" fn __export_read_object(arg0: i32,arg1: i32,arg2: i32,arg3: i32,) -> i32 {
+                #[allow(unused_imports)]
+                use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                
+                // Before executing any other code, use this function to run all static
+                // constructors, if they have not yet been run. This is a hack required
+                // to work around wasi-libc ctors calling import functions to initialize
+                // the environment.
+                //
+                // This functionality will be removed once rust 1.69.0 is stable, at which
+                // point wasi-libc will no longer have this behavior.
+                //
+                // See
+                // https://github.com/bytecodealliance/preview2-prototyping/issues/99
+                // for more details.
+                #[cfg(target_arch="wasm32")]
+                wit_bindgen::rt::run_ctors_once();
+                
+                let len0 = arg1 as usize;
+                let bytes0 = Vec::from_raw_parts(arg0 as *mut _, len0, len0);
+                let len1 = arg3 as usize;
+                let bytes1 = Vec::from_raw_parts(arg2 as *mut _, len1, len1);
+                let result2 = <_GuestImpl as Guest>::read_object(wit_bindgen::rt::string_lift(bytes0), wit_bindgen::rt::string_lift(bytes1));
+                let ptr3 = _RET_AREA.0.as_mut_ptr() as i32;
+                let vec4 = (result2).into_boxed_slice();
+                let ptr4 = vec4.as_ptr() as i32;
+                let len4 = vec4.len() as i32;
+                ::core::mem::forget(vec4);
+                *((ptr3 + 4) as *mut i32) = len4;
+                *((ptr3 + 0) as *mut i32) = ptr4;
+                ptr3
+              }
+              
+              const _: () = {
+                #[doc(hidden)]
+                #[export_name = "cabi_post_golem:it/api#read-object"]
+                #[allow(non_snake_case)]
+                unsafe extern "C" fn __post_return_read_object(arg0: i32,) {
+                  let l0 = *((arg0 + 0) as *const i32);
+                  let l1 = *((arg0 + 4) as *const i32);
+                  let base2 = l0;
+                  let len2 = l1;
+                  wit_bindgen::rt::dealloc(base2, (len2 as usize) * 1, 1);
+                }
+              };
+            };
             use super::super::super::super::super::Component as _GuestImpl;
             pub trait Guest {
               fn create_container(container_name: wit_bindgen::rt::string::String,);
               fn container_exists(container_name: wit_bindgen::rt::string::String,) -> bool;
+              fn write_object(container_name: wit_bindgen::rt::string::String,object_name: wit_bindgen::rt::string::String,data: wit_bindgen::rt::vec::Vec::<u8>,content_type: Option<wit_bindgen::rt::string::String>,metadata: wit_bindgen::rt::vec::Vec::<(wit_bindgen::rt::string::String,wit_bindgen::rt::string::String,)>,);
+              fn get_object_attributes(container_name: wit_bindgen::rt::string::String,object_name: wit_bindgen::rt::string::String,) -> (Option<wit_bindgen::rt::string::String>,wit_bindgen::rt::vec::Vec::<(wit_bindgen::rt::string::String,wit_bindgen::rt::string::String,)>,);
+              fn read_object(container_name: wit_bindgen::rt::string::String,object_name: wit_bindgen::rt::string::String,) -> wit_bindgen::rt::vec::Vec::<u8>;
             }
             
+            #[allow(unused_imports)]
+            use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+            
+            #[repr(align(4))]
+            struct _RetArea([u8; 20]);
+            static mut _RET_AREA: _RetArea = _RetArea([0; 20]);
+            
           }
           
         }
@@ -2926,7 +3350,7 @@ pub mod wasi {
     #[cfg(target_arch = "wasm32")]
     #[link_section = "component-type:blob-store-service"]
     #[doc(hidden)]
-    pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 3743] = [3, 0, 18, 98, 108, 111, 98, 45, 115, 116, 111, 114, 101, 45, 115, 101, 114, 118, 105, 99, 101, 0, 97, 115, 109, 13, 0, 1, 0, 7, 107, 1, 65, 2, 1, 66, 4, 1, 64, 1, 14, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 110, 97, 109, 101, 115, 1, 0, 4, 0, 16, 99, 114, 101, 97, 116, 101, 45, 99, 111, 110, 116, 97, 105, 110, 101, 114, 1, 0, 1, 64, 1, 14, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 110, 97, 109, 101, 115, 0, 127, 4, 0, 16, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 101, 120, 105, 115, 116, 115, 1, 1, 4, 1, 12, 103, 111, 108, 101, 109, 58, 105, 116, 47, 97, 112, 105, 5, 0, 11, 9, 1, 0, 3, 97, 112, 105, 3, 0, 0, 7, 147, 27, 1, 65, 2, 1, 65, 27, 1, 66, 4, 4, 0, 5, 101, 114, 114, 111, 114, 3, 1, 1, 104, 0, 1, 64, 1, 4, 115, 101, 108, 102, 1, 0, 115, 4, 0, 29, 91, 109, 101, 116, 104, 111, 100, 93, 101, 114, 114, 111, 114, 46, 116, 111, 45, 100, 101, 98, 117, 103, 45, 115, 116, 114, 105, 110, 103, 1, 2, 3, 1, 19, 119, 97, 115, 105, 58, 105, 111, 47, 101, 114, 114, 111, 114, 64, 48, 46, 50, 46, 48, 5, 0, 1, 66, 10, 4, 0, 8, 112, 111, 108, 108, 97, 98, 108, 101, 3, 1, 1, 104, 0, 1, 64, 1, 4, 115, 101, 108, 102, 1, 0, 127, 4, 0, 22, 91, 109, 101, 116, 104, 111, 100, 93, 112, 111, 108, 108, 97, 98, 108, 101, 46, 114, 101, 97, 100, 121, 1, 2, 1, 64, 1, 4, 115, 101, 108, 102, 1, 1, 0, 4, 0, 22, 91, 109, 101, 116, 104, 111, 100, 93, 112, 111, 108, 108, 97, 98, 108, 101, 46, 98, 108, 111, 99, 107, 1, 3, 1, 112, 1, 1, 112, 121, 1, 64, 1, 2, 105, 110, 4, 0, 5, 4, 0, 4, 112, 111, 108, 108, 1, 6, 3, 1, 18, 119, 97, 115, 105, 58, 105, 111, 47, 112, 111, 108, 108, 64, 48, 46, 50, 46, 48, 5, 1, 2, 3, 0, 0, 5, 101, 114, 114, 111, 114, 2, 3, 0, 1, 8, 112, 111, 108, 108, 97, 98, 108, 101, 1, 66, 40, 2, 3, 2, 1, 2, 4, 0, 5, 101, 114, 114, 111, 114, 3, 0, 0, 2, 3, 2, 1, 3, 4, 0, 8, 112, 111, 108, 108, 97, 98, 108, 101, 3, 0, 2, 1, 105, 1, 1, 113, 2, 21, 108, 97, 115, 116, 45, 111, 112, 101, 114, 97, 116, 105, 111, 110, 45, 102, 97, 105, 108, 101, 100, 1, 4, 0, 6, 99, 108, 111, 115, 101, 100, 0, 0, 4, 0, 12, 115, 116, 114, 101, 97, 109, 45, 101, 114, 114, 111, 114, 3, 0, 5, 4, 0, 12, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 3, 1, 4, 0, 13, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 3, 1, 1, 104, 7, 1, 112, 125, 1, 106, 1, 10, 1, 6, 1, 64, 2, 4, 115, 101, 108, 102, 9, 3, 108, 101, 110, 119, 0, 11, 4, 0, 25, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 114, 101, 97, 100, 1, 12, 4, 0, 34, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 98, 108, 111, 99, 107, 105, 110, 103, 45, 114, 101, 97, 100, 1, 12, 1, 106, 1, 119, 1, 6, 1, 64, 2, 4, 115, 101, 108, 102, 9, 3, 108, 101, 110, 119, 0, 13, 4, 0, 25, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 115, 107, 105, 112, 1, 14, 4, 0, 34, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 98, 108, 111, 99, 107, 105, 110, 103, 45, 115, 107, 105, 112, 1, 14, 1, 105, 3, 1, 64, 1, 4, 115, 101, 108, 102, 9, 0, 15, 4, 0, 30, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 115, 117, 98, 115, 99, 114, 105, 98, 101, 1, 16, 1, 104, 8, 1, 64, 1, 4, 115, 101, 108, 102, 17, 0, 13, 4, 0, 33, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 99, 104, 101, 99, 107, 45, 119, 114, 105, 116, 101, 1, 18, 1, 106, 0, 1, 6, 1, 64, 2, 4, 115, 101, 108, 102, 17, 8, 99, 111, 110, 116, 101, 110, 116, 115, 10, 0, 19, 4, 0, 27, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 119, 114, 105, 116, 101, 1, 20, 4, 0, 46, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 98, 108, 111, 99, 107, 105, 110, 103, 45, 119, 114, 105, 116, 101, 45, 97, 110, 100, 45, 102, 108, 117, 115, 104, 1, 20, 1, 64, 1, 4, 115, 101, 108, 102, 17, 0, 19, 4, 0, 27, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 102, 108, 117, 115, 104, 1, 21, 4, 0, 36, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 98, 108, 111, 99, 107, 105, 110, 103, 45, 102, 108, 117, 115, 104, 1, 21, 1, 64, 1, 4, 115, 101, 108, 102, 17, 0, 15, 4, 0, 31, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 115, 117, 98, 115, 99, 114, 105, 98, 101, 1, 22, 1, 64, 2, 4, 115, 101, 108, 102, 17, 3, 108, 101, 110, 119, 0, 19, 4, 0, 34, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 119, 114, 105, 116, 101, 45, 122, 101, 114, 111, 101, 115, 1, 23, 4, 0, 53, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 98, 108, 111, 99, 107, 105, 110, 103, 45, 119, 114, 105, 116, 101, 45, 122, 101, 114, 111, 101, 115, 45, 97, 110, 100, 45, 102, 108, 117, 115, 104, 1, 23, 1, 64, 3, 4, 115, 101, 108, 102, 17, 3, 115, 114, 99, 9, 3, 108, 101, 110, 119, 0, 13, 4, 0, 28, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 115, 112, 108, 105, 99, 101, 1, 24, 4, 0, 37, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 98, 108, 111, 99, 107, 105, 110, 103, 45, 115, 112, 108, 105, 99, 101, 1, 24, 3, 1, 21, 119, 97, 115, 105, 58, 105, 111, 47, 115, 116, 114, 101, 97, 109, 115, 64, 48, 46, 50, 46, 48, 5, 4, 2, 3, 0, 2, 12, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 2, 3, 0, 2, 13, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 1, 66, 43, 2, 3, 2, 1, 5, 4, 0, 12, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 3, 0, 0, 2, 3, 2, 1, 6, 4, 0, 13, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 3, 0, 2, 1, 115, 4, 0, 14, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 110, 97, 109, 101, 3, 0, 4, 1, 115, 4, 0, 11, 111, 98, 106, 101, 99, 116, 45, 110, 97, 109, 101, 3, 0, 6, 1, 119, 4, 0, 9, 116, 105, 109, 101, 115, 116, 97, 109, 112, 3, 0, 8, 1, 119, 4, 0, 11, 111, 98, 106, 101, 99, 116, 45, 115, 105, 122, 101, 3, 0, 10, 1, 115, 4, 0, 5, 101, 114, 114, 111, 114, 3, 0, 12, 1, 114, 2, 4, 110, 97, 109, 101, 5, 10, 99, 114, 101, 97, 116, 101, 100, 45, 97, 116, 9, 4, 0, 18, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 109, 101, 116, 97, 100, 97, 116, 97, 3, 0, 14, 1, 114, 4, 4, 110, 97, 109, 101, 7, 9, 99, 111, 110, 116, 97, 105, 110, 101, 114, 5, 10, 99, 114, 101, 97, 116, 101, 100, 45, 97, 116, 9, 4, 115, 105, 122, 101, 11, 4, 0, 15, 111, 98, 106, 101, 99, 116, 45, 109, 101, 116, 97, 100, 97, 116, 97, 3, 0, 16, 1, 114, 2, 9, 99, 111, 110, 116, 97, 105, 110, 101, 114, 5, 6, 111, 98, 106, 101, 99, 116, 7, 4, 0, 9, 111, 98, 106, 101, 99, 116, 45, 105, 100, 3, 0, 18, 4, 0, 14, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 3, 1, 4, 0, 14, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 3, 1, 4, 0, 25, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 45, 97, 115, 121, 110, 99, 45, 98, 111, 100, 121, 3, 0, 1, 1, 112, 125, 4, 0, 24, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 45, 115, 121, 110, 99, 45, 98, 111, 100, 121, 3, 0, 23, 1, 105, 20, 1, 64, 0, 0, 25, 4, 0, 41, 91, 115, 116, 97, 116, 105, 99, 93, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 46, 110, 101, 119, 45, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 1, 26, 1, 104, 20, 1, 105, 3, 1, 106, 1, 28, 0, 1, 64, 1, 4, 115, 101, 108, 102, 27, 0, 29, 4, 0, 48, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 46, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 45, 119, 114, 105, 116, 101, 45, 98, 111, 100, 121, 1, 30, 1, 104, 21, 1, 106, 1, 24, 1, 13, 1, 64, 1, 4, 115, 101, 108, 102, 31, 0, 32, 4, 0, 50, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 46, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 45, 99, 111, 110, 115, 117, 109, 101, 45, 115, 121, 110, 99, 1, 33, 1, 105, 22, 1, 106, 1, 34, 1, 13, 1, 64, 1, 4, 115, 101, 108, 102, 31, 0, 35, 4, 0, 51, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 46, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 45, 99, 111, 110, 115, 117, 109, 101, 45, 97, 115, 121, 110, 99, 1, 36, 1, 64, 1, 4, 115, 101, 108, 102, 31, 0, 119, 4, 0, 27, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 46, 115, 105, 122, 101, 1, 37, 3, 1, 20, 119, 97, 115, 105, 58, 98, 108, 111, 98, 115, 116, 111, 114, 101, 47, 116, 121, 112, 101, 115, 5, 7, 2, 3, 0, 3, 18, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 109, 101, 116, 97, 100, 97, 116, 97, 2, 3, 0, 3, 5, 101, 114, 114, 111, 114, 2, 3, 0, 3, 14, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 2, 3, 0, 3, 15, 111, 98, 106, 101, 99, 116, 45, 109, 101, 116, 97, 100, 97, 116, 97, 2, 3, 0, 3, 11, 111, 98, 106, 101, 99, 116, 45, 110, 97, 109, 101, 2, 3, 0, 3, 14, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 1, 66, 59, 2, 3, 2, 1, 5, 4, 0, 12, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 3, 0, 0, 2, 3, 2, 1, 6, 4, 0, 13, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 3, 0, 2, 2, 3, 2, 1, 8, 4, 0, 18, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 109, 101, 116, 97, 100, 97, 116, 97, 3, 0, 4, 2, 3, 2, 1, 9, 4, 0, 5, 101, 114, 114, 111, 114, 3, 0, 6, 2, 3, 2, 1, 10, 4, 0, 14, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 3, 0, 8, 2, 3, 2, 1, 11, 4, 0, 15, 111, 98, 106, 101, 99, 116, 45, 109, 101, 116, 97, 100, 97, 116, 97, 3, 0, 10, 2, 3, 2, 1, 12, 4, 0, 11, 111, 98, 106, 101, 99, 116, 45, 110, 97, 109, 101, 3, 0, 12, 2, 3, 2, 1, 13, 4, 0, 14, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 3, 0, 14, 4, 0, 9, 99, 111, 110, 116, 97, 105, 110, 101, 114, 3, 1, 4, 0, 19, 115, 116, 114, 101, 97, 109, 45, 111, 98, 106, 101, 99, 116, 45, 110, 97, 109, 101, 115, 3, 1, 1, 104, 16, 1, 106, 1, 115, 1, 7, 1, 64, 1, 4, 115, 101, 108, 102, 18, 0, 19, 4, 0, 22, 91, 109, 101, 116, 104, 111, 100, 93, 99, 111, 110, 116, 97, 105, 110, 101, 114, 46, 110, 97, 109, 101, 1, 20, 1, 106, 1, 5, 1, 7, 1, 64, 1, 4, 115, 101, 108, 102, 18, 0, 21, 4, 0, 22, 91, 109, 101, 116, 104, 111, 100, 93, 99, 111, 110, 116, 97, 105, 110, 101, 114, 46, 105, 110, 102, 111, 1, 22, 1, 105, 9, 1, 106, 1, 23, 1, 7, 1, 64, 4, 4, 115, 101, 108, 102, 18, 4, 110, 97, 109, 101, 13, 5, 115, 116, 97, 114, 116, 119, 3, 101, 110, 100, 119, 0, 24, 4, 0, 26, 91, 109, 101, 116, 104, 111, 100, 93, 99, 111, 110, 116, 97, 105, 110, 101, 114, 46, 103, 101, 116, 45, 100, 97, 116, 97, 1, 25, 1, 104, 15, 1, 106, 0, 1, 7, 1, 64, 3, 4, 115, 101, 108, 102, 18, 4, 110, 97, 109, 101, 13, 4, 100, 97, 116, 97, 26, 0, 27, 4, 0, 28, 91, 109, 101, 116, 104, 111, 100, 93, 99, 111, 110, 116, 97, 105, 110, 101, 114, 46, 119, 114, 105, 116, 101, 45, 100, 97, 116, 97, 1, 28, 1, 105, 17, 1, 106, 1, 29, 1, 7, 1, 64, 1, 4, 115, 101, 108, 102, 18, 0, 30, 4, 0, 30, 91, 109, 101, 116, 104, 111, 100, 93, 99, 111, 110, 116, 97, 105, 110, 101, 114, 46, 108, 105, 115, 116, 45, 111, 98, 106, 101, 99, 116, 115, 1, 31, 1, 64, 2, 4, 115, 101, 108, 102, 18, 4, 110, 97, 109, 101, 13, 0, 27, 4, 0, 31, 91, 109, 101, 116, 104, 111, 100, 93, 99, 111, 110, 116, 97, 105, 110, 101, 114, 46, 100, 101, 108, 101, 116, 101, 45, 111, 98, 106, 101, 99, 116, 1, 32, 1, 112, 13, 1, 64, 2, 4, 115, 101, 108, 102, 18, 5, 110, 97, 109, 101, 115, 33, 0, 27, 4, 0, 32, 91, 109, 101, 116, 104, 111, 100, 93, 99, 111, 110, 116, 97, 105, 110, 101, 114, 46, 100, 101, 108, 101, 116, 101, 45, 111, 98, 106, 101, 99, 116, 115, 1, 34, 1, 106, 1, 127, 1, 7, 1, 64, 2, 4, 115, 101, 108, 102, 18, 4, 110, 97, 109, 101, 13, 0, 35, 4, 0, 28, 91, 109, 101, 116, 104, 111, 100, 93, 99, 111, 110, 116, 97, 105, 110, 101, 114, 46, 104, 97, 115, 45, 111, 98, 106, 101, 99, 116, 1, 36, 1, 106, 1, 11, 1, 7, 1, 64, 2, 4, 115, 101, 108, 102, 18, 4, 110, 97, 109, 101, 13, 0, 37, 4, 0, 29, 91, 109, 101, 116, 104, 111, 100, 93, 99, 111, 110, 116, 97, 105, 110, 101, 114, 46, 111, 98, 106, 101, 99, 116, 45, 105, 110, 102, 111, 1, 38, 1, 64, 1, 4, 115, 101, 108, 102, 18, 0, 27, 4, 0, 23, 91, 109, 101, 116, 104, 111, 100, 93, 99, 111, 110, 116, 97, 105, 110, 101, 114, 46, 99, 108, 101, 97, 114, 1, 39, 1, 104, 17, 1, 111, 2, 33, 127, 1, 106, 1, 41, 1, 7, 1, 64, 2, 4, 115, 101, 108, 102, 40, 3, 108, 101, 110, 119, 0, 42, 4, 0, 52, 91, 109, 101, 116, 104, 111, 100, 93, 115, 116, 114, 101, 97, 109, 45, 111, 98, 106, 101, 99, 116, 45, 110, 97, 109, 101, 115, 46, 114, 101, 97, 100, 45, 115, 116, 114, 101, 97, 109, 45, 111, 98, 106, 101, 99, 116, 45, 110, 97, 109, 101, 115, 1, 43, 1, 111, 2, 119, 127, 1, 106, 1, 44, 1, 7, 1, 64, 2, 4, 115, 101, 108, 102, 40, 3, 110, 117, 109, 119, 0, 45, 4, 0, 52, 91, 109, 101, 116, 104, 111, 100, 93, 115, 116, 114, 101, 97, 109, 45, 111, 98, 106, 101, 99, 116, 45, 110, 97, 109, 101, 115, 46, 115, 107, 105, 112, 45, 115, 116, 114, 101, 97, 109, 45, 111, 98, 106, 101, 99, 116, 45, 110, 97, 109, 101, 115, 1, 46, 3, 1, 24, 119, 97, 115, 105, 58, 98, 108, 111, 98, 115, 116, 111, 114, 101, 47, 99, 111, 110, 116, 97, 105, 110, 101, 114, 5, 14, 2, 3, 0, 4, 9, 99, 111, 110, 116, 97, 105, 110, 101, 114, 2, 3, 0, 3, 14, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 110, 97, 109, 101, 2, 3, 0, 3, 9, 111, 98, 106, 101, 99, 116, 45, 105, 100, 1, 66, 22, 2, 3, 2, 1, 15, 4, 0, 9, 99, 111, 110, 116, 97, 105, 110, 101, 114, 3, 0, 0, 2, 3, 2, 1, 9, 4, 0, 5, 101, 114, 114, 111, 114, 3, 0, 2, 2, 3, 2, 1, 16, 4, 0, 14, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 110, 97, 109, 101, 3, 0, 4, 2, 3, 2, 1, 17, 4, 0, 9, 111, 98, 106, 101, 99, 116, 45, 105, 100, 3, 0, 6, 1, 105, 1, 1, 106, 1, 8, 1, 3, 1, 64, 1, 4, 110, 97, 109, 101, 5, 0, 9, 4, 0, 16, 99, 114, 101, 97, 116, 101, 45, 99, 111, 110, 116, 97, 105, 110, 101, 114, 1, 10, 4, 0, 13, 103, 101, 116, 45, 99, 111, 110, 116, 97, 105, 110, 101, 114, 1, 10, 1, 106, 0, 1, 3, 1, 64, 1, 4, 110, 97, 109, 101, 5, 0, 11, 4, 0, 16, 100, 101, 108, 101, 116, 101, 45, 99, 111, 110, 116, 97, 105, 110, 101, 114, 1, 12, 1, 106, 1, 127, 1, 3, 1, 64, 1, 4, 110, 97, 109, 101, 5, 0, 13, 4, 0, 16, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 101, 120, 105, 115, 116, 115, 1, 14, 1, 64, 2, 3, 115, 114, 99, 7, 4, 100, 101, 115, 116, 7, 0, 11, 4, 0, 11, 99, 111, 112, 121, 45, 111, 98, 106, 101, 99, 116, 1, 15, 4, 0, 11, 109, 111, 118, 101, 45, 111, 98, 106, 101, 99, 116, 1, 15, 3, 1, 24, 119, 97, 115, 105, 58, 98, 108, 111, 98, 115, 116, 111, 114, 101, 47, 98, 108, 111, 98, 115, 116, 111, 114, 101, 5, 18, 1, 66, 4, 1, 64, 1, 14, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 110, 97, 109, 101, 115, 1, 0, 4, 0, 16, 99, 114, 101, 97, 116, 101, 45, 99, 111, 110, 116, 97, 105, 110, 101, 114, 1, 0, 1, 64, 1, 14, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 110, 97, 109, 101, 115, 0, 127, 4, 0, 16, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 101, 120, 105, 115, 116, 115, 1, 1, 4, 1, 12, 103, 111, 108, 101, 109, 58, 105, 116, 47, 97, 112, 105, 5, 19, 4, 1, 27, 103, 111, 108, 101, 109, 58, 105, 116, 47, 98, 108, 111, 98, 45, 115, 116, 111, 114, 101, 45, 115, 101, 114, 118, 105, 99, 101, 4, 0, 11, 24, 1, 0, 18, 98, 108, 111, 98, 45, 115, 116, 111, 114, 101, 45, 115, 101, 114, 118, 105, 99, 101, 3, 2, 0, 0, 16, 12, 112, 97, 99, 107, 97, 103, 101, 45, 100, 111, 99, 115, 0, 123, 125, 0, 70, 9, 112, 114, 111, 100, 117, 99, 101, 114, 115, 1, 12, 112, 114, 111, 99, 101, 115, 115, 101, 100, 45, 98, 121, 2, 13, 119, 105, 116, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 6, 48, 46, 49, 56, 46, 50, 16, 119, 105, 116, 45, 98, 105, 110, 100, 103, 101, 110, 45, 114, 117, 115, 116, 6, 48, 46, 49, 54, 46, 48];
+    pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 4470] = [3, 0, 18, 98, 108, 111, 98, 45, 115, 116, 111, 114, 101, 45, 115, 101, 114, 118, 105, 99, 101, 0, 97, 115, 109, 13, 0, 1, 0, 7, 189, 2, 1, 65, 2, 1, 66, 15, 1, 64, 1, 14, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 110, 97, 109, 101, 115, 1, 0, 4, 0, 16, 99, 114, 101, 97, 116, 101, 45, 99, 111, 110, 116, 97, 105, 110, 101, 114, 1, 0, 1, 64, 1, 14, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 110, 97, 109, 101, 115, 0, 127, 4, 0, 16, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 101, 120, 105, 115, 116, 115, 1, 1, 1, 112, 125, 1, 107, 115, 1, 111, 2, 115, 115, 1, 112, 4, 1, 64, 5, 14, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 110, 97, 109, 101, 115, 11, 111, 98, 106, 101, 99, 116, 45, 110, 97, 109, 101, 115, 4, 100, 97, 116, 97, 2, 12, 99, 111, 110, 116, 101, 110, 116, 45, 116, 121, 112, 101, 3, 8, 109, 101, 116, 97, 100, 97, 116, 97, 5, 1, 0, 4, 0, 12, 119, 114, 105, 116, 101, 45, 111, 98, 106, 101, 99, 116, 1, 6, 1, 111, 2, 3, 5, 1, 64, 2, 14, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 110, 97, 109, 101, 115, 11, 111, 98, 106, 101, 99, 116, 45, 110, 97, 109, 101, 115, 0, 7, 4, 0, 21, 103, 101, 116, 45, 111, 98, 106, 101, 99, 116, 45, 97, 116, 116, 114, 105, 98, 117, 116, 101, 115, 1, 8, 1, 64, 2, 14, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 110, 97, 109, 101, 115, 11, 111, 98, 106, 101, 99, 116, 45, 110, 97, 109, 101, 115, 0, 2, 4, 0, 11, 114, 101, 97, 100, 45, 111, 98, 106, 101, 99, 116, 1, 9, 4, 1, 12, 103, 111, 108, 101, 109, 58, 105, 116, 47, 97, 112, 105, 5, 0, 11, 9, 1, 0, 3, 97, 112, 105, 3, 0, 0, 7, 151, 31, 1, 65, 2, 1, 65, 29, 1, 66, 4, 4, 0, 5, 101, 114, 114, 111, 114, 3, 1, 1, 104, 0, 1, 64, 1, 4, 115, 101, 108, 102, 1, 0, 115, 4, 0, 29, 91, 109, 101, 116, 104, 111, 100, 93, 101, 114, 114, 111, 114, 46, 116, 111, 45, 100, 101, 98, 117, 103, 45, 115, 116, 114, 105, 110, 103, 1, 2, 3, 1, 19, 119, 97, 115, 105, 58, 105, 111, 47, 101, 114, 114, 111, 114, 64, 48, 46, 50, 46, 48, 5, 0, 1, 66, 10, 4, 0, 8, 112, 111, 108, 108, 97, 98, 108, 101, 3, 1, 1, 104, 0, 1, 64, 1, 4, 115, 101, 108, 102, 1, 0, 127, 4, 0, 22, 91, 109, 101, 116, 104, 111, 100, 93, 112, 111, 108, 108, 97, 98, 108, 101, 46, 114, 101, 97, 100, 121, 1, 2, 1, 64, 1, 4, 115, 101, 108, 102, 1, 1, 0, 4, 0, 22, 91, 109, 101, 116, 104, 111, 100, 93, 112, 111, 108, 108, 97, 98, 108, 101, 46, 98, 108, 111, 99, 107, 1, 3, 1, 112, 1, 1, 112, 121, 1, 64, 1, 2, 105, 110, 4, 0, 5, 4, 0, 4, 112, 111, 108, 108, 1, 6, 3, 1, 18, 119, 97, 115, 105, 58, 105, 111, 47, 112, 111, 108, 108, 64, 48, 46, 50, 46, 48, 5, 1, 2, 3, 0, 0, 5, 101, 114, 114, 111, 114, 2, 3, 0, 1, 8, 112, 111, 108, 108, 97, 98, 108, 101, 1, 66, 40, 2, 3, 2, 1, 2, 4, 0, 5, 101, 114, 114, 111, 114, 3, 0, 0, 2, 3, 2, 1, 3, 4, 0, 8, 112, 111, 108, 108, 97, 98, 108, 101, 3, 0, 2, 1, 105, 1, 1, 113, 2, 21, 108, 97, 115, 116, 45, 111, 112, 101, 114, 97, 116, 105, 111, 110, 45, 102, 97, 105, 108, 101, 100, 1, 4, 0, 6, 99, 108, 111, 115, 101, 100, 0, 0, 4, 0, 12, 115, 116, 114, 101, 97, 109, 45, 101, 114, 114, 111, 114, 3, 0, 5, 4, 0, 12, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 3, 1, 4, 0, 13, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 3, 1, 1, 104, 7, 1, 112, 125, 1, 106, 1, 10, 1, 6, 1, 64, 2, 4, 115, 101, 108, 102, 9, 3, 108, 101, 110, 119, 0, 11, 4, 0, 25, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 114, 101, 97, 100, 1, 12, 4, 0, 34, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 98, 108, 111, 99, 107, 105, 110, 103, 45, 114, 101, 97, 100, 1, 12, 1, 106, 1, 119, 1, 6, 1, 64, 2, 4, 115, 101, 108, 102, 9, 3, 108, 101, 110, 119, 0, 13, 4, 0, 25, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 115, 107, 105, 112, 1, 14, 4, 0, 34, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 98, 108, 111, 99, 107, 105, 110, 103, 45, 115, 107, 105, 112, 1, 14, 1, 105, 3, 1, 64, 1, 4, 115, 101, 108, 102, 9, 0, 15, 4, 0, 30, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 115, 117, 98, 115, 99, 114, 105, 98, 101, 1, 16, 1, 104, 8, 1, 64, 1, 4, 115, 101, 108, 102, 17, 0, 13, 4, 0, 33, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 99, 104, 101, 99, 107, 45, 119, 114, 105, 116, 101, 1, 18, 1, 106, 0, 1, 6, 1, 64, 2, 4, 115, 101, 108, 102, 17, 8, 99, 111, 110, 116, 101, 110, 116, 115, 10, 0, 19, 4, 0, 27, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 119, 114, 105, 116, 101, 1, 20, 4, 0, 46, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 98, 108, 111, 99, 107, 105, 110, 103, 45, 119, 114, 105, 116, 101, 45, 97, 110, 100, 45, 102, 108, 117, 115, 104, 1, 20, 1, 64, 1, 4, 115, 101, 108, 102, 17, 0, 19, 4, 0, 27, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 102, 108, 117, 115, 104, 1, 21, 4, 0, 36, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 98, 108, 111, 99, 107, 105, 110, 103, 45, 102, 108, 117, 115, 104, 1, 21, 1, 64, 1, 4, 115, 101, 108, 102, 17, 0, 15, 4, 0, 31, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 115, 117, 98, 115, 99, 114, 105, 98, 101, 1, 22, 1, 64, 2, 4, 115, 101, 108, 102, 17, 3, 108, 101, 110, 119, 0, 19, 4, 0, 34, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 119, 114, 105, 116, 101, 45, 122, 101, 114, 111, 101, 115, 1, 23, 4, 0, 53, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 98, 108, 111, 99, 107, 105, 110, 103, 45, 119, 114, 105, 116, 101, 45, 122, 101, 114, 111, 101, 115, 45, 97, 110, 100, 45, 102, 108, 117, 115, 104, 1, 23, 1, 64, 3, 4, 115, 101, 108, 102, 17, 3, 115, 114, 99, 9, 3, 108, 101, 110, 119, 0, 13, 4, 0, 28, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 115, 112, 108, 105, 99, 101, 1, 24, 4, 0, 37, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 98, 108, 111, 99, 107, 105, 110, 103, 45, 115, 112, 108, 105, 99, 101, 1, 24, 3, 1, 21, 119, 97, 115, 105, 58, 105, 111, 47, 115, 116, 114, 101, 97, 109, 115, 64, 48, 46, 50, 46, 48, 5, 4, 2, 3, 0, 2, 12, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 2, 3, 0, 2, 13, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 1, 66, 43, 2, 3, 2, 1, 5, 4, 0, 12, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 3, 0, 0, 2, 3, 2, 1, 6, 4, 0, 13, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 3, 0, 2, 1, 115, 4, 0, 14, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 110, 97, 109, 101, 3, 0, 4, 1, 115, 4, 0, 11, 111, 98, 106, 101, 99, 116, 45, 110, 97, 109, 101, 3, 0, 6, 1, 119, 4, 0, 9, 116, 105, 109, 101, 115, 116, 97, 109, 112, 3, 0, 8, 1, 119, 4, 0, 11, 111, 98, 106, 101, 99, 116, 45, 115, 105, 122, 101, 3, 0, 10, 1, 115, 4, 0, 5, 101, 114, 114, 111, 114, 3, 0, 12, 1, 114, 2, 4, 110, 97, 109, 101, 5, 10, 99, 114, 101, 97, 116, 101, 100, 45, 97, 116, 9, 4, 0, 18, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 109, 101, 116, 97, 100, 97, 116, 97, 3, 0, 14, 1, 114, 4, 4, 110, 97, 109, 101, 7, 9, 99, 111, 110, 116, 97, 105, 110, 101, 114, 5, 10, 99, 114, 101, 97, 116, 101, 100, 45, 97, 116, 9, 4, 115, 105, 122, 101, 11, 4, 0, 15, 111, 98, 106, 101, 99, 116, 45, 109, 101, 116, 97, 100, 97, 116, 97, 3, 0, 16, 1, 114, 2, 9, 99, 111, 110, 116, 97, 105, 110, 101, 114, 5, 6, 111, 98, 106, 101, 99, 116, 7, 4, 0, 9, 111, 98, 106, 101, 99, 116, 45, 105, 100, 3, 0, 18, 4, 0, 14, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 3, 1, 4, 0, 14, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 3, 1, 4, 0, 25, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 45, 97, 115, 121, 110, 99, 45, 98, 111, 100, 121, 3, 0, 1, 1, 112, 125, 4, 0, 24, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 45, 115, 121, 110, 99, 45, 98, 111, 100, 121, 3, 0, 23, 1, 105, 20, 1, 64, 0, 0, 25, 4, 0, 41, 91, 115, 116, 97, 116, 105, 99, 93, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 46, 110, 101, 119, 45, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 1, 26, 1, 104, 20, 1, 105, 3, 1, 106, 1, 28, 0, 1, 64, 1, 4, 115, 101, 108, 102, 27, 0, 29, 4, 0, 48, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 46, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 45, 119, 114, 105, 116, 101, 45, 98, 111, 100, 121, 1, 30, 1, 104, 21, 1, 106, 1, 24, 1, 13, 1, 64, 1, 4, 115, 101, 108, 102, 31, 0, 32, 4, 0, 50, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 46, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 45, 99, 111, 110, 115, 117, 109, 101, 45, 115, 121, 110, 99, 1, 33, 1, 105, 22, 1, 106, 1, 34, 1, 13, 1, 64, 1, 4, 115, 101, 108, 102, 31, 0, 35, 4, 0, 51, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 46, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 45, 99, 111, 110, 115, 117, 109, 101, 45, 97, 115, 121, 110, 99, 1, 36, 1, 64, 1, 4, 115, 101, 108, 102, 31, 0, 119, 4, 0, 27, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 46, 115, 105, 122, 101, 1, 37, 3, 1, 20, 119, 97, 115, 105, 58, 98, 108, 111, 98, 115, 116, 111, 114, 101, 47, 116, 121, 112, 101, 115, 5, 7, 2, 3, 0, 3, 18, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 109, 101, 116, 97, 100, 97, 116, 97, 2, 3, 0, 3, 5, 101, 114, 114, 111, 114, 2, 3, 0, 3, 14, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 2, 3, 0, 3, 15, 111, 98, 106, 101, 99, 116, 45, 109, 101, 116, 97, 100, 97, 116, 97, 2, 3, 0, 3, 11, 111, 98, 106, 101, 99, 116, 45, 110, 97, 109, 101, 2, 3, 0, 3, 14, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 1, 66, 59, 2, 3, 2, 1, 5, 4, 0, 12, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 3, 0, 0, 2, 3, 2, 1, 6, 4, 0, 13, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 3, 0, 2, 2, 3, 2, 1, 8, 4, 0, 18, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 109, 101, 116, 97, 100, 97, 116, 97, 3, 0, 4, 2, 3, 2, 1, 9, 4, 0, 5, 101, 114, 114, 111, 114, 3, 0, 6, 2, 3, 2, 1, 10, 4, 0, 14, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 3, 0, 8, 2, 3, 2, 1, 11, 4, 0, 15, 111, 98, 106, 101, 99, 116, 45, 109, 101, 116, 97, 100, 97, 116, 97, 3, 0, 10, 2, 3, 2, 1, 12, 4, 0, 11, 111, 98, 106, 101, 99, 116, 45, 110, 97, 109, 101, 3, 0, 12, 2, 3, 2, 1, 13, 4, 0, 14, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 3, 0, 14, 4, 0, 9, 99, 111, 110, 116, 97, 105, 110, 101, 114, 3, 1, 4, 0, 19, 115, 116, 114, 101, 97, 109, 45, 111, 98, 106, 101, 99, 116, 45, 110, 97, 109, 101, 115, 3, 1, 1, 104, 16, 1, 106, 1, 115, 1, 7, 1, 64, 1, 4, 115, 101, 108, 102, 18, 0, 19, 4, 0, 22, 91, 109, 101, 116, 104, 111, 100, 93, 99, 111, 110, 116, 97, 105, 110, 101, 114, 46, 110, 97, 109, 101, 1, 20, 1, 106, 1, 5, 1, 7, 1, 64, 1, 4, 115, 101, 108, 102, 18, 0, 21, 4, 0, 22, 91, 109, 101, 116, 104, 111, 100, 93, 99, 111, 110, 116, 97, 105, 110, 101, 114, 46, 105, 110, 102, 111, 1, 22, 1, 105, 9, 1, 106, 1, 23, 1, 7, 1, 64, 4, 4, 115, 101, 108, 102, 18, 4, 110, 97, 109, 101, 13, 5, 115, 116, 97, 114, 116, 119, 3, 101, 110, 100, 119, 0, 24, 4, 0, 26, 91, 109, 101, 116, 104, 111, 100, 93, 99, 111, 110, 116, 97, 105, 110, 101, 114, 46, 103, 101, 116, 45, 100, 97, 116, 97, 1, 25, 1, 104, 15, 1, 106, 0, 1, 7, 1, 64, 3, 4, 115, 101, 108, 102, 18, 4, 110, 97, 109, 101, 13, 4, 100, 97, 116, 97, 26, 0, 27, 4, 0, 28, 91, 109, 101, 116, 104, 111, 100, 93, 99, 111, 110, 116, 97, 105, 110, 101, 114, 46, 119, 114, 105, 116, 101, 45, 100, 97, 116, 97, 1, 28, 1, 105, 17, 1, 106, 1, 29, 1, 7, 1, 64, 1, 4, 115, 101, 108, 102, 18, 0, 30, 4, 0, 30, 91, 109, 101, 116, 104, 111, 100, 93, 99, 111, 110, 116, 97, 105, 110, 101, 114, 46, 108, 105, 115, 116, 45, 111, 98, 106, 101, 99, 116, 115, 1, 31, 1, 64, 2, 4, 115, 101, 108, 102, 18, 4, 110, 97, 109, 101, 13, 0, 27, 4, 0, 31, 91, 109, 101, 116, 104, 111, 100, 93, 99, 111, 110, 116, 97, 105, 110, 101, 114, 46, 100, 101, 108, 101, 116, 101, 45, 111, 98, 106, 101, 99, 116, 1, 32, 1, 112, 13, 1, 64, 2, 4, 115, 101, 108, 102, 18, 5, 110, 97, 109, 101, 115, 33, 0, 27, 4, 0, 32, 91, 109, 101, 116, 104, 111, 100, 93, 99, 111, 110, 116, 97, 105, 110, 101, 114, 46, 100, 101, 108, 101, 116, 101, 45, 111, 98, 106, 101, 99, 116, 115, 1, 34, 1, 106, 1, 127, 1, 7, 1, 64, 2, 4, 115, 101, 108, 102, 18, 4, 110, 97, 109, 101, 13, 0, 35, 4, 0, 28, 91, 109, 101, 116, 104, 111, 100, 93, 99, 111, 110, 116, 97, 105, 110, 101, 114, 46, 104, 97, 115, 45, 111, 98, 106, 101, 99, 116, 1, 36, 1, 106, 1, 11, 1, 7, 1, 64, 2, 4, 115, 101, 108, 102, 18, 4, 110, 97, 109, 101, 13, 0, 37, 4, 0, 29, 91, 109, 101, 116, 104, 111, 100, 93, 99, 111, 110, 116, 97, 105, 110, 101, 114, 46, 111, 98, 106, 101, 99, 116, 45, 105, 110, 102, 111, 1, 38, 1, 64, 1, 4, 115, 101, 108, 102, 18, 0, 27, 4, 0, 23, 91, 109, 101, 116, 104, 111, 100, 93, 99, 111, 110, 116, 97, 105, 110, 101, 114, 46, 99, 108, 101, 97, 114, 1, 39, 1, 104, 17, 1, 111, 2, 33, 127, 1, 106, 1, 41, 1, 7, 1, 64, 2, 4, 115, 101, 108, 102, 40, 3, 108, 101, 110, 119, 0, 42, 4, 0, 52, 91, 109, 101, 116, 104, 111, 100, 93, 115, 116, 114, 101, 97, 109, 45, 111, 98, 106, 101, 99, 116, 45, 110, 97, 109, 101, 115, 46, 114, 101, 97, 100, 45, 115, 116, 114, 101, 97, 109, 45, 111, 98, 106, 101, 99, 116, 45, 110, 97, 109, 101, 115, 1, 43, 1, 111, 2, 119, 127, 1, 106, 1, 44, 1, 7, 1, 64, 2, 4, 115, 101, 108, 102, 40, 3, 110, 117, 109, 119, 0, 45, 4, 0, 52, 91, 109, 101, 116, 104, 111, 100, 93, 115, 116, 114, 101, 97, 109, 45, 111, 98, 106, 101, 99, 116, 45, 110, 97, 109, 101, 115, 46, 115, 107, 105, 112, 45, 115, 116, 114, 101, 97, 109, 45, 111, 98, 106, 101, 99, 116, 45, 110, 97, 109, 101, 115, 1, 46, 3, 1, 24, 119, 97, 115, 105, 58, 98, 108, 111, 98, 115, 116, 111, 114, 101, 47, 99, 111, 110, 116, 97, 105, 110, 101, 114, 5, 14, 2, 3, 0, 4, 9, 99, 111, 110, 116, 97, 105, 110, 101, 114, 2, 3, 0, 3, 14, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 110, 97, 109, 101, 2, 3, 0, 3, 9, 111, 98, 106, 101, 99, 116, 45, 105, 100, 1, 66, 22, 2, 3, 2, 1, 15, 4, 0, 9, 99, 111, 110, 116, 97, 105, 110, 101, 114, 3, 0, 0, 2, 3, 2, 1, 9, 4, 0, 5, 101, 114, 114, 111, 114, 3, 0, 2, 2, 3, 2, 1, 16, 4, 0, 14, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 110, 97, 109, 101, 3, 0, 4, 2, 3, 2, 1, 17, 4, 0, 9, 111, 98, 106, 101, 99, 116, 45, 105, 100, 3, 0, 6, 1, 105, 1, 1, 106, 1, 8, 1, 3, 1, 64, 1, 4, 110, 97, 109, 101, 5, 0, 9, 4, 0, 16, 99, 114, 101, 97, 116, 101, 45, 99, 111, 110, 116, 97, 105, 110, 101, 114, 1, 10, 4, 0, 13, 103, 101, 116, 45, 99, 111, 110, 116, 97, 105, 110, 101, 114, 1, 10, 1, 106, 0, 1, 3, 1, 64, 1, 4, 110, 97, 109, 101, 5, 0, 11, 4, 0, 16, 100, 101, 108, 101, 116, 101, 45, 99, 111, 110, 116, 97, 105, 110, 101, 114, 1, 12, 1, 106, 1, 127, 1, 3, 1, 64, 1, 4, 110, 97, 109, 101, 5, 0, 13, 4, 0, 16, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 101, 120, 105, 115, 116, 115, 1, 14, 1, 64, 2, 3, 115, 114, 99, 7, 4, 100, 101, 115, 116, 7, 0, 11, 4, 0, 11, 99, 111, 112, 121, 45, 111, 98, 106, 101, 99, 116, 1, 15, 4, 0, 11, 109, 111, 118, 101, 45, 111, 98, 106, 101, 99, 116, 1, 15, 3, 1, 24, 119, 97, 115, 105, 58, 98, 108, 111, 98, 115, 116, 111, 114, 101, 47, 98, 108, 111, 98, 115, 116, 111, 114, 101, 5, 18, 1, 66, 20, 2, 3, 2, 1, 13, 4, 0, 14, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 3, 0, 0, 2, 3, 2, 1, 12, 4, 0, 11, 111, 98, 106, 101, 99, 116, 45, 110, 97, 109, 101, 3, 0, 2, 2, 3, 2, 1, 9, 4, 0, 5, 101, 114, 114, 111, 114, 3, 0, 4, 2, 3, 2, 1, 15, 4, 0, 9, 99, 111, 110, 116, 97, 105, 110, 101, 114, 3, 0, 6, 1, 107, 115, 1, 111, 2, 115, 115, 1, 112, 9, 1, 114, 2, 12, 99, 111, 110, 116, 101, 110, 116, 45, 116, 121, 112, 101, 8, 8, 109, 101, 116, 97, 100, 97, 116, 97, 10, 4, 0, 17, 111, 98, 106, 101, 99, 116, 45, 97, 116, 116, 114, 105, 98, 117, 116, 101, 115, 3, 0, 11, 1, 104, 1, 1, 64, 2, 5, 118, 97, 108, 117, 101, 13, 10, 97, 116, 116, 114, 105, 98, 117, 116, 101, 115, 12, 1, 0, 4, 0, 29, 115, 101, 116, 45, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 45, 97, 116, 116, 114, 105, 98, 117, 116, 101, 115, 1, 14, 1, 104, 7, 1, 106, 1, 12, 1, 5, 1, 64, 2, 9, 99, 111, 110, 116, 97, 105, 110, 101, 114, 15, 4, 110, 97, 109, 101, 3, 0, 16, 4, 0, 21, 103, 101, 116, 45, 111, 98, 106, 101, 99, 116, 45, 97, 116, 116, 114, 105, 98, 117, 116, 101, 115, 1, 17, 3, 1, 36, 103, 111, 108, 101, 109, 58, 101, 120, 116, 47, 98, 108, 111, 98, 115, 116, 111, 114, 101, 45, 97, 116, 116, 114, 105, 98, 117, 116, 101, 115, 64, 48, 46, 49, 46, 48, 5, 19, 1, 66, 15, 1, 64, 1, 14, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 110, 97, 109, 101, 115, 1, 0, 4, 0, 16, 99, 114, 101, 97, 116, 101, 45, 99, 111, 110, 116, 97, 105, 110, 101, 114, 1, 0, 1, 64, 1, 14, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 110, 97, 109, 101, 115, 0, 127, 4, 0, 16, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 101, 120, 105, 115, 116, 115, 1, 1, 1, 112, 125, 1, 107, 115, 1, 111, 2, 115, 115, 1, 112, 4, 1, 64, 5, 14, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 110, 97, 109, 101, 115, 11, 111, 98, 106, 101, 99, 116, 45, 110, 97, 109, 101, 115, 4, 100, 97, 116, 97, 2, 12, 99, 111, 110, 116, 101, 110, 116, 45, 116, 121, 112, 101, 3, 8, 109, 101, 116, 97, 100, 97, 116, 97, 5, 1, 0, 4, 0, 12, 119, 114, 105, 116, 101, 45, 111, 98, 106, 101, 99, 116, 1, 6, 1, 111, 2, 3, 5, 1, 64, 2, 14, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 110, 97, 109, 101, 115, 11, 111, 98, 106, 101, 99, 116, 45, 110, 97, 109, 101, 115, 0, 7, 4, 0, 21, 103, 101, 116, 45, 111, 98, 106, 101, 99, 116, 45, 97, 116, 116, 114, 105, 98, 117, 116, 101, 115, 1, 8, 1, 64, 2, 14, 99, 111, 110, 116, 97, 105, 110, 101, 114, 45, 110, 97, 109, 101, 115, 11, 111, 98, 106, 101, 99, 116, 45, 110, 97, 109, 101, 115, 0, 2, 4, 0, 11, 114, 101, 97, 100, 45, 111, 98, 106, 101, 99, 116, 1, 9, 4, 1, 12, 103, 111, 108, 101, 109, 58, 105, 116, 47, 97, 112, 105, 5, 20, 4, 1, 27, 103, 111, 108, 101, 109, 58, 105, 116, 47, 98, 108, 111, 98, 45, 115, 116, 111, 114, 101, 45, 115, 101, 114, 118, 105, 99, 101, 4, 0, 11, 24, 1, 0, 18, 98, 108, 111, 98, 45, 115, 116, 111, 114, 101, 45, 115, 101, 114, 118, 105, 99, 101, 3, 2, 0, 0, 16, 12, 112, 97, 99, 107, 97, 103, 101, 45, 100, 111, 99, 115, 0, 123, 125, 0, 70, 9, 112, 114, 111, 100, 117, 99, 101, 114, 115, 1, 12, 112, 114, 111, 99, 101, 115, 115, 101, 100, 45, 98, 121, 2, 13, 119, 105, 116, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 6, 48, 46, 49, 56, 46, 50, 16, 119, 105, 116, 45, 98, 105, 110, 100, 103, 101, 110, 45, 114, 117, 115, 116, 6, 48, 46, 49, 54, 46, 48];
     
     #[inline(never)]
     #[doc(hidden)]
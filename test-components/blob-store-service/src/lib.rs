@@ -1,9 +1,11 @@
 mod bindings;
 
 use crate::bindings::exports::golem::it::api::*;
+use crate::bindings::golem::ext::blobstore_attributes::{
+    get_object_attributes, set_outgoing_value_attributes, ObjectAttributes,
+};
 use crate::bindings::wasi::blobstore::blobstore::*;
 use crate::bindings::wasi::blobstore::container::*;
-use crate::bindings::wasi::blobstore::types::*;
 
 struct Component;
 
@@ -15,4 +17,42 @@ impl Guest for Component {
     fn container_exists(container_name: String) -> bool {
         container_exists(&container_name).unwrap()
     }
+
+    fn write_object(
+        container_name: String,
+        object_name: String,
+        data: Vec<u8>,
+        content_type: Option<String>,
+        metadata: Vec<(String, String)>,
+    ) {
+        let container = get_container(&container_name).unwrap();
+        let value = OutgoingValue::new_outgoing_value();
+        set_outgoing_value_attributes(
+            &value,
+            &ObjectAttributes {
+                content_type,
+                metadata,
+            },
+        );
+        let body = value.outgoing_value_write_body().unwrap();
+        body.blocking_write_and_flush(&data).unwrap();
+        drop(body);
+        container.write_data(&object_name, &value).unwrap();
+    }
+
+    fn get_object_attributes(
+        container_name: String,
+        object_name: String,
+    ) -> (Option<String>, Vec<(String, String)>) {
+        let container = get_container(&container_name).unwrap();
+        let attributes = get_object_attributes(&container, &object_name).unwrap();
+        (attributes.content_type, attributes.metadata)
+    }
+
+    fn read_object(container_name: String, object_name: String) -> Vec<u8> {
+        let container = get_container(&container_name).unwrap();
+        let size = container.object_info(&object_name).unwrap().size;
+        let value = container.get_data(&object_name, 0, size).unwrap();
+        value.incoming_value_consume_sync().unwrap()
+    }
 }
@@ -1,4 +1,137 @@
 // Generated by `wit-bindgen` 0.16.0. DO NOT EDIT!
+pub mod golem {
+  pub mod ext {
+    
+    #[allow(clippy::all)]
+    pub mod keyvalue_keys {
+      #[used]
+      #[doc(hidden)]
+      #[cfg(target_arch = "wasm32")]
+      static __FORCE_SECTION_REF: fn() = super::super::super::__link_section;
+      pub type Bucket = super::super::super::wasi::keyvalue::types::Bucket;
+      pub type Key = super::super::super::wasi::keyvalue::types::Key;
+      pub type Error = super::super::super::wasi::keyvalue::wasi_keyvalue_error::Error;
+      /// One page of the keys of a bucket
+      #[derive(Clone)]
+      pub struct KeyPage {
+        pub keys: wit_bindgen::rt::vec::Vec::<Key>,
+        /// The cursor to list the next page with, or none if this was the last page
+        pub cursor: Option<wit_bindgen::rt::string::String>,
+      }
+      impl ::core::fmt::Debug for KeyPage {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          f.debug_struct("KeyPage").field("keys", &self.keys).field("cursor", &self.cursor).finish()
+        }
+      }
+      #[allow(unused_unsafe, clippy::all)]
+      /// Lists one page of the keys of a bucket starting with `prefix`. The listing starts with no
+      /// cursor, and continues with the one returned in the previous page until it is none.
+      /// Pages may contain fewer than `count` keys even if more keys follow, and a key may be listed
+      /// in more than one page.
+      pub fn list_keys(bucket: &Bucket,prefix: Option<&str>,cursor: Option<&str>,count: u64,) -> Result<KeyPage,Error>{
+        
+        #[allow(unused_imports)]
+        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+        unsafe {
+          
+          #[repr(align(4))]
+          struct RetArea([u8; 24]);
+          let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+          let (result1_0,result1_1,result1_2,) = match prefix {
+            Some(e) => {
+              let vec0 = e;
+              let ptr0 = vec0.as_ptr() as i32;
+              let len0 = vec0.len() as i32;
+              
+              (1i32, ptr0, len0)
+            },
+            None => {
+              (0i32, 0i32, 0i32)
+            },
+          };let (result3_0,result3_1,result3_2,) = match cursor {
+            Some(e) => {
+              let vec2 = e;
+              let ptr2 = vec2.as_ptr() as i32;
+              let len2 = vec2.len() as i32;
+              
+              (1i32, ptr2, len2)
+            },
+            None => {
+              (0i32, 0i32, 0i32)
+            },
+          };let ptr4 = ret_area.as_mut_ptr() as i32;
+          #[cfg(target_arch = "wasm32")]
+          #[link(wasm_import_module = "golem:ext/keyvalue-keys@0.1.0")]
+          extern "C" {
+            #[link_name = "list-keys"]
+            fn wit_import(_: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i64, _: i32, );
+          }
+          
+          #[cfg(not(target_arch = "wasm32"))]
+          fn wit_import(_: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i64, _: i32, ){ unreachable!() }
+          wit_import((bucket).handle() as i32, result1_0, result1_1, result1_2, result3_0, result3_1, result3_2, wit_bindgen::rt::as_i64(count), ptr4);
+          let l5 = i32::from(*((ptr4 + 0) as *const u8));
+          match l5 {
+            0 => {
+              let e = {
+                let l6 = *((ptr4 + 4) as *const i32);
+                let l7 = *((ptr4 + 8) as *const i32);
+                let base11 = l6;
+                let len11 = l7;
+                let mut result11 = Vec::with_capacity(len11 as usize);
+                for i in 0..len11 {
+                  let base = base11 + i * 8;
+                  let e11 = {
+                    let l8 = *((base + 0) as *const i32);
+                    let l9 = *((base + 4) as *const i32);
+                    let len10 = l9 as usize;
+                    let bytes10 = Vec::from_raw_parts(l8 as *mut _, len10, len10);
+                    
+                    wit_bindgen::rt::string_lift(bytes10)
+                  };
+                  result11.push(e11);
+                }
+                wit_bindgen::rt::dealloc(base11, (len11 as usize) * 8, 4);
+                let l12 = i32::from(*((ptr4 + 12) as *const u8));
+                
+                KeyPage{
+                  keys: result11,
+                  cursor: match l12 {
+                    0 => None,
+                    1 => {
+                      let e = {
+                        let l13 = *((ptr4 + 16) as *const i32);
+                        let l14 = *((ptr4 + 20) as *const i32);
+                        let len15 = l14 as usize;
+                        let bytes15 = Vec::from_raw_parts(l13 as *mut _, len15, len15);
+                        
+                        wit_bindgen::rt::string_lift(bytes15)
+                      };
+                      Some(e)
+                    }
+                    _ => wit_bindgen::rt::invalid_enum_discriminant(),
+                  },
+                }
+              };
+              Ok(e)
+            }
+            1 => {
+              let e = {
+                let l16 = *((ptr4 + 4) as *const i32);
+                
+                super::super::super::wasi::keyvalue::wasi_keyvalue_error::Error::from_handle(l16 as u32)
+              };
+              Err(e)
+            }
+            _ => wit_bindgen::rt::invalid_enum_discriminant(),
+          }
+        }
+      }
+      
+    }
+    
+  }
+}
 pub mod wasi {
   pub mod io {
     
@@ -2932,6 +3065,133 @@ pub mod wasi {
                 }
               };
             };
+            const _: () = {
+              
+              #[doc(hidden)]
+              #[export_name = "golem:it/api#list-keys"]
+              #[allow(non_snake_case)]
+              unsafe extern "C" fn __export_list_keys(arg0: i32,arg1: i32,arg2: i32,arg3: i32,arg4: i32,arg5: i32,arg6: i32,arg7: i32,arg8: i64,) -> i32 {
+                #[allow(unused_imports)]
+                use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                
+                // Before executing any other code, use this function to run all static
+                // constructors, if they have not yet been run. This is a hack required
+                // to work around wasi-libc ctors calling import functions to initialize
+                // the environment.
+                //
+                // This functionality will be removed once rust 1.69.0 is stable, at which
+                // point wasi-libc will no longer have this behavior.
+                //
+                // See
+                // https://github.com/bytecodealliance/preview2-prototyping/issues/99
+                // for more details.
+                #[cfg(target_arch="wasm32")]
+                wit_bindgen::rt::run_ctors_once();
+                
+                let len0 = arg1 as usize;
+                let bytes0 = Vec::from_raw_parts(arg0 as *mut _, len0, len0);
+                let result3 = <_GuestImpl as Guest>::list_keys(wit_bindgen::rt::string_lift(bytes0), match arg2 {
+                  0 => None,
+                  1 => {
+                    let e = {
+                      let len1 = arg4 as usize;
+                      let bytes1 = Vec::from_raw_parts(arg3 as *mut _, len1, len1);
+                      
+                      wit_bindgen::rt::string_lift(bytes1)
+                    };
+                    Some(e)
+                  }
+                  _ => wit_bindgen::rt::invalid_enum_discriminant(),
+                }, match arg5 {
+                  0 => None,
+                  1 => {
+                    let e = {
+                      let len2 = arg7 as usize;
+                      let bytes2 = Vec::from_raw_parts(arg6 as *mut _, len2, len2);
+                      
+                      wit_bindgen::rt::string_lift(bytes2)
+                    };
+                    Some(e)
+                  }
+                  _ => wit_bindgen::rt::invalid_enum_discriminant(),
+                }, arg8 as u64);
+                let ptr4 = _RET_AREA.0.as_mut_ptr() as i32;
+                let (t5_0, t5_1, ) = result3;
+                let vec7 = t5_0;
+                let len7 = vec7.len() as i32;
+                let layout7 = alloc::Layout::from_size_align_unchecked(vec7.len() * 8, 4);
+                let result7 = if layout7.size() != 0
+                {
+                  let ptr = alloc::alloc(layout7);
+                  if ptr.is_null()
+                  {
+                    alloc::handle_alloc_error(layout7);
+                  }
+                  ptr
+                }else {{
+                  ::core::ptr::null_mut()
+                }};
+                for (i, e) in vec7.into_iter().enumerate() {
+                  let base = result7 as i32 + (i as i32) * 8;
+                  {
+                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                    let ptr6 = vec6.as_ptr() as i32;
+                    let len6 = vec6.len() as i32;
+                    ::core::mem::forget(vec6);
+                    *((base + 4) as *mut i32) = len6;
+                    *((base + 0) as *mut i32) = ptr6;
+                  }
+                }
+                *((ptr4 + 4) as *mut i32) = len7;
+                *((ptr4 + 0) as *mut i32) = result7 as i32;
+                match t5_1 {
+                  Some(e) => {
+                    *((ptr4 + 8) as *mut u8) = (1i32) as u8;
+                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                    let ptr8 = vec8.as_ptr() as i32;
+                    let len8 = vec8.len() as i32;
+                    ::core::mem::forget(vec8);
+                    *((ptr4 + 16) as *mut i32) = len8;
+                    *((ptr4 + 12) as *mut i32) = ptr8;
+                  },
+                  None => {
+                    {
+                      *((ptr4 + 8) as *mut u8) = (0i32) as u8;
+                    }
+                  },
+                };ptr4
+              }
+              
+              const _: () = {
+                #[doc(hidden)]
+                #[export_name = "cabi_post_golem:it/api#list-keys"]
+                #[allow(non_snake_case)]
+                unsafe extern "C" fn __post_return_list_keys(arg0: i32,) {
+                  let l2 = *((arg0 + 0) as *const i32);
+                  let l3 = *((arg0 + 4) as *const i32);
+                  let base4 = l2;
+                  let len4 = l3;
+                  for i in 0..len4 {
+                    let base = base4 + i *8;
+                    {
+                      let l0 = *((base + 0) as *const i32);
+                      let l1 = *((base + 4) as *const i32);
+                      wit_bindgen::rt::dealloc(l0, (l1) as usize, 1);
+                    }
+                  }
+                  wit_bindgen::rt::dealloc(base4, (len4 as usize) * 8, 4);
+                  let l5 = i32::from(*((arg0 + 8) as *const u8));
+                  match l5 {
+                    0 => (),
+                    _ => {
+                      let l6 = *((arg0 + 12) as *const i32);
+                      let l7 = *((arg0 + 16) as *const i32);
+                      wit_bindgen::rt::dealloc(l6, (l7) as usize, 1);
+                    },
+                  }
+                }
+              };
+            };
             const _: () = {
               
               #[doc(hidden)]
@@ -3018,6 +3278,7 @@ pub mod wasi {
               fn get(bucket: wit_bindgen::rt::string::String,key: wit_bindgen::rt::string::String,) -> Option<wit_bindgen::rt::vec::Vec::<u8>>;
               fn get_keys(bucket: wit_bindgen::rt::string::String,) -> wit_bindgen::rt::vec::Vec::<wit_bindgen::rt::string::String>;
               fn get_many(bucket: wit_bindgen::rt::string::String,keys: wit_bindgen::rt::vec::Vec::<wit_bindgen::rt::string::String>,) -> Option<wit_bindgen::rt::vec::Vec::<wit_bindgen::rt::vec::Vec::<u8>>>;
+              fn list_keys(bucket: wit_bindgen::rt::string::String,prefix: Option<wit_bindgen::rt::string::String>,cursor: Option<wit_bindgen::rt::string::String>,count: u64,) -> (wit_bindgen::rt::vec::Vec::<wit_bindgen::rt::string::String>,Option<wit_bindgen::rt::string::String>,);
               fn set(bucket: wit_bindgen::rt::string::String,key: wit_bindgen::rt::string::String,value: wit_bindgen::rt::vec::Vec::<u8>,);
               fn set_many(bucket: wit_bindgen::rt::string::String,key_values: wit_bindgen::rt::vec::Vec::<(wit_bindgen::rt::string::String,wit_bindgen::rt::vec::Vec::<u8>,)>,);
             }
@@ -3026,8 +3287,8 @@ pub mod wasi {
             use wit_bindgen::rt::{alloc, vec::Vec, string::String};
             
             #[repr(align(4))]
-            struct _RetArea([u8; 12]);
-            static mut _RET_AREA: _RetArea = _RetArea([0; 12]);
+            struct _RetArea([u8; 20]);
+            static mut _RET_AREA: _RetArea = _RetArea([0; 20]);
             
           }
           
@@ -3038,7 +3299,7 @@ pub mod wasi {
     #[cfg(target_arch = "wasm32")]
     #[link_section = "component-type:key-value-service"]
     #[doc(hidden)]
-    pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 3507] = [3, 0, 17, 107, 101, 121, 45, 118, 97, 108, 117, 101, 45, 115, 101, 114, 118, 105, 99, 101, 0, 97, 115, 109, 13, 0, 1, 0, 7, 166, 2, 1, 65, 2, 1, 66, 23, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 115, 3, 107, 101, 121, 115, 1, 0, 4, 0, 6, 100, 101, 108, 101, 116, 101, 1, 0, 1, 112, 115, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 115, 4, 107, 101, 121, 115, 1, 1, 0, 4, 0, 11, 100, 101, 108, 101, 116, 101, 45, 109, 97, 110, 121, 1, 2, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 115, 3, 107, 101, 121, 115, 0, 127, 4, 0, 6, 101, 120, 105, 115, 116, 115, 1, 3, 1, 112, 125, 1, 107, 4, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 115, 3, 107, 101, 121, 115, 0, 5, 4, 0, 3, 103, 101, 116, 1, 6, 1, 64, 1, 6, 98, 117, 99, 107, 101, 116, 115, 0, 1, 4, 0, 8, 103, 101, 116, 45, 107, 101, 121, 115, 1, 7, 1, 112, 4, 1, 107, 8, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 115, 4, 107, 101, 121, 115, 1, 0, 9, 4, 0, 8, 103, 101, 116, 45, 109, 97, 110, 121, 1, 10, 1, 64, 3, 6, 98, 117, 99, 107, 101, 116, 115, 3, 107, 101, 121, 115, 5, 118, 97, 108, 117, 101, 4, 1, 0, 4, 0, 3, 115, 101, 116, 1, 11, 1, 111, 2, 115, 4, 1, 112, 12, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 115, 10, 107, 101, 121, 45, 118, 97, 108, 117, 101, 115, 13, 1, 0, 4, 0, 8, 115, 101, 116, 45, 109, 97, 110, 121, 1, 14, 4, 1, 12, 103, 111, 108, 101, 109, 58, 105, 116, 47, 97, 112, 105, 5, 0, 11, 9, 1, 0, 3, 97, 112, 105, 3, 0, 0, 7, 237, 23, 1, 65, 2, 1, 65, 26, 1, 66, 4, 4, 0, 5, 101, 114, 114, 111, 114, 3, 1, 1, 104, 0, 1, 64, 1, 4, 115, 101, 108, 102, 1, 0, 115, 4, 0, 29, 91, 109, 101, 116, 104, 111, 100, 93, 101, 114, 114, 111, 114, 46, 116, 111, 45, 100, 101, 98, 117, 103, 45, 115, 116, 114, 105, 110, 103, 1, 2, 3, 1, 19, 119, 97, 115, 105, 58, 105, 111, 47, 101, 114, 114, 111, 114, 64, 48, 46, 50, 46, 48, 5, 0, 1, 66, 10, 4, 0, 8, 112, 111, 108, 108, 97, 98, 108, 101, 3, 1, 1, 104, 0, 1, 64, 1, 4, 115, 101, 108, 102, 1, 0, 127, 4, 0, 22, 91, 109, 101, 116, 104, 111, 100, 93, 112, 111, 108, 108, 97, 98, 108, 101, 46, 114, 101, 97, 100, 121, 1, 2, 1, 64, 1, 4, 115, 101, 108, 102, 1, 1, 0, 4, 0, 22, 91, 109, 101, 116, 104, 111, 100, 93, 112, 111, 108, 108, 97, 98, 108, 101, 46, 98, 108, 111, 99, 107, 1, 3, 1, 112, 1, 1, 112, 121, 1, 64, 1, 2, 105, 110, 4, 0, 5, 4, 0, 4, 112, 111, 108, 108, 1, 6, 3, 1, 18, 119, 97, 115, 105, 58, 105, 111, 47, 112, 111, 108, 108, 64, 48, 46, 50, 46, 48, 5, 1, 2, 3, 0, 0, 5, 101, 114, 114, 111, 114, 2, 3, 0, 1, 8, 112, 111, 108, 108, 97, 98, 108, 101, 1, 66, 40, 2, 3, 2, 1, 2, 4, 0, 5, 101, 114, 114, 111, 114, 3, 0, 0, 2, 3, 2, 1, 3, 4, 0, 8, 112, 111, 108, 108, 97, 98, 108, 101, 3, 0, 2, 1, 105, 1, 1, 113, 2, 21, 108, 97, 115, 116, 45, 111, 112, 101, 114, 97, 116, 105, 111, 110, 45, 102, 97, 105, 108, 101, 100, 1, 4, 0, 6, 99, 108, 111, 115, 101, 100, 0, 0, 4, 0, 12, 115, 116, 114, 101, 97, 109, 45, 101, 114, 114, 111, 114, 3, 0, 5, 4, 0, 12, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 3, 1, 4, 0, 13, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 3, 1, 1, 104, 7, 1, 112, 125, 1, 106, 1, 10, 1, 6, 1, 64, 2, 4, 115, 101, 108, 102, 9, 3, 108, 101, 110, 119, 0, 11, 4, 0, 25, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 114, 101, 97, 100, 1, 12, 4, 0, 34, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 98, 108, 111, 99, 107, 105, 110, 103, 45, 114, 101, 97, 100, 1, 12, 1, 106, 1, 119, 1, 6, 1, 64, 2, 4, 115, 101, 108, 102, 9, 3, 108, 101, 110, 119, 0, 13, 4, 0, 25, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 115, 107, 105, 112, 1, 14, 4, 0, 34, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 98, 108, 111, 99, 107, 105, 110, 103, 45, 115, 107, 105, 112, 1, 14, 1, 105, 3, 1, 64, 1, 4, 115, 101, 108, 102, 9, 0, 15, 4, 0, 30, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 115, 117, 98, 115, 99, 114, 105, 98, 101, 1, 16, 1, 104, 8, 1, 64, 1, 4, 115, 101, 108, 102, 17, 0, 13, 4, 0, 33, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 99, 104, 101, 99, 107, 45, 119, 114, 105, 116, 101, 1, 18, 1, 106, 0, 1, 6, 1, 64, 2, 4, 115, 101, 108, 102, 17, 8, 99, 111, 110, 116, 101, 110, 116, 115, 10, 0, 19, 4, 0, 27, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 119, 114, 105, 116, 101, 1, 20, 4, 0, 46, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 98, 108, 111, 99, 107, 105, 110, 103, 45, 119, 114, 105, 116, 101, 45, 97, 110, 100, 45, 102, 108, 117, 115, 104, 1, 20, 1, 64, 1, 4, 115, 101, 108, 102, 17, 0, 19, 4, 0, 27, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 102, 108, 117, 115, 104, 1, 21, 4, 0, 36, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 98, 108, 111, 99, 107, 105, 110, 103, 45, 102, 108, 117, 115, 104, 1, 21, 1, 64, 1, 4, 115, 101, 108, 102, 17, 0, 15, 4, 0, 31, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 115, 117, 98, 115, 99, 114, 105, 98, 101, 1, 22, 1, 64, 2, 4, 115, 101, 108, 102, 17, 3, 108, 101, 110, 119, 0, 19, 4, 0, 34, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 119, 114, 105, 116, 101, 45, 122, 101, 114, 111, 101, 115, 1, 23, 4, 0, 53, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 98, 108, 111, 99, 107, 105, 110, 103, 45, 119, 114, 105, 116, 101, 45, 122, 101, 114, 111, 101, 115, 45, 97, 110, 100, 45, 102, 108, 117, 115, 104, 1, 23, 1, 64, 3, 4, 115, 101, 108, 102, 17, 3, 115, 114, 99, 9, 3, 108, 101, 110, 119, 0, 13, 4, 0, 28, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 115, 112, 108, 105, 99, 101, 1, 24, 4, 0, 37, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 98, 108, 111, 99, 107, 105, 110, 103, 45, 115, 112, 108, 105, 99, 101, 1, 24, 3, 1, 21, 119, 97, 115, 105, 58, 105, 111, 47, 115, 116, 114, 101, 97, 109, 115, 64, 48, 46, 50, 46, 48, 5, 4, 1, 66, 4, 4, 0, 5, 101, 114, 114, 111, 114, 3, 1, 1, 104, 0, 1, 64, 1, 4, 115, 101, 108, 102, 1, 0, 115, 4, 0, 19, 91, 109, 101, 116, 104, 111, 100, 93, 101, 114, 114, 111, 114, 46, 116, 114, 97, 99, 101, 1, 2, 3, 1, 39, 119, 97, 115, 105, 58, 107, 101, 121, 118, 97, 108, 117, 101, 47, 119, 97, 115, 105, 45, 107, 101, 121, 118, 97, 108, 117, 101, 45, 101, 114, 114, 111, 114, 64, 48, 46, 49, 46, 48, 5, 5, 2, 3, 0, 2, 12, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 2, 3, 0, 2, 13, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 2, 3, 0, 3, 5, 101, 114, 114, 111, 114, 1, 66, 44, 2, 3, 2, 1, 6, 4, 0, 12, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 3, 0, 0, 2, 3, 2, 1, 7, 4, 0, 13, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 3, 0, 2, 2, 3, 2, 1, 8, 4, 0, 5, 101, 114, 114, 111, 114, 3, 0, 4, 4, 0, 6, 98, 117, 99, 107, 101, 116, 3, 1, 1, 115, 4, 0, 3, 107, 101, 121, 3, 0, 7, 4, 0, 14, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 3, 1, 4, 0, 25, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 45, 98, 111, 100, 121, 45, 97, 115, 121, 110, 99, 3, 0, 3, 1, 112, 125, 4, 0, 24, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 45, 98, 111, 100, 121, 45, 115, 121, 110, 99, 3, 0, 11, 4, 0, 14, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 3, 1, 4, 0, 25, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 45, 97, 115, 121, 110, 99, 45, 98, 111, 100, 121, 3, 0, 1, 1, 112, 125, 4, 0, 24, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 45, 115, 121, 110, 99, 45, 98, 111, 100, 121, 3, 0, 15, 1, 105, 6, 1, 105, 5, 1, 106, 1, 17, 1, 18, 1, 64, 1, 4, 110, 97, 109, 101, 115, 0, 19, 4, 0, 26, 91, 115, 116, 97, 116, 105, 99, 93, 98, 117, 99, 107, 101, 116, 46, 111, 112, 101, 110, 45, 98, 117, 99, 107, 101, 116, 1, 20, 1, 105, 9, 1, 64, 0, 0, 21, 4, 0, 41, 91, 115, 116, 97, 116, 105, 99, 93, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 46, 110, 101, 119, 45, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 1, 22, 1, 104, 9, 1, 105, 10, 1, 106, 1, 24, 1, 18, 1, 64, 1, 4, 115, 101, 108, 102, 23, 0, 25, 4, 0, 54, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 46, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 45, 119, 114, 105, 116, 101, 45, 98, 111, 100, 121, 45, 97, 115, 121, 110, 99, 1, 26, 1, 106, 0, 1, 18, 1, 64, 2, 4, 115, 101, 108, 102, 23, 5, 118, 97, 108, 117, 101, 12, 0, 27, 4, 0, 53, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 46, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 45, 119, 114, 105, 116, 101, 45, 98, 111, 100, 121, 45, 115, 121, 110, 99, 1, 28, 1, 104, 13, 1, 106, 1, 16, 1, 18, 1, 64, 1, 4, 115, 101, 108, 102, 29, 0, 30, 4, 0, 50, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 46, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 45, 99, 111, 110, 115, 117, 109, 101, 45, 115, 121, 110, 99, 1, 31, 1, 105, 14, 1, 106, 1, 32, 1, 18, 1, 64, 1, 4, 115, 101, 108, 102, 29, 0, 33, 4, 0, 51, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 46, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 45, 99, 111, 110, 115, 117, 109, 101, 45, 97, 115, 121, 110, 99, 1, 34, 1, 106, 1, 119, 1, 18, 1, 64, 1, 4, 115, 101, 108, 102, 29, 0, 35, 4, 0, 42, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 46, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 45, 115, 105, 122, 101, 1, 36, 3, 1, 25, 119, 97, 115, 105, 58, 107, 101, 121, 118, 97, 108, 117, 101, 47, 116, 121, 112, 101, 115, 64, 48, 46, 49, 46, 48, 5, 9, 2, 3, 0, 4, 6, 98, 117, 99, 107, 101, 116, 2, 3, 0, 4, 5, 101, 114, 114, 111, 114, 2, 3, 0, 4, 3, 107, 101, 121, 2, 3, 0, 4, 14, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 2, 3, 0, 4, 14, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 1, 66, 30, 2, 3, 2, 1, 10, 4, 0, 6, 98, 117, 99, 107, 101, 116, 3, 0, 0, 2, 3, 2, 1, 11, 4, 0, 5, 101, 114, 114, 111, 114, 3, 0, 2, 2, 3, 2, 1, 12, 4, 0, 3, 107, 101, 121, 3, 0, 4, 2, 3, 2, 1, 13, 4, 0, 14, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 3, 0, 6, 2, 3, 2, 1, 14, 4, 0, 14, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 3, 0, 8, 1, 104, 1, 1, 112, 5, 1, 105, 7, 1, 107, 12, 1, 112, 13, 1, 105, 3, 1, 106, 1, 14, 1, 15, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 10, 4, 107, 101, 121, 115, 11, 0, 16, 4, 0, 8, 103, 101, 116, 45, 109, 97, 110, 121, 1, 17, 1, 106, 1, 11, 1, 15, 1, 64, 1, 6, 98, 117, 99, 107, 101, 116, 10, 0, 18, 4, 0, 4, 107, 101, 121, 115, 1, 19, 1, 104, 9, 1, 111, 2, 5, 20, 1, 112, 21, 1, 106, 0, 1, 15, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 10, 10, 107, 101, 121, 45, 118, 97, 108, 117, 101, 115, 22, 0, 23, 4, 0, 8, 115, 101, 116, 45, 109, 97, 110, 121, 1, 24, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 10, 4, 107, 101, 121, 115, 11, 0, 23, 4, 0, 11, 100, 101, 108, 101, 116, 101, 45, 109, 97, 110, 121, 1, 25, 3, 1, 34, 119, 97, 115, 105, 58, 107, 101, 121, 118, 97, 108, 117, 101, 47, 101, 118, 101, 110, 116, 117, 97, 108, 45, 98, 97, 116, 99, 104, 64, 48, 46, 49, 46, 48, 5, 15, 1, 66, 26, 2, 3, 2, 1, 10, 4, 0, 6, 98, 117, 99, 107, 101, 116, 3, 0, 0, 2, 3, 2, 1, 11, 4, 0, 5, 101, 114, 114, 111, 114, 3, 0, 2, 2, 3, 2, 1, 13, 4, 0, 14, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 3, 0, 4, 2, 3, 2, 1, 12, 4, 0, 3, 107, 101, 121, 3, 0, 6, 2, 3, 2, 1, 14, 4, 0, 14, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 3, 0, 8, 1, 104, 1, 1, 105, 5, 1, 107, 11, 1, 105, 3, 1, 106, 1, 12, 1, 13, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 10, 3, 107, 101, 121, 7, 0, 14, 4, 0, 3, 103, 101, 116, 1, 15, 1, 104, 9, 1, 106, 0, 1, 13, 1, 64, 3, 6, 98, 117, 99, 107, 101, 116, 10, 3, 107, 101, 121, 7, 14, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 16, 0, 17, 4, 0, 3, 115, 101, 116, 1, 18, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 10, 3, 107, 101, 121, 7, 0, 17, 4, 0, 6, 100, 101, 108, 101, 116, 101, 1, 19, 1, 106, 1, 127, 1, 13, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 10, 3, 107, 101, 121, 7, 0, 20, 4, 0, 6, 101, 120, 105, 115, 116, 115, 1, 21, 3, 1, 28, 119, 97, 115, 105, 58, 107, 101, 121, 118, 97, 108, 117, 101, 47, 101, 118, 101, 110, 116, 117, 97, 108, 64, 48, 46, 49, 46, 48, 5, 16, 1, 66, 23, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 115, 3, 107, 101, 121, 115, 1, 0, 4, 0, 6, 100, 101, 108, 101, 116, 101, 1, 0, 1, 112, 115, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 115, 4, 107, 101, 121, 115, 1, 1, 0, 4, 0, 11, 100, 101, 108, 101, 116, 101, 45, 109, 97, 110, 121, 1, 2, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 115, 3, 107, 101, 121, 115, 0, 127, 4, 0, 6, 101, 120, 105, 115, 116, 115, 1, 3, 1, 112, 125, 1, 107, 4, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 115, 3, 107, 101, 121, 115, 0, 5, 4, 0, 3, 103, 101, 116, 1, 6, 1, 64, 1, 6, 98, 117, 99, 107, 101, 116, 115, 0, 1, 4, 0, 8, 103, 101, 116, 45, 107, 101, 121, 115, 1, 7, 1, 112, 4, 1, 107, 8, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 115, 4, 107, 101, 121, 115, 1, 0, 9, 4, 0, 8, 103, 101, 116, 45, 109, 97, 110, 121, 1, 10, 1, 64, 3, 6, 98, 117, 99, 107, 101, 116, 115, 3, 107, 101, 121, 115, 5, 118, 97, 108, 117, 101, 4, 1, 0, 4, 0, 3, 115, 101, 116, 1, 11, 1, 111, 2, 115, 4, 1, 112, 12, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 115, 10, 107, 101, 121, 45, 118, 97, 108, 117, 101, 115, 13, 1, 0, 4, 0, 8, 115, 101, 116, 45, 109, 97, 110, 121, 1, 14, 4, 1, 12, 103, 111, 108, 101, 109, 58, 105, 116, 47, 97, 112, 105, 5, 17, 4, 1, 26, 103, 111, 108, 101, 109, 58, 105, 116, 47, 107, 101, 121, 45, 118, 97, 108, 117, 101, 45, 115, 101, 114, 118, 105, 99, 101, 4, 0, 11, 23, 1, 0, 17, 107, 101, 121, 45, 118, 97, 108, 117, 101, 45, 115, 101, 114, 118, 105, 99, 101, 3, 2, 0, 0, 16, 12, 112, 97, 99, 107, 97, 103, 101, 45, 100, 111, 99, 115, 0, 123, 125, 0, 70, 9, 112, 114, 111, 100, 117, 99, 101, 114, 115, 1, 12, 112, 114, 111, 99, 101, 115, 115, 101, 100, 45, 98, 121, 2, 13, 119, 105, 116, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 6, 48, 46, 49, 56, 46, 50, 16, 119, 105, 116, 45, 98, 105, 110, 100, 103, 101, 110, 45, 114, 117, 115, 116, 6, 48, 46, 49, 54, 46, 48];
+    pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 3806] = [3, 0, 17, 107, 101, 121, 45, 118, 97, 108, 117, 101, 45, 115, 101, 114, 118, 105, 99, 101, 0, 97, 115, 109, 13, 0, 1, 0, 7, 224, 2, 1, 65, 2, 1, 66, 27, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 115, 3, 107, 101, 121, 115, 1, 0, 4, 0, 6, 100, 101, 108, 101, 116, 101, 1, 0, 1, 112, 115, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 115, 4, 107, 101, 121, 115, 1, 1, 0, 4, 0, 11, 100, 101, 108, 101, 116, 101, 45, 109, 97, 110, 121, 1, 2, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 115, 3, 107, 101, 121, 115, 0, 127, 4, 0, 6, 101, 120, 105, 115, 116, 115, 1, 3, 1, 112, 125, 1, 107, 4, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 115, 3, 107, 101, 121, 115, 0, 5, 4, 0, 3, 103, 101, 116, 1, 6, 1, 64, 1, 6, 98, 117, 99, 107, 101, 116, 115, 0, 1, 4, 0, 8, 103, 101, 116, 45, 107, 101, 121, 115, 1, 7, 1, 112, 4, 1, 107, 8, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 115, 4, 107, 101, 121, 115, 1, 0, 9, 4, 0, 8, 103, 101, 116, 45, 109, 97, 110, 121, 1, 10, 1, 107, 115, 1, 111, 2, 1, 11, 1, 64, 4, 6, 98, 117, 99, 107, 101, 116, 115, 6, 112, 114, 101, 102, 105, 120, 11, 6, 99, 117, 114, 115, 111, 114, 11, 5, 99, 111, 117, 110, 116, 119, 0, 12, 4, 0, 9, 108, 105, 115, 116, 45, 107, 101, 121, 115, 1, 13, 1, 64, 3, 6, 98, 117, 99, 107, 101, 116, 115, 3, 107, 101, 121, 115, 5, 118, 97, 108, 117, 101, 4, 1, 0, 4, 0, 3, 115, 101, 116, 1, 14, 1, 111, 2, 115, 4, 1, 112, 15, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 115, 10, 107, 101, 121, 45, 118, 97, 108, 117, 101, 115, 16, 1, 0, 4, 0, 8, 115, 101, 116, 45, 109, 97, 110, 121, 1, 17, 4, 1, 12, 103, 111, 108, 101, 109, 58, 105, 116, 47, 97, 112, 105, 5, 0, 11, 9, 1, 0, 3, 97, 112, 105, 3, 0, 0, 7, 222, 25, 1, 65, 2, 1, 65, 28, 1, 66, 4, 4, 0, 5, 101, 114, 114, 111, 114, 3, 1, 1, 104, 0, 1, 64, 1, 4, 115, 101, 108, 102, 1, 0, 115, 4, 0, 29, 91, 109, 101, 116, 104, 111, 100, 93, 101, 114, 114, 111, 114, 46, 116, 111, 45, 100, 101, 98, 117, 103, 45, 115, 116, 114, 105, 110, 103, 1, 2, 3, 1, 19, 119, 97, 115, 105, 58, 105, 111, 47, 101, 114, 114, 111, 114, 64, 48, 46, 50, 46, 48, 5, 0, 1, 66, 10, 4, 0, 8, 112, 111, 108, 108, 97, 98, 108, 101, 3, 1, 1, 104, 0, 1, 64, 1, 4, 115, 101, 108, 102, 1, 0, 127, 4, 0, 22, 91, 109, 101, 116, 104, 111, 100, 93, 112, 111, 108, 108, 97, 98, 108, 101, 46, 114, 101, 97, 100, 121, 1, 2, 1, 64, 1, 4, 115, 101, 108, 102, 1, 1, 0, 4, 0, 22, 91, 109, 101, 116, 104, 111, 100, 93, 112, 111, 108, 108, 97, 98, 108, 101, 46, 98, 108, 111, 99, 107, 1, 3, 1, 112, 1, 1, 112, 121, 1, 64, 1, 2, 105, 110, 4, 0, 5, 4, 0, 4, 112, 111, 108, 108, 1, 6, 3, 1, 18, 119, 97, 115, 105, 58, 105, 111, 47, 112, 111, 108, 108, 64, 48, 46, 50, 46, 48, 5, 1, 2, 3, 0, 0, 5, 101, 114, 114, 111, 114, 2, 3, 0, 1, 8, 112, 111, 108, 108, 97, 98, 108, 101, 1, 66, 40, 2, 3, 2, 1, 2, 4, 0, 5, 101, 114, 114, 111, 114, 3, 0, 0, 2, 3, 2, 1, 3, 4, 0, 8, 112, 111, 108, 108, 97, 98, 108, 101, 3, 0, 2, 1, 105, 1, 1, 113, 2, 21, 108, 97, 115, 116, 45, 111, 112, 101, 114, 97, 116, 105, 111, 110, 45, 102, 97, 105, 108, 101, 100, 1, 4, 0, 6, 99, 108, 111, 115, 101, 100, 0, 0, 4, 0, 12, 115, 116, 114, 101, 97, 109, 45, 101, 114, 114, 111, 114, 3, 0, 5, 4, 0, 12, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 3, 1, 4, 0, 13, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 3, 1, 1, 104, 7, 1, 112, 125, 1, 106, 1, 10, 1, 6, 1, 64, 2, 4, 115, 101, 108, 102, 9, 3, 108, 101, 110, 119, 0, 11, 4, 0, 25, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 114, 101, 97, 100, 1, 12, 4, 0, 34, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 98, 108, 111, 99, 107, 105, 110, 103, 45, 114, 101, 97, 100, 1, 12, 1, 106, 1, 119, 1, 6, 1, 64, 2, 4, 115, 101, 108, 102, 9, 3, 108, 101, 110, 119, 0, 13, 4, 0, 25, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 115, 107, 105, 112, 1, 14, 4, 0, 34, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 98, 108, 111, 99, 107, 105, 110, 103, 45, 115, 107, 105, 112, 1, 14, 1, 105, 3, 1, 64, 1, 4, 115, 101, 108, 102, 9, 0, 15, 4, 0, 30, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 115, 117, 98, 115, 99, 114, 105, 98, 101, 1, 16, 1, 104, 8, 1, 64, 1, 4, 115, 101, 108, 102, 17, 0, 13, 4, 0, 33, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 99, 104, 101, 99, 107, 45, 119, 114, 105, 116, 101, 1, 18, 1, 106, 0, 1, 6, 1, 64, 2, 4, 115, 101, 108, 102, 17, 8, 99, 111, 110, 116, 101, 110, 116, 115, 10, 0, 19, 4, 0, 27, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 119, 114, 105, 116, 101, 1, 20, 4, 0, 46, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 98, 108, 111, 99, 107, 105, 110, 103, 45, 119, 114, 105, 116, 101, 45, 97, 110, 100, 45, 102, 108, 117, 115, 104, 1, 20, 1, 64, 1, 4, 115, 101, 108, 102, 17, 0, 19, 4, 0, 27, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 102, 108, 117, 115, 104, 1, 21, 4, 0, 36, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 98, 108, 111, 99, 107, 105, 110, 103, 45, 102, 108, 117, 115, 104, 1, 21, 1, 64, 1, 4, 115, 101, 108, 102, 17, 0, 15, 4, 0, 31, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 115, 117, 98, 115, 99, 114, 105, 98, 101, 1, 22, 1, 64, 2, 4, 115, 101, 108, 102, 17, 3, 108, 101, 110, 119, 0, 19, 4, 0, 34, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 119, 114, 105, 116, 101, 45, 122, 101, 114, 111, 101, 115, 1, 23, 4, 0, 53, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 98, 108, 111, 99, 107, 105, 110, 103, 45, 119, 114, 105, 116, 101, 45, 122, 101, 114, 111, 101, 115, 45, 97, 110, 100, 45, 102, 108, 117, 115, 104, 1, 23, 1, 64, 3, 4, 115, 101, 108, 102, 17, 3, 115, 114, 99, 9, 3, 108, 101, 110, 119, 0, 13, 4, 0, 28, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 115, 112, 108, 105, 99, 101, 1, 24, 4, 0, 37, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 46, 98, 108, 111, 99, 107, 105, 110, 103, 45, 115, 112, 108, 105, 99, 101, 1, 24, 3, 1, 21, 119, 97, 115, 105, 58, 105, 111, 47, 115, 116, 114, 101, 97, 109, 115, 64, 48, 46, 50, 46, 48, 5, 4, 1, 66, 4, 4, 0, 5, 101, 114, 114, 111, 114, 3, 1, 1, 104, 0, 1, 64, 1, 4, 115, 101, 108, 102, 1, 0, 115, 4, 0, 19, 91, 109, 101, 116, 104, 111, 100, 93, 101, 114, 114, 111, 114, 46, 116, 114, 97, 99, 101, 1, 2, 3, 1, 39, 119, 97, 115, 105, 58, 107, 101, 121, 118, 97, 108, 117, 101, 47, 119, 97, 115, 105, 45, 107, 101, 121, 118, 97, 108, 117, 101, 45, 101, 114, 114, 111, 114, 64, 48, 46, 49, 46, 48, 5, 5, 2, 3, 0, 2, 12, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 2, 3, 0, 2, 13, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 2, 3, 0, 3, 5, 101, 114, 114, 111, 114, 1, 66, 44, 2, 3, 2, 1, 6, 4, 0, 12, 105, 110, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 3, 0, 0, 2, 3, 2, 1, 7, 4, 0, 13, 111, 117, 116, 112, 117, 116, 45, 115, 116, 114, 101, 97, 109, 3, 0, 2, 2, 3, 2, 1, 8, 4, 0, 5, 101, 114, 114, 111, 114, 3, 0, 4, 4, 0, 6, 98, 117, 99, 107, 101, 116, 3, 1, 1, 115, 4, 0, 3, 107, 101, 121, 3, 0, 7, 4, 0, 14, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 3, 1, 4, 0, 25, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 45, 98, 111, 100, 121, 45, 97, 115, 121, 110, 99, 3, 0, 3, 1, 112, 125, 4, 0, 24, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 45, 98, 111, 100, 121, 45, 115, 121, 110, 99, 3, 0, 11, 4, 0, 14, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 3, 1, 4, 0, 25, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 45, 97, 115, 121, 110, 99, 45, 98, 111, 100, 121, 3, 0, 1, 1, 112, 125, 4, 0, 24, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 45, 115, 121, 110, 99, 45, 98, 111, 100, 121, 3, 0, 15, 1, 105, 6, 1, 105, 5, 1, 106, 1, 17, 1, 18, 1, 64, 1, 4, 110, 97, 109, 101, 115, 0, 19, 4, 0, 26, 91, 115, 116, 97, 116, 105, 99, 93, 98, 117, 99, 107, 101, 116, 46, 111, 112, 101, 110, 45, 98, 117, 99, 107, 101, 116, 1, 20, 1, 105, 9, 1, 64, 0, 0, 21, 4, 0, 41, 91, 115, 116, 97, 116, 105, 99, 93, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 46, 110, 101, 119, 45, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 1, 22, 1, 104, 9, 1, 105, 10, 1, 106, 1, 24, 1, 18, 1, 64, 1, 4, 115, 101, 108, 102, 23, 0, 25, 4, 0, 54, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 46, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 45, 119, 114, 105, 116, 101, 45, 98, 111, 100, 121, 45, 97, 115, 121, 110, 99, 1, 26, 1, 106, 0, 1, 18, 1, 64, 2, 4, 115, 101, 108, 102, 23, 5, 118, 97, 108, 117, 101, 12, 0, 27, 4, 0, 53, 91, 109, 101, 116, 104, 111, 100, 93, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 46, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 45, 119, 114, 105, 116, 101, 45, 98, 111, 100, 121, 45, 115, 121, 110, 99, 1, 28, 1, 104, 13, 1, 106, 1, 16, 1, 18, 1, 64, 1, 4, 115, 101, 108, 102, 29, 0, 30, 4, 0, 50, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 46, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 45, 99, 111, 110, 115, 117, 109, 101, 45, 115, 121, 110, 99, 1, 31, 1, 105, 14, 1, 106, 1, 32, 1, 18, 1, 64, 1, 4, 115, 101, 108, 102, 29, 0, 33, 4, 0, 51, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 46, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 45, 99, 111, 110, 115, 117, 109, 101, 45, 97, 115, 121, 110, 99, 1, 34, 1, 106, 1, 119, 1, 18, 1, 64, 1, 4, 115, 101, 108, 102, 29, 0, 35, 4, 0, 42, 91, 109, 101, 116, 104, 111, 100, 93, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 46, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 45, 115, 105, 122, 101, 1, 36, 3, 1, 25, 119, 97, 115, 105, 58, 107, 101, 121, 118, 97, 108, 117, 101, 47, 116, 121, 112, 101, 115, 64, 48, 46, 49, 46, 48, 5, 9, 2, 3, 0, 4, 6, 98, 117, 99, 107, 101, 116, 2, 3, 0, 4, 5, 101, 114, 114, 111, 114, 2, 3, 0, 4, 3, 107, 101, 121, 2, 3, 0, 4, 14, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 2, 3, 0, 4, 14, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 1, 66, 30, 2, 3, 2, 1, 10, 4, 0, 6, 98, 117, 99, 107, 101, 116, 3, 0, 0, 2, 3, 2, 1, 11, 4, 0, 5, 101, 114, 114, 111, 114, 3, 0, 2, 2, 3, 2, 1, 12, 4, 0, 3, 107, 101, 121, 3, 0, 4, 2, 3, 2, 1, 13, 4, 0, 14, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 3, 0, 6, 2, 3, 2, 1, 14, 4, 0, 14, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 3, 0, 8, 1, 104, 1, 1, 112, 5, 1, 105, 7, 1, 107, 12, 1, 112, 13, 1, 105, 3, 1, 106, 1, 14, 1, 15, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 10, 4, 107, 101, 121, 115, 11, 0, 16, 4, 0, 8, 103, 101, 116, 45, 109, 97, 110, 121, 1, 17, 1, 106, 1, 11, 1, 15, 1, 64, 1, 6, 98, 117, 99, 107, 101, 116, 10, 0, 18, 4, 0, 4, 107, 101, 121, 115, 1, 19, 1, 104, 9, 1, 111, 2, 5, 20, 1, 112, 21, 1, 106, 0, 1, 15, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 10, 10, 107, 101, 121, 45, 118, 97, 108, 117, 101, 115, 22, 0, 23, 4, 0, 8, 115, 101, 116, 45, 109, 97, 110, 121, 1, 24, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 10, 4, 107, 101, 121, 115, 11, 0, 23, 4, 0, 11, 100, 101, 108, 101, 116, 101, 45, 109, 97, 110, 121, 1, 25, 3, 1, 34, 119, 97, 115, 105, 58, 107, 101, 121, 118, 97, 108, 117, 101, 47, 101, 118, 101, 110, 116, 117, 97, 108, 45, 98, 97, 116, 99, 104, 64, 48, 46, 49, 46, 48, 5, 15, 1, 66, 26, 2, 3, 2, 1, 10, 4, 0, 6, 98, 117, 99, 107, 101, 116, 3, 0, 0, 2, 3, 2, 1, 11, 4, 0, 5, 101, 114, 114, 111, 114, 3, 0, 2, 2, 3, 2, 1, 13, 4, 0, 14, 105, 110, 99, 111, 109, 105, 110, 103, 45, 118, 97, 108, 117, 101, 3, 0, 4, 2, 3, 2, 1, 12, 4, 0, 3, 107, 101, 121, 3, 0, 6, 2, 3, 2, 1, 14, 4, 0, 14, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 3, 0, 8, 1, 104, 1, 1, 105, 5, 1, 107, 11, 1, 105, 3, 1, 106, 1, 12, 1, 13, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 10, 3, 107, 101, 121, 7, 0, 14, 4, 0, 3, 103, 101, 116, 1, 15, 1, 104, 9, 1, 106, 0, 1, 13, 1, 64, 3, 6, 98, 117, 99, 107, 101, 116, 10, 3, 107, 101, 121, 7, 14, 111, 117, 116, 103, 111, 105, 110, 103, 45, 118, 97, 108, 117, 101, 16, 0, 17, 4, 0, 3, 115, 101, 116, 1, 18, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 10, 3, 107, 101, 121, 7, 0, 17, 4, 0, 6, 100, 101, 108, 101, 116, 101, 1, 19, 1, 106, 1, 127, 1, 13, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 10, 3, 107, 101, 121, 7, 0, 20, 4, 0, 6, 101, 120, 105, 115, 116, 115, 1, 21, 3, 1, 28, 119, 97, 115, 105, 58, 107, 101, 121, 118, 97, 108, 117, 101, 47, 101, 118, 101, 110, 116, 117, 97, 108, 64, 48, 46, 49, 46, 48, 5, 16, 1, 66, 15, 2, 3, 2, 1, 10, 4, 0, 6, 98, 117, 99, 107, 101, 116, 3, 0, 0, 2, 3, 2, 1, 12, 4, 0, 3, 107, 101, 121, 3, 0, 2, 2, 3, 2, 1, 8, 4, 0, 5, 101, 114, 114, 111, 114, 3, 0, 4, 1, 112, 3, 1, 107, 115, 1, 114, 2, 4, 107, 101, 121, 115, 6, 6, 99, 117, 114, 115, 111, 114, 7, 4, 0, 8, 107, 101, 121, 45, 112, 97, 103, 101, 3, 0, 8, 1, 104, 1, 1, 105, 5, 1, 106, 1, 9, 1, 11, 1, 64, 4, 6, 98, 117, 99, 107, 101, 116, 10, 6, 112, 114, 101, 102, 105, 120, 7, 6, 99, 117, 114, 115, 111, 114, 7, 5, 99, 111, 117, 110, 116, 119, 0, 12, 4, 0, 9, 108, 105, 115, 116, 45, 107, 101, 121, 115, 1, 13, 3, 1, 29, 103, 111, 108, 101, 109, 58, 101, 120, 116, 47, 107, 101, 121, 118, 97, 108, 117, 101, 45, 107, 101, 121, 115, 64, 48, 46, 49, 46, 48, 5, 17, 1, 66, 27, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 115, 3, 107, 101, 121, 115, 1, 0, 4, 0, 6, 100, 101, 108, 101, 116, 101, 1, 0, 1, 112, 115, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 115, 4, 107, 101, 121, 115, 1, 1, 0, 4, 0, 11, 100, 101, 108, 101, 116, 101, 45, 109, 97, 110, 121, 1, 2, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 115, 3, 107, 101, 121, 115, 0, 127, 4, 0, 6, 101, 120, 105, 115, 116, 115, 1, 3, 1, 112, 125, 1, 107, 4, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 115, 3, 107, 101, 121, 115, 0, 5, 4, 0, 3, 103, 101, 116, 1, 6, 1, 64, 1, 6, 98, 117, 99, 107, 101, 116, 115, 0, 1, 4, 0, 8, 103, 101, 116, 45, 107, 101, 121, 115, 1, 7, 1, 112, 4, 1, 107, 8, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 115, 4, 107, 101, 121, 115, 1, 0, 9, 4, 0, 8, 103, 101, 116, 45, 109, 97, 110, 121, 1, 10, 1, 107, 115, 1, 111, 2, 1, 11, 1, 64, 4, 6, 98, 117, 99, 107, 101, 116, 115, 6, 112, 114, 101, 102, 105, 120, 11, 6, 99, 117, 114, 115, 111, 114, 11, 5, 99, 111, 117, 110, 116, 119, 0, 12, 4, 0, 9, 108, 105, 115, 116, 45, 107, 101, 121, 115, 1, 13, 1, 64, 3, 6, 98, 117, 99, 107, 101, 116, 115, 3, 107, 101, 121, 115, 5, 118, 97, 108, 117, 101, 4, 1, 0, 4, 0, 3, 115, 101, 116, 1, 14, 1, 111, 2, 115, 4, 1, 112, 15, 1, 64, 2, 6, 98, 117, 99, 107, 101, 116, 115, 10, 107, 101, 121, 45, 118, 97, 108, 117, 101, 115, 16, 1, 0, 4, 0, 8, 115, 101, 116, 45, 109, 97, 110, 121, 1, 17, 4, 1, 12, 103, 111, 108, 101, 109, 58, 105, 116, 47, 97, 112, 105, 5, 18, 4, 1, 26, 103, 111, 108, 101, 109, 58, 105, 116, 47, 107, 101, 121, 45, 118, 97, 108, 117, 101, 45, 115, 101, 114, 118, 105, 99, 101, 4, 0, 11, 23, 1, 0, 17, 107, 101, 121, 45, 118, 97, 108, 117, 101, 45, 115, 101, 114, 118, 105, 99, 101, 3, 2, 0, 0, 16, 12, 112, 97, 99, 107, 97, 103, 101, 45, 100, 111, 99, 115, 0, 123, 125, 0, 70, 9, 112, 114, 111, 100, 117, 99, 101, 114, 115, 1, 12, 112, 114, 111, 99, 101, 115, 115, 101, 100, 45, 98, 121, 2, 13, 119, 105, 116, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 6, 48, 46, 49, 56, 46, 50, 16, 119, 105, 116, 45, 98, 105, 110, 100, 103, 101, 110, 45, 114, 117, 115, 116, 6, 48, 46, 49, 54, 46, 48];
     
     #[inline(never)]
     #[doc(hidden)]
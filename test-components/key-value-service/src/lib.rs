@@ -1,6 +1,7 @@
 mod bindings;
 
 use crate::bindings::exports::golem::it::api::*;
+use crate::bindings::golem::ext::keyvalue_keys::list_keys;
 use crate::bindings::wasi::keyvalue::eventual_batch::*;
 use crate::bindings::wasi::keyvalue::eventual::{Bucket, OutgoingValue, delete, exists, get, set};
 
@@ -42,6 +43,17 @@ impl Guest for Component {
         keys(&bucket).unwrap()
     }
 
+    fn list_keys(
+        bucket: String,
+        prefix: Option<String>,
+        cursor: Option<String>,
+        count: u64,
+    ) -> (Vec<String>, Option<String>) {
+        let bucket = Bucket::open_bucket(&bucket).unwrap();
+        let page = list_keys(&bucket, prefix.as_deref(), cursor.as_deref(), count).unwrap();
+        (page.keys, page.cursor)
+    }
+
     fn get_many(bucket: String, keys: Vec<String>) -> Option<Vec<Vec<u8>>> {
         let bucket = Bucket::open_bucket(&bucket).unwrap();
         match get_many(&bucket, &keys) {
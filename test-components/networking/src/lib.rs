@@ -3,6 +3,10 @@ mod bindings;
 use crate::bindings::exports::golem::it::api::*;
 use crate::bindings::wasi::sockets::instance_network::*;
 use crate::bindings::wasi::sockets::ip_name_lookup::*;
+use crate::bindings::wasi::sockets::network::{
+    IpAddressFamily, IpSocketAddress, Ipv4SocketAddress,
+};
+use crate::bindings::wasi::sockets::tcp_create_socket::create_tcp_socket;
 
 struct Component;
 
@@ -25,4 +29,39 @@ impl Guest for Component {
 
         result
     }
+
+    fn tcp_echo(port: u16, message: String) -> String {
+        let network = instance_network();
+        let socket = create_tcp_socket(IpAddressFamily::Ipv4).expect("create_tcp_socket");
+        let address = IpSocketAddress::Ipv4(Ipv4SocketAddress {
+            port,
+            address: (127, 0, 0, 1),
+        });
+        socket
+            .start_connect(&network, address)
+            .expect("start_connect");
+        socket.subscribe().block();
+        let (input, output) = socket.finish_connect().expect("finish_connect");
+
+        // Uses the non-blocking write functions, the data is sent by the following blocking read
+        let mut remaining = message.as_bytes();
+        while !remaining.is_empty() {
+            output.subscribe().block();
+            let permit = output.check_write().expect("check_write") as usize;
+            let len = permit.min(remaining.len());
+            output.write(&remaining[..len]).expect("write");
+            remaining = &remaining[len..];
+        }
+        output.flush().expect("flush");
+
+        let mut response = Vec::new();
+        while response.len() < message.len() {
+            let bytes = input
+                .blocking_read((message.len() - response.len()) as u64)
+                .expect("blocking_read");
+            response.extend(bytes);
+        }
+
+        String::from_utf8(response).expect("utf8")
+    }
 }
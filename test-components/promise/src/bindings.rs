@@ -46,17 +46,154 @@ const _: () = {
     }
   };
 };
+const _: () = {
+  
+  #[doc(hidden)]
+  #[export_name = "await-with-timeout"]
+  #[allow(non_snake_case)]
+  unsafe extern "C" fn __export_await_with_timeout(arg0: i64,) -> i32 {
+    #[allow(unused_imports)]
+    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+    
+    // Before executing any other code, use this function to run all static
+    // constructors, if they have not yet been run. This is a hack required
+    // to work around wasi-libc ctors calling import functions to initialize
+    // the environment.
+    //
+    // This functionality will be removed once rust 1.69.0 is stable, at which
+    // point wasi-libc will no longer have this behavior.
+    //
+    // See
+    // https://github.com/bytecodealliance/preview2-prototyping/issues/99
+    // for more details.
+    #[cfg(target_arch="wasm32")]
+    wit_bindgen::rt::run_ctors_once();
+    
+    let result0 = <_GuestImpl as Guest>::await_with_timeout(arg0 as u64);
+    let ptr1 = _RET_AREA.0.as_mut_ptr() as i32;
+    let (t2_0, t2_1, ) = result0;
+    let vec3 = (t2_0.into_bytes()).into_boxed_slice();
+    let ptr3 = vec3.as_ptr() as i32;
+    let len3 = vec3.len() as i32;
+    ::core::mem::forget(vec3);
+    *((ptr1 + 4) as *mut i32) = len3;
+    *((ptr1 + 0) as *mut i32) = ptr3;
+    let vec4 = (t2_1).into_boxed_slice();
+    let ptr4 = vec4.as_ptr() as i32;
+    let len4 = vec4.len() as i32;
+    ::core::mem::forget(vec4);
+    *((ptr1 + 12) as *mut i32) = len4;
+    *((ptr1 + 8) as *mut i32) = ptr4;
+    ptr1
+  }
+  
+  const _: () = {
+    #[doc(hidden)]
+    #[export_name = "cabi_post_await-with-timeout"]
+    #[allow(non_snake_case)]
+    unsafe extern "C" fn __post_return_await_with_timeout(arg0: i32,) {
+      let l0 = *((arg0 + 0) as *const i32);
+      let l1 = *((arg0 + 4) as *const i32);
+      wit_bindgen::rt::dealloc(l0, (l1) as usize, 1);
+      let l2 = *((arg0 + 8) as *const i32);
+      let l3 = *((arg0 + 12) as *const i32);
+      let base4 = l2;
+      let len4 = l3;
+      wit_bindgen::rt::dealloc(base4, (len4 as usize) * 1, 1);
+    }
+  };
+};
+const _: () = {
+  
+  #[doc(hidden)]
+  #[export_name = "receive-messages"]
+  #[allow(non_snake_case)]
+  unsafe extern "C" fn __export_receive_messages(arg0: i32,) -> i32 {
+    #[allow(unused_imports)]
+    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+    
+    // Before executing any other code, use this function to run all static
+    // constructors, if they have not yet been run. This is a hack required
+    // to work around wasi-libc ctors calling import functions to initialize
+    // the environment.
+    //
+    // This functionality will be removed once rust 1.69.0 is stable, at which
+    // point wasi-libc will no longer have this behavior.
+    //
+    // See
+    // https://github.com/bytecodealliance/preview2-prototyping/issues/99
+    // for more details.
+    #[cfg(target_arch="wasm32")]
+    wit_bindgen::rt::run_ctors_once();
+    
+    let result0 = <_GuestImpl as Guest>::receive_messages(arg0 as u32);
+    let ptr1 = _RET_AREA.0.as_mut_ptr() as i32;
+    let vec3 = result0;
+    let len3 = vec3.len() as i32;
+    let layout3 = alloc::Layout::from_size_align_unchecked(vec3.len() * 8, 4);
+    let result3 = if layout3.size() != 0
+    {
+      let ptr = alloc::alloc(layout3);
+      if ptr.is_null()
+      {
+        alloc::handle_alloc_error(layout3);
+      }
+      ptr
+    }else {{
+      ::core::ptr::null_mut()
+    }};
+    for (i, e) in vec3.into_iter().enumerate() {
+      let base = result3 as i32 + (i as i32) * 8;
+      {
+        let vec2 = (e).into_boxed_slice();
+        let ptr2 = vec2.as_ptr() as i32;
+        let len2 = vec2.len() as i32;
+        ::core::mem::forget(vec2);
+        *((base + 4) as *mut i32) = len2;
+        *((base + 0) as *mut i32) = ptr2;
+      }
+    }
+    *((ptr1 + 4) as *mut i32) = len3;
+    *((ptr1 + 0) as *mut i32) = result3 as i32;
+    ptr1
+  }
+  
+  const _: () = {
+    #[doc(hidden)]
+    #[export_name = "cabi_post_receive-messages"]
+    #[allow(non_snake_case)]
+    unsafe extern "C" fn __post_return_receive_messages(arg0: i32,) {
+      let l3 = *((arg0 + 0) as *const i32);
+      let l4 = *((arg0 + 4) as *const i32);
+      let base5 = l3;
+      let len5 = l4;
+      for i in 0..len5 {
+        let base = base5 + i *8;
+        {
+          let l0 = *((base + 0) as *const i32);
+          let l1 = *((base + 4) as *const i32);
+          let base2 = l0;
+          let len2 = l1;
+          wit_bindgen::rt::dealloc(base2, (len2 as usize) * 1, 1);
+        }
+      }
+      wit_bindgen::rt::dealloc(base5, (len5 as usize) * 8, 4);
+    }
+  };
+};
 use super::Component as _GuestImpl;
 pub trait Guest {
   fn run() -> wit_bindgen::rt::vec::Vec::<u8>;
+  fn await_with_timeout(timeout_seconds: u64,) -> (wit_bindgen::rt::string::String,wit_bindgen::rt::vec::Vec::<u8>,);
+  fn receive_messages(count: u32,) -> wit_bindgen::rt::vec::Vec::<wit_bindgen::rt::vec::Vec::<u8>>;
 }
 
 #[allow(unused_imports)]
 use wit_bindgen::rt::{alloc, vec::Vec, string::String};
 
 #[repr(align(4))]
-struct _RetArea([u8; 8]);
-static mut _RET_AREA: _RetArea = _RetArea([0; 8]);
+struct _RetArea([u8; 16]);
+static mut _RET_AREA: _RetArea = _RetArea([0; 16]);
 pub mod golem {
   pub mod api {
     
@@ -1276,6 +1413,289 @@ pub mod golem {
       
     }
     
+  }
+  pub mod ext {
+    
+    #[allow(clippy::all)]
+    pub mod promises {
+      #[used]
+      #[doc(hidden)]
+      #[cfg(target_arch = "wasm32")]
+      static __FORCE_SECTION_REF: fn() = super::super::super::__link_section;
+      pub type Datetime = super::super::super::wasi::clocks::wall_clock::Datetime;
+      pub type PromiseId = super::super::super::golem::api::host::PromiseId;
+      /// The outcome of a promise which is no longer pending
+      #[derive(Clone)]
+      pub enum PromiseResult{
+        /// The promise was completed with the given data
+        Completed(wit_bindgen::rt::vec::Vec::<u8>),
+        /// The promise was rejected with the given error payload
+        Failed(wit_bindgen::rt::vec::Vec::<u8>),
+        /// The deadline of the promise passed before it was completed
+        TimedOut,
+      }
+      impl ::core::fmt::Debug for PromiseResult {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          match self {
+            PromiseResult::Completed(e) => {
+              f.debug_tuple("PromiseResult::Completed").field(e).finish()
+            }
+            PromiseResult::Failed(e) => {
+              f.debug_tuple("PromiseResult::Failed").field(e).finish()
+            }
+            PromiseResult::TimedOut => {
+              f.debug_tuple("PromiseResult::TimedOut").finish()
+            }
+          }
+        }
+      }
+      #[allow(unused_unsafe, clippy::all)]
+      /// Creates a promise which times out at `deadline` unless it gets completed or rejected before
+      pub fn create_promise_with_deadline(deadline: Datetime,) -> PromiseId{
+        
+        #[allow(unused_imports)]
+        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+        unsafe {
+          
+          #[repr(align(8))]
+          struct RetArea([u8; 32]);
+          let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+          let super::super::super::wasi::clocks::wall_clock::Datetime{ seconds:seconds0, nanoseconds:nanoseconds0, } = deadline;
+          let ptr1 = ret_area.as_mut_ptr() as i32;
+          #[cfg(target_arch = "wasm32")]
+          #[link(wasm_import_module = "golem:ext/promises@0.1.0")]
+          extern "C" {
+            #[link_name = "create-promise-with-deadline"]
+            fn wit_import(_: i64, _: i32, _: i32, );
+          }
+          
+          #[cfg(not(target_arch = "wasm32"))]
+          fn wit_import(_: i64, _: i32, _: i32, ){ unreachable!() }
+          wit_import(wit_bindgen::rt::as_i64(seconds0), wit_bindgen::rt::as_i32(nanoseconds0), ptr1);
+          let l2 = *((ptr1 + 0) as *const i64);
+          let l3 = *((ptr1 + 8) as *const i64);
+          let l4 = *((ptr1 + 16) as *const i32);
+          let l5 = *((ptr1 + 20) as *const i32);
+          let len6 = l5 as usize;
+          let bytes6 = Vec::from_raw_parts(l4 as *mut _, len6, len6);
+          let l7 = *((ptr1 + 24) as *const i64);
+          super::super::super::golem::api::host::PromiseId{
+            worker_id: super::super::super::golem::api::host::WorkerId{
+              component_id: super::super::super::golem::api::host::ComponentId{
+                uuid: super::super::super::golem::api::host::Uuid{
+                  high_bits: l2 as u64,
+                  low_bits: l3 as u64,
+                },
+              },
+              worker_name: wit_bindgen::rt::string_lift(bytes6),
+            },
+            oplog_idx: l7 as u64,
+          }
+        }
+      }
+      #[allow(unused_unsafe, clippy::all)]
+      /// Rejects a promise with an error payload. Returns false if it was already completed.
+      pub fn reject_promise(promise_id: &PromiseId,error: &[u8],) -> bool{
+        
+        #[allow(unused_imports)]
+        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+        unsafe {
+          let super::super::super::golem::api::host::PromiseId{ worker_id:worker_id0, oplog_idx:oplog_idx0, } = promise_id;
+          let super::super::super::golem::api::host::WorkerId{ component_id:component_id1, worker_name:worker_name1, } = worker_id0;
+          let super::super::super::golem::api::host::ComponentId{ uuid:uuid2, } = component_id1;
+          let super::super::super::golem::api::host::Uuid{ high_bits:high_bits3, low_bits:low_bits3, } = uuid2;
+          let vec4 = worker_name1;
+          let ptr4 = vec4.as_ptr() as i32;
+          let len4 = vec4.len() as i32;
+          let vec5 = error;
+          let ptr5 = vec5.as_ptr() as i32;
+          let len5 = vec5.len() as i32;
+          
+          #[cfg(target_arch = "wasm32")]
+          #[link(wasm_import_module = "golem:ext/promises@0.1.0")]
+          extern "C" {
+            #[link_name = "reject-promise"]
+            fn wit_import(_: i64, _: i64, _: i32, _: i32, _: i64, _: i32, _: i32, ) -> i32;
+          }
+          
+          #[cfg(not(target_arch = "wasm32"))]
+          fn wit_import(_: i64, _: i64, _: i32, _: i32, _: i64, _: i32, _: i32, ) -> i32{ unreachable!() }
+          let ret = wit_import(wit_bindgen::rt::as_i64(high_bits3), wit_bindgen::rt::as_i64(low_bits3), ptr4, len4, wit_bindgen::rt::as_i64(oplog_idx0), ptr5, len5);
+          wit_bindgen::rt::bool_lift(ret as u8)
+        }
+      }
+      #[allow(unused_unsafe, clippy::all)]
+      /// Returns the outcome of a promise, suspending the worker until it is no longer pending
+      pub fn await_promise_result(promise_id: &PromiseId,) -> PromiseResult{
+        
+        #[allow(unused_imports)]
+        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+        unsafe {
+          
+          #[repr(align(4))]
+          struct RetArea([u8; 12]);
+          let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+          let super::super::super::golem::api::host::PromiseId{ worker_id:worker_id0, oplog_idx:oplog_idx0, } = promise_id;
+          let super::super::super::golem::api::host::WorkerId{ component_id:component_id1, worker_name:worker_name1, } = worker_id0;
+          let super::super::super::golem::api::host::ComponentId{ uuid:uuid2, } = component_id1;
+          let super::super::super::golem::api::host::Uuid{ high_bits:high_bits3, low_bits:low_bits3, } = uuid2;
+          let vec4 = worker_name1;
+          let ptr4 = vec4.as_ptr() as i32;
+          let len4 = vec4.len() as i32;
+          let ptr5 = ret_area.as_mut_ptr() as i32;
+          #[cfg(target_arch = "wasm32")]
+          #[link(wasm_import_module = "golem:ext/promises@0.1.0")]
+          extern "C" {
+            #[link_name = "await-promise-result"]
+            fn wit_import(_: i64, _: i64, _: i32, _: i32, _: i64, _: i32, );
+          }
+          
+          #[cfg(not(target_arch = "wasm32"))]
+          fn wit_import(_: i64, _: i64, _: i32, _: i32, _: i64, _: i32, ){ unreachable!() }
+          wit_import(wit_bindgen::rt::as_i64(high_bits3), wit_bindgen::rt::as_i64(low_bits3), ptr4, len4, wit_bindgen::rt::as_i64(oplog_idx0), ptr5);
+          let l6 = i32::from(*((ptr5 + 0) as *const u8));
+          let v13 = match l6 {
+            0 => {
+              let e13 = {
+                let l7 = *((ptr5 + 4) as *const i32);
+                let l8 = *((ptr5 + 8) as *const i32);
+                let len9 = l8 as usize;
+                
+                Vec::from_raw_parts(l7 as *mut _, len9, len9)
+              };
+              PromiseResult::Completed(e13)
+            }
+            1 => {
+              let e13 = {
+                let l10 = *((ptr5 + 4) as *const i32);
+                let l11 = *((ptr5 + 8) as *const i32);
+                let len12 = l11 as usize;
+                
+                Vec::from_raw_parts(l10 as *mut _, len12, len12)
+              };
+              PromiseResult::Failed(e13)
+            }
+            n => {
+              debug_assert_eq!(n, 2, "invalid enum discriminant");
+              PromiseResult::TimedOut
+            }
+          };
+          v13
+        }
+      }
+      
+    }
+    
+    
+    #[allow(clippy::all)]
+    pub mod mailbox {
+      #[used]
+      #[doc(hidden)]
+      #[cfg(target_arch = "wasm32")]
+      static __FORCE_SECTION_REF: fn() = super::super::super::__link_section;
+      pub type WorkerId = super::super::super::golem::api::host::WorkerId;
+      #[allow(unused_unsafe, clippy::all)]
+      /// Appends a message to the mailbox of a worker. The message is delivered only once, even if
+      /// the sending worker gets recovered after a failure during the call.
+      pub fn send(worker_id: &WorkerId,payload: &[u8],){
+        
+        #[allow(unused_imports)]
+        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+        unsafe {
+          let super::super::super::golem::api::host::WorkerId{ component_id:component_id0, worker_name:worker_name0, } = worker_id;
+          let super::super::super::golem::api::host::ComponentId{ uuid:uuid1, } = component_id0;
+          let super::super::super::golem::api::host::Uuid{ high_bits:high_bits2, low_bits:low_bits2, } = uuid1;
+          let vec3 = worker_name0;
+          let ptr3 = vec3.as_ptr() as i32;
+          let len3 = vec3.len() as i32;
+          let vec4 = payload;
+          let ptr4 = vec4.as_ptr() as i32;
+          let len4 = vec4.len() as i32;
+          
+          #[cfg(target_arch = "wasm32")]
+          #[link(wasm_import_module = "golem:ext/mailbox@0.1.0")]
+          extern "C" {
+            #[link_name = "send"]
+            fn wit_import(_: i64, _: i64, _: i32, _: i32, _: i32, _: i32, );
+          }
+          
+          #[cfg(not(target_arch = "wasm32"))]
+          fn wit_import(_: i64, _: i64, _: i32, _: i32, _: i32, _: i32, ){ unreachable!() }
+          wit_import(wit_bindgen::rt::as_i64(high_bits2), wit_bindgen::rt::as_i64(low_bits2), ptr3, len3, ptr4, len4);
+        }
+      }
+      #[allow(unused_unsafe, clippy::all)]
+      /// Returns the next message of the calling worker's mailbox. The worker is suspended until a
+      /// message arrives if its mailbox is empty. Received messages are removed from the mailbox
+      /// once their receiving has been recorded in the worker's oplog.
+      pub fn receive() -> wit_bindgen::rt::vec::Vec::<u8>{
+        
+        #[allow(unused_imports)]
+        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+        unsafe {
+          
+          #[repr(align(4))]
+          struct RetArea([u8; 8]);
+          let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+          let ptr0 = ret_area.as_mut_ptr() as i32;
+          #[cfg(target_arch = "wasm32")]
+          #[link(wasm_import_module = "golem:ext/mailbox@0.1.0")]
+          extern "C" {
+            #[link_name = "receive"]
+            fn wit_import(_: i32, );
+          }
+          
+          #[cfg(not(target_arch = "wasm32"))]
+          fn wit_import(_: i32, ){ unreachable!() }
+          wit_import(ptr0);
+          let l1 = *((ptr0 + 0) as *const i32);
+          let l2 = *((ptr0 + 4) as *const i32);
+          let len3 = l2 as usize;
+          Vec::from_raw_parts(l1 as *mut _, len3, len3)
+        }
+      }
+      #[allow(unused_unsafe, clippy::all)]
+      /// Returns the next message of the calling worker's mailbox, or none if it is empty
+      pub fn try_receive() -> Option<wit_bindgen::rt::vec::Vec::<u8>>{
+        
+        #[allow(unused_imports)]
+        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+        unsafe {
+          
+          #[repr(align(4))]
+          struct RetArea([u8; 12]);
+          let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+          let ptr0 = ret_area.as_mut_ptr() as i32;
+          #[cfg(target_arch = "wasm32")]
+          #[link(wasm_import_module = "golem:ext/mailbox@0.1.0")]
+          extern "C" {
+            #[link_name = "try-receive"]
+            fn wit_import(_: i32, );
+          }
+          
+          #[cfg(not(target_arch = "wasm32"))]
+          fn wit_import(_: i32, ){ unreachable!() }
+          wit_import(ptr0);
+          let l1 = i32::from(*((ptr0 + 0) as *const u8));
+          match l1 {
+            0 => None,
+            1 => {
+              let e = {
+                let l2 = *((ptr0 + 4) as *const i32);
+                let l3 = *((ptr0 + 8) as *const i32);
+                let len4 = l3 as usize;
+                
+                Vec::from_raw_parts(l2 as *mut _, len4, len4)
+              };
+              Some(e)
+            }
+            _ => wit_bindgen::rt::invalid_enum_discriminant(),
+          }
+        }
+      }
+      
+    }
+    
   }
   pub mod rpc {
     
@@ -2209,6 +2629,102 @@ pub mod golem {
                 
               }
               
+              
+              #[allow(clippy::all)]
+              pub mod wall_clock {
+                #[used]
+                #[doc(hidden)]
+                #[cfg(target_arch = "wasm32")]
+                static __FORCE_SECTION_REF: fn() = super::super::super::__link_section;
+                /// A time and date in seconds plus nanoseconds.
+                #[repr(C)]
+                #[derive(Clone, Copy)]
+                pub struct Datetime {
+                  pub seconds: u64,
+                  pub nanoseconds: u32,
+                }
+                impl ::core::fmt::Debug for Datetime {
+                  fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_struct("Datetime").field("seconds", &self.seconds).field("nanoseconds", &self.nanoseconds).finish()
+                  }
+                }
+                #[allow(unused_unsafe, clippy::all)]
+                /// Read the current value of the clock.
+                /// 
+                /// This clock is not monotonic, therefore calling this function repeatedly
+                /// will not necessarily produce a sequence of non-decreasing values.
+                /// 
+                /// The returned timestamps represent the number of seconds since
+                /// 1970-01-01T00:00:00Z, also known as [POSIX's Seconds Since the Epoch],
+                /// also known as [Unix Time].
+                /// 
+                /// The nanoseconds field of the output is always less than 1000000000.
+                /// 
+                /// [POSIX's Seconds Since the Epoch]: https://pubs.opengroup.org/onlinepubs/9699919799/xrat/V4_xbd_chap04.html#tag_21_04_16
+                /// [Unix Time]: https://en.wikipedia.org/wiki/Unix_time
+                pub fn now() -> Datetime{
+                  
+                  #[allow(unused_imports)]
+                  use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                  unsafe {
+                    
+                    #[repr(align(8))]
+                    struct RetArea([u8; 16]);
+                    let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                    let ptr0 = ret_area.as_mut_ptr() as i32;
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "wasi:clocks/wall-clock@0.2.0")]
+                    extern "C" {
+                      #[link_name = "now"]
+                      fn wit_import(_: i32, );
+                    }
+                    
+                    #[cfg(not(target_arch = "wasm32"))]
+                    fn wit_import(_: i32, ){ unreachable!() }
+                    wit_import(ptr0);
+                    let l1 = *((ptr0 + 0) as *const i64);
+                    let l2 = *((ptr0 + 8) as *const i32);
+                    Datetime{
+                      seconds: l1 as u64,
+                      nanoseconds: l2 as u32,
+                    }
+                  }
+                }
+                #[allow(unused_unsafe, clippy::all)]
+                /// Query the resolution of the clock.
+                /// 
+                /// The nanoseconds field of the output is always less than 1000000000.
+                pub fn resolution() -> Datetime{
+                  
+                  #[allow(unused_imports)]
+                  use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                  unsafe {
+                    
+                    #[repr(align(8))]
+                    struct RetArea([u8; 16]);
+                    let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                    let ptr0 = ret_area.as_mut_ptr() as i32;
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "wasi:clocks/wall-clock@0.2.0")]
+                    extern "C" {
+                      #[link_name = "resolution"]
+                      fn wit_import(_: i32, );
+                    }
+                    
+                    #[cfg(not(target_arch = "wasm32"))]
+                    fn wit_import(_: i32, ){ unreachable!() }
+                    wit_import(ptr0);
+                    let l1 = *((ptr0 + 0) as *const i64);
+                    let l2 = *((ptr0 + 8) as *const i32);
+                    Datetime{
+                      seconds: l1 as u64,
+                      nanoseconds: l2 as u32,
+                    }
+                  }
+                }
+                
+              }
+              
             }
             pub mod io {
               
@@ -2393,7 +2909,7 @@ pub mod golem {
           #[cfg(target_arch = "wasm32")]
           #[link_section = "component-type:promise"]
           #[doc(hidden)]
-          pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 3190] = [3, 0, 7, 112, 114, 111, 109, 105, 115, 101, 0, 97, 115, 109, 13, 0, 1, 0, 7, 248, 23, 1, 65, 2, 1, 65, 14, 1, 66, 25, 1, 122, 4, 0, 10, 110, 111, 100, 101, 45, 105, 110, 100, 101, 120, 3, 0, 0, 1, 112, 1, 1, 107, 1, 1, 111, 2, 121, 3, 1, 112, 127, 1, 106, 1, 3, 1, 3, 1, 113, 21, 12, 114, 101, 99, 111, 114, 100, 45, 118, 97, 108, 117, 101, 1, 2, 0, 13, 118, 97, 114, 105, 97, 110, 116, 45, 118, 97, 108, 117, 101, 1, 4, 0, 10, 101, 110, 117, 109, 45, 118, 97, 108, 117, 101, 1, 121, 0, 11, 102, 108, 97, 103, 115, 45, 118, 97, 108, 117, 101, 1, 5, 0, 11, 116, 117, 112, 108, 101, 45, 118, 97, 108, 117, 101, 1, 2, 0, 10, 108, 105, 115, 116, 45, 118, 97, 108, 117, 101, 1, 2, 0, 12, 111, 112, 116, 105, 111, 110, 45, 118, 97, 108, 117, 101, 1, 3, 0, 12, 114, 101, 115, 117, 108, 116, 45, 118, 97, 108, 117, 101, 1, 6, 0, 7, 112, 114, 105, 109, 45, 117, 56, 1, 125, 0, 8, 112, 114, 105, 109, 45, 117, 49, 54, 1, 123, 0, 8, 112, 114, 105, 109, 45, 117, 51, 50, 1, 121, 0, 8, 112, 114, 105, 109, 45, 117, 54, 52, 1, 119, 0, 7, 112, 114, 105, 109, 45, 115, 56, 1, 126, 0, 8, 112, 114, 105, 109, 45, 115, 49, 54, 1, 124, 0, 8, 112, 114, 105, 109, 45, 115, 51, 50, 1, 122, 0, 8, 112, 114, 105, 109, 45, 115, 54, 52, 1, 120, 0, 12, 112, 114, 105, 109, 45, 102, 108, 111, 97, 116, 51, 50, 1, 118, 0, 12, 112, 114, 105, 109, 45, 102, 108, 111, 97, 116, 54, 52, 1, 117, 0, 9, 112, 114, 105, 109, 45, 99, 104, 97, 114, 1, 116, 0, 9, 112, 114, 105, 109, 45, 98, 111, 111, 108, 1, 127, 0, 11, 112, 114, 105, 109, 45, 115, 116, 114, 105, 110, 103, 1, 115, 0, 4, 0, 8, 119, 105, 116, 45, 110, 111, 100, 101, 3, 0, 7, 1, 112, 8, 1, 114, 1, 5, 110, 111, 100, 101, 115, 9, 4, 0, 9, 119, 105, 116, 45, 118, 97, 108, 117, 101, 3, 0, 10, 1, 114, 1, 5, 118, 97, 108, 117, 101, 115, 4, 0, 3, 117, 114, 105, 3, 0, 12, 1, 113, 4, 14, 112, 114, 111, 116, 111, 99, 111, 108, 45, 101, 114, 114, 111, 114, 1, 115, 0, 6, 100, 101, 110, 105, 101, 100, 1, 115, 0, 9, 110, 111, 116, 45, 102, 111, 117, 110, 100, 1, 115, 0, 21, 114, 101, 109, 111, 116, 101, 45, 105, 110, 116, 101, 114, 110, 97, 108, 45, 101, 114, 114, 111, 114, 1, 115, 0, 4, 0, 9, 114, 112, 99, 45, 101, 114, 114, 111, 114, 3, 0, 14, 4, 0, 8, 119, 97, 115, 109, 45, 114, 112, 99, 3, 1, 1, 105, 16, 1, 64, 1, 8, 108, 111, 99, 97, 116, 105, 111, 110, 13, 0, 17, 4, 0, 21, 91, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 93, 119, 97, 115, 109, 45, 114, 112, 99, 1, 18, 1, 104, 16, 1, 112, 11, 1, 106, 1, 11, 1, 15, 1, 64, 3, 4, 115, 101, 108, 102, 19, 13, 102, 117, 110, 99, 116, 105, 111, 110, 45, 110, 97, 109, 101, 115, 15, 102, 117, 110, 99, 116, 105, 111, 110, 45, 112, 97, 114, 97, 109, 115, 20, 0, 21, 4, 0, 33, 91, 109, 101, 116, 104, 111, 100, 93, 119, 97, 115, 109, 45, 114, 112, 99, 46, 105, 110, 118, 111, 107, 101, 45, 97, 110, 100, 45, 97, 119, 97, 105, 116, 1, 22, 3, 1, 21, 103, 111, 108, 101, 109, 58, 114, 112, 99, 47, 116, 121, 112, 101, 115, 64, 48, 46, 49, 46, 48, 5, 0, 1, 66, 10, 4, 0, 8, 112, 111, 108, 108, 97, 98, 108, 101, 3, 1, 1, 104, 0, 1, 64, 1, 4, 115, 101, 108, 102, 1, 0, 127, 4, 0, 22, 91, 109, 101, 116, 104, 111, 100, 93, 112, 111, 108, 108, 97, 98, 108, 101, 46, 114, 101, 97, 100, 121, 1, 2, 1, 64, 1, 4, 115, 101, 108, 102, 1, 1, 0, 4, 0, 22, 91, 109, 101, 116, 104, 111, 100, 93, 112, 111, 108, 108, 97, 98, 108, 101, 46, 98, 108, 111, 99, 107, 1, 3, 1, 112, 1, 1, 112, 121, 1, 64, 1, 2, 105, 110, 4, 0, 5, 4, 0, 4, 112, 111, 108, 108, 1, 6, 3, 1, 18, 119, 97, 115, 105, 58, 105, 111, 47, 112, 111, 108, 108, 64, 48, 46, 50, 46, 48, 5, 1, 2, 3, 0, 1, 8, 112, 111, 108, 108, 97, 98, 108, 101, 1, 66, 15, 2, 3, 2, 1, 2, 4, 0, 8, 112, 111, 108, 108, 97, 98, 108, 101, 3, 0, 0, 1, 119, 4, 0, 7, 105, 110, 115, 116, 97, 110, 116, 3, 0, 2, 1, 119, 4, 0, 8, 100, 117, 114, 97, 116, 105, 111, 110, 3, 0, 4, 1, 64, 0, 0, 3, 4, 0, 3, 110, 111, 119, 1, 6, 1, 64, 0, 0, 5, 4, 0, 10, 114, 101, 115, 111, 108, 117, 116, 105, 111, 110, 1, 7, 1, 105, 1, 1, 64, 1, 4, 119, 104, 101, 110, 3, 0, 8, 4, 0, 17, 115, 117, 98, 115, 99, 114, 105, 98, 101, 45, 105, 110, 115, 116, 97, 110, 116, 1, 9, 1, 64, 1, 4, 119, 104, 101, 110, 5, 0, 8, 4, 0, 18, 115, 117, 98, 115, 99, 114, 105, 98, 101, 45, 100, 117, 114, 97, 116, 105, 111, 110, 1, 10, 3, 1, 33, 119, 97, 115, 105, 58, 99, 108, 111, 99, 107, 115, 47, 109, 111, 110, 111, 116, 111, 110, 105, 99, 45, 99, 108, 111, 99, 107, 64, 48, 46, 50, 46, 48, 5, 3, 2, 3, 0, 0, 3, 117, 114, 105, 2, 3, 0, 2, 8, 100, 117, 114, 97, 116, 105, 111, 110, 1, 66, 97, 2, 3, 2, 1, 4, 4, 0, 3, 117, 114, 105, 3, 0, 0, 2, 3, 2, 1, 5, 4, 0, 8, 100, 117, 114, 97, 116, 105, 111, 110, 3, 0, 2, 1, 119, 4, 0, 11, 111, 112, 108, 111, 103, 45, 105, 110, 100, 101, 120, 3, 0, 4, 1, 119, 4, 0, 17, 99, 111, 109, 112, 111, 110, 101, 110, 116, 45, 118, 101, 114, 115, 105, 111, 110, 3, 0, 6, 1, 114, 2, 9, 104, 105, 103, 104, 45, 98, 105, 116, 115, 119, 8, 108, 111, 119, 45, 98, 105, 116, 115, 119, 4, 0, 4, 117, 117, 105, 100, 3, 0, 8, 1, 114, 1, 4, 117, 117, 105, 100, 9, 4, 0, 12, 99, 111, 109, 112, 111, 110, 101, 110, 116, 45, 105, 100, 3, 0, 10, 1, 114, 2, 12, 99, 111, 109, 112, 111, 110, 101, 110, 116, 45, 105, 100, 11, 11, 119, 111, 114, 107, 101, 114, 45, 110, 97, 109, 101, 115, 4, 0, 9, 119, 111, 114, 107, 101, 114, 45, 105, 100, 3, 0, 12, 1, 114, 2, 9, 119, 111, 114, 107, 101, 114, 45, 105, 100, 13, 9, 111, 112, 108, 111, 103, 45, 105, 100, 120, 5, 4, 0, 10, 112, 114, 111, 109, 105, 115, 101, 45, 105, 100, 3, 0, 14, 1, 114, 4, 12, 109, 97, 120, 45, 97, 116, 116, 101, 109, 112, 116, 115, 121, 9, 109, 105, 110, 45, 100, 101, 108, 97, 121, 3, 9, 109, 97, 120, 45, 100, 101, 108, 97, 121, 3, 10, 109, 117, 108, 116, 105, 112, 108, 105, 101, 114, 121, 4, 0, 12, 114, 101, 116, 114, 121, 45, 112, 111, 108, 105, 99, 121, 3, 0, 16, 1, 113, 3, 15, 112, 101, 114, 115, 105, 115, 116, 45, 110, 111, 116, 104, 105, 110, 103, 0, 0, 27, 112, 101, 114, 115, 105, 115, 116, 45, 114, 101, 109, 111, 116, 101, 45, 115, 105, 100, 101, 45, 101, 102, 102, 101, 99, 116, 115, 0, 0, 5, 115, 109, 97, 114, 116, 0, 0, 4, 0, 17, 112, 101, 114, 115, 105, 115, 116, 101, 110, 99, 101, 45, 108, 101, 118, 101, 108, 3, 0, 18, 1, 109, 2, 9, 97, 117, 116, 111, 109, 97, 116, 105, 99, 14, 115, 110, 97, 112, 115, 104, 111, 116, 45, 98, 97, 115, 101, 100, 4, 0, 11, 117, 112, 100, 97, 116, 101, 45, 109, 111, 100, 101, 3, 0, 20, 1, 109, 6, 5, 101, 113, 117, 97, 108, 9, 110, 111, 116, 45, 101, 113, 117, 97, 108, 13, 103, 114, 101, 97, 116, 101, 114, 45, 101, 113, 117, 97, 108, 7, 103, 114, 101, 97, 116, 101, 114, 10, 108, 101, 115, 115, 45, 101, 113, 117, 97, 108, 4, 108, 101, 115, 115, 4, 0, 17, 102, 105, 108, 116, 101, 114, 45, 99, 111, 109, 112, 97, 114, 97, 116, 111, 114, 3, 0, 22, 1, 109, 4, 5, 101, 113, 117, 97, 108, 9, 110, 111, 116, 45, 101, 113, 117, 97, 108, 4, 108, 105, 107, 101, 8, 110, 111, 116, 45, 108, 105, 107, 101, 4, 0, 24, 115, 116, 114, 105, 110, 103, 45, 102, 105, 108, 116, 101, 114, 45, 99, 111, 109, 112, 97, 114, 97, 116, 111, 114, 3, 0, 24, 1, 109, 7, 7, 114, 117, 110, 110, 105, 110, 103, 4, 105, 100, 108, 101, 9, 115, 117, 115, 112, 101, 110, 100, 101, 100, 11, 105, 110, 116, 101, 114, 114, 117, 112, 116, 101, 100, 8, 114, 101, 116, 114, 121, 105, 110, 103, 6, 102, 97, 105, 108, 101, 100, 6, 101, 120, 105, 116, 101, 100, 4, 0, 13, 119, 111, 114, 107, 101, 114, 45, 115, 116, 97, 116, 117, 115, 3, 0, 26, 1, 114, 2, 10, 99, 111, 109, 112, 97, 114, 97, 116, 111, 114, 25, 5, 118, 97, 108, 117, 101, 115, 4, 0, 18, 119, 111, 114, 107, 101, 114, 45, 110, 97, 109, 101, 45, 102, 105, 108, 116, 101, 114, 3, 0, 28, 1, 114, 2, 10, 99, 111, 109, 112, 97, 114, 97, 116, 111, 114, 23, 5, 118, 97, 108, 117, 101, 27, 4, 0, 20, 119, 111, 114, 107, 101, 114, 45, 115, 116, 97, 116, 117, 115, 45, 102, 105, 108, 116, 101, 114, 3, 0, 30, 1, 114, 2, 10, 99, 111, 109, 112, 97, 114, 97, 116, 111, 114, 23, 5, 118, 97, 108, 117, 101, 119, 4, 0, 21, 119, 111, 114, 107, 101, 114, 45, 118, 101, 114, 115, 105, 111, 110, 45, 102, 105, 108, 116, 101, 114, 3, 0, 32, 1, 114, 2, 10, 99, 111, 109, 112, 97, 114, 97, 116, 111, 114, 23, 5, 118, 97, 108, 117, 101, 119, 4, 0, 24, 119, 111, 114, 107, 101, 114, 45, 99, 114, 101, 97, 116, 101, 100, 45, 97, 116, 45, 102, 105, 108, 116, 101, 114, 3, 0, 34, 1, 114, 3, 4, 110, 97, 109, 101, 115, 10, 99, 111, 109, 112, 97, 114, 97, 116, 111, 114, 25, 5, 118, 97, 108, 117, 101, 115, 4, 0, 17, 119, 111, 114, 107, 101, 114, 45, 101, 110, 118, 45, 102, 105, 108, 116, 101, 114, 3, 0, 36, 1, 113, 5, 4, 110, 97, 109, 101, 1, 29, 0, 6, 115, 116, 97, 116, 117, 115, 1, 31, 0, 7, 118, 101, 114, 115, 105, 111, 110, 1, 33, 0, 10, 99, 114, 101, 97, 116, 101, 100, 45, 97, 116, 1, 35, 0, 3, 101, 110, 118, 1, 37, 0, 4, 0, 22, 119, 111, 114, 107, 101, 114, 45, 112, 114, 111, 112, 101, 114, 116, 121, 45, 102, 105, 108, 116, 101, 114, 3, 0, 38, 1, 112, 39, 1, 114, 1, 7, 102, 105, 108, 116, 101, 114, 115, 40, 4, 0, 17, 119, 111, 114, 107, 101, 114, 45, 97, 108, 108, 45, 102, 105, 108, 116, 101, 114, 3, 0, 41, 1, 112, 42, 1, 114, 1, 7, 102, 105, 108, 116, 101, 114, 115, 43, 4, 0, 17, 119, 111, 114, 107, 101, 114, 45, 97, 110, 121, 45, 102, 105, 108, 116, 101, 114, 3, 0, 44, 1, 112, 115, 1, 111, 2, 115, 115, 1, 112, 47, 1, 114, 6, 9, 119, 111, 114, 107, 101, 114, 45, 105, 100, 13, 4, 97, 114, 103, 115, 46, 3, 101, 110, 118, 48, 6, 115, 116, 97, 116, 117, 115, 27, 17, 99, 111, 109, 112, 111, 110, 101, 110, 116, 45, 118, 101, 114, 115, 105, 111, 110, 119, 11, 114, 101, 116, 114, 121, 45, 99, 111, 117, 110, 116, 119, 4, 0, 15, 119, 111, 114, 107, 101, 114, 45, 109, 101, 116, 97, 100, 97, 116, 97, 3, 0, 49, 4, 0, 11, 103, 101, 116, 45, 119, 111, 114, 107, 101, 114, 115, 3, 1, 1, 107, 45, 1, 105, 51, 1, 64, 3, 12, 99, 111, 109, 112, 111, 110, 101, 110, 116, 45, 105, 100, 11, 6, 102, 105, 108, 116, 101, 114, 52, 7, 112, 114, 101, 99, 105, 115, 101, 127, 0, 53, 4, 0, 24, 91, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 93, 103, 101, 116, 45, 119, 111, 114, 107, 101, 114, 115, 1, 54, 1, 104, 51, 1, 112, 50, 1, 107, 56, 1, 64, 1, 4, 115, 101, 108, 102, 55, 0, 57, 4, 0, 28, 91, 109, 101, 116, 104, 111, 100, 93, 103, 101, 116, 45, 119, 111, 114, 107, 101, 114, 115, 46, 103, 101, 116, 45, 110, 101, 120, 116, 1, 58, 1, 64, 0, 0, 15, 4, 0, 20, 103, 111, 108, 101, 109, 45, 99, 114, 101, 97, 116, 101, 45, 112, 114, 111, 109, 105, 115, 101, 1, 59, 1, 112, 125, 1, 64, 1, 10, 112, 114, 111, 109, 105, 115, 101, 45, 105, 100, 15, 0, 60, 4, 0, 19, 103, 111, 108, 101, 109, 45, 97, 119, 97, 105, 116, 45, 112, 114, 111, 109, 105, 115, 101, 1, 61, 1, 64, 2, 10, 112, 114, 111, 109, 105, 115, 101, 45, 105, 100, 15, 4, 100, 97, 116, 97, 60, 0, 127, 4, 0, 22, 103, 111, 108, 101, 109, 45, 99, 111, 109, 112, 108, 101, 116, 101, 45, 112, 114, 111, 109, 105, 115, 101, 1, 62, 1, 64, 1, 10, 112, 114, 111, 109, 105, 115, 101, 45, 105, 100, 15, 1, 0, 4, 0, 20, 103, 111, 108, 101, 109, 45, 100, 101, 108, 101, 116, 101, 45, 112, 114, 111, 109, 105, 115, 101, 1, 63, 1, 64, 1, 13, 102, 117, 110, 99, 116, 105, 111, 110, 45, 110, 97, 109, 101, 115, 0, 1, 4, 0, 12, 103, 101, 116, 45, 115, 101, 108, 102, 45, 117, 114, 105, 1, 64, 1, 64, 0, 0, 5, 4, 0, 15, 103, 101, 116, 45, 111, 112, 108, 111, 103, 45, 105, 110, 100, 101, 120, 1, 65, 1, 64, 1, 9, 111, 112, 108, 111, 103, 45, 105, 100, 120, 5, 1, 0, 4, 0, 15, 115, 101, 116, 45, 111, 112, 108, 111, 103, 45, 105, 110, 100, 101, 120, 1, 66, 1, 64, 1, 8, 114, 101, 112, 108, 105, 99, 97, 115, 125, 1, 0, 4, 0, 12, 111, 112, 108, 111, 103, 45, 99, 111, 109, 109, 105, 116, 1, 67, 4, 0, 20, 109, 97, 114, 107, 45, 98, 101, 103, 105, 110, 45, 111, 112, 101, 114, 97, 116, 105, 111, 110, 1, 65, 1, 64, 1, 5, 98, 101, 103, 105, 110, 5, 1, 0, 4, 0, 18, 109, 97, 114, 107, 45, 101, 110, 100, 45, 111, 112, 101, 114, 97, 116, 105, 111, 110, 1, 68, 1, 64, 0, 0, 17, 4, 0, 16, 103, 101, 116, 45, 114, 101, 116, 114, 121, 45, 112, 111, 108, 105, 99, 121, 1, 69, 1, 64, 1, 16, 110, 101, 119, 45, 114, 101, 116, 114, 121, 45, 112, 111, 108, 105, 99, 121, 17, 1, 0, 4, 0, 16, 115, 101, 116, 45, 114, 101, 116, 114, 121, 45, 112, 111, 108, 105, 99, 121, 1, 70, 1, 64, 0, 0, 19, 4, 0, 27, 103, 101, 116, 45, 111, 112, 108, 111, 103, 45, 112, 101, 114, 115, 105, 115, 116, 101, 110, 99, 101, 45, 108, 101, 118, 101, 108, 1, 71, 1, 64, 1, 21, 110, 101, 119, 45, 112, 101, 114, 115, 105, 115, 116, 101, 110, 99, 101, 45, 108, 101, 118, 101, 108, 19, 1, 0, 4, 0, 27, 115, 101, 116, 45, 111, 112, 108, 111, 103, 45, 112, 101, 114, 115, 105, 115, 116, 101, 110, 99, 101, 45, 108, 101, 118, 101, 108, 1, 72, 1, 64, 0, 0, 127, 4, 0, 20, 103, 101, 116, 45, 105, 100, 101, 109, 112, 111, 116, 101, 110, 99, 101, 45, 109, 111, 100, 101, 1, 73, 1, 64, 1, 10, 105, 100, 101, 109, 112, 111, 116, 101, 110, 116, 127, 1, 0, 4, 0, 20, 115, 101, 116, 45, 105, 100, 101, 109, 112, 111, 116, 101, 110, 99, 101, 45, 109, 111, 100, 101, 1, 74, 1, 64, 0, 0, 9, 4, 0, 24, 103, 101, 110, 101, 114, 97, 116, 101, 45, 105, 100, 101, 109, 112, 111, 116, 101, 110, 99, 121, 45, 107, 101, 121, 1, 75, 1, 64, 3, 9, 119, 111, 114, 107, 101, 114, 45, 105, 100, 13, 14, 116, 97, 114, 103, 101, 116, 45, 118, 101, 114, 115, 105, 111, 110, 7, 4, 109, 111, 100, 101, 21, 1, 0, 4, 0, 13, 117, 112, 100, 97, 116, 101, 45, 119, 111, 114, 107, 101, 114, 1, 76, 3, 1, 20, 103, 111, 108, 101, 109, 58, 97, 112, 105, 47, 104, 111, 115, 116, 64, 48, 46, 50, 46, 48, 5, 6, 1, 112, 125, 1, 64, 0, 0, 7, 4, 0, 3, 114, 117, 110, 1, 8, 4, 1, 16, 103, 111, 108, 101, 109, 58, 105, 116, 47, 112, 114, 111, 109, 105, 115, 101, 4, 0, 11, 13, 1, 0, 7, 112, 114, 111, 109, 105, 115, 101, 3, 0, 0, 0, 16, 12, 112, 97, 99, 107, 97, 103, 101, 45, 100, 111, 99, 115, 0, 123, 125, 0, 70, 9, 112, 114, 111, 100, 117, 99, 101, 114, 115, 1, 12, 112, 114, 111, 99, 101, 115, 115, 101, 100, 45, 98, 121, 2, 13, 119, 105, 116, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 6, 48, 46, 49, 56, 46, 50, 16, 119, 105, 116, 45, 98, 105, 110, 100, 103, 101, 110, 45, 114, 117, 115, 116, 6, 48, 46, 49, 54, 46, 48];
+          pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 3816] = [3, 0, 7, 112, 114, 111, 109, 105, 115, 101, 0, 97, 115, 109, 13, 0, 1, 0, 7, 234, 28, 1, 65, 2, 1, 65, 29, 1, 66, 25, 1, 122, 4, 0, 10, 110, 111, 100, 101, 45, 105, 110, 100, 101, 120, 3, 0, 0, 1, 112, 1, 1, 107, 1, 1, 111, 2, 121, 3, 1, 112, 127, 1, 106, 1, 3, 1, 3, 1, 113, 21, 12, 114, 101, 99, 111, 114, 100, 45, 118, 97, 108, 117, 101, 1, 2, 0, 13, 118, 97, 114, 105, 97, 110, 116, 45, 118, 97, 108, 117, 101, 1, 4, 0, 10, 101, 110, 117, 109, 45, 118, 97, 108, 117, 101, 1, 121, 0, 11, 102, 108, 97, 103, 115, 45, 118, 97, 108, 117, 101, 1, 5, 0, 11, 116, 117, 112, 108, 101, 45, 118, 97, 108, 117, 101, 1, 2, 0, 10, 108, 105, 115, 116, 45, 118, 97, 108, 117, 101, 1, 2, 0, 12, 111, 112, 116, 105, 111, 110, 45, 118, 97, 108, 117, 101, 1, 3, 0, 12, 114, 101, 115, 117, 108, 116, 45, 118, 97, 108, 117, 101, 1, 6, 0, 7, 112, 114, 105, 109, 45, 117, 56, 1, 125, 0, 8, 112, 114, 105, 109, 45, 117, 49, 54, 1, 123, 0, 8, 112, 114, 105, 109, 45, 117, 51, 50, 1, 121, 0, 8, 112, 114, 105, 109, 45, 117, 54, 52, 1, 119, 0, 7, 112, 114, 105, 109, 45, 115, 56, 1, 126, 0, 8, 112, 114, 105, 109, 45, 115, 49, 54, 1, 124, 0, 8, 112, 114, 105, 109, 45, 115, 51, 50, 1, 122, 0, 8, 112, 114, 105, 109, 45, 115, 54, 52, 1, 120, 0, 12, 112, 114, 105, 109, 45, 102, 108, 111, 97, 116, 51, 50, 1, 118, 0, 12, 112, 114, 105, 109, 45, 102, 108, 111, 97, 116, 54, 52, 1, 117, 0, 9, 112, 114, 105, 109, 45, 99, 104, 97, 114, 1, 116, 0, 9, 112, 114, 105, 109, 45, 98, 111, 111, 108, 1, 127, 0, 11, 112, 114, 105, 109, 45, 115, 116, 114, 105, 110, 103, 1, 115, 0, 4, 0, 8, 119, 105, 116, 45, 110, 111, 100, 101, 3, 0, 7, 1, 112, 8, 1, 114, 1, 5, 110, 111, 100, 101, 115, 9, 4, 0, 9, 119, 105, 116, 45, 118, 97, 108, 117, 101, 3, 0, 10, 1, 114, 1, 5, 118, 97, 108, 117, 101, 115, 4, 0, 3, 117, 114, 105, 3, 0, 12, 1, 113, 4, 14, 112, 114, 111, 116, 111, 99, 111, 108, 45, 101, 114, 114, 111, 114, 1, 115, 0, 6, 100, 101, 110, 105, 101, 100, 1, 115, 0, 9, 110, 111, 116, 45, 102, 111, 117, 110, 100, 1, 115, 0, 21, 114, 101, 109, 111, 116, 101, 45, 105, 110, 116, 101, 114, 110, 97, 108, 45, 101, 114, 114, 111, 114, 1, 115, 0, 4, 0, 9, 114, 112, 99, 45, 101, 114, 114, 111, 114, 3, 0, 14, 4, 0, 8, 119, 97, 115, 109, 45, 114, 112, 99, 3, 1, 1, 105, 16, 1, 64, 1, 8, 108, 111, 99, 97, 116, 105, 111, 110, 13, 0, 17, 4, 0, 21, 91, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 93, 119, 97, 115, 109, 45, 114, 112, 99, 1, 18, 1, 104, 16, 1, 112, 11, 1, 106, 1, 11, 1, 15, 1, 64, 3, 4, 115, 101, 108, 102, 19, 13, 102, 117, 110, 99, 116, 105, 111, 110, 45, 110, 97, 109, 101, 115, 15, 102, 117, 110, 99, 116, 105, 111, 110, 45, 112, 97, 114, 97, 109, 115, 20, 0, 21, 4, 0, 33, 91, 109, 101, 116, 104, 111, 100, 93, 119, 97, 115, 109, 45, 114, 112, 99, 46, 105, 110, 118, 111, 107, 101, 45, 97, 110, 100, 45, 97, 119, 97, 105, 116, 1, 22, 3, 1, 21, 103, 111, 108, 101, 109, 58, 114, 112, 99, 47, 116, 121, 112, 101, 115, 64, 48, 46, 49, 46, 48, 5, 0, 1, 66, 10, 4, 0, 8, 112, 111, 108, 108, 97, 98, 108, 101, 3, 1, 1, 104, 0, 1, 64, 1, 4, 115, 101, 108, 102, 1, 0, 127, 4, 0, 22, 91, 109, 101, 116, 104, 111, 100, 93, 112, 111, 108, 108, 97, 98, 108, 101, 46, 114, 101, 97, 100, 121, 1, 2, 1, 64, 1, 4, 115, 101, 108, 102, 1, 1, 0, 4, 0, 22, 91, 109, 101, 116, 104, 111, 100, 93, 112, 111, 108, 108, 97, 98, 108, 101, 46, 98, 108, 111, 99, 107, 1, 3, 1, 112, 1, 1, 112, 121, 1, 64, 1, 2, 105, 110, 4, 0, 5, 4, 0, 4, 112, 111, 108, 108, 1, 6, 3, 1, 18, 119, 97, 115, 105, 58, 105, 111, 47, 112, 111, 108, 108, 64, 48, 46, 50, 46, 48, 5, 1, 2, 3, 0, 1, 8, 112, 111, 108, 108, 97, 98, 108, 101, 1, 66, 15, 2, 3, 2, 1, 2, 4, 0, 8, 112, 111, 108, 108, 97, 98, 108, 101, 3, 0, 0, 1, 119, 4, 0, 7, 105, 110, 115, 116, 97, 110, 116, 3, 0, 2, 1, 119, 4, 0, 8, 100, 117, 114, 97, 116, 105, 111, 110, 3, 0, 4, 1, 64, 0, 0, 3, 4, 0, 3, 110, 111, 119, 1, 6, 1, 64, 0, 0, 5, 4, 0, 10, 114, 101, 115, 111, 108, 117, 116, 105, 111, 110, 1, 7, 1, 105, 1, 1, 64, 1, 4, 119, 104, 101, 110, 3, 0, 8, 4, 0, 17, 115, 117, 98, 115, 99, 114, 105, 98, 101, 45, 105, 110, 115, 116, 97, 110, 116, 1, 9, 1, 64, 1, 4, 119, 104, 101, 110, 5, 0, 8, 4, 0, 18, 115, 117, 98, 115, 99, 114, 105, 98, 101, 45, 100, 117, 114, 97, 116, 105, 111, 110, 1, 10, 3, 1, 33, 119, 97, 115, 105, 58, 99, 108, 111, 99, 107, 115, 47, 109, 111, 110, 111, 116, 111, 110, 105, 99, 45, 99, 108, 111, 99, 107, 64, 48, 46, 50, 46, 48, 5, 3, 2, 3, 0, 0, 3, 117, 114, 105, 2, 3, 0, 2, 8, 100, 117, 114, 97, 116, 105, 111, 110, 1, 66, 97, 2, 3, 2, 1, 4, 4, 0, 3, 117, 114, 105, 3, 0, 0, 2, 3, 2, 1, 5, 4, 0, 8, 100, 117, 114, 97, 116, 105, 111, 110, 3, 0, 2, 1, 119, 4, 0, 11, 111, 112, 108, 111, 103, 45, 105, 110, 100, 101, 120, 3, 0, 4, 1, 119, 4, 0, 17, 99, 111, 109, 112, 111, 110, 101, 110, 116, 45, 118, 101, 114, 115, 105, 111, 110, 3, 0, 6, 1, 114, 2, 9, 104, 105, 103, 104, 45, 98, 105, 116, 115, 119, 8, 108, 111, 119, 45, 98, 105, 116, 115, 119, 4, 0, 4, 117, 117, 105, 100, 3, 0, 8, 1, 114, 1, 4, 117, 117, 105, 100, 9, 4, 0, 12, 99, 111, 109, 112, 111, 110, 101, 110, 116, 45, 105, 100, 3, 0, 10, 1, 114, 2, 12, 99, 111, 109, 112, 111, 110, 101, 110, 116, 45, 105, 100, 11, 11, 119, 111, 114, 107, 101, 114, 45, 110, 97, 109, 101, 115, 4, 0, 9, 119, 111, 114, 107, 101, 114, 45, 105, 100, 3, 0, 12, 1, 114, 2, 9, 119, 111, 114, 107, 101, 114, 45, 105, 100, 13, 9, 111, 112, 108, 111, 103, 45, 105, 100, 120, 5, 4, 0, 10, 112, 114, 111, 109, 105, 115, 101, 45, 105, 100, 3, 0, 14, 1, 114, 4, 12, 109, 97, 120, 45, 97, 116, 116, 101, 109, 112, 116, 115, 121, 9, 109, 105, 110, 45, 100, 101, 108, 97, 121, 3, 9, 109, 97, 120, 45, 100, 101, 108, 97, 121, 3, 10, 109, 117, 108, 116, 105, 112, 108, 105, 101, 114, 121, 4, 0, 12, 114, 101, 116, 114, 121, 45, 112, 111, 108, 105, 99, 121, 3, 0, 16, 1, 113, 3, 15, 112, 101, 114, 115, 105, 115, 116, 45, 110, 111, 116, 104, 105, 110, 103, 0, 0, 27, 112, 101, 114, 115, 105, 115, 116, 45, 114, 101, 109, 111, 116, 101, 45, 115, 105, 100, 101, 45, 101, 102, 102, 101, 99, 116, 115, 0, 0, 5, 115, 109, 97, 114, 116, 0, 0, 4, 0, 17, 112, 101, 114, 115, 105, 115, 116, 101, 110, 99, 101, 45, 108, 101, 118, 101, 108, 3, 0, 18, 1, 109, 2, 9, 97, 117, 116, 111, 109, 97, 116, 105, 99, 14, 115, 110, 97, 112, 115, 104, 111, 116, 45, 98, 97, 115, 101, 100, 4, 0, 11, 117, 112, 100, 97, 116, 101, 45, 109, 111, 100, 101, 3, 0, 20, 1, 109, 6, 5, 101, 113, 117, 97, 108, 9, 110, 111, 116, 45, 101, 113, 117, 97, 108, 13, 103, 114, 101, 97, 116, 101, 114, 45, 101, 113, 117, 97, 108, 7, 103, 114, 101, 97, 116, 101, 114, 10, 108, 101, 115, 115, 45, 101, 113, 117, 97, 108, 4, 108, 101, 115, 115, 4, 0, 17, 102, 105, 108, 116, 101, 114, 45, 99, 111, 109, 112, 97, 114, 97, 116, 111, 114, 3, 0, 22, 1, 109, 4, 5, 101, 113, 117, 97, 108, 9, 110, 111, 116, 45, 101, 113, 117, 97, 108, 4, 108, 105, 107, 101, 8, 110, 111, 116, 45, 108, 105, 107, 101, 4, 0, 24, 115, 116, 114, 105, 110, 103, 45, 102, 105, 108, 116, 101, 114, 45, 99, 111, 109, 112, 97, 114, 97, 116, 111, 114, 3, 0, 24, 1, 109, 7, 7, 114, 117, 110, 110, 105, 110, 103, 4, 105, 100, 108, 101, 9, 115, 117, 115, 112, 101, 110, 100, 101, 100, 11, 105, 110, 116, 101, 114, 114, 117, 112, 116, 101, 100, 8, 114, 101, 116, 114, 121, 105, 110, 103, 6, 102, 97, 105, 108, 101, 100, 6, 101, 120, 105, 116, 101, 100, 4, 0, 13, 119, 111, 114, 107, 101, 114, 45, 115, 116, 97, 116, 117, 115, 3, 0, 26, 1, 114, 2, 10, 99, 111, 109, 112, 97, 114, 97, 116, 111, 114, 25, 5, 118, 97, 108, 117, 101, 115, 4, 0, 18, 119, 111, 114, 107, 101, 114, 45, 110, 97, 109, 101, 45, 102, 105, 108, 116, 101, 114, 3, 0, 28, 1, 114, 2, 10, 99, 111, 109, 112, 97, 114, 97, 116, 111, 114, 23, 5, 118, 97, 108, 117, 101, 27, 4, 0, 20, 119, 111, 114, 107, 101, 114, 45, 115, 116, 97, 116, 117, 115, 45, 102, 105, 108, 116, 101, 114, 3, 0, 30, 1, 114, 2, 10, 99, 111, 109, 112, 97, 114, 97, 116, 111, 114, 23, 5, 118, 97, 108, 117, 101, 119, 4, 0, 21, 119, 111, 114, 107, 101, 114, 45, 118, 101, 114, 115, 105, 111, 110, 45, 102, 105, 108, 116, 101, 114, 3, 0, 32, 1, 114, 2, 10, 99, 111, 109, 112, 97, 114, 97, 116, 111, 114, 23, 5, 118, 97, 108, 117, 101, 119, 4, 0, 24, 119, 111, 114, 107, 101, 114, 45, 99, 114, 101, 97, 116, 101, 100, 45, 97, 116, 45, 102, 105, 108, 116, 101, 114, 3, 0, 34, 1, 114, 3, 4, 110, 97, 109, 101, 115, 10, 99, 111, 109, 112, 97, 114, 97, 116, 111, 114, 25, 5, 118, 97, 108, 117, 101, 115, 4, 0, 17, 119, 111, 114, 107, 101, 114, 45, 101, 110, 118, 45, 102, 105, 108, 116, 101, 114, 3, 0, 36, 1, 113, 5, 4, 110, 97, 109, 101, 1, 29, 0, 6, 115, 116, 97, 116, 117, 115, 1, 31, 0, 7, 118, 101, 114, 115, 105, 111, 110, 1, 33, 0, 10, 99, 114, 101, 97, 116, 101, 100, 45, 97, 116, 1, 35, 0, 3, 101, 110, 118, 1, 37, 0, 4, 0, 22, 119, 111, 114, 107, 101, 114, 45, 112, 114, 111, 112, 101, 114, 116, 121, 45, 102, 105, 108, 116, 101, 114, 3, 0, 38, 1, 112, 39, 1, 114, 1, 7, 102, 105, 108, 116, 101, 114, 115, 40, 4, 0, 17, 119, 111, 114, 107, 101, 114, 45, 97, 108, 108, 45, 102, 105, 108, 116, 101, 114, 3, 0, 41, 1, 112, 42, 1, 114, 1, 7, 102, 105, 108, 116, 101, 114, 115, 43, 4, 0, 17, 119, 111, 114, 107, 101, 114, 45, 97, 110, 121, 45, 102, 105, 108, 116, 101, 114, 3, 0, 44, 1, 112, 115, 1, 111, 2, 115, 115, 1, 112, 47, 1, 114, 6, 9, 119, 111, 114, 107, 101, 114, 45, 105, 100, 13, 4, 97, 114, 103, 115, 46, 3, 101, 110, 118, 48, 6, 115, 116, 97, 116, 117, 115, 27, 17, 99, 111, 109, 112, 111, 110, 101, 110, 116, 45, 118, 101, 114, 115, 105, 111, 110, 119, 11, 114, 101, 116, 114, 121, 45, 99, 111, 117, 110, 116, 119, 4, 0, 15, 119, 111, 114, 107, 101, 114, 45, 109, 101, 116, 97, 100, 97, 116, 97, 3, 0, 49, 4, 0, 11, 103, 101, 116, 45, 119, 111, 114, 107, 101, 114, 115, 3, 1, 1, 107, 45, 1, 105, 51, 1, 64, 3, 12, 99, 111, 109, 112, 111, 110, 101, 110, 116, 45, 105, 100, 11, 6, 102, 105, 108, 116, 101, 114, 52, 7, 112, 114, 101, 99, 105, 115, 101, 127, 0, 53, 4, 0, 24, 91, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 93, 103, 101, 116, 45, 119, 111, 114, 107, 101, 114, 115, 1, 54, 1, 104, 51, 1, 112, 50, 1, 107, 56, 1, 64, 1, 4, 115, 101, 108, 102, 55, 0, 57, 4, 0, 28, 91, 109, 101, 116, 104, 111, 100, 93, 103, 101, 116, 45, 119, 111, 114, 107, 101, 114, 115, 46, 103, 101, 116, 45, 110, 101, 120, 116, 1, 58, 1, 64, 0, 0, 15, 4, 0, 20, 103, 111, 108, 101, 109, 45, 99, 114, 101, 97, 116, 101, 45, 112, 114, 111, 109, 105, 115, 101, 1, 59, 1, 112, 125, 1, 64, 1, 10, 112, 114, 111, 109, 105, 115, 101, 45, 105, 100, 15, 0, 60, 4, 0, 19, 103, 111, 108, 101, 109, 45, 97, 119, 97, 105, 116, 45, 112, 114, 111, 109, 105, 115, 101, 1, 61, 1, 64, 2, 10, 112, 114, 111, 109, 105, 115, 101, 45, 105, 100, 15, 4, 100, 97, 116, 97, 60, 0, 127, 4, 0, 22, 103, 111, 108, 101, 109, 45, 99, 111, 109, 112, 108, 101, 116, 101, 45, 112, 114, 111, 109, 105, 115, 101, 1, 62, 1, 64, 1, 10, 112, 114, 111, 109, 105, 115, 101, 45, 105, 100, 15, 1, 0, 4, 0, 20, 103, 111, 108, 101, 109, 45, 100, 101, 108, 101, 116, 101, 45, 112, 114, 111, 109, 105, 115, 101, 1, 63, 1, 64, 1, 13, 102, 117, 110, 99, 116, 105, 111, 110, 45, 110, 97, 109, 101, 115, 0, 1, 4, 0, 12, 103, 101, 116, 45, 115, 101, 108, 102, 45, 117, 114, 105, 1, 64, 1, 64, 0, 0, 5, 4, 0, 15, 103, 101, 116, 45, 111, 112, 108, 111, 103, 45, 105, 110, 100, 101, 120, 1, 65, 1, 64, 1, 9, 111, 112, 108, 111, 103, 45, 105, 100, 120, 5, 1, 0, 4, 0, 15, 115, 101, 116, 45, 111, 112, 108, 111, 103, 45, 105, 110, 100, 101, 120, 1, 66, 1, 64, 1, 8, 114, 101, 112, 108, 105, 99, 97, 115, 125, 1, 0, 4, 0, 12, 111, 112, 108, 111, 103, 45, 99, 111, 109, 109, 105, 116, 1, 67, 4, 0, 20, 109, 97, 114, 107, 45, 98, 101, 103, 105, 110, 45, 111, 112, 101, 114, 97, 116, 105, 111, 110, 1, 65, 1, 64, 1, 5, 98, 101, 103, 105, 110, 5, 1, 0, 4, 0, 18, 109, 97, 114, 107, 45, 101, 110, 100, 45, 111, 112, 101, 114, 97, 116, 105, 111, 110, 1, 68, 1, 64, 0, 0, 17, 4, 0, 16, 103, 101, 116, 45, 114, 101, 116, 114, 121, 45, 112, 111, 108, 105, 99, 121, 1, 69, 1, 64, 1, 16, 110, 101, 119, 45, 114, 101, 116, 114, 121, 45, 112, 111, 108, 105, 99, 121, 17, 1, 0, 4, 0, 16, 115, 101, 116, 45, 114, 101, 116, 114, 121, 45, 112, 111, 108, 105, 99, 121, 1, 70, 1, 64, 0, 0, 19, 4, 0, 27, 103, 101, 116, 45, 111, 112, 108, 111, 103, 45, 112, 101, 114, 115, 105, 115, 116, 101, 110, 99, 101, 45, 108, 101, 118, 101, 108, 1, 71, 1, 64, 1, 21, 110, 101, 119, 45, 112, 101, 114, 115, 105, 115, 116, 101, 110, 99, 101, 45, 108, 101, 118, 101, 108, 19, 1, 0, 4, 0, 27, 115, 101, 116, 45, 111, 112, 108, 111, 103, 45, 112, 101, 114, 115, 105, 115, 116, 101, 110, 99, 101, 45, 108, 101, 118, 101, 108, 1, 72, 1, 64, 0, 0, 127, 4, 0, 20, 103, 101, 116, 45, 105, 100, 101, 109, 112, 111, 116, 101, 110, 99, 101, 45, 109, 111, 100, 101, 1, 73, 1, 64, 1, 10, 105, 100, 101, 109, 112, 111, 116, 101, 110, 116, 127, 1, 0, 4, 0, 20, 115, 101, 116, 45, 105, 100, 101, 109, 112, 111, 116, 101, 110, 99, 101, 45, 109, 111, 100, 101, 1, 74, 1, 64, 0, 0, 9, 4, 0, 24, 103, 101, 110, 101, 114, 97, 116, 101, 45, 105, 100, 101, 109, 112, 111, 116, 101, 110, 99, 121, 45, 107, 101, 121, 1, 75, 1, 64, 3, 9, 119, 111, 114, 107, 101, 114, 45, 105, 100, 13, 14, 116, 97, 114, 103, 101, 116, 45, 118, 101, 114, 115, 105, 111, 110, 7, 4, 109, 111, 100, 101, 21, 1, 0, 4, 0, 13, 117, 112, 100, 97, 116, 101, 45, 119, 111, 114, 107, 101, 114, 1, 76, 3, 1, 20, 103, 111, 108, 101, 109, 58, 97, 112, 105, 47, 104, 111, 115, 116, 64, 48, 46, 50, 46, 48, 5, 6, 1, 66, 5, 1, 114, 2, 7, 115, 101, 99, 111, 110, 100, 115, 119, 11, 110, 97, 110, 111, 115, 101, 99, 111, 110, 100, 115, 121, 4, 0, 8, 100, 97, 116, 101, 116, 105, 109, 101, 3, 0, 0, 1, 64, 0, 0, 1, 4, 0, 3, 110, 111, 119, 1, 2, 4, 0, 10, 114, 101, 115, 111, 108, 117, 116, 105, 111, 110, 1, 2, 3, 1, 28, 119, 97, 115, 105, 58, 99, 108, 111, 99, 107, 115, 47, 119, 97, 108, 108, 45, 99, 108, 111, 99, 107, 64, 48, 46, 50, 46, 48, 5, 7, 2, 3, 0, 4, 8, 100, 97, 116, 101, 116, 105, 109, 101, 2, 3, 0, 3, 10, 112, 114, 111, 109, 105, 115, 101, 45, 105, 100, 1, 66, 13, 2, 3, 2, 1, 8, 4, 0, 8, 100, 97, 116, 101, 116, 105, 109, 101, 3, 0, 0, 2, 3, 2, 1, 9, 4, 0, 10, 112, 114, 111, 109, 105, 115, 101, 45, 105, 100, 3, 0, 2, 1, 112, 125, 1, 113, 3, 9, 99, 111, 109, 112, 108, 101, 116, 101, 100, 1, 4, 0, 6, 102, 97, 105, 108, 101, 100, 1, 4, 0, 9, 116, 105, 109, 101, 100, 45, 111, 117, 116, 0, 0, 4, 0, 14, 112, 114, 111, 109, 105, 115, 101, 45, 114, 101, 115, 117, 108, 116, 3, 0, 5, 1, 64, 1, 8, 100, 101, 97, 100, 108, 105, 110, 101, 1, 0, 3, 4, 0, 28, 99, 114, 101, 97, 116, 101, 45, 112, 114, 111, 109, 105, 115, 101, 45, 119, 105, 116, 104, 45, 100, 101, 97, 100, 108, 105, 110, 101, 1, 7, 1, 64, 2, 10, 112, 114, 111, 109, 105, 115, 101, 45, 105, 100, 3, 5, 101, 114, 114, 111, 114, 4, 0, 127, 4, 0, 14, 114, 101, 106, 101, 99, 116, 45, 112, 114, 111, 109, 105, 115, 101, 1, 8, 1, 64, 1, 10, 112, 114, 111, 109, 105, 115, 101, 45, 105, 100, 3, 0, 6, 4, 0, 20, 97, 119, 97, 105, 116, 45, 112, 114, 111, 109, 105, 115, 101, 45, 114, 101, 115, 117, 108, 116, 1, 9, 3, 1, 24, 103, 111, 108, 101, 109, 58, 101, 120, 116, 47, 112, 114, 111, 109, 105, 115, 101, 115, 64, 48, 46, 49, 46, 48, 5, 10, 2, 3, 0, 3, 9, 119, 111, 114, 107, 101, 114, 45, 105, 100, 1, 66, 10, 2, 3, 2, 1, 11, 4, 0, 9, 119, 111, 114, 107, 101, 114, 45, 105, 100, 3, 0, 0, 1, 112, 125, 1, 64, 2, 9, 119, 111, 114, 107, 101, 114, 45, 105, 100, 1, 7, 112, 97, 121, 108, 111, 97, 100, 2, 1, 0, 4, 0, 4, 115, 101, 110, 100, 1, 3, 1, 64, 0, 0, 2, 4, 0, 7, 114, 101, 99, 101, 105, 118, 101, 1, 4, 1, 107, 2, 1, 64, 0, 0, 5, 4, 0, 11, 116, 114, 121, 45, 114, 101, 99, 101, 105, 118, 101, 1, 6, 3, 1, 23, 103, 111, 108, 101, 109, 58, 101, 120, 116, 47, 109, 97, 105, 108, 98, 111, 120, 64, 48, 46, 49, 46, 48, 5, 12, 1, 112, 125, 1, 64, 0, 0, 13, 4, 0, 3, 114, 117, 110, 1, 14, 1, 111, 2, 115, 13, 1, 64, 1, 15, 116, 105, 109, 101, 111, 117, 116, 45, 115, 101, 99, 111, 110, 100, 115, 119, 0, 15, 4, 0, 18, 97, 119, 97, 105, 116, 45, 119, 105, 116, 104, 45, 116, 105, 109, 101, 111, 117, 116, 1, 16, 1, 112, 13, 1, 64, 1, 5, 99, 111, 117, 110, 116, 121, 0, 17, 4, 0, 16, 114, 101, 99, 101, 105, 118, 101, 45, 109, 101, 115, 115, 97, 103, 101, 115, 1, 18, 4, 1, 16, 103, 111, 108, 101, 109, 58, 105, 116, 47, 112, 114, 111, 109, 105, 115, 101, 4, 0, 11, 13, 1, 0, 7, 112, 114, 111, 109, 105, 115, 101, 3, 0, 0, 0, 16, 12, 112, 97, 99, 107, 97, 103, 101, 45, 100, 111, 99, 115, 0, 123, 125, 0, 70, 9, 112, 114, 111, 100, 117, 99, 101, 114, 115, 1, 12, 112, 114, 111, 99, 101, 115, 115, 101, 100, 45, 98, 121, 2, 13, 119, 105, 116, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 6, 48, 46, 49, 56, 46, 50, 16, 119, 105, 116, 45, 98, 105, 110, 100, 103, 101, 110, 45, 114, 117, 115, 116, 6, 48, 46, 49, 54, 46, 48];
           
           #[inline(never)]
           #[doc(hidden)]
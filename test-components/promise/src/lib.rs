@@ -1,6 +1,11 @@
 mod bindings;
 
 use crate::bindings::golem::api::host::*;
+use crate::bindings::golem::ext::mailbox;
+use crate::bindings::golem::ext::promises::{
+    await_promise_result, create_promise_with_deadline, PromiseResult,
+};
+use crate::bindings::wasi::clocks::wall_clock;
 use crate::bindings::Guest;
 
 struct Component;
@@ -10,4 +15,21 @@ impl Guest for Component {
         let promise_id = golem_create_promise();
         golem_await_promise(&promise_id)
     }
+
+    fn await_with_timeout(timeout_seconds: u64) -> (String, Vec<u8>) {
+        let now = wall_clock::now();
+        let promise_id = create_promise_with_deadline(wall_clock::Datetime {
+            seconds: now.seconds + timeout_seconds,
+            nanoseconds: now.nanoseconds,
+        });
+        match await_promise_result(&promise_id) {
+            PromiseResult::Completed(data) => ("completed".to_string(), data),
+            PromiseResult::Failed(error) => ("failed".to_string(), error),
+            PromiseResult::TimedOut => ("timed-out".to_string(), vec![]),
+        }
+    }
+
+    fn receive_messages(count: u32) -> Vec<Vec<u8>> {
+        (0..count).map(|_| mailbox::receive()).collect()
+    }
 }
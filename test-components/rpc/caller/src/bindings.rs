@@ -131,11 +131,185 @@ const _: () = {
     wit_bindgen::rt::as_i64(result0)
   }
 };
+const _: () = {
+  
+  #[doc(hidden)]
+  #[export_name = "test4"]
+  #[allow(non_snake_case)]
+  unsafe extern "C" fn __export_test4() -> i64 {
+    #[allow(unused_imports)]
+    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+    
+    // Before executing any other code, use this function to run all static
+    // constructors, if they have not yet been run. This is a hack required
+    // to work around wasi-libc ctors calling import functions to initialize
+    // the environment.
+    //
+    // This functionality will be removed once rust 1.69.0 is stable, at which
+    // point wasi-libc will no longer have this behavior.
+    //
+    // See
+    // https://github.com/bytecodealliance/preview2-prototyping/issues/99
+    // for more details.
+    #[cfg(target_arch="wasm32")]
+    wit_bindgen::rt::run_ctors_once();
+    
+    let result0 = <_GuestImpl as Guest>::test4();
+    wit_bindgen::rt::as_i64(result0)
+  }
+};
+const _: () = {
+  
+  #[doc(hidden)]
+  #[export_name = "test5"]
+  #[allow(non_snake_case)]
+  unsafe extern "C" fn __export_test5(arg0: i32,) -> i32 {
+    #[allow(unused_imports)]
+    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+    
+    // Before executing any other code, use this function to run all static
+    // constructors, if they have not yet been run. This is a hack required
+    // to work around wasi-libc ctors calling import functions to initialize
+    // the environment.
+    //
+    // This functionality will be removed once rust 1.69.0 is stable, at which
+    // point wasi-libc will no longer have this behavior.
+    //
+    // See
+    // https://github.com/bytecodealliance/preview2-prototyping/issues/99
+    // for more details.
+    #[cfg(target_arch="wasm32")]
+    wit_bindgen::rt::run_ctors_once();
+    
+    let result0 = <_GuestImpl as Guest>::test5(arg0 as u32);
+    wit_bindgen::rt::as_i32(result0)
+  }
+};
+const _: () = {
+  
+  #[doc(hidden)]
+  #[export_name = "test6"]
+  #[allow(non_snake_case)]
+  unsafe extern "C" fn __export_test6() -> i64 {
+    #[allow(unused_imports)]
+    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+    
+    // Before executing any other code, use this function to run all static
+    // constructors, if they have not yet been run. This is a hack required
+    // to work around wasi-libc ctors calling import functions to initialize
+    // the environment.
+    //
+    // This functionality will be removed once rust 1.69.0 is stable, at which
+    // point wasi-libc will no longer have this behavior.
+    //
+    // See
+    // https://github.com/bytecodealliance/preview2-prototyping/issues/99
+    // for more details.
+    #[cfg(target_arch="wasm32")]
+    wit_bindgen::rt::run_ctors_once();
+    
+    let result0 = <_GuestImpl as Guest>::test6();
+    wit_bindgen::rt::as_i64(result0)
+  }
+};
+const _: () = {
+  
+  #[doc(hidden)]
+  #[export_name = "bounce"]
+  #[allow(non_snake_case)]
+  unsafe extern "C" fn __export_bounce(arg0: i32,arg1: i32,) -> i32 {
+    #[allow(unused_imports)]
+    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+    
+    // Before executing any other code, use this function to run all static
+    // constructors, if they have not yet been run. This is a hack required
+    // to work around wasi-libc ctors calling import functions to initialize
+    // the environment.
+    //
+    // This functionality will be removed once rust 1.69.0 is stable, at which
+    // point wasi-libc will no longer have this behavior.
+    //
+    // See
+    // https://github.com/bytecodealliance/preview2-prototyping/issues/99
+    // for more details.
+    #[cfg(target_arch="wasm32")]
+    wit_bindgen::rt::run_ctors_once();
+    
+    let base3 = arg0;
+    let len3 = arg1;
+    let mut result3 = Vec::with_capacity(len3 as usize);
+    for i in 0..len3 {
+      let base = base3 + i * 8;
+      let e3 = {
+        let l0 = *((base + 0) as *const i32);
+        let l1 = *((base + 4) as *const i32);
+        let len2 = l1 as usize;
+        let bytes2 = Vec::from_raw_parts(l0 as *mut _, len2, len2);
+        
+        wit_bindgen::rt::string_lift(bytes2)
+      };
+      result3.push(e3);
+    }
+    wit_bindgen::rt::dealloc(base3, (len3 as usize) * 8, 4);
+    let result4 = <_GuestImpl as Guest>::bounce(result3);
+    let ptr5 = _RET_AREA.0.as_mut_ptr() as i32;
+    let vec6 = (result4.into_bytes()).into_boxed_slice();
+    let ptr6 = vec6.as_ptr() as i32;
+    let len6 = vec6.len() as i32;
+    ::core::mem::forget(vec6);
+    *((ptr5 + 4) as *mut i32) = len6;
+    *((ptr5 + 0) as *mut i32) = ptr6;
+    ptr5
+  }
+  
+  const _: () = {
+    #[doc(hidden)]
+    #[export_name = "cabi_post_bounce"]
+    #[allow(non_snake_case)]
+    unsafe extern "C" fn __post_return_bounce(arg0: i32,) {
+      let l0 = *((arg0 + 0) as *const i32);
+      let l1 = *((arg0 + 4) as *const i32);
+      wit_bindgen::rt::dealloc(l0, (l1) as usize, 1);
+    }
+  };
+};
+const _: () = {
+  
+  #[doc(hidden)]
+  #[export_name = "test7"]
+  #[allow(non_snake_case)]
+  unsafe extern "C" fn __export_test7(arg0: i64,) -> i64 {
+    #[allow(unused_imports)]
+    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+    
+    // Before executing any other code, use this function to run all static
+    // constructors, if they have not yet been run. This is a hack required
+    // to work around wasi-libc ctors calling import functions to initialize
+    // the environment.
+    //
+    // This functionality will be removed once rust 1.69.0 is stable, at which
+    // point wasi-libc will no longer have this behavior.
+    //
+    // See
+    // https://github.com/bytecodealliance/preview2-prototyping/issues/99
+    // for more details.
+    #[cfg(target_arch="wasm32")]
+    wit_bindgen::rt::run_ctors_once();
+    
+    let result0 = <_GuestImpl as Guest>::test7(arg0 as u64);
+    wit_bindgen::rt::as_i64(result0)
+  }
+};
 use super::Component as _GuestImpl;
 pub trait Guest {
   fn test1() -> wit_bindgen::rt::vec::Vec::<(wit_bindgen::rt::string::String,u64,)>;
   fn test2() -> u64;
   fn test3() -> u64;
+  fn test4() -> u64;
+  fn test5(max_concurrency: u32,) -> u32;
+  fn test6() -> u64;
+  fn bounce(path: wit_bindgen::rt::vec::Vec::<wit_bindgen::rt::string::String>,) -> wit_bindgen::rt::string::String;
+  fn test7(secs: u64,) -> u64;
 }
 
 #[allow(unused_imports)]
@@ -145,170 +319,433 @@ use wit_bindgen::rt::{alloc, vec::Vec, string::String};
 struct _RetArea([u8; 8]);
 static mut _RET_AREA: _RetArea = _RetArea([0; 8]);
 pub mod golem {
-  pub mod rpc {
+  pub mod api {
     
     #[allow(clippy::all)]
-    pub mod types {
+    pub mod host {
       #[used]
       #[doc(hidden)]
       #[cfg(target_arch = "wasm32")]
       static __FORCE_SECTION_REF: fn() = super::super::super::__link_section;
-      pub type NodeIndex = i32;
+      pub type Uri = super::super::super::golem::rpc::types::Uri;
+      pub type Duration = super::super::super::wasi::clocks::monotonic_clock::Duration;
+      /// An index into the persistent log storing all performed operations of a worker
+      pub type OplogIndex = u64;
+      /// Represents a Golem component's version
+      pub type ComponentVersion = u64;
+      /// UUID
+      #[repr(C)]
+      #[derive(Clone, Copy)]
+      pub struct Uuid {
+        pub high_bits: u64,
+        pub low_bits: u64,
+      }
+      impl ::core::fmt::Debug for Uuid {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          f.debug_struct("Uuid").field("high-bits", &self.high_bits).field("low-bits", &self.low_bits).finish()
+        }
+      }
+      /// Represents a Golem component
+      #[repr(C)]
+      #[derive(Clone, Copy)]
+      pub struct ComponentId {
+        pub uuid: Uuid,
+      }
+      impl ::core::fmt::Debug for ComponentId {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          f.debug_struct("ComponentId").field("uuid", &self.uuid).finish()
+        }
+      }
+      /// Represents a Golem worker
       #[derive(Clone)]
-      pub struct Uri {
-        pub value: wit_bindgen::rt::string::String,
+      pub struct WorkerId {
+        pub component_id: ComponentId,
+        pub worker_name: wit_bindgen::rt::string::String,
       }
-      impl ::core::fmt::Debug for Uri {
+      impl ::core::fmt::Debug for WorkerId {
         fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-          f.debug_struct("Uri").field("value", &self.value).finish()
+          f.debug_struct("WorkerId").field("component-id", &self.component_id).field("worker-name", &self.worker_name).finish()
         }
       }
+      /// A promise ID is a value that can be passed to an external Golem API to complete that promise
+      /// from an arbitrary external source, while Golem workers can await for this completion.
       #[derive(Clone)]
-      pub enum WitNode{
-        RecordValue(wit_bindgen::rt::vec::Vec::<NodeIndex>),
-        VariantValue((u32,Option<NodeIndex>,)),
-        EnumValue(u32),
-        FlagsValue(wit_bindgen::rt::vec::Vec::<bool>),
-        TupleValue(wit_bindgen::rt::vec::Vec::<NodeIndex>),
-        ListValue(wit_bindgen::rt::vec::Vec::<NodeIndex>),
-        OptionValue(Option<NodeIndex>),
-        ResultValue(Result<Option<NodeIndex>,Option<NodeIndex>>),
-        PrimU8(u8),
-        PrimU16(u16),
-        PrimU32(u32),
-        PrimU64(u64),
-        PrimS8(i8),
-        PrimS16(i16),
-        PrimS32(i32),
-        PrimS64(i64),
-        PrimFloat32(f32),
-        PrimFloat64(f64),
-        PrimChar(char),
-        PrimBool(bool),
-        PrimString(wit_bindgen::rt::string::String),
-        Handle((Uri,u64,)),
-      }
-      impl ::core::fmt::Debug for WitNode {
+      pub struct PromiseId {
+        pub worker_id: WorkerId,
+        pub oplog_idx: OplogIndex,
+      }
+      impl ::core::fmt::Debug for PromiseId {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          f.debug_struct("PromiseId").field("worker-id", &self.worker_id).field("oplog-idx", &self.oplog_idx).finish()
+        }
+      }
+      /// Configures how the executor retries failures
+      #[repr(C)]
+      #[derive(Clone, Copy)]
+      pub struct RetryPolicy {
+        /// The maximum number of retries before the worker becomes permanently failed
+        pub max_attempts: u32,
+        /// The minimum delay between retries (applied to the first retry)
+        pub min_delay: Duration,
+        /// The maximum delay between retries
+        pub max_delay: Duration,
+        /// Multiplier applied to the delay on each retry to implement exponential backoff
+        pub multiplier: u32,
+      }
+      impl ::core::fmt::Debug for RetryPolicy {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          f.debug_struct("RetryPolicy").field("max-attempts", &self.max_attempts).field("min-delay", &self.min_delay).field("max-delay", &self.max_delay).field("multiplier", &self.multiplier).finish()
+        }
+      }
+      /// Configurable persistence level for workers
+      #[derive(Clone, Copy)]
+      pub enum PersistenceLevel{
+        PersistNothing,
+        PersistRemoteSideEffects,
+        Smart,
+      }
+      impl ::core::fmt::Debug for PersistenceLevel {
         fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
           match self {
-            WitNode::RecordValue(e) => {
-              f.debug_tuple("WitNode::RecordValue").field(e).finish()
+            PersistenceLevel::PersistNothing => {
+              f.debug_tuple("PersistenceLevel::PersistNothing").finish()
             }
-            WitNode::VariantValue(e) => {
-              f.debug_tuple("WitNode::VariantValue").field(e).finish()
+            PersistenceLevel::PersistRemoteSideEffects => {
+              f.debug_tuple("PersistenceLevel::PersistRemoteSideEffects").finish()
             }
-            WitNode::EnumValue(e) => {
-              f.debug_tuple("WitNode::EnumValue").field(e).finish()
+            PersistenceLevel::Smart => {
+              f.debug_tuple("PersistenceLevel::Smart").finish()
             }
-            WitNode::FlagsValue(e) => {
-              f.debug_tuple("WitNode::FlagsValue").field(e).finish()
+          }
+        }
+      }
+      /// Describes how to update a worker to a different component version
+      #[repr(u8)]
+      #[derive(Clone, Copy, Eq, PartialEq)]
+      pub enum UpdateMode {
+        /// Automatic update tries to recover the worker using the new component version
+        /// and may fail if there is a divergence.
+        Automatic,
+        /// Manual, snapshot-based update uses a user-defined implementation of the `save-snapshot` interface
+        /// to store the worker's state, and a user-defined implementation of the `load-snapshot` interface to
+        /// load it into the new version.
+        SnapshotBased,
+      }
+      impl ::core::fmt::Debug for UpdateMode {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          match self {
+            UpdateMode::Automatic => {
+              f.debug_tuple("UpdateMode::Automatic").finish()
             }
-            WitNode::TupleValue(e) => {
-              f.debug_tuple("WitNode::TupleValue").field(e).finish()
+            UpdateMode::SnapshotBased => {
+              f.debug_tuple("UpdateMode::SnapshotBased").finish()
             }
-            WitNode::ListValue(e) => {
-              f.debug_tuple("WitNode::ListValue").field(e).finish()
+          }
+        }
+      }
+      
+      impl UpdateMode{
+        pub(crate) unsafe fn _lift(val: u8) -> UpdateMode{
+          if !cfg!(debug_assertions) {
+            return ::core::mem::transmute(val);
+          }
+          
+          match val {
+            0 => UpdateMode::Automatic,
+            1 => UpdateMode::SnapshotBased,
+            
+            _ => panic!("invalid enum discriminant"),
+          }
+        }
+      }
+      
+      #[repr(u8)]
+      #[derive(Clone, Copy, Eq, PartialEq)]
+      pub enum FilterComparator {
+        Equal,
+        NotEqual,
+        GreaterEqual,
+        Greater,
+        LessEqual,
+        Less,
+      }
+      impl ::core::fmt::Debug for FilterComparator {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          match self {
+            FilterComparator::Equal => {
+              f.debug_tuple("FilterComparator::Equal").finish()
             }
-            WitNode::OptionValue(e) => {
-              f.debug_tuple("WitNode::OptionValue").field(e).finish()
+            FilterComparator::NotEqual => {
+              f.debug_tuple("FilterComparator::NotEqual").finish()
             }
-            WitNode::ResultValue(e) => {
-              f.debug_tuple("WitNode::ResultValue").field(e).finish()
+            FilterComparator::GreaterEqual => {
+              f.debug_tuple("FilterComparator::GreaterEqual").finish()
             }
-            WitNode::PrimU8(e) => {
-              f.debug_tuple("WitNode::PrimU8").field(e).finish()
+            FilterComparator::Greater => {
+              f.debug_tuple("FilterComparator::Greater").finish()
             }
-            WitNode::PrimU16(e) => {
-              f.debug_tuple("WitNode::PrimU16").field(e).finish()
+            FilterComparator::LessEqual => {
+              f.debug_tuple("FilterComparator::LessEqual").finish()
             }
-            WitNode::PrimU32(e) => {
-              f.debug_tuple("WitNode::PrimU32").field(e).finish()
+            FilterComparator::Less => {
+              f.debug_tuple("FilterComparator::Less").finish()
             }
-            WitNode::PrimU64(e) => {
-              f.debug_tuple("WitNode::PrimU64").field(e).finish()
+          }
+        }
+      }
+      
+      impl FilterComparator{
+        pub(crate) unsafe fn _lift(val: u8) -> FilterComparator{
+          if !cfg!(debug_assertions) {
+            return ::core::mem::transmute(val);
+          }
+          
+          match val {
+            0 => FilterComparator::Equal,
+            1 => FilterComparator::NotEqual,
+            2 => FilterComparator::GreaterEqual,
+            3 => FilterComparator::Greater,
+            4 => FilterComparator::LessEqual,
+            5 => FilterComparator::Less,
+            
+            _ => panic!("invalid enum discriminant"),
+          }
+        }
+      }
+      
+      #[repr(u8)]
+      #[derive(Clone, Copy, Eq, PartialEq)]
+      pub enum StringFilterComparator {
+        Equal,
+        NotEqual,
+        Like,
+        NotLike,
+      }
+      impl ::core::fmt::Debug for StringFilterComparator {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          match self {
+            StringFilterComparator::Equal => {
+              f.debug_tuple("StringFilterComparator::Equal").finish()
             }
-            WitNode::PrimS8(e) => {
-              f.debug_tuple("WitNode::PrimS8").field(e).finish()
+            StringFilterComparator::NotEqual => {
+              f.debug_tuple("StringFilterComparator::NotEqual").finish()
             }
-            WitNode::PrimS16(e) => {
-              f.debug_tuple("WitNode::PrimS16").field(e).finish()
+            StringFilterComparator::Like => {
+              f.debug_tuple("StringFilterComparator::Like").finish()
             }
-            WitNode::PrimS32(e) => {
-              f.debug_tuple("WitNode::PrimS32").field(e).finish()
+            StringFilterComparator::NotLike => {
+              f.debug_tuple("StringFilterComparator::NotLike").finish()
             }
-            WitNode::PrimS64(e) => {
-              f.debug_tuple("WitNode::PrimS64").field(e).finish()
+          }
+        }
+      }
+      
+      impl StringFilterComparator{
+        pub(crate) unsafe fn _lift(val: u8) -> StringFilterComparator{
+          if !cfg!(debug_assertions) {
+            return ::core::mem::transmute(val);
+          }
+          
+          match val {
+            0 => StringFilterComparator::Equal,
+            1 => StringFilterComparator::NotEqual,
+            2 => StringFilterComparator::Like,
+            3 => StringFilterComparator::NotLike,
+            
+            _ => panic!("invalid enum discriminant"),
+          }
+        }
+      }
+      
+      #[repr(u8)]
+      #[derive(Clone, Copy, Eq, PartialEq)]
+      pub enum WorkerStatus {
+        /// The worker is running an invoked function
+        Running,
+        /// The worker is ready to run an invoked function
+        Idle,
+        /// An invocation is active but waiting for something (sleeping, waiting for a promise)
+        Suspended,
+        /// The last invocation was interrupted but will be resumed
+        Interrupted,
+        /// The last invocation failed and a retry was scheduled
+        Retrying,
+        /// The last invocation failed and the worker can no longer be used
+        Failed,
+        /// The worker exited after a successful invocation and can no longer be invoked
+        Exited,
+      }
+      impl ::core::fmt::Debug for WorkerStatus {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          match self {
+            WorkerStatus::Running => {
+              f.debug_tuple("WorkerStatus::Running").finish()
             }
-            WitNode::PrimFloat32(e) => {
-              f.debug_tuple("WitNode::PrimFloat32").field(e).finish()
+            WorkerStatus::Idle => {
+              f.debug_tuple("WorkerStatus::Idle").finish()
             }
-            WitNode::PrimFloat64(e) => {
-              f.debug_tuple("WitNode::PrimFloat64").field(e).finish()
+            WorkerStatus::Suspended => {
+              f.debug_tuple("WorkerStatus::Suspended").finish()
             }
-            WitNode::PrimChar(e) => {
-              f.debug_tuple("WitNode::PrimChar").field(e).finish()
+            WorkerStatus::Interrupted => {
+              f.debug_tuple("WorkerStatus::Interrupted").finish()
             }
-            WitNode::PrimBool(e) => {
-              f.debug_tuple("WitNode::PrimBool").field(e).finish()
+            WorkerStatus::Retrying => {
+              f.debug_tuple("WorkerStatus::Retrying").finish()
             }
-            WitNode::PrimString(e) => {
-              f.debug_tuple("WitNode::PrimString").field(e).finish()
+            WorkerStatus::Failed => {
+              f.debug_tuple("WorkerStatus::Failed").finish()
             }
-            WitNode::Handle(e) => {
-              f.debug_tuple("WitNode::Handle").field(e).finish()
+            WorkerStatus::Exited => {
+              f.debug_tuple("WorkerStatus::Exited").finish()
             }
           }
         }
       }
+      
+      impl WorkerStatus{
+        pub(crate) unsafe fn _lift(val: u8) -> WorkerStatus{
+          if !cfg!(debug_assertions) {
+            return ::core::mem::transmute(val);
+          }
+          
+          match val {
+            0 => WorkerStatus::Running,
+            1 => WorkerStatus::Idle,
+            2 => WorkerStatus::Suspended,
+            3 => WorkerStatus::Interrupted,
+            4 => WorkerStatus::Retrying,
+            5 => WorkerStatus::Failed,
+            6 => WorkerStatus::Exited,
+            
+            _ => panic!("invalid enum discriminant"),
+          }
+        }
+      }
+      
+      #[derive(Clone)]
+      pub struct WorkerNameFilter {
+        pub comparator: StringFilterComparator,
+        pub value: wit_bindgen::rt::string::String,
+      }
+      impl ::core::fmt::Debug for WorkerNameFilter {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          f.debug_struct("WorkerNameFilter").field("comparator", &self.comparator).field("value", &self.value).finish()
+        }
+      }
+      #[repr(C)]
+      #[derive(Clone, Copy)]
+      pub struct WorkerStatusFilter {
+        pub comparator: FilterComparator,
+        pub value: WorkerStatus,
+      }
+      impl ::core::fmt::Debug for WorkerStatusFilter {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          f.debug_struct("WorkerStatusFilter").field("comparator", &self.comparator).field("value", &self.value).finish()
+        }
+      }
+      #[repr(C)]
+      #[derive(Clone, Copy)]
+      pub struct WorkerVersionFilter {
+        pub comparator: FilterComparator,
+        pub value: u64,
+      }
+      impl ::core::fmt::Debug for WorkerVersionFilter {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          f.debug_struct("WorkerVersionFilter").field("comparator", &self.comparator).field("value", &self.value).finish()
+        }
+      }
+      #[repr(C)]
+      #[derive(Clone, Copy)]
+      pub struct WorkerCreatedAtFilter {
+        pub comparator: FilterComparator,
+        pub value: u64,
+      }
+      impl ::core::fmt::Debug for WorkerCreatedAtFilter {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          f.debug_struct("WorkerCreatedAtFilter").field("comparator", &self.comparator).field("value", &self.value).finish()
+        }
+      }
       #[derive(Clone)]
-      pub struct WitValue {
-        pub nodes: wit_bindgen::rt::vec::Vec::<WitNode>,
+      pub struct WorkerEnvFilter {
+        pub name: wit_bindgen::rt::string::String,
+        pub comparator: StringFilterComparator,
+        pub value: wit_bindgen::rt::string::String,
       }
-      impl ::core::fmt::Debug for WitValue {
+      impl ::core::fmt::Debug for WorkerEnvFilter {
         fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-          f.debug_struct("WitValue").field("nodes", &self.nodes).finish()
+          f.debug_struct("WorkerEnvFilter").field("name", &self.name).field("comparator", &self.comparator).field("value", &self.value).finish()
         }
       }
       #[derive(Clone)]
-      pub enum RpcError{
-        ProtocolError(wit_bindgen::rt::string::String),
-        Denied(wit_bindgen::rt::string::String),
-        NotFound(wit_bindgen::rt::string::String),
-        RemoteInternalError(wit_bindgen::rt::string::String),
+      pub enum WorkerPropertyFilter{
+        Name(WorkerNameFilter),
+        Status(WorkerStatusFilter),
+        Version(WorkerVersionFilter),
+        CreatedAt(WorkerCreatedAtFilter),
+        Env(WorkerEnvFilter),
       }
-      impl ::core::fmt::Debug for RpcError {
+      impl ::core::fmt::Debug for WorkerPropertyFilter {
         fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
           match self {
-            RpcError::ProtocolError(e) => {
-              f.debug_tuple("RpcError::ProtocolError").field(e).finish()
+            WorkerPropertyFilter::Name(e) => {
+              f.debug_tuple("WorkerPropertyFilter::Name").field(e).finish()
+            }
+            WorkerPropertyFilter::Status(e) => {
+              f.debug_tuple("WorkerPropertyFilter::Status").field(e).finish()
             }
-            RpcError::Denied(e) => {
-              f.debug_tuple("RpcError::Denied").field(e).finish()
+            WorkerPropertyFilter::Version(e) => {
+              f.debug_tuple("WorkerPropertyFilter::Version").field(e).finish()
             }
-            RpcError::NotFound(e) => {
-              f.debug_tuple("RpcError::NotFound").field(e).finish()
+            WorkerPropertyFilter::CreatedAt(e) => {
+              f.debug_tuple("WorkerPropertyFilter::CreatedAt").field(e).finish()
             }
-            RpcError::RemoteInternalError(e) => {
-              f.debug_tuple("RpcError::RemoteInternalError").field(e).finish()
+            WorkerPropertyFilter::Env(e) => {
+              f.debug_tuple("WorkerPropertyFilter::Env").field(e).finish()
             }
           }
         }
       }
-      impl ::core::fmt::Display for RpcError {
+      #[derive(Clone)]
+      pub struct WorkerAllFilter {
+        pub filters: wit_bindgen::rt::vec::Vec::<WorkerPropertyFilter>,
+      }
+      impl ::core::fmt::Debug for WorkerAllFilter {
         fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-          write!(f, "{:?}", self)
+          f.debug_struct("WorkerAllFilter").field("filters", &self.filters).finish()
+        }
+      }
+      #[derive(Clone)]
+      pub struct WorkerAnyFilter {
+        pub filters: wit_bindgen::rt::vec::Vec::<WorkerAllFilter>,
+      }
+      impl ::core::fmt::Debug for WorkerAnyFilter {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          f.debug_struct("WorkerAnyFilter").field("filters", &self.filters).finish()
+        }
+      }
+      #[derive(Clone)]
+      pub struct WorkerMetadata {
+        pub worker_id: WorkerId,
+        pub args: wit_bindgen::rt::vec::Vec::<wit_bindgen::rt::string::String>,
+        pub env: wit_bindgen::rt::vec::Vec::<(wit_bindgen::rt::string::String,wit_bindgen::rt::string::String,)>,
+        pub status: WorkerStatus,
+        pub component_version: u64,
+        pub retry_count: u64,
+      }
+      impl ::core::fmt::Debug for WorkerMetadata {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          f.debug_struct("WorkerMetadata").field("worker-id", &self.worker_id).field("args", &self.args).field("env", &self.env).field("status", &self.status).field("component-version", &self.component_version).field("retry-count", &self.retry_count).finish()
         }
       }
-      
-      impl std::error::Error for RpcError {}
       
       #[derive(Debug)]
       #[repr(transparent)]
-      pub struct WasmRpc{
-        handle: wit_bindgen::rt::Resource<WasmRpc>,
+      pub struct GetWorkers{
+        handle: wit_bindgen::rt::Resource<GetWorkers>,
       }
       
-      impl WasmRpc{
+      impl GetWorkers{
         #[doc(hidden)]
         pub unsafe fn from_handle(handle: u32) -> Self {
           Self {
@@ -328,7 +765,7 @@ pub mod golem {
       }
       
       
-      unsafe impl wit_bindgen::rt::WasmResource for WasmRpc{
+      unsafe impl wit_bindgen::rt::WasmResource for GetWorkers{
         #[inline]
         unsafe fn drop(_handle: u32) {
           #[cfg(not(target_arch = "wasm32"))]
@@ -336,9 +773,9 @@ pub mod golem {
           
           #[cfg(target_arch = "wasm32")]
           {
-            #[link(wasm_import_module = "golem:rpc/types@0.1.0")]
+            #[link(wasm_import_module = "golem:api/host@0.2.0")]
             extern "C" {
-              #[link_name = "[resource-drop]wasm-rpc"]
+              #[link_name = "[resource-drop]get-workers"]
               fn drop(_: u32);
             }
             
@@ -347,788 +784,1781 @@ pub mod golem {
         }
       }
       
-      impl WasmRpc {
-        #[allow(unused_unsafe, clippy::all)]
-        pub fn new(location: &Uri,) -> Self{
-          
-          #[allow(unused_imports)]
-          use wit_bindgen::rt::{alloc, vec::Vec, string::String};
-          unsafe {
-            let Uri{ value:value0, } = location;
-            let vec1 = value0;
-            let ptr1 = vec1.as_ptr() as i32;
-            let len1 = vec1.len() as i32;
-            
-            #[cfg(target_arch = "wasm32")]
-            #[link(wasm_import_module = "golem:rpc/types@0.1.0")]
-            extern "C" {
-              #[link_name = "[constructor]wasm-rpc"]
-              fn wit_import(_: i32, _: i32, ) -> i32;
-            }
-            
-            #[cfg(not(target_arch = "wasm32"))]
-            fn wit_import(_: i32, _: i32, ) -> i32{ unreachable!() }
-            let ret = wit_import(ptr1, len1);
-            WasmRpc::from_handle(ret as u32)
-          }
-        }
-      }
-      impl WasmRpc {
+      impl GetWorkers {
         #[allow(unused_unsafe, clippy::all)]
-        pub fn invoke_and_await(&self,function_name: &str,function_params: &[WitValue],) -> Result<WitValue,RpcError>{
+        pub fn new(component_id: ComponentId,filter: Option<&WorkerAnyFilter>,precise: bool,) -> Self{
           
           #[allow(unused_imports)]
           use wit_bindgen::rt::{alloc, vec::Vec, string::String};
           unsafe {
             let mut cleanup_list = Vec::new();
-            
-            #[repr(align(4))]
-            struct RetArea([u8; 16]);
-            let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
-            let vec0 = function_name;
-            let ptr0 = vec0.as_ptr() as i32;
-            let len0 = vec0.len() as i32;
-            let vec12 = function_params;
-            let len12 = vec12.len() as i32;
-            let layout12 = alloc::Layout::from_size_align_unchecked(vec12.len() * 8, 4);
-            let result12 = if layout12.size() != 0
-            {
-              let ptr = alloc::alloc(layout12);
-              if ptr.is_null()
-              {
-                alloc::handle_alloc_error(layout12);
-              }
-              ptr
-            }else {{
-              ::core::ptr::null_mut()
-            }};
-            for (i, e) in vec12.into_iter().enumerate() {
-              let base = result12 as i32 + (i as i32) * 8;
-              {
-                let WitValue{ nodes:nodes1, } = e;
-                let vec11 = nodes1;
-                let len11 = vec11.len() as i32;
-                let layout11 = alloc::Layout::from_size_align_unchecked(vec11.len() * 24, 8);
-                let result11 = if layout11.size() != 0
+            let ComponentId{ uuid:uuid0, } = component_id;
+            let Uuid{ high_bits:high_bits1, low_bits:low_bits1, } = uuid0;
+            let (result14_0,result14_1,result14_2,) = match filter {
+              Some(e) => {
+                let WorkerAnyFilter{ filters:filters2, } = e;
+                let vec13 = filters2;
+                let len13 = vec13.len() as i32;
+                let layout13 = alloc::Layout::from_size_align_unchecked(vec13.len() * 8, 4);
+                let result13 = if layout13.size() != 0
                 {
-                  let ptr = alloc::alloc(layout11);
+                  let ptr = alloc::alloc(layout13);
                   if ptr.is_null()
                   {
-                    alloc::handle_alloc_error(layout11);
+                    alloc::handle_alloc_error(layout13);
                   }
                   ptr
                 }else {{
                   ::core::ptr::null_mut()
                 }};
-                for (i, e) in vec11.into_iter().enumerate() {
-                  let base = result11 as i32 + (i as i32) * 24;
+                for (i, e) in vec13.into_iter().enumerate() {
+                  let base = result13 as i32 + (i as i32) * 8;
                   {
-                    match e {
-                      WitNode::RecordValue(e) => {
-                        *((base + 0) as *mut u8) = (0i32) as u8;
-                        let vec2 = e;
-                        let ptr2 = vec2.as_ptr() as i32;
-                        let len2 = vec2.len() as i32;
-                        *((base + 12) as *mut i32) = len2;
-                        *((base + 8) as *mut i32) = ptr2;
-                      },
-                      WitNode::VariantValue(e) => {
-                        *((base + 0) as *mut u8) = (1i32) as u8;
-                        let (t3_0, t3_1, ) = e;
-                        *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(t3_0);
-                        match t3_1 {
-                          Some(e) => {
-                            *((base + 12) as *mut u8) = (1i32) as u8;
-                            *((base + 16) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                    let WorkerAllFilter{ filters:filters3, } = e;
+                    let vec12 = filters3;
+                    let len12 = vec12.len() as i32;
+                    let layout12 = alloc::Layout::from_size_align_unchecked(vec12.len() * 32, 8);
+                    let result12 = if layout12.size() != 0
+                    {
+                      let ptr = alloc::alloc(layout12);
+                      if ptr.is_null()
+                      {
+                        alloc::handle_alloc_error(layout12);
+                      }
+                      ptr
+                    }else {{
+                      ::core::ptr::null_mut()
+                    }};
+                    for (i, e) in vec12.into_iter().enumerate() {
+                      let base = result12 as i32 + (i as i32) * 32;
+                      {
+                        match e {
+                          WorkerPropertyFilter::Name(e) => {
+                            *((base + 0) as *mut u8) = (0i32) as u8;
+                            let WorkerNameFilter{ comparator:comparator4, value:value4, } = e;
+                            *((base + 8) as *mut u8) = (comparator4.clone() as i32) as u8;
+                            let vec5 = value4;
+                            let ptr5 = vec5.as_ptr() as i32;
+                            let len5 = vec5.len() as i32;
+                            *((base + 16) as *mut i32) = len5;
+                            *((base + 12) as *mut i32) = ptr5;
                           },
-                          None => {
-                            {
-                              *((base + 12) as *mut u8) = (0i32) as u8;
-                            }
+                          WorkerPropertyFilter::Status(e) => {
+                            *((base + 0) as *mut u8) = (1i32) as u8;
+                            let WorkerStatusFilter{ comparator:comparator6, value:value6, } = e;
+                            *((base + 8) as *mut u8) = (comparator6.clone() as i32) as u8;
+                            *((base + 9) as *mut u8) = (value6.clone() as i32) as u8;
                           },
-                        };},
-                        WitNode::EnumValue(e) => {
-                          *((base + 0) as *mut u8) = (2i32) as u8;
-                          *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(e);
-                        },
-                        WitNode::FlagsValue(e) => {
-                          *((base + 0) as *mut u8) = (3i32) as u8;
-                          let vec4 = e;
-                          let len4 = vec4.len() as i32;
-                          let layout4 = alloc::Layout::from_size_align_unchecked(vec4.len() * 1, 1);
-                          let result4 = if layout4.size() != 0
-                          {
-                            let ptr = alloc::alloc(layout4);
-                            if ptr.is_null()
-                            {
-                              alloc::handle_alloc_error(layout4);
-                            }
-                            ptr
-                          }else {{
-                            ::core::ptr::null_mut()
-                          }};
-                          for (i, e) in vec4.into_iter().enumerate() {
-                            let base = result4 as i32 + (i as i32) * 1;
-                            {
-                              *((base + 0) as *mut u8) = (match e { true => 1, false => 0 }) as u8;
-                            }
-                          }
-                          *((base + 12) as *mut i32) = len4;
-                          *((base + 8) as *mut i32) = result4 as i32;
-                          cleanup_list.extend_from_slice(&[(result4, layout4),]);
-                        },
-                        WitNode::TupleValue(e) => {
-                          *((base + 0) as *mut u8) = (4i32) as u8;
-                          let vec5 = e;
-                          let ptr5 = vec5.as_ptr() as i32;
-                          let len5 = vec5.len() as i32;
-                          *((base + 12) as *mut i32) = len5;
-                          *((base + 8) as *mut i32) = ptr5;
-                        },
-                        WitNode::ListValue(e) => {
-                          *((base + 0) as *mut u8) = (5i32) as u8;
-                          let vec6 = e;
-                          let ptr6 = vec6.as_ptr() as i32;
-                          let len6 = vec6.len() as i32;
-                          *((base + 12) as *mut i32) = len6;
-                          *((base + 8) as *mut i32) = ptr6;
-                        },
-                        WitNode::OptionValue(e) => {
-                          *((base + 0) as *mut u8) = (6i32) as u8;
-                          match e {
-                            Some(e) => {
-                              *((base + 8) as *mut u8) = (1i32) as u8;
-                              *((base + 12) as *mut i32) = wit_bindgen::rt::as_i32(e);
-                            },
-                            None => {
-                              {
-                                *((base + 8) as *mut u8) = (0i32) as u8;
-                              }
+                          WorkerPropertyFilter::Version(e) => {
+                            *((base + 0) as *mut u8) = (2i32) as u8;
+                            let WorkerVersionFilter{ comparator:comparator7, value:value7, } = e;
+                            *((base + 8) as *mut u8) = (comparator7.clone() as i32) as u8;
+                            *((base + 16) as *mut i64) = wit_bindgen::rt::as_i64(value7);
+                          },
+                          WorkerPropertyFilter::CreatedAt(e) => {
+                            *((base + 0) as *mut u8) = (3i32) as u8;
+                            let WorkerCreatedAtFilter{ comparator:comparator8, value:value8, } = e;
+                            *((base + 8) as *mut u8) = (comparator8.clone() as i32) as u8;
+                            *((base + 16) as *mut i64) = wit_bindgen::rt::as_i64(value8);
+                          },
+                          WorkerPropertyFilter::Env(e) => {
+                            *((base + 0) as *mut u8) = (4i32) as u8;
+                            let WorkerEnvFilter{ name:name9, comparator:comparator9, value:value9, } = e;
+                            let vec10 = name9;
+                            let ptr10 = vec10.as_ptr() as i32;
+                            let len10 = vec10.len() as i32;
+                            *((base + 12) as *mut i32) = len10;
+                            *((base + 8) as *mut i32) = ptr10;
+                            *((base + 16) as *mut u8) = (comparator9.clone() as i32) as u8;
+                            let vec11 = value9;
+                            let ptr11 = vec11.as_ptr() as i32;
+                            let len11 = vec11.len() as i32;
+                            *((base + 24) as *mut i32) = len11;
+                            *((base + 20) as *mut i32) = ptr11;
+                          },
+                        }
+                      }
+                    }
+                    *((base + 4) as *mut i32) = len12;
+                    *((base + 0) as *mut i32) = result12 as i32;
+                    cleanup_list.extend_from_slice(&[(result12, layout12),]);
+                  }
+                }
+                cleanup_list.extend_from_slice(&[(result13, layout13),]);
+                
+                (1i32, result13 as i32, len13)
+              },
+              None => {
+                (0i32, 0i32, 0i32)
+              },
+            };
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "golem:api/host@0.2.0")]
+            extern "C" {
+              #[link_name = "[constructor]get-workers"]
+              fn wit_import(_: i64, _: i64, _: i32, _: i32, _: i32, _: i32, ) -> i32;
+            }
+            
+            #[cfg(not(target_arch = "wasm32"))]
+            fn wit_import(_: i64, _: i64, _: i32, _: i32, _: i32, _: i32, ) -> i32{ unreachable!() }
+            let ret = wit_import(wit_bindgen::rt::as_i64(high_bits1), wit_bindgen::rt::as_i64(low_bits1), result14_0, result14_1, result14_2, match precise { true => 1, false => 0 });
+            for (ptr, layout) in cleanup_list {
+              
+              if layout.size() != 0 {
+                
+                alloc::dealloc(ptr, layout);
+                
+              }
+              
+            }
+            GetWorkers::from_handle(ret as u32)
+          }
+        }
+      }
+      impl GetWorkers {
+        #[allow(unused_unsafe, clippy::all)]
+        pub fn get_next(&self,) -> Option<wit_bindgen::rt::vec::Vec::<WorkerMetadata>>{
+          
+          #[allow(unused_imports)]
+          use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+          unsafe {
+            
+            #[repr(align(4))]
+            struct RetArea([u8; 12]);
+            let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+            let ptr0 = ret_area.as_mut_ptr() as i32;
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "golem:api/host@0.2.0")]
+            extern "C" {
+              #[link_name = "[method]get-workers.get-next"]
+              fn wit_import(_: i32, _: i32, );
+            }
+            
+            #[cfg(not(target_arch = "wasm32"))]
+            fn wit_import(_: i32, _: i32, ){ unreachable!() }
+            wit_import((self).handle() as i32, ptr0);
+            let l1 = i32::from(*((ptr0 + 0) as *const u8));
+            match l1 {
+              0 => None,
+              1 => {
+                let e = {
+                  let l2 = *((ptr0 + 4) as *const i32);
+                  let l3 = *((ptr0 + 8) as *const i32);
+                  let base27 = l2;
+                  let len27 = l3;
+                  let mut result27 = Vec::with_capacity(len27 as usize);
+                  for i in 0..len27 {
+                    let base = base27 + i * 64;
+                    let e27 = {
+                      let l4 = *((base + 0) as *const i64);
+                      let l5 = *((base + 8) as *const i64);
+                      let l6 = *((base + 16) as *const i32);
+                      let l7 = *((base + 20) as *const i32);
+                      let len8 = l7 as usize;
+                      let bytes8 = Vec::from_raw_parts(l6 as *mut _, len8, len8);
+                      let l9 = *((base + 24) as *const i32);
+                      let l10 = *((base + 28) as *const i32);
+                      let base14 = l9;
+                      let len14 = l10;
+                      let mut result14 = Vec::with_capacity(len14 as usize);
+                      for i in 0..len14 {
+                        let base = base14 + i * 8;
+                        let e14 = {
+                          let l11 = *((base + 0) as *const i32);
+                          let l12 = *((base + 4) as *const i32);
+                          let len13 = l12 as usize;
+                          let bytes13 = Vec::from_raw_parts(l11 as *mut _, len13, len13);
+                          
+                          wit_bindgen::rt::string_lift(bytes13)
+                        };
+                        result14.push(e14);
+                      }
+                      wit_bindgen::rt::dealloc(base14, (len14 as usize) * 8, 4);
+                      let l15 = *((base + 32) as *const i32);
+                      let l16 = *((base + 36) as *const i32);
+                      let base23 = l15;
+                      let len23 = l16;
+                      let mut result23 = Vec::with_capacity(len23 as usize);
+                      for i in 0..len23 {
+                        let base = base23 + i * 16;
+                        let e23 = {
+                          let l17 = *((base + 0) as *const i32);
+                          let l18 = *((base + 4) as *const i32);
+                          let len19 = l18 as usize;
+                          let bytes19 = Vec::from_raw_parts(l17 as *mut _, len19, len19);
+                          let l20 = *((base + 8) as *const i32);
+                          let l21 = *((base + 12) as *const i32);
+                          let len22 = l21 as usize;
+                          let bytes22 = Vec::from_raw_parts(l20 as *mut _, len22, len22);
+                          
+                          (wit_bindgen::rt::string_lift(bytes19), wit_bindgen::rt::string_lift(bytes22))
+                        };
+                        result23.push(e23);
+                      }
+                      wit_bindgen::rt::dealloc(base23, (len23 as usize) * 16, 4);
+                      let l24 = i32::from(*((base + 40) as *const u8));
+                      let l25 = *((base + 48) as *const i64);
+                      let l26 = *((base + 56) as *const i64);
+                      
+                      WorkerMetadata{
+                        worker_id: WorkerId{
+                          component_id: ComponentId{
+                            uuid: Uuid{
+                              high_bits: l4 as u64,
+                              low_bits: l5 as u64,
                             },
-                          };},
-                          WitNode::ResultValue(e) => {
-                            *((base + 0) as *mut u8) = (7i32) as u8;
-                            match e {
-                              Ok(e) => { {
-                                *((base + 8) as *mut u8) = (0i32) as u8;
-                                match e {
-                                  Some(e) => {
-                                    *((base + 12) as *mut u8) = (1i32) as u8;
-                                    *((base + 16) as *mut i32) = wit_bindgen::rt::as_i32(e);
-                                  },
-                                  None => {
-                                    {
-                                      *((base + 12) as *mut u8) = (0i32) as u8;
-                                    }
-                                  },
-                                };} },
-                                Err(e) => { {
-                                  *((base + 8) as *mut u8) = (1i32) as u8;
-                                  match e {
-                                    Some(e) => {
-                                      *((base + 12) as *mut u8) = (1i32) as u8;
-                                      *((base + 16) as *mut i32) = wit_bindgen::rt::as_i32(e);
-                                    },
-                                    None => {
-                                      {
-                                        *((base + 12) as *mut u8) = (0i32) as u8;
-                                      }
-                                    },
-                                  };} },
-                                };},
-                                WitNode::PrimU8(e) => {
-                                  *((base + 0) as *mut u8) = (8i32) as u8;
-                                  *((base + 8) as *mut u8) = (wit_bindgen::rt::as_i32(e)) as u8;
-                                },
-                                WitNode::PrimU16(e) => {
-                                  *((base + 0) as *mut u8) = (9i32) as u8;
-                                  *((base + 8) as *mut u16) = (wit_bindgen::rt::as_i32(e)) as u16;
-                                },
-                                WitNode::PrimU32(e) => {
-                                  *((base + 0) as *mut u8) = (10i32) as u8;
-                                  *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(e);
-                                },
-                                WitNode::PrimU64(e) => {
-                                  *((base + 0) as *mut u8) = (11i32) as u8;
-                                  *((base + 8) as *mut i64) = wit_bindgen::rt::as_i64(e);
-                                },
-                                WitNode::PrimS8(e) => {
-                                  *((base + 0) as *mut u8) = (12i32) as u8;
-                                  *((base + 8) as *mut u8) = (wit_bindgen::rt::as_i32(e)) as u8;
-                                },
-                                WitNode::PrimS16(e) => {
-                                  *((base + 0) as *mut u8) = (13i32) as u8;
-                                  *((base + 8) as *mut u16) = (wit_bindgen::rt::as_i32(e)) as u16;
-                                },
-                                WitNode::PrimS32(e) => {
-                                  *((base + 0) as *mut u8) = (14i32) as u8;
-                                  *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(e);
-                                },
-                                WitNode::PrimS64(e) => {
-                                  *((base + 0) as *mut u8) = (15i32) as u8;
-                                  *((base + 8) as *mut i64) = wit_bindgen::rt::as_i64(e);
-                                },
-                                WitNode::PrimFloat32(e) => {
-                                  *((base + 0) as *mut u8) = (16i32) as u8;
-                                  *((base + 8) as *mut f32) = wit_bindgen::rt::as_f32(e);
-                                },
-                                WitNode::PrimFloat64(e) => {
-                                  *((base + 0) as *mut u8) = (17i32) as u8;
-                                  *((base + 8) as *mut f64) = wit_bindgen::rt::as_f64(e);
-                                },
-                                WitNode::PrimChar(e) => {
-                                  *((base + 0) as *mut u8) = (18i32) as u8;
-                                  *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(e);
-                                },
-                                WitNode::PrimBool(e) => {
-                                  *((base + 0) as *mut u8) = (19i32) as u8;
-                                  *((base + 8) as *mut u8) = (match e { true => 1, false => 0 }) as u8;
-                                },
-                                WitNode::PrimString(e) => {
-                                  *((base + 0) as *mut u8) = (20i32) as u8;
-                                  let vec7 = e;
-                                  let ptr7 = vec7.as_ptr() as i32;
-                                  let len7 = vec7.len() as i32;
-                                  *((base + 12) as *mut i32) = len7;
-                                  *((base + 8) as *mut i32) = ptr7;
-                                },
-                                WitNode::Handle(e) => {
-                                  *((base + 0) as *mut u8) = (21i32) as u8;
-                                  let (t8_0, t8_1, ) = e;
-                                  let Uri{ value:value9, } = t8_0;
-                                  let vec10 = value9;
-                                  let ptr10 = vec10.as_ptr() as i32;
-                                  let len10 = vec10.len() as i32;
-                                  *((base + 12) as *mut i32) = len10;
-                                  *((base + 8) as *mut i32) = ptr10;
-                                  *((base + 16) as *mut i64) = wit_bindgen::rt::as_i64(t8_1);
-                                },
+                          },
+                          worker_name: wit_bindgen::rt::string_lift(bytes8),
+                        },
+                        args: result14,
+                        env: result23,
+                        status: WorkerStatus::_lift(l24 as u8),
+                        component_version: l25 as u64,
+                        retry_count: l26 as u64,
+                      }
+                    };
+                    result27.push(e27);
+                  }
+                  wit_bindgen::rt::dealloc(base27, (len27 as usize) * 64, 8);
+                  
+                  result27
+                };
+                Some(e)
+              }
+              _ => wit_bindgen::rt::invalid_enum_discriminant(),
+            }
+          }
+        }
+      }
+      #[allow(unused_unsafe, clippy::all)]
+      /// Create a new promise
+      pub fn golem_create_promise() -> PromiseId{
+        
+        #[allow(unused_imports)]
+        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+        unsafe {
+          
+          #[repr(align(8))]
+          struct RetArea([u8; 32]);
+          let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+          let ptr0 = ret_area.as_mut_ptr() as i32;
+          #[cfg(target_arch = "wasm32")]
+          #[link(wasm_import_module = "golem:api/host@0.2.0")]
+          extern "C" {
+            #[link_name = "golem-create-promise"]
+            fn wit_import(_: i32, );
+          }
+          
+          #[cfg(not(target_arch = "wasm32"))]
+          fn wit_import(_: i32, ){ unreachable!() }
+          wit_import(ptr0);
+          let l1 = *((ptr0 + 0) as *const i64);
+          let l2 = *((ptr0 + 8) as *const i64);
+          let l3 = *((ptr0 + 16) as *const i32);
+          let l4 = *((ptr0 + 20) as *const i32);
+          let len5 = l4 as usize;
+          let bytes5 = Vec::from_raw_parts(l3 as *mut _, len5, len5);
+          let l6 = *((ptr0 + 24) as *const i64);
+          PromiseId{
+            worker_id: WorkerId{
+              component_id: ComponentId{
+                uuid: Uuid{
+                  high_bits: l1 as u64,
+                  low_bits: l2 as u64,
+                },
+              },
+              worker_name: wit_bindgen::rt::string_lift(bytes5),
+            },
+            oplog_idx: l6 as u64,
+          }
+        }
+      }
+      #[allow(unused_unsafe, clippy::all)]
+      /// Suspends execution until the given promise gets completed, and returns the payload passed to
+      /// the promise completion.
+      pub fn golem_await_promise(promise_id: &PromiseId,) -> wit_bindgen::rt::vec::Vec::<u8>{
+        
+        #[allow(unused_imports)]
+        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+        unsafe {
+          
+          #[repr(align(4))]
+          struct RetArea([u8; 8]);
+          let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+          let PromiseId{ worker_id:worker_id0, oplog_idx:oplog_idx0, } = promise_id;
+          let WorkerId{ component_id:component_id1, worker_name:worker_name1, } = worker_id0;
+          let ComponentId{ uuid:uuid2, } = component_id1;
+          let Uuid{ high_bits:high_bits3, low_bits:low_bits3, } = uuid2;
+          let vec4 = worker_name1;
+          let ptr4 = vec4.as_ptr() as i32;
+          let len4 = vec4.len() as i32;
+          let ptr5 = ret_area.as_mut_ptr() as i32;
+          #[cfg(target_arch = "wasm32")]
+          #[link(wasm_import_module = "golem:api/host@0.2.0")]
+          extern "C" {
+            #[link_name = "golem-await-promise"]
+            fn wit_import(_: i64, _: i64, _: i32, _: i32, _: i64, _: i32, );
+          }
+          
+          #[cfg(not(target_arch = "wasm32"))]
+          fn wit_import(_: i64, _: i64, _: i32, _: i32, _: i64, _: i32, ){ unreachable!() }
+          wit_import(wit_bindgen::rt::as_i64(high_bits3), wit_bindgen::rt::as_i64(low_bits3), ptr4, len4, wit_bindgen::rt::as_i64(oplog_idx0), ptr5);
+          let l6 = *((ptr5 + 0) as *const i32);
+          let l7 = *((ptr5 + 4) as *const i32);
+          let len8 = l7 as usize;
+          Vec::from_raw_parts(l6 as *mut _, len8, len8)
+        }
+      }
+      #[allow(unused_unsafe, clippy::all)]
+      /// Completes the given promise with the given payload. Returns true if the promise was completed, false
+      /// if the promise was already completed. The payload is passed to the worker that is awaiting the promise.
+      pub fn golem_complete_promise(promise_id: &PromiseId,data: &[u8],) -> bool{
+        
+        #[allow(unused_imports)]
+        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+        unsafe {
+          let PromiseId{ worker_id:worker_id0, oplog_idx:oplog_idx0, } = promise_id;
+          let WorkerId{ component_id:component_id1, worker_name:worker_name1, } = worker_id0;
+          let ComponentId{ uuid:uuid2, } = component_id1;
+          let Uuid{ high_bits:high_bits3, low_bits:low_bits3, } = uuid2;
+          let vec4 = worker_name1;
+          let ptr4 = vec4.as_ptr() as i32;
+          let len4 = vec4.len() as i32;
+          let vec5 = data;
+          let ptr5 = vec5.as_ptr() as i32;
+          let len5 = vec5.len() as i32;
+          
+          #[cfg(target_arch = "wasm32")]
+          #[link(wasm_import_module = "golem:api/host@0.2.0")]
+          extern "C" {
+            #[link_name = "golem-complete-promise"]
+            fn wit_import(_: i64, _: i64, _: i32, _: i32, _: i64, _: i32, _: i32, ) -> i32;
+          }
+          
+          #[cfg(not(target_arch = "wasm32"))]
+          fn wit_import(_: i64, _: i64, _: i32, _: i32, _: i64, _: i32, _: i32, ) -> i32{ unreachable!() }
+          let ret = wit_import(wit_bindgen::rt::as_i64(high_bits3), wit_bindgen::rt::as_i64(low_bits3), ptr4, len4, wit_bindgen::rt::as_i64(oplog_idx0), ptr5, len5);
+          wit_bindgen::rt::bool_lift(ret as u8)
+        }
+      }
+      #[allow(unused_unsafe, clippy::all)]
+      /// Deletes the given promise
+      pub fn golem_delete_promise(promise_id: &PromiseId,){
+        
+        #[allow(unused_imports)]
+        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+        unsafe {
+          let PromiseId{ worker_id:worker_id0, oplog_idx:oplog_idx0, } = promise_id;
+          let WorkerId{ component_id:component_id1, worker_name:worker_name1, } = worker_id0;
+          let ComponentId{ uuid:uuid2, } = component_id1;
+          let Uuid{ high_bits:high_bits3, low_bits:low_bits3, } = uuid2;
+          let vec4 = worker_name1;
+          let ptr4 = vec4.as_ptr() as i32;
+          let len4 = vec4.len() as i32;
+          
+          #[cfg(target_arch = "wasm32")]
+          #[link(wasm_import_module = "golem:api/host@0.2.0")]
+          extern "C" {
+            #[link_name = "golem-delete-promise"]
+            fn wit_import(_: i64, _: i64, _: i32, _: i32, _: i64, );
+          }
+          
+          #[cfg(not(target_arch = "wasm32"))]
+          fn wit_import(_: i64, _: i64, _: i32, _: i32, _: i64, ){ unreachable!() }
+          wit_import(wit_bindgen::rt::as_i64(high_bits3), wit_bindgen::rt::as_i64(low_bits3), ptr4, len4, wit_bindgen::rt::as_i64(oplog_idx0));
+        }
+      }
+      #[allow(unused_unsafe, clippy::all)]
+      /// Returns a Golem worker URI that can be used to invoke a given function on the current worker
+      pub fn get_self_uri(function_name: &str,) -> Uri{
+        
+        #[allow(unused_imports)]
+        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+        unsafe {
+          
+          #[repr(align(4))]
+          struct RetArea([u8; 8]);
+          let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+          let vec0 = function_name;
+          let ptr0 = vec0.as_ptr() as i32;
+          let len0 = vec0.len() as i32;
+          let ptr1 = ret_area.as_mut_ptr() as i32;
+          #[cfg(target_arch = "wasm32")]
+          #[link(wasm_import_module = "golem:api/host@0.2.0")]
+          extern "C" {
+            #[link_name = "get-self-uri"]
+            fn wit_import(_: i32, _: i32, _: i32, );
+          }
+          
+          #[cfg(not(target_arch = "wasm32"))]
+          fn wit_import(_: i32, _: i32, _: i32, ){ unreachable!() }
+          wit_import(ptr0, len0, ptr1);
+          let l2 = *((ptr1 + 0) as *const i32);
+          let l3 = *((ptr1 + 4) as *const i32);
+          let len4 = l3 as usize;
+          let bytes4 = Vec::from_raw_parts(l2 as *mut _, len4, len4);
+          super::super::super::golem::rpc::types::Uri{
+            value: wit_bindgen::rt::string_lift(bytes4),
+          }
+        }
+      }
+      #[allow(unused_unsafe, clippy::all)]
+      /// Returns the current position in the persistent op log
+      pub fn get_oplog_index() -> OplogIndex{
+        
+        #[allow(unused_imports)]
+        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+        unsafe {
+          
+          #[cfg(target_arch = "wasm32")]
+          #[link(wasm_import_module = "golem:api/host@0.2.0")]
+          extern "C" {
+            #[link_name = "get-oplog-index"]
+            fn wit_import() -> i64;
+          }
+          
+          #[cfg(not(target_arch = "wasm32"))]
+          fn wit_import() -> i64{ unreachable!() }
+          let ret = wit_import();
+          ret as u64
+        }
+      }
+      #[allow(unused_unsafe, clippy::all)]
+      /// Makes the current worker travel back in time and continue execution from the given position in the persistent
+      /// op log.
+      pub fn set_oplog_index(oplog_idx: OplogIndex,){
+        
+        #[allow(unused_imports)]
+        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+        unsafe {
+          
+          #[cfg(target_arch = "wasm32")]
+          #[link(wasm_import_module = "golem:api/host@0.2.0")]
+          extern "C" {
+            #[link_name = "set-oplog-index"]
+            fn wit_import(_: i64, );
+          }
+          
+          #[cfg(not(target_arch = "wasm32"))]
+          fn wit_import(_: i64, ){ unreachable!() }
+          wit_import(wit_bindgen::rt::as_i64(oplog_idx));
+        }
+      }
+      #[allow(unused_unsafe, clippy::all)]
+      /// Blocks the execution until the oplog has been written to at least the specified number of replicas,
+      /// or the maximum number of replicas if the requested number is higher.
+      pub fn oplog_commit(replicas: u8,){
+        
+        #[allow(unused_imports)]
+        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+        unsafe {
+          
+          #[cfg(target_arch = "wasm32")]
+          #[link(wasm_import_module = "golem:api/host@0.2.0")]
+          extern "C" {
+            #[link_name = "oplog-commit"]
+            fn wit_import(_: i32, );
+          }
+          
+          #[cfg(not(target_arch = "wasm32"))]
+          fn wit_import(_: i32, ){ unreachable!() }
+          wit_import(wit_bindgen::rt::as_i32(replicas));
+        }
+      }
+      #[allow(unused_unsafe, clippy::all)]
+      /// Marks the beginning of an atomic operation.
+      /// In case of a failure within the region selected by `mark-begin-operation` and `mark-end-operation`
+      /// the whole region will be reexecuted on retry.
+      /// The end of the region is when `mark-end-operation` is called with the returned oplog-index.
+      pub fn mark_begin_operation() -> OplogIndex{
+        
+        #[allow(unused_imports)]
+        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+        unsafe {
+          
+          #[cfg(target_arch = "wasm32")]
+          #[link(wasm_import_module = "golem:api/host@0.2.0")]
+          extern "C" {
+            #[link_name = "mark-begin-operation"]
+            fn wit_import() -> i64;
+          }
+          
+          #[cfg(not(target_arch = "wasm32"))]
+          fn wit_import() -> i64{ unreachable!() }
+          let ret = wit_import();
+          ret as u64
+        }
+      }
+      #[allow(unused_unsafe, clippy::all)]
+      /// Commits this atomic operation. After `mark-end-operation` is called for a given index, further calls
+      /// with the same parameter will do nothing.
+      pub fn mark_end_operation(begin: OplogIndex,){
+        
+        #[allow(unused_imports)]
+        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+        unsafe {
+          
+          #[cfg(target_arch = "wasm32")]
+          #[link(wasm_import_module = "golem:api/host@0.2.0")]
+          extern "C" {
+            #[link_name = "mark-end-operation"]
+            fn wit_import(_: i64, );
+          }
+          
+          #[cfg(not(target_arch = "wasm32"))]
+          fn wit_import(_: i64, ){ unreachable!() }
+          wit_import(wit_bindgen::rt::as_i64(begin));
+        }
+      }
+      #[allow(unused_unsafe, clippy::all)]
+      /// Gets the current retry policy associated with the worker
+      pub fn get_retry_policy() -> RetryPolicy{
+        
+        #[allow(unused_imports)]
+        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+        unsafe {
+          
+          #[repr(align(8))]
+          struct RetArea([u8; 32]);
+          let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+          let ptr0 = ret_area.as_mut_ptr() as i32;
+          #[cfg(target_arch = "wasm32")]
+          #[link(wasm_import_module = "golem:api/host@0.2.0")]
+          extern "C" {
+            #[link_name = "get-retry-policy"]
+            fn wit_import(_: i32, );
+          }
+          
+          #[cfg(not(target_arch = "wasm32"))]
+          fn wit_import(_: i32, ){ unreachable!() }
+          wit_import(ptr0);
+          let l1 = *((ptr0 + 0) as *const i32);
+          let l2 = *((ptr0 + 8) as *const i64);
+          let l3 = *((ptr0 + 16) as *const i64);
+          let l4 = *((ptr0 + 24) as *const i32);
+          RetryPolicy{
+            max_attempts: l1 as u32,
+            min_delay: l2 as u64,
+            max_delay: l3 as u64,
+            multiplier: l4 as u32,
+          }
+        }
+      }
+      #[allow(unused_unsafe, clippy::all)]
+      /// Overrides the current retry policy associated with the worker. Following this call, `get-retry-policy` will return the
+      /// new retry policy.
+      pub fn set_retry_policy(new_retry_policy: RetryPolicy,){
+        
+        #[allow(unused_imports)]
+        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+        unsafe {
+          let RetryPolicy{ max_attempts:max_attempts0, min_delay:min_delay0, max_delay:max_delay0, multiplier:multiplier0, } = new_retry_policy;
+          
+          #[cfg(target_arch = "wasm32")]
+          #[link(wasm_import_module = "golem:api/host@0.2.0")]
+          extern "C" {
+            #[link_name = "set-retry-policy"]
+            fn wit_import(_: i32, _: i64, _: i64, _: i32, );
+          }
+          
+          #[cfg(not(target_arch = "wasm32"))]
+          fn wit_import(_: i32, _: i64, _: i64, _: i32, ){ unreachable!() }
+          wit_import(wit_bindgen::rt::as_i32(max_attempts0), wit_bindgen::rt::as_i64(min_delay0), wit_bindgen::rt::as_i64(max_delay0), wit_bindgen::rt::as_i32(multiplier0));
+        }
+      }
+      #[allow(unused_unsafe, clippy::all)]
+      /// Gets the worker's current persistence level.
+      pub fn get_oplog_persistence_level() -> PersistenceLevel{
+        
+        #[allow(unused_imports)]
+        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+        unsafe {
+          
+          #[cfg(target_arch = "wasm32")]
+          #[link(wasm_import_module = "golem:api/host@0.2.0")]
+          extern "C" {
+            #[link_name = "get-oplog-persistence-level"]
+            fn wit_import() -> i32;
+          }
+          
+          #[cfg(not(target_arch = "wasm32"))]
+          fn wit_import() -> i32{ unreachable!() }
+          let ret = wit_import();
+          let v0 = match ret {
+            0 => {
+              PersistenceLevel::PersistNothing
+            }
+            1 => {
+              PersistenceLevel::PersistRemoteSideEffects
+            }
+            n => {
+              debug_assert_eq!(n, 2, "invalid enum discriminant");
+              PersistenceLevel::Smart
+            }
+          };
+          v0
+        }
+      }
+      #[allow(unused_unsafe, clippy::all)]
+      /// Sets the worker's current persistence level. This can increase the performance of execution in cases where durable
+      /// execution is not required.
+      pub fn set_oplog_persistence_level(new_persistence_level: PersistenceLevel,){
+        
+        #[allow(unused_imports)]
+        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+        unsafe {
+          let result0 = match new_persistence_level {
+            PersistenceLevel::PersistNothing=> {
+              0i32
+            }
+            PersistenceLevel::PersistRemoteSideEffects=> {
+              1i32
+            }
+            PersistenceLevel::Smart=> {
+              2i32
+            }
+          };
+          
+          #[cfg(target_arch = "wasm32")]
+          #[link(wasm_import_module = "golem:api/host@0.2.0")]
+          extern "C" {
+            #[link_name = "set-oplog-persistence-level"]
+            fn wit_import(_: i32, );
+          }
+          
+          #[cfg(not(target_arch = "wasm32"))]
+          fn wit_import(_: i32, ){ unreachable!() }
+          wit_import(result0);
+        }
+      }
+      #[allow(unused_unsafe, clippy::all)]
+      /// Gets the current idempotence mode. See `set-idempotence-mode` for details.
+      pub fn get_idempotence_mode() -> bool{
+        
+        #[allow(unused_imports)]
+        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+        unsafe {
+          
+          #[cfg(target_arch = "wasm32")]
+          #[link(wasm_import_module = "golem:api/host@0.2.0")]
+          extern "C" {
+            #[link_name = "get-idempotence-mode"]
+            fn wit_import() -> i32;
+          }
+          
+          #[cfg(not(target_arch = "wasm32"))]
+          fn wit_import() -> i32{ unreachable!() }
+          let ret = wit_import();
+          wit_bindgen::rt::bool_lift(ret as u8)
+        }
+      }
+      #[allow(unused_unsafe, clippy::all)]
+      /// Sets the current idempotence mode. The default is true.
+      /// True means side-effects are treated idempotent and Golem guarantees at-least-once semantics.
+      /// In case of false the executor provides at-most-once semantics, failing the worker in case it is
+      /// not known if the side effect was already executed.
+      pub fn set_idempotence_mode(idempotent: bool,){
+        
+        #[allow(unused_imports)]
+        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+        unsafe {
+          
+          #[cfg(target_arch = "wasm32")]
+          #[link(wasm_import_module = "golem:api/host@0.2.0")]
+          extern "C" {
+            #[link_name = "set-idempotence-mode"]
+            fn wit_import(_: i32, );
+          }
+          
+          #[cfg(not(target_arch = "wasm32"))]
+          fn wit_import(_: i32, ){ unreachable!() }
+          wit_import(match idempotent { true => 1, false => 0 });
+        }
+      }
+      #[allow(unused_unsafe, clippy::all)]
+      /// Generates an idempotency key. This operation will never be replayed —
+      /// i.e. not only is this key generated, but it is persisted and committed, such that the key can be used in third-party systems (e.g. payment processing)
+      /// to introduce idempotence.
+      pub fn generate_idempotency_key() -> Uuid{
+        
+        #[allow(unused_imports)]
+        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+        unsafe {
+          
+          #[repr(align(8))]
+          struct RetArea([u8; 16]);
+          let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+          let ptr0 = ret_area.as_mut_ptr() as i32;
+          #[cfg(target_arch = "wasm32")]
+          #[link(wasm_import_module = "golem:api/host@0.2.0")]
+          extern "C" {
+            #[link_name = "generate-idempotency-key"]
+            fn wit_import(_: i32, );
+          }
+          
+          #[cfg(not(target_arch = "wasm32"))]
+          fn wit_import(_: i32, ){ unreachable!() }
+          wit_import(ptr0);
+          let l1 = *((ptr0 + 0) as *const i64);
+          let l2 = *((ptr0 + 8) as *const i64);
+          Uuid{
+            high_bits: l1 as u64,
+            low_bits: l2 as u64,
+          }
+        }
+      }
+      #[allow(unused_unsafe, clippy::all)]
+      /// Initiates an update attempt for the given worker. The function returns immediately once the request has been processed,
+      /// not waiting for the worker to get updated.
+      pub fn update_worker(worker_id: &WorkerId,target_version: ComponentVersion,mode: UpdateMode,){
+        
+        #[allow(unused_imports)]
+        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+        unsafe {
+          let WorkerId{ component_id:component_id0, worker_name:worker_name0, } = worker_id;
+          let ComponentId{ uuid:uuid1, } = component_id0;
+          let Uuid{ high_bits:high_bits2, low_bits:low_bits2, } = uuid1;
+          let vec3 = worker_name0;
+          let ptr3 = vec3.as_ptr() as i32;
+          let len3 = vec3.len() as i32;
+          
+          #[cfg(target_arch = "wasm32")]
+          #[link(wasm_import_module = "golem:api/host@0.2.0")]
+          extern "C" {
+            #[link_name = "update-worker"]
+            fn wit_import(_: i64, _: i64, _: i32, _: i32, _: i64, _: i32, );
+          }
+          
+          #[cfg(not(target_arch = "wasm32"))]
+          fn wit_import(_: i64, _: i64, _: i32, _: i32, _: i64, _: i32, ){ unreachable!() }
+          wit_import(wit_bindgen::rt::as_i64(high_bits2), wit_bindgen::rt::as_i64(low_bits2), ptr3, len3, wit_bindgen::rt::as_i64(target_version), mode.clone() as i32);
+        }
+      }
+      
+    }
+    
+  }
+  pub mod ext {
+    
+    #[allow(clippy::all)]
+    pub mod async_rpc {
+      #[used]
+      #[doc(hidden)]
+      #[cfg(target_arch = "wasm32")]
+      static __FORCE_SECTION_REF: fn() = super::super::super::__link_section;
+      pub type Pollable = super::super::super::wasi::io::poll::Pollable;
+      pub type WasmRpc = super::super::super::golem::rpc::types::WasmRpc;
+      pub type WitValue = super::super::super::golem::rpc::types::WitValue;
+      pub type RpcError = super::super::super::golem::rpc::types::RpcError;
+      /// Handle to the result of a call started with `async-invoke-and-await`
+      
+      #[derive(Debug)]
+      #[repr(transparent)]
+      pub struct FutureInvokeResult{
+        handle: wit_bindgen::rt::Resource<FutureInvokeResult>,
+      }
+      
+      impl FutureInvokeResult{
+        #[doc(hidden)]
+        pub unsafe fn from_handle(handle: u32) -> Self {
+          Self {
+            handle: wit_bindgen::rt::Resource::from_handle(handle),
+          }
+        }
+        
+        #[doc(hidden)]
+        pub fn into_handle(self) -> u32 {
+          wit_bindgen::rt::Resource::into_handle(self.handle)
+        }
+        
+        #[doc(hidden)]
+        pub fn handle(&self) -> u32 {
+          wit_bindgen::rt::Resource::handle(&self.handle)
+        }
+      }
+      
+      
+      unsafe impl wit_bindgen::rt::WasmResource for FutureInvokeResult{
+        #[inline]
+        unsafe fn drop(_handle: u32) {
+          #[cfg(not(target_arch = "wasm32"))]
+          unreachable!();
+          
+          #[cfg(target_arch = "wasm32")]
+          {
+            #[link(wasm_import_module = "golem:ext/async-rpc@0.1.0")]
+            extern "C" {
+              #[link_name = "[resource-drop]future-invoke-result"]
+              fn drop(_: u32);
+            }
+            
+            drop(_handle);
+          }
+        }
+      }
+      
+      impl FutureInvokeResult {
+        #[allow(unused_unsafe, clippy::all)]
+        /// Returns a pollable which becomes ready when the result of the call is available.
+        /// Calls to a worker (as opposed to an ephemeral one) which take longer than the executor's
+        /// suspension threshold make it ready too, and the following `get` suspends the calling
+        /// worker until the result arrives.
+        pub fn subscribe(&self,) -> Pollable{
+          
+          #[allow(unused_imports)]
+          use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+          unsafe {
+            
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "golem:ext/async-rpc@0.1.0")]
+            extern "C" {
+              #[link_name = "[method]future-invoke-result.subscribe"]
+              fn wit_import(_: i32, ) -> i32;
+            }
+            
+            #[cfg(not(target_arch = "wasm32"))]
+            fn wit_import(_: i32, ) -> i32{ unreachable!() }
+            let ret = wit_import((self).handle() as i32);
+            super::super::super::wasi::io::poll::Pollable::from_handle(ret as u32)
+          }
+        }
+      }
+      impl FutureInvokeResult {
+        #[allow(unused_unsafe, clippy::all)]
+        /// Returns the result of the call, or none if it is still in progress.
+        /// Once a result has been returned the handle is consumed and must not be queried again.
+        pub fn get(&self,) -> Option<Result<WitValue,RpcError>>{
+          
+          #[allow(unused_imports)]
+          use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+          unsafe {
+            
+            #[repr(align(4))]
+            struct RetArea([u8; 20]);
+            let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+            let ptr0 = ret_area.as_mut_ptr() as i32;
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "golem:ext/async-rpc@0.1.0")]
+            extern "C" {
+              #[link_name = "[method]future-invoke-result.get"]
+              fn wit_import(_: i32, _: i32, );
+            }
+            
+            #[cfg(not(target_arch = "wasm32"))]
+            fn wit_import(_: i32, _: i32, ){ unreachable!() }
+            wit_import((self).handle() as i32, ptr0);
+            let l1 = i32::from(*((ptr0 + 0) as *const u8));
+            match l1 {
+              0 => None,
+              1 => {
+                let e = {
+                  let l2 = i32::from(*((ptr0 + 4) as *const u8));
+                  
+                  match l2 {
+                    0 => {
+                      let e = {
+                        let l3 = *((ptr0 + 8) as *const i32);
+                        let l4 = *((ptr0 + 12) as *const i32);
+                        let base50 = l3;
+                        let len50 = l4;
+                        let mut result50 = Vec::with_capacity(len50 as usize);
+                        for i in 0..len50 {
+                          let base = base50 + i * 24;
+                          let e50 = {
+                            let l5 = i32::from(*((base + 0) as *const u8));
+                            use super::super::super::golem::rpc::types::WitNode as V49;
+                            let v49 = match l5 {
+                              0 => {
+                                let e49 = {
+                                  let l6 = *((base + 8) as *const i32);
+                                  let l7 = *((base + 12) as *const i32);
+                                  let len8 = l7 as usize;
+                                  
+                                  Vec::from_raw_parts(l6 as *mut _, len8, len8)
+                                };
+                                V49::RecordValue(e49)
                               }
-                            }
-                          }
-                          *((base + 4) as *mut i32) = len11;
-                          *((base + 0) as *mut i32) = result11 as i32;
-                          cleanup_list.extend_from_slice(&[(result11, layout11),]);
-                        }
-                      }
-                      let ptr13 = ret_area.as_mut_ptr() as i32;
-                      #[cfg(target_arch = "wasm32")]
-                      #[link(wasm_import_module = "golem:rpc/types@0.1.0")]
-                      extern "C" {
-                        #[link_name = "[method]wasm-rpc.invoke-and-await"]
-                        fn wit_import(_: i32, _: i32, _: i32, _: i32, _: i32, _: i32, );
-                      }
-                      
-                      #[cfg(not(target_arch = "wasm32"))]
-                      fn wit_import(_: i32, _: i32, _: i32, _: i32, _: i32, _: i32, ){ unreachable!() }
-                      wit_import((self).handle() as i32, ptr0, len0, result12 as i32, len12, ptr13);
-                      let l14 = i32::from(*((ptr13 + 0) as *const u8));
-                      if layout12.size() != 0 {
-                        alloc::dealloc(result12, layout12);
-                      }
-                      for (ptr, layout) in cleanup_list {
-                        
-                        if layout.size() != 0 {
-                          
-                          alloc::dealloc(ptr, layout);
-                          
-                        }
-                        
-                      }
-                      match l14 {
-                        0 => {
-                          let e = {
-                            let l15 = *((ptr13 + 4) as *const i32);
-                            let l16 = *((ptr13 + 8) as *const i32);
-                            let base62 = l15;
-                            let len62 = l16;
-                            let mut result62 = Vec::with_capacity(len62 as usize);
-                            for i in 0..len62 {
-                              let base = base62 + i * 24;
-                              let e62 = {
-                                let l17 = i32::from(*((base + 0) as *const u8));
-                                let v61 = match l17 {
-                                  0 => {
-                                    let e61 = {
-                                      let l18 = *((base + 8) as *const i32);
-                                      let l19 = *((base + 12) as *const i32);
-                                      let len20 = l19 as usize;
-                                      
-                                      Vec::from_raw_parts(l18 as *mut _, len20, len20)
-                                    };
-                                    WitNode::RecordValue(e61)
-                                  }
-                                  1 => {
-                                    let e61 = {
-                                      let l21 = *((base + 8) as *const i32);
-                                      let l22 = i32::from(*((base + 12) as *const u8));
-                                      
-                                      (l21 as u32, match l22 {
-                                        0 => None,
-                                        1 => {
-                                          let e = {
-                                            let l23 = *((base + 16) as *const i32);
-                                            
-                                            l23
-                                          };
-                                          Some(e)
-                                        }
-                                        _ => wit_bindgen::rt::invalid_enum_discriminant(),
-                                      })
-                                    };
-                                    WitNode::VariantValue(e61)
-                                  }
-                                  2 => {
-                                    let e61 = {
-                                      let l24 = *((base + 8) as *const i32);
-                                      
-                                      l24 as u32
-                                    };
-                                    WitNode::EnumValue(e61)
-                                  }
-                                  3 => {
-                                    let e61 = {
-                                      let l25 = *((base + 8) as *const i32);
-                                      let l26 = *((base + 12) as *const i32);
-                                      let base28 = l25;
-                                      let len28 = l26;
-                                      let mut result28 = Vec::with_capacity(len28 as usize);
-                                      for i in 0..len28 {
-                                        let base = base28 + i * 1;
-                                        let e28 = {
-                                          let l27 = i32::from(*((base + 0) as *const u8));
-                                          
-                                          wit_bindgen::rt::bool_lift(l27 as u8)
-                                        };
-                                        result28.push(e28);
-                                      }
-                                      wit_bindgen::rt::dealloc(base28, (len28 as usize) * 1, 1);
-                                      
-                                      result28
-                                    };
-                                    WitNode::FlagsValue(e61)
-                                  }
-                                  4 => {
-                                    let e61 = {
-                                      let l29 = *((base + 8) as *const i32);
-                                      let l30 = *((base + 12) as *const i32);
-                                      let len31 = l30 as usize;
-                                      
-                                      Vec::from_raw_parts(l29 as *mut _, len31, len31)
-                                    };
-                                    WitNode::TupleValue(e61)
-                                  }
-                                  5 => {
-                                    let e61 = {
-                                      let l32 = *((base + 8) as *const i32);
-                                      let l33 = *((base + 12) as *const i32);
-                                      let len34 = l33 as usize;
+                              1 => {
+                                let e49 = {
+                                  let l9 = *((base + 8) as *const i32);
+                                  let l10 = i32::from(*((base + 12) as *const u8));
+                                  
+                                  (l9 as u32, match l10 {
+                                    0 => None,
+                                    1 => {
+                                      let e = {
+                                        let l11 = *((base + 16) as *const i32);
+                                        
+                                        l11
+                                      };
+                                      Some(e)
+                                    }
+                                    _ => wit_bindgen::rt::invalid_enum_discriminant(),
+                                  })
+                                };
+                                V49::VariantValue(e49)
+                              }
+                              2 => {
+                                let e49 = {
+                                  let l12 = *((base + 8) as *const i32);
+                                  
+                                  l12 as u32
+                                };
+                                V49::EnumValue(e49)
+                              }
+                              3 => {
+                                let e49 = {
+                                  let l13 = *((base + 8) as *const i32);
+                                  let l14 = *((base + 12) as *const i32);
+                                  let base16 = l13;
+                                  let len16 = l14;
+                                  let mut result16 = Vec::with_capacity(len16 as usize);
+                                  for i in 0..len16 {
+                                    let base = base16 + i * 1;
+                                    let e16 = {
+                                      let l15 = i32::from(*((base + 0) as *const u8));
                                       
-                                      Vec::from_raw_parts(l32 as *mut _, len34, len34)
+                                      wit_bindgen::rt::bool_lift(l15 as u8)
                                     };
-                                    WitNode::ListValue(e61)
+                                    result16.push(e16);
                                   }
-                                  6 => {
-                                    let e61 = {
-                                      let l35 = i32::from(*((base + 8) as *const u8));
-                                      
-                                      match l35 {
-                                        0 => None,
-                                        1 => {
-                                          let e = {
-                                            let l36 = *((base + 12) as *const i32);
-                                            
-                                            l36
-                                          };
-                                          Some(e)
-                                        }
-                                        _ => wit_bindgen::rt::invalid_enum_discriminant(),
-                                      }
-                                    };
-                                    WitNode::OptionValue(e61)
+                                  wit_bindgen::rt::dealloc(base16, (len16 as usize) * 1, 1);
+                                  
+                                  result16
+                                };
+                                V49::FlagsValue(e49)
+                              }
+                              4 => {
+                                let e49 = {
+                                  let l17 = *((base + 8) as *const i32);
+                                  let l18 = *((base + 12) as *const i32);
+                                  let len19 = l18 as usize;
+                                  
+                                  Vec::from_raw_parts(l17 as *mut _, len19, len19)
+                                };
+                                V49::TupleValue(e49)
+                              }
+                              5 => {
+                                let e49 = {
+                                  let l20 = *((base + 8) as *const i32);
+                                  let l21 = *((base + 12) as *const i32);
+                                  let len22 = l21 as usize;
+                                  
+                                  Vec::from_raw_parts(l20 as *mut _, len22, len22)
+                                };
+                                V49::ListValue(e49)
+                              }
+                              6 => {
+                                let e49 = {
+                                  let l23 = i32::from(*((base + 8) as *const u8));
+                                  
+                                  match l23 {
+                                    0 => None,
+                                    1 => {
+                                      let e = {
+                                        let l24 = *((base + 12) as *const i32);
+                                        
+                                        l24
+                                      };
+                                      Some(e)
+                                    }
+                                    _ => wit_bindgen::rt::invalid_enum_discriminant(),
                                   }
-                                  7 => {
-                                    let e61 = {
-                                      let l37 = i32::from(*((base + 8) as *const u8));
-                                      
-                                      match l37 {
-                                        0 => {
-                                          let e = {
-                                            let l38 = i32::from(*((base + 12) as *const u8));
-                                            
-                                            match l38 {
-                                              0 => None,
-                                              1 => {
-                                                let e = {
-                                                  let l39 = *((base + 16) as *const i32);
-                                                  
-                                                  l39
-                                                };
-                                                Some(e)
-                                              }
-                                              _ => wit_bindgen::rt::invalid_enum_discriminant(),
-                                            }
-                                          };
-                                          Ok(e)
+                                };
+                                V49::OptionValue(e49)
+                              }
+                              7 => {
+                                let e49 = {
+                                  let l25 = i32::from(*((base + 8) as *const u8));
+                                  
+                                  match l25 {
+                                    0 => {
+                                      let e = {
+                                        let l26 = i32::from(*((base + 12) as *const u8));
+                                        
+                                        match l26 {
+                                          0 => None,
+                                          1 => {
+                                            let e = {
+                                              let l27 = *((base + 16) as *const i32);
+                                              
+                                              l27
+                                            };
+                                            Some(e)
+                                          }
+                                          _ => wit_bindgen::rt::invalid_enum_discriminant(),
                                         }
-                                        1 => {
-                                          let e = {
-                                            let l40 = i32::from(*((base + 12) as *const u8));
-                                            
-                                            match l40 {
-                                              0 => None,
-                                              1 => {
-                                                let e = {
-                                                  let l41 = *((base + 16) as *const i32);
-                                                  
-                                                  l41
-                                                };
-                                                Some(e)
-                                              }
-                                              _ => wit_bindgen::rt::invalid_enum_discriminant(),
-                                            }
-                                          };
-                                          Err(e)
+                                      };
+                                      Ok(e)
+                                    }
+                                    1 => {
+                                      let e = {
+                                        let l28 = i32::from(*((base + 12) as *const u8));
+                                        
+                                        match l28 {
+                                          0 => None,
+                                          1 => {
+                                            let e = {
+                                              let l29 = *((base + 16) as *const i32);
+                                              
+                                              l29
+                                            };
+                                            Some(e)
+                                          }
+                                          _ => wit_bindgen::rt::invalid_enum_discriminant(),
                                         }
-                                        _ => wit_bindgen::rt::invalid_enum_discriminant(),
-                                      }
-                                    };
-                                    WitNode::ResultValue(e61)
-                                  }
-                                  8 => {
-                                    let e61 = {
-                                      let l42 = i32::from(*((base + 8) as *const u8));
-                                      
-                                      l42 as u8
-                                    };
-                                    WitNode::PrimU8(e61)
-                                  }
-                                  9 => {
-                                    let e61 = {
-                                      let l43 = i32::from(*((base + 8) as *const u16));
-                                      
-                                      l43 as u16
-                                    };
-                                    WitNode::PrimU16(e61)
-                                  }
-                                  10 => {
-                                    let e61 = {
-                                      let l44 = *((base + 8) as *const i32);
-                                      
-                                      l44 as u32
-                                    };
-                                    WitNode::PrimU32(e61)
-                                  }
-                                  11 => {
-                                    let e61 = {
-                                      let l45 = *((base + 8) as *const i64);
-                                      
-                                      l45 as u64
-                                    };
-                                    WitNode::PrimU64(e61)
-                                  }
-                                  12 => {
-                                    let e61 = {
-                                      let l46 = i32::from(*((base + 8) as *const i8));
-                                      
-                                      l46 as i8
-                                    };
-                                    WitNode::PrimS8(e61)
-                                  }
-                                  13 => {
-                                    let e61 = {
-                                      let l47 = i32::from(*((base + 8) as *const i16));
-                                      
-                                      l47 as i16
-                                    };
-                                    WitNode::PrimS16(e61)
-                                  }
-                                  14 => {
-                                    let e61 = {
-                                      let l48 = *((base + 8) as *const i32);
-                                      
-                                      l48
-                                    };
-                                    WitNode::PrimS32(e61)
-                                  }
-                                  15 => {
-                                    let e61 = {
-                                      let l49 = *((base + 8) as *const i64);
-                                      
-                                      l49
-                                    };
-                                    WitNode::PrimS64(e61)
-                                  }
-                                  16 => {
-                                    let e61 = {
-                                      let l50 = *((base + 8) as *const f32);
-                                      
-                                      l50
-                                    };
-                                    WitNode::PrimFloat32(e61)
-                                  }
-                                  17 => {
-                                    let e61 = {
-                                      let l51 = *((base + 8) as *const f64);
-                                      
-                                      l51
-                                    };
-                                    WitNode::PrimFloat64(e61)
-                                  }
-                                  18 => {
-                                    let e61 = {
-                                      let l52 = *((base + 8) as *const i32);
-                                      
-                                      wit_bindgen::rt::char_lift(l52 as u32)
-                                    };
-                                    WitNode::PrimChar(e61)
-                                  }
-                                  19 => {
-                                    let e61 = {
-                                      let l53 = i32::from(*((base + 8) as *const u8));
-                                      
-                                      wit_bindgen::rt::bool_lift(l53 as u8)
-                                    };
-                                    WitNode::PrimBool(e61)
-                                  }
-                                  20 => {
-                                    let e61 = {
-                                      let l54 = *((base + 8) as *const i32);
-                                      let l55 = *((base + 12) as *const i32);
-                                      let len56 = l55 as usize;
-                                      let bytes56 = Vec::from_raw_parts(l54 as *mut _, len56, len56);
-                                      
-                                      wit_bindgen::rt::string_lift(bytes56)
-                                    };
-                                    WitNode::PrimString(e61)
-                                  }
-                                  n => {
-                                    debug_assert_eq!(n, 21, "invalid enum discriminant");
-                                    let e61 = {
-                                      let l57 = *((base + 8) as *const i32);
-                                      let l58 = *((base + 12) as *const i32);
-                                      let len59 = l58 as usize;
-                                      let bytes59 = Vec::from_raw_parts(l57 as *mut _, len59, len59);
-                                      let l60 = *((base + 16) as *const i64);
-                                      
-                                      (Uri{
-                                        value: wit_bindgen::rt::string_lift(bytes59),
-                                      }, l60 as u64)
-                                    };
-                                    WitNode::Handle(e61)
+                                      };
+                                      Err(e)
+                                    }
+                                    _ => wit_bindgen::rt::invalid_enum_discriminant(),
                                   }
                                 };
-                                
-                                v61
-                              };
-                              result62.push(e62);
-                            }
-                            wit_bindgen::rt::dealloc(base62, (len62 as usize) * 24, 8);
-                            
-                            WitValue{
-                              nodes: result62,
-                            }
-                          };
-                          Ok(e)
-                        }
-                        1 => {
-                          let e = {
-                            let l63 = i32::from(*((ptr13 + 4) as *const u8));
-                            let v76 = match l63 {
-                              0 => {
-                                let e76 = {
-                                  let l64 = *((ptr13 + 8) as *const i32);
-                                  let l65 = *((ptr13 + 12) as *const i32);
-                                  let len66 = l65 as usize;
-                                  let bytes66 = Vec::from_raw_parts(l64 as *mut _, len66, len66);
+                                V49::ResultValue(e49)
+                              }
+                              8 => {
+                                let e49 = {
+                                  let l30 = i32::from(*((base + 8) as *const u8));
                                   
-                                  wit_bindgen::rt::string_lift(bytes66)
+                                  l30 as u8
                                 };
-                                RpcError::ProtocolError(e76)
+                                V49::PrimU8(e49)
                               }
-                              1 => {
-                                let e76 = {
-                                  let l67 = *((ptr13 + 8) as *const i32);
-                                  let l68 = *((ptr13 + 12) as *const i32);
-                                  let len69 = l68 as usize;
-                                  let bytes69 = Vec::from_raw_parts(l67 as *mut _, len69, len69);
+                              9 => {
+                                let e49 = {
+                                  let l31 = i32::from(*((base + 8) as *const u16));
                                   
-                                  wit_bindgen::rt::string_lift(bytes69)
+                                  l31 as u16
                                 };
-                                RpcError::Denied(e76)
+                                V49::PrimU16(e49)
                               }
-                              2 => {
-                                let e76 = {
-                                  let l70 = *((ptr13 + 8) as *const i32);
-                                  let l71 = *((ptr13 + 12) as *const i32);
-                                  let len72 = l71 as usize;
-                                  let bytes72 = Vec::from_raw_parts(l70 as *mut _, len72, len72);
+                              10 => {
+                                let e49 = {
+                                  let l32 = *((base + 8) as *const i32);
+                                  
+                                  l32 as u32
+                                };
+                                V49::PrimU32(e49)
+                              }
+                              11 => {
+                                let e49 = {
+                                  let l33 = *((base + 8) as *const i64);
+                                  
+                                  l33 as u64
+                                };
+                                V49::PrimU64(e49)
+                              }
+                              12 => {
+                                let e49 = {
+                                  let l34 = i32::from(*((base + 8) as *const i8));
+                                  
+                                  l34 as i8
+                                };
+                                V49::PrimS8(e49)
+                              }
+                              13 => {
+                                let e49 = {
+                                  let l35 = i32::from(*((base + 8) as *const i16));
+                                  
+                                  l35 as i16
+                                };
+                                V49::PrimS16(e49)
+                              }
+                              14 => {
+                                let e49 = {
+                                  let l36 = *((base + 8) as *const i32);
+                                  
+                                  l36
+                                };
+                                V49::PrimS32(e49)
+                              }
+                              15 => {
+                                let e49 = {
+                                  let l37 = *((base + 8) as *const i64);
+                                  
+                                  l37
+                                };
+                                V49::PrimS64(e49)
+                              }
+                              16 => {
+                                let e49 = {
+                                  let l38 = *((base + 8) as *const f32);
+                                  
+                                  l38
+                                };
+                                V49::PrimFloat32(e49)
+                              }
+                              17 => {
+                                let e49 = {
+                                  let l39 = *((base + 8) as *const f64);
+                                  
+                                  l39
+                                };
+                                V49::PrimFloat64(e49)
+                              }
+                              18 => {
+                                let e49 = {
+                                  let l40 = *((base + 8) as *const i32);
+                                  
+                                  wit_bindgen::rt::char_lift(l40 as u32)
+                                };
+                                V49::PrimChar(e49)
+                              }
+                              19 => {
+                                let e49 = {
+                                  let l41 = i32::from(*((base + 8) as *const u8));
+                                  
+                                  wit_bindgen::rt::bool_lift(l41 as u8)
+                                };
+                                V49::PrimBool(e49)
+                              }
+                              20 => {
+                                let e49 = {
+                                  let l42 = *((base + 8) as *const i32);
+                                  let l43 = *((base + 12) as *const i32);
+                                  let len44 = l43 as usize;
+                                  let bytes44 = Vec::from_raw_parts(l42 as *mut _, len44, len44);
                                   
-                                  wit_bindgen::rt::string_lift(bytes72)
+                                  wit_bindgen::rt::string_lift(bytes44)
                                 };
-                                RpcError::NotFound(e76)
+                                V49::PrimString(e49)
                               }
                               n => {
-                                debug_assert_eq!(n, 3, "invalid enum discriminant");
-                                let e76 = {
-                                  let l73 = *((ptr13 + 8) as *const i32);
-                                  let l74 = *((ptr13 + 12) as *const i32);
-                                  let len75 = l74 as usize;
-                                  let bytes75 = Vec::from_raw_parts(l73 as *mut _, len75, len75);
+                                debug_assert_eq!(n, 21, "invalid enum discriminant");
+                                let e49 = {
+                                  let l45 = *((base + 8) as *const i32);
+                                  let l46 = *((base + 12) as *const i32);
+                                  let len47 = l46 as usize;
+                                  let bytes47 = Vec::from_raw_parts(l45 as *mut _, len47, len47);
+                                  let l48 = *((base + 16) as *const i64);
                                   
-                                  wit_bindgen::rt::string_lift(bytes75)
+                                  (super::super::super::golem::rpc::types::Uri{
+                                    value: wit_bindgen::rt::string_lift(bytes47),
+                                  }, l48 as u64)
                                 };
-                                RpcError::RemoteInternalError(e76)
+                                V49::Handle(e49)
                               }
                             };
                             
-                            v76
+                            v49
                           };
-                          Err(e)
+                          result50.push(e50);
+                        }
+                        wit_bindgen::rt::dealloc(base50, (len50 as usize) * 24, 8);
+                        
+                        super::super::super::golem::rpc::types::WitValue{
+                          nodes: result50,
+                        }
+                      };
+                      Ok(e)
+                    }
+                    1 => {
+                      let e = {
+                        let l51 = i32::from(*((ptr0 + 8) as *const u8));
+                        use super::super::super::golem::rpc::types::RpcError as V64;
+                        let v64 = match l51 {
+                          0 => {
+                            let e64 = {
+                              let l52 = *((ptr0 + 12) as *const i32);
+                              let l53 = *((ptr0 + 16) as *const i32);
+                              let len54 = l53 as usize;
+                              let bytes54 = Vec::from_raw_parts(l52 as *mut _, len54, len54);
+                              
+                              wit_bindgen::rt::string_lift(bytes54)
+                            };
+                            V64::ProtocolError(e64)
+                          }
+                          1 => {
+                            let e64 = {
+                              let l55 = *((ptr0 + 12) as *const i32);
+                              let l56 = *((ptr0 + 16) as *const i32);
+                              let len57 = l56 as usize;
+                              let bytes57 = Vec::from_raw_parts(l55 as *mut _, len57, len57);
+                              
+                              wit_bindgen::rt::string_lift(bytes57)
+                            };
+                            V64::Denied(e64)
+                          }
+                          2 => {
+                            let e64 = {
+                              let l58 = *((ptr0 + 12) as *const i32);
+                              let l59 = *((ptr0 + 16) as *const i32);
+                              let len60 = l59 as usize;
+                              let bytes60 = Vec::from_raw_parts(l58 as *mut _, len60, len60);
+                              
+                              wit_bindgen::rt::string_lift(bytes60)
+                            };
+                            V64::NotFound(e64)
+                          }
+                          n => {
+                            debug_assert_eq!(n, 3, "invalid enum discriminant");
+                            let e64 = {
+                              let l61 = *((ptr0 + 12) as *const i32);
+                              let l62 = *((ptr0 + 16) as *const i32);
+                              let len63 = l62 as usize;
+                              let bytes63 = Vec::from_raw_parts(l61 as *mut _, len63, len63);
+                              
+                              wit_bindgen::rt::string_lift(bytes63)
+                            };
+                            V64::RemoteInternalError(e64)
+                          }
+                        };
+                        
+                        v64
+                      };
+                      Err(e)
+                    }
+                    _ => wit_bindgen::rt::invalid_enum_discriminant(),
+                  }
+                };
+                Some(e)
+              }
+              _ => wit_bindgen::rt::invalid_enum_discriminant(),
+            }
+          }
+        }
+      }
+      #[allow(unused_unsafe, clippy::all)]
+      /// Starts invoking a function on the remote worker of `rpc` without waiting for its result
+      pub fn async_invoke_and_await(rpc: &WasmRpc,function_name: &str,function_params: &[WitValue],) -> FutureInvokeResult{
+        
+        #[allow(unused_imports)]
+        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+        unsafe {
+          let mut cleanup_list = Vec::new();
+          let vec0 = function_name;
+          let ptr0 = vec0.as_ptr() as i32;
+          let len0 = vec0.len() as i32;
+          let vec13 = function_params;
+          let len13 = vec13.len() as i32;
+          let layout13 = alloc::Layout::from_size_align_unchecked(vec13.len() * 8, 4);
+          let result13 = if layout13.size() != 0
+          {
+            let ptr = alloc::alloc(layout13);
+            if ptr.is_null()
+            {
+              alloc::handle_alloc_error(layout13);
+            }
+            ptr
+          }else {{
+            ::core::ptr::null_mut()
+          }};
+          for (i, e) in vec13.into_iter().enumerate() {
+            let base = result13 as i32 + (i as i32) * 8;
+            {
+              let super::super::super::golem::rpc::types::WitValue{ nodes:nodes1, } = e;
+              let vec12 = nodes1;
+              let len12 = vec12.len() as i32;
+              let layout12 = alloc::Layout::from_size_align_unchecked(vec12.len() * 24, 8);
+              let result12 = if layout12.size() != 0
+              {
+                let ptr = alloc::alloc(layout12);
+                if ptr.is_null()
+                {
+                  alloc::handle_alloc_error(layout12);
+                }
+                ptr
+              }else {{
+                ::core::ptr::null_mut()
+              }};
+              for (i, e) in vec12.into_iter().enumerate() {
+                let base = result12 as i32 + (i as i32) * 24;
+                {
+                  use super::super::super::golem::rpc::types::WitNode as V11;
+                  match e {
+                    V11::RecordValue(e) => {
+                      *((base + 0) as *mut u8) = (0i32) as u8;
+                      let vec2 = e;
+                      let ptr2 = vec2.as_ptr() as i32;
+                      let len2 = vec2.len() as i32;
+                      *((base + 12) as *mut i32) = len2;
+                      *((base + 8) as *mut i32) = ptr2;
+                    },
+                    V11::VariantValue(e) => {
+                      *((base + 0) as *mut u8) = (1i32) as u8;
+                      let (t3_0, t3_1, ) = e;
+                      *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(t3_0);
+                      match t3_1 {
+                        Some(e) => {
+                          *((base + 12) as *mut u8) = (1i32) as u8;
+                          *((base + 16) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                        },
+                        None => {
+                          {
+                            *((base + 12) as *mut u8) = (0i32) as u8;
+                          }
+                        },
+                      };},
+                      V11::EnumValue(e) => {
+                        *((base + 0) as *mut u8) = (2i32) as u8;
+                        *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                      },
+                      V11::FlagsValue(e) => {
+                        *((base + 0) as *mut u8) = (3i32) as u8;
+                        let vec4 = e;
+                        let len4 = vec4.len() as i32;
+                        let layout4 = alloc::Layout::from_size_align_unchecked(vec4.len() * 1, 1);
+                        let result4 = if layout4.size() != 0
+                        {
+                          let ptr = alloc::alloc(layout4);
+                          if ptr.is_null()
+                          {
+                            alloc::handle_alloc_error(layout4);
+                          }
+                          ptr
+                        }else {{
+                          ::core::ptr::null_mut()
+                        }};
+                        for (i, e) in vec4.into_iter().enumerate() {
+                          let base = result4 as i32 + (i as i32) * 1;
+                          {
+                            *((base + 0) as *mut u8) = (match e { true => 1, false => 0 }) as u8;
+                          }
+                        }
+                        *((base + 12) as *mut i32) = len4;
+                        *((base + 8) as *mut i32) = result4 as i32;
+                        cleanup_list.extend_from_slice(&[(result4, layout4),]);
+                      },
+                      V11::TupleValue(e) => {
+                        *((base + 0) as *mut u8) = (4i32) as u8;
+                        let vec5 = e;
+                        let ptr5 = vec5.as_ptr() as i32;
+                        let len5 = vec5.len() as i32;
+                        *((base + 12) as *mut i32) = len5;
+                        *((base + 8) as *mut i32) = ptr5;
+                      },
+                      V11::ListValue(e) => {
+                        *((base + 0) as *mut u8) = (5i32) as u8;
+                        let vec6 = e;
+                        let ptr6 = vec6.as_ptr() as i32;
+                        let len6 = vec6.len() as i32;
+                        *((base + 12) as *mut i32) = len6;
+                        *((base + 8) as *mut i32) = ptr6;
+                      },
+                      V11::OptionValue(e) => {
+                        *((base + 0) as *mut u8) = (6i32) as u8;
+                        match e {
+                          Some(e) => {
+                            *((base + 8) as *mut u8) = (1i32) as u8;
+                            *((base + 12) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                          },
+                          None => {
+                            {
+                              *((base + 8) as *mut u8) = (0i32) as u8;
+                            }
+                          },
+                        };},
+                        V11::ResultValue(e) => {
+                          *((base + 0) as *mut u8) = (7i32) as u8;
+                          match e {
+                            Ok(e) => { {
+                              *((base + 8) as *mut u8) = (0i32) as u8;
+                              match e {
+                                Some(e) => {
+                                  *((base + 12) as *mut u8) = (1i32) as u8;
+                                  *((base + 16) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                                },
+                                None => {
+                                  {
+                                    *((base + 12) as *mut u8) = (0i32) as u8;
+                                  }
+                                },
+                              };} },
+                              Err(e) => { {
+                                *((base + 8) as *mut u8) = (1i32) as u8;
+                                match e {
+                                  Some(e) => {
+                                    *((base + 12) as *mut u8) = (1i32) as u8;
+                                    *((base + 16) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                                  },
+                                  None => {
+                                    {
+                                      *((base + 12) as *mut u8) = (0i32) as u8;
+                                    }
+                                  },
+                                };} },
+                              };},
+                              V11::PrimU8(e) => {
+                                *((base + 0) as *mut u8) = (8i32) as u8;
+                                *((base + 8) as *mut u8) = (wit_bindgen::rt::as_i32(e)) as u8;
+                              },
+                              V11::PrimU16(e) => {
+                                *((base + 0) as *mut u8) = (9i32) as u8;
+                                *((base + 8) as *mut u16) = (wit_bindgen::rt::as_i32(e)) as u16;
+                              },
+                              V11::PrimU32(e) => {
+                                *((base + 0) as *mut u8) = (10i32) as u8;
+                                *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                              },
+                              V11::PrimU64(e) => {
+                                *((base + 0) as *mut u8) = (11i32) as u8;
+                                *((base + 8) as *mut i64) = wit_bindgen::rt::as_i64(e);
+                              },
+                              V11::PrimS8(e) => {
+                                *((base + 0) as *mut u8) = (12i32) as u8;
+                                *((base + 8) as *mut u8) = (wit_bindgen::rt::as_i32(e)) as u8;
+                              },
+                              V11::PrimS16(e) => {
+                                *((base + 0) as *mut u8) = (13i32) as u8;
+                                *((base + 8) as *mut u16) = (wit_bindgen::rt::as_i32(e)) as u16;
+                              },
+                              V11::PrimS32(e) => {
+                                *((base + 0) as *mut u8) = (14i32) as u8;
+                                *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                              },
+                              V11::PrimS64(e) => {
+                                *((base + 0) as *mut u8) = (15i32) as u8;
+                                *((base + 8) as *mut i64) = wit_bindgen::rt::as_i64(e);
+                              },
+                              V11::PrimFloat32(e) => {
+                                *((base + 0) as *mut u8) = (16i32) as u8;
+                                *((base + 8) as *mut f32) = wit_bindgen::rt::as_f32(e);
+                              },
+                              V11::PrimFloat64(e) => {
+                                *((base + 0) as *mut u8) = (17i32) as u8;
+                                *((base + 8) as *mut f64) = wit_bindgen::rt::as_f64(e);
+                              },
+                              V11::PrimChar(e) => {
+                                *((base + 0) as *mut u8) = (18i32) as u8;
+                                *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                              },
+                              V11::PrimBool(e) => {
+                                *((base + 0) as *mut u8) = (19i32) as u8;
+                                *((base + 8) as *mut u8) = (match e { true => 1, false => 0 }) as u8;
+                              },
+                              V11::PrimString(e) => {
+                                *((base + 0) as *mut u8) = (20i32) as u8;
+                                let vec7 = e;
+                                let ptr7 = vec7.as_ptr() as i32;
+                                let len7 = vec7.len() as i32;
+                                *((base + 12) as *mut i32) = len7;
+                                *((base + 8) as *mut i32) = ptr7;
+                              },
+                              V11::Handle(e) => {
+                                *((base + 0) as *mut u8) = (21i32) as u8;
+                                let (t8_0, t8_1, ) = e;
+                                let super::super::super::golem::rpc::types::Uri{ value:value9, } = t8_0;
+                                let vec10 = value9;
+                                let ptr10 = vec10.as_ptr() as i32;
+                                let len10 = vec10.len() as i32;
+                                *((base + 12) as *mut i32) = len10;
+                                *((base + 8) as *mut i32) = ptr10;
+                                *((base + 16) as *mut i64) = wit_bindgen::rt::as_i64(t8_1);
+                              },
+                            }
+                          }
                         }
-                        _ => wit_bindgen::rt::invalid_enum_discriminant(),
+                        *((base + 4) as *mut i32) = len12;
+                        *((base + 0) as *mut i32) = result12 as i32;
+                        cleanup_list.extend_from_slice(&[(result12, layout12),]);
+                      }
+                    }
+                    
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:ext/async-rpc@0.1.0")]
+                    extern "C" {
+                      #[link_name = "async-invoke-and-await"]
+                      fn wit_import(_: i32, _: i32, _: i32, _: i32, _: i32, ) -> i32;
+                    }
+                    
+                    #[cfg(not(target_arch = "wasm32"))]
+                    fn wit_import(_: i32, _: i32, _: i32, _: i32, _: i32, ) -> i32{ unreachable!() }
+                    let ret = wit_import((rpc).handle() as i32, ptr0, len0, result13 as i32, len13);
+                    if layout13.size() != 0 {
+                      alloc::dealloc(result13, layout13);
+                    }
+                    for (ptr, layout) in cleanup_list {
+                      
+                      if layout.size() != 0 {
+                        
+                        alloc::dealloc(ptr, layout);
+                        
                       }
+                      
                     }
+                    FutureInvokeResult::from_handle(ret as u32)
                   }
                 }
-                impl WasmRpc {
-                  #[allow(unused_unsafe, clippy::all)]
-                  pub fn invoke(&self,function_name: &str,function_params: &[WitValue],) -> Result<(),RpcError>{
+                
+              }
+              
+              
+              #[allow(clippy::all)]
+              pub mod broadcast {
+                #[used]
+                #[doc(hidden)]
+                #[cfg(target_arch = "wasm32")]
+                static __FORCE_SECTION_REF: fn() = super::super::super::__link_section;
+                pub type ComponentId = super::super::super::golem::api::host::ComponentId;
+                pub type WorkerId = super::super::super::golem::api::host::WorkerId;
+                pub type WorkerAnyFilter = super::super::super::golem::api::host::WorkerAnyFilter;
+                pub type WitValue = super::super::super::golem::rpc::types::WitValue;
+                pub type RpcError = super::super::super::golem::rpc::types::RpcError;
+                /// Outcome of the invocation of one worker
+                #[derive(Clone)]
+                pub struct BroadcastResult {
+                  pub worker_id: WorkerId,
+                  pub result: Result<WitValue,RpcError>,
+                }
+                impl ::core::fmt::Debug for BroadcastResult {
+                  fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_struct("BroadcastResult").field("worker-id", &self.worker_id).field("result", &self.result).finish()
+                  }
+                }
+                #[allow(unused_unsafe, clippy::all)]
+                /// Invokes a function on every worker of a component matching the filter, running at most
+                /// `max-concurrency` invocations at the same time, and waits for all of them to finish.
+                /// The concurrency is capped by the limit configured for the executor.
+                /// The calling worker is never invoked, even if it matches the filter.
+                pub fn broadcast_invoke_and_await(component_id: ComponentId,filter: Option<&WorkerAnyFilter>,function_name: &str,function_params: &[WitValue],max_concurrency: u32,) -> wit_bindgen::rt::vec::Vec::<BroadcastResult>{
+                  
+                  #[allow(unused_imports)]
+                  use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                  unsafe {
+                    let mut cleanup_list = Vec::new();
                     
-                    #[allow(unused_imports)]
-                    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
-                    unsafe {
-                      let mut cleanup_list = Vec::new();
-                      
-                      #[repr(align(4))]
-                      struct RetArea([u8; 16]);
-                      let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
-                      let vec0 = function_name;
-                      let ptr0 = vec0.as_ptr() as i32;
-                      let len0 = vec0.len() as i32;
-                      let vec12 = function_params;
-                      let len12 = vec12.len() as i32;
-                      let layout12 = alloc::Layout::from_size_align_unchecked(vec12.len() * 8, 4);
-                      let result12 = if layout12.size() != 0
-                      {
-                        let ptr = alloc::alloc(layout12);
-                        if ptr.is_null()
-                        {
-                          alloc::handle_alloc_error(layout12);
-                        }
-                        ptr
-                      }else {{
-                        ::core::ptr::null_mut()
-                      }};
-                      for (i, e) in vec12.into_iter().enumerate() {
-                        let base = result12 as i32 + (i as i32) * 8;
+                    #[repr(align(4))]
+                    struct RetArea([u8; 8]);
+                    let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                    let super::super::super::golem::api::host::ComponentId{ uuid:uuid0, } = component_id;
+                    let super::super::super::golem::api::host::Uuid{ high_bits:high_bits1, low_bits:low_bits1, } = uuid0;
+                    let (result15_0,result15_1,result15_2,) = match filter {
+                      Some(e) => {
+                        let super::super::super::golem::api::host::WorkerAnyFilter{ filters:filters2, } = e;
+                        let vec14 = filters2;
+                        let len14 = vec14.len() as i32;
+                        let layout14 = alloc::Layout::from_size_align_unchecked(vec14.len() * 8, 4);
+                        let result14 = if layout14.size() != 0
                         {
-                          let WitValue{ nodes:nodes1, } = e;
-                          let vec11 = nodes1;
-                          let len11 = vec11.len() as i32;
-                          let layout11 = alloc::Layout::from_size_align_unchecked(vec11.len() * 24, 8);
-                          let result11 = if layout11.size() != 0
+                          let ptr = alloc::alloc(layout14);
+                          if ptr.is_null()
                           {
-                            let ptr = alloc::alloc(layout11);
-                            if ptr.is_null()
+                            alloc::handle_alloc_error(layout14);
+                          }
+                          ptr
+                        }else {{
+                          ::core::ptr::null_mut()
+                        }};
+                        for (i, e) in vec14.into_iter().enumerate() {
+                          let base = result14 as i32 + (i as i32) * 8;
+                          {
+                            let super::super::super::golem::api::host::WorkerAllFilter{ filters:filters3, } = e;
+                            let vec13 = filters3;
+                            let len13 = vec13.len() as i32;
+                            let layout13 = alloc::Layout::from_size_align_unchecked(vec13.len() * 32, 8);
+                            let result13 = if layout13.size() != 0
                             {
-                              alloc::handle_alloc_error(layout11);
+                              let ptr = alloc::alloc(layout13);
+                              if ptr.is_null()
+                              {
+                                alloc::handle_alloc_error(layout13);
+                              }
+                              ptr
+                            }else {{
+                              ::core::ptr::null_mut()
+                            }};
+                            for (i, e) in vec13.into_iter().enumerate() {
+                              let base = result13 as i32 + (i as i32) * 32;
+                              {
+                                use super::super::super::golem::api::host::WorkerPropertyFilter as V12;
+                                match e {
+                                  V12::Name(e) => {
+                                    *((base + 0) as *mut u8) = (0i32) as u8;
+                                    let super::super::super::golem::api::host::WorkerNameFilter{ comparator:comparator4, value:value4, } = e;
+                                    *((base + 8) as *mut u8) = (comparator4.clone() as i32) as u8;
+                                    let vec5 = value4;
+                                    let ptr5 = vec5.as_ptr() as i32;
+                                    let len5 = vec5.len() as i32;
+                                    *((base + 16) as *mut i32) = len5;
+                                    *((base + 12) as *mut i32) = ptr5;
+                                  },
+                                  V12::Status(e) => {
+                                    *((base + 0) as *mut u8) = (1i32) as u8;
+                                    let super::super::super::golem::api::host::WorkerStatusFilter{ comparator:comparator6, value:value6, } = e;
+                                    *((base + 8) as *mut u8) = (comparator6.clone() as i32) as u8;
+                                    *((base + 9) as *mut u8) = (value6.clone() as i32) as u8;
+                                  },
+                                  V12::Version(e) => {
+                                    *((base + 0) as *mut u8) = (2i32) as u8;
+                                    let super::super::super::golem::api::host::WorkerVersionFilter{ comparator:comparator7, value:value7, } = e;
+                                    *((base + 8) as *mut u8) = (comparator7.clone() as i32) as u8;
+                                    *((base + 16) as *mut i64) = wit_bindgen::rt::as_i64(value7);
+                                  },
+                                  V12::CreatedAt(e) => {
+                                    *((base + 0) as *mut u8) = (3i32) as u8;
+                                    let super::super::super::golem::api::host::WorkerCreatedAtFilter{ comparator:comparator8, value:value8, } = e;
+                                    *((base + 8) as *mut u8) = (comparator8.clone() as i32) as u8;
+                                    *((base + 16) as *mut i64) = wit_bindgen::rt::as_i64(value8);
+                                  },
+                                  V12::Env(e) => {
+                                    *((base + 0) as *mut u8) = (4i32) as u8;
+                                    let super::super::super::golem::api::host::WorkerEnvFilter{ name:name9, comparator:comparator9, value:value9, } = e;
+                                    let vec10 = name9;
+                                    let ptr10 = vec10.as_ptr() as i32;
+                                    let len10 = vec10.len() as i32;
+                                    *((base + 12) as *mut i32) = len10;
+                                    *((base + 8) as *mut i32) = ptr10;
+                                    *((base + 16) as *mut u8) = (comparator9.clone() as i32) as u8;
+                                    let vec11 = value9;
+                                    let ptr11 = vec11.as_ptr() as i32;
+                                    let len11 = vec11.len() as i32;
+                                    *((base + 24) as *mut i32) = len11;
+                                    *((base + 20) as *mut i32) = ptr11;
+                                  },
+                                }
+                              }
                             }
-                            ptr
-                          }else {{
-                            ::core::ptr::null_mut()
-                          }};
-                          for (i, e) in vec11.into_iter().enumerate() {
-                            let base = result11 as i32 + (i as i32) * 24;
-                            {
-                              match e {
-                                WitNode::RecordValue(e) => {
-                                  *((base + 0) as *mut u8) = (0i32) as u8;
-                                  let vec2 = e;
-                                  let ptr2 = vec2.as_ptr() as i32;
-                                  let len2 = vec2.len() as i32;
-                                  *((base + 12) as *mut i32) = len2;
-                                  *((base + 8) as *mut i32) = ptr2;
+                            *((base + 4) as *mut i32) = len13;
+                            *((base + 0) as *mut i32) = result13 as i32;
+                            cleanup_list.extend_from_slice(&[(result13, layout13),]);
+                          }
+                        }
+                        cleanup_list.extend_from_slice(&[(result14, layout14),]);
+                        
+                        (1i32, result14 as i32, len14)
+                      },
+                      None => {
+                        (0i32, 0i32, 0i32)
+                      },
+                    };let vec16 = function_name;
+                    let ptr16 = vec16.as_ptr() as i32;
+                    let len16 = vec16.len() as i32;
+                    let vec29 = function_params;
+                    let len29 = vec29.len() as i32;
+                    let layout29 = alloc::Layout::from_size_align_unchecked(vec29.len() * 8, 4);
+                    let result29 = if layout29.size() != 0
+                    {
+                      let ptr = alloc::alloc(layout29);
+                      if ptr.is_null()
+                      {
+                        alloc::handle_alloc_error(layout29);
+                      }
+                      ptr
+                    }else {{
+                      ::core::ptr::null_mut()
+                    }};
+                    for (i, e) in vec29.into_iter().enumerate() {
+                      let base = result29 as i32 + (i as i32) * 8;
+                      {
+                        let super::super::super::golem::rpc::types::WitValue{ nodes:nodes17, } = e;
+                        let vec28 = nodes17;
+                        let len28 = vec28.len() as i32;
+                        let layout28 = alloc::Layout::from_size_align_unchecked(vec28.len() * 24, 8);
+                        let result28 = if layout28.size() != 0
+                        {
+                          let ptr = alloc::alloc(layout28);
+                          if ptr.is_null()
+                          {
+                            alloc::handle_alloc_error(layout28);
+                          }
+                          ptr
+                        }else {{
+                          ::core::ptr::null_mut()
+                        }};
+                        for (i, e) in vec28.into_iter().enumerate() {
+                          let base = result28 as i32 + (i as i32) * 24;
+                          {
+                            use super::super::super::golem::rpc::types::WitNode as V27;
+                            match e {
+                              V27::RecordValue(e) => {
+                                *((base + 0) as *mut u8) = (0i32) as u8;
+                                let vec18 = e;
+                                let ptr18 = vec18.as_ptr() as i32;
+                                let len18 = vec18.len() as i32;
+                                *((base + 12) as *mut i32) = len18;
+                                *((base + 8) as *mut i32) = ptr18;
+                              },
+                              V27::VariantValue(e) => {
+                                *((base + 0) as *mut u8) = (1i32) as u8;
+                                let (t19_0, t19_1, ) = e;
+                                *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(t19_0);
+                                match t19_1 {
+                                  Some(e) => {
+                                    *((base + 12) as *mut u8) = (1i32) as u8;
+                                    *((base + 16) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                                  },
+                                  None => {
+                                    {
+                                      *((base + 12) as *mut u8) = (0i32) as u8;
+                                    }
+                                  },
+                                };},
+                                V27::EnumValue(e) => {
+                                  *((base + 0) as *mut u8) = (2i32) as u8;
+                                  *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(e);
                                 },
-                                WitNode::VariantValue(e) => {
-                                  *((base + 0) as *mut u8) = (1i32) as u8;
-                                  let (t3_0, t3_1, ) = e;
-                                  *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(t3_0);
-                                  match t3_1 {
+                                V27::FlagsValue(e) => {
+                                  *((base + 0) as *mut u8) = (3i32) as u8;
+                                  let vec20 = e;
+                                  let len20 = vec20.len() as i32;
+                                  let layout20 = alloc::Layout::from_size_align_unchecked(vec20.len() * 1, 1);
+                                  let result20 = if layout20.size() != 0
+                                  {
+                                    let ptr = alloc::alloc(layout20);
+                                    if ptr.is_null()
+                                    {
+                                      alloc::handle_alloc_error(layout20);
+                                    }
+                                    ptr
+                                  }else {{
+                                    ::core::ptr::null_mut()
+                                  }};
+                                  for (i, e) in vec20.into_iter().enumerate() {
+                                    let base = result20 as i32 + (i as i32) * 1;
+                                    {
+                                      *((base + 0) as *mut u8) = (match e { true => 1, false => 0 }) as u8;
+                                    }
+                                  }
+                                  *((base + 12) as *mut i32) = len20;
+                                  *((base + 8) as *mut i32) = result20 as i32;
+                                  cleanup_list.extend_from_slice(&[(result20, layout20),]);
+                                },
+                                V27::TupleValue(e) => {
+                                  *((base + 0) as *mut u8) = (4i32) as u8;
+                                  let vec21 = e;
+                                  let ptr21 = vec21.as_ptr() as i32;
+                                  let len21 = vec21.len() as i32;
+                                  *((base + 12) as *mut i32) = len21;
+                                  *((base + 8) as *mut i32) = ptr21;
+                                },
+                                V27::ListValue(e) => {
+                                  *((base + 0) as *mut u8) = (5i32) as u8;
+                                  let vec22 = e;
+                                  let ptr22 = vec22.as_ptr() as i32;
+                                  let len22 = vec22.len() as i32;
+                                  *((base + 12) as *mut i32) = len22;
+                                  *((base + 8) as *mut i32) = ptr22;
+                                },
+                                V27::OptionValue(e) => {
+                                  *((base + 0) as *mut u8) = (6i32) as u8;
+                                  match e {
                                     Some(e) => {
-                                      *((base + 12) as *mut u8) = (1i32) as u8;
-                                      *((base + 16) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                                      *((base + 8) as *mut u8) = (1i32) as u8;
+                                      *((base + 12) as *mut i32) = wit_bindgen::rt::as_i32(e);
                                     },
                                     None => {
                                       {
-                                        *((base + 12) as *mut u8) = (0i32) as u8;
+                                        *((base + 8) as *mut u8) = (0i32) as u8;
                                       }
                                     },
                                   };},
-                                  WitNode::EnumValue(e) => {
-                                    *((base + 0) as *mut u8) = (2i32) as u8;
-                                    *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(e);
-                                  },
-                                  WitNode::FlagsValue(e) => {
-                                    *((base + 0) as *mut u8) = (3i32) as u8;
-                                    let vec4 = e;
-                                    let len4 = vec4.len() as i32;
-                                    let layout4 = alloc::Layout::from_size_align_unchecked(vec4.len() * 1, 1);
-                                    let result4 = if layout4.size() != 0
-                                    {
-                                      let ptr = alloc::alloc(layout4);
-                                      if ptr.is_null()
-                                      {
-                                        alloc::handle_alloc_error(layout4);
-                                      }
-                                      ptr
-                                    }else {{
-                                      ::core::ptr::null_mut()
-                                    }};
-                                    for (i, e) in vec4.into_iter().enumerate() {
-                                      let base = result4 as i32 + (i as i32) * 1;
-                                      {
-                                        *((base + 0) as *mut u8) = (match e { true => 1, false => 0 }) as u8;
-                                      }
-                                    }
-                                    *((base + 12) as *mut i32) = len4;
-                                    *((base + 8) as *mut i32) = result4 as i32;
-                                    cleanup_list.extend_from_slice(&[(result4, layout4),]);
-                                  },
-                                  WitNode::TupleValue(e) => {
-                                    *((base + 0) as *mut u8) = (4i32) as u8;
-                                    let vec5 = e;
-                                    let ptr5 = vec5.as_ptr() as i32;
-                                    let len5 = vec5.len() as i32;
-                                    *((base + 12) as *mut i32) = len5;
-                                    *((base + 8) as *mut i32) = ptr5;
-                                  },
-                                  WitNode::ListValue(e) => {
-                                    *((base + 0) as *mut u8) = (5i32) as u8;
-                                    let vec6 = e;
-                                    let ptr6 = vec6.as_ptr() as i32;
-                                    let len6 = vec6.len() as i32;
-                                    *((base + 12) as *mut i32) = len6;
-                                    *((base + 8) as *mut i32) = ptr6;
-                                  },
-                                  WitNode::OptionValue(e) => {
-                                    *((base + 0) as *mut u8) = (6i32) as u8;
+                                  V27::ResultValue(e) => {
+                                    *((base + 0) as *mut u8) = (7i32) as u8;
                                     match e {
-                                      Some(e) => {
-                                        *((base + 8) as *mut u8) = (1i32) as u8;
-                                        *((base + 12) as *mut i32) = wit_bindgen::rt::as_i32(e);
-                                      },
-                                      None => {
-                                        {
-                                          *((base + 8) as *mut u8) = (0i32) as u8;
-                                        }
-                                      },
-                                    };},
-                                    WitNode::ResultValue(e) => {
-                                      *((base + 0) as *mut u8) = (7i32) as u8;
-                                      match e {
-                                        Ok(e) => { {
-                                          *((base + 8) as *mut u8) = (0i32) as u8;
+                                      Ok(e) => { {
+                                        *((base + 8) as *mut u8) = (0i32) as u8;
+                                        match e {
+                                          Some(e) => {
+                                            *((base + 12) as *mut u8) = (1i32) as u8;
+                                            *((base + 16) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                                          },
+                                          None => {
+                                            {
+                                              *((base + 12) as *mut u8) = (0i32) as u8;
+                                            }
+                                          },
+                                        };} },
+                                        Err(e) => { {
+                                          *((base + 8) as *mut u8) = (1i32) as u8;
                                           match e {
                                             Some(e) => {
                                               *((base + 12) as *mut u8) = (1i32) as u8;
@@ -1140,253 +2570,662 @@ pub mod golem {
                                               }
                                             },
                                           };} },
-                                          Err(e) => { {
-                                            *((base + 8) as *mut u8) = (1i32) as u8;
-                                            match e {
-                                              Some(e) => {
-                                                *((base + 12) as *mut u8) = (1i32) as u8;
-                                                *((base + 16) as *mut i32) = wit_bindgen::rt::as_i32(e);
-                                              },
-                                              None => {
-                                                {
-                                                  *((base + 12) as *mut u8) = (0i32) as u8;
-                                                }
-                                              },
-                                            };} },
-                                          };},
-                                          WitNode::PrimU8(e) => {
-                                            *((base + 0) as *mut u8) = (8i32) as u8;
-                                            *((base + 8) as *mut u8) = (wit_bindgen::rt::as_i32(e)) as u8;
-                                          },
-                                          WitNode::PrimU16(e) => {
-                                            *((base + 0) as *mut u8) = (9i32) as u8;
-                                            *((base + 8) as *mut u16) = (wit_bindgen::rt::as_i32(e)) as u16;
-                                          },
-                                          WitNode::PrimU32(e) => {
-                                            *((base + 0) as *mut u8) = (10i32) as u8;
-                                            *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(e);
-                                          },
-                                          WitNode::PrimU64(e) => {
-                                            *((base + 0) as *mut u8) = (11i32) as u8;
-                                            *((base + 8) as *mut i64) = wit_bindgen::rt::as_i64(e);
-                                          },
-                                          WitNode::PrimS8(e) => {
-                                            *((base + 0) as *mut u8) = (12i32) as u8;
-                                            *((base + 8) as *mut u8) = (wit_bindgen::rt::as_i32(e)) as u8;
-                                          },
-                                          WitNode::PrimS16(e) => {
-                                            *((base + 0) as *mut u8) = (13i32) as u8;
-                                            *((base + 8) as *mut u16) = (wit_bindgen::rt::as_i32(e)) as u16;
-                                          },
-                                          WitNode::PrimS32(e) => {
-                                            *((base + 0) as *mut u8) = (14i32) as u8;
-                                            *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(e);
-                                          },
-                                          WitNode::PrimS64(e) => {
-                                            *((base + 0) as *mut u8) = (15i32) as u8;
-                                            *((base + 8) as *mut i64) = wit_bindgen::rt::as_i64(e);
-                                          },
-                                          WitNode::PrimFloat32(e) => {
-                                            *((base + 0) as *mut u8) = (16i32) as u8;
-                                            *((base + 8) as *mut f32) = wit_bindgen::rt::as_f32(e);
-                                          },
-                                          WitNode::PrimFloat64(e) => {
-                                            *((base + 0) as *mut u8) = (17i32) as u8;
-                                            *((base + 8) as *mut f64) = wit_bindgen::rt::as_f64(e);
-                                          },
-                                          WitNode::PrimChar(e) => {
-                                            *((base + 0) as *mut u8) = (18i32) as u8;
-                                            *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(e);
-                                          },
-                                          WitNode::PrimBool(e) => {
-                                            *((base + 0) as *mut u8) = (19i32) as u8;
-                                            *((base + 8) as *mut u8) = (match e { true => 1, false => 0 }) as u8;
-                                          },
-                                          WitNode::PrimString(e) => {
-                                            *((base + 0) as *mut u8) = (20i32) as u8;
-                                            let vec7 = e;
-                                            let ptr7 = vec7.as_ptr() as i32;
-                                            let len7 = vec7.len() as i32;
-                                            *((base + 12) as *mut i32) = len7;
-                                            *((base + 8) as *mut i32) = ptr7;
-                                          },
-                                          WitNode::Handle(e) => {
-                                            *((base + 0) as *mut u8) = (21i32) as u8;
-                                            let (t8_0, t8_1, ) = e;
-                                            let Uri{ value:value9, } = t8_0;
-                                            let vec10 = value9;
-                                            let ptr10 = vec10.as_ptr() as i32;
-                                            let len10 = vec10.len() as i32;
-                                            *((base + 12) as *mut i32) = len10;
-                                            *((base + 8) as *mut i32) = ptr10;
-                                            *((base + 16) as *mut i64) = wit_bindgen::rt::as_i64(t8_1);
-                                          },
-                                        }
+                                        };},
+                                        V27::PrimU8(e) => {
+                                          *((base + 0) as *mut u8) = (8i32) as u8;
+                                          *((base + 8) as *mut u8) = (wit_bindgen::rt::as_i32(e)) as u8;
+                                        },
+                                        V27::PrimU16(e) => {
+                                          *((base + 0) as *mut u8) = (9i32) as u8;
+                                          *((base + 8) as *mut u16) = (wit_bindgen::rt::as_i32(e)) as u16;
+                                        },
+                                        V27::PrimU32(e) => {
+                                          *((base + 0) as *mut u8) = (10i32) as u8;
+                                          *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                                        },
+                                        V27::PrimU64(e) => {
+                                          *((base + 0) as *mut u8) = (11i32) as u8;
+                                          *((base + 8) as *mut i64) = wit_bindgen::rt::as_i64(e);
+                                        },
+                                        V27::PrimS8(e) => {
+                                          *((base + 0) as *mut u8) = (12i32) as u8;
+                                          *((base + 8) as *mut u8) = (wit_bindgen::rt::as_i32(e)) as u8;
+                                        },
+                                        V27::PrimS16(e) => {
+                                          *((base + 0) as *mut u8) = (13i32) as u8;
+                                          *((base + 8) as *mut u16) = (wit_bindgen::rt::as_i32(e)) as u16;
+                                        },
+                                        V27::PrimS32(e) => {
+                                          *((base + 0) as *mut u8) = (14i32) as u8;
+                                          *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                                        },
+                                        V27::PrimS64(e) => {
+                                          *((base + 0) as *mut u8) = (15i32) as u8;
+                                          *((base + 8) as *mut i64) = wit_bindgen::rt::as_i64(e);
+                                        },
+                                        V27::PrimFloat32(e) => {
+                                          *((base + 0) as *mut u8) = (16i32) as u8;
+                                          *((base + 8) as *mut f32) = wit_bindgen::rt::as_f32(e);
+                                        },
+                                        V27::PrimFloat64(e) => {
+                                          *((base + 0) as *mut u8) = (17i32) as u8;
+                                          *((base + 8) as *mut f64) = wit_bindgen::rt::as_f64(e);
+                                        },
+                                        V27::PrimChar(e) => {
+                                          *((base + 0) as *mut u8) = (18i32) as u8;
+                                          *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                                        },
+                                        V27::PrimBool(e) => {
+                                          *((base + 0) as *mut u8) = (19i32) as u8;
+                                          *((base + 8) as *mut u8) = (match e { true => 1, false => 0 }) as u8;
+                                        },
+                                        V27::PrimString(e) => {
+                                          *((base + 0) as *mut u8) = (20i32) as u8;
+                                          let vec23 = e;
+                                          let ptr23 = vec23.as_ptr() as i32;
+                                          let len23 = vec23.len() as i32;
+                                          *((base + 12) as *mut i32) = len23;
+                                          *((base + 8) as *mut i32) = ptr23;
+                                        },
+                                        V27::Handle(e) => {
+                                          *((base + 0) as *mut u8) = (21i32) as u8;
+                                          let (t24_0, t24_1, ) = e;
+                                          let super::super::super::golem::rpc::types::Uri{ value:value25, } = t24_0;
+                                          let vec26 = value25;
+                                          let ptr26 = vec26.as_ptr() as i32;
+                                          let len26 = vec26.len() as i32;
+                                          *((base + 12) as *mut i32) = len26;
+                                          *((base + 8) as *mut i32) = ptr26;
+                                          *((base + 16) as *mut i64) = wit_bindgen::rt::as_i64(t24_1);
+                                        },
                                       }
                                     }
-                                    *((base + 4) as *mut i32) = len11;
-                                    *((base + 0) as *mut i32) = result11 as i32;
-                                    cleanup_list.extend_from_slice(&[(result11, layout11),]);
                                   }
+                                  *((base + 4) as *mut i32) = len28;
+                                  *((base + 0) as *mut i32) = result28 as i32;
+                                  cleanup_list.extend_from_slice(&[(result28, layout28),]);
                                 }
-                                let ptr13 = ret_area.as_mut_ptr() as i32;
-                                #[cfg(target_arch = "wasm32")]
-                                #[link(wasm_import_module = "golem:rpc/types@0.1.0")]
-                                extern "C" {
-                                  #[link_name = "[method]wasm-rpc.invoke"]
-                                  fn wit_import(_: i32, _: i32, _: i32, _: i32, _: i32, _: i32, );
-                                }
-                                
-                                #[cfg(not(target_arch = "wasm32"))]
-                                fn wit_import(_: i32, _: i32, _: i32, _: i32, _: i32, _: i32, ){ unreachable!() }
-                                wit_import((self).handle() as i32, ptr0, len0, result12 as i32, len12, ptr13);
-                                let l14 = i32::from(*((ptr13 + 0) as *const u8));
-                                if layout12.size() != 0 {
-                                  alloc::dealloc(result12, layout12);
-                                }
-                                for (ptr, layout) in cleanup_list {
-                                  
-                                  if layout.size() != 0 {
-                                    
-                                    alloc::dealloc(ptr, layout);
-                                    
-                                  }
+                              }
+                              let ptr30 = ret_area.as_mut_ptr() as i32;
+                              #[cfg(target_arch = "wasm32")]
+                              #[link(wasm_import_module = "golem:ext/broadcast@0.1.0")]
+                              extern "C" {
+                                #[link_name = "broadcast-invoke-and-await"]
+                                fn wit_import(_: i64, _: i64, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, );
+                              }
+                              
+                              #[cfg(not(target_arch = "wasm32"))]
+                              fn wit_import(_: i64, _: i64, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, ){ unreachable!() }
+                              wit_import(wit_bindgen::rt::as_i64(high_bits1), wit_bindgen::rt::as_i64(low_bits1), result15_0, result15_1, result15_2, ptr16, len16, result29 as i32, len29, wit_bindgen::rt::as_i32(max_concurrency), ptr30);
+                              let l31 = *((ptr30 + 0) as *const i32);
+                              let l32 = *((ptr30 + 4) as *const i32);
+                              let base101 = l31;
+                              let len101 = l32;
+                              let mut result101 = Vec::with_capacity(len101 as usize);
+                              for i in 0..len101 {
+                                let base = base101 + i * 40;
+                                let e101 = {
+                                  let l33 = *((base + 0) as *const i64);
+                                  let l34 = *((base + 8) as *const i64);
+                                  let l35 = *((base + 16) as *const i32);
+                                  let l36 = *((base + 20) as *const i32);
+                                  let len37 = l36 as usize;
+                                  let bytes37 = Vec::from_raw_parts(l35 as *mut _, len37, len37);
+                                  let l38 = i32::from(*((base + 24) as *const u8));
                                   
-                                }
-                                match l14 {
-                                  0 => {
-                                    let e = ();
-                                    Ok(e)
-                                  }
-                                  1 => {
-                                    let e = {
-                                      let l15 = i32::from(*((ptr13 + 4) as *const u8));
-                                      let v28 = match l15 {
-                                        0 => {
-                                          let e28 = {
-                                            let l16 = *((ptr13 + 8) as *const i32);
-                                            let l17 = *((ptr13 + 12) as *const i32);
-                                            let len18 = l17 as usize;
-                                            let bytes18 = Vec::from_raw_parts(l16 as *mut _, len18, len18);
-                                            
-                                            wit_bindgen::rt::string_lift(bytes18)
-                                          };
-                                          RpcError::ProtocolError(e28)
-                                        }
-                                        1 => {
-                                          let e28 = {
-                                            let l19 = *((ptr13 + 8) as *const i32);
-                                            let l20 = *((ptr13 + 12) as *const i32);
-                                            let len21 = l20 as usize;
-                                            let bytes21 = Vec::from_raw_parts(l19 as *mut _, len21, len21);
-                                            
-                                            wit_bindgen::rt::string_lift(bytes21)
-                                          };
-                                          RpcError::Denied(e28)
-                                        }
-                                        2 => {
-                                          let e28 = {
-                                            let l22 = *((ptr13 + 8) as *const i32);
-                                            let l23 = *((ptr13 + 12) as *const i32);
-                                            let len24 = l23 as usize;
-                                            let bytes24 = Vec::from_raw_parts(l22 as *mut _, len24, len24);
-                                            
-                                            wit_bindgen::rt::string_lift(bytes24)
-                                          };
-                                          RpcError::NotFound(e28)
-                                        }
-                                        n => {
-                                          debug_assert_eq!(n, 3, "invalid enum discriminant");
-                                          let e28 = {
-                                            let l25 = *((ptr13 + 8) as *const i32);
-                                            let l26 = *((ptr13 + 12) as *const i32);
-                                            let len27 = l26 as usize;
-                                            let bytes27 = Vec::from_raw_parts(l25 as *mut _, len27, len27);
-                                            
-                                            wit_bindgen::rt::string_lift(bytes27)
+                                  BroadcastResult{
+                                    worker_id: super::super::super::golem::api::host::WorkerId{
+                                      component_id: super::super::super::golem::api::host::ComponentId{
+                                        uuid: super::super::super::golem::api::host::Uuid{
+                                          high_bits: l33 as u64,
+                                          low_bits: l34 as u64,
+                                        },
+                                      },
+                                      worker_name: wit_bindgen::rt::string_lift(bytes37),
+                                    },
+                                    result: match l38 {
+                                      0 => {
+                                        let e = {
+                                          let l39 = *((base + 28) as *const i32);
+                                          let l40 = *((base + 32) as *const i32);
+                                          let base86 = l39;
+                                          let len86 = l40;
+                                          let mut result86 = Vec::with_capacity(len86 as usize);
+                                          for i in 0..len86 {
+                                            let base = base86 + i * 24;
+                                            let e86 = {
+                                              let l41 = i32::from(*((base + 0) as *const u8));
+                                              use super::super::super::golem::rpc::types::WitNode as V85;
+                                              let v85 = match l41 {
+                                                0 => {
+                                                  let e85 = {
+                                                    let l42 = *((base + 8) as *const i32);
+                                                    let l43 = *((base + 12) as *const i32);
+                                                    let len44 = l43 as usize;
+                                                    
+                                                    Vec::from_raw_parts(l42 as *mut _, len44, len44)
+                                                  };
+                                                  V85::RecordValue(e85)
+                                                }
+                                                1 => {
+                                                  let e85 = {
+                                                    let l45 = *((base + 8) as *const i32);
+                                                    let l46 = i32::from(*((base + 12) as *const u8));
+                                                    
+                                                    (l45 as u32, match l46 {
+                                                      0 => None,
+                                                      1 => {
+                                                        let e = {
+                                                          let l47 = *((base + 16) as *const i32);
+                                                          
+                                                          l47
+                                                        };
+                                                        Some(e)
+                                                      }
+                                                      _ => wit_bindgen::rt::invalid_enum_discriminant(),
+                                                    })
+                                                  };
+                                                  V85::VariantValue(e85)
+                                                }
+                                                2 => {
+                                                  let e85 = {
+                                                    let l48 = *((base + 8) as *const i32);
+                                                    
+                                                    l48 as u32
+                                                  };
+                                                  V85::EnumValue(e85)
+                                                }
+                                                3 => {
+                                                  let e85 = {
+                                                    let l49 = *((base + 8) as *const i32);
+                                                    let l50 = *((base + 12) as *const i32);
+                                                    let base52 = l49;
+                                                    let len52 = l50;
+                                                    let mut result52 = Vec::with_capacity(len52 as usize);
+                                                    for i in 0..len52 {
+                                                      let base = base52 + i * 1;
+                                                      let e52 = {
+                                                        let l51 = i32::from(*((base + 0) as *const u8));
+                                                        
+                                                        wit_bindgen::rt::bool_lift(l51 as u8)
+                                                      };
+                                                      result52.push(e52);
+                                                    }
+                                                    wit_bindgen::rt::dealloc(base52, (len52 as usize) * 1, 1);
+                                                    
+                                                    result52
+                                                  };
+                                                  V85::FlagsValue(e85)
+                                                }
+                                                4 => {
+                                                  let e85 = {
+                                                    let l53 = *((base + 8) as *const i32);
+                                                    let l54 = *((base + 12) as *const i32);
+                                                    let len55 = l54 as usize;
+                                                    
+                                                    Vec::from_raw_parts(l53 as *mut _, len55, len55)
+                                                  };
+                                                  V85::TupleValue(e85)
+                                                }
+                                                5 => {
+                                                  let e85 = {
+                                                    let l56 = *((base + 8) as *const i32);
+                                                    let l57 = *((base + 12) as *const i32);
+                                                    let len58 = l57 as usize;
+                                                    
+                                                    Vec::from_raw_parts(l56 as *mut _, len58, len58)
+                                                  };
+                                                  V85::ListValue(e85)
+                                                }
+                                                6 => {
+                                                  let e85 = {
+                                                    let l59 = i32::from(*((base + 8) as *const u8));
+                                                    
+                                                    match l59 {
+                                                      0 => None,
+                                                      1 => {
+                                                        let e = {
+                                                          let l60 = *((base + 12) as *const i32);
+                                                          
+                                                          l60
+                                                        };
+                                                        Some(e)
+                                                      }
+                                                      _ => wit_bindgen::rt::invalid_enum_discriminant(),
+                                                    }
+                                                  };
+                                                  V85::OptionValue(e85)
+                                                }
+                                                7 => {
+                                                  let e85 = {
+                                                    let l61 = i32::from(*((base + 8) as *const u8));
+                                                    
+                                                    match l61 {
+                                                      0 => {
+                                                        let e = {
+                                                          let l62 = i32::from(*((base + 12) as *const u8));
+                                                          
+                                                          match l62 {
+                                                            0 => None,
+                                                            1 => {
+                                                              let e = {
+                                                                let l63 = *((base + 16) as *const i32);
+                                                                
+                                                                l63
+                                                              };
+                                                              Some(e)
+                                                            }
+                                                            _ => wit_bindgen::rt::invalid_enum_discriminant(),
+                                                          }
+                                                        };
+                                                        Ok(e)
+                                                      }
+                                                      1 => {
+                                                        let e = {
+                                                          let l64 = i32::from(*((base + 12) as *const u8));
+                                                          
+                                                          match l64 {
+                                                            0 => None,
+                                                            1 => {
+                                                              let e = {
+                                                                let l65 = *((base + 16) as *const i32);
+                                                                
+                                                                l65
+                                                              };
+                                                              Some(e)
+                                                            }
+                                                            _ => wit_bindgen::rt::invalid_enum_discriminant(),
+                                                          }
+                                                        };
+                                                        Err(e)
+                                                      }
+                                                      _ => wit_bindgen::rt::invalid_enum_discriminant(),
+                                                    }
+                                                  };
+                                                  V85::ResultValue(e85)
+                                                }
+                                                8 => {
+                                                  let e85 = {
+                                                    let l66 = i32::from(*((base + 8) as *const u8));
+                                                    
+                                                    l66 as u8
+                                                  };
+                                                  V85::PrimU8(e85)
+                                                }
+                                                9 => {
+                                                  let e85 = {
+                                                    let l67 = i32::from(*((base + 8) as *const u16));
+                                                    
+                                                    l67 as u16
+                                                  };
+                                                  V85::PrimU16(e85)
+                                                }
+                                                10 => {
+                                                  let e85 = {
+                                                    let l68 = *((base + 8) as *const i32);
+                                                    
+                                                    l68 as u32
+                                                  };
+                                                  V85::PrimU32(e85)
+                                                }
+                                                11 => {
+                                                  let e85 = {
+                                                    let l69 = *((base + 8) as *const i64);
+                                                    
+                                                    l69 as u64
+                                                  };
+                                                  V85::PrimU64(e85)
+                                                }
+                                                12 => {
+                                                  let e85 = {
+                                                    let l70 = i32::from(*((base + 8) as *const i8));
+                                                    
+                                                    l70 as i8
+                                                  };
+                                                  V85::PrimS8(e85)
+                                                }
+                                                13 => {
+                                                  let e85 = {
+                                                    let l71 = i32::from(*((base + 8) as *const i16));
+                                                    
+                                                    l71 as i16
+                                                  };
+                                                  V85::PrimS16(e85)
+                                                }
+                                                14 => {
+                                                  let e85 = {
+                                                    let l72 = *((base + 8) as *const i32);
+                                                    
+                                                    l72
+                                                  };
+                                                  V85::PrimS32(e85)
+                                                }
+                                                15 => {
+                                                  let e85 = {
+                                                    let l73 = *((base + 8) as *const i64);
+                                                    
+                                                    l73
+                                                  };
+                                                  V85::PrimS64(e85)
+                                                }
+                                                16 => {
+                                                  let e85 = {
+                                                    let l74 = *((base + 8) as *const f32);
+                                                    
+                                                    l74
+                                                  };
+                                                  V85::PrimFloat32(e85)
+                                                }
+                                                17 => {
+                                                  let e85 = {
+                                                    let l75 = *((base + 8) as *const f64);
+                                                    
+                                                    l75
+                                                  };
+                                                  V85::PrimFloat64(e85)
+                                                }
+                                                18 => {
+                                                  let e85 = {
+                                                    let l76 = *((base + 8) as *const i32);
+                                                    
+                                                    wit_bindgen::rt::char_lift(l76 as u32)
+                                                  };
+                                                  V85::PrimChar(e85)
+                                                }
+                                                19 => {
+                                                  let e85 = {
+                                                    let l77 = i32::from(*((base + 8) as *const u8));
+                                                    
+                                                    wit_bindgen::rt::bool_lift(l77 as u8)
+                                                  };
+                                                  V85::PrimBool(e85)
+                                                }
+                                                20 => {
+                                                  let e85 = {
+                                                    let l78 = *((base + 8) as *const i32);
+                                                    let l79 = *((base + 12) as *const i32);
+                                                    let len80 = l79 as usize;
+                                                    let bytes80 = Vec::from_raw_parts(l78 as *mut _, len80, len80);
+                                                    
+                                                    wit_bindgen::rt::string_lift(bytes80)
+                                                  };
+                                                  V85::PrimString(e85)
+                                                }
+                                                n => {
+                                                  debug_assert_eq!(n, 21, "invalid enum discriminant");
+                                                  let e85 = {
+                                                    let l81 = *((base + 8) as *const i32);
+                                                    let l82 = *((base + 12) as *const i32);
+                                                    let len83 = l82 as usize;
+                                                    let bytes83 = Vec::from_raw_parts(l81 as *mut _, len83, len83);
+                                                    let l84 = *((base + 16) as *const i64);
+                                                    
+                                                    (super::super::super::golem::rpc::types::Uri{
+                                                      value: wit_bindgen::rt::string_lift(bytes83),
+                                                    }, l84 as u64)
+                                                  };
+                                                  V85::Handle(e85)
+                                                }
+                                              };
+                                              
+                                              v85
+                                            };
+                                            result86.push(e86);
+                                          }
+                                          wit_bindgen::rt::dealloc(base86, (len86 as usize) * 24, 8);
+                                          
+                                          super::super::super::golem::rpc::types::WitValue{
+                                            nodes: result86,
+                                          }
+                                        };
+                                        Ok(e)
+                                      }
+                                      1 => {
+                                        let e = {
+                                          let l87 = i32::from(*((base + 28) as *const u8));
+                                          use super::super::super::golem::rpc::types::RpcError as V100;
+                                          let v100 = match l87 {
+                                            0 => {
+                                              let e100 = {
+                                                let l88 = *((base + 32) as *const i32);
+                                                let l89 = *((base + 36) as *const i32);
+                                                let len90 = l89 as usize;
+                                                let bytes90 = Vec::from_raw_parts(l88 as *mut _, len90, len90);
+                                                
+                                                wit_bindgen::rt::string_lift(bytes90)
+                                              };
+                                              V100::ProtocolError(e100)
+                                            }
+                                            1 => {
+                                              let e100 = {
+                                                let l91 = *((base + 32) as *const i32);
+                                                let l92 = *((base + 36) as *const i32);
+                                                let len93 = l92 as usize;
+                                                let bytes93 = Vec::from_raw_parts(l91 as *mut _, len93, len93);
+                                                
+                                                wit_bindgen::rt::string_lift(bytes93)
+                                              };
+                                              V100::Denied(e100)
+                                            }
+                                            2 => {
+                                              let e100 = {
+                                                let l94 = *((base + 32) as *const i32);
+                                                let l95 = *((base + 36) as *const i32);
+                                                let len96 = l95 as usize;
+                                                let bytes96 = Vec::from_raw_parts(l94 as *mut _, len96, len96);
+                                                
+                                                wit_bindgen::rt::string_lift(bytes96)
+                                              };
+                                              V100::NotFound(e100)
+                                            }
+                                            n => {
+                                              debug_assert_eq!(n, 3, "invalid enum discriminant");
+                                              let e100 = {
+                                                let l97 = *((base + 32) as *const i32);
+                                                let l98 = *((base + 36) as *const i32);
+                                                let len99 = l98 as usize;
+                                                let bytes99 = Vec::from_raw_parts(l97 as *mut _, len99, len99);
+                                                
+                                                wit_bindgen::rt::string_lift(bytes99)
+                                              };
+                                              V100::RemoteInternalError(e100)
+                                            }
                                           };
-                                          RpcError::RemoteInternalError(e28)
-                                        }
-                                      };
-                                      
-                                      v28
-                                    };
-                                    Err(e)
+                                          
+                                          v100
+                                        };
+                                        Err(e)
+                                      }
+                                      _ => wit_bindgen::rt::invalid_enum_discriminant(),
+                                    },
                                   }
-                                  _ => wit_bindgen::rt::invalid_enum_discriminant(),
+                                };
+                                result101.push(e101);
+                              }
+                              wit_bindgen::rt::dealloc(base101, (len101 as usize) * 40, 8);
+                              if layout29.size() != 0 {
+                                alloc::dealloc(result29, layout29);
+                              }
+                              for (ptr, layout) in cleanup_list {
+                                
+                                if layout.size() != 0 {
+                                  
+                                  alloc::dealloc(ptr, layout);
+                                  
                                 }
+                                
                               }
+                              result101
                             }
                           }
                           
                         }
                         
                       }
-                    }
-                    pub mod rpc {
-                      pub mod counters_stub {
+                      pub mod rpc {
                         
                         #[allow(clippy::all)]
-                        pub mod stub_counters {
+                        pub mod types {
                           #[used]
                           #[doc(hidden)]
                           #[cfg(target_arch = "wasm32")]
                           static __FORCE_SECTION_REF: fn() = super::super::super::__link_section;
-                          pub type Uri = super::super::super::golem::rpc::types::Uri;
-                          
-                          #[derive(Debug)]
-                          #[repr(transparent)]
-                          pub struct Api{
-                            handle: wit_bindgen::rt::Resource<Api>,
+                          pub type NodeIndex = i32;
+                          #[derive(Clone)]
+                          pub struct Uri {
+                            pub value: wit_bindgen::rt::string::String,
                           }
-                          
-                          impl Api{
-                            #[doc(hidden)]
-                            pub unsafe fn from_handle(handle: u32) -> Self {
-                              Self {
-                                handle: wit_bindgen::rt::Resource::from_handle(handle),
-                              }
+                          impl ::core::fmt::Debug for Uri {
+                            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                              f.debug_struct("Uri").field("value", &self.value).finish()
                             }
-                            
-                            #[doc(hidden)]
-                            pub fn into_handle(self) -> u32 {
-                              wit_bindgen::rt::Resource::into_handle(self.handle)
+                          }
+                          #[derive(Clone)]
+                          pub enum WitNode{
+                            RecordValue(wit_bindgen::rt::vec::Vec::<NodeIndex>),
+                            VariantValue((u32,Option<NodeIndex>,)),
+                            EnumValue(u32),
+                            FlagsValue(wit_bindgen::rt::vec::Vec::<bool>),
+                            TupleValue(wit_bindgen::rt::vec::Vec::<NodeIndex>),
+                            ListValue(wit_bindgen::rt::vec::Vec::<NodeIndex>),
+                            OptionValue(Option<NodeIndex>),
+                            ResultValue(Result<Option<NodeIndex>,Option<NodeIndex>>),
+                            PrimU8(u8),
+                            PrimU16(u16),
+                            PrimU32(u32),
+                            PrimU64(u64),
+                            PrimS8(i8),
+                            PrimS16(i16),
+                            PrimS32(i32),
+                            PrimS64(i64),
+                            PrimFloat32(f32),
+                            PrimFloat64(f64),
+                            PrimChar(char),
+                            PrimBool(bool),
+                            PrimString(wit_bindgen::rt::string::String),
+                            Handle((Uri,u64,)),
+                          }
+                          impl ::core::fmt::Debug for WitNode {
+                            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                              match self {
+                                WitNode::RecordValue(e) => {
+                                  f.debug_tuple("WitNode::RecordValue").field(e).finish()
+                                }
+                                WitNode::VariantValue(e) => {
+                                  f.debug_tuple("WitNode::VariantValue").field(e).finish()
+                                }
+                                WitNode::EnumValue(e) => {
+                                  f.debug_tuple("WitNode::EnumValue").field(e).finish()
+                                }
+                                WitNode::FlagsValue(e) => {
+                                  f.debug_tuple("WitNode::FlagsValue").field(e).finish()
+                                }
+                                WitNode::TupleValue(e) => {
+                                  f.debug_tuple("WitNode::TupleValue").field(e).finish()
+                                }
+                                WitNode::ListValue(e) => {
+                                  f.debug_tuple("WitNode::ListValue").field(e).finish()
+                                }
+                                WitNode::OptionValue(e) => {
+                                  f.debug_tuple("WitNode::OptionValue").field(e).finish()
+                                }
+                                WitNode::ResultValue(e) => {
+                                  f.debug_tuple("WitNode::ResultValue").field(e).finish()
+                                }
+                                WitNode::PrimU8(e) => {
+                                  f.debug_tuple("WitNode::PrimU8").field(e).finish()
+                                }
+                                WitNode::PrimU16(e) => {
+                                  f.debug_tuple("WitNode::PrimU16").field(e).finish()
+                                }
+                                WitNode::PrimU32(e) => {
+                                  f.debug_tuple("WitNode::PrimU32").field(e).finish()
+                                }
+                                WitNode::PrimU64(e) => {
+                                  f.debug_tuple("WitNode::PrimU64").field(e).finish()
+                                }
+                                WitNode::PrimS8(e) => {
+                                  f.debug_tuple("WitNode::PrimS8").field(e).finish()
+                                }
+                                WitNode::PrimS16(e) => {
+                                  f.debug_tuple("WitNode::PrimS16").field(e).finish()
+                                }
+                                WitNode::PrimS32(e) => {
+                                  f.debug_tuple("WitNode::PrimS32").field(e).finish()
+                                }
+                                WitNode::PrimS64(e) => {
+                                  f.debug_tuple("WitNode::PrimS64").field(e).finish()
+                                }
+                                WitNode::PrimFloat32(e) => {
+                                  f.debug_tuple("WitNode::PrimFloat32").field(e).finish()
+                                }
+                                WitNode::PrimFloat64(e) => {
+                                  f.debug_tuple("WitNode::PrimFloat64").field(e).finish()
+                                }
+                                WitNode::PrimChar(e) => {
+                                  f.debug_tuple("WitNode::PrimChar").field(e).finish()
+                                }
+                                WitNode::PrimBool(e) => {
+                                  f.debug_tuple("WitNode::PrimBool").field(e).finish()
+                                }
+                                WitNode::PrimString(e) => {
+                                  f.debug_tuple("WitNode::PrimString").field(e).finish()
+                                }
+                                WitNode::Handle(e) => {
+                                  f.debug_tuple("WitNode::Handle").field(e).finish()
+                                }
+                              }
                             }
-                            
-                            #[doc(hidden)]
-                            pub fn handle(&self) -> u32 {
-                              wit_bindgen::rt::Resource::handle(&self.handle)
+                          }
+                          #[derive(Clone)]
+                          pub struct WitValue {
+                            pub nodes: wit_bindgen::rt::vec::Vec::<WitNode>,
+                          }
+                          impl ::core::fmt::Debug for WitValue {
+                            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                              f.debug_struct("WitValue").field("nodes", &self.nodes).finish()
                             }
                           }
-                          
-                          
-                          unsafe impl wit_bindgen::rt::WasmResource for Api{
-                            #[inline]
-                            unsafe fn drop(_handle: u32) {
-                              #[cfg(not(target_arch = "wasm32"))]
-                              unreachable!();
-                              
-                              #[cfg(target_arch = "wasm32")]
-                              {
-                                #[link(wasm_import_module = "rpc:counters-stub/stub-counters")]
-                                extern "C" {
-                                  #[link_name = "[resource-drop]api"]
-                                  fn drop(_: u32);
+                          #[derive(Clone)]
+                          pub enum RpcError{
+                            ProtocolError(wit_bindgen::rt::string::String),
+                            Denied(wit_bindgen::rt::string::String),
+                            NotFound(wit_bindgen::rt::string::String),
+                            RemoteInternalError(wit_bindgen::rt::string::String),
+                          }
+                          impl ::core::fmt::Debug for RpcError {
+                            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                              match self {
+                                RpcError::ProtocolError(e) => {
+                                  f.debug_tuple("RpcError::ProtocolError").field(e).finish()
+                                }
+                                RpcError::Denied(e) => {
+                                  f.debug_tuple("RpcError::Denied").field(e).finish()
+                                }
+                                RpcError::NotFound(e) => {
+                                  f.debug_tuple("RpcError::NotFound").field(e).finish()
+                                }
+                                RpcError::RemoteInternalError(e) => {
+                                  f.debug_tuple("RpcError::RemoteInternalError").field(e).finish()
                                 }
-                                
-                                drop(_handle);
                               }
                             }
                           }
+                          impl ::core::fmt::Display for RpcError {
+                            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                              write!(f, "{:?}", self)
+                            }
+                          }
                           
+                          impl std::error::Error for RpcError {}
                           
                           #[derive(Debug)]
                           #[repr(transparent)]
-                          pub struct Counter{
-                            handle: wit_bindgen::rt::Resource<Counter>,
+                          pub struct WasmRpc{
+                            handle: wit_bindgen::rt::Resource<WasmRpc>,
                           }
                           
-                          impl Counter{
+                          impl WasmRpc{
                             #[doc(hidden)]
                             pub unsafe fn from_handle(handle: u32) -> Self {
                               Self {
@@ -1406,7 +3245,7 @@ pub mod golem {
                           }
                           
                           
-                          unsafe impl wit_bindgen::rt::WasmResource for Counter{
+                          unsafe impl wit_bindgen::rt::WasmResource for WasmRpc{
                             #[inline]
                             unsafe fn drop(_handle: u32) {
                               #[cfg(not(target_arch = "wasm32"))]
@@ -1414,9 +3253,9 @@ pub mod golem {
                               
                               #[cfg(target_arch = "wasm32")]
                               {
-                                #[link(wasm_import_module = "rpc:counters-stub/stub-counters")]
+                                #[link(wasm_import_module = "golem:rpc/types@0.1.0")]
                                 extern "C" {
-                                  #[link_name = "[resource-drop]counter"]
+                                  #[link_name = "[resource-drop]wasm-rpc"]
                                   fn drop(_: u32);
                                 }
                                 
@@ -1425,205 +3264,1573 @@ pub mod golem {
                             }
                           }
                           
-                          impl Api {
+                          impl WasmRpc {
                             #[allow(unused_unsafe, clippy::all)]
                             pub fn new(location: &Uri,) -> Self{
                               
                               #[allow(unused_imports)]
                               use wit_bindgen::rt::{alloc, vec::Vec, string::String};
                               unsafe {
-                                let super::super::super::golem::rpc::types::Uri{ value:value0, } = location;
+                                let Uri{ value:value0, } = location;
                                 let vec1 = value0;
                                 let ptr1 = vec1.as_ptr() as i32;
                                 let len1 = vec1.len() as i32;
                                 
                                 #[cfg(target_arch = "wasm32")]
-                                #[link(wasm_import_module = "rpc:counters-stub/stub-counters")]
+                                #[link(wasm_import_module = "golem:rpc/types@0.1.0")]
                                 extern "C" {
-                                  #[link_name = "[constructor]api"]
+                                  #[link_name = "[constructor]wasm-rpc"]
                                   fn wit_import(_: i32, _: i32, ) -> i32;
                                 }
                                 
                                 #[cfg(not(target_arch = "wasm32"))]
                                 fn wit_import(_: i32, _: i32, ) -> i32{ unreachable!() }
                                 let ret = wit_import(ptr1, len1);
-                                Api::from_handle(ret as u32)
-                              }
-                            }
-                          }
-                          impl Api {
-                            #[allow(unused_unsafe, clippy::all)]
-                            pub fn inc_global_by(&self,value: u64,){
-                              
-                              #[allow(unused_imports)]
-                              use wit_bindgen::rt::{alloc, vec::Vec, string::String};
-                              unsafe {
-                                
-                                #[cfg(target_arch = "wasm32")]
-                                #[link(wasm_import_module = "rpc:counters-stub/stub-counters")]
-                                extern "C" {
-                                  #[link_name = "[method]api.inc-global-by"]
-                                  fn wit_import(_: i32, _: i64, );
-                                }
-                                
-                                #[cfg(not(target_arch = "wasm32"))]
-                                fn wit_import(_: i32, _: i64, ){ unreachable!() }
-                                wit_import((self).handle() as i32, wit_bindgen::rt::as_i64(value));
-                              }
-                            }
-                          }
-                          impl Api {
-                            #[allow(unused_unsafe, clippy::all)]
-                            pub fn get_global_value(&self,) -> u64{
-                              
-                              #[allow(unused_imports)]
-                              use wit_bindgen::rt::{alloc, vec::Vec, string::String};
-                              unsafe {
-                                
-                                #[cfg(target_arch = "wasm32")]
-                                #[link(wasm_import_module = "rpc:counters-stub/stub-counters")]
-                                extern "C" {
-                                  #[link_name = "[method]api.get-global-value"]
-                                  fn wit_import(_: i32, ) -> i64;
-                                }
-                                
-                                #[cfg(not(target_arch = "wasm32"))]
-                                fn wit_import(_: i32, ) -> i64{ unreachable!() }
-                                let ret = wit_import((self).handle() as i32);
-                                ret as u64
+                                WasmRpc::from_handle(ret as u32)
                               }
                             }
                           }
-                          impl Api {
+                          impl WasmRpc {
                             #[allow(unused_unsafe, clippy::all)]
-                            pub fn get_all_dropped(&self,) -> wit_bindgen::rt::vec::Vec::<(wit_bindgen::rt::string::String,u64,)>{
+                            pub fn invoke_and_await(&self,function_name: &str,function_params: &[WitValue],) -> Result<WitValue,RpcError>{
                               
                               #[allow(unused_imports)]
                               use wit_bindgen::rt::{alloc, vec::Vec, string::String};
                               unsafe {
+                                let mut cleanup_list = Vec::new();
                                 
                                 #[repr(align(4))]
-                                struct RetArea([u8; 8]);
+                                struct RetArea([u8; 16]);
                                 let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
-                                let ptr0 = ret_area.as_mut_ptr() as i32;
-                                #[cfg(target_arch = "wasm32")]
-                                #[link(wasm_import_module = "rpc:counters-stub/stub-counters")]
-                                extern "C" {
-                                  #[link_name = "[method]api.get-all-dropped"]
-                                  fn wit_import(_: i32, _: i32, );
-                                }
-                                
-                                #[cfg(not(target_arch = "wasm32"))]
-                                fn wit_import(_: i32, _: i32, ){ unreachable!() }
-                                wit_import((self).handle() as i32, ptr0);
-                                let l1 = *((ptr0 + 0) as *const i32);
-                                let l2 = *((ptr0 + 4) as *const i32);
-                                let base7 = l1;
-                                let len7 = l2;
-                                let mut result7 = Vec::with_capacity(len7 as usize);
-                                for i in 0..len7 {
-                                  let base = base7 + i * 16;
-                                  let e7 = {
-                                    let l3 = *((base + 0) as *const i32);
-                                    let l4 = *((base + 4) as *const i32);
-                                    let len5 = l4 as usize;
-                                    let bytes5 = Vec::from_raw_parts(l3 as *mut _, len5, len5);
-                                    let l6 = *((base + 8) as *const i64);
-                                    
-                                    (wit_bindgen::rt::string_lift(bytes5), l6 as u64)
-                                  };
-                                  result7.push(e7);
-                                }
-                                wit_bindgen::rt::dealloc(base7, (len7 as usize) * 16, 8);
-                                result7
-                              }
-                            }
-                          }
-                          impl Counter {
-                            #[allow(unused_unsafe, clippy::all)]
-                            pub fn new(location: &Uri,name: &str,) -> Self{
-                              
-                              #[allow(unused_imports)]
-                              use wit_bindgen::rt::{alloc, vec::Vec, string::String};
-                              unsafe {
-                                let super::super::super::golem::rpc::types::Uri{ value:value0, } = location;
-                                let vec1 = value0;
-                                let ptr1 = vec1.as_ptr() as i32;
-                                let len1 = vec1.len() as i32;
-                                let vec2 = name;
-                                let ptr2 = vec2.as_ptr() as i32;
-                                let len2 = vec2.len() as i32;
-                                
-                                #[cfg(target_arch = "wasm32")]
-                                #[link(wasm_import_module = "rpc:counters-stub/stub-counters")]
-                                extern "C" {
-                                  #[link_name = "[constructor]counter"]
-                                  fn wit_import(_: i32, _: i32, _: i32, _: i32, ) -> i32;
-                                }
-                                
-                                #[cfg(not(target_arch = "wasm32"))]
-                                fn wit_import(_: i32, _: i32, _: i32, _: i32, ) -> i32{ unreachable!() }
-                                let ret = wit_import(ptr1, len1, ptr2, len2);
-                                Counter::from_handle(ret as u32)
-                              }
-                            }
-                          }
-                          impl Counter {
-                            #[allow(unused_unsafe, clippy::all)]
-                            pub fn inc_by(&self,value: u64,){
-                              
-                              #[allow(unused_imports)]
-                              use wit_bindgen::rt::{alloc, vec::Vec, string::String};
-                              unsafe {
-                                
-                                #[cfg(target_arch = "wasm32")]
-                                #[link(wasm_import_module = "rpc:counters-stub/stub-counters")]
-                                extern "C" {
-                                  #[link_name = "[method]counter.inc-by"]
-                                  fn wit_import(_: i32, _: i64, );
-                                }
-                                
-                                #[cfg(not(target_arch = "wasm32"))]
-                                fn wit_import(_: i32, _: i64, ){ unreachable!() }
-                                wit_import((self).handle() as i32, wit_bindgen::rt::as_i64(value));
-                              }
-                            }
-                          }
-                          impl Counter {
-                            #[allow(unused_unsafe, clippy::all)]
-                            pub fn get_value(&self,) -> u64{
-                              
-                              #[allow(unused_imports)]
-                              use wit_bindgen::rt::{alloc, vec::Vec, string::String};
-                              unsafe {
-                                
-                                #[cfg(target_arch = "wasm32")]
-                                #[link(wasm_import_module = "rpc:counters-stub/stub-counters")]
-                                extern "C" {
-                                  #[link_name = "[method]counter.get-value"]
-                                  fn wit_import(_: i32, ) -> i64;
-                                }
-                                
-                                #[cfg(not(target_arch = "wasm32"))]
-                                fn wit_import(_: i32, ) -> i64{ unreachable!() }
-                                let ret = wit_import((self).handle() as i32);
-                                ret as u64
-                              }
-                            }
-                          }
-                          
-                        }
-                        
-                      }
-                    }
-                    
-                    #[cfg(target_arch = "wasm32")]
-                    #[link_section = "component-type:caller"]
-                    #[doc(hidden)]
-                    pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 1350] = [3, 0, 6, 99, 97, 108, 108, 101, 114, 0, 97, 115, 109, 13, 0, 1, 0, 7, 202, 9, 1, 65, 2, 1, 65, 12, 1, 66, 29, 1, 122, 4, 0, 10, 110, 111, 100, 101, 45, 105, 110, 100, 101, 120, 3, 0, 0, 1, 114, 1, 5, 118, 97, 108, 117, 101, 115, 4, 0, 3, 117, 114, 105, 3, 0, 2, 1, 112, 1, 1, 107, 1, 1, 111, 2, 121, 5, 1, 112, 127, 1, 106, 1, 5, 1, 5, 1, 111, 2, 3, 119, 1, 113, 22, 12, 114, 101, 99, 111, 114, 100, 45, 118, 97, 108, 117, 101, 1, 4, 0, 13, 118, 97, 114, 105, 97, 110, 116, 45, 118, 97, 108, 117, 101, 1, 6, 0, 10, 101, 110, 117, 109, 45, 118, 97, 108, 117, 101, 1, 121, 0, 11, 102, 108, 97, 103, 115, 45, 118, 97, 108, 117, 101, 1, 7, 0, 11, 116, 117, 112, 108, 101, 45, 118, 97, 108, 117, 101, 1, 4, 0, 10, 108, 105, 115, 116, 45, 118, 97, 108, 117, 101, 1, 4, 0, 12, 111, 112, 116, 105, 111, 110, 45, 118, 97, 108, 117, 101, 1, 5, 0, 12, 114, 101, 115, 117, 108, 116, 45, 118, 97, 108, 117, 101, 1, 8, 0, 7, 112, 114, 105, 109, 45, 117, 56, 1, 125, 0, 8, 112, 114, 105, 109, 45, 117, 49, 54, 1, 123, 0, 8, 112, 114, 105, 109, 45, 117, 51, 50, 1, 121, 0, 8, 112, 114, 105, 109, 45, 117, 54, 52, 1, 119, 0, 7, 112, 114, 105, 109, 45, 115, 56, 1, 126, 0, 8, 112, 114, 105, 109, 45, 115, 49, 54, 1, 124, 0, 8, 112, 114, 105, 109, 45, 115, 51, 50, 1, 122, 0, 8, 112, 114, 105, 109, 45, 115, 54, 52, 1, 120, 0, 12, 112, 114, 105, 109, 45, 102, 108, 111, 97, 116, 51, 50, 1, 118, 0, 12, 112, 114, 105, 109, 45, 102, 108, 111, 97, 116, 54, 52, 1, 117, 0, 9, 112, 114, 105, 109, 45, 99, 104, 97, 114, 1, 116, 0, 9, 112, 114, 105, 109, 45, 98, 111, 111, 108, 1, 127, 0, 11, 112, 114, 105, 109, 45, 115, 116, 114, 105, 110, 103, 1, 115, 0, 6, 104, 97, 110, 100, 108, 101, 1, 9, 0, 4, 0, 8, 119, 105, 116, 45, 110, 111, 100, 101, 3, 0, 10, 1, 112, 11, 1, 114, 1, 5, 110, 111, 100, 101, 115, 12, 4, 0, 9, 119, 105, 116, 45, 118, 97, 108, 117, 101, 3, 0, 13, 1, 113, 4, 14, 112, 114, 111, 116, 111, 99, 111, 108, 45, 101, 114, 114, 111, 114, 1, 115, 0, 6, 100, 101, 110, 105, 101, 100, 1, 115, 0, 9, 110, 111, 116, 45, 102, 111, 117, 110, 100, 1, 115, 0, 21, 114, 101, 109, 111, 116, 101, 45, 105, 110, 116, 101, 114, 110, 97, 108, 45, 101, 114, 114, 111, 114, 1, 115, 0, 4, 0, 9, 114, 112, 99, 45, 101, 114, 114, 111, 114, 3, 0, 15, 4, 0, 8, 119, 97, 115, 109, 45, 114, 112, 99, 3, 1, 1, 105, 17, 1, 64, 1, 8, 108, 111, 99, 97, 116, 105, 111, 110, 3, 0, 18, 4, 0, 21, 91, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 93, 119, 97, 115, 109, 45, 114, 112, 99, 1, 19, 1, 104, 17, 1, 112, 14, 1, 106, 1, 14, 1, 16, 1, 64, 3, 4, 115, 101, 108, 102, 20, 13, 102, 117, 110, 99, 116, 105, 111, 110, 45, 110, 97, 109, 101, 115, 15, 102, 117, 110, 99, 116, 105, 111, 110, 45, 112, 97, 114, 97, 109, 115, 21, 0, 22, 4, 0, 33, 91, 109, 101, 116, 104, 111, 100, 93, 119, 97, 115, 109, 45, 114, 112, 99, 46, 105, 110, 118, 111, 107, 101, 45, 97, 110, 100, 45, 97, 119, 97, 105, 116, 1, 23, 1, 106, 0, 1, 16, 1, 64, 3, 4, 115, 101, 108, 102, 20, 13, 102, 117, 110, 99, 116, 105, 111, 110, 45, 110, 97, 109, 101, 115, 15, 102, 117, 110, 99, 116, 105, 111, 110, 45, 112, 97, 114, 97, 109, 115, 21, 0, 24, 4, 0, 23, 91, 109, 101, 116, 104, 111, 100, 93, 119, 97, 115, 109, 45, 114, 112, 99, 46, 105, 110, 118, 111, 107, 101, 1, 25, 3, 1, 21, 103, 111, 108, 101, 109, 58, 114, 112, 99, 47, 116, 121, 112, 101, 115, 64, 48, 46, 49, 46, 48, 5, 0, 2, 3, 0, 0, 3, 117, 114, 105, 1, 66, 24, 2, 3, 2, 1, 1, 4, 0, 3, 117, 114, 105, 3, 0, 0, 4, 0, 3, 97, 112, 105, 3, 1, 4, 0, 7, 99, 111, 117, 110, 116, 101, 114, 3, 1, 1, 105, 2, 1, 64, 1, 8, 108, 111, 99, 97, 116, 105, 111, 110, 1, 0, 4, 4, 0, 16, 91, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 93, 97, 112, 105, 1, 5, 1, 104, 2, 1, 64, 2, 4, 115, 101, 108, 102, 6, 5, 118, 97, 108, 117, 101, 119, 1, 0, 4, 0, 25, 91, 109, 101, 116, 104, 111, 100, 93, 97, 112, 105, 46, 105, 110, 99, 45, 103, 108, 111, 98, 97, 108, 45, 98, 121, 1, 7, 1, 64, 1, 4, 115, 101, 108, 102, 6, 0, 119, 4, 0, 28, 91, 109, 101, 116, 104, 111, 100, 93, 97, 112, 105, 46, 103, 101, 116, 45, 103, 108, 111, 98, 97, 108, 45, 118, 97, 108, 117, 101, 1, 8, 1, 111, 2, 115, 119, 1, 112, 9, 1, 64, 1, 4, 115, 101, 108, 102, 6, 0, 10, 4, 0, 27, 91, 109, 101, 116, 104, 111, 100, 93, 97, 112, 105, 46, 103, 101, 116, 45, 97, 108, 108, 45, 100, 114, 111, 112, 112, 101, 100, 1, 11, 1, 105, 3, 1, 64, 2, 8, 108, 111, 99, 97, 116, 105, 111, 110, 1, 4, 110, 97, 109, 101, 115, 0, 12, 4, 0, 20, 91, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 93, 99, 111, 117, 110, 116, 101, 114, 1, 13, 1, 104, 3, 1, 64, 2, 4, 115, 101, 108, 102, 14, 5, 118, 97, 108, 117, 101, 119, 1, 0, 4, 0, 22, 91, 109, 101, 116, 104, 111, 100, 93, 99, 111, 117, 110, 116, 101, 114, 46, 105, 110, 99, 45, 98, 121, 1, 15, 1, 64, 1, 4, 115, 101, 108, 102, 14, 0, 119, 4, 0, 25, 91, 109, 101, 116, 104, 111, 100, 93, 99, 111, 117, 110, 116, 101, 114, 46, 103, 101, 116, 45, 118, 97, 108, 117, 101, 1, 16, 3, 1, 31, 114, 112, 99, 58, 99, 111, 117, 110, 116, 101, 114, 115, 45, 115, 116, 117, 98, 47, 115, 116, 117, 98, 45, 99, 111, 117, 110, 116, 101, 114, 115, 5, 2, 1, 111, 2, 115, 119, 1, 112, 3, 1, 64, 0, 0, 4, 4, 0, 5, 116, 101, 115, 116, 49, 1, 5, 1, 64, 0, 0, 119, 4, 0, 5, 116, 101, 115, 116, 50, 1, 6, 4, 0, 5, 116, 101, 115, 116, 51, 1, 6, 4, 1, 17, 114, 112, 99, 58, 99, 97, 108, 108, 101, 114, 47, 99, 97, 108, 108, 101, 114, 4, 0, 11, 12, 1, 0, 6, 99, 97, 108, 108, 101, 114, 3, 0, 0, 0, 16, 12, 112, 97, 99, 107, 97, 103, 101, 45, 100, 111, 99, 115, 0, 123, 125, 0, 70, 9, 112, 114, 111, 100, 117, 99, 101, 114, 115, 1, 12, 112, 114, 111, 99, 101, 115, 115, 101, 100, 45, 98, 121, 2, 13, 119, 105, 116, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 6, 48, 46, 49, 56, 46, 50, 16, 119, 105, 116, 45, 98, 105, 110, 100, 103, 101, 110, 45, 114, 117, 115, 116, 6, 48, 46, 49, 54, 46, 48];
-                    
-                    #[inline(never)]
-                    #[doc(hidden)]
-                    #[cfg(target_arch = "wasm32")]
-                    pub fn __link_section() {}
-                    
\ No newline at end of file
+                                let vec0 = function_name;
+                                let ptr0 = vec0.as_ptr() as i32;
+                                let len0 = vec0.len() as i32;
+                                let vec12 = function_params;
+                                let len12 = vec12.len() as i32;
+                                let layout12 = alloc::Layout::from_size_align_unchecked(vec12.len() * 8, 4);
+                                let result12 = if layout12.size() != 0
+                                {
+                                  let ptr = alloc::alloc(layout12);
+                                  if ptr.is_null()
+                                  {
+                                    alloc::handle_alloc_error(layout12);
+                                  }
+                                  ptr
+                                }else {{
+                                  ::core::ptr::null_mut()
+                                }};
+                                for (i, e) in vec12.into_iter().enumerate() {
+                                  let base = result12 as i32 + (i as i32) * 8;
+                                  {
+                                    let WitValue{ nodes:nodes1, } = e;
+                                    let vec11 = nodes1;
+                                    let len11 = vec11.len() as i32;
+                                    let layout11 = alloc::Layout::from_size_align_unchecked(vec11.len() * 24, 8);
+                                    let result11 = if layout11.size() != 0
+                                    {
+                                      let ptr = alloc::alloc(layout11);
+                                      if ptr.is_null()
+                                      {
+                                        alloc::handle_alloc_error(layout11);
+                                      }
+                                      ptr
+                                    }else {{
+                                      ::core::ptr::null_mut()
+                                    }};
+                                    for (i, e) in vec11.into_iter().enumerate() {
+                                      let base = result11 as i32 + (i as i32) * 24;
+                                      {
+                                        match e {
+                                          WitNode::RecordValue(e) => {
+                                            *((base + 0) as *mut u8) = (0i32) as u8;
+                                            let vec2 = e;
+                                            let ptr2 = vec2.as_ptr() as i32;
+                                            let len2 = vec2.len() as i32;
+                                            *((base + 12) as *mut i32) = len2;
+                                            *((base + 8) as *mut i32) = ptr2;
+                                          },
+                                          WitNode::VariantValue(e) => {
+                                            *((base + 0) as *mut u8) = (1i32) as u8;
+                                            let (t3_0, t3_1, ) = e;
+                                            *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(t3_0);
+                                            match t3_1 {
+                                              Some(e) => {
+                                                *((base + 12) as *mut u8) = (1i32) as u8;
+                                                *((base + 16) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                                              },
+                                              None => {
+                                                {
+                                                  *((base + 12) as *mut u8) = (0i32) as u8;
+                                                }
+                                              },
+                                            };},
+                                            WitNode::EnumValue(e) => {
+                                              *((base + 0) as *mut u8) = (2i32) as u8;
+                                              *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                                            },
+                                            WitNode::FlagsValue(e) => {
+                                              *((base + 0) as *mut u8) = (3i32) as u8;
+                                              let vec4 = e;
+                                              let len4 = vec4.len() as i32;
+                                              let layout4 = alloc::Layout::from_size_align_unchecked(vec4.len() * 1, 1);
+                                              let result4 = if layout4.size() != 0
+                                              {
+                                                let ptr = alloc::alloc(layout4);
+                                                if ptr.is_null()
+                                                {
+                                                  alloc::handle_alloc_error(layout4);
+                                                }
+                                                ptr
+                                              }else {{
+                                                ::core::ptr::null_mut()
+                                              }};
+                                              for (i, e) in vec4.into_iter().enumerate() {
+                                                let base = result4 as i32 + (i as i32) * 1;
+                                                {
+                                                  *((base + 0) as *mut u8) = (match e { true => 1, false => 0 }) as u8;
+                                                }
+                                              }
+                                              *((base + 12) as *mut i32) = len4;
+                                              *((base + 8) as *mut i32) = result4 as i32;
+                                              cleanup_list.extend_from_slice(&[(result4, layout4),]);
+                                            },
+                                            WitNode::TupleValue(e) => {
+                                              *((base + 0) as *mut u8) = (4i32) as u8;
+                                              let vec5 = e;
+                                              let ptr5 = vec5.as_ptr() as i32;
+                                              let len5 = vec5.len() as i32;
+                                              *((base + 12) as *mut i32) = len5;
+                                              *((base + 8) as *mut i32) = ptr5;
+                                            },
+                                            WitNode::ListValue(e) => {
+                                              *((base + 0) as *mut u8) = (5i32) as u8;
+                                              let vec6 = e;
+                                              let ptr6 = vec6.as_ptr() as i32;
+                                              let len6 = vec6.len() as i32;
+                                              *((base + 12) as *mut i32) = len6;
+                                              *((base + 8) as *mut i32) = ptr6;
+                                            },
+                                            WitNode::OptionValue(e) => {
+                                              *((base + 0) as *mut u8) = (6i32) as u8;
+                                              match e {
+                                                Some(e) => {
+                                                  *((base + 8) as *mut u8) = (1i32) as u8;
+                                                  *((base + 12) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                                                },
+                                                None => {
+                                                  {
+                                                    *((base + 8) as *mut u8) = (0i32) as u8;
+                                                  }
+                                                },
+                                              };},
+                                              WitNode::ResultValue(e) => {
+                                                *((base + 0) as *mut u8) = (7i32) as u8;
+                                                match e {
+                                                  Ok(e) => { {
+                                                    *((base + 8) as *mut u8) = (0i32) as u8;
+                                                    match e {
+                                                      Some(e) => {
+                                                        *((base + 12) as *mut u8) = (1i32) as u8;
+                                                        *((base + 16) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                                                      },
+                                                      None => {
+                                                        {
+                                                          *((base + 12) as *mut u8) = (0i32) as u8;
+                                                        }
+                                                      },
+                                                    };} },
+                                                    Err(e) => { {
+                                                      *((base + 8) as *mut u8) = (1i32) as u8;
+                                                      match e {
+                                                        Some(e) => {
+                                                          *((base + 12) as *mut u8) = (1i32) as u8;
+                                                          *((base + 16) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                                                        },
+                                                        None => {
+                                                          {
+                                                            *((base + 12) as *mut u8) = (0i32) as u8;
+                                                          }
+                                                        },
+                                                      };} },
+                                                    };},
+                                                    WitNode::PrimU8(e) => {
+                                                      *((base + 0) as *mut u8) = (8i32) as u8;
+                                                      *((base + 8) as *mut u8) = (wit_bindgen::rt::as_i32(e)) as u8;
+                                                    },
+                                                    WitNode::PrimU16(e) => {
+                                                      *((base + 0) as *mut u8) = (9i32) as u8;
+                                                      *((base + 8) as *mut u16) = (wit_bindgen::rt::as_i32(e)) as u16;
+                                                    },
+                                                    WitNode::PrimU32(e) => {
+                                                      *((base + 0) as *mut u8) = (10i32) as u8;
+                                                      *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                                                    },
+                                                    WitNode::PrimU64(e) => {
+                                                      *((base + 0) as *mut u8) = (11i32) as u8;
+                                                      *((base + 8) as *mut i64) = wit_bindgen::rt::as_i64(e);
+                                                    },
+                                                    WitNode::PrimS8(e) => {
+                                                      *((base + 0) as *mut u8) = (12i32) as u8;
+                                                      *((base + 8) as *mut u8) = (wit_bindgen::rt::as_i32(e)) as u8;
+                                                    },
+                                                    WitNode::PrimS16(e) => {
+                                                      *((base + 0) as *mut u8) = (13i32) as u8;
+                                                      *((base + 8) as *mut u16) = (wit_bindgen::rt::as_i32(e)) as u16;
+                                                    },
+                                                    WitNode::PrimS32(e) => {
+                                                      *((base + 0) as *mut u8) = (14i32) as u8;
+                                                      *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                                                    },
+                                                    WitNode::PrimS64(e) => {
+                                                      *((base + 0) as *mut u8) = (15i32) as u8;
+                                                      *((base + 8) as *mut i64) = wit_bindgen::rt::as_i64(e);
+                                                    },
+                                                    WitNode::PrimFloat32(e) => {
+                                                      *((base + 0) as *mut u8) = (16i32) as u8;
+                                                      *((base + 8) as *mut f32) = wit_bindgen::rt::as_f32(e);
+                                                    },
+                                                    WitNode::PrimFloat64(e) => {
+                                                      *((base + 0) as *mut u8) = (17i32) as u8;
+                                                      *((base + 8) as *mut f64) = wit_bindgen::rt::as_f64(e);
+                                                    },
+                                                    WitNode::PrimChar(e) => {
+                                                      *((base + 0) as *mut u8) = (18i32) as u8;
+                                                      *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                                                    },
+                                                    WitNode::PrimBool(e) => {
+                                                      *((base + 0) as *mut u8) = (19i32) as u8;
+                                                      *((base + 8) as *mut u8) = (match e { true => 1, false => 0 }) as u8;
+                                                    },
+                                                    WitNode::PrimString(e) => {
+                                                      *((base + 0) as *mut u8) = (20i32) as u8;
+                                                      let vec7 = e;
+                                                      let ptr7 = vec7.as_ptr() as i32;
+                                                      let len7 = vec7.len() as i32;
+                                                      *((base + 12) as *mut i32) = len7;
+                                                      *((base + 8) as *mut i32) = ptr7;
+                                                    },
+                                                    WitNode::Handle(e) => {
+                                                      *((base + 0) as *mut u8) = (21i32) as u8;
+                                                      let (t8_0, t8_1, ) = e;
+                                                      let Uri{ value:value9, } = t8_0;
+                                                      let vec10 = value9;
+                                                      let ptr10 = vec10.as_ptr() as i32;
+                                                      let len10 = vec10.len() as i32;
+                                                      *((base + 12) as *mut i32) = len10;
+                                                      *((base + 8) as *mut i32) = ptr10;
+                                                      *((base + 16) as *mut i64) = wit_bindgen::rt::as_i64(t8_1);
+                                                    },
+                                                  }
+                                                }
+                                              }
+                                              *((base + 4) as *mut i32) = len11;
+                                              *((base + 0) as *mut i32) = result11 as i32;
+                                              cleanup_list.extend_from_slice(&[(result11, layout11),]);
+                                            }
+                                          }
+                                          let ptr13 = ret_area.as_mut_ptr() as i32;
+                                          #[cfg(target_arch = "wasm32")]
+                                          #[link(wasm_import_module = "golem:rpc/types@0.1.0")]
+                                          extern "C" {
+                                            #[link_name = "[method]wasm-rpc.invoke-and-await"]
+                                            fn wit_import(_: i32, _: i32, _: i32, _: i32, _: i32, _: i32, );
+                                          }
+                                          
+                                          #[cfg(not(target_arch = "wasm32"))]
+                                          fn wit_import(_: i32, _: i32, _: i32, _: i32, _: i32, _: i32, ){ unreachable!() }
+                                          wit_import((self).handle() as i32, ptr0, len0, result12 as i32, len12, ptr13);
+                                          let l14 = i32::from(*((ptr13 + 0) as *const u8));
+                                          if layout12.size() != 0 {
+                                            alloc::dealloc(result12, layout12);
+                                          }
+                                          for (ptr, layout) in cleanup_list {
+                                            
+                                            if layout.size() != 0 {
+                                              
+                                              alloc::dealloc(ptr, layout);
+                                              
+                                            }
+                                            
+                                          }
+                                          match l14 {
+                                            0 => {
+                                              let e = {
+                                                let l15 = *((ptr13 + 4) as *const i32);
+                                                let l16 = *((ptr13 + 8) as *const i32);
+                                                let base62 = l15;
+                                                let len62 = l16;
+                                                let mut result62 = Vec::with_capacity(len62 as usize);
+                                                for i in 0..len62 {
+                                                  let base = base62 + i * 24;
+                                                  let e62 = {
+                                                    let l17 = i32::from(*((base + 0) as *const u8));
+                                                    let v61 = match l17 {
+                                                      0 => {
+                                                        let e61 = {
+                                                          let l18 = *((base + 8) as *const i32);
+                                                          let l19 = *((base + 12) as *const i32);
+                                                          let len20 = l19 as usize;
+                                                          
+                                                          Vec::from_raw_parts(l18 as *mut _, len20, len20)
+                                                        };
+                                                        WitNode::RecordValue(e61)
+                                                      }
+                                                      1 => {
+                                                        let e61 = {
+                                                          let l21 = *((base + 8) as *const i32);
+                                                          let l22 = i32::from(*((base + 12) as *const u8));
+                                                          
+                                                          (l21 as u32, match l22 {
+                                                            0 => None,
+                                                            1 => {
+                                                              let e = {
+                                                                let l23 = *((base + 16) as *const i32);
+                                                                
+                                                                l23
+                                                              };
+                                                              Some(e)
+                                                            }
+                                                            _ => wit_bindgen::rt::invalid_enum_discriminant(),
+                                                          })
+                                                        };
+                                                        WitNode::VariantValue(e61)
+                                                      }
+                                                      2 => {
+                                                        let e61 = {
+                                                          let l24 = *((base + 8) as *const i32);
+                                                          
+                                                          l24 as u32
+                                                        };
+                                                        WitNode::EnumValue(e61)
+                                                      }
+                                                      3 => {
+                                                        let e61 = {
+                                                          let l25 = *((base + 8) as *const i32);
+                                                          let l26 = *((base + 12) as *const i32);
+                                                          let base28 = l25;
+                                                          let len28 = l26;
+                                                          let mut result28 = Vec::with_capacity(len28 as usize);
+                                                          for i in 0..len28 {
+                                                            let base = base28 + i * 1;
+                                                            let e28 = {
+                                                              let l27 = i32::from(*((base + 0) as *const u8));
+                                                              
+                                                              wit_bindgen::rt::bool_lift(l27 as u8)
+                                                            };
+                                                            result28.push(e28);
+                                                          }
+                                                          wit_bindgen::rt::dealloc(base28, (len28 as usize) * 1, 1);
+                                                          
+                                                          result28
+                                                        };
+                                                        WitNode::FlagsValue(e61)
+                                                      }
+                                                      4 => {
+                                                        let e61 = {
+                                                          let l29 = *((base + 8) as *const i32);
+                                                          let l30 = *((base + 12) as *const i32);
+                                                          let len31 = l30 as usize;
+                                                          
+                                                          Vec::from_raw_parts(l29 as *mut _, len31, len31)
+                                                        };
+                                                        WitNode::TupleValue(e61)
+                                                      }
+                                                      5 => {
+                                                        let e61 = {
+                                                          let l32 = *((base + 8) as *const i32);
+                                                          let l33 = *((base + 12) as *const i32);
+                                                          let len34 = l33 as usize;
+                                                          
+                                                          Vec::from_raw_parts(l32 as *mut _, len34, len34)
+                                                        };
+                                                        WitNode::ListValue(e61)
+                                                      }
+                                                      6 => {
+                                                        let e61 = {
+                                                          let l35 = i32::from(*((base + 8) as *const u8));
+                                                          
+                                                          match l35 {
+                                                            0 => None,
+                                                            1 => {
+                                                              let e = {
+                                                                let l36 = *((base + 12) as *const i32);
+                                                                
+                                                                l36
+                                                              };
+                                                              Some(e)
+                                                            }
+                                                            _ => wit_bindgen::rt::invalid_enum_discriminant(),
+                                                          }
+                                                        };
+                                                        WitNode::OptionValue(e61)
+                                                      }
+                                                      7 => {
+                                                        let e61 = {
+                                                          let l37 = i32::from(*((base + 8) as *const u8));
+                                                          
+                                                          match l37 {
+                                                            0 => {
+                                                              let e = {
+                                                                let l38 = i32::from(*((base + 12) as *const u8));
+                                                                
+                                                                match l38 {
+                                                                  0 => None,
+                                                                  1 => {
+                                                                    let e = {
+                                                                      let l39 = *((base + 16) as *const i32);
+                                                                      
+                                                                      l39
+                                                                    };
+                                                                    Some(e)
+                                                                  }
+                                                                  _ => wit_bindgen::rt::invalid_enum_discriminant(),
+                                                                }
+                                                              };
+                                                              Ok(e)
+                                                            }
+                                                            1 => {
+                                                              let e = {
+                                                                let l40 = i32::from(*((base + 12) as *const u8));
+                                                                
+                                                                match l40 {
+                                                                  0 => None,
+                                                                  1 => {
+                                                                    let e = {
+                                                                      let l41 = *((base + 16) as *const i32);
+                                                                      
+                                                                      l41
+                                                                    };
+                                                                    Some(e)
+                                                                  }
+                                                                  _ => wit_bindgen::rt::invalid_enum_discriminant(),
+                                                                }
+                                                              };
+                                                              Err(e)
+                                                            }
+                                                            _ => wit_bindgen::rt::invalid_enum_discriminant(),
+                                                          }
+                                                        };
+                                                        WitNode::ResultValue(e61)
+                                                      }
+                                                      8 => {
+                                                        let e61 = {
+                                                          let l42 = i32::from(*((base + 8) as *const u8));
+                                                          
+                                                          l42 as u8
+                                                        };
+                                                        WitNode::PrimU8(e61)
+                                                      }
+                                                      9 => {
+                                                        let e61 = {
+                                                          let l43 = i32::from(*((base + 8) as *const u16));
+                                                          
+                                                          l43 as u16
+                                                        };
+                                                        WitNode::PrimU16(e61)
+                                                      }
+                                                      10 => {
+                                                        let e61 = {
+                                                          let l44 = *((base + 8) as *const i32);
+                                                          
+                                                          l44 as u32
+                                                        };
+                                                        WitNode::PrimU32(e61)
+                                                      }
+                                                      11 => {
+                                                        let e61 = {
+                                                          let l45 = *((base + 8) as *const i64);
+                                                          
+                                                          l45 as u64
+                                                        };
+                                                        WitNode::PrimU64(e61)
+                                                      }
+                                                      12 => {
+                                                        let e61 = {
+                                                          let l46 = i32::from(*((base + 8) as *const i8));
+                                                          
+                                                          l46 as i8
+                                                        };
+                                                        WitNode::PrimS8(e61)
+                                                      }
+                                                      13 => {
+                                                        let e61 = {
+                                                          let l47 = i32::from(*((base + 8) as *const i16));
+                                                          
+                                                          l47 as i16
+                                                        };
+                                                        WitNode::PrimS16(e61)
+                                                      }
+                                                      14 => {
+                                                        let e61 = {
+                                                          let l48 = *((base + 8) as *const i32);
+                                                          
+                                                          l48
+                                                        };
+                                                        WitNode::PrimS32(e61)
+                                                      }
+                                                      15 => {
+                                                        let e61 = {
+                                                          let l49 = *((base + 8) as *const i64);
+                                                          
+                                                          l49
+                                                        };
+                                                        WitNode::PrimS64(e61)
+                                                      }
+                                                      16 => {
+                                                        let e61 = {
+                                                          let l50 = *((base + 8) as *const f32);
+                                                          
+                                                          l50
+                                                        };
+                                                        WitNode::PrimFloat32(e61)
+                                                      }
+                                                      17 => {
+                                                        let e61 = {
+                                                          let l51 = *((base + 8) as *const f64);
+                                                          
+                                                          l51
+                                                        };
+                                                        WitNode::PrimFloat64(e61)
+                                                      }
+                                                      18 => {
+                                                        let e61 = {
+                                                          let l52 = *((base + 8) as *const i32);
+                                                          
+                                                          wit_bindgen::rt::char_lift(l52 as u32)
+                                                        };
+                                                        WitNode::PrimChar(e61)
+                                                      }
+                                                      19 => {
+                                                        let e61 = {
+                                                          let l53 = i32::from(*((base + 8) as *const u8));
+                                                          
+                                                          wit_bindgen::rt::bool_lift(l53 as u8)
+                                                        };
+                                                        WitNode::PrimBool(e61)
+                                                      }
+                                                      20 => {
+                                                        let e61 = {
+                                                          let l54 = *((base + 8) as *const i32);
+                                                          let l55 = *((base + 12) as *const i32);
+                                                          let len56 = l55 as usize;
+                                                          let bytes56 = Vec::from_raw_parts(l54 as *mut _, len56, len56);
+                                                          
+                                                          wit_bindgen::rt::string_lift(bytes56)
+                                                        };
+                                                        WitNode::PrimString(e61)
+                                                      }
+                                                      n => {
+                                                        debug_assert_eq!(n, 21, "invalid enum discriminant");
+                                                        let e61 = {
+                                                          let l57 = *((base + 8) as *const i32);
+                                                          let l58 = *((base + 12) as *const i32);
+                                                          let len59 = l58 as usize;
+                                                          let bytes59 = Vec::from_raw_parts(l57 as *mut _, len59, len59);
+                                                          let l60 = *((base + 16) as *const i64);
+                                                          
+                                                          (Uri{
+                                                            value: wit_bindgen::rt::string_lift(bytes59),
+                                                          }, l60 as u64)
+                                                        };
+                                                        WitNode::Handle(e61)
+                                                      }
+                                                    };
+                                                    
+                                                    v61
+                                                  };
+                                                  result62.push(e62);
+                                                }
+                                                wit_bindgen::rt::dealloc(base62, (len62 as usize) * 24, 8);
+                                                
+                                                WitValue{
+                                                  nodes: result62,
+                                                }
+                                              };
+                                              Ok(e)
+                                            }
+                                            1 => {
+                                              let e = {
+                                                let l63 = i32::from(*((ptr13 + 4) as *const u8));
+                                                let v76 = match l63 {
+                                                  0 => {
+                                                    let e76 = {
+                                                      let l64 = *((ptr13 + 8) as *const i32);
+                                                      let l65 = *((ptr13 + 12) as *const i32);
+                                                      let len66 = l65 as usize;
+                                                      let bytes66 = Vec::from_raw_parts(l64 as *mut _, len66, len66);
+                                                      
+                                                      wit_bindgen::rt::string_lift(bytes66)
+                                                    };
+                                                    RpcError::ProtocolError(e76)
+                                                  }
+                                                  1 => {
+                                                    let e76 = {
+                                                      let l67 = *((ptr13 + 8) as *const i32);
+                                                      let l68 = *((ptr13 + 12) as *const i32);
+                                                      let len69 = l68 as usize;
+                                                      let bytes69 = Vec::from_raw_parts(l67 as *mut _, len69, len69);
+                                                      
+                                                      wit_bindgen::rt::string_lift(bytes69)
+                                                    };
+                                                    RpcError::Denied(e76)
+                                                  }
+                                                  2 => {
+                                                    let e76 = {
+                                                      let l70 = *((ptr13 + 8) as *const i32);
+                                                      let l71 = *((ptr13 + 12) as *const i32);
+                                                      let len72 = l71 as usize;
+                                                      let bytes72 = Vec::from_raw_parts(l70 as *mut _, len72, len72);
+                                                      
+                                                      wit_bindgen::rt::string_lift(bytes72)
+                                                    };
+                                                    RpcError::NotFound(e76)
+                                                  }
+                                                  n => {
+                                                    debug_assert_eq!(n, 3, "invalid enum discriminant");
+                                                    let e76 = {
+                                                      let l73 = *((ptr13 + 8) as *const i32);
+                                                      let l74 = *((ptr13 + 12) as *const i32);
+                                                      let len75 = l74 as usize;
+                                                      let bytes75 = Vec::from_raw_parts(l73 as *mut _, len75, len75);
+                                                      
+                                                      wit_bindgen::rt::string_lift(bytes75)
+                                                    };
+                                                    RpcError::RemoteInternalError(e76)
+                                                  }
+                                                };
+                                                
+                                                v76
+                                              };
+                                              Err(e)
+                                            }
+                                            _ => wit_bindgen::rt::invalid_enum_discriminant(),
+                                          }
+                                        }
+                                      }
+                                    }
+                                    impl WasmRpc {
+                                      #[allow(unused_unsafe, clippy::all)]
+                                      pub fn invoke(&self,function_name: &str,function_params: &[WitValue],) -> Result<(),RpcError>{
+                                        
+                                        #[allow(unused_imports)]
+                                        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                        unsafe {
+                                          let mut cleanup_list = Vec::new();
+                                          
+                                          #[repr(align(4))]
+                                          struct RetArea([u8; 16]);
+                                          let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                          let vec0 = function_name;
+                                          let ptr0 = vec0.as_ptr() as i32;
+                                          let len0 = vec0.len() as i32;
+                                          let vec12 = function_params;
+                                          let len12 = vec12.len() as i32;
+                                          let layout12 = alloc::Layout::from_size_align_unchecked(vec12.len() * 8, 4);
+                                          let result12 = if layout12.size() != 0
+                                          {
+                                            let ptr = alloc::alloc(layout12);
+                                            if ptr.is_null()
+                                            {
+                                              alloc::handle_alloc_error(layout12);
+                                            }
+                                            ptr
+                                          }else {{
+                                            ::core::ptr::null_mut()
+                                          }};
+                                          for (i, e) in vec12.into_iter().enumerate() {
+                                            let base = result12 as i32 + (i as i32) * 8;
+                                            {
+                                              let WitValue{ nodes:nodes1, } = e;
+                                              let vec11 = nodes1;
+                                              let len11 = vec11.len() as i32;
+                                              let layout11 = alloc::Layout::from_size_align_unchecked(vec11.len() * 24, 8);
+                                              let result11 = if layout11.size() != 0
+                                              {
+                                                let ptr = alloc::alloc(layout11);
+                                                if ptr.is_null()
+                                                {
+                                                  alloc::handle_alloc_error(layout11);
+                                                }
+                                                ptr
+                                              }else {{
+                                                ::core::ptr::null_mut()
+                                              }};
+                                              for (i, e) in vec11.into_iter().enumerate() {
+                                                let base = result11 as i32 + (i as i32) * 24;
+                                                {
+                                                  match e {
+                                                    WitNode::RecordValue(e) => {
+                                                      *((base + 0) as *mut u8) = (0i32) as u8;
+                                                      let vec2 = e;
+                                                      let ptr2 = vec2.as_ptr() as i32;
+                                                      let len2 = vec2.len() as i32;
+                                                      *((base + 12) as *mut i32) = len2;
+                                                      *((base + 8) as *mut i32) = ptr2;
+                                                    },
+                                                    WitNode::VariantValue(e) => {
+                                                      *((base + 0) as *mut u8) = (1i32) as u8;
+                                                      let (t3_0, t3_1, ) = e;
+                                                      *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(t3_0);
+                                                      match t3_1 {
+                                                        Some(e) => {
+                                                          *((base + 12) as *mut u8) = (1i32) as u8;
+                                                          *((base + 16) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                                                        },
+                                                        None => {
+                                                          {
+                                                            *((base + 12) as *mut u8) = (0i32) as u8;
+                                                          }
+                                                        },
+                                                      };},
+                                                      WitNode::EnumValue(e) => {
+                                                        *((base + 0) as *mut u8) = (2i32) as u8;
+                                                        *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                                                      },
+                                                      WitNode::FlagsValue(e) => {
+                                                        *((base + 0) as *mut u8) = (3i32) as u8;
+                                                        let vec4 = e;
+                                                        let len4 = vec4.len() as i32;
+                                                        let layout4 = alloc::Layout::from_size_align_unchecked(vec4.len() * 1, 1);
+                                                        let result4 = if layout4.size() != 0
+                                                        {
+                                                          let ptr = alloc::alloc(layout4);
+                                                          if ptr.is_null()
+                                                          {
+                                                            alloc::handle_alloc_error(layout4);
+                                                          }
+                                                          ptr
+                                                        }else {{
+                                                          ::core::ptr::null_mut()
+                                                        }};
+                                                        for (i, e) in vec4.into_iter().enumerate() {
+                                                          let base = result4 as i32 + (i as i32) * 1;
+                                                          {
+                                                            *((base + 0) as *mut u8) = (match e { true => 1, false => 0 }) as u8;
+                                                          }
+                                                        }
+                                                        *((base + 12) as *mut i32) = len4;
+                                                        *((base + 8) as *mut i32) = result4 as i32;
+                                                        cleanup_list.extend_from_slice(&[(result4, layout4),]);
+                                                      },
+                                                      WitNode::TupleValue(e) => {
+                                                        *((base + 0) as *mut u8) = (4i32) as u8;
+                                                        let vec5 = e;
+                                                        let ptr5 = vec5.as_ptr() as i32;
+                                                        let len5 = vec5.len() as i32;
+                                                        *((base + 12) as *mut i32) = len5;
+                                                        *((base + 8) as *mut i32) = ptr5;
+                                                      },
+                                                      WitNode::ListValue(e) => {
+                                                        *((base + 0) as *mut u8) = (5i32) as u8;
+                                                        let vec6 = e;
+                                                        let ptr6 = vec6.as_ptr() as i32;
+                                                        let len6 = vec6.len() as i32;
+                                                        *((base + 12) as *mut i32) = len6;
+                                                        *((base + 8) as *mut i32) = ptr6;
+                                                      },
+                                                      WitNode::OptionValue(e) => {
+                                                        *((base + 0) as *mut u8) = (6i32) as u8;
+                                                        match e {
+                                                          Some(e) => {
+                                                            *((base + 8) as *mut u8) = (1i32) as u8;
+                                                            *((base + 12) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                                                          },
+                                                          None => {
+                                                            {
+                                                              *((base + 8) as *mut u8) = (0i32) as u8;
+                                                            }
+                                                          },
+                                                        };},
+                                                        WitNode::ResultValue(e) => {
+                                                          *((base + 0) as *mut u8) = (7i32) as u8;
+                                                          match e {
+                                                            Ok(e) => { {
+                                                              *((base + 8) as *mut u8) = (0i32) as u8;
+                                                              match e {
+                                                                Some(e) => {
+                                                                  *((base + 12) as *mut u8) = (1i32) as u8;
+                                                                  *((base + 16) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                                                                },
+                                                                None => {
+                                                                  {
+                                                                    *((base + 12) as *mut u8) = (0i32) as u8;
+                                                                  }
+                                                                },
+                                                              };} },
+                                                              Err(e) => { {
+                                                                *((base + 8) as *mut u8) = (1i32) as u8;
+                                                                match e {
+                                                                  Some(e) => {
+                                                                    *((base + 12) as *mut u8) = (1i32) as u8;
+                                                                    *((base + 16) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                                                                  },
+                                                                  None => {
+                                                                    {
+                                                                      *((base + 12) as *mut u8) = (0i32) as u8;
+                                                                    }
+                                                                  },
+                                                                };} },
+                                                              };},
+                                                              WitNode::PrimU8(e) => {
+                                                                *((base + 0) as *mut u8) = (8i32) as u8;
+                                                                *((base + 8) as *mut u8) = (wit_bindgen::rt::as_i32(e)) as u8;
+                                                              },
+                                                              WitNode::PrimU16(e) => {
+                                                                *((base + 0) as *mut u8) = (9i32) as u8;
+                                                                *((base + 8) as *mut u16) = (wit_bindgen::rt::as_i32(e)) as u16;
+                                                              },
+                                                              WitNode::PrimU32(e) => {
+                                                                *((base + 0) as *mut u8) = (10i32) as u8;
+                                                                *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                                                              },
+                                                              WitNode::PrimU64(e) => {
+                                                                *((base + 0) as *mut u8) = (11i32) as u8;
+                                                                *((base + 8) as *mut i64) = wit_bindgen::rt::as_i64(e);
+                                                              },
+                                                              WitNode::PrimS8(e) => {
+                                                                *((base + 0) as *mut u8) = (12i32) as u8;
+                                                                *((base + 8) as *mut u8) = (wit_bindgen::rt::as_i32(e)) as u8;
+                                                              },
+                                                              WitNode::PrimS16(e) => {
+                                                                *((base + 0) as *mut u8) = (13i32) as u8;
+                                                                *((base + 8) as *mut u16) = (wit_bindgen::rt::as_i32(e)) as u16;
+                                                              },
+                                                              WitNode::PrimS32(e) => {
+                                                                *((base + 0) as *mut u8) = (14i32) as u8;
+                                                                *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                                                              },
+                                                              WitNode::PrimS64(e) => {
+                                                                *((base + 0) as *mut u8) = (15i32) as u8;
+                                                                *((base + 8) as *mut i64) = wit_bindgen::rt::as_i64(e);
+                                                              },
+                                                              WitNode::PrimFloat32(e) => {
+                                                                *((base + 0) as *mut u8) = (16i32) as u8;
+                                                                *((base + 8) as *mut f32) = wit_bindgen::rt::as_f32(e);
+                                                              },
+                                                              WitNode::PrimFloat64(e) => {
+                                                                *((base + 0) as *mut u8) = (17i32) as u8;
+                                                                *((base + 8) as *mut f64) = wit_bindgen::rt::as_f64(e);
+                                                              },
+                                                              WitNode::PrimChar(e) => {
+                                                                *((base + 0) as *mut u8) = (18i32) as u8;
+                                                                *((base + 8) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                                                              },
+                                                              WitNode::PrimBool(e) => {
+                                                                *((base + 0) as *mut u8) = (19i32) as u8;
+                                                                *((base + 8) as *mut u8) = (match e { true => 1, false => 0 }) as u8;
+                                                              },
+                                                              WitNode::PrimString(e) => {
+                                                                *((base + 0) as *mut u8) = (20i32) as u8;
+                                                                let vec7 = e;
+                                                                let ptr7 = vec7.as_ptr() as i32;
+                                                                let len7 = vec7.len() as i32;
+                                                                *((base + 12) as *mut i32) = len7;
+                                                                *((base + 8) as *mut i32) = ptr7;
+                                                              },
+                                                              WitNode::Handle(e) => {
+                                                                *((base + 0) as *mut u8) = (21i32) as u8;
+                                                                let (t8_0, t8_1, ) = e;
+                                                                let Uri{ value:value9, } = t8_0;
+                                                                let vec10 = value9;
+                                                                let ptr10 = vec10.as_ptr() as i32;
+                                                                let len10 = vec10.len() as i32;
+                                                                *((base + 12) as *mut i32) = len10;
+                                                                *((base + 8) as *mut i32) = ptr10;
+                                                                *((base + 16) as *mut i64) = wit_bindgen::rt::as_i64(t8_1);
+                                                              },
+                                                            }
+                                                          }
+                                                        }
+                                                        *((base + 4) as *mut i32) = len11;
+                                                        *((base + 0) as *mut i32) = result11 as i32;
+                                                        cleanup_list.extend_from_slice(&[(result11, layout11),]);
+                                                      }
+                                                    }
+                                                    let ptr13 = ret_area.as_mut_ptr() as i32;
+                                                    #[cfg(target_arch = "wasm32")]
+                                                    #[link(wasm_import_module = "golem:rpc/types@0.1.0")]
+                                                    extern "C" {
+                                                      #[link_name = "[method]wasm-rpc.invoke"]
+                                                      fn wit_import(_: i32, _: i32, _: i32, _: i32, _: i32, _: i32, );
+                                                    }
+                                                    
+                                                    #[cfg(not(target_arch = "wasm32"))]
+                                                    fn wit_import(_: i32, _: i32, _: i32, _: i32, _: i32, _: i32, ){ unreachable!() }
+                                                    wit_import((self).handle() as i32, ptr0, len0, result12 as i32, len12, ptr13);
+                                                    let l14 = i32::from(*((ptr13 + 0) as *const u8));
+                                                    if layout12.size() != 0 {
+                                                      alloc::dealloc(result12, layout12);
+                                                    }
+                                                    for (ptr, layout) in cleanup_list {
+                                                      
+                                                      if layout.size() != 0 {
+                                                        
+                                                        alloc::dealloc(ptr, layout);
+                                                        
+                                                      }
+                                                      
+                                                    }
+                                                    match l14 {
+                                                      0 => {
+                                                        let e = ();
+                                                        Ok(e)
+                                                      }
+                                                      1 => {
+                                                        let e = {
+                                                          let l15 = i32::from(*((ptr13 + 4) as *const u8));
+                                                          let v28 = match l15 {
+                                                            0 => {
+                                                              let e28 = {
+                                                                let l16 = *((ptr13 + 8) as *const i32);
+                                                                let l17 = *((ptr13 + 12) as *const i32);
+                                                                let len18 = l17 as usize;
+                                                                let bytes18 = Vec::from_raw_parts(l16 as *mut _, len18, len18);
+                                                                
+                                                                wit_bindgen::rt::string_lift(bytes18)
+                                                              };
+                                                              RpcError::ProtocolError(e28)
+                                                            }
+                                                            1 => {
+                                                              let e28 = {
+                                                                let l19 = *((ptr13 + 8) as *const i32);
+                                                                let l20 = *((ptr13 + 12) as *const i32);
+                                                                let len21 = l20 as usize;
+                                                                let bytes21 = Vec::from_raw_parts(l19 as *mut _, len21, len21);
+                                                                
+                                                                wit_bindgen::rt::string_lift(bytes21)
+                                                              };
+                                                              RpcError::Denied(e28)
+                                                            }
+                                                            2 => {
+                                                              let e28 = {
+                                                                let l22 = *((ptr13 + 8) as *const i32);
+                                                                let l23 = *((ptr13 + 12) as *const i32);
+                                                                let len24 = l23 as usize;
+                                                                let bytes24 = Vec::from_raw_parts(l22 as *mut _, len24, len24);
+                                                                
+                                                                wit_bindgen::rt::string_lift(bytes24)
+                                                              };
+                                                              RpcError::NotFound(e28)
+                                                            }
+                                                            n => {
+                                                              debug_assert_eq!(n, 3, "invalid enum discriminant");
+                                                              let e28 = {
+                                                                let l25 = *((ptr13 + 8) as *const i32);
+                                                                let l26 = *((ptr13 + 12) as *const i32);
+                                                                let len27 = l26 as usize;
+                                                                let bytes27 = Vec::from_raw_parts(l25 as *mut _, len27, len27);
+                                                                
+                                                                wit_bindgen::rt::string_lift(bytes27)
+                                                              };
+                                                              RpcError::RemoteInternalError(e28)
+                                                            }
+                                                          };
+                                                          
+                                                          v28
+                                                        };
+                                                        Err(e)
+                                                      }
+                                                      _ => wit_bindgen::rt::invalid_enum_discriminant(),
+                                                    }
+                                                  }
+                                                }
+                                              }
+                                              
+                                            }
+                                            
+                                          }
+                                        }
+                                        pub mod rpc {
+                                          pub mod counters_stub {
+                                            
+                                            #[allow(clippy::all)]
+                                            pub mod stub_counters {
+                                              #[used]
+                                              #[doc(hidden)]
+                                              #[cfg(target_arch = "wasm32")]
+                                              static __FORCE_SECTION_REF: fn() = super::super::super::__link_section;
+                                              pub type Uri = super::super::super::golem::rpc::types::Uri;
+                                              
+                                              #[derive(Debug)]
+                                              #[repr(transparent)]
+                                              pub struct Api{
+                                                handle: wit_bindgen::rt::Resource<Api>,
+                                              }
+                                              
+                                              impl Api{
+                                                #[doc(hidden)]
+                                                pub unsafe fn from_handle(handle: u32) -> Self {
+                                                  Self {
+                                                    handle: wit_bindgen::rt::Resource::from_handle(handle),
+                                                  }
+                                                }
+                                                
+                                                #[doc(hidden)]
+                                                pub fn into_handle(self) -> u32 {
+                                                  wit_bindgen::rt::Resource::into_handle(self.handle)
+                                                }
+                                                
+                                                #[doc(hidden)]
+                                                pub fn handle(&self) -> u32 {
+                                                  wit_bindgen::rt::Resource::handle(&self.handle)
+                                                }
+                                              }
+                                              
+                                              
+                                              unsafe impl wit_bindgen::rt::WasmResource for Api{
+                                                #[inline]
+                                                unsafe fn drop(_handle: u32) {
+                                                  #[cfg(not(target_arch = "wasm32"))]
+                                                  unreachable!();
+                                                  
+                                                  #[cfg(target_arch = "wasm32")]
+                                                  {
+                                                    #[link(wasm_import_module = "rpc:counters-stub/stub-counters")]
+                                                    extern "C" {
+                                                      #[link_name = "[resource-drop]api"]
+                                                      fn drop(_: u32);
+                                                    }
+                                                    
+                                                    drop(_handle);
+                                                  }
+                                                }
+                                              }
+                                              
+                                              
+                                              #[derive(Debug)]
+                                              #[repr(transparent)]
+                                              pub struct Counter{
+                                                handle: wit_bindgen::rt::Resource<Counter>,
+                                              }
+                                              
+                                              impl Counter{
+                                                #[doc(hidden)]
+                                                pub unsafe fn from_handle(handle: u32) -> Self {
+                                                  Self {
+                                                    handle: wit_bindgen::rt::Resource::from_handle(handle),
+                                                  }
+                                                }
+                                                
+                                                #[doc(hidden)]
+                                                pub fn into_handle(self) -> u32 {
+                                                  wit_bindgen::rt::Resource::into_handle(self.handle)
+                                                }
+                                                
+                                                #[doc(hidden)]
+                                                pub fn handle(&self) -> u32 {
+                                                  wit_bindgen::rt::Resource::handle(&self.handle)
+                                                }
+                                              }
+                                              
+                                              
+                                              unsafe impl wit_bindgen::rt::WasmResource for Counter{
+                                                #[inline]
+                                                unsafe fn drop(_handle: u32) {
+                                                  #[cfg(not(target_arch = "wasm32"))]
+                                                  unreachable!();
+                                                  
+                                                  #[cfg(target_arch = "wasm32")]
+                                                  {
+                                                    #[link(wasm_import_module = "rpc:counters-stub/stub-counters")]
+                                                    extern "C" {
+                                                      #[link_name = "[resource-drop]counter"]
+                                                      fn drop(_: u32);
+                                                    }
+                                                    
+                                                    drop(_handle);
+                                                  }
+                                                }
+                                              }
+                                              
+                                              impl Api {
+                                                #[allow(unused_unsafe, clippy::all)]
+                                                pub fn new(location: &Uri,) -> Self{
+                                                  
+                                                  #[allow(unused_imports)]
+                                                  use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                                  unsafe {
+                                                    let super::super::super::golem::rpc::types::Uri{ value:value0, } = location;
+                                                    let vec1 = value0;
+                                                    let ptr1 = vec1.as_ptr() as i32;
+                                                    let len1 = vec1.len() as i32;
+                                                    
+                                                    #[cfg(target_arch = "wasm32")]
+                                                    #[link(wasm_import_module = "rpc:counters-stub/stub-counters")]
+                                                    extern "C" {
+                                                      #[link_name = "[constructor]api"]
+                                                      fn wit_import(_: i32, _: i32, ) -> i32;
+                                                    }
+                                                    
+                                                    #[cfg(not(target_arch = "wasm32"))]
+                                                    fn wit_import(_: i32, _: i32, ) -> i32{ unreachable!() }
+                                                    let ret = wit_import(ptr1, len1);
+                                                    Api::from_handle(ret as u32)
+                                                  }
+                                                }
+                                              }
+                                              impl Api {
+                                                #[allow(unused_unsafe, clippy::all)]
+                                                pub fn inc_global_by(&self,value: u64,){
+                                                  
+                                                  #[allow(unused_imports)]
+                                                  use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                                  unsafe {
+                                                    
+                                                    #[cfg(target_arch = "wasm32")]
+                                                    #[link(wasm_import_module = "rpc:counters-stub/stub-counters")]
+                                                    extern "C" {
+                                                      #[link_name = "[method]api.inc-global-by"]
+                                                      fn wit_import(_: i32, _: i64, );
+                                                    }
+                                                    
+                                                    #[cfg(not(target_arch = "wasm32"))]
+                                                    fn wit_import(_: i32, _: i64, ){ unreachable!() }
+                                                    wit_import((self).handle() as i32, wit_bindgen::rt::as_i64(value));
+                                                  }
+                                                }
+                                              }
+                                              impl Api {
+                                                #[allow(unused_unsafe, clippy::all)]
+                                                pub fn get_global_value(&self,) -> u64{
+                                                  
+                                                  #[allow(unused_imports)]
+                                                  use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                                  unsafe {
+                                                    
+                                                    #[cfg(target_arch = "wasm32")]
+                                                    #[link(wasm_import_module = "rpc:counters-stub/stub-counters")]
+                                                    extern "C" {
+                                                      #[link_name = "[method]api.get-global-value"]
+                                                      fn wit_import(_: i32, ) -> i64;
+                                                    }
+                                                    
+                                                    #[cfg(not(target_arch = "wasm32"))]
+                                                    fn wit_import(_: i32, ) -> i64{ unreachable!() }
+                                                    let ret = wit_import((self).handle() as i32);
+                                                    ret as u64
+                                                  }
+                                                }
+                                              }
+                                              impl Api {
+                                                #[allow(unused_unsafe, clippy::all)]
+                                                pub fn get_all_dropped(&self,) -> wit_bindgen::rt::vec::Vec::<(wit_bindgen::rt::string::String,u64,)>{
+                                                  
+                                                  #[allow(unused_imports)]
+                                                  use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                                  unsafe {
+                                                    
+                                                    #[repr(align(4))]
+                                                    struct RetArea([u8; 8]);
+                                                    let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                                    let ptr0 = ret_area.as_mut_ptr() as i32;
+                                                    #[cfg(target_arch = "wasm32")]
+                                                    #[link(wasm_import_module = "rpc:counters-stub/stub-counters")]
+                                                    extern "C" {
+                                                      #[link_name = "[method]api.get-all-dropped"]
+                                                      fn wit_import(_: i32, _: i32, );
+                                                    }
+                                                    
+                                                    #[cfg(not(target_arch = "wasm32"))]
+                                                    fn wit_import(_: i32, _: i32, ){ unreachable!() }
+                                                    wit_import((self).handle() as i32, ptr0);
+                                                    let l1 = *((ptr0 + 0) as *const i32);
+                                                    let l2 = *((ptr0 + 4) as *const i32);
+                                                    let base7 = l1;
+                                                    let len7 = l2;
+                                                    let mut result7 = Vec::with_capacity(len7 as usize);
+                                                    for i in 0..len7 {
+                                                      let base = base7 + i * 16;
+                                                      let e7 = {
+                                                        let l3 = *((base + 0) as *const i32);
+                                                        let l4 = *((base + 4) as *const i32);
+                                                        let len5 = l4 as usize;
+                                                        let bytes5 = Vec::from_raw_parts(l3 as *mut _, len5, len5);
+                                                        let l6 = *((base + 8) as *const i64);
+                                                        
+                                                        (wit_bindgen::rt::string_lift(bytes5), l6 as u64)
+                                                      };
+                                                      result7.push(e7);
+                                                    }
+                                                    wit_bindgen::rt::dealloc(base7, (len7 as usize) * 16, 8);
+                                                    result7
+                                                  }
+                                                }
+                                              }
+                                              impl Counter {
+                                                #[allow(unused_unsafe, clippy::all)]
+                                                pub fn new(location: &Uri,name: &str,) -> Self{
+                                                  
+                                                  #[allow(unused_imports)]
+                                                  use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                                  unsafe {
+                                                    let super::super::super::golem::rpc::types::Uri{ value:value0, } = location;
+                                                    let vec1 = value0;
+                                                    let ptr1 = vec1.as_ptr() as i32;
+                                                    let len1 = vec1.len() as i32;
+                                                    let vec2 = name;
+                                                    let ptr2 = vec2.as_ptr() as i32;
+                                                    let len2 = vec2.len() as i32;
+                                                    
+                                                    #[cfg(target_arch = "wasm32")]
+                                                    #[link(wasm_import_module = "rpc:counters-stub/stub-counters")]
+                                                    extern "C" {
+                                                      #[link_name = "[constructor]counter"]
+                                                      fn wit_import(_: i32, _: i32, _: i32, _: i32, ) -> i32;
+                                                    }
+                                                    
+                                                    #[cfg(not(target_arch = "wasm32"))]
+                                                    fn wit_import(_: i32, _: i32, _: i32, _: i32, ) -> i32{ unreachable!() }
+                                                    let ret = wit_import(ptr1, len1, ptr2, len2);
+                                                    Counter::from_handle(ret as u32)
+                                                  }
+                                                }
+                                              }
+                                              impl Counter {
+                                                #[allow(unused_unsafe, clippy::all)]
+                                                pub fn inc_by(&self,value: u64,){
+                                                  
+                                                  #[allow(unused_imports)]
+                                                  use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                                  unsafe {
+                                                    
+                                                    #[cfg(target_arch = "wasm32")]
+                                                    #[link(wasm_import_module = "rpc:counters-stub/stub-counters")]
+                                                    extern "C" {
+                                                      #[link_name = "[method]counter.inc-by"]
+                                                      fn wit_import(_: i32, _: i64, );
+                                                    }
+                                                    
+                                                    #[cfg(not(target_arch = "wasm32"))]
+                                                    fn wit_import(_: i32, _: i64, ){ unreachable!() }
+                                                    wit_import((self).handle() as i32, wit_bindgen::rt::as_i64(value));
+                                                  }
+                                                }
+                                              }
+                                              impl Counter {
+                                                #[allow(unused_unsafe, clippy::all)]
+                                                pub fn get_value(&self,) -> u64{
+                                                  
+                                                  #[allow(unused_imports)]
+                                                  use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                                  unsafe {
+                                                    
+                                                    #[cfg(target_arch = "wasm32")]
+                                                    #[link(wasm_import_module = "rpc:counters-stub/stub-counters")]
+                                                    extern "C" {
+                                                      #[link_name = "[method]counter.get-value"]
+                                                      fn wit_import(_: i32, ) -> i64;
+                                                    }
+                                                    
+                                                    #[cfg(not(target_arch = "wasm32"))]
+                                                    fn wit_import(_: i32, ) -> i64{ unreachable!() }
+                                                    let ret = wit_import((self).handle() as i32);
+                                                    ret as u64
+                                                  }
+                                                }
+                                              }
+                                              
+                                            }
+                                            
+                                          }
+                                        }
+                                        pub mod wasi {
+                                          pub mod clocks {
+                                            
+                                            #[allow(clippy::all)]
+                                            pub mod monotonic_clock {
+                                              #[used]
+                                              #[doc(hidden)]
+                                              #[cfg(target_arch = "wasm32")]
+                                              static __FORCE_SECTION_REF: fn() = super::super::super::__link_section;
+                                              pub type Pollable = super::super::super::wasi::io::poll::Pollable;
+                                              /// An instant in time, in nanoseconds. An instant is relative to an
+                                              /// unspecified initial value, and can only be compared to instances from
+                                              /// the same monotonic-clock.
+                                              pub type Instant = u64;
+                                              /// A duration of time, in nanoseconds.
+                                              pub type Duration = u64;
+                                              #[allow(unused_unsafe, clippy::all)]
+                                              /// Read the current value of the clock.
+                                              /// 
+                                              /// The clock is monotonic, therefore calling this function repeatedly will
+                                              /// produce a sequence of non-decreasing values.
+                                              pub fn now() -> Instant{
+                                                
+                                                #[allow(unused_imports)]
+                                                use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                                unsafe {
+                                                  
+                                                  #[cfg(target_arch = "wasm32")]
+                                                  #[link(wasm_import_module = "wasi:clocks/monotonic-clock@0.2.0")]
+                                                  extern "C" {
+                                                    #[link_name = "now"]
+                                                    fn wit_import() -> i64;
+                                                  }
+                                                  
+                                                  #[cfg(not(target_arch = "wasm32"))]
+                                                  fn wit_import() -> i64{ unreachable!() }
+                                                  let ret = wit_import();
+                                                  ret as u64
+                                                }
+                                              }
+                                              #[allow(unused_unsafe, clippy::all)]
+                                              /// Query the resolution of the clock. Returns the duration of time
+                                              /// corresponding to a clock tick.
+                                              pub fn resolution() -> Duration{
+                                                
+                                                #[allow(unused_imports)]
+                                                use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                                unsafe {
+                                                  
+                                                  #[cfg(target_arch = "wasm32")]
+                                                  #[link(wasm_import_module = "wasi:clocks/monotonic-clock@0.2.0")]
+                                                  extern "C" {
+                                                    #[link_name = "resolution"]
+                                                    fn wit_import() -> i64;
+                                                  }
+                                                  
+                                                  #[cfg(not(target_arch = "wasm32"))]
+                                                  fn wit_import() -> i64{ unreachable!() }
+                                                  let ret = wit_import();
+                                                  ret as u64
+                                                }
+                                              }
+                                              #[allow(unused_unsafe, clippy::all)]
+                                              /// Create a `pollable` which will resolve once the specified instant
+                                              /// occured.
+                                              pub fn subscribe_instant(when: Instant,) -> Pollable{
+                                                
+                                                #[allow(unused_imports)]
+                                                use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                                unsafe {
+                                                  
+                                                  #[cfg(target_arch = "wasm32")]
+                                                  #[link(wasm_import_module = "wasi:clocks/monotonic-clock@0.2.0")]
+                                                  extern "C" {
+                                                    #[link_name = "subscribe-instant"]
+                                                    fn wit_import(_: i64, ) -> i32;
+                                                  }
+                                                  
+                                                  #[cfg(not(target_arch = "wasm32"))]
+                                                  fn wit_import(_: i64, ) -> i32{ unreachable!() }
+                                                  let ret = wit_import(wit_bindgen::rt::as_i64(when));
+                                                  super::super::super::wasi::io::poll::Pollable::from_handle(ret as u32)
+                                                }
+                                              }
+                                              #[allow(unused_unsafe, clippy::all)]
+                                              /// Create a `pollable` which will resolve once the given duration has
+                                              /// elapsed, starting at the time at which this function was called.
+                                              /// occured.
+                                              pub fn subscribe_duration(when: Duration,) -> Pollable{
+                                                
+                                                #[allow(unused_imports)]
+                                                use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                                unsafe {
+                                                  
+                                                  #[cfg(target_arch = "wasm32")]
+                                                  #[link(wasm_import_module = "wasi:clocks/monotonic-clock@0.2.0")]
+                                                  extern "C" {
+                                                    #[link_name = "subscribe-duration"]
+                                                    fn wit_import(_: i64, ) -> i32;
+                                                  }
+                                                  
+                                                  #[cfg(not(target_arch = "wasm32"))]
+                                                  fn wit_import(_: i64, ) -> i32{ unreachable!() }
+                                                  let ret = wit_import(wit_bindgen::rt::as_i64(when));
+                                                  super::super::super::wasi::io::poll::Pollable::from_handle(ret as u32)
+                                                }
+                                              }
+                                              
+                                            }
+                                            
+                                          }
+                                          pub mod io {
+                                            
+                                            #[allow(clippy::all)]
+                                            pub mod poll {
+                                              #[used]
+                                              #[doc(hidden)]
+                                              #[cfg(target_arch = "wasm32")]
+                                              static __FORCE_SECTION_REF: fn() = super::super::super::__link_section;
+                                              /// `pollable` epresents a single I/O event which may be ready, or not.
+                                              
+                                              #[derive(Debug)]
+                                              #[repr(transparent)]
+                                              pub struct Pollable{
+                                                handle: wit_bindgen::rt::Resource<Pollable>,
+                                              }
+                                              
+                                              impl Pollable{
+                                                #[doc(hidden)]
+                                                pub unsafe fn from_handle(handle: u32) -> Self {
+                                                  Self {
+                                                    handle: wit_bindgen::rt::Resource::from_handle(handle),
+                                                  }
+                                                }
+                                                
+                                                #[doc(hidden)]
+                                                pub fn into_handle(self) -> u32 {
+                                                  wit_bindgen::rt::Resource::into_handle(self.handle)
+                                                }
+                                                
+                                                #[doc(hidden)]
+                                                pub fn handle(&self) -> u32 {
+                                                  wit_bindgen::rt::Resource::handle(&self.handle)
+                                                }
+                                              }
+                                              
+                                              
+                                              unsafe impl wit_bindgen::rt::WasmResource for Pollable{
+                                                #[inline]
+                                                unsafe fn drop(_handle: u32) {
+                                                  #[cfg(not(target_arch = "wasm32"))]
+                                                  unreachable!();
+                                                  
+                                                  #[cfg(target_arch = "wasm32")]
+                                                  {
+                                                    #[link(wasm_import_module = "wasi:io/poll@0.2.0")]
+                                                    extern "C" {
+                                                      #[link_name = "[resource-drop]pollable"]
+                                                      fn drop(_: u32);
+                                                    }
+                                                    
+                                                    drop(_handle);
+                                                  }
+                                                }
+                                              }
+                                              
+                                              impl Pollable {
+                                                #[allow(unused_unsafe, clippy::all)]
+                                                /// Return the readiness of a pollable. This function never blocks.
+                                                /// 
+                                                /// Returns `true` when the pollable is ready, and `false` otherwise.
+                                                pub fn ready(&self,) -> bool{
+                                                  
+                                                  #[allow(unused_imports)]
+                                                  use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                                  unsafe {
+                                                    
+                                                    #[cfg(target_arch = "wasm32")]
+                                                    #[link(wasm_import_module = "wasi:io/poll@0.2.0")]
+                                                    extern "C" {
+                                                      #[link_name = "[method]pollable.ready"]
+                                                      fn wit_import(_: i32, ) -> i32;
+                                                    }
+                                                    
+                                                    #[cfg(not(target_arch = "wasm32"))]
+                                                    fn wit_import(_: i32, ) -> i32{ unreachable!() }
+                                                    let ret = wit_import((self).handle() as i32);
+                                                    wit_bindgen::rt::bool_lift(ret as u8)
+                                                  }
+                                                }
+                                              }
+                                              impl Pollable {
+                                                #[allow(unused_unsafe, clippy::all)]
+                                                /// `block` returns immediately if the pollable is ready, and otherwise
+                                                /// blocks until ready.
+                                                /// 
+                                                /// This function is equivalent to calling `poll.poll` on a list
+                                                /// containing only this pollable.
+                                                pub fn block(&self,){
+                                                  
+                                                  #[allow(unused_imports)]
+                                                  use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                                  unsafe {
+                                                    
+                                                    #[cfg(target_arch = "wasm32")]
+                                                    #[link(wasm_import_module = "wasi:io/poll@0.2.0")]
+                                                    extern "C" {
+                                                      #[link_name = "[method]pollable.block"]
+                                                      fn wit_import(_: i32, );
+                                                    }
+                                                    
+                                                    #[cfg(not(target_arch = "wasm32"))]
+                                                    fn wit_import(_: i32, ){ unreachable!() }
+                                                    wit_import((self).handle() as i32);
+                                                  }
+                                                }
+                                              }
+                                              #[allow(unused_unsafe, clippy::all)]
+                                              /// Poll for completion on a set of pollables.
+                                              /// 
+                                              /// This function takes a list of pollables, which identify I/O sources of
+                                              /// interest, and waits until one or more of the events is ready for I/O.
+                                              /// 
+                                              /// The result `list<u32>` contains one or more indices of handles in the
+                                              /// argument list that is ready for I/O.
+                                              /// 
+                                              /// If the list contains more elements than can be indexed with a `u32`
+                                              /// value, this function traps.
+                                              /// 
+                                              /// A timeout can be implemented by adding a pollable from the
+                                              /// wasi-clocks API to the list.
+                                              /// 
+                                              /// This function does not return a `result`; polling in itself does not
+                                              /// do any I/O so it doesn't fail. If any of the I/O sources identified by
+                                              /// the pollables has an error, it is indicated by marking the source as
+                                              /// being reaedy for I/O.
+                                              pub fn poll(in_: &[&Pollable],) -> wit_bindgen::rt::vec::Vec::<u32>{
+                                                
+                                                #[allow(unused_imports)]
+                                                use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                                unsafe {
+                                                  
+                                                  #[repr(align(4))]
+                                                  struct RetArea([u8; 8]);
+                                                  let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                                  let vec0 = in_;
+                                                  let len0 = vec0.len() as i32;
+                                                  let layout0 = alloc::Layout::from_size_align_unchecked(vec0.len() * 4, 4);
+                                                  let result0 = if layout0.size() != 0
+                                                  {
+                                                    let ptr = alloc::alloc(layout0);
+                                                    if ptr.is_null()
+                                                    {
+                                                      alloc::handle_alloc_error(layout0);
+                                                    }
+                                                    ptr
+                                                  }else {{
+                                                    ::core::ptr::null_mut()
+                                                  }};
+                                                  for (i, e) in vec0.into_iter().enumerate() {
+                                                    let base = result0 as i32 + (i as i32) * 4;
+                                                    {
+                                                      *((base + 0) as *mut i32) = (e).handle() as i32;
+                                                    }
+                                                  }
+                                                  let ptr1 = ret_area.as_mut_ptr() as i32;
+                                                  #[cfg(target_arch = "wasm32")]
+                                                  #[link(wasm_import_module = "wasi:io/poll@0.2.0")]
+                                                  extern "C" {
+                                                    #[link_name = "poll"]
+                                                    fn wit_import(_: i32, _: i32, _: i32, );
+                                                  }
+                                                  
+                                                  #[cfg(not(target_arch = "wasm32"))]
+                                                  fn wit_import(_: i32, _: i32, _: i32, ){ unreachable!() }
+                                                  wit_import(result0 as i32, len0, ptr1);
+                                                  let l2 = *((ptr1 + 0) as *const i32);
+                                                  let l3 = *((ptr1 + 4) as *const i32);
+                                                  let len4 = l3 as usize;
+                                                  if layout0.size() != 0 {
+                                                    alloc::dealloc(result0, layout0);
+                                                  }
+                                                  Vec::from_raw_parts(l2 as *mut _, len4, len4)
+                                                }
+                                              }
+                                              
+                                            }
+                                            
+                                          }
+                                        }
+                                        
+                                        #[cfg(target_arch = "wasm32")]
+                                        #[link_section = "component-type:caller"]
+                                        #[doc(hidden)]
+                                        pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 4541] = [3, 0, 6, 99, 97, 108, 108, 101, 114, 0, 97, 115, 109, 13, 0, 1, 0, 7, 193, 34, 1, 65, 2, 1, 65, 39, 1, 66, 29, 1, 122, 4, 0, 10, 110, 111, 100, 101, 45, 105, 110, 100, 101, 120, 3, 0, 0, 1, 114, 1, 5, 118, 97, 108, 117, 101, 115, 4, 0, 3, 117, 114, 105, 3, 0, 2, 1, 112, 1, 1, 107, 1, 1, 111, 2, 121, 5, 1, 112, 127, 1, 106, 1, 5, 1, 5, 1, 111, 2, 3, 119, 1, 113, 22, 12, 114, 101, 99, 111, 114, 100, 45, 118, 97, 108, 117, 101, 1, 4, 0, 13, 118, 97, 114, 105, 97, 110, 116, 45, 118, 97, 108, 117, 101, 1, 6, 0, 10, 101, 110, 117, 109, 45, 118, 97, 108, 117, 101, 1, 121, 0, 11, 102, 108, 97, 103, 115, 45, 118, 97, 108, 117, 101, 1, 7, 0, 11, 116, 117, 112, 108, 101, 45, 118, 97, 108, 117, 101, 1, 4, 0, 10, 108, 105, 115, 116, 45, 118, 97, 108, 117, 101, 1, 4, 0, 12, 111, 112, 116, 105, 111, 110, 45, 118, 97, 108, 117, 101, 1, 5, 0, 12, 114, 101, 115, 117, 108, 116, 45, 118, 97, 108, 117, 101, 1, 8, 0, 7, 112, 114, 105, 109, 45, 117, 56, 1, 125, 0, 8, 112, 114, 105, 109, 45, 117, 49, 54, 1, 123, 0, 8, 112, 114, 105, 109, 45, 117, 51, 50, 1, 121, 0, 8, 112, 114, 105, 109, 45, 117, 54, 52, 1, 119, 0, 7, 112, 114, 105, 109, 45, 115, 56, 1, 126, 0, 8, 112, 114, 105, 109, 45, 115, 49, 54, 1, 124, 0, 8, 112, 114, 105, 109, 45, 115, 51, 50, 1, 122, 0, 8, 112, 114, 105, 109, 45, 115, 54, 52, 1, 120, 0, 12, 112, 114, 105, 109, 45, 102, 108, 111, 97, 116, 51, 50, 1, 118, 0, 12, 112, 114, 105, 109, 45, 102, 108, 111, 97, 116, 54, 52, 1, 117, 0, 9, 112, 114, 105, 109, 45, 99, 104, 97, 114, 1, 116, 0, 9, 112, 114, 105, 109, 45, 98, 111, 111, 108, 1, 127, 0, 11, 112, 114, 105, 109, 45, 115, 116, 114, 105, 110, 103, 1, 115, 0, 6, 104, 97, 110, 100, 108, 101, 1, 9, 0, 4, 0, 8, 119, 105, 116, 45, 110, 111, 100, 101, 3, 0, 10, 1, 112, 11, 1, 114, 1, 5, 110, 111, 100, 101, 115, 12, 4, 0, 9, 119, 105, 116, 45, 118, 97, 108, 117, 101, 3, 0, 13, 1, 113, 4, 14, 112, 114, 111, 116, 111, 99, 111, 108, 45, 101, 114, 114, 111, 114, 1, 115, 0, 6, 100, 101, 110, 105, 101, 100, 1, 115, 0, 9, 110, 111, 116, 45, 102, 111, 117, 110, 100, 1, 115, 0, 21, 114, 101, 109, 111, 116, 101, 45, 105, 110, 116, 101, 114, 110, 97, 108, 45, 101, 114, 114, 111, 114, 1, 115, 0, 4, 0, 9, 114, 112, 99, 45, 101, 114, 114, 111, 114, 3, 0, 15, 4, 0, 8, 119, 97, 115, 109, 45, 114, 112, 99, 3, 1, 1, 105, 17, 1, 64, 1, 8, 108, 111, 99, 97, 116, 105, 111, 110, 3, 0, 18, 4, 0, 21, 91, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 93, 119, 97, 115, 109, 45, 114, 112, 99, 1, 19, 1, 104, 17, 1, 112, 14, 1, 106, 1, 14, 1, 16, 1, 64, 3, 4, 115, 101, 108, 102, 20, 13, 102, 117, 110, 99, 116, 105, 111, 110, 45, 110, 97, 109, 101, 115, 15, 102, 117, 110, 99, 116, 105, 111, 110, 45, 112, 97, 114, 97, 109, 115, 21, 0, 22, 4, 0, 33, 91, 109, 101, 116, 104, 111, 100, 93, 119, 97, 115, 109, 45, 114, 112, 99, 46, 105, 110, 118, 111, 107, 101, 45, 97, 110, 100, 45, 97, 119, 97, 105, 116, 1, 23, 1, 106, 0, 1, 16, 1, 64, 3, 4, 115, 101, 108, 102, 20, 13, 102, 117, 110, 99, 116, 105, 111, 110, 45, 110, 97, 109, 101, 115, 15, 102, 117, 110, 99, 116, 105, 111, 110, 45, 112, 97, 114, 97, 109, 115, 21, 0, 24, 4, 0, 23, 91, 109, 101, 116, 104, 111, 100, 93, 119, 97, 115, 109, 45, 114, 112, 99, 46, 105, 110, 118, 111, 107, 101, 1, 25, 3, 1, 21, 103, 111, 108, 101, 109, 58, 114, 112, 99, 47, 116, 121, 112, 101, 115, 64, 48, 46, 49, 46, 48, 5, 0, 2, 3, 0, 0, 3, 117, 114, 105, 1, 66, 24, 2, 3, 2, 1, 1, 4, 0, 3, 117, 114, 105, 3, 0, 0, 4, 0, 3, 97, 112, 105, 3, 1, 4, 0, 7, 99, 111, 117, 110, 116, 101, 114, 3, 1, 1, 105, 2, 1, 64, 1, 8, 108, 111, 99, 97, 116, 105, 111, 110, 1, 0, 4, 4, 0, 16, 91, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 93, 97, 112, 105, 1, 5, 1, 104, 2, 1, 64, 2, 4, 115, 101, 108, 102, 6, 5, 118, 97, 108, 117, 101, 119, 1, 0, 4, 0, 25, 91, 109, 101, 116, 104, 111, 100, 93, 97, 112, 105, 46, 105, 110, 99, 45, 103, 108, 111, 98, 97, 108, 45, 98, 121, 1, 7, 1, 64, 1, 4, 115, 101, 108, 102, 6, 0, 119, 4, 0, 28, 91, 109, 101, 116, 104, 111, 100, 93, 97, 112, 105, 46, 103, 101, 116, 45, 103, 108, 111, 98, 97, 108, 45, 118, 97, 108, 117, 101, 1, 8, 1, 111, 2, 115, 119, 1, 112, 9, 1, 64, 1, 4, 115, 101, 108, 102, 6, 0, 10, 4, 0, 27, 91, 109, 101, 116, 104, 111, 100, 93, 97, 112, 105, 46, 103, 101, 116, 45, 97, 108, 108, 45, 100, 114, 111, 112, 112, 101, 100, 1, 11, 1, 105, 3, 1, 64, 2, 8, 108, 111, 99, 97, 116, 105, 111, 110, 1, 4, 110, 97, 109, 101, 115, 0, 12, 4, 0, 20, 91, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 93, 99, 111, 117, 110, 116, 101, 114, 1, 13, 1, 104, 3, 1, 64, 2, 4, 115, 101, 108, 102, 14, 5, 118, 97, 108, 117, 101, 119, 1, 0, 4, 0, 22, 91, 109, 101, 116, 104, 111, 100, 93, 99, 111, 117, 110, 116, 101, 114, 46, 105, 110, 99, 45, 98, 121, 1, 15, 1, 64, 1, 4, 115, 101, 108, 102, 14, 0, 119, 4, 0, 25, 91, 109, 101, 116, 104, 111, 100, 93, 99, 111, 117, 110, 116, 101, 114, 46, 103, 101, 116, 45, 118, 97, 108, 117, 101, 1, 16, 3, 1, 31, 114, 112, 99, 58, 99, 111, 117, 110, 116, 101, 114, 115, 45, 115, 116, 117, 98, 47, 115, 116, 117, 98, 45, 99, 111, 117, 110, 116, 101, 114, 115, 5, 2, 1, 66, 10, 4, 0, 8, 112, 111, 108, 108, 97, 98, 108, 101, 3, 1, 1, 104, 0, 1, 64, 1, 4, 115, 101, 108, 102, 1, 0, 127, 4, 0, 22, 91, 109, 101, 116, 104, 111, 100, 93, 112, 111, 108, 108, 97, 98, 108, 101, 46, 114, 101, 97, 100, 121, 1, 2, 1, 64, 1, 4, 115, 101, 108, 102, 1, 1, 0, 4, 0, 22, 91, 109, 101, 116, 104, 111, 100, 93, 112, 111, 108, 108, 97, 98, 108, 101, 46, 98, 108, 111, 99, 107, 1, 3, 1, 112, 1, 1, 112, 121, 1, 64, 1, 2, 105, 110, 4, 0, 5, 4, 0, 4, 112, 111, 108, 108, 1, 6, 3, 1, 18, 119, 97, 115, 105, 58, 105, 111, 47, 112, 111, 108, 108, 64, 48, 46, 50, 46, 48, 5, 3, 2, 3, 0, 2, 8, 112, 111, 108, 108, 97, 98, 108, 101, 2, 3, 0, 0, 8, 119, 97, 115, 109, 45, 114, 112, 99, 2, 3, 0, 0, 9, 119, 105, 116, 45, 118, 97, 108, 117, 101, 2, 3, 0, 0, 9, 114, 112, 99, 45, 101, 114, 114, 111, 114, 1, 66, 22, 2, 3, 2, 1, 4, 4, 0, 8, 112, 111, 108, 108, 97, 98, 108, 101, 3, 0, 0, 2, 3, 2, 1, 5, 4, 0, 8, 119, 97, 115, 109, 45, 114, 112, 99, 3, 0, 2, 2, 3, 2, 1, 6, 4, 0, 9, 119, 105, 116, 45, 118, 97, 108, 117, 101, 3, 0, 4, 2, 3, 2, 1, 7, 4, 0, 9, 114, 112, 99, 45, 101, 114, 114, 111, 114, 3, 0, 6, 4, 0, 20, 102, 117, 116, 117, 114, 101, 45, 105, 110, 118, 111, 107, 101, 45, 114, 101, 115, 117, 108, 116, 3, 1, 1, 104, 8, 1, 105, 1, 1, 64, 1, 4, 115, 101, 108, 102, 9, 0, 10, 4, 0, 38, 91, 109, 101, 116, 104, 111, 100, 93, 102, 117, 116, 117, 114, 101, 45, 105, 110, 118, 111, 107, 101, 45, 114, 101, 115, 117, 108, 116, 46, 115, 117, 98, 115, 99, 114, 105, 98, 101, 1, 11, 1, 106, 1, 5, 1, 7, 1, 107, 12, 1, 64, 1, 4, 115, 101, 108, 102, 9, 0, 13, 4, 0, 32, 91, 109, 101, 116, 104, 111, 100, 93, 102, 117, 116, 117, 114, 101, 45, 105, 110, 118, 111, 107, 101, 45, 114, 101, 115, 117, 108, 116, 46, 103, 101, 116, 1, 14, 1, 104, 3, 1, 112, 5, 1, 105, 8, 1, 64, 3, 3, 114, 112, 99, 15, 13, 102, 117, 110, 99, 116, 105, 111, 110, 45, 110, 97, 109, 101, 115, 15, 102, 117, 110, 99, 116, 105, 111, 110, 45, 112, 97, 114, 97, 109, 115, 16, 0, 17, 4, 0, 22, 97, 115, 121, 110, 99, 45, 105, 110, 118, 111, 107, 101, 45, 97, 110, 100, 45, 97, 119, 97, 105, 116, 1, 18, 3, 1, 25, 103, 111, 108, 101, 109, 58, 101, 120, 116, 47, 97, 115, 121, 110, 99, 45, 114, 112, 99, 64, 48, 46, 49, 46, 48, 5, 8, 1, 66, 15, 2, 3, 2, 1, 4, 4, 0, 8, 112, 111, 108, 108, 97, 98, 108, 101, 3, 0, 0, 1, 119, 4, 0, 7, 105, 110, 115, 116, 97, 110, 116, 3, 0, 2, 1, 119, 4, 0, 8, 100, 117, 114, 97, 116, 105, 111, 110, 3, 0, 4, 1, 64, 0, 0, 3, 4, 0, 3, 110, 111, 119, 1, 6, 1, 64, 0, 0, 5, 4, 0, 10, 114, 101, 115, 111, 108, 117, 116, 105, 111, 110, 1, 7, 1, 105, 1, 1, 64, 1, 4, 119, 104, 101, 110, 3, 0, 8, 4, 0, 17, 115, 117, 98, 115, 99, 114, 105, 98, 101, 45, 105, 110, 115, 116, 97, 110, 116, 1, 9, 1, 64, 1, 4, 119, 104, 101, 110, 5, 0, 8, 4, 0, 18, 115, 117, 98, 115, 99, 114, 105, 98, 101, 45, 100, 117, 114, 97, 116, 105, 111, 110, 1, 10, 3, 1, 33, 119, 97, 115, 105, 58, 99, 108, 111, 99, 107, 115, 47, 109, 111, 110, 111, 116, 111, 110, 105, 99, 45, 99, 108, 111, 99, 107, 64, 48, 46, 50, 46, 48, 5, 9, 2, 3, 0, 4, 8, 100, 117, 114, 97, 116, 105, 111, 110, 1, 66, 97, 2, 3, 2, 1, 1, 4, 0, 3, 117, 114, 105, 3, 0, 0, 2, 3, 2, 1, 10, 4, 0, 8, 100, 117, 114, 97, 116, 105, 111, 110, 3, 0, 2, 1, 119, 4, 0, 11, 111, 112, 108, 111, 103, 45, 105, 110, 100, 101, 120, 3, 0, 4, 1, 119, 4, 0, 17, 99, 111, 109, 112, 111, 110, 101, 110, 116, 45, 118, 101, 114, 115, 105, 111, 110, 3, 0, 6, 1, 114, 2, 9, 104, 105, 103, 104, 45, 98, 105, 116, 115, 119, 8, 108, 111, 119, 45, 98, 105, 116, 115, 119, 4, 0, 4, 117, 117, 105, 100, 3, 0, 8, 1, 114, 1, 4, 117, 117, 105, 100, 9, 4, 0, 12, 99, 111, 109, 112, 111, 110, 101, 110, 116, 45, 105, 100, 3, 0, 10, 1, 114, 2, 12, 99, 111, 109, 112, 111, 110, 101, 110, 116, 45, 105, 100, 11, 11, 119, 111, 114, 107, 101, 114, 45, 110, 97, 109, 101, 115, 4, 0, 9, 119, 111, 114, 107, 101, 114, 45, 105, 100, 3, 0, 12, 1, 114, 2, 9, 119, 111, 114, 107, 101, 114, 45, 105, 100, 13, 9, 111, 112, 108, 111, 103, 45, 105, 100, 120, 5, 4, 0, 10, 112, 114, 111, 109, 105, 115, 101, 45, 105, 100, 3, 0, 14, 1, 114, 4, 12, 109, 97, 120, 45, 97, 116, 116, 101, 109, 112, 116, 115, 121, 9, 109, 105, 110, 45, 100, 101, 108, 97, 121, 3, 9, 109, 97, 120, 45, 100, 101, 108, 97, 121, 3, 10, 109, 117, 108, 116, 105, 112, 108, 105, 101, 114, 121, 4, 0, 12, 114, 101, 116, 114, 121, 45, 112, 111, 108, 105, 99, 121, 3, 0, 16, 1, 113, 3, 15, 112, 101, 114, 115, 105, 115, 116, 45, 110, 111, 116, 104, 105, 110, 103, 0, 0, 27, 112, 101, 114, 115, 105, 115, 116, 45, 114, 101, 109, 111, 116, 101, 45, 115, 105, 100, 101, 45, 101, 102, 102, 101, 99, 116, 115, 0, 0, 5, 115, 109, 97, 114, 116, 0, 0, 4, 0, 17, 112, 101, 114, 115, 105, 115, 116, 101, 110, 99, 101, 45, 108, 101, 118, 101, 108, 3, 0, 18, 1, 109, 2, 9, 97, 117, 116, 111, 109, 97, 116, 105, 99, 14, 115, 110, 97, 112, 115, 104, 111, 116, 45, 98, 97, 115, 101, 100, 4, 0, 11, 117, 112, 100, 97, 116, 101, 45, 109, 111, 100, 101, 3, 0, 20, 1, 109, 6, 5, 101, 113, 117, 97, 108, 9, 110, 111, 116, 45, 101, 113, 117, 97, 108, 13, 103, 114, 101, 97, 116, 101, 114, 45, 101, 113, 117, 97, 108, 7, 103, 114, 101, 97, 116, 101, 114, 10, 108, 101, 115, 115, 45, 101, 113, 117, 97, 108, 4, 108, 101, 115, 115, 4, 0, 17, 102, 105, 108, 116, 101, 114, 45, 99, 111, 109, 112, 97, 114, 97, 116, 111, 114, 3, 0, 22, 1, 109, 4, 5, 101, 113, 117, 97, 108, 9, 110, 111, 116, 45, 101, 113, 117, 97, 108, 4, 108, 105, 107, 101, 8, 110, 111, 116, 45, 108, 105, 107, 101, 4, 0, 24, 115, 116, 114, 105, 110, 103, 45, 102, 105, 108, 116, 101, 114, 45, 99, 111, 109, 112, 97, 114, 97, 116, 111, 114, 3, 0, 24, 1, 109, 7, 7, 114, 117, 110, 110, 105, 110, 103, 4, 105, 100, 108, 101, 9, 115, 117, 115, 112, 101, 110, 100, 101, 100, 11, 105, 110, 116, 101, 114, 114, 117, 112, 116, 101, 100, 8, 114, 101, 116, 114, 121, 105, 110, 103, 6, 102, 97, 105, 108, 101, 100, 6, 101, 120, 105, 116, 101, 100, 4, 0, 13, 119, 111, 114, 107, 101, 114, 45, 115, 116, 97, 116, 117, 115, 3, 0, 26, 1, 114, 2, 10, 99, 111, 109, 112, 97, 114, 97, 116, 111, 114, 25, 5, 118, 97, 108, 117, 101, 115, 4, 0, 18, 119, 111, 114, 107, 101, 114, 45, 110, 97, 109, 101, 45, 102, 105, 108, 116, 101, 114, 3, 0, 28, 1, 114, 2, 10, 99, 111, 109, 112, 97, 114, 97, 116, 111, 114, 23, 5, 118, 97, 108, 117, 101, 27, 4, 0, 20, 119, 111, 114, 107, 101, 114, 45, 115, 116, 97, 116, 117, 115, 45, 102, 105, 108, 116, 101, 114, 3, 0, 30, 1, 114, 2, 10, 99, 111, 109, 112, 97, 114, 97, 116, 111, 114, 23, 5, 118, 97, 108, 117, 101, 119, 4, 0, 21, 119, 111, 114, 107, 101, 114, 45, 118, 101, 114, 115, 105, 111, 110, 45, 102, 105, 108, 116, 101, 114, 3, 0, 32, 1, 114, 2, 10, 99, 111, 109, 112, 97, 114, 97, 116, 111, 114, 23, 5, 118, 97, 108, 117, 101, 119, 4, 0, 24, 119, 111, 114, 107, 101, 114, 45, 99, 114, 101, 97, 116, 101, 100, 45, 97, 116, 45, 102, 105, 108, 116, 101, 114, 3, 0, 34, 1, 114, 3, 4, 110, 97, 109, 101, 115, 10, 99, 111, 109, 112, 97, 114, 97, 116, 111, 114, 25, 5, 118, 97, 108, 117, 101, 115, 4, 0, 17, 119, 111, 114, 107, 101, 114, 45, 101, 110, 118, 45, 102, 105, 108, 116, 101, 114, 3, 0, 36, 1, 113, 5, 4, 110, 97, 109, 101, 1, 29, 0, 6, 115, 116, 97, 116, 117, 115, 1, 31, 0, 7, 118, 101, 114, 115, 105, 111, 110, 1, 33, 0, 10, 99, 114, 101, 97, 116, 101, 100, 45, 97, 116, 1, 35, 0, 3, 101, 110, 118, 1, 37, 0, 4, 0, 22, 119, 111, 114, 107, 101, 114, 45, 112, 114, 111, 112, 101, 114, 116, 121, 45, 102, 105, 108, 116, 101, 114, 3, 0, 38, 1, 112, 39, 1, 114, 1, 7, 102, 105, 108, 116, 101, 114, 115, 40, 4, 0, 17, 119, 111, 114, 107, 101, 114, 45, 97, 108, 108, 45, 102, 105, 108, 116, 101, 114, 3, 0, 41, 1, 112, 42, 1, 114, 1, 7, 102, 105, 108, 116, 101, 114, 115, 43, 4, 0, 17, 119, 111, 114, 107, 101, 114, 45, 97, 110, 121, 45, 102, 105, 108, 116, 101, 114, 3, 0, 44, 1, 112, 115, 1, 111, 2, 115, 115, 1, 112, 47, 1, 114, 6, 9, 119, 111, 114, 107, 101, 114, 45, 105, 100, 13, 4, 97, 114, 103, 115, 46, 3, 101, 110, 118, 48, 6, 115, 116, 97, 116, 117, 115, 27, 17, 99, 111, 109, 112, 111, 110, 101, 110, 116, 45, 118, 101, 114, 115, 105, 111, 110, 119, 11, 114, 101, 116, 114, 121, 45, 99, 111, 117, 110, 116, 119, 4, 0, 15, 119, 111, 114, 107, 101, 114, 45, 109, 101, 116, 97, 100, 97, 116, 97, 3, 0, 49, 4, 0, 11, 103, 101, 116, 45, 119, 111, 114, 107, 101, 114, 115, 3, 1, 1, 107, 45, 1, 105, 51, 1, 64, 3, 12, 99, 111, 109, 112, 111, 110, 101, 110, 116, 45, 105, 100, 11, 6, 102, 105, 108, 116, 101, 114, 52, 7, 112, 114, 101, 99, 105, 115, 101, 127, 0, 53, 4, 0, 24, 91, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 93, 103, 101, 116, 45, 119, 111, 114, 107, 101, 114, 115, 1, 54, 1, 104, 51, 1, 112, 50, 1, 107, 56, 1, 64, 1, 4, 115, 101, 108, 102, 55, 0, 57, 4, 0, 28, 91, 109, 101, 116, 104, 111, 100, 93, 103, 101, 116, 45, 119, 111, 114, 107, 101, 114, 115, 46, 103, 101, 116, 45, 110, 101, 120, 116, 1, 58, 1, 64, 0, 0, 15, 4, 0, 20, 103, 111, 108, 101, 109, 45, 99, 114, 101, 97, 116, 101, 45, 112, 114, 111, 109, 105, 115, 101, 1, 59, 1, 112, 125, 1, 64, 1, 10, 112, 114, 111, 109, 105, 115, 101, 45, 105, 100, 15, 0, 60, 4, 0, 19, 103, 111, 108, 101, 109, 45, 97, 119, 97, 105, 116, 45, 112, 114, 111, 109, 105, 115, 101, 1, 61, 1, 64, 2, 10, 112, 114, 111, 109, 105, 115, 101, 45, 105, 100, 15, 4, 100, 97, 116, 97, 60, 0, 127, 4, 0, 22, 103, 111, 108, 101, 109, 45, 99, 111, 109, 112, 108, 101, 116, 101, 45, 112, 114, 111, 109, 105, 115, 101, 1, 62, 1, 64, 1, 10, 112, 114, 111, 109, 105, 115, 101, 45, 105, 100, 15, 1, 0, 4, 0, 20, 103, 111, 108, 101, 109, 45, 100, 101, 108, 101, 116, 101, 45, 112, 114, 111, 109, 105, 115, 101, 1, 63, 1, 64, 1, 13, 102, 117, 110, 99, 116, 105, 111, 110, 45, 110, 97, 109, 101, 115, 0, 1, 4, 0, 12, 103, 101, 116, 45, 115, 101, 108, 102, 45, 117, 114, 105, 1, 64, 1, 64, 0, 0, 5, 4, 0, 15, 103, 101, 116, 45, 111, 112, 108, 111, 103, 45, 105, 110, 100, 101, 120, 1, 65, 1, 64, 1, 9, 111, 112, 108, 111, 103, 45, 105, 100, 120, 5, 1, 0, 4, 0, 15, 115, 101, 116, 45, 111, 112, 108, 111, 103, 45, 105, 110, 100, 101, 120, 1, 66, 1, 64, 1, 8, 114, 101, 112, 108, 105, 99, 97, 115, 125, 1, 0, 4, 0, 12, 111, 112, 108, 111, 103, 45, 99, 111, 109, 109, 105, 116, 1, 67, 4, 0, 20, 109, 97, 114, 107, 45, 98, 101, 103, 105, 110, 45, 111, 112, 101, 114, 97, 116, 105, 111, 110, 1, 65, 1, 64, 1, 5, 98, 101, 103, 105, 110, 5, 1, 0, 4, 0, 18, 109, 97, 114, 107, 45, 101, 110, 100, 45, 111, 112, 101, 114, 97, 116, 105, 111, 110, 1, 68, 1, 64, 0, 0, 17, 4, 0, 16, 103, 101, 116, 45, 114, 101, 116, 114, 121, 45, 112, 111, 108, 105, 99, 121, 1, 69, 1, 64, 1, 16, 110, 101, 119, 45, 114, 101, 116, 114, 121, 45, 112, 111, 108, 105, 99, 121, 17, 1, 0, 4, 0, 16, 115, 101, 116, 45, 114, 101, 116, 114, 121, 45, 112, 111, 108, 105, 99, 121, 1, 70, 1, 64, 0, 0, 19, 4, 0, 27, 103, 101, 116, 45, 111, 112, 108, 111, 103, 45, 112, 101, 114, 115, 105, 115, 116, 101, 110, 99, 101, 45, 108, 101, 118, 101, 108, 1, 71, 1, 64, 1, 21, 110, 101, 119, 45, 112, 101, 114, 115, 105, 115, 116, 101, 110, 99, 101, 45, 108, 101, 118, 101, 108, 19, 1, 0, 4, 0, 27, 115, 101, 116, 45, 111, 112, 108, 111, 103, 45, 112, 101, 114, 115, 105, 115, 116, 101, 110, 99, 101, 45, 108, 101, 118, 101, 108, 1, 72, 1, 64, 0, 0, 127, 4, 0, 20, 103, 101, 116, 45, 105, 100, 101, 109, 112, 111, 116, 101, 110, 99, 101, 45, 109, 111, 100, 101, 1, 73, 1, 64, 1, 10, 105, 100, 101, 109, 112, 111, 116, 101, 110, 116, 127, 1, 0, 4, 0, 20, 115, 101, 116, 45, 105, 100, 101, 109, 112, 111, 116, 101, 110, 99, 101, 45, 109, 111, 100, 101, 1, 74, 1, 64, 0, 0, 9, 4, 0, 24, 103, 101, 110, 101, 114, 97, 116, 101, 45, 105, 100, 101, 109, 112, 111, 116, 101, 110, 99, 121, 45, 107, 101, 121, 1, 75, 1, 64, 3, 9, 119, 111, 114, 107, 101, 114, 45, 105, 100, 13, 14, 116, 97, 114, 103, 101, 116, 45, 118, 101, 114, 115, 105, 111, 110, 7, 4, 109, 111, 100, 101, 21, 1, 0, 4, 0, 13, 117, 112, 100, 97, 116, 101, 45, 119, 111, 114, 107, 101, 114, 1, 76, 3, 1, 20, 103, 111, 108, 101, 109, 58, 97, 112, 105, 47, 104, 111, 115, 116, 64, 48, 46, 50, 46, 48, 5, 11, 2, 3, 0, 5, 12, 99, 111, 109, 112, 111, 110, 101, 110, 116, 45, 105, 100, 2, 3, 0, 5, 9, 119, 111, 114, 107, 101, 114, 45, 105, 100, 2, 3, 0, 5, 17, 119, 111, 114, 107, 101, 114, 45, 97, 110, 121, 45, 102, 105, 108, 116, 101, 114, 1, 66, 18, 2, 3, 2, 1, 12, 4, 0, 12, 99, 111, 109, 112, 111, 110, 101, 110, 116, 45, 105, 100, 3, 0, 0, 2, 3, 2, 1, 13, 4, 0, 9, 119, 111, 114, 107, 101, 114, 45, 105, 100, 3, 0, 2, 2, 3, 2, 1, 14, 4, 0, 17, 119, 111, 114, 107, 101, 114, 45, 97, 110, 121, 45, 102, 105, 108, 116, 101, 114, 3, 0, 4, 2, 3, 2, 1, 6, 4, 0, 9, 119, 105, 116, 45, 118, 97, 108, 117, 101, 3, 0, 6, 2, 3, 2, 1, 7, 4, 0, 9, 114, 112, 99, 45, 101, 114, 114, 111, 114, 3, 0, 8, 1, 106, 1, 7, 1, 9, 1, 114, 2, 9, 119, 111, 114, 107, 101, 114, 45, 105, 100, 3, 6, 114, 101, 115, 117, 108, 116, 10, 4, 0, 16, 98, 114, 111, 97, 100, 99, 97, 115, 116, 45, 114, 101, 115, 117, 108, 116, 3, 0, 11, 1, 107, 5, 1, 112, 7, 1, 112, 12, 1, 64, 5, 12, 99, 111, 109, 112, 111, 110, 101, 110, 116, 45, 105, 100, 1, 6, 102, 105, 108, 116, 101, 114, 13, 13, 102, 117, 110, 99, 116, 105, 111, 110, 45, 110, 97, 109, 101, 115, 15, 102, 117, 110, 99, 116, 105, 111, 110, 45, 112, 97, 114, 97, 109, 115, 14, 15, 109, 97, 120, 45, 99, 111, 110, 99, 117, 114, 114, 101, 110, 99, 121, 121, 0, 15, 4, 0, 26, 98, 114, 111, 97, 100, 99, 97, 115, 116, 45, 105, 110, 118, 111, 107, 101, 45, 97, 110, 100, 45, 97, 119, 97, 105, 116, 1, 16, 3, 1, 25, 103, 111, 108, 101, 109, 58, 101, 120, 116, 47, 98, 114, 111, 97, 100, 99, 97, 115, 116, 64, 48, 46, 49, 46, 48, 5, 15, 1, 111, 2, 115, 119, 1, 112, 16, 1, 64, 0, 0, 17, 4, 0, 5, 116, 101, 115, 116, 49, 1, 18, 1, 64, 0, 0, 119, 4, 0, 5, 116, 101, 115, 116, 50, 1, 19, 4, 0, 5, 116, 101, 115, 116, 51, 1, 19, 4, 0, 5, 116, 101, 115, 116, 52, 1, 19, 1, 64, 1, 15, 109, 97, 120, 45, 99, 111, 110, 99, 117, 114, 114, 101, 110, 99, 121, 121, 0, 121, 4, 0, 5, 116, 101, 115, 116, 53, 1, 20, 4, 0, 5, 116, 101, 115, 116, 54, 1, 19, 1, 112, 115, 1, 64, 1, 4, 112, 97, 116, 104, 21, 0, 115, 4, 0, 6, 98, 111, 117, 110, 99, 101, 1, 22, 1, 64, 1, 4, 115, 101, 99, 115, 119, 0, 119, 4, 0, 5, 116, 101, 115, 116, 55, 1, 23, 4, 1, 17, 114, 112, 99, 58, 99, 97, 108, 108, 101, 114, 47, 99, 97, 108, 108, 101, 114, 4, 0, 11, 12, 1, 0, 6, 99, 97, 108, 108, 101, 114, 3, 0, 0, 0, 16, 12, 112, 97, 99, 107, 97, 103, 101, 45, 100, 111, 99, 115, 0, 123, 125, 0, 70, 9, 112, 114, 111, 100, 117, 99, 101, 114, 115, 1, 12, 112, 114, 111, 99, 101, 115, 115, 101, 100, 45, 98, 121, 2, 13, 119, 105, 116, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 6, 48, 46, 49, 56, 46, 50, 16, 119, 105, 116, 45, 98, 105, 110, 100, 103, 101, 110, 45, 114, 117, 115, 116, 6, 48, 46, 49, 54, 46, 48];
+                                        
+                                        #[inline(never)]
+                                        #[doc(hidden)]
+                                        #[cfg(target_arch = "wasm32")]
+                                        pub fn __link_section() {}
+                                        
\ No newline at end of file
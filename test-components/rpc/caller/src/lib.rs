@@ -1,6 +1,10 @@
 mod bindings;
 
-use crate::bindings::golem::rpc::types::Uri;
+use crate::bindings::golem::api::host::{ComponentId, Uuid};
+use crate::bindings::golem::ext::async_rpc::{async_invoke_and_await, FutureInvokeResult};
+use crate::bindings::golem::ext::broadcast::broadcast_invoke_and_await;
+use crate::bindings::golem::rpc::types::{RpcError, Uri, WasmRpc, WitNode, WitValue};
+use crate::bindings::wasi::io::poll::poll;
 use crate::bindings::rpc::counters_stub::stub_counters::{Api, Counter};
 use bindings::*;
 use std::env;
@@ -65,6 +69,154 @@ impl Guest for Component {
         api.inc_global_by(1);
         api.get_global_value()
     }
+
+    fn test4() -> u64 {
+        let component_id =
+            env::var("COUNTERS_COMPONENT_ID").expect("COUNTERS_COMPONENT_ID not set");
+        let counters_uri = Uri {
+            value: format!("worker://{component_id}/counters_test4"),
+        };
+        let rpc = WasmRpc::new(&counters_uri);
+
+        println!("Starting two increments without waiting for them");
+        let first = async_invoke_and_await(&rpc, "rpc:counters/api/inc-global-by", &[u64_value(1)]);
+        let second = async_invoke_and_await(&rpc, "rpc:counters/api/inc-global-by", &[u64_value(2)]);
+
+        // Checking the first result without waiting, then blocking until any of them is done
+        let first_result = match first.get() {
+            Some(result) => result,
+            None => {
+                let pollables = [first.subscribe(), second.subscribe()];
+                poll(&pollables.iter().collect::<Vec<_>>());
+                wait_for(&first)
+            }
+        };
+        let second_result = wait_for(&second);
+        first_result.expect("first increment failed");
+        second_result.expect("second increment failed");
+
+        let value = async_invoke_and_await(&rpc, "rpc:counters/api/get-global-value", &[]);
+        let value = wait_for(&value).expect("failed to get the global value");
+        match single_result(&value) {
+            Some(WitNode::PrimU64(value)) => *value,
+            _ => panic!("unexpected global value"),
+        }
+    }
+
+    fn test5(max_concurrency: u32) -> u32 {
+        let component_id =
+            env::var("COUNTERS_COMPONENT_ID").expect("COUNTERS_COMPONENT_ID not set");
+        let (high_bits, low_bits) = uuid::Uuid::parse_str(&component_id)
+            .expect("COUNTERS_COMPONENT_ID is not a UUID")
+            .as_u64_pair();
+        let component_id = ComponentId {
+            uuid: Uuid {
+                high_bits,
+                low_bits,
+            },
+        };
+
+        println!("Incrementing the global value of every counters worker");
+        let results = broadcast_invoke_and_await(
+            component_id,
+            None,
+            "rpc:counters/api/inc-global-by",
+            &[u64_value(1)],
+            max_concurrency,
+        );
+        results
+            .into_iter()
+            .filter(|result| result.result.is_ok())
+            .count() as u32
+    }
+
+    fn test6() -> u64 {
+        let component_id =
+            env::var("COUNTERS_COMPONENT_ID").expect("COUNTERS_COMPONENT_ID not set");
+        let counters_uri = Uri {
+            value: format!("worker://{component_id}"),
+        };
+        let rpc = WasmRpc::new(&counters_uri);
+
+        println!("Incrementing and reading the global value on ephemeral workers");
+        rpc.invoke_and_await("rpc:counters/api/inc-global-by", &[u64_value(1)])
+            .expect("failed to increment on an ephemeral worker");
+        let value = rpc
+            .invoke_and_await("rpc:counters/api/get-global-value", &[])
+            .expect("failed to get the global value of an ephemeral worker");
+        match single_result(&value) {
+            Some(WitNode::PrimU64(value)) => *value,
+            _ => panic!("unexpected global value"),
+        }
+    }
+
+    fn bounce(path: Vec<String>) -> String {
+        match path.split_first() {
+            None => "done".to_string(),
+            Some((next, rest)) => {
+                let component_id =
+                    env::var("CALLER_COMPONENT_ID").expect("CALLER_COMPONENT_ID not set");
+                let uri = Uri {
+                    value: format!("worker://{component_id}/{next}"),
+                };
+                println!("Bouncing to {next}");
+                match WasmRpc::new(&uri).invoke_and_await("bounce", &[string_list_value(rest)]) {
+                    Ok(result) => match single_result(&result) {
+                        Some(WitNode::PrimString(result)) => result.clone(),
+                        _ => panic!("unexpected result of bouncing to {next}"),
+                    },
+                    Err(RpcError::Denied(details)) => format!("denied: {details}"),
+                    Err(err) => panic!("bouncing to {next} failed: {err:?}"),
+                }
+            }
+        }
+    }
+
+    fn test7(secs: u64) -> u64 {
+        let component_id =
+            env::var("CLOCK_SERVICE_COMPONENT_ID").expect("CLOCK_SERVICE_COMPONENT_ID not set");
+        let uri = Uri {
+            value: format!("worker://{component_id}/clock_test7"),
+        };
+        let rpc = WasmRpc::new(&uri);
+
+        println!("Waiting for a remote sleep of {secs} seconds");
+        let result = async_invoke_and_await(&rpc, "golem:it/api/sleep", &[u64_value(secs)]);
+        wait_for(&result).expect("remote sleep failed");
+        secs
+    }
+}
+
+fn u64_value(value: u64) -> WitValue {
+    WitValue {
+        nodes: vec![WitNode::PrimU64(value)],
+    }
+}
+
+fn string_list_value(values: &[String]) -> WitValue {
+    let mut nodes = vec![WitNode::ListValue((1..=values.len() as i32).collect())];
+    nodes.extend(values.iter().map(|value| WitNode::PrimString(value.clone())));
+    WitValue { nodes }
+}
+
+/// The result of an invocation is a tuple of the function's results
+fn single_result(value: &WitValue) -> Option<&WitNode> {
+    match value.nodes.first() {
+        Some(WitNode::TupleValue(items)) => items
+            .first()
+            .and_then(|idx| value.nodes.get(*idx as usize)),
+        _ => None,
+    }
+}
+
+/// Waits until the result of the call is available
+fn wait_for(result: &FutureInvokeResult) -> Result<WitValue, RpcError> {
+    loop {
+        if let Some(result) = result.get() {
+            return result;
+        }
+        poll(&[&result.subscribe()]);
+    }
 }
 
 fn create_use_and_drop_counters(counters_uri: &Uri) {
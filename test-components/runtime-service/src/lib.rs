@@ -2,6 +2,8 @@ mod bindings;
 
 use crate::bindings::exports::golem::it::api::Guest;
 use crate::bindings::golem::api::host::*;
+use crate::bindings::golem::ext::saga::register_compensation;
+use crate::bindings::golem::rpc::types::{WitNode, WitValue};
 use crate::bindings::wasi;
 use crate::bindings::wasi::io::streams::StreamError;
 use reqwest::{Client, Response};
@@ -157,6 +159,41 @@ impl Guest for Component {
         remote_side_effect("4"); // only performed once
     }
 
+    fn failed_compensated_region(component_id: ComponentId, worker_name: String) {
+        set_retry_policy(RetryPolicy {
+            max_attempts: 0,
+            min_delay: 1000000000, // 1s
+            max_delay: 1000000000, // 1s
+            multiplier: 1,
+        });
+
+        let begin = mark_begin_operation();
+
+        remote_side_effect("1");
+        register_compensation(
+            begin,
+            component_id,
+            &worker_name,
+            "golem:it/api/compensate-step",
+            &[string_value("undo 1")],
+        );
+
+        remote_side_effect("2");
+        register_compensation(
+            begin,
+            component_id,
+            &worker_name,
+            "golem:it/api/compensate-step",
+            &[string_value("undo 2")],
+        );
+
+        panic!("crash before the end of the region"); // runs 'undo 2' and then 'undo 1'
+    }
+
+    fn compensate_step(step: String) {
+        remote_side_effect(&step);
+    }
+
     fn get_workers(
         component_id: ComponentId,
         filter: Option<WorkerAnyFilter>,
@@ -186,6 +223,12 @@ impl Guest for Component {
     }
 }
 
+fn string_value(value: &str) -> WitValue {
+    WitValue {
+        nodes: vec![WitNode::PrimString(value.to_string())],
+    }
+}
+
 fn remote_call(param: u64) -> bool {
     let port = std::env::var("PORT").unwrap_or("9999".to_string());
 